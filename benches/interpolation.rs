@@ -32,6 +32,7 @@ fn bench_interpolation_types(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -47,6 +48,7 @@ fn bench_interpolation_types(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -62,6 +64,7 @@ fn bench_interpolation_types(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -77,6 +80,7 @@ fn bench_interpolation_types(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -104,6 +108,7 @@ fn bench_subdivision_scaling(c: &mut Criterion) {
                     subdivisions,
                     tension: 0.5,
                     closed: false,
+                    ..Default::default()
                 };
 
                 b.iter(|| {
@@ -125,6 +130,7 @@ fn bench_input_size_scaling(c: &mut Criterion) {
         subdivisions: 10,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     for size in [5, 10, 20, 50] {
@@ -163,6 +169,7 @@ fn bench_series_interpolation(c: &mut Criterion) {
                 subdivisions: 8,
                 tension: 0.5,
                 closed: false,
+                ..Default::default()
             };
 
             b.iter(|| {
@@ -192,6 +199,7 @@ fn bench_tension_effects(c: &mut Criterion) {
                     subdivisions: 10,
                     tension,
                     closed: false,
+                    ..Default::default()
                 };
 
                 b.iter(|| {
@@ -222,6 +230,7 @@ fn bench_edge_cases(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -239,6 +248,7 @@ fn bench_edge_cases(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -262,6 +272,7 @@ fn bench_edge_cases(c: &mut Criterion) {
             subdivisions: 15,
             tension: 0.7,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -347,6 +347,37 @@ fn bench_feature_memory_overhead(c: &mut Criterion) {
     group.finish();
 }
 
+/// Measure theme and palette setup, and guard the size of const themes
+fn bench_const_theme_memory(c: &mut Criterion) {
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    // Built-in themes and palettes are plain constants: a theme is nine colors
+    // and a palette only borrows its color array, so neither grows with N.
+    static THEME: Theme<Rgb565> = Theme::DARK;
+    assert_eq!(
+        mem::size_of::<Theme<Rgb565>>(),
+        9 * mem::size_of::<Rgb565>()
+    );
+    assert!(
+        mem::size_of::<ColorPalette<Rgb565, 8>>()
+            <= mem::size_of::<heapless::Vec<Rgb565, 8>>() + 2 * mem::size_of::<usize>(),
+        "ColorPalette grew beyond its color storage"
+    );
+    assert_eq!(THEME.background, Theme::<Rgb565>::dark().background);
+
+    let mut group = c.benchmark_group("const_theme_memory");
+
+    group.bench_function("theme_const", |b| {
+        b.iter(|| black_box(&THEME).primary);
+    });
+
+    group.bench_function("theme_constructor", |b| {
+        b.iter(|| black_box(Theme::<Rgb565>::dark()).primary);
+    });
+
+    group.finish();
+}
+
 /// Memory usage summary reporting
 #[allow(dead_code)]
 fn generate_memory_report() {
@@ -417,6 +448,7 @@ criterion_group!(
     bench_complete_chart_memory,
     bench_allocation_patterns,
     bench_feature_memory_overhead,
+    bench_const_theme_memory,
 );
 
 criterion_main!(memory_benches);
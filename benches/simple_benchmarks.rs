@@ -160,6 +160,7 @@ fn bench_interpolation(c: &mut Criterion) {
             subdivisions: 10,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         b.iter(|| {
@@ -0,0 +1,96 @@
+//! Span rasterizer benchmarks
+//!
+//! Measures the shared span fills used by stacked, area, pattern and pie fills
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use embedded_charts::render::{FallbackTarget, SpanRasterizer};
+use embedded_charts::style::{PatternFill, PatternType};
+use embedded_graphics::{
+    mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*, primitives::Rectangle,
+};
+use std::hint::black_box;
+
+/// Create a fresh MockDisplay that allows overdrawing
+fn create_test_display() -> MockDisplay<Rgb565> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display
+}
+
+/// Benchmark triangle and quad fills
+fn bench_triangles(c: &mut Criterion) {
+    let raster = SpanRasterizer::new(Rectangle::new(Point::zero(), Size::new(64, 64)));
+
+    c.bench_function("span_fill_triangle", |b| {
+        b.iter(|| {
+            let mut display = create_test_display();
+            raster
+                .fill_triangle(
+                    black_box(Point::new(2, 1)),
+                    black_box(Point::new(60, 20)),
+                    black_box(Point::new(10, 62)),
+                    Rgb565::RED,
+                    &mut FallbackTarget::new(&mut display),
+                )
+                .unwrap();
+        })
+    });
+
+    c.bench_function("span_fill_under_polyline", |b| {
+        let points: Vec<Point> = (0..16)
+            .map(|i| Point::new(i * 4, 32 + ((i * 7) % 20) - 10))
+            .collect();
+        b.iter(|| {
+            let mut display = create_test_display();
+            raster
+                .fill_under_polyline(
+                    black_box(&points),
+                    63,
+                    Rgb565::BLUE,
+                    &mut FallbackTarget::new(&mut display),
+                )
+                .unwrap();
+        })
+    });
+}
+
+/// Benchmark mask and pattern fills
+fn bench_mask_and_pattern(c: &mut Criterion) {
+    let area = Rectangle::new(Point::zero(), Size::new(64, 64));
+    let raster = SpanRasterizer::new(area);
+
+    c.bench_function("span_fill_mask_circle", |b| {
+        b.iter(|| {
+            let mut display = create_test_display();
+            raster
+                .fill_mask(
+                    area,
+                    |x, y| (x - 32) * (x - 32) + (y - 32) * (y - 32) <= 30 * 30,
+                    Rgb565::GREEN,
+                    &mut FallbackTarget::new(&mut display),
+                )
+                .unwrap();
+        })
+    });
+
+    c.bench_function("span_fill_pattern", |b| {
+        let pattern = PatternFill::new(
+            Rgb565::BLACK,
+            Rgb565::WHITE,
+            PatternType::Checkerboard { size: 4 },
+        );
+        b.iter(|| {
+            let mut display = create_test_display();
+            raster
+                .fill_pattern(
+                    black_box(area),
+                    &pattern,
+                    &mut FallbackTarget::new(&mut display),
+                )
+                .unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_triangles, bench_mask_and_pattern);
+criterion_main!(benches);
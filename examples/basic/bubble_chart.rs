@@ -56,6 +56,7 @@ fn main() -> ChartResult<()> {
     let color_mapping = ColorMapping {
         colors,
         strategy: ColorMappingStrategy::ValueBased,
+        scaling: ColorScaling::MinMax,
     };
 
     // Create size mapping for bubble effect
@@ -168,6 +169,7 @@ mod tests {
         let mapping = ColorMapping {
             colors,
             strategy: ColorMappingStrategy::ValueBased,
+            scaling: ColorScaling::MinMax,
         };
 
         assert_eq!(mapping.colors.len(), 3);
@@ -25,9 +25,8 @@ use embedded_graphics_simulator::{
 use std::{thread, time::Duration};
 
 // Import capture utilities for GIF generation
-#[path = "../common/capture.rs"]
-mod capture;
-use capture::GifCapture;
+#[cfg(feature = "capture")]
+use embedded_charts::dev_tools::capture::GifCapture;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create display
@@ -79,13 +78,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut last_fps_time = std::time::Instant::now();
     let mut fps = 0.0f32;
 
-    // GIF capture setup
-    let mut gif_capture = GifCapture::new(50); // 50ms per frame = 20fps
-    let mut capture_frame_count = 0;
+    // GIF capture setup, 50ms per frame = 20fps
+    #[cfg(feature = "capture")]
+    let mut gif_capture = std::env::var("CAPTURE_GIF")
+        .is_ok()
+        .then(|| GifCapture::new(50));
+    #[cfg(feature = "capture")]
     let max_capture_frames = 60; // Capture 3 seconds at 20fps for a more reasonable GIF size
-    let capture_enabled = std::env::var("CAPTURE_GIF").is_ok();
 
-    if capture_enabled {
+    #[cfg(feature = "capture")]
+    if gif_capture.is_some() {
         println!("GIF capture enabled! Will save to docs/assets/ring_buffer_demo.gif");
     }
 
@@ -145,9 +147,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             draw_buffer_visualization(&mut display, &streaming_buffer)?;
 
             // Capture frame for GIF if enabled
-            if capture_enabled && capture_frame_count < max_capture_frames {
+            #[cfg(feature = "capture")]
+            if let Some(gif_capture) = gif_capture.as_mut() {
                 gif_capture.add_frame(&display);
-                capture_frame_count += 1;
+                let capture_frame_count = gif_capture.frame_count();
                 println!("Captured frame {capture_frame_count}/{max_capture_frames}");
 
                 // Save GIF when we have enough frames
@@ -222,6 +222,7 @@ fn render_clustered_mode(
     let color_mapping = ColorMapping {
         colors,
         strategy: ColorMappingStrategy::IndexBased, // Color by data point index
+        scaling: ColorScaling::MinMax,
     };
 
     // Create collision detection settings
@@ -362,6 +363,7 @@ mod tests {
         let mapping = ColorMapping {
             colors,
             strategy: ColorMappingStrategy::IndexBased,
+            scaling: ColorScaling::MinMax,
         };
 
         assert_eq!(mapping.colors.len(), 3);
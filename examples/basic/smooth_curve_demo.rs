@@ -60,10 +60,15 @@ fn main() -> ChartResult<()> {
     window::run_static(window_config, move |display, viewport| {
         let config = ChartConfig {
             title: Some(heapless::String::try_from("Temperature Over Time").unwrap_or_default()),
+            subtitle: None,
+            title_style: None,
             background_color: None, // Window handles background
             margins: common::CHART_MARGINS,
             grid_color: Some(Rgb565::CSS_LIGHT_GRAY),
+            background_mode: BackgroundMode::Fill,
+            placeholder: None,
             show_grid: true,
+            locale: NumberLocale::PLAIN,
         };
 
         curve_chart.draw(&data, &config, viewport, display)
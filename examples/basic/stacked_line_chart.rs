@@ -330,10 +330,15 @@ fn run_animated_demo() -> ChartResult<()> {
     // Pre-create chart configuration (static)
     let config = ChartConfig {
         title: Some(heapless::String::try_from("Energy Transition - Renewable Growth").unwrap()),
+        subtitle: None,
+        title_style: None,
         background_color: Some(Rgb565::WHITE),
         margins: CHART_MARGINS,
         show_grid: false,
         grid_color: None,
+        background_mode: BackgroundMode::Fill,
+        placeholder: None,
+        locale: NumberLocale::PLAIN,
     };
 
     // Pre-create text style for month labels
@@ -2,19 +2,73 @@
 //
 // This module provides essential utilities for examples to eliminate boilerplate code duplication.
 
-pub mod window;
-
-#[cfg(all(feature = "std", feature = "capture"))]
-pub mod capture;
-
 use embedded_charts::prelude::*;
 use embedded_graphics::pixelcolor::Rgb565;
 
 /// Re-export commonly used types
 #[allow(unused_imports)] // May not be used by all examples
-pub use window::WindowConfig;
+pub use embedded_charts::dev_tools::layout;
 #[allow(unused_imports)] // May not be used by all examples
-pub use window::WindowTheme;
+pub use embedded_charts::dev_tools::{WindowConfig, WindowTheme};
+
+/// Preview windows from `embedded_charts::dev_tools`
+///
+/// With the `capture` feature, the runners save README assets to `docs/assets`:
+/// a GIF for animated examples and a PNG for the others.
+#[allow(dead_code)] // Not every example uses both runners
+pub mod window {
+    use embedded_charts::dev_tools;
+    use embedded_charts::prelude::*;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics_simulator::SimulatorDisplay;
+
+    #[allow(unused_imports)] // May not be used by all examples
+    pub use embedded_charts::dev_tools::{presets, WindowConfig, WindowManager, WindowTheme};
+
+    /// Run an animated example, see `dev_tools::run`
+    pub fn run<F>(window_config: WindowConfig, animation_fn: F) -> ChartResult<()>
+    where
+        F: FnMut(&mut SimulatorDisplay<Rgb565>, Rectangle, f32) -> ChartResult<()>,
+    {
+        dev_tools::run(with_capture(window_config), animation_fn)
+    }
+
+    /// Run a static example, see `dev_tools::run_static`
+    pub fn run_static<F>(window_config: WindowConfig, render_fn: F) -> ChartResult<()>
+    where
+        F: FnMut(&mut SimulatorDisplay<Rgb565>, Rectangle) -> ChartResult<()>,
+    {
+        dev_tools::run_static(with_capture(window_config), render_fn)
+    }
+
+    #[cfg(feature = "capture")]
+    fn with_capture(window_config: WindowConfig) -> WindowConfig {
+        use embedded_charts::dev_tools::capture::CaptureMode;
+
+        let title = window_config.title.to_lowercase();
+        let animated = [
+            "animation",
+            "animated",
+            "streaming",
+            "real-time",
+            "dashboard",
+            "demo",
+        ]
+        .iter()
+        .any(|word| title.contains(word));
+        let mode = if animated {
+            CaptureMode::Animation
+        } else {
+            CaptureMode::Screenshot
+        };
+        window_config.capture(mode, "docs/assets")
+    }
+
+    #[cfg(not(feature = "capture"))]
+    fn with_capture(window_config: WindowConfig) -> WindowConfig {
+        window_config
+    }
+}
 
 /// Chart margins optimized for examples with axes and legends
 #[allow(dead_code)] // Standard margins for examples
@@ -26,6 +80,9 @@ pub const CHART_MARGINS: Margins = Margins {
 };
 
 /// Data generation utilities
+///
+/// Seeded versions of the sine, temperature and system metric generators are
+/// published in `embedded_charts::data::demo` behind the `demo-data` feature.
 #[allow(dead_code)] // Utility functions for examples data generation
 pub mod data {
     use super::*;
@@ -223,81 +280,6 @@ pub mod configs {
     }
 }
 
-/// Auto-legend layout functionality
-#[allow(dead_code)] // Layout utilities for charts
-pub mod layout {
-    use super::*;
-    use embedded_graphics::draw_target::DrawTarget;
-
-    /// Chart with automatic legend layout
-    #[allow(dead_code)] // Utility struct for charts with legends
-    pub struct ChartWithLegend<'a, C: PixelColor> {
-        pub legend: Option<&'a StandardLegend<C>>,
-        pub renderer: Option<&'a StandardLegendRenderer<C>>,
-    }
-
-    #[allow(dead_code)] // Utility methods for chart with legend
-    impl<'a, C: PixelColor> ChartWithLegend<'a, C> {
-        pub fn new(legend: &'a StandardLegend<C>, renderer: &'a StandardLegendRenderer<C>) -> Self {
-            Self {
-                legend: Some(legend),
-                renderer: Some(renderer),
-            }
-        }
-
-        pub fn none() -> Self {
-            Self {
-                legend: None,
-                renderer: None,
-            }
-        }
-    }
-
-    /// Draw any chart with automatic legend layout
-    pub fn draw_chart_with_auto_legend<D>(
-        chart_drawer: impl FnOnce(Rectangle, &mut D) -> ChartResult<()>,
-        viewport: Rectangle,
-        display: &mut D,
-        legend_setup: ChartWithLegend<Rgb565>,
-    ) -> ChartResult<()>
-    where
-        D: DrawTarget<Color = Rgb565>,
-    {
-        if let (Some(legend), Some(renderer)) = (legend_setup.legend, legend_setup.renderer) {
-            // Simple layout: legend on right, chart on left
-            let legend_size = legend.calculate_size();
-            let legend_width = legend_size.width + 20; // Add padding
-
-            let chart_area = Rectangle::new(
-                viewport.top_left,
-                Size::new(
-                    viewport.size.width.saturating_sub(legend_width),
-                    viewport.size.height,
-                ),
-            );
-
-            let legend_rect = Rectangle::new(
-                Point::new(
-                    viewport.top_left.x + chart_area.size.width as i32 + 10,
-                    viewport.top_left.y + 20,
-                ),
-                legend_size,
-            );
-
-            // Draw chart in adjusted area
-            chart_drawer(chart_area, display)?;
-
-            // Render legend
-            renderer.render(legend, legend_rect, display)?;
-        } else {
-            // No legend, use full viewport
-            chart_drawer(viewport, display)?;
-        }
-
-        Ok(())
-    }
-}
-
 /// Utility functions for common example patterns
 #[allow(dead_code)] // Utility functions for examples
 pub mod utils {
@@ -50,10 +50,15 @@ fn main() -> ChartResult<()> {
     // Create chart configuration outside the render loop
     let chart_config = ChartConfig {
         title: None,
+        subtitle: None,
+        title_style: None,
         background_color: None,
         margins: Margins::new(60, 40, 60, 80),
         show_grid: false,
         grid_color: None,
+        background_mode: BackgroundMode::Fill,
+        placeholder: None,
+        locale: NumberLocale::PLAIN,
     };
 
     // Pre-create series data array and series names
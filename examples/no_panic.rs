@@ -0,0 +1,108 @@
+//! panic-never link check
+//!
+//! Draws a line chart with markers and an area fill on a display that only
+//! counts pixels. For bare-metal targets the example links `panic-never`, whose
+//! panic handler is an undefined symbol: if any panic path survives
+//! optimization in the line chart render path, linking fails. CI builds it with
+//! the `no-panic-strict` feature, which also denies panicking indexing and
+//! unwraps in that path:
+//!
+//! ```text
+//! cargo build --release --example no_panic --target thumbv6m-none-eabi \
+//!     --no-default-features --features "no_std,line,no-panic-strict"
+//! ```
+//!
+//! Only release builds can pass; debug builds keep overflow checks and
+//! unoptimized bounds checks. On the host it runs as a normal example.
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+
+use embedded_charts::prelude::*;
+
+/// Display that discards pixels and counts how many were drawn
+struct CountingDisplay {
+    pixels: u32,
+}
+
+impl OriginDimensions for CountingDisplay {
+    fn size(&self) -> Size {
+        Size::new(96, 64)
+    }
+}
+
+impl DrawTarget for CountingDisplay {
+    type Color = Rgb565;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for _ in pixels {
+            self.pixels = self.pixels.wrapping_add(1);
+        }
+        Ok(())
+    }
+}
+
+fn run(display: &mut CountingDisplay) -> ChartResult<()> {
+    let viewport = Rectangle::new(Point::zero(), display.size());
+
+    let mut samples = StaticDataSeries::<Point2D, 256>::new();
+    for i in 0..24 {
+        let y = (i * 7) % 19;
+        samples.push(Point2D::new(i as f32, y as f32))?;
+    }
+
+    let chart = LineChart::builder()
+        .line_color(Rgb565::GREEN)
+        .fill_area(Rgb565::CSS_DARK_GREEN)
+        .with_markers(MarkerStyle {
+            shape: MarkerShape::Circle,
+            size: 4,
+            color: Rgb565::WHITE,
+            visible: true,
+        })
+        .build()?;
+    chart.draw(&samples, chart.config(), viewport, display)
+}
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    let mut display = CountingDisplay { pixels: 0 };
+    run(&mut display).expect("drawing failed");
+    assert!(display.pixels > 0, "nothing was drawn");
+    println!("no_panic example drew {} pixels", display.pixels);
+}
+
+#[cfg(target_os = "none")]
+mod bare_metal {
+    use core::alloc::{GlobalAlloc, Layout};
+
+    // Provides the panic handler; any remaining panic path fails to link
+    use panic_never as _;
+
+    /// The line chart path is heapless, but the crate links `alloc` under
+    /// `no_std`, so an allocator must exist. It never succeeds.
+    struct NoAlloc;
+
+    unsafe impl GlobalAlloc for NoAlloc {
+        unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+            core::ptr::null_mut()
+        }
+
+        unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: NoAlloc = NoAlloc;
+
+    #[no_mangle]
+    pub extern "C" fn _start() -> ! {
+        let mut display = super::CountingDisplay { pixels: 0 };
+        let _ = super::run(&mut display);
+        loop {
+            core::hint::spin_loop();
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! no_std smoke test
+//!
+//! Draws a line chart and a gauge into a two-cell dashboard on a display that
+//! only counts pixels. On the host it runs as a normal example; CI also builds
+//! it for `thumbv6m-none-eabi` so that any std-only API pulled into the line,
+//! gauge or dashboard paths fails the build:
+//!
+//! ```text
+//! cargo build --example no_std_smoke --target thumbv6m-none-eabi \
+//!     --no-default-features --features "no_std,line,gauge"
+//! ```
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+
+use embedded_charts::dashboard::{GridPosition, SimpleDashboard};
+use embedded_charts::prelude::*;
+
+/// Display that discards pixels and counts how many were drawn
+struct CountingDisplay {
+    pixels: u32,
+}
+
+impl OriginDimensions for CountingDisplay {
+    fn size(&self) -> Size {
+        Size::new(128, 64)
+    }
+}
+
+impl DrawTarget for CountingDisplay {
+    type Color = Rgb565;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for _ in pixels {
+            self.pixels = self.pixels.wrapping_add(1);
+        }
+        Ok(())
+    }
+}
+
+fn run(display: &mut CountingDisplay) -> ChartResult<()> {
+    let screen = Rectangle::new(Point::zero(), display.size());
+    let dashboard = SimpleDashboard::new(1, 2, 4);
+
+    let mut samples = StaticDataSeries::<Point2D, 256>::new();
+    for i in 0..16 {
+        let y = if i % 2 == 0 { i * 2 } else { i * 3 };
+        samples.push(Point2D::new(i as f32, y as f32))?;
+    }
+
+    let line = LineChart::builder()
+        .line_color(Rgb565::GREEN)
+        .line_width(1)
+        .build()?;
+    line.draw(
+        &samples,
+        line.config(),
+        dashboard.get_viewport(GridPosition::new(0, 0), screen),
+        display,
+    )?;
+
+    let mut reading = StaticDataSeries::<Point2D, 1>::new();
+    reading.push(Point2D::new(0.0, 42.0))?;
+
+    let gauge = GaugeChart::builder().value_range(0.0, 100.0).build()?;
+    gauge.draw(
+        &reading,
+        gauge.config(),
+        dashboard.get_viewport(GridPosition::new(0, 1), screen),
+        display,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    let mut display = CountingDisplay { pixels: 0 };
+    run(&mut display).expect("smoke test failed");
+    assert!(display.pixels > 0, "nothing was drawn");
+    println!("no_std smoke test drew {} pixels", display.pixels);
+}
+
+#[cfg(target_os = "none")]
+mod bare_metal {
+    use core::alloc::{GlobalAlloc, Layout};
+
+    /// The chart paths exercised here are heapless, but the crate links
+    /// `alloc` under `no_std`, so an allocator must exist. It never succeeds.
+    struct NoAlloc;
+
+    unsafe impl GlobalAlloc for NoAlloc {
+        unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+            core::ptr::null_mut()
+        }
+
+        unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: NoAlloc = NoAlloc;
+
+    #[panic_handler]
+    fn panic(_info: &core::panic::PanicInfo) -> ! {
+        loop {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn _start() -> ! {
+        let mut display = super::CountingDisplay { pixels: 0 };
+        let _ = super::run(&mut display);
+        loop {
+            core::hint::spin_loop();
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! This example demonstrates platform-specific optimizations by comparing
 //! standard math functions with optimized versions side by side.
 //!
-//! Run with: cargo run --example platform_optimized_chart --features "std,line"
+//! Run with: cargo run --example platform_optimized_chart --features "std,line,dev-tools"
 
 use embedded_charts::prelude::*;
 
@@ -16,7 +16,9 @@ use common::{window, WindowConfig};
 #[cfg(not(all(feature = "std", feature = "line")))]
 fn main() {
     eprintln!("This example requires 'std' and 'line' features.");
-    eprintln!("Run with: cargo run --example platform_optimized_chart --features std,line");
+    eprintln!(
+        "Run with: cargo run --example platform_optimized_chart --features std,line,dev-tools"
+    );
 }
 
 #[cfg(all(feature = "std", feature = "line"))]
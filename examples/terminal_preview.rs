@@ -0,0 +1,33 @@
+//! Terminal preview example without SDL2 dependency
+//! This example draws a line chart to stdout using braille characters.
+
+use embedded_charts::prelude::*;
+use embedded_graphics::pixelcolor::Rgb565;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // 60 columns by 16 rows of braille characters (120x64 pixels)
+    let mut display = TerminalDisplay::new(60, 16, TerminalMode::Braille);
+    display.clear(Rgb565::WHITE)?;
+
+    // Create sample data
+    let mut data = StaticDataSeries::<Point2D, 256>::new();
+    for i in 0..40 {
+        let x = i as f32;
+        let y = (x * 0.3).sin() * 20.0 + 50.0;
+        data.push(Point2D::new(x, y))?;
+    }
+
+    // Create line chart
+    let chart = LineChart::builder()
+        .line_color(Rgb565::BLUE)
+        .background_color(Rgb565::WHITE)
+        .build()?;
+
+    // Draw chart over the whole terminal area
+    let viewport = display.bounding_box();
+    chart.draw(&data, chart.config(), viewport, &mut display)?;
+
+    display.print();
+
+    Ok(())
+}
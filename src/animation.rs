@@ -27,9 +27,50 @@ pub enum EasingFunction {
     EaseOut,
     /// Ease in-out (slow start and end).
     EaseInOut,
+    /// User-defined cubic-bezier curve from (0, 0) to (1, 1).
+    ///
+    /// The control points follow the CSS `cubic-bezier(x1, y1, x2, y2)` convention.
+    /// X coordinates must lie in 0.0..=1.0; Y coordinates may overshoot.
+    CubicBezier {
+        /// X coordinate of the first control point.
+        x1: f32,
+        /// Y coordinate of the first control point.
+        y1: f32,
+        /// X coordinate of the second control point.
+        x2: f32,
+        /// Y coordinate of the second control point.
+        y2: f32,
+    },
+    /// Critically damped spring settling on the target without overshoot.
+    Spring {
+        /// Natural frequency relative to the animation duration.
+        ///
+        /// Larger values settle faster; 8.0 to 12.0 gives a natural motion.
+        stiffness: f32,
+    },
 }
 
 impl EasingFunction {
+    /// Number of bisection steps used to invert the cubic-bezier X curve.
+    const BEZIER_ITERATIONS: usize = 16;
+
+    /// Create a cubic-bezier easing, clamping the X control points to 0.0..=1.0.
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        EasingFunction::CubicBezier {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Create a critically damped spring easing.
+    pub fn spring(stiffness: f32) -> Self {
+        EasingFunction::Spring {
+            stiffness: stiffness.max(0.1),
+        }
+    }
+
     /// Apply the easing function to a linear progress value (0.0 to 1.0).
     pub fn apply(self, t: f32) -> f32 {
         match self {
@@ -43,8 +84,54 @@ impl EasingFunction {
                     1.0 - 2.0 * (1.0 - t) * (1.0 - t)
                 }
             }
+            EasingFunction::CubicBezier { x1, y1, x2, y2 } => {
+                Self::cubic_bezier_at(t.clamp(0.0, 1.0), x1, y1, x2, y2)
+            }
+            EasingFunction::Spring { stiffness } => Self::spring_at(t.clamp(0.0, 1.0), stiffness),
         }
     }
+
+    /// Evaluate one coordinate of a cubic-bezier curve with fixed end points.
+    fn bezier_component(s: f32, p1: f32, p2: f32) -> f32 {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+    }
+
+    /// Solve the curve parameter for the given X and return the matching Y.
+    fn cubic_bezier_at(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+        // X is monotonic for control points in 0.0..=1.0, so bisection converges
+        let mut low = 0.0f32;
+        let mut high = 1.0f32;
+        let mut s = t;
+
+        for _ in 0..Self::BEZIER_ITERATIONS {
+            let x = Self::bezier_component(s, x1, x2);
+            if x < t {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) * 0.5;
+        }
+
+        Self::bezier_component(s, y1, y2)
+    }
+
+    /// Critically damped step response, normalized to reach exactly 1.0 at t = 1.0.
+    fn spring_at(t: f32, stiffness: f32) -> f32 {
+        let response = |time: f32| {
+            let omega_t = stiffness * time;
+            // e^-x as 10^(-x log10 e), which is exact enough on every math backend
+            let decay = crate::math::pow10_f32(-omega_t * core::f32::consts::LOG10_E);
+            1.0 - (1.0 + omega_t) * decay
+        };
+
+        let end = response(1.0);
+        if end <= 0.0 {
+            return t;
+        }
+        response(t) / end
+    }
 }
 
 /// Trait for types that can be interpolated between two values.
@@ -505,7 +592,7 @@ impl TimeBasedProgress {
         }
 
         let start_time = self.start_time_ms.unwrap();
-        let elapsed = current_time.saturating_sub(start_time);
+        let elapsed = crate::time::wrapping_elapsed_ms(start_time, current_time);
 
         if self.looping {
             // For looping animations, wrap around
@@ -555,7 +642,7 @@ impl TimeBasedProgress {
 
         if let Some(start_time) = self.start_time_ms {
             let current_time = time_provider.current_time_ms();
-            let elapsed = current_time.saturating_sub(start_time);
+            let elapsed = crate::time::wrapping_elapsed_ms(start_time, current_time);
             elapsed >= self.duration_ms
         } else {
             false // Not started yet
@@ -583,6 +670,275 @@ impl TimeBasedProgress {
     }
 }
 
+/// Playback mode for [`TimedAnimator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackMode {
+    /// Play once and hold at the end.
+    Once,
+    /// Restart from the beginning after each cycle.
+    Loop,
+    /// Play forward, then backward, then forward again.
+    PingPong,
+}
+
+/// Frame-rate independent animation driver.
+///
+/// The animator accumulates elapsed time and converts it to eased progress,
+/// so an animation takes the same wall-clock time whether the application
+/// renders at 5 or 60 frames per second.
+///
+/// When the resulting progress drives a [`ChartAnimator`], which applies its
+/// own easing, create the timer with [`EasingFunction::Linear`].
+#[derive(Debug, Clone)]
+pub struct TimedAnimator {
+    /// Duration of a single cycle in milliseconds.
+    duration_ms: Milliseconds,
+    /// Time accumulated in the current playback.
+    elapsed_ms: Milliseconds,
+    /// Easing function applied to the linear progress.
+    easing: EasingFunction,
+    /// Playback mode.
+    mode: PlaybackMode,
+}
+
+impl TimedAnimator {
+    /// Create a new animator that plays once.
+    ///
+    /// # Arguments
+    /// * `duration_ms` - Animation duration in milliseconds
+    /// * `easing` - Easing function to apply
+    pub fn new(duration_ms: Milliseconds, easing: EasingFunction) -> Self {
+        Self {
+            duration_ms,
+            elapsed_ms: 0,
+            easing,
+            mode: PlaybackMode::Once,
+        }
+    }
+
+    /// Create a new animator that restarts after each cycle.
+    pub fn looping(duration_ms: Milliseconds, easing: EasingFunction) -> Self {
+        Self::new(duration_ms, easing).with_mode(PlaybackMode::Loop)
+    }
+
+    /// Create a new animator that alternates between forward and backward playback.
+    pub fn ping_pong(duration_ms: Milliseconds, easing: EasingFunction) -> Self {
+        Self::new(duration_ms, easing).with_mode(PlaybackMode::PingPong)
+    }
+
+    /// Set the playback mode.
+    pub fn with_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Advance the animation by the given amount of time.
+    ///
+    /// # Arguments
+    /// * `delta_ms` - Time elapsed since the previous update
+    pub fn update(&mut self, delta_ms: Milliseconds) {
+        let elapsed = self.elapsed_ms.saturating_add(delta_ms);
+        self.elapsed_ms = match self.mode {
+            PlaybackMode::Once => elapsed.min(self.duration_ms),
+            PlaybackMode::Loop if self.duration_ms > 0 => elapsed % self.duration_ms,
+            PlaybackMode::PingPong if self.duration_ms > 0 => {
+                elapsed % self.duration_ms.saturating_mul(2)
+            }
+            _ => 0,
+        };
+    }
+
+    /// Advance the animation by the time elapsed on a time provider.
+    ///
+    /// # Arguments
+    /// * `time_provider` - Time provider tracking the time since its last call
+    pub fn update_from<T: TimeProvider>(&mut self, time_provider: &mut T) {
+        let delta = time_provider.elapsed_ms();
+        self.update(delta);
+    }
+
+    /// Get the linear progress (0.0 to 1.0) before easing.
+    pub fn linear_progress(&self) -> f32 {
+        if self.duration_ms == 0 {
+            return 1.0;
+        }
+
+        let duration = self.duration_ms as f32;
+        match self.mode {
+            PlaybackMode::Once | PlaybackMode::Loop => {
+                (self.elapsed_ms as f32 / duration).clamp(0.0, 1.0)
+            }
+            PlaybackMode::PingPong => {
+                let phase = self.elapsed_ms as f32 / duration;
+                if phase <= 1.0 {
+                    phase
+                } else {
+                    (2.0 - phase).clamp(0.0, 1.0)
+                }
+            }
+        }
+    }
+
+    /// Get the eased progress (0.0 to 1.0).
+    pub fn eased_progress(&self) -> f32 {
+        self.easing.apply(self.linear_progress())
+    }
+
+    /// Get the eased progress as a [`Progress`] value (0-100).
+    pub fn progress(&self) -> Progress {
+        (self.eased_progress() * 100.0).clamp(0.0, 100.0) as Progress
+    }
+
+    /// Check if the animation has finished (only for [`PlaybackMode::Once`]).
+    pub fn is_complete(&self) -> bool {
+        self.mode == PlaybackMode::Once && self.elapsed_ms >= self.duration_ms
+    }
+
+    /// Restart the animation from the beginning.
+    pub fn reset(&mut self) {
+        self.elapsed_ms = 0;
+    }
+
+    /// Get the time accumulated in the current playback.
+    pub fn elapsed_ms(&self) -> Milliseconds {
+        self.elapsed_ms
+    }
+
+    /// Get the cycle duration.
+    pub fn duration_ms(&self) -> Milliseconds {
+        self.duration_ms
+    }
+
+    /// Get the easing function.
+    pub fn easing(&self) -> EasingFunction {
+        self.easing
+    }
+
+    /// Get the playback mode.
+    pub fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+}
+
+/// Smooth X-axis scrolling for sliding-window charts.
+///
+/// When data arrives at a lower rate than the display refreshes, redrawing the
+/// window on every push makes the trace jump once per sample. The interpolator
+/// instead slides the visible X range from the previous sample to the newest one
+/// over the expected sample interval, giving a continuously scrolling trace.
+///
+/// The newest point enters from the right edge as the window catches up with it,
+/// so the trace lags the data by at most one sample interval.
+#[derive(Debug, Clone)]
+pub struct ScrollInterpolator {
+    /// Width of the visible X range in data units.
+    window_span: f32,
+    /// Expected time between two pushes in milliseconds.
+    sample_interval_ms: Milliseconds,
+    /// Time elapsed since the most recent push.
+    since_push_ms: Milliseconds,
+    /// X value of the sample pushed before the newest one.
+    previous_x: Option<f32>,
+    /// X value of the newest sample.
+    latest_x: Option<f32>,
+}
+
+impl ScrollInterpolator {
+    /// Create a new scroll interpolator.
+    ///
+    /// # Arguments
+    /// * `window_span` - Width of the visible X range in data units
+    /// * `sample_interval_ms` - Expected time between two pushes
+    pub fn new(window_span: f32, sample_interval_ms: Milliseconds) -> Self {
+        Self {
+            window_span,
+            sample_interval_ms,
+            since_push_ms: 0,
+            previous_x: None,
+            latest_x: None,
+        }
+    }
+
+    /// Record that a new sample has been pushed to the window.
+    ///
+    /// # Arguments
+    /// * `x` - X value of the new sample
+    pub fn on_push(&mut self, x: f32) {
+        // Start from wherever the view currently is so a late push doesn't snap back
+        self.previous_x = self.right_edge().or(Some(x));
+        self.latest_x = Some(x);
+        self.since_push_ms = 0;
+    }
+
+    /// Advance the scroll animation by the given amount of time.
+    ///
+    /// # Arguments
+    /// * `delta_ms` - Time elapsed since the previous update
+    pub fn update(&mut self, delta_ms: Milliseconds) {
+        self.since_push_ms = self
+            .since_push_ms
+            .saturating_add(delta_ms)
+            .min(self.sample_interval_ms);
+    }
+
+    /// Advance the scroll animation by the time elapsed on a time provider.
+    ///
+    /// # Arguments
+    /// * `time_provider` - Time provider tracking the time since its last call
+    pub fn update_from<T: TimeProvider>(&mut self, time_provider: &mut T) {
+        let delta = time_provider.elapsed_ms();
+        self.update(delta);
+    }
+
+    /// Get how far the view has scrolled towards the newest sample (0.0 to 1.0).
+    pub fn scroll_fraction(&self) -> f32 {
+        if self.sample_interval_ms == 0 {
+            return 1.0;
+        }
+        (self.since_push_ms as f32 / self.sample_interval_ms as f32).clamp(0.0, 1.0)
+    }
+
+    /// Get the X value currently shown at the right edge of the chart.
+    pub fn right_edge(&self) -> Option<f32> {
+        let latest = self.latest_x?;
+        let previous = self.previous_x.unwrap_or(latest);
+        Some(previous + (latest - previous) * self.scroll_fraction())
+    }
+
+    /// Get the visible X range as `(min, max)`, or `None` before the first push.
+    pub fn x_range(&self) -> Option<(f32, f32)> {
+        self.right_edge()
+            .map(|right| (right - self.window_span, right))
+    }
+
+    /// Get the width of the visible X range.
+    pub fn window_span(&self) -> f32 {
+        self.window_span
+    }
+
+    /// Set the width of the visible X range.
+    pub fn set_window_span(&mut self, window_span: f32) {
+        self.window_span = window_span;
+    }
+
+    /// Get the expected time between two pushes.
+    pub fn sample_interval_ms(&self) -> Milliseconds {
+        self.sample_interval_ms
+    }
+
+    /// Set the expected time between two pushes.
+    pub fn set_sample_interval_ms(&mut self, sample_interval_ms: Milliseconds) {
+        self.sample_interval_ms = sample_interval_ms;
+    }
+
+    /// Forget all pushed samples.
+    pub fn reset(&mut self) {
+        self.since_push_ms = 0;
+        self.previous_x = None;
+        self.latest_x = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -596,6 +952,39 @@ mod tests {
         assert_eq!(EasingFunction::EaseInOut.apply(0.5), 0.5);
     }
 
+    #[test]
+    fn test_cubic_bezier_easing() {
+        let linear = EasingFunction::cubic_bezier(0.0, 0.0, 1.0, 1.0);
+        assert!((linear.apply(0.25) - 0.25).abs() < 0.01);
+        assert!((linear.apply(0.75) - 0.75).abs() < 0.01);
+
+        let ease = EasingFunction::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+        assert!(ease.apply(0.0).abs() < 0.01);
+        assert!((ease.apply(1.0) - 1.0).abs() < 0.01);
+        assert!(ease.apply(0.5) > 0.5); // Fast middle, slow end
+
+        let mut previous = 0.0;
+        for step in 1..=10 {
+            let value = ease.apply(step as f32 / 10.0);
+            assert!(value >= previous);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_spring_easing() {
+        let spring = EasingFunction::spring(10.0);
+        assert!(spring.apply(0.0).abs() < 0.01);
+        assert!((spring.apply(1.0) - 1.0).abs() < 0.01);
+        assert!(spring.apply(0.3) > EasingFunction::Linear.apply(0.3));
+
+        // Critically damped: approaches the target without overshooting
+        for step in 0..=10 {
+            assert!(spring.apply(step as f32 / 10.0) <= 1.01);
+        }
+        assert!(spring.apply(0.2) < spring.apply(0.4));
+    }
+
     #[test]
     fn test_interpolatable_f32() {
         let result = 10.0f32.interpolate(20.0, 0.5);
@@ -684,6 +1073,19 @@ mod tests {
         assert_eq!(progress_calc.progress_from_time(&time_provider), 100);
     }
 
+    #[test]
+    fn test_time_based_progress_after_backward_step() {
+        let mut progress_calc = TimeBasedProgress::new(1000);
+        let mut time_provider = ManualTimeProvider::new();
+        time_provider.set_time_ms(5000);
+        assert_eq!(progress_calc.progress_from_time(&time_provider), 0);
+
+        // A clock set back before the start does not finish the animation
+        time_provider.set_time_ms(4000);
+        assert_eq!(progress_calc.progress_from_time(&time_provider), 0);
+        assert!(!progress_calc.is_complete(&time_provider));
+    }
+
     #[test]
     fn test_time_based_progress_looping() {
         let mut progress_calc = TimeBasedProgress::new_looping(1000); // 1 second loop
@@ -710,4 +1112,73 @@ mod tests {
         assert_eq!(progress_calc.progress_from_elapsed(2000), 100);
         assert_eq!(progress_calc.progress_from_elapsed(3000), 100); // Clamped
     }
+
+    #[test]
+    fn test_timed_animator_is_frame_rate_independent() {
+        let mut slow = TimedAnimator::new(1000, EasingFunction::Linear);
+        let mut fast = TimedAnimator::new(1000, EasingFunction::Linear);
+
+        for _ in 0..2 {
+            slow.update(250);
+        }
+        for _ in 0..25 {
+            fast.update(20);
+        }
+
+        assert_eq!(slow.progress(), 50);
+        assert_eq!(fast.progress(), slow.progress());
+
+        slow.update(5000);
+        assert!(slow.is_complete());
+        assert_eq!(slow.progress(), 100);
+    }
+
+    #[test]
+    fn test_timed_animator_loop_and_ping_pong() {
+        let mut looping = TimedAnimator::looping(1000, EasingFunction::Linear);
+        looping.update(1250);
+        assert_eq!(looping.progress(), 25);
+        assert!(!looping.is_complete());
+
+        let mut ping_pong = TimedAnimator::ping_pong(1000, EasingFunction::Linear);
+        ping_pong.update(500);
+        assert_eq!(ping_pong.progress(), 50);
+        ping_pong.update(750);
+        assert_eq!(ping_pong.progress(), 75);
+        ping_pong.update(1000);
+        assert_eq!(ping_pong.progress(), 25);
+    }
+
+    #[test]
+    fn test_timed_animator_from_time_provider() {
+        let mut animator = TimedAnimator::new(200, EasingFunction::EaseIn);
+        let mut time_provider = ManualTimeProvider::new();
+
+        time_provider.advance_ms(100);
+        animator.update_from(&mut time_provider);
+        assert_eq!(animator.elapsed_ms(), 100);
+        assert_eq!(animator.progress(), 25);
+    }
+
+    #[test]
+    fn test_scroll_interpolator_slides_between_pushes() {
+        let mut scroll = ScrollInterpolator::new(10.0, 1000);
+        assert_eq!(scroll.x_range(), None);
+
+        scroll.on_push(0.0);
+        assert_eq!(scroll.x_range(), Some((-10.0, 0.0)));
+
+        scroll.on_push(1.0);
+        assert_eq!(scroll.right_edge(), Some(0.0));
+        scroll.update(500);
+        assert_eq!(scroll.right_edge(), Some(0.5));
+        scroll.update(2000);
+        assert_eq!(scroll.x_range(), Some((-9.0, 1.0)));
+
+        // A push arriving mid-scroll continues from the current position
+        scroll.on_push(2.0);
+        scroll.update(250);
+        scroll.on_push(3.0);
+        assert_eq!(scroll.right_edge(), Some(1.25));
+    }
 }
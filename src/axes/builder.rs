@@ -3,7 +3,7 @@
 use crate::axes::{
     linear::LinearAxis,
     style::AxisStyle,
-    ticks::{CustomTickGenerator, LinearTickGenerator},
+    ticks::{CustomTickGenerator, ExplicitTicks, LinearTickGenerator, MAX_EXPLICIT_TICKS},
     traits::{AxisValue, TickGenerator},
     AxisOrientation, AxisPosition,
 };
@@ -18,11 +18,18 @@ pub struct LinearAxisBuilder<T, C: PixelColor> {
     orientation: AxisOrientation,
     position: AxisPosition,
     tick_generator: LinearTickGenerator,
+    /// User-supplied ticks replacing the generated ones
+    explicit_ticks: Option<ExplicitTicks>,
+    /// Error from an invalid explicit tick list, reported by `build`
+    tick_error: Option<ChartError>,
     style: AxisStyle<C>,
     show_line: bool,
     show_ticks: bool,
     show_labels: bool,
     show_grid: bool,
+    show_zero_line: bool,
+    mirror_ticks: bool,
+    show_frame: bool,
 }
 
 impl<T, C> LinearAxisBuilder<T, C>
@@ -38,11 +45,16 @@ where
             orientation,
             position,
             tick_generator: LinearTickGenerator::new(5),
+            explicit_ticks: None,
+            tick_error: None,
             style: AxisStyle::new(),
             show_line: true,
             show_ticks: true,
             show_labels: true,
             show_grid: false,
+            show_zero_line: false,
+            mirror_ticks: false,
+            show_frame: false,
         }
     }
 
@@ -86,6 +98,35 @@ where
         self
     }
 
+    /// Place major ticks exactly at `positions`, labelled with their values
+    ///
+    /// `build` fails if the positions are not strictly increasing or fall
+    /// outside the axis range.
+    pub fn ticks_at(self, positions: &[T]) -> Self {
+        self.labeled_ticks_at(positions, &[])
+    }
+
+    /// Place major ticks exactly at `positions` with the given labels
+    ///
+    /// `labels[i]` is drawn at `positions[i]`; positions without a label show
+    /// their value. At most [`MAX_EXPLICIT_TICKS`] positions are supported.
+    pub fn labeled_ticks_at(mut self, positions: &[T], labels: &[&str]) -> Self {
+        if positions.len() > MAX_EXPLICIT_TICKS {
+            self.tick_error = Some(ChartError::ConfigurationError);
+            return self;
+        }
+        let values: heapless::Vec<f32, MAX_EXPLICIT_TICKS> =
+            positions.iter().map(|position| position.to_f32()).collect();
+        match ExplicitTicks::new(&values, labels) {
+            Ok(ticks) => {
+                self.explicit_ticks = Some(ticks);
+                self.tick_error = None;
+            }
+            Err(error) => self.tick_error = Some(error),
+        }
+        self
+    }
+
     /// Set the axis style
     pub fn style(mut self, style: AxisStyle<C>) -> Self {
         self.style = style;
@@ -128,6 +169,24 @@ where
         self
     }
 
+    /// Enable or disable the zero line
+    pub fn show_zero_line(mut self, show: bool) -> Self {
+        self.show_zero_line = show;
+        self
+    }
+
+    /// Repeat the tick marks, unlabeled, on the opposite side of the plot
+    pub fn mirror_ticks(mut self, mirror: bool) -> Self {
+        self.mirror_ticks = mirror;
+        self
+    }
+
+    /// Draw the axis line on the opposite side too, closing the plot frame
+    pub fn show_frame(mut self, show: bool) -> Self {
+        self.show_frame = show;
+        self
+    }
+
     /// Build the linear axis
     pub fn build(self) -> Result<LinearAxis<T, C>, ChartError> {
         let min = self.min.ok_or(ChartError::ConfigurationError)?;
@@ -137,13 +196,31 @@ where
             return Err(ChartError::ConfigurationError);
         }
 
-        let axis = LinearAxis::new(min, max, self.orientation, self.position)
+        if let Some(error) = self.tick_error {
+            return Err(error);
+        }
+        if self
+            .explicit_ticks
+            .iter()
+            .flat_map(|explicit| explicit.ticks())
+            .any(|tick| tick.value < min.to_f32() || tick.value > max.to_f32())
+        {
+            return Err(ChartError::InvalidRange);
+        }
+
+        let mut axis = LinearAxis::new(min, max, self.orientation, self.position)
             .with_tick_generator(self.tick_generator)
             .with_style(self.style)
             .show_line(self.show_line)
             .show_ticks(self.show_ticks)
             .show_labels(self.show_labels)
-            .show_grid(self.show_grid);
+            .show_grid(self.show_grid)
+            .show_zero_line(self.show_zero_line)
+            .mirror_ticks(self.mirror_ticks)
+            .show_frame(self.show_frame);
+        if let Some(explicit_ticks) = self.explicit_ticks {
+            axis = axis.with_explicit_ticks(explicit_ticks);
+        }
 
         Ok(axis)
     }
@@ -162,6 +239,7 @@ pub struct CustomAxisBuilder<T, C: PixelColor> {
     show_ticks: bool,
     show_labels: bool,
     show_grid: bool,
+    show_zero_line: bool,
 }
 
 impl<T, C> CustomAxisBuilder<T, C>
@@ -182,6 +260,7 @@ where
             show_ticks: true,
             show_labels: true,
             show_grid: false,
+            show_zero_line: false,
         }
     }
 
@@ -234,6 +313,12 @@ where
         self
     }
 
+    /// Enable or disable the zero line
+    pub fn show_zero_line(mut self, show: bool) -> Self {
+        self.show_zero_line = show;
+        self
+    }
+
     /// Build the custom axis (returns a LinearAxis with custom tick generator)
     pub fn build(self) -> Result<LinearAxis<T, C>, ChartError> {
         let min = self.min.ok_or(ChartError::ConfigurationError)?;
@@ -249,7 +334,8 @@ where
             .show_line(self.show_line)
             .show_ticks(self.show_ticks)
             .show_labels(self.show_labels)
-            .show_grid(self.show_grid);
+            .show_grid(self.show_grid)
+            .show_zero_line(self.show_zero_line);
 
         // Note: In a full implementation, we'd need to modify LinearAxis to accept
         // different tick generator types. For now, this is a simplified version.
@@ -360,6 +446,33 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_explicit_tick_positions() {
+        let axis = presets::x_axis_bottom(0.0f32, 48.0f32)
+            .labeled_ticks_at(
+                &[0.0, 12.0, 24.0, 36.0, 48.0],
+                &["0h", "12h", "24h", "36h", "48h"],
+            )
+            .build()
+            .unwrap();
+        let ticks = axis.explicit_ticks().unwrap().generate(0.0, 48.0, 32);
+        assert_eq!(ticks.len(), 5);
+        assert_eq!(ticks[4].value, 48.0);
+        assert_eq!(ticks[4].label.as_deref(), Some("48h"));
+
+        // Positions must lie inside the axis range
+        let result = presets::x_axis_bottom(0.0f32, 40.0f32)
+            .ticks_at(&[0.0, 12.0, 48.0])
+            .build();
+        assert_eq!(result.unwrap_err(), ChartError::InvalidRange);
+
+        // ...and be strictly increasing
+        let result = presets::x_axis_bottom(0.0f32, 48.0f32)
+            .ticks_at(&[12.0, 12.0])
+            .build();
+        assert_eq!(result.unwrap_err(), ChartError::InvalidRange);
+    }
+
     #[test]
     fn test_custom_axis_builder() {
         let axis =
@@ -0,0 +1,278 @@
+//! Categorical axis for labelled slots such as weekdays or sensor names.
+//!
+//! [`CategoryAxis`] puts one tick in the middle of each [`CategoryScale`]
+//! slot and labels it with the category name. A category-aligned bar chart
+//! lays its bars out with the same scale, so labels sit under (or beside)
+//! their bars:
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let axis: CategoryAxis<Rgb565> =
+//!     CategoryAxis::new(&["Mon", "Tue", "Wed"], AxisPosition::Bottom)?;
+//! assert_eq!(axis.count(), 3);
+//! assert_eq!(axis.label(1), Some("Tue"));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::axes::{
+    linear::LinearAxis,
+    style::AxisStyle,
+    ticks::{CategoryScale, ExplicitTicks, LinearTickGenerator, MAX_EXPLICIT_TICKS},
+    traits::Axis,
+    AxisOrientation, AxisPosition,
+};
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use heapless::Vec;
+
+/// Maximum number of categories on a [`CategoryAxis`]
+pub const MAX_CATEGORIES: usize = MAX_EXPLICIT_TICKS;
+
+/// Axis with one labelled tick per category
+///
+/// Draws like a [`LinearAxis`] over [`CategoryScale::range`] whose ticks are
+/// the category labels, and converts into one for use with chart types.
+/// Labels longer than 16 bytes are drawn without text.
+#[derive(Debug, Clone)]
+pub struct CategoryAxis<C: PixelColor> {
+    /// Axis drawn with the categories as explicit ticks
+    axis: LinearAxis<f32, C>,
+    scale: CategoryScale,
+}
+
+impl<C> CategoryAxis<C>
+where
+    C: PixelColor + From<Rgb565>,
+{
+    /// Create an axis with one category per label, in order
+    ///
+    /// The first category sits at the left of a horizontal axis and at the
+    /// bottom of a vertical one. The orientation follows `position`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ChartError::InsufficientData`] if `labels` is empty
+    /// - [`ChartError::ConfigurationError`] if there are more than
+    ///   [`MAX_CATEGORIES`] labels
+    pub fn new(labels: &[&str], position: AxisPosition) -> ChartResult<Self> {
+        if labels.is_empty() {
+            return Err(ChartError::InsufficientData);
+        }
+        if labels.len() > MAX_CATEGORIES {
+            return Err(ChartError::ConfigurationError);
+        }
+
+        let positions: Vec<f32, MAX_CATEGORIES> =
+            (0..labels.len()).map(|index| index as f32).collect();
+        let scale = CategoryScale::new(labels.len());
+        let (min, max) = scale.range();
+        let axis = LinearAxis::new(min, max, AxisOrientation::Horizontal, AxisPosition::Bottom)
+            .with_position(position)
+            .with_explicit_ticks(ExplicitTicks::new(&positions, labels)?);
+
+        Ok(Self { axis, scale })
+    }
+
+    /// Get the label of the category at `index`
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.axis
+            .explicit_ticks()?
+            .ticks()
+            .get(index)?
+            .label
+            .as_deref()
+    }
+
+    /// Move the axis to another side of the plot, keeping its labels and style
+    pub fn with_position(mut self, position: AxisPosition) -> Self {
+        self.axis = self.axis.with_position(position);
+        self
+    }
+
+    /// Set the axis style
+    pub fn with_style(mut self, style: AxisStyle<C>) -> Self {
+        self.axis = self.axis.with_style(style);
+        self
+    }
+
+    /// Enable or disable the axis line
+    pub fn show_line(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_line(show);
+        self
+    }
+
+    /// Enable or disable tick marks
+    pub fn show_ticks(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_ticks(show);
+        self
+    }
+
+    /// Enable or disable labels
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_labels(show);
+        self
+    }
+
+    /// Enable or disable grid lines
+    pub fn show_grid(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_grid(show);
+        self
+    }
+
+    /// Draw only grid lines
+    pub fn draw_grid_lines<D>(
+        &self,
+        viewport: Rectangle,
+        chart_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_grid_lines(viewport, chart_area, target)
+    }
+
+    /// Draw only axis line, ticks, and labels (without grid lines)
+    pub fn draw_axis_only<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_axis_only(viewport, target)
+    }
+}
+
+impl<C: PixelColor> CategoryAxis<C> {
+    /// Get the number of categories
+    pub fn count(&self) -> usize {
+        self.scale.count()
+    }
+
+    /// Get the scale mapping category indices to slots
+    pub fn scale(&self) -> CategoryScale {
+        self.scale
+    }
+
+    /// Get the linear axis the category axis draws with
+    pub fn linear(&self) -> &LinearAxis<f32, C> {
+        &self.axis
+    }
+
+    /// Convert into the linear axis the category axis draws with
+    pub fn into_linear(self) -> LinearAxis<f32, C> {
+        self.axis
+    }
+}
+
+impl<C: PixelColor> From<CategoryAxis<C>> for LinearAxis<f32, C> {
+    fn from(axis: CategoryAxis<C>) -> Self {
+        axis.axis
+    }
+}
+
+impl<C> Axis<f32, C> for CategoryAxis<C>
+where
+    C: PixelColor + From<Rgb565>,
+{
+    type TickGenerator = LinearTickGenerator;
+    type Style = AxisStyle<C>;
+
+    fn min(&self) -> f32 {
+        self.axis.min()
+    }
+
+    fn max(&self) -> f32 {
+        self.axis.max()
+    }
+
+    fn orientation(&self) -> AxisOrientation {
+        self.axis.orientation()
+    }
+
+    fn position(&self) -> AxisPosition {
+        self.axis.position()
+    }
+
+    fn transform_value(&self, value: f32, viewport: Rectangle) -> i32 {
+        self.axis.transform_value(value, viewport)
+    }
+
+    fn inverse_transform(&self, coordinate: i32, viewport: Rectangle) -> f32 {
+        self.axis.inverse_transform(coordinate, viewport)
+    }
+
+    fn tick_generator(&self) -> &Self::TickGenerator {
+        self.axis.tick_generator()
+    }
+
+    fn style(&self) -> &Self::Style {
+        self.axis.style()
+    }
+
+    fn draw<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw(viewport, target)
+    }
+
+    fn required_space(&self) -> u32 {
+        self.axis.required_space()
+    }
+
+    fn draw_labels<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_labels(viewport, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_and_range() {
+        let axis: CategoryAxis<Rgb565> =
+            CategoryAxis::new(&["Mon", "Tue", "Wed"], AxisPosition::Bottom).unwrap();
+        assert_eq!(axis.count(), 3);
+        assert_eq!(axis.label(0), Some("Mon"));
+        assert_eq!(axis.label(2), Some("Wed"));
+        assert_eq!(axis.label(3), None);
+        assert_eq!((axis.min(), axis.max()), (-0.5, 2.5));
+        assert_eq!(axis.orientation(), AxisOrientation::Horizontal);
+
+        let axis = axis.with_position(AxisPosition::Left);
+        assert_eq!(axis.orientation(), AxisOrientation::Vertical);
+        assert_eq!(axis.label(1), Some("Tue"));
+    }
+
+    #[test]
+    fn test_ticks_at_slot_centers() {
+        let axis: CategoryAxis<Rgb565> =
+            CategoryAxis::new(&["A", "B", "C", "D"], AxisPosition::Bottom).unwrap();
+        let area = Rectangle::new(Point::new(10, 0), Size::new(81, 40));
+        for index in 0..axis.count() {
+            assert_eq!(
+                axis.transform_value(index as f32, area),
+                axis.scale()
+                    .center(index, area, AxisOrientation::Horizontal)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_labels() {
+        assert_eq!(
+            CategoryAxis::<Rgb565>::new(&[], AxisPosition::Bottom).err(),
+            Some(ChartError::InsufficientData)
+        );
+        let labels = ["x"; MAX_CATEGORIES + 1];
+        assert_eq!(
+            CategoryAxis::<Rgb565>::new(&labels, AxisPosition::Bottom).err(),
+            Some(ChartError::ConfigurationError)
+        );
+    }
+}
@@ -1,13 +1,14 @@
 //! Linear axis implementation.
 
 use crate::axes::{
-    style::AxisStyle,
-    ticks::LinearTickGenerator,
-    traits::{Axis, AxisRenderer, AxisValue, TickGenerator},
+    style::{AxisStyle, LabelStyle},
+    ticks::{ExplicitTicks, LinearTickGenerator},
+    traits::{Axis, AxisRenderer, AxisValue, Tick, TickGenerator},
     AxisConfig, AxisOrientation, AxisPosition,
 };
 use crate::error::ChartResult;
-use crate::style::LineStyle;
+use crate::math::{power_of_ten, split_power_of_ten, LogRangeTransform};
+use crate::style::{FixedDecimals, LabelFormat, LineStyle, NumberLocale, ValueFormatter};
 use embedded_graphics::{
     draw_target::DrawTarget,
     prelude::*,
@@ -21,10 +22,18 @@ pub struct LinearAxis<T, C: PixelColor> {
     config: AxisConfig<T>,
     /// Tick generator
     tick_generator: LinearTickGenerator,
+    /// User-supplied ticks drawn instead of the generated ones
+    explicit_ticks: Option<ExplicitTicks>,
     /// Axis styling
     style: AxisStyle<C>,
     /// Axis renderer
     renderer: DefaultAxisRenderer<C>,
+    /// Decimal separator and digit grouping for tick labels
+    locale: NumberLocale,
+    /// Formatter replacing the generated tick label text
+    formatter: Option<LabelFormat>,
+    /// Map values on a base-10 logarithmic scale
+    logarithmic: bool,
 }
 
 /// Default axis renderer implementation
@@ -58,23 +67,148 @@ where
         Self {
             config: AxisConfig::new(min, max, orientation, position),
             tick_generator: LinearTickGenerator::new(5),
+            explicit_ticks: None,
             style: AxisStyle::new(),
             renderer: DefaultAxisRenderer::new(),
+            locale: NumberLocale::PLAIN,
+            formatter: None,
+            logarithmic: false,
         }
     }
 
+    /// Map values on a base-10 logarithmic scale
+    ///
+    /// Ticks are placed on whole decades and labelled `10^n`. The range must be
+    /// positive; values at or below zero are drawn below the axis minimum.
+    pub fn with_log_scale(mut self) -> Self {
+        self.logarithmic = true;
+        self
+    }
+
+    /// Ticks for the current range, on whole decades for logarithmic axes
+    fn ticks(&self, max_ticks: usize) -> heapless::Vec<Tick<T>, 32> {
+        if let Some(ref explicit) = self.explicit_ticks {
+            return explicit.generate(self.config.min, self.config.max, max_ticks);
+        }
+        if !self.logarithmic {
+            return self
+                .tick_generator
+                .generate_ticks(self.config.min, self.config.max, max_ticks);
+        }
+
+        let mut ticks = heapless::Vec::new();
+        let min = self.config.min.to_f32();
+        let max = self.config.max.to_f32();
+        if !(min > 0.0 && max > min) {
+            return ticks;
+        }
+
+        let (mantissa, mut first) = split_power_of_ten(min);
+        if mantissa > 1.0 {
+            first += 1;
+        }
+        let (_, last) = split_power_of_ten(max);
+        // Skip decades so the count stays near the preferred tick count
+        if last < first {
+            return ticks;
+        }
+        let decades = (last - first + 1) as usize;
+        let preferred =
+            <LinearTickGenerator as TickGenerator<T>>::preferred_tick_count(&self.tick_generator)
+                .max(2);
+        let step = decades.div_ceil(preferred).max(1) as i32;
+
+        let mut exponent = first;
+        while exponent <= last && ticks.len() < max_ticks.min(32) {
+            let mut label = heapless::String::<16>::new();
+            let _ = core::fmt::write(&mut label, format_args!("10^{exponent}"));
+            let _ = ticks.push(Tick::major(T::from_f32(power_of_ten(exponent)), &label));
+            exponent += step;
+        }
+        ticks
+    }
+
     /// Set the tick generator
+    ///
+    /// Replaces any explicit ticks set with [`with_explicit_ticks`](Self::with_explicit_ticks).
     pub fn with_tick_generator(mut self, generator: LinearTickGenerator) -> Self {
         self.tick_generator = generator;
+        self.explicit_ticks = None;
+        self
+    }
+
+    /// Draw major ticks exactly at user-supplied positions
+    ///
+    /// The ticks replace the generated ones, including on logarithmic axes.
+    pub fn with_explicit_ticks(mut self, ticks: ExplicitTicks) -> Self {
+        self.explicit_ticks = Some(ticks);
         self
     }
 
+    /// Get the user-supplied ticks, if any
+    pub fn explicit_ticks(&self) -> Option<&ExplicitTicks> {
+        self.explicit_ticks.as_ref()
+    }
+
     /// Set the axis style
     pub fn with_style(mut self, style: AxisStyle<C>) -> Self {
         self.style = style;
         self
     }
 
+    /// Set the number locale used for tick labels
+    pub fn with_locale(mut self, locale: NumberLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Get the number locale used for tick labels
+    pub fn locale(&self) -> NumberLocale {
+        self.locale
+    }
+
+    /// Format tick labels with units, SI prefixes, percentages or durations.
+    ///
+    /// Labels of [explicit ticks](Self::with_explicit_ticks) are kept as they are.
+    pub fn with_formatter(mut self, formatter: impl Into<LabelFormat>) -> Self {
+        self.formatter = Some(formatter.into());
+        self
+    }
+
+    /// Get the formatter used for tick labels, if any
+    pub fn formatter(&self) -> Option<LabelFormat> {
+        self.formatter
+    }
+
+    /// Draw tick labels with a fixed number of decimal places
+    ///
+    /// Shorthand for [`with_formatter`](Self::with_formatter) with
+    /// [`FixedDecimals`].
+    pub fn with_precision(self, decimals: u8) -> Self {
+        self.with_formatter(FixedDecimals::new(decimals))
+    }
+
+    /// Rotate the tick labels of a horizontal axis
+    ///
+    /// Rounded to a quarter turn like [`LabelStyle::with_rotation`]. At 90
+    /// degrees labels read top to bottom and at 270 degrees bottom to top,
+    /// each starting next to the axis. Other angles draw labels upright, as
+    /// do vertical axes.
+    pub fn with_label_rotation(mut self, degrees: u16) -> Self {
+        self.style.labels = self.style.labels.clone().with_rotation(degrees);
+        self
+    }
+
+    /// Text drawn for a major tick, applying the formatter and locale
+    fn tick_label_text(&self, value: T, label: &str) -> heapless::String<24> {
+        match self.formatter {
+            Some(formatter) if self.explicit_ticks.is_none() => {
+                self.locale.localize(&formatter.format(value.to_f32()))
+            }
+            _ => self.locale.localize(label),
+        }
+    }
+
     /// Set the range of the axis
     pub fn with_range(mut self, min: T, max: T) -> Self {
         self.config.min = min;
@@ -82,6 +216,19 @@ where
         self
     }
 
+    /// Move the axis to another side of the plot
+    ///
+    /// The orientation follows the position: top and bottom axes run
+    /// horizontally, left and right axes vertically.
+    pub fn with_position(mut self, position: AxisPosition) -> Self {
+        self.config.orientation = match position {
+            AxisPosition::Top | AxisPosition::Bottom => AxisOrientation::Horizontal,
+            AxisPosition::Left | AxisPosition::Right => AxisOrientation::Vertical,
+        };
+        self.config.position = position;
+        self
+    }
+
     /// Enable or disable the axis line
     pub fn show_line(mut self, show: bool) -> Self {
         self.config.show_line = show;
@@ -106,9 +253,97 @@ where
         self
     }
 
+    /// Enable or disable the zero line
+    ///
+    /// The zero line is drawn independently of grid lines whenever zero lies
+    /// within the axis range.
+    pub fn show_zero_line(mut self, show: bool) -> Self {
+        self.config.show_zero_line = show;
+        self
+    }
+
+    /// Repeat the tick marks on the opposite side of the plot
+    ///
+    /// Mirrored ticks are unlabeled and point into the plot area, so they need
+    /// no extra margin.
+    pub fn mirror_ticks(mut self, mirror: bool) -> Self {
+        self.config.mirror_ticks = mirror;
+        self
+    }
+
+    /// Draw the axis line on the opposite side of the plot as well
+    ///
+    /// Enabled on both the X and Y axis, this closes the frame around the plot.
+    pub fn show_frame(mut self, show: bool) -> Self {
+        self.config.show_frame = show;
+        self
+    }
+
+    /// Side of the plot opposite the axis
+    fn opposite_position(&self) -> AxisPosition {
+        match (self.config.orientation, self.config.position) {
+            (AxisOrientation::Horizontal, AxisPosition::Top) => AxisPosition::Bottom,
+            (AxisOrientation::Horizontal, _) => AxisPosition::Top,
+            (AxisOrientation::Vertical, AxisPosition::Right) => AxisPosition::Left,
+            (AxisOrientation::Vertical, _) => AxisPosition::Right,
+        }
+    }
+
+    /// Draw the frame line and mirrored tick marks opposite the axis
+    fn draw_mirror<D>(
+        &self,
+        plot_area: Rectangle,
+        ticks: &[Tick<T>],
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let position = self.opposite_position();
+        let viewport = self.axis_viewport_at(plot_area, position);
+
+        if self.config.show_frame {
+            let (start, end) = self.axis_line_at(viewport, position);
+            self.renderer
+                .draw_axis_line(start, end, &self.style.axis_line, target)?;
+        }
+
+        if !(self.config.mirror_ticks && self.config.show_ticks) {
+            return Ok(());
+        }
+        for tick in ticks {
+            let tick_style = if tick.is_major {
+                &self.style.major_ticks
+            } else {
+                &self.style.minor_ticks
+            };
+            if !tick_style.visible {
+                continue;
+            }
+
+            let start = self.tick_position_at(tick.value, viewport, position);
+            let length = tick_style.length as i32;
+            // Point into the plot area
+            let end = match position {
+                AxisPosition::Top => Point::new(start.x, start.y + length),
+                AxisPosition::Bottom => Point::new(start.x, start.y - length),
+                AxisPosition::Left => Point::new(start.x + length, start.y),
+                AxisPosition::Right => Point::new(start.x - length, start.y),
+            };
+            self.renderer
+                .draw_axis_line(start, end, &tick_style.line, target)?;
+        }
+        Ok(())
+    }
+
     /// Calculate the axis line endpoints for the given viewport
     fn calculate_axis_line(&self, viewport: Rectangle) -> (Point, Point) {
-        match (self.config.orientation, self.config.position) {
+        self.axis_line_at(viewport, self.config.position)
+    }
+
+    /// Axis line endpoints for an axis drawn at `position`
+    fn axis_line_at(&self, viewport: Rectangle, position: AxisPosition) -> (Point, Point) {
+        match (self.config.orientation, position) {
             (AxisOrientation::Horizontal, AxisPosition::Bottom) => {
                 let y = viewport.top_left.y + viewport.size.height as i32 - 1;
                 (
@@ -161,9 +396,14 @@ where
 
     /// Calculate the position for a tick mark
     fn calculate_tick_position(&self, value: T, viewport: Rectangle) -> Point {
+        self.tick_position_at(value, viewport, self.config.position)
+    }
+
+    /// Tick mark position for an axis drawn at `position`
+    fn tick_position_at(&self, value: T, viewport: Rectangle, position: AxisPosition) -> Point {
         let screen_coord = self.transform_value(value, viewport);
 
-        match (self.config.orientation, self.config.position) {
+        match (self.config.orientation, position) {
             (AxisOrientation::Horizontal, AxisPosition::Bottom) => Point::new(
                 screen_coord,
                 viewport.top_left.y + viewport.size.height as i32 - 1,
@@ -243,9 +483,7 @@ where
         }
 
         let grid_style = self.style.grid_lines.as_ref().unwrap();
-        let ticks = self
-            .tick_generator
-            .generate_ticks(self.config.min, self.config.max, 20);
+        let ticks = self.ticks(20);
 
         for tick in &ticks {
             if tick.is_major {
@@ -258,11 +496,82 @@ where
         Ok(())
     }
 
+    /// Draw the zero line if enabled and zero lies within the axis range
+    pub fn draw_zero_line<D>(
+        &self,
+        viewport: Rectangle,
+        chart_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let zero = T::from_f32(0.0);
+        if !self.config.show_zero_line || zero < self.config.min || zero > self.config.max {
+            return Ok(());
+        }
+
+        let (start, end) = self.calculate_grid_line(zero, viewport, chart_area);
+        self.renderer
+            .draw_grid_line(start, end, &self.style.zero_line, target)
+    }
+
+    /// Width in pixels of the widest tick label in the 6x10 label font
+    fn longest_label_width(&self) -> u32 {
+        self.ticks(50)
+            .iter()
+            .filter_map(|tick| match (tick.is_major, tick.label.as_ref()) {
+                (true, Some(label)) => {
+                    Some(self.tick_label_text(tick.value, label).chars().count() as u32 * 6)
+                }
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Draw the labels of the major ticks in `ticks`
+    fn draw_tick_labels<D>(
+        &self,
+        ticks: &[Tick<T>],
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if !self.config.show_labels || !self.style.labels.visible {
+            return Ok(());
+        }
+
+        for tick in ticks {
+            if let (true, Some(label)) = (tick.is_major, tick.label.as_ref()) {
+                let tick_pos = self.calculate_tick_position(tick.value, viewport);
+                let label_pos = self.calculate_label_position(tick_pos);
+                let label = self.tick_label_text(tick.value, label);
+                if self.label_fits(&label, label_pos, viewport) {
+                    self.renderer.draw_styled_label(
+                        &label,
+                        label_pos,
+                        &self.style.labels,
+                        self.label_side(),
+                        target,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Draw only axis line, ticks, and labels (without grid lines)
     pub fn draw_axis_only<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>,
     {
+        let plot_area = viewport;
+        let viewport = self.axis_viewport(viewport);
+
         // Draw the main axis line
         if self.config.show_line {
             let (start, end) = self.calculate_axis_line(viewport);
@@ -271,9 +580,7 @@ where
         }
 
         // Generate ticks - use larger limit to accommodate both major and minor ticks
-        let ticks = self
-            .tick_generator
-            .generate_ticks(self.config.min, self.config.max, 50);
+        let ticks = self.ticks(50);
 
         // Draw tick marks
         if self.config.show_ticks {
@@ -297,22 +604,9 @@ where
             }
         }
 
-        // Draw labels
-        if self.config.show_labels && self.style.labels.visible {
-            for tick in &ticks {
-                if tick.is_major && tick.label.is_some() {
-                    let tick_pos = self.calculate_tick_position(tick.value, viewport);
-                    let label_pos = self.calculate_label_position(tick_pos);
-                    self.renderer.draw_label(
-                        tick.label.as_ref().unwrap().as_str(),
-                        label_pos,
-                        target,
-                    )?;
-                }
-            }
-        }
+        self.draw_mirror(plot_area, &ticks, target)?;
 
-        Ok(())
+        self.draw_tick_labels(&ticks, viewport, target)
     }
 }
 
@@ -341,18 +635,14 @@ where
     }
 
     fn transform_value(&self, value: T, viewport: Rectangle) -> i32 {
-        let min_f32 = self.config.min.to_f32();
-        let max_f32 = self.config.max.to_f32();
-        let value_f32 = value.to_f32();
-
-        if max_f32 <= min_f32 {
+        if !self.logarithmic && self.config.max.to_f32() <= self.config.min.to_f32() {
             return match self.config.orientation {
                 AxisOrientation::Horizontal => viewport.top_left.x + viewport.size.width as i32 / 2,
                 AxisOrientation::Vertical => viewport.top_left.y + viewport.size.height as i32 / 2,
             };
         }
 
-        let normalized = (value_f32 - min_f32) / (max_f32 - min_f32);
+        let normalized = self.normalize(value);
 
         match self.config.orientation {
             AxisOrientation::Horizontal => {
@@ -382,6 +672,9 @@ where
             }
         };
 
+        if self.logarithmic {
+            return T::from_f32(LogRangeTransform::new(min_f32, max_f32).denormalize(normalized));
+        }
         let value_f32 = min_f32 + normalized * (max_f32 - min_f32);
         T::from_f32(value_f32)
     }
@@ -398,6 +691,9 @@ where
     where
         D: DrawTarget<Color = C>,
     {
+        let plot_area = viewport;
+        let viewport = self.axis_viewport(viewport);
+
         // Draw the main axis line
         if self.config.show_line {
             let (start, end) = self.calculate_axis_line(viewport);
@@ -406,9 +702,7 @@ where
         }
 
         // Generate ticks - use larger limit to accommodate both major and minor ticks
-        let ticks = self
-            .tick_generator
-            .generate_ticks(self.config.min, self.config.max, 50);
+        let ticks = self.ticks(50);
 
         // Draw tick marks
         if self.config.show_ticks {
@@ -434,26 +728,13 @@ where
 
         // Grid lines are now drawn separately by LineChart for proper layering
 
-        // Draw labels
-        if self.config.show_labels && self.style.labels.visible {
-            for tick in &ticks {
-                if tick.is_major && tick.label.is_some() {
-                    let tick_pos = self.calculate_tick_position(tick.value, viewport);
-                    let label_pos = self.calculate_label_position(tick_pos);
-                    self.renderer.draw_label(
-                        tick.label.as_ref().unwrap().as_str(),
-                        label_pos,
-                        target,
-                    )?;
-                }
-            }
-        }
+        self.draw_mirror(plot_area, &ticks, target)?;
 
-        Ok(())
+        self.draw_tick_labels(&ticks, viewport, target)
     }
 
     fn required_space(&self) -> u32 {
-        let mut space = 0;
+        let mut space = self.style.plot_gap;
 
         // Space for axis line
         if self.config.show_line {
@@ -475,13 +756,31 @@ where
             space += major_tick_space.max(minor_tick_space);
         }
 
-        // Space for labels
+        // Space for labels: their height below or above a horizontal axis,
+        // their width beside a vertical one
         if self.config.show_labels && self.style.labels.visible {
-            space += self.style.label_offset + self.style.labels.font_size;
+            space += self.style.label_offset;
+            space += match self.config.orientation {
+                AxisOrientation::Horizontal if self.rotated_labels() => self.longest_label_width(),
+                AxisOrientation::Horizontal => self.style.labels.font_size,
+                // Labels are centered on the offset point, so half their width reaches past it
+                AxisOrientation::Vertical => self
+                    .style
+                    .labels
+                    .font_size
+                    .max(self.longest_label_width() / 2),
+            };
         }
 
         space
     }
+
+    fn draw_labels<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_tick_labels(&self.ticks(50), self.axis_viewport(viewport), target)
+    }
 }
 
 impl<T, C> LinearAxis<T, C>
@@ -489,6 +788,114 @@ where
     T: AxisValue,
     C: PixelColor,
 {
+    /// Check whether the axis uses a logarithmic scale
+    pub fn is_logarithmic(&self) -> bool {
+        self.logarithmic
+    }
+
+    /// Position of `value` along the axis, 0.0 at the minimum and 1.0 at the maximum
+    ///
+    /// Returns 0.5 for an empty range.
+    pub fn normalize(&self, value: T) -> f32 {
+        let min = self.config.min.to_f32();
+        let max = self.config.max.to_f32();
+        if self.logarithmic {
+            return LogRangeTransform::new(min, max).normalize_f32(value.to_f32());
+        }
+        if max <= min {
+            return 0.5;
+        }
+        (value.to_f32() - min) / (max - min)
+    }
+
+    /// Extend the viewport outward on the axis side by the plot gap
+    ///
+    /// Only the side the axis is drawn on grows, so tick positions along the
+    /// axis still line up with the data.
+    fn axis_viewport(&self, viewport: Rectangle) -> Rectangle {
+        self.axis_viewport_at(viewport, self.config.position)
+    }
+
+    /// Extend the viewport outward by the plot gap on the side of `position`
+    fn axis_viewport_at(&self, viewport: Rectangle, position: AxisPosition) -> Rectangle {
+        let gap = self.style.plot_gap;
+        if gap == 0 {
+            return viewport;
+        }
+
+        let Rectangle { top_left, size } = viewport;
+        match (self.config.orientation, position) {
+            (AxisOrientation::Horizontal, AxisPosition::Top) => Rectangle::new(
+                Point::new(top_left.x, top_left.y - gap as i32),
+                Size::new(size.width, size.height + gap),
+            ),
+            (AxisOrientation::Horizontal, _) => {
+                Rectangle::new(top_left, Size::new(size.width, size.height + gap))
+            }
+            (AxisOrientation::Vertical, AxisPosition::Right) => {
+                Rectangle::new(top_left, Size::new(size.width + gap, size.height))
+            }
+            (AxisOrientation::Vertical, _) => Rectangle::new(
+                Point::new(top_left.x - gap as i32, top_left.y),
+                Size::new(size.width + gap, size.height),
+            ),
+        }
+    }
+
+    /// Side of the plot the labels are drawn on, treating invalid
+    /// combinations like [`calculate_label_position`](Self::calculate_label_position)
+    fn label_side(&self) -> AxisPosition {
+        match (self.config.orientation, self.config.position) {
+            (AxisOrientation::Horizontal, AxisPosition::Top) => AxisPosition::Top,
+            (AxisOrientation::Horizontal, _) => AxisPosition::Bottom,
+            (AxisOrientation::Vertical, AxisPosition::Right) => AxisPosition::Right,
+            (AxisOrientation::Vertical, _) => AxisPosition::Left,
+        }
+    }
+
+    /// Check whether tick labels are drawn rotated by a quarter turn
+    fn rotated_labels(&self) -> bool {
+        self.config.orientation == AxisOrientation::Horizontal
+            && matches!(self.style.labels.rotation, 90 | 270)
+    }
+
+    /// Check whether a label at `position` clears the edge culling margin
+    ///
+    /// Always true without edge culling. Extents assume the 6x10 font used by
+    /// [`DefaultAxisRenderer`], with labels centered on their tick.
+    fn label_fits(&self, text: &str, position: Point, viewport: Rectangle) -> bool {
+        const CHAR_WIDTH: i32 = 6;
+        const CHAR_HEIGHT: i32 = 10;
+
+        let Some(margin) = self.style.label_edge_margin else {
+            return true;
+        };
+        let margin = margin as i32;
+
+        let (center, half_extent, start, length) = match self.config.orientation {
+            AxisOrientation::Horizontal if self.rotated_labels() => (
+                position.x,
+                CHAR_HEIGHT / 2,
+                viewport.top_left.x,
+                viewport.size.width as i32,
+            ),
+            AxisOrientation::Horizontal => (
+                position.x,
+                text.chars().count() as i32 * CHAR_WIDTH / 2,
+                viewport.top_left.x,
+                viewport.size.width as i32,
+            ),
+            AxisOrientation::Vertical => (
+                position.y,
+                CHAR_HEIGHT / 2,
+                viewport.top_left.y,
+                viewport.size.height as i32,
+            ),
+        };
+
+        center - half_extent >= start + margin && center + half_extent <= start + length - margin
+    }
+
     /// Calculate the position for a label
     fn calculate_label_position(&self, tick_pos: Point) -> Point {
         match (self.config.orientation, self.config.position) {
@@ -584,10 +991,8 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> AxisRenderer<C
     where
         D: DrawTarget<Color = C>,
     {
-        Line::new(start, end)
-            .into_styled(PrimitiveStyle::with_stroke(style.color, style.width))
-            .draw(target)
-            .map_err(|_| crate::error::ChartError::RenderingError)?;
+        // Dotted and dashed grid lines stay apart from solid data lines
+        crate::render::ChartRenderer::draw_line(start, end, style, target)?;
         Ok(())
     }
 
@@ -613,6 +1018,52 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> AxisRenderer<C
 
         Ok(())
     }
+
+    fn draw_styled_label<D>(
+        &self,
+        text: &str,
+        position: Point,
+        style: &LabelStyle<C>,
+        side: AxisPosition,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        use crate::render::text::{TextRenderer, TextRotation};
+        use embedded_graphics::{
+            mono_font::{ascii::FONT_6X10, MonoTextStyle},
+            text::{Alignment, Text},
+        };
+
+        let text_style = MonoTextStyle::new(&FONT_6X10, style.color);
+
+        let rotation = match (side, style.rotation) {
+            (AxisPosition::Top | AxisPosition::Bottom, 90) => Some(TextRotation::TopToBottom),
+            (AxisPosition::Top | AxisPosition::Bottom, 270) => Some(TextRotation::BottomToTop),
+            _ => None,
+        };
+
+        let Some(rotation) = rotation else {
+            Text::with_alignment(text, position, text_style, Alignment::Center)
+                .draw(target)
+                .map_err(|_| crate::error::ChartError::RenderingError)?;
+            return Ok(());
+        };
+
+        // Keep the end of the column nearest the axis where upright labels
+        // start: 8 pixels above the baseline position for a bottom axis and
+        // 2 below it for a top axis
+        let size = TextRenderer::vertical_text_size::<C>(text, &FONT_6X10);
+        let top = match side {
+            AxisPosition::Top => position.y + 2 - size.height as i32,
+            _ => position.y - 8,
+        };
+        let top_left = Point::new(position.x - size.width as i32 / 2, top);
+
+        TextRenderer::draw_vertical_text(text, top_left, rotation, &text_style, target)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -661,4 +1112,202 @@ mod tests {
         // Note: Tick generator test commented out due to type inference issues
         // assert_eq!(axis.tick_generator().preferred_tick_count(), 8);
     }
+
+    #[test]
+    fn test_zero_line_only_within_range() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(20, 21));
+        let style = AxisStyle::new().with_zero_line(crate::style::LineStyle::solid(Rgb565::RED));
+
+        let axis: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(-10.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left)
+                .with_style(style.clone())
+                .show_zero_line(true);
+        let mut display = MockDisplay::<Rgb565>::new();
+        axis.draw_zero_line(viewport, viewport, &mut display)
+            .unwrap();
+        let y = axis.transform_value(0.0, viewport);
+        assert_eq!(display.get_pixel(Point::new(5, y)), Some(Rgb565::RED));
+
+        let positive: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(1.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left)
+                .with_style(style)
+                .show_zero_line(true);
+        let mut display = MockDisplay::<Rgb565>::new();
+        positive
+            .draw_zero_line(viewport, viewport, &mut display)
+            .unwrap();
+        assert_eq!(display.affected_area().size, Size::zero());
+    }
+
+    #[test]
+    fn test_plot_gap_moves_axis_outward() {
+        let viewport = Rectangle::new(Point::new(20, 10), Size::new(60, 40));
+        let style = AxisStyle::new().with_plot_gap(4);
+
+        let bottom: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 10.0, AxisOrientation::Horizontal, AxisPosition::Bottom)
+                .with_style(style.clone());
+        let (start, end) = bottom.calculate_axis_line(bottom.axis_viewport(viewport));
+        assert_eq!((start.y, end.y), (53, 53));
+        assert_eq!((start.x, end.x), (20, 79));
+
+        let left: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left)
+                .with_style(style);
+        let (start, _) = left.calculate_axis_line(left.axis_viewport(viewport));
+        assert_eq!(start.x, 16);
+
+        let flush: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left);
+        assert_eq!(left.required_space(), flush.required_space() + 4);
+    }
+
+    #[test]
+    fn test_mirrored_ticks_and_frame() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let viewport = Rectangle::new(Point::new(10, 10), Size::new(40, 40));
+        let render = |frame: bool| {
+            let axis: LinearAxis<f32, Rgb565> =
+                LinearAxis::new(0.0, 10.0, AxisOrientation::Horizontal, AxisPosition::Bottom)
+                    .show_labels(false)
+                    .mirror_ticks(true)
+                    .show_frame(frame);
+            let mut display = MockDisplay::<Rgb565>::new();
+            display.set_allow_overdraw(true);
+            axis.draw(viewport, &mut display).unwrap();
+            display
+        };
+
+        // The tick at 10 is repeated on the top edge, pointing down into the plot
+        let display = render(false);
+        assert!(display.get_pixel(Point::new(49, 11)).is_some());
+        assert!(display.get_pixel(Point::new(11, 10)).is_none());
+
+        let display = render(true);
+        assert!(display.get_pixel(Point::new(11, 10)).is_some());
+    }
+
+    #[test]
+    fn test_locale_changes_tick_labels() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let viewport = Rectangle::new(Point::new(4, 4), Size::new(56, 30));
+        let render = |locale: NumberLocale| {
+            let axis: LinearAxis<f32, Rgb565> = LinearAxis::new(
+                0.0,
+                20000.0,
+                AxisOrientation::Horizontal,
+                AxisPosition::Bottom,
+            )
+            .with_tick_generator(LinearTickGenerator::new(2))
+            .with_locale(locale);
+            let mut display = MockDisplay::<Rgb565>::new();
+            display.set_allow_overdraw(true);
+            display.set_allow_out_of_bounds_drawing(true);
+            axis.draw_axis_only(viewport, &mut display).unwrap();
+            display
+        };
+
+        assert_ne!(render(NumberLocale::PLAIN), render(NumberLocale::GERMAN));
+        assert_eq!(render(NumberLocale::PLAIN), render(NumberLocale::default()));
+    }
+
+    #[test]
+    fn test_edge_culling_hides_clipped_labels() {
+        let viewport = Rectangle::new(Point::new(10, 10), Size::new(100, 20));
+        let axis: LinearAxis<f32, Rgb565> = LinearAxis::new(
+            0.0,
+            100.0,
+            AxisOrientation::Horizontal,
+            AxisPosition::Bottom,
+        );
+        // Without culling every label is drawn, even where it overhangs the edge
+        assert!(axis.label_fits("0", Point::new(10, 30), viewport));
+
+        let axis = axis.with_style(AxisStyle::new().with_edge_culling(4));
+        assert!(!axis.label_fits("0", Point::new(10, 30), viewport));
+        assert!(!axis.label_fits("100", Point::new(109, 30), viewport));
+        assert!(!axis.label_fits("20", Point::new(19, 30), viewport));
+        assert!(axis.label_fits("20", Point::new(20, 30), viewport));
+        assert!(axis.label_fits("50", Point::new(60, 30), viewport));
+    }
+
+    #[test]
+    fn test_formatter_replaces_tick_labels() {
+        use crate::style::SiPrefix;
+
+        let axis: LinearAxis<f32, Rgb565> = LinearAxis::new(
+            0.0,
+            20000.0,
+            AxisOrientation::Horizontal,
+            AxisPosition::Bottom,
+        )
+        .with_formatter(SiPrefix::new(0, "Hz"));
+        assert_eq!(axis.tick_label_text(20000.0, "20000"), "20 kHz");
+
+        let explicit = axis
+            .clone()
+            .with_explicit_ticks(ExplicitTicks::new(&[0.0], &["DC"]).unwrap());
+        assert_eq!(explicit.tick_label_text(0.0, "DC"), "DC");
+    }
+
+    #[test]
+    fn test_label_precision_and_rotation() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let viewport = Rectangle::new(Point::new(8, 4), Size::new(48, 20));
+        let axis: LinearAxis<f32, Rgb565> = LinearAxis::new(
+            0.0,
+            100.0,
+            AxisOrientation::Horizontal,
+            AxisPosition::Bottom,
+        )
+        .with_tick_generator(LinearTickGenerator::new(2))
+        .with_precision(1);
+        assert_eq!(axis.tick_label_text(50.0, "50"), "50.0");
+
+        let label_area = |axis: &LinearAxis<f32, Rgb565>| {
+            let mut display = MockDisplay::<Rgb565>::new();
+            display.set_allow_overdraw(true);
+            display.set_allow_out_of_bounds_drawing(true);
+            axis.draw_labels(viewport, &mut display).unwrap();
+            display.affected_area()
+        };
+
+        let upright = label_area(&axis);
+        let rotated_axis = axis.clone().with_label_rotation(90);
+        let rotated = label_area(&rotated_axis);
+        assert!(upright.size.width > upright.size.height);
+        assert!(rotated.size.height > upright.size.height);
+        assert!(rotated.top_left.y >= viewport.bottom_right().unwrap().y);
+        assert!(rotated_axis.required_space() > axis.required_space());
+    }
+
+    #[test]
+    fn test_log_scale_ticks_and_transform() {
+        let axis: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(1e-6, 0.1, AxisOrientation::Horizontal, AxisPosition::Bottom)
+                .with_log_scale();
+        assert!(axis.is_logarithmic());
+
+        let ticks = axis.ticks(50);
+        let labels: heapless::Vec<&str, 8> = ticks
+            .iter()
+            .filter_map(|tick| tick.label.as_deref())
+            .collect();
+        // Six decades with the default five preferred ticks: every other one
+        assert_eq!(labels, ["10^-6", "10^-4", "10^-2"]);
+
+        // Each decade takes the same share of the axis
+        let viewport = Rectangle::new(Point::zero(), Size::new(101, 20));
+        assert_eq!(axis.transform_value(1e-6, viewport), 0);
+        assert!((59..=60).contains(&axis.transform_value(1e-3, viewport)));
+        assert!((99..=100).contains(&axis.transform_value(0.1, viewport)));
+
+        let value = axis.inverse_transform(60, viewport);
+        assert!((value - 1e-3).abs() < 1e-6);
+    }
 }
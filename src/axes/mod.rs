@@ -1,25 +1,29 @@
 //! Axis system for embedded graphics charts.
 //!
-//! This module provides a comprehensive axis system including linear axes,
+//! This module provides a comprehensive axis system including linear and time axes,
 //! automatic tick generation, and configurable styling. The axis system
 //! integrates seamlessly with existing chart types while maintaining
 //! no_std compatibility and memory efficiency.
 
 pub mod builder;
+pub mod category;
 pub mod linear;
 pub mod range;
 pub mod scale;
 pub mod style;
 pub mod ticks;
+pub mod time;
 pub mod traits;
 
 pub use builder::presets;
 pub use builder::*;
+pub use category::*;
 pub use linear::*;
 pub use range::*;
 pub use scale::*;
 pub use style::*;
 pub use ticks::*;
+pub use time::*;
 pub use traits::*;
 
 /// Axis orientation
@@ -63,6 +67,12 @@ pub struct AxisConfig<T> {
     pub show_labels: bool,
     /// Whether to show grid lines
     pub show_grid: bool,
+    /// Whether to show a line at zero when it lies within the range
+    pub show_zero_line: bool,
+    /// Whether to repeat the tick marks, unlabeled, on the opposite side of the plot
+    pub mirror_ticks: bool,
+    /// Whether to draw the axis line on the opposite side too, closing the plot frame
+    pub show_frame: bool,
 }
 
 impl<T> AxisConfig<T>
@@ -80,6 +90,9 @@ where
             show_ticks: true,
             show_labels: true,
             show_grid: false,
+            show_zero_line: false,
+            mirror_ticks: false,
+            show_frame: false,
         }
     }
 
@@ -113,6 +126,9 @@ where
             show_ticks: true,
             show_labels: true,
             show_grid: false,
+            show_zero_line: false,
+            mirror_ticks: false,
+            show_frame: false,
         }
     }
 }
@@ -384,6 +384,111 @@ where
     }
 }
 
+/// Reciprocal scale transformation, spacing values by `1 / value`
+///
+/// Useful for inverse-scaled measurements such as thermistor resistance,
+/// period versus frequency or lens focal ratios. The domain must not contain
+/// zero; larger values map closer together.
+#[cfg(feature = "reciprocal-scale")]
+#[derive(Debug, Clone)]
+pub struct ReciprocalScale {
+    config: ScaleConfig,
+    inv_min: f32,
+    inv_range: f32,
+}
+
+#[cfg(feature = "reciprocal-scale")]
+impl ReciprocalScale {
+    /// Create a new reciprocal scale
+    pub fn new(config: ScaleConfig) -> ChartResult<Self> {
+        if config.min >= config.max {
+            return Err(ChartError::InvalidRange);
+        }
+        // Both bounds must be on the same side of zero
+        if config.min <= 0.0 && config.max >= 0.0 {
+            return Err(ChartError::InvalidRange);
+        }
+
+        let inv_min = 1.0 / config.min;
+        let inv_range = 1.0 / config.max - inv_min;
+        Ok(Self {
+            config,
+            inv_min,
+            inv_range,
+        })
+    }
+
+    /// Round a value to a nearby "nice" number with at most two significant digits
+    fn nice_value(value: f32) -> f32 {
+        let sign = if value < 0.0 { -1.0 } else { 1.0 };
+        let (mantissa, exponent) = crate::math::split_power_of_ten(value * sign);
+        let rounded = ((mantissa * 2.0 + 0.5) as i32) as f32 / 2.0;
+        sign * rounded * crate::math::power_of_ten(exponent)
+    }
+}
+
+#[cfg(feature = "reciprocal-scale")]
+impl ScaleTransform for ReciprocalScale {
+    fn transform(&self, value: f32) -> ChartResult<f32> {
+        if value.is_nan() || value.is_infinite() || value == 0.0 {
+            return Err(ChartError::InvalidData);
+        }
+
+        let normalized = (1.0 / value - self.inv_min) / self.inv_range;
+        Ok(normalized.clamp(0.0, 1.0))
+    }
+
+    fn inverse(&self, normalized: f32) -> ChartResult<f32> {
+        if !(0.0..=1.0).contains(&normalized) {
+            return Err(ChartError::InvalidRange);
+        }
+
+        Ok(1.0 / (self.inv_min + normalized * self.inv_range))
+    }
+
+    fn get_ticks(&self, count: usize) -> ChartResult<heapless::Vec<f32, 16>> {
+        let mut ticks: heapless::Vec<f32, 16> = heapless::Vec::new();
+
+        if count == 0 {
+            return Ok(ticks);
+        }
+        if count == 1 {
+            let _ = ticks.push(Self::nice_value(self.inverse(0.5)?));
+            return Ok(ticks);
+        }
+
+        // Space ticks evenly in reciprocal space, then snap them to nice values
+        // so the labels stay readable. The bounds themselves are kept exact.
+        for i in 0..count {
+            let normalized = i as f32 / (count - 1) as f32;
+            let tick = if i == 0 {
+                self.config.min
+            } else if i == count - 1 {
+                self.config.max
+            } else {
+                Self::nice_value(self.inverse(normalized)?)
+            };
+
+            let in_range = tick >= self.config.min && tick <= self.config.max;
+            let is_new = ticks.last().is_none_or(|&last| last != tick);
+            if in_range && is_new && ticks.push(tick).is_err() {
+                break;
+            }
+        }
+
+        ticks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        Ok(ticks)
+    }
+
+    fn format_value(&self, value: f32) -> heapless::String<16> {
+        LinearScale {
+            config: self.config,
+            range: self.config.max - self.config.min,
+        }
+        .format_value(value)
+    }
+}
+
 /// Enumeration of available scale types
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AxisScaleType {
@@ -395,6 +500,9 @@ pub enum AxisScaleType {
     LogE,
     /// Logarithmic scale with custom base
     LogBase(f32),
+    /// Reciprocal scale, spacing values by `1 / value`
+    #[cfg(feature = "reciprocal-scale")]
+    Reciprocal,
     /// Custom scale (requires transformation functions)
     Custom,
 }
@@ -412,6 +520,9 @@ pub enum AxisScale {
     Linear(LinearScale),
     /// Logarithmic scale transformation
     Logarithmic(LogarithmicScale),
+    /// Reciprocal scale transformation
+    #[cfg(feature = "reciprocal-scale")]
+    Reciprocal(ReciprocalScale),
     /// Custom scale with user-defined transformation
     Custom(Box<dyn ScaleTransform>),
 }
@@ -426,6 +537,8 @@ impl AxisScale {
             AxisScaleType::LogBase(base) => {
                 Ok(Self::Logarithmic(LogarithmicScale::new(config, base)?))
             }
+            #[cfg(feature = "reciprocal-scale")]
+            AxisScaleType::Reciprocal => Ok(Self::Reciprocal(ReciprocalScale::new(config)?)),
             AxisScaleType::Custom => Err(ChartError::InvalidConfiguration),
         }
     }
@@ -435,6 +548,8 @@ impl AxisScale {
         match self {
             Self::Linear(scale) => scale.transform(value),
             Self::Logarithmic(scale) => scale.transform(value),
+            #[cfg(feature = "reciprocal-scale")]
+            Self::Reciprocal(scale) => scale.transform(value),
             Self::Custom(scale) => scale.transform(value),
         }
     }
@@ -444,6 +559,8 @@ impl AxisScale {
         match self {
             Self::Linear(scale) => scale.inverse(normalized),
             Self::Logarithmic(scale) => scale.inverse(normalized),
+            #[cfg(feature = "reciprocal-scale")]
+            Self::Reciprocal(scale) => scale.inverse(normalized),
             Self::Custom(scale) => scale.inverse(normalized),
         }
     }
@@ -453,6 +570,8 @@ impl AxisScale {
         match self {
             Self::Linear(scale) => scale.get_ticks(count),
             Self::Logarithmic(scale) => scale.get_ticks(count),
+            #[cfg(feature = "reciprocal-scale")]
+            Self::Reciprocal(scale) => scale.get_ticks(count),
             Self::Custom(scale) => scale.get_ticks(count),
         }
     }
@@ -462,6 +581,8 @@ impl AxisScale {
         match self {
             Self::Linear(scale) => scale.format_value(value),
             Self::Logarithmic(scale) => scale.format_value(value),
+            #[cfg(feature = "reciprocal-scale")]
+            Self::Reciprocal(scale) => scale.format_value(value),
             Self::Custom(scale) => scale.format_value(value),
         }
     }
@@ -521,4 +642,35 @@ mod tests {
         assert!(scale.transform(0.0).is_err());
         assert!(scale.transform(-1.0).is_err());
     }
+
+    #[test]
+    #[cfg(feature = "reciprocal-scale")]
+    fn test_reciprocal_scale() {
+        let config = ScaleConfig {
+            min: 1.0,
+            max: 100.0,
+            ..Default::default()
+        };
+
+        let scale = ReciprocalScale::new(config).unwrap();
+
+        assert!((scale.transform(1.0).unwrap() - 0.0).abs() < 0.001);
+        assert!((scale.transform(100.0).unwrap() - 1.0).abs() < 0.001);
+        // 1/2 sits just past the middle of 1/1..1/100 in reciprocal space
+        assert!((scale.transform(2.0).unwrap() - 0.5051).abs() < 0.001);
+        assert!((scale.inverse(scale.transform(4.0).unwrap()).unwrap() - 4.0).abs() < 0.01);
+
+        let ticks = scale.get_ticks(5).unwrap();
+        assert_eq!(ticks.first(), Some(&1.0));
+        assert_eq!(ticks.last(), Some(&100.0));
+        assert!(ticks.windows(2).all(|w| w[0] < w[1]));
+
+        // The domain may not straddle zero
+        let straddling = ScaleConfig {
+            min: -1.0,
+            max: 1.0,
+            ..Default::default()
+        };
+        assert!(ReciprocalScale::new(straddling).is_err());
+    }
 }
@@ -14,10 +14,25 @@ pub struct AxisStyle<C: PixelColor> {
     pub minor_ticks: TickStyle<C>,
     /// Style for grid lines
     pub grid_lines: Option<LineStyle<C>>,
+    /// Style for the zero line
+    pub zero_line: LineStyle<C>,
     /// Style for axis labels
     pub labels: LabelStyle<C>,
     /// Spacing between the axis and labels
     pub label_offset: u32,
+    /// Gap between the plot area and the axis line
+    ///
+    /// The axis, its ticks and labels are moved outward by this many pixels,
+    /// away from the data.
+    pub plot_gap: u32,
+    /// Edge culling for tick labels
+    ///
+    /// When set, a label is only drawn once its text lies entirely inside the
+    /// axis span, at least this many pixels from either end. Ticks near the
+    /// edges are still drawn, so while panning a tick appears first and its
+    /// label fades in once it has cleared the margin, instead of being cut
+    /// off by the edge of the plot.
+    pub label_edge_margin: Option<u32>,
 }
 
 /// Style configuration for tick marks
@@ -68,8 +83,13 @@ where
             major_ticks: TickStyle::new(embedded_graphics::pixelcolor::Rgb565::RED.into(), 10),
             minor_ticks: TickStyle::new(embedded_graphics::pixelcolor::Rgb565::BLUE.into(), 5),
             grid_lines: None,
+            zero_line: LineStyle::solid(
+                embedded_graphics::pixelcolor::Rgb565::new(12, 24, 12).into(),
+            ),
             labels: LabelStyle::new(embedded_graphics::pixelcolor::Rgb565::BLACK.into()),
             label_offset: 8,
+            plot_gap: 0,
+            label_edge_margin: None,
         }
     }
 
@@ -103,6 +123,12 @@ where
         self
     }
 
+    /// Set the zero line style
+    pub fn with_zero_line(mut self, style: LineStyle<C>) -> Self {
+        self.zero_line = style;
+        self
+    }
+
     /// Set the label style
     pub fn with_labels(mut self, style: LabelStyle<C>) -> Self {
         self.labels = style;
@@ -115,6 +141,28 @@ where
         self
     }
 
+    /// Set the gap between the plot area and the axis line
+    pub fn with_plot_gap(mut self, gap: u32) -> Self {
+        self.plot_gap = gap;
+        self
+    }
+
+    /// Hide tick labels that would not fit entirely inside the axis span
+    ///
+    /// `fade_margin` keeps labels this many pixels away from the ends of the
+    /// axis; see [`AxisStyle::label_edge_margin`].
+    pub fn with_edge_culling(mut self, fade_margin: u32) -> Self {
+        self.label_edge_margin = Some(fade_margin);
+        self
+    }
+
+    /// Set the major and minor tick lengths
+    pub fn with_tick_length(mut self, major: u32, minor: u32) -> Self {
+        self.major_ticks.length = major;
+        self.minor_ticks.length = minor;
+        self
+    }
+
     /// Create a minimal style for small displays
     pub fn minimal() -> Self {
         Self {
@@ -123,9 +171,14 @@ where
             minor_ticks: TickStyle::new(embedded_graphics::pixelcolor::Rgb565::BLACK.into(), 1)
                 .hidden(),
             grid_lines: None,
+            zero_line: LineStyle::solid(
+                embedded_graphics::pixelcolor::Rgb565::new(12, 24, 12).into(),
+            ),
             labels: LabelStyle::new(embedded_graphics::pixelcolor::Rgb565::BLACK.into())
                 .with_font_size(8),
             label_offset: 4,
+            plot_gap: 0,
+            label_edge_margin: None,
         }
     }
 
@@ -142,8 +195,13 @@ where
             grid_lines: Some(LineStyle::solid(
                 embedded_graphics::pixelcolor::Rgb565::new(25, 50, 25).into(),
             )),
+            zero_line: LineStyle::solid(
+                embedded_graphics::pixelcolor::Rgb565::new(12, 24, 12).into(),
+            ),
             labels: LabelStyle::new(embedded_graphics::pixelcolor::Rgb565::BLACK.into()),
             label_offset: 10,
+            plot_gap: 0,
+            label_edge_margin: None,
         }
     }
 }
@@ -323,4 +381,17 @@ mod tests {
         assert!(!style.minor_ticks.visible);
         assert_eq!(style.label_offset, 4);
     }
+
+    #[test]
+    fn test_padding_controls() {
+        let style: AxisStyle<Rgb565> = AxisStyle::new()
+            .with_label_offset(6)
+            .with_plot_gap(3)
+            .with_tick_length(7, 2);
+
+        assert_eq!(style.label_offset, 6);
+        assert_eq!(style.plot_gap, 3);
+        assert_eq!(style.major_ticks.length, 7);
+        assert_eq!(style.minor_ticks.length, 2);
+    }
 }
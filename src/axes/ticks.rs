@@ -1,14 +1,168 @@
 //! Tick generation algorithms for axes.
 
 use crate::axes::traits::{AxisValue, Tick, TickGenerator};
-use crate::math::{Math, NumericConversion};
+use crate::axes::AxisOrientation;
+use crate::error::{ChartError, ChartResult};
+use crate::math::{power_of_ten, split_power_of_ten, Math, NumericConversion};
+use embedded_graphics::primitives::Rectangle;
 use heapless::Vec;
 
+/// Strategy used by [`LinearTickGenerator`] to place major ticks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickPlacement {
+    /// Evenly spaced ticks at "nice" round numbers
+    Nice,
+    /// One tick per category index (`0..count`), see [`CategoryScale`]
+    Categorical(usize),
+}
+
+/// Maps category indices to equally sized slots along an axis.
+///
+/// A [`LinearAxis`](crate::axes::LinearAxis) spanning [`CategoryScale::range`] with a
+/// [`LinearTickGenerator::categorical`] tick generator and a category-aligned
+/// `BarChart` both position categories through this type, so tick marks always
+/// sit under bar centers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryScale {
+    /// Number of categories
+    count: usize,
+}
+
+impl CategoryScale {
+    /// Create a category scale for the given number of categories
+    pub const fn new(count: usize) -> Self {
+        Self { count }
+    }
+
+    /// Get the number of categories
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Axis range that centers every category index inside its slot
+    pub fn range(&self) -> (f32, f32) {
+        (-0.5, self.count as f32 - 0.5)
+    }
+
+    /// Size of a single category slot in pixels for the given axis extent
+    pub fn slot_size(&self, extent: u32) -> u32 {
+        if self.count == 0 {
+            return 0;
+        }
+        extent / self.count as u32
+    }
+
+    /// Screen coordinate of the center of the category at `index`
+    ///
+    /// Uses the same mapping as a linear axis over [`CategoryScale::range`]:
+    /// horizontal axes grow to the right, vertical axes grow upward.
+    pub fn center(&self, index: usize, area: Rectangle, orientation: AxisOrientation) -> i32 {
+        if self.count == 0 {
+            return match orientation {
+                AxisOrientation::Horizontal => area.top_left.x + area.size.width as i32 / 2,
+                AxisOrientation::Vertical => area.top_left.y + area.size.height as i32 / 2,
+            };
+        }
+
+        let normalized = (index as f32 + 0.5) / self.count as f32;
+
+        match orientation {
+            AxisOrientation::Horizontal => {
+                area.top_left.x + (normalized * (area.size.width as f32 - 1.0)) as i32
+            }
+            AxisOrientation::Vertical => {
+                area.top_left.y + area.size.height as i32
+                    - 1
+                    - (normalized * (area.size.height as f32 - 1.0)) as i32
+            }
+        }
+    }
+
+    /// Create a tick generator placing one tick per category of this scale
+    pub fn tick_generator(&self) -> LinearTickGenerator {
+        LinearTickGenerator::categorical(self.count)
+    }
+}
+
+/// Maximum number of user-supplied ticks in [`ExplicitTicks`]
+pub const MAX_EXPLICIT_TICKS: usize = 16;
+
+/// Major ticks at user-supplied positions, optionally with their own labels.
+///
+/// Set on an axis with [`LinearAxis::with_explicit_ticks`](crate::axes::LinearAxis::with_explicit_ticks).
+/// The ticks are kept outside [`LinearTickGenerator`] so the generator stays
+/// small for axes that don't use them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplicitTicks {
+    ticks: Vec<Tick<f32>, MAX_EXPLICIT_TICKS>,
+}
+
+impl ExplicitTicks {
+    /// Create ticks exactly at `positions`
+    ///
+    /// Ticks are drawn verbatim, without nice-number rounding or minor ticks.
+    /// `labels[i]` is used for `positions[i]`; positions without a label show
+    /// their formatted value. Positions outside the axis range are skipped.
+    ///
+    /// # Arguments
+    /// * `positions` - Strictly increasing, finite tick positions (at most
+    ///   [`MAX_EXPLICIT_TICKS`])
+    /// * `labels` - Optional labels, no more than there are positions
+    pub fn new(positions: &[f32], labels: &[&str]) -> ChartResult<Self> {
+        if positions.len() > MAX_EXPLICIT_TICKS || labels.len() > positions.len() {
+            return Err(ChartError::ConfigurationError);
+        }
+        if positions.iter().any(|position| !position.is_finite())
+            || positions.windows(2).any(|pair| pair[0] >= pair[1])
+        {
+            return Err(ChartError::InvalidRange);
+        }
+
+        let mut ticks = Vec::new();
+        for (index, &position) in positions.iter().enumerate() {
+            let tick = match labels.get(index) {
+                Some(label) => Tick::major(position, label),
+                None => Tick::major_unlabeled(position),
+            };
+            let _ = ticks.push(tick);
+        }
+        Ok(Self { ticks })
+    }
+
+    /// Get the user-supplied ticks
+    pub fn ticks(&self) -> &[Tick<f32>] {
+        &self.ticks
+    }
+
+    /// Generate the ticks that fall inside the range, at most `max_ticks`
+    pub fn generate<T: AxisValue>(&self, min: T, max: T, max_ticks: usize) -> Vec<Tick<T>, 32> {
+        let mut ticks = Vec::new();
+
+        for tick in &self.ticks {
+            if ticks.len() >= max_ticks {
+                break;
+            }
+            if tick.value >= min.to_f32() && tick.value <= max.to_f32() {
+                let value = T::from_f32(tick.value);
+                let label = match &tick.label {
+                    Some(label) => label.clone(),
+                    None => value.format(),
+                };
+                let _ = ticks.push(Tick::major(value, label.as_str()));
+            }
+        }
+
+        ticks
+    }
+}
+
 /// Linear tick generator that creates evenly spaced ticks
 #[derive(Debug, Clone)]
 pub struct LinearTickGenerator {
     /// Preferred number of ticks
     preferred_count: usize,
+    /// How major tick positions are derived
+    placement: TickPlacement,
     /// Whether to include minor ticks
     include_minor_ticks: bool,
     /// Ratio of minor ticks to major ticks
@@ -20,11 +174,33 @@ impl LinearTickGenerator {
     pub fn new(preferred_count: usize) -> Self {
         Self {
             preferred_count: preferred_count.clamp(2, 20),
+            placement: TickPlacement::Nice,
+            include_minor_ticks: false,
+            minor_tick_ratio: 4,
+        }
+    }
+
+    /// Create a tick generator with one major tick per category index
+    ///
+    /// Ticks are placed at `0, 1, ..., count - 1`. Pair this with an axis range
+    /// from [`CategoryScale::range`] so each tick sits in the middle of its slot.
+    ///
+    /// # Arguments
+    /// * `count` - Number of categories (clamped to 32 ticks)
+    pub fn categorical(count: usize) -> Self {
+        Self {
+            preferred_count: count.clamp(2, 20),
+            placement: TickPlacement::Categorical(count.min(32)),
             include_minor_ticks: false,
             minor_tick_ratio: 4,
         }
     }
 
+    /// Get the tick placement strategy
+    pub fn placement(&self) -> TickPlacement {
+        self.placement
+    }
+
     /// Enable minor ticks with the specified ratio
     ///
     /// # Arguments
@@ -63,35 +239,27 @@ impl LinearTickGenerator {
             return T::from_f32(1e-6); // Safe minimum step
         }
 
-        // Find the magnitude of the step using Math abstraction
-        let rough_step_num = rough_step.to_number();
-        let magnitude = Math::floor(Math::log10(rough_step_num));
-        let ten = 10.0f32.to_number();
-        let normalized_step = rough_step_num / Math::pow(ten, magnitude);
+        // Find the magnitude of the step in f32 so steps beyond the
+        // fixed-point range (e.g. 0..100000 axes) are not truncated
+        let (normalized_step, magnitude) = split_power_of_ten(rough_step);
 
         // Choose a nice normalized step
-        let one = 1.0f32.to_number();
-        let two = 2.0f32.to_number();
-        let five = 5.0f32.to_number();
-        let ten_norm = 10.0f32.to_number();
-
-        let nice_normalized = if normalized_step <= one {
-            one
-        } else if normalized_step <= two {
-            two
-        } else if normalized_step <= five {
-            five
+        let nice_normalized = if normalized_step <= 1.0 {
+            1.0
+        } else if normalized_step <= 2.0 {
+            2.0
+        } else if normalized_step <= 5.0 {
+            5.0
         } else {
-            ten_norm
+            10.0
         };
 
-        let result = if magnitude >= 0.0.to_number() && magnitude <= 10.0.to_number() {
-            nice_normalized * Math::pow(ten, magnitude)
+        let step_f32 = if (0..=10).contains(&magnitude) {
+            nice_normalized * power_of_ten(magnitude)
         } else {
             // Fallback for extreme magnitudes to prevent overflow
             nice_normalized
         };
-        let step_f32 = f32::from_number(result);
 
         // Final safety check
         if step_f32 <= 0.0 || !step_f32.is_finite() {
@@ -101,8 +269,30 @@ impl LinearTickGenerator {
         T::from_f32(step_f32)
     }
 
+    /// Generate one major tick per category index inside the range
+    fn generate_categorical_ticks<T: AxisValue>(min: T, max: T, count: usize) -> Vec<Tick<T>, 32> {
+        let mut ticks = Vec::new();
+
+        for index in 0..count.min(32) {
+            let value = T::from_f32(index as f32);
+            if value.to_f32() >= min.to_f32() && value.to_f32() <= max.to_f32() {
+                let label = value.format();
+                let _ = ticks.push(Tick::major(value, label.as_str()));
+            }
+        }
+
+        ticks
+    }
+
     /// Generate major ticks for the range
     fn generate_major_ticks<T: AxisValue>(&self, min: T, max: T) -> Vec<Tick<T>, 32> {
+        match self.placement {
+            TickPlacement::Categorical(count) => {
+                return Self::generate_categorical_ticks(min, max, count)
+            }
+            TickPlacement::Nice => {}
+        }
+
         let mut ticks = Vec::new();
 
         let step = Self::calculate_nice_step(min, max, self.preferred_count);
@@ -122,11 +312,9 @@ impl LinearTickGenerator {
 
         // Find the first tick position (rounded down to nearest step)
         let first_tick_value = {
-            let min_f32 = min.to_f32();
-            let min_num = min_f32.to_number();
-            let step_num = step_f32.to_number();
-            let first_tick_num = Math::floor(min_num / step_num) * step_num;
-            let first_tick_f32 = f32::from_number(first_tick_num);
+            // Only the step count goes through Number, which keeps it small
+            let steps = (min.to_f32() / step_f32).to_number();
+            let first_tick_f32 = f32::from_number(Math::floor(steps)) * step_f32;
             T::from_f32(first_tick_f32)
         };
 
@@ -382,25 +570,22 @@ impl TickGenerator<f32> for LogTickGenerator {
             return ticks; // Logarithmic scale requires positive values
         }
 
-        let min_num = min.to_number();
-        let max_num = max.to_number();
-        let base_num = self.base.to_number();
-
-        let log_min = Math::ln(min_num) / Math::ln(base_num);
-        let log_max = Math::ln(max_num) / Math::ln(base_num);
-
-        let start_power = f32::from_number(Math::floor(log_min)) as i32;
-        let end_power = f32::from_number(Math::ceil(log_max)) as i32;
+        // Walk the powers of the base in f32 so decades beyond the
+        // fixed-point range (e.g. 1..100000) are still reached exactly
+        let mut value = 1.0f32;
+        while value > min {
+            value /= self.base;
+        }
+        while value * self.base <= min {
+            value *= self.base;
+        }
 
-        for power in start_power..=end_power {
+        while value <= max {
             if ticks.len() >= max_ticks.min(32) {
                 break;
             }
 
-            let power_num = (power as f32).to_number();
-            let value_num = Math::pow(base_num, power_num);
-            let value = f32::from_number(value_num);
-            if value >= min && value <= max {
+            if value >= min {
                 // Simple no_std formatting
                 let mut label = heapless::String::new();
                 if value >= 1000.0 {
@@ -446,6 +631,7 @@ impl TickGenerator<f32> for LogTickGenerator {
                     label: Some(label),
                 });
             }
+            value *= self.base;
         }
 
         ticks
@@ -469,6 +655,7 @@ impl Default for LogTickGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use embedded_graphics::prelude::{Point, Size};
 
     #[test]
     #[cfg(not(feature = "integer-math"))] // Skip for integer-math to avoid overflow
@@ -487,6 +674,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_explicit_ticks_are_verbatim() {
+        let explicit = ExplicitTicks::new(&[0.0, 12.0, 24.0, 36.0, 48.0], &["0h", "12h"]).unwrap();
+
+        let ticks = explicit.generate(0.0f32, 40.0f32, 32);
+        let values: Vec<f32, 8> = ticks.iter().map(|tick| tick.value).collect();
+        assert_eq!(values.as_slice(), &[0.0, 12.0, 24.0, 36.0]);
+        assert_eq!(ticks[1].label.as_deref(), Some("12h"));
+        assert_eq!(ticks[2].label.as_deref(), Some("24"));
+        assert!(ticks.iter().all(|tick| tick.is_major));
+
+        assert_eq!(
+            ExplicitTicks::new(&[0.0, 24.0, 12.0], &[]).unwrap_err(),
+            ChartError::InvalidRange
+        );
+        assert_eq!(
+            ExplicitTicks::new(&[0.0], &["a", "b"]).unwrap_err(),
+            ChartError::ConfigurationError
+        );
+    }
+
+    #[test]
+    fn test_linear_ticks_large_range() {
+        let generator = LinearTickGenerator::new(5);
+        let ticks = generator.generate_ticks(0.0f32, 100_000.0f32, 10);
+
+        // Steps beyond the fixed-point range must not be truncated
+        let values: Vec<f32, 8> = ticks.iter().map(|tick| tick.value).collect();
+        assert_eq!(values.as_slice(), &[0.0, 50_000.0, 100_000.0]);
+
+        let log_ticks = LogTickGenerator::new().generate_ticks(1.0, 100_000.0, 10);
+        assert_eq!(log_ticks.last().map(|tick| tick.value), Some(100_000.0));
+    }
+
     #[test]
     #[cfg(not(any(feature = "fixed-point", feature = "integer-math")))] // Skip for fixed-point and integer-math to avoid overflow
     fn test_linear_tick_generator_with_minor_ticks() {
@@ -502,6 +723,30 @@ mod tests {
         assert!(minor_count > 0);
     }
 
+    #[test]
+    fn test_categorical_ticks_align_with_scale() {
+        let scale = CategoryScale::new(4);
+        let (min, max) = scale.range();
+        let ticks = scale.tick_generator().generate_ticks(min, max, 10);
+
+        assert_eq!(ticks.len(), 4);
+        for (index, tick) in ticks.iter().enumerate() {
+            assert!(tick.is_major);
+            assert_eq!(tick.value, index as f32);
+        }
+
+        let area = Rectangle::new(Point::zero(), Size::new(100, 50));
+        assert!(
+            scale.center(0, area, AxisOrientation::Horizontal)
+                < scale.center(1, area, AxisOrientation::Horizontal)
+        );
+        assert!(
+            scale.center(0, area, AxisOrientation::Vertical)
+                > scale.center(1, area, AxisOrientation::Vertical)
+        );
+        assert_eq!(scale.slot_size(100), 25);
+    }
+
     #[test]
     fn test_custom_tick_generator() {
         let generator = CustomTickGenerator::new()
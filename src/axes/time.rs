@@ -0,0 +1,608 @@
+//! Date/time axis for timestamped data.
+//!
+//! [`TimeAxis`] takes its range as Unix timestamps or millisecond counters and
+//! places ticks on whole seconds, minutes, hours or days, labelled `12:30:05`,
+//! `12:30` or `Mar 5` depending on the tick interval. Labels are built in
+//! fixed-size strings, so no allocator is needed.
+//!
+//! Chart data stays in `f32`, which cannot hold a Unix timestamp to the second.
+//! Axis values are therefore offsets from the start of the range, in the unit
+//! the axis was created with; [`TimeAxis::value`] converts a timestamp. With
+//! millisecond resolution, offsets stay exact for a little over four hours.
+//!
+//! 32-bit millisecond tick counters wrap after about 49 days. Either extend
+//! them with a [`TickUnwrapper`](crate::time::TickUnwrapper) before building
+//! the axis, or convert raw readings with [`TimeAxis::tick_value`], which
+//! measures from the start of the axis with wrapping arithmetic.
+//!
+//! Charts take the axis through [`LinearAxis`]:
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::axes::{TimeAxis, TimestampUnit};
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! // 2024-03-05 12:30 to 14:30 UTC
+//! let axis: TimeAxis<Rgb565> = TimeAxis::new(
+//!     1_709_641_800,
+//!     1_709_649_000,
+//!     TimestampUnit::Seconds,
+//!     AxisOrientation::Horizontal,
+//!     AxisPosition::Bottom,
+//! );
+//!
+//! let mut data = StaticDataSeries::<Point2D, 64>::new();
+//! data.push(Point2D::new(axis.value(1_709_642_000), 21.5))?;
+//!
+//! let mut chart: LineChart<Rgb565> = LineChart::builder().build()?;
+//! chart.set_x_axis(axis.into());
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::axes::{
+    linear::LinearAxis,
+    style::AxisStyle,
+    ticks::{ExplicitTicks, MAX_EXPLICIT_TICKS},
+    traits::{Axis, Tick, TickGenerator},
+    AxisOrientation, AxisPosition,
+};
+use crate::error::ChartResult;
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use heapless::{String, Vec};
+
+const SECOND_MS: i64 = 1_000;
+const MINUTE_MS: i64 = 60 * SECOND_MS;
+const HOUR_MS: i64 = 60 * MINUTE_MS;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Tick intervals tried from finest to coarsest
+const TICK_INTERVALS_MS: [i64; 30] = [
+    1,
+    2,
+    5,
+    10,
+    20,
+    50,
+    100,
+    200,
+    500,
+    SECOND_MS,
+    2 * SECOND_MS,
+    5 * SECOND_MS,
+    10 * SECOND_MS,
+    15 * SECOND_MS,
+    30 * SECOND_MS,
+    MINUTE_MS,
+    2 * MINUTE_MS,
+    5 * MINUTE_MS,
+    10 * MINUTE_MS,
+    15 * MINUTE_MS,
+    30 * MINUTE_MS,
+    HOUR_MS,
+    2 * HOUR_MS,
+    3 * HOUR_MS,
+    6 * HOUR_MS,
+    12 * HOUR_MS,
+    DAY_MS,
+    2 * DAY_MS,
+    7 * DAY_MS,
+    14 * DAY_MS,
+];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Unit of the timestamps and axis values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// Seconds, e.g. Unix time from an RTC
+    Seconds,
+    /// Milliseconds, e.g. a system tick counter
+    Milliseconds,
+}
+
+impl TimestampUnit {
+    /// Length of one unit in milliseconds
+    const fn millis(self) -> i64 {
+        match self {
+            Self::Seconds => SECOND_MS,
+            Self::Milliseconds => 1,
+        }
+    }
+}
+
+/// Tick generator placing ticks on whole calendar and clock intervals
+///
+/// Works on axis values relative to `origin`, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeTickGenerator {
+    unit: TimestampUnit,
+    /// Timestamp of axis value zero, in `unit`
+    origin: u64,
+    /// Offset from UTC applied to tick alignment and labels
+    utc_offset_ms: i64,
+    preferred_count: usize,
+}
+
+impl TimeTickGenerator {
+    /// Create a generator for values measured in `unit` from `origin`
+    pub const fn new(unit: TimestampUnit, origin: u64) -> Self {
+        Self {
+            unit,
+            origin,
+            utc_offset_ms: 0,
+            preferred_count: 5,
+        }
+    }
+
+    /// Show local time at `minutes` east of UTC instead of UTC
+    pub fn with_utc_offset(mut self, minutes: i32) -> Self {
+        self.utc_offset_ms = minutes as i64 * MINUTE_MS;
+        self
+    }
+
+    /// Get the timestamp unit
+    pub fn unit(&self) -> TimestampUnit {
+        self.unit
+    }
+
+    /// Get the timestamp of axis value zero
+    pub fn origin(&self) -> u64 {
+        self.origin
+    }
+
+    /// Local time in milliseconds for an axis value
+    fn local_millis(&self, value: f32) -> i64 {
+        let offset = value * self.unit.millis() as f32;
+        let offset = if offset >= 0.0 {
+            offset + 0.5
+        } else {
+            offset - 0.5
+        };
+        (self.origin as i64).saturating_mul(self.unit.millis()) + offset as i64 + self.utc_offset_ms
+    }
+
+    /// Axis value for a local time in milliseconds
+    fn value_at(&self, local_millis: i64) -> f32 {
+        let offset = local_millis
+            - self.utc_offset_ms
+            - (self.origin as i64).saturating_mul(self.unit.millis());
+        offset as f32 / self.unit.millis() as f32
+    }
+
+    /// Smallest interval giving at most `target` ticks between `start` and `end`
+    fn interval(&self, start: i64, end: i64, target: usize) -> i64 {
+        let tick_count =
+            |interval: i64| (end.div_euclid(interval) - (start - 1).div_euclid(interval)) as usize;
+
+        TICK_INTERVALS_MS
+            .iter()
+            .copied()
+            .filter(|&interval| interval >= self.unit.millis())
+            .find(|&interval| tick_count(interval) <= target)
+            .unwrap_or_else(|| {
+                // Beyond two weeks, step in multiples of 30 days
+                let month = 30 * DAY_MS;
+                let months = (end - start) / month / target.max(1) as i64 + 1;
+                month * months
+            })
+    }
+}
+
+impl TickGenerator<f32> for TimeTickGenerator {
+    fn generate_ticks(&self, min: f32, max: f32, max_ticks: usize) -> Vec<Tick<f32>, 32> {
+        let mut ticks = Vec::new();
+        if !min.is_finite() || !max.is_finite() || max < min {
+            return ticks;
+        }
+
+        let start = self.local_millis(min);
+        let end = self.local_millis(max);
+        let target = self.preferred_count.min(max_ticks).clamp(1, 32);
+        let interval = self.interval(start, end, target);
+
+        let mut tick = (start - 1).div_euclid(interval) * interval + interval;
+        while tick <= end && ticks.len() < target {
+            let label = format_time(tick, interval);
+            let _ = ticks.push(Tick::major(self.value_at(tick), &label));
+            tick += interval;
+        }
+        ticks
+    }
+
+    fn preferred_tick_count(&self) -> usize {
+        self.preferred_count
+    }
+
+    fn set_preferred_tick_count(&mut self, count: usize) {
+        self.preferred_count = count.clamp(2, 20);
+    }
+}
+
+/// Label for a tick at `local_millis`, with precision matching `interval`
+fn format_time(local_millis: i64, interval: i64) -> String<16> {
+    let mut label = String::new();
+    let day = local_millis.div_euclid(DAY_MS);
+    let time = local_millis.rem_euclid(DAY_MS);
+
+    if interval >= DAY_MS {
+        let (month, day_of_month) = month_and_day(day);
+        let _ = label.push_str(MONTHS[month as usize - 1]);
+        let _ = label.push(' ');
+        push_number(&mut label, day_of_month, 1);
+    } else if interval < SECOND_MS {
+        push_number(&mut label, (time % MINUTE_MS / SECOND_MS) as u32, 2);
+        let _ = label.push('.');
+        push_number(&mut label, (time % SECOND_MS) as u32, 3);
+    } else {
+        push_number(&mut label, (time / HOUR_MS) as u32, 2);
+        let _ = label.push(':');
+        push_number(&mut label, (time % HOUR_MS / MINUTE_MS) as u32, 2);
+        if interval < MINUTE_MS {
+            let _ = label.push(':');
+            push_number(&mut label, (time % MINUTE_MS / SECOND_MS) as u32, 2);
+        }
+    }
+    label
+}
+
+/// Month (1-12) and day of month for a count of days since 1970-01-01
+fn month_and_day(days: i64) -> (u32, u32) {
+    // Civil calendar from days, with years starting in March
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    (month as u32, day as u32)
+}
+
+/// Append `value` zero-padded to at least `width` digits
+fn push_number(out: &mut String<16>, mut value: u32, width: usize) {
+    let mut digits = [0u8; 10];
+    let mut count = 0;
+    while value > 0 || count < width.max(1) {
+        digits[count] = b'0' + (value % 10) as u8;
+        value /= 10;
+        count += 1;
+    }
+    for &digit in digits[..count].iter().rev() {
+        let _ = out.push(digit as char);
+    }
+}
+
+/// Axis over a range of timestamps
+///
+/// Draws like a [`LinearAxis`] whose ticks come from a [`TimeTickGenerator`],
+/// and converts into one for use with chart types.
+#[derive(Debug, Clone)]
+pub struct TimeAxis<C: PixelColor> {
+    /// Axis drawn with the time ticks as explicit ticks
+    axis: LinearAxis<f32, C>,
+    tick_generator: TimeTickGenerator,
+}
+
+impl<C> TimeAxis<C>
+where
+    C: PixelColor + From<Rgb565>,
+{
+    /// Create an axis from `start` to `end`, both timestamps in `unit`
+    pub fn new(
+        start: u64,
+        end: u64,
+        unit: TimestampUnit,
+        orientation: AxisOrientation,
+        position: AxisPosition,
+    ) -> Self {
+        let span = end.saturating_sub(start) as f32;
+        let mut axis = Self {
+            axis: LinearAxis::new(0.0, span, orientation, position),
+            tick_generator: TimeTickGenerator::new(unit, start),
+        };
+        axis.refresh_ticks();
+        axis
+    }
+
+    /// Show local time at `minutes` east of UTC instead of UTC
+    pub fn with_utc_offset(mut self, minutes: i32) -> Self {
+        self.tick_generator = self.tick_generator.with_utc_offset(minutes);
+        self.refresh_ticks();
+        self
+    }
+
+    /// Set the preferred number of ticks
+    pub fn with_tick_count(mut self, count: usize) -> Self {
+        self.tick_generator.set_preferred_tick_count(count);
+        self.refresh_ticks();
+        self
+    }
+
+    /// Set the range of the axis, as timestamps in the axis unit
+    pub fn with_time_range(mut self, start: u64, end: u64) -> Self {
+        self.tick_generator.origin = start;
+        self.axis = self.axis.with_range(0.0, end.saturating_sub(start) as f32);
+        self.refresh_ticks();
+        self
+    }
+
+    /// Set the axis style
+    pub fn with_style(mut self, style: AxisStyle<C>) -> Self {
+        self.axis = self.axis.with_style(style);
+        self
+    }
+
+    /// Enable or disable the axis line
+    pub fn show_line(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_line(show);
+        self
+    }
+
+    /// Enable or disable tick marks
+    pub fn show_ticks(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_ticks(show);
+        self
+    }
+
+    /// Enable or disable labels
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_labels(show);
+        self
+    }
+
+    /// Enable or disable grid lines
+    pub fn show_grid(mut self, show: bool) -> Self {
+        self.axis = self.axis.show_grid(show);
+        self
+    }
+
+    /// Draw only grid lines
+    pub fn draw_grid_lines<D>(
+        &self,
+        viewport: Rectangle,
+        chart_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_grid_lines(viewport, chart_area, target)
+    }
+
+    /// Draw only axis line, ticks, and labels (without grid lines)
+    pub fn draw_axis_only<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_axis_only(viewport, target)
+    }
+
+    /// Regenerate the explicit ticks of the inner axis
+    fn refresh_ticks(&mut self) {
+        let ticks = self.tick_generator.generate_ticks(
+            self.axis.min(),
+            self.axis.max(),
+            MAX_EXPLICIT_TICKS,
+        );
+        let positions: Vec<f32, MAX_EXPLICIT_TICKS> = ticks.iter().map(|tick| tick.value).collect();
+        let labels: Vec<&str, MAX_EXPLICIT_TICKS> = ticks
+            .iter()
+            .map(|tick| tick.label.as_deref().unwrap_or(""))
+            .collect();
+
+        if let Ok(explicit_ticks) = ExplicitTicks::new(&positions, &labels) {
+            self.axis = self.axis.clone().with_explicit_ticks(explicit_ticks);
+        }
+    }
+}
+
+impl<C: PixelColor> TimeAxis<C> {
+    /// Axis value for a timestamp in the axis unit
+    pub fn value(&self, timestamp: u64) -> f32 {
+        if timestamp >= self.tick_generator.origin {
+            (timestamp - self.tick_generator.origin) as f32
+        } else {
+            -((self.tick_generator.origin - timestamp) as f32)
+        }
+    }
+
+    /// Axis value for a raw reading of a wrapping 32-bit millisecond counter
+    ///
+    /// The value is the signed distance from the start of the axis modulo
+    /// 2^32, so it stays correct when the counter wraps inside the window, as
+    /// long as the reading is within about 24 days of the axis start. Only
+    /// meaningful for [`TimestampUnit::Milliseconds`] axes whose start was
+    /// read from the same counter (or unwrapped from it).
+    pub fn tick_value(&self, ticks: u32) -> f32 {
+        ticks.wrapping_sub(self.tick_generator.origin as u32) as i32 as f32
+    }
+
+    /// Timestamp in the axis unit for an axis value, saturating at zero
+    pub fn timestamp(&self, value: f32) -> u64 {
+        let offset = if value >= 0.0 {
+            value + 0.5
+        } else {
+            value - 0.5
+        };
+        (self.tick_generator.origin as i64)
+            .saturating_add(offset as i64)
+            .max(0) as u64
+    }
+
+    /// Get the timestamp unit
+    pub fn unit(&self) -> TimestampUnit {
+        self.tick_generator.unit
+    }
+
+    /// Get the linear axis the time axis draws with
+    pub fn linear(&self) -> &LinearAxis<f32, C> {
+        &self.axis
+    }
+
+    /// Convert into the linear axis the time axis draws with
+    pub fn into_linear(self) -> LinearAxis<f32, C> {
+        self.axis
+    }
+}
+
+impl<C: PixelColor> From<TimeAxis<C>> for LinearAxis<f32, C> {
+    fn from(axis: TimeAxis<C>) -> Self {
+        axis.axis
+    }
+}
+
+impl<C> Axis<f32, C> for TimeAxis<C>
+where
+    C: PixelColor + From<Rgb565>,
+{
+    type TickGenerator = TimeTickGenerator;
+    type Style = AxisStyle<C>;
+
+    fn min(&self) -> f32 {
+        self.axis.min()
+    }
+
+    fn max(&self) -> f32 {
+        self.axis.max()
+    }
+
+    fn orientation(&self) -> AxisOrientation {
+        self.axis.orientation()
+    }
+
+    fn position(&self) -> AxisPosition {
+        self.axis.position()
+    }
+
+    fn transform_value(&self, value: f32, viewport: Rectangle) -> i32 {
+        self.axis.transform_value(value, viewport)
+    }
+
+    fn inverse_transform(&self, coordinate: i32, viewport: Rectangle) -> f32 {
+        self.axis.inverse_transform(coordinate, viewport)
+    }
+
+    fn tick_generator(&self) -> &Self::TickGenerator {
+        &self.tick_generator
+    }
+
+    fn style(&self) -> &Self::Style {
+        self.axis.style()
+    }
+
+    fn draw<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw(viewport, target)
+    }
+
+    fn required_space(&self) -> u32 {
+        self.axis.required_space()
+    }
+
+    fn draw_labels<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.axis.draw_labels(viewport, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-03-05 12:30:00 UTC
+    const MARCH_5_1230: u64 = 1_709_641_800;
+
+    fn labels(ticks: &[Tick<f32>]) -> Vec<&str, 32> {
+        ticks
+            .iter()
+            .filter_map(|tick| tick.label.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_clock_ticks() {
+        let axis: TimeAxis<Rgb565> = TimeAxis::new(
+            MARCH_5_1230,
+            MARCH_5_1230 + 7200,
+            TimestampUnit::Seconds,
+            AxisOrientation::Horizontal,
+            AxisPosition::Bottom,
+        );
+        let ticks = axis.tick_generator().generate_ticks(0.0, 7200.0, 50);
+        assert_eq!(
+            labels(&ticks),
+            ["12:30", "13:00", "13:30", "14:00", "14:30"]
+        );
+        assert_eq!(ticks[1].value, 1800.0);
+        assert_eq!(axis.value(MARCH_5_1230 + 60), 60.0);
+        assert_eq!(axis.timestamp(60.0), MARCH_5_1230 + 60);
+
+        // The axis handed to charts carries the same ticks
+        let linear: LinearAxis<f32, Rgb565> = axis.with_utc_offset(60).into();
+        assert_eq!(
+            labels(linear.explicit_ticks().unwrap().ticks()),
+            ["13:30", "14:00", "14:30", "15:00", "15:30"]
+        );
+    }
+
+    #[test]
+    fn test_tick_values_across_counter_wrap() {
+        let start = u32::MAX - 999;
+        let axis: TimeAxis<Rgb565> = TimeAxis::new(
+            start as u64,
+            start as u64 + 3000,
+            TimestampUnit::Milliseconds,
+            AxisOrientation::Horizontal,
+            AxisPosition::Bottom,
+        );
+
+        assert_eq!(axis.tick_value(start), 0.0);
+        assert_eq!(axis.tick_value(u32::MAX), 999.0);
+        // The counter wrapped to zero one millisecond later
+        assert_eq!(axis.tick_value(0), 1000.0);
+        assert_eq!(axis.tick_value(2000), 3000.0);
+        assert_eq!(axis.tick_value(start - 500), -500.0);
+
+        // Unwrapped readings land on the same axis values
+        let mut unwrapper = crate::time::TickUnwrapper::new();
+        unwrapper.unwrap(start);
+        assert_eq!(axis.value(unwrapper.unwrap(2000)), 3000.0);
+    }
+
+    #[test]
+    fn test_date_and_millisecond_ticks() {
+        // 2024-03-01 to 2024-03-11
+        let start = MARCH_5_1230 - 4 * 86_400 - 45_000;
+        let generator = TimeTickGenerator::new(TimestampUnit::Seconds, start);
+        let ticks = generator.generate_ticks(0.0, 864_000.0, 50);
+        assert_eq!(
+            labels(&ticks),
+            ["Mar 2", "Mar 4", "Mar 6", "Mar 8", "Mar 10"]
+        );
+
+        let generator = TimeTickGenerator::new(TimestampUnit::Milliseconds, 0);
+        let ticks = generator.generate_ticks(0.0, 2000.0, 50);
+        assert_eq!(
+            labels(&ticks),
+            ["00.000", "00.500", "01.000", "01.500", "02.000"]
+        );
+        let ticks = generator.generate_ticks(0.0, 40_000.0, 50);
+        assert_eq!(
+            labels(&ticks),
+            ["00:00:00", "00:00:10", "00:00:20", "00:00:30", "00:00:40"]
+        );
+    }
+}
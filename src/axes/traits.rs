@@ -2,7 +2,7 @@
 
 use crate::axes::{AxisOrientation, AxisPosition};
 use crate::error::ChartResult;
-use crate::math::{Math, NumericConversion};
+use crate::math::{power_of_ten, split_power_of_ten};
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
 /// Core trait for all axis types
@@ -55,6 +55,21 @@ pub trait Axis<T, C: PixelColor> {
 
     /// Calculate the space required for this axis (labels, ticks, etc.)
     fn required_space(&self) -> u32;
+
+    /// Draw only the tick labels
+    ///
+    /// Axes without labels keep the default, which draws nothing.
+    ///
+    /// # Arguments
+    /// * `viewport` - The area to draw the axis in
+    /// * `target` - The display target to draw to
+    fn draw_labels<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let _ = (viewport, target);
+        Ok(())
+    }
 }
 
 /// Trait for generating tick marks and labels
@@ -138,6 +153,32 @@ pub trait AxisRenderer<C: PixelColor> {
     fn draw_label<D>(&self, text: &str, position: Point, target: &mut D) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>;
+
+    /// Draw a tick label using its label style
+    ///
+    /// The default implementation ignores the style and calls
+    /// [`draw_label`](Self::draw_label).
+    ///
+    /// # Arguments
+    /// * `text` - The text to draw
+    /// * `position` - Position to draw the label
+    /// * `style` - Color and rotation of the label
+    /// * `side` - Side of the plot the axis is drawn on
+    /// * `target` - The display target to draw to
+    fn draw_styled_label<D>(
+        &self,
+        text: &str,
+        position: Point,
+        style: &crate::axes::style::LabelStyle<C>,
+        side: AxisPosition,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let _ = (style, side);
+        self.draw_label(text, position, target)
+    }
 }
 
 /// Represents a single tick mark on an axis
@@ -195,6 +236,33 @@ pub trait AxisValue: Copy + PartialOrd + core::fmt::Display {
     fn format(&self) -> heapless::String<16>;
 }
 
+/// Round a range to a 1, 2 or 5 times power-of-ten step.
+///
+/// Computed in `f32` rather than [`Number`](crate::math::Number) so large
+/// axis ranges don't overflow under fixed-point builds.
+fn nice_step_f32(range: f32) -> f32 {
+    let abs_range = if range < 0.0 { -range } else { range };
+    let (_, magnitude) = split_power_of_ten(abs_range);
+    let normalized = range / power_of_ten(magnitude);
+
+    let nice_normalized = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    if (0..=10).contains(&magnitude) {
+        nice_normalized * power_of_ten(magnitude)
+    } else {
+        // Fallback for extreme magnitudes to prevent overflow
+        nice_normalized
+    }
+}
+
 impl AxisValue for f32 {
     fn to_f32(self) -> f32 {
         self
@@ -205,43 +273,14 @@ impl AxisValue for f32 {
     }
 
     fn nice_step(range: Self) -> Self {
-        let range_num = range.to_number();
-        let abs_range = Math::abs(range_num);
-        let magnitude = Math::floor(Math::log10(abs_range));
-        let ten = 10.0f32.to_number();
-        let normalized = range_num / Math::pow(ten, magnitude);
-
-        let one = 1.0f32.to_number();
-        let two = 2.0f32.to_number();
-        let five = 5.0f32.to_number();
-        let ten_norm = 10.0f32.to_number();
-
-        let nice_normalized = if normalized <= one {
-            one
-        } else if normalized <= two {
-            two
-        } else if normalized <= five {
-            five
-        } else {
-            ten_norm
-        };
-
-        let result = if magnitude >= 0.0.to_number() && magnitude <= 10.0.to_number() {
-            nice_normalized * Math::pow(ten, magnitude)
-        } else {
-            // Fallback for extreme magnitudes to prevent overflow
-            nice_normalized
-        };
-        f32::from_number(result)
+        nice_step_f32(range)
     }
 
     fn format(&self) -> heapless::String<16> {
         // Simple formatting for no_std
-        let self_num = self.to_number();
-        let fract_part = self_num - Math::floor(self_num);
-        let zero = 0.0f32.to_number();
+        let fract_part = *self - (*self as i32) as f32;
 
-        if fract_part == zero {
+        if fract_part == 0.0 {
             // Integer formatting
             let int_val = *self as i32;
             let mut result = heapless::String::new();
@@ -293,41 +332,18 @@ impl AxisValue for i32 {
     }
 
     fn from_f32(value: f32) -> Self {
-        let value_num = value.to_number();
-        let rounded = Math::floor(value_num + 0.5f32.to_number());
-        f32::from_number(rounded) as i32
+        // Round in f32; values beyond the fixed-point range would saturate
+        let shifted = value + 0.5;
+        let truncated = shifted as i32;
+        if (truncated as f32) > shifted {
+            truncated.saturating_sub(1)
+        } else {
+            truncated
+        }
     }
 
     fn nice_step(range: Self) -> Self {
-        let range_f32 = range.abs() as f32;
-        let range_num = range_f32.to_number();
-        let magnitude = Math::floor(Math::log10(range_num));
-        let ten = 10.0f32.to_number();
-        let normalized = range_num / Math::pow(ten, magnitude);
-
-        let one = 1.0f32.to_number();
-        let two = 2.0f32.to_number();
-        let five = 5.0f32.to_number();
-        let ten_norm = 10.0f32.to_number();
-
-        let nice_normalized = if normalized <= one {
-            one
-        } else if normalized <= two {
-            two
-        } else if normalized <= five {
-            five
-        } else {
-            ten_norm
-        };
-
-        let result = if magnitude >= 0.0.to_number() && magnitude <= 10.0.to_number() {
-            nice_normalized * Math::pow(ten, magnitude)
-        } else {
-            // Fallback for extreme magnitudes to prevent overflow
-            nice_normalized
-        };
-        let rounded = Math::floor(result + 0.5f32.to_number());
-        f32::from_number(rounded) as i32
+        Self::from_f32(nice_step_f32(range.abs() as f32))
     }
 
     fn format(&self) -> heapless::String<16> {
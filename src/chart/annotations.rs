@@ -0,0 +1,434 @@
+//! Reference lines and shaded bands for cartesian charts.
+//!
+//! An [`AnnotationOverlay`] holds [`ReferenceLine`]s such as alarm limits and
+//! [`ReferenceBand`]s such as a normal operating range, each at a value on the
+//! X or Y axis and with an optional short label. Attached to a
+//! [`LineChart`](crate::chart::LineChart), annotations are drawn above the grid
+//! and below the data, with bands under lines, and clipped to the plot area.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut annotations: AnnotationOverlay<Rgb565> = AnnotationOverlay::new();
+//! annotations.add_band(ReferenceBand::horizontal(20.0, 60.0, Rgb565::CSS_HONEYDEW))?;
+//! annotations.add_line(
+//!     ReferenceLine::horizontal(75.0, LineStyle::dashed(Rgb565::CSS_ORANGE)).with_label("warn"),
+//! )?;
+//! annotations.add_line(
+//!     ReferenceLine::horizontal(90.0, LineStyle::solid(Rgb565::RED)).with_label("critical"),
+//! )?;
+//!
+//! let chart = LineChart::builder()
+//!     .line_color(Rgb565::BLUE)
+//!     .with_annotations(annotations)
+//!     .build()?;
+//! assert_eq!(chart.annotations().map(|annotations| annotations.len()), Some(3));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::data::Point2D;
+use crate::error::{ChartError, ChartResult};
+use crate::grid::{DefaultGridRenderer, GridRenderer};
+use crate::style::LineStyle;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+/// Default number of annotations a line chart can hold
+pub const DEFAULT_ANNOTATION_CAPACITY: usize = 8;
+
+/// Axis an annotation value is measured on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationAxis {
+    /// A value on the X axis, drawn as a vertical line or band
+    X,
+    /// A value on the Y axis, drawn as a horizontal line or band
+    Y,
+}
+
+/// A straight line at a fixed value, e.g. an alarm limit
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLine<C: PixelColor> {
+    /// Axis the value is measured on
+    pub axis: AnnotationAxis,
+    /// Position of the line on its axis
+    pub value: f32,
+    /// Stroke color, width and pattern
+    pub style: LineStyle<C>,
+    /// Short label drawn next to the line
+    pub label: heapless::String<16>,
+    /// Color of the label
+    pub label_color: C,
+}
+
+impl<C: PixelColor> ReferenceLine<C> {
+    /// Create a horizontal line at Y value `value`
+    pub fn horizontal(value: f32, style: LineStyle<C>) -> Self {
+        Self {
+            axis: AnnotationAxis::Y,
+            value,
+            style,
+            label: heapless::String::new(),
+            label_color: style.color,
+        }
+    }
+
+    /// Create a vertical line at X value `value`
+    pub fn vertical(value: f32, style: LineStyle<C>) -> Self {
+        Self {
+            axis: AnnotationAxis::X,
+            ..Self::horizontal(value, style)
+        }
+    }
+
+    /// Label the line, truncating the label to 16 bytes
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = truncated(label);
+        self
+    }
+
+    /// Draw the label in its own color instead of the line color
+    pub fn with_label_color(mut self, color: C) -> Self {
+        self.label_color = color;
+        self
+    }
+}
+
+/// A shaded range between two values, e.g. a normal operating range
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceBand<C: PixelColor> {
+    /// Axis the values are measured on
+    pub axis: AnnotationAxis,
+    /// One edge of the band
+    pub from: f32,
+    /// The other edge of the band
+    pub to: f32,
+    /// Fill color
+    pub color: C,
+    /// Short label drawn inside the band
+    pub label: heapless::String<16>,
+    /// Color of the label
+    pub label_color: C,
+}
+
+impl<C: PixelColor> ReferenceBand<C> {
+    /// Create a horizontal band between Y values `from` and `to`
+    pub fn horizontal(from: f32, to: f32, color: C) -> Self {
+        Self {
+            axis: AnnotationAxis::Y,
+            from,
+            to,
+            color,
+            label: heapless::String::new(),
+            label_color: color,
+        }
+    }
+
+    /// Create a vertical band between X values `from` and `to`
+    pub fn vertical(from: f32, to: f32, color: C) -> Self {
+        Self {
+            axis: AnnotationAxis::X,
+            ..Self::horizontal(from, to, color)
+        }
+    }
+
+    /// Label the band, truncating the label to 16 bytes
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = truncated(label);
+        self
+    }
+
+    /// Draw the label in its own color instead of the fill color
+    pub fn with_label_color(mut self, color: C) -> Self {
+        self.label_color = color;
+        self
+    }
+}
+
+/// A single annotation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation<C: PixelColor> {
+    /// A reference line
+    Line(ReferenceLine<C>),
+    /// A shaded band
+    Band(ReferenceBand<C>),
+}
+
+/// Collection of reference lines and bands drawn behind a chart's data
+#[derive(Debug, Clone)]
+pub struct AnnotationOverlay<C: PixelColor, const N: usize = DEFAULT_ANNOTATION_CAPACITY> {
+    annotations: heapless::Vec<Annotation<C>, N>,
+}
+
+impl<C: PixelColor, const N: usize> AnnotationOverlay<C, N> {
+    /// Create an empty overlay
+    pub const fn new() -> Self {
+        Self {
+            annotations: heapless::Vec::new(),
+        }
+    }
+
+    /// Add an annotation
+    pub fn add(&mut self, annotation: Annotation<C>) -> ChartResult<()> {
+        self.annotations
+            .push(annotation)
+            .map_err(|_| ChartError::MemoryFull)
+    }
+
+    /// Add a reference line
+    pub fn add_line(&mut self, line: ReferenceLine<C>) -> ChartResult<()> {
+        self.add(Annotation::Line(line))
+    }
+
+    /// Add a shaded band
+    pub fn add_band(&mut self, band: ReferenceBand<C>) -> ChartResult<()> {
+        self.add(Annotation::Band(band))
+    }
+
+    /// Remove all annotations
+    pub fn clear(&mut self) {
+        self.annotations.clear();
+    }
+
+    /// Get the registered annotations
+    pub fn annotations(&self) -> &[Annotation<C>] {
+        &self.annotations
+    }
+
+    /// Number of registered annotations
+    pub fn len(&self) -> usize {
+        self.annotations.len()
+    }
+
+    /// Whether no annotations are registered
+    pub fn is_empty(&self) -> bool {
+        self.annotations.is_empty()
+    }
+
+    /// Draw all bands, then all lines, clipped to `plot_area`.
+    ///
+    /// `to_screen` maps data coordinates to the screen the same way the
+    /// chart's data points are mapped; only the coordinate on the annotated
+    /// axis is used.
+    pub fn draw<F, D>(&self, to_screen: F, plot_area: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        F: Fn(Point2D) -> Point,
+        D: DrawTarget<Color = C>,
+    {
+        if self.annotations.is_empty() || plot_area.size.width == 0 || plot_area.size.height == 0 {
+            return Ok(());
+        }
+
+        let mut clipped = target.clipped(&plot_area);
+        let Some(bottom_right) = plot_area.bottom_right() else {
+            return Ok(());
+        };
+        let top_left = plot_area.top_left;
+        let position = |axis: AnnotationAxis, value: f32| match axis {
+            AnnotationAxis::X => to_screen(Point2D::new(value, 0.0)).x,
+            AnnotationAxis::Y => to_screen(Point2D::new(0.0, value)).y,
+        };
+
+        for annotation in self.annotations.iter() {
+            let Annotation::Band(band) = annotation else {
+                continue;
+            };
+            let (a, b) = (position(band.axis, band.from), position(band.axis, band.to));
+            let (low, high) = (a.min(b), a.max(b));
+            let area = match band.axis {
+                AnnotationAxis::X => Rectangle::with_corners(
+                    Point::new(low, top_left.y),
+                    Point::new(high, bottom_right.y),
+                ),
+                AnnotationAxis::Y => Rectangle::with_corners(
+                    Point::new(top_left.x, low),
+                    Point::new(bottom_right.x, high),
+                ),
+            };
+            area.into_styled(PrimitiveStyle::with_fill(band.color))
+                .draw(&mut clipped)
+                .map_err(|_| ChartError::RenderingError)?;
+            // Labels sit in the top left corner of the band
+            draw_label(
+                &band.label,
+                Point::new(
+                    area.top_left.x.max(top_left.x) + 2,
+                    area.top_left.y.max(top_left.y) + 1,
+                ),
+                Baseline::Top,
+                band.label_color,
+                &mut clipped,
+            )?;
+        }
+
+        let renderer = DefaultGridRenderer;
+        for annotation in self.annotations.iter() {
+            let Annotation::Line(line) = annotation else {
+                continue;
+            };
+            let at = position(line.axis, line.value);
+            let (start, end, label_anchor, baseline) = match line.axis {
+                AnnotationAxis::X => (
+                    Point::new(at, top_left.y),
+                    Point::new(at, bottom_right.y),
+                    Point::new(at + 2, top_left.y + 1),
+                    Baseline::Top,
+                ),
+                AnnotationAxis::Y => {
+                    // Labels sit right-aligned just above horizontal lines
+                    let width = line.label.len() as i32 * FONT_6X10.character_size.width as i32;
+                    (
+                        Point::new(top_left.x, at),
+                        Point::new(bottom_right.x, at),
+                        Point::new(bottom_right.x - width - 1, at - 1),
+                        Baseline::Bottom,
+                    )
+                }
+            };
+            renderer.draw_grid_line(start, end, &line.style, &mut clipped)?;
+            draw_label(
+                &line.label,
+                label_anchor,
+                baseline,
+                line.label_color,
+                &mut clipped,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor, const N: usize> Default for AnnotationOverlay<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn truncated(label: &str) -> heapless::String<16> {
+    let mut text = heapless::String::new();
+    for c in label.chars() {
+        if text.push(c).is_err() {
+            break;
+        }
+    }
+    text
+}
+
+fn draw_label<C, D>(
+    label: &str,
+    position: Point,
+    baseline: Baseline,
+    color: C,
+    target: &mut D,
+) -> ChartResult<()>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+{
+    if label.is_empty() {
+        return Ok(());
+    }
+    Text::with_baseline(
+        label,
+        position,
+        MonoTextStyle::new(&FONT_6X10, color),
+        baseline,
+    )
+    .draw(target)
+    .map(|_| ())
+    .map_err(|_| ChartError::RenderingError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+
+    /// Map 0..10 on both axes onto a 11x11 area, Y growing upwards
+    fn to_screen(point: Point2D) -> Point {
+        Point::new(point.x as i32, 10 - point.y as i32)
+    }
+
+    #[test]
+    fn test_lines_drawn_over_bands() {
+        let mut overlay: AnnotationOverlay<Rgb565, 4> = AnnotationOverlay::new();
+        overlay
+            .add_line(ReferenceLine::horizontal(
+                5.0,
+                LineStyle::solid(Rgb565::RED),
+            ))
+            .unwrap();
+        overlay
+            .add_band(ReferenceBand::horizontal(4.0, 6.0, Rgb565::GREEN))
+            .unwrap();
+
+        let plot_area = Rectangle::new(Point::zero(), Size::new(11, 11));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        overlay.draw(to_screen, plot_area, &mut display).unwrap();
+
+        // The band covers rows 4..=6 and the line, added first, is drawn on top
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(0, 4), Size::new(11, 3))
+        );
+        assert_eq!(display.get_pixel(Point::new(3, 5)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(3, 4)), Some(Rgb565::GREEN));
+    }
+
+    #[test]
+    fn test_annotations_clipped_to_plot_area() {
+        let mut overlay: AnnotationOverlay<Rgb565, 4> = AnnotationOverlay::new();
+        overlay
+            .add_line(ReferenceLine::vertical(20.0, LineStyle::solid(Rgb565::RED)))
+            .unwrap();
+        overlay
+            .add_band(ReferenceBand::vertical(8.0, 30.0, Rgb565::GREEN))
+            .unwrap();
+
+        let plot_area = Rectangle::new(Point::zero(), Size::new(11, 11));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        overlay.draw(to_screen, plot_area, &mut display).unwrap();
+
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(8, 0), Size::new(3, 11))
+        );
+    }
+
+    #[test]
+    fn test_overlay_capacity() {
+        let mut overlay: AnnotationOverlay<Rgb565, 1> = AnnotationOverlay::new();
+        overlay
+            .add_line(
+                ReferenceLine::horizontal(1.0, LineStyle::solid(Rgb565::RED)).with_label("limit"),
+            )
+            .unwrap();
+        assert_eq!(
+            overlay.add_band(ReferenceBand::horizontal(0.0, 1.0, Rgb565::GREEN)),
+            Err(ChartError::MemoryFull)
+        );
+        assert!(matches!(
+            overlay.annotations().first(),
+            Some(Annotation::Line(line)) if line.label == "limit"
+        ));
+    }
+}
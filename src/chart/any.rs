@@ -0,0 +1,262 @@
+//! Runtime selection between the built-in chart types.
+//!
+//! [`Chart::draw`] is generic over the draw target, so charts cannot be used
+//! as trait objects. [`AnyChart`] wraps the built-in chart types in an enum
+//! with a single [`Chart`] implementation instead, which lets a panel switch
+//! between line, area, bar and other charts at runtime while sharing one data
+//! series and one code path.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "line", feature = "bar"))]
+//! # {
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut chart: AnyChart<Rgb565> = LineChart::builder().build()?.into();
+//! assert_eq!(chart.kind(), ChartKind::Line);
+//!
+//! // The user picked "bar" from a menu
+//! chart = BarChart::builder().build()?.into();
+//! assert_eq!(chart.kind(), ChartKind::Bar);
+//! # }
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::{Chart, ChartConfig, ConfiguredChart};
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::error::ChartResult;
+use crate::render::{ChartDrawTarget, FallbackTarget};
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+
+#[cfg(feature = "bar")]
+use crate::chart::bar::BarChart;
+#[cfg(feature = "gauge")]
+use crate::chart::gauge::GaugeChart;
+#[cfg(feature = "line")]
+use crate::chart::line::LineChart;
+#[cfg(feature = "pie")]
+use crate::chart::pie::PieChart;
+#[cfg(feature = "scatter")]
+use crate::chart::scatter::ScatterChart;
+
+/// Kind of chart held by an [`AnyChart`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    /// Line chart without area fill
+    Line,
+    /// Line chart with area fill
+    Area,
+    /// Bar chart
+    Bar,
+    /// Pie or donut chart
+    Pie,
+    /// Scatter chart
+    Scatter,
+    /// Gauge chart
+    Gauge,
+}
+
+/// One of the built-in chart types, selected at runtime.
+///
+/// All variants draw from the same [`StaticDataSeries`]. The gauge variant
+/// shows the y value of the last point in the series.
+// Variants are stored inline; boxing would need an allocator
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum AnyChart<C: PixelColor> {
+    /// Line or area chart
+    #[cfg(feature = "line")]
+    Line(LineChart<C>),
+    /// Bar chart
+    #[cfg(feature = "bar")]
+    Bar(BarChart<C>),
+    /// Pie or donut chart
+    #[cfg(feature = "pie")]
+    Pie(PieChart<C>),
+    /// Scatter chart
+    #[cfg(feature = "scatter")]
+    Scatter(ScatterChart<C>),
+    /// Gauge chart
+    #[cfg(feature = "gauge")]
+    Gauge(GaugeChart<C>),
+}
+
+impl<C: PixelColor + 'static> AnyChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Get the kind of the wrapped chart
+    pub fn kind(&self) -> ChartKind {
+        match self {
+            #[cfg(feature = "line")]
+            Self::Line(chart) => {
+                if chart.style().fill_area {
+                    ChartKind::Area
+                } else {
+                    ChartKind::Line
+                }
+            }
+            #[cfg(feature = "bar")]
+            Self::Bar(_) => ChartKind::Bar,
+            #[cfg(feature = "pie")]
+            Self::Pie(_) => ChartKind::Pie,
+            #[cfg(feature = "scatter")]
+            Self::Scatter(_) => ChartKind::Scatter,
+            #[cfg(feature = "gauge")]
+            Self::Gauge(_) => ChartKind::Gauge,
+        }
+    }
+
+    /// Get the configuration of the wrapped chart
+    pub fn config(&self) -> &ChartConfig<C> {
+        match self {
+            #[cfg(feature = "line")]
+            Self::Line(chart) => chart.config(),
+            #[cfg(feature = "bar")]
+            Self::Bar(chart) => chart.config(),
+            #[cfg(feature = "pie")]
+            Self::Pie(chart) => chart.config(),
+            #[cfg(feature = "scatter")]
+            Self::Scatter(chart) => chart.config(),
+            #[cfg(feature = "gauge")]
+            Self::Gauge(chart) => chart.config(),
+        }
+    }
+}
+
+impl<C: PixelColor + 'static> Chart<C> for AnyChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Data = StaticDataSeries<Point2D, 256>;
+    type Config = ChartConfig<C>;
+
+    fn draw<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_accelerated(data, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        match self {
+            #[cfg(feature = "line")]
+            Self::Line(chart) => chart.draw_accelerated(data, config, viewport, target),
+            #[cfg(feature = "bar")]
+            Self::Bar(chart) => chart.draw_accelerated(data, config, viewport, target),
+            #[cfg(feature = "pie")]
+            Self::Pie(chart) => chart.draw_accelerated(data, config, viewport, target),
+            #[cfg(feature = "scatter")]
+            Self::Scatter(chart) => chart.draw_accelerated(data, config, viewport, target),
+            #[cfg(feature = "gauge")]
+            Self::Gauge(chart) => {
+                let mut reading = StaticDataSeries::<Point2D, 1>::new();
+                if let Some(point) = data.as_slice().last() {
+                    reading.push(*point)?;
+                }
+                chart.draw_accelerated(&reading, config, viewport, target)
+            }
+        }
+    }
+}
+
+impl<C: PixelColor + 'static> ConfiguredChart<C> for AnyChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
+#[cfg(feature = "line")]
+impl<C: PixelColor> From<LineChart<C>> for AnyChart<C> {
+    fn from(chart: LineChart<C>) -> Self {
+        Self::Line(chart)
+    }
+}
+
+#[cfg(feature = "bar")]
+impl<C: PixelColor> From<BarChart<C>> for AnyChart<C> {
+    fn from(chart: BarChart<C>) -> Self {
+        Self::Bar(chart)
+    }
+}
+
+#[cfg(feature = "pie")]
+impl<C: PixelColor> From<PieChart<C>> for AnyChart<C> {
+    fn from(chart: PieChart<C>) -> Self {
+        Self::Pie(chart)
+    }
+}
+
+#[cfg(feature = "scatter")]
+impl<C: PixelColor> From<ScatterChart<C>> for AnyChart<C> {
+    fn from(chart: ScatterChart<C>) -> Self {
+        Self::Scatter(chart)
+    }
+}
+
+#[cfg(feature = "gauge")]
+impl<C: PixelColor> From<GaugeChart<C>> for AnyChart<C> {
+    fn from(chart: GaugeChart<C>) -> Self {
+        Self::Gauge(chart)
+    }
+}
+
+#[cfg(all(test, feature = "line", feature = "bar"))]
+mod tests {
+    use super::*;
+    use crate::chart::traits::ChartBuilder;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+
+    #[test]
+    fn test_switch_chart_kind_at_runtime() {
+        let mut data = StaticDataSeries::<Point2D, 256>::new();
+        for (x, y) in [(0.0, 5.0), (1.0, 20.0), (2.0, 12.0), (3.0, 30.0)] {
+            data.push(Point2D::new(x, y)).unwrap();
+        }
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+
+        let charts: [AnyChart<Rgb565>; 3] = [
+            LineChart::builder().build().unwrap().into(),
+            LineChart::builder()
+                .fill_area(Rgb565::CSS_LIGHT_BLUE)
+                .build()
+                .unwrap()
+                .into(),
+            BarChart::builder().build().unwrap().into(),
+        ];
+        let kinds = [ChartKind::Line, ChartKind::Area, ChartKind::Bar];
+
+        for (chart, kind) in charts.iter().zip(kinds) {
+            assert_eq!(chart.kind(), kind);
+
+            let mut display = MockDisplay::<Rgb565>::new();
+            display.set_allow_overdraw(true);
+            display.set_allow_out_of_bounds_drawing(true);
+            chart
+                .draw(&data, chart.config(), viewport, &mut display)
+                .unwrap();
+            assert!(display.affected_area().size.width > 0);
+        }
+    }
+}
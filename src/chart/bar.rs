@@ -83,14 +83,18 @@
 //! # Ok::<(), embedded_charts::error::ChartError>(())
 //! ```
 
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig};
+use crate::axes::{Axis, AxisOrientation, AxisPosition, CategoryAxis, CategoryScale};
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig, TitleStyle};
 use crate::data::{DataBounds, DataPoint, DataSeries};
 use crate::error::{ChartError, ChartResult};
-use crate::style::BorderStyle;
+use crate::render::ChartRenderer;
+use crate::style::{BorderStyle, FillStyle, LineStyle, MonochromeStyle, NumberLocale};
 use embedded_graphics::{
     draw_target::DrawTarget,
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
     prelude::*,
     primitives::{PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 use heapless::Vec;
 
@@ -148,6 +152,38 @@ pub struct BarChart<C: PixelColor> {
     style: BarChartStyle<C>,
     config: ChartConfig<C>,
     orientation: BarOrientation,
+    placement: BarPlacement,
+    baseline: Option<f32>,
+    negative_color: Option<C>,
+    category_axis: Option<CategoryAxis<C>>,
+    value_labels: Option<BarValueLabels<C>>,
+    monochrome: Option<MonochromeStyle<C>>,
+}
+
+/// Value labels drawn at the end of each bar.
+///
+/// Labels sit above vertical bars and to the right of horizontal ones. Bars
+/// extending below the [baseline](BarChart::set_baseline) get their label on
+/// the opposite side. Values use the chart's [`NumberLocale`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BarValueLabels<C: PixelColor> {
+    /// Text color
+    pub color: C,
+    /// Number of decimal places
+    pub decimals: u8,
+    /// Gap in pixels between the end of the bar and its label
+    pub gap: u32,
+}
+
+impl<C: PixelColor> BarValueLabels<C> {
+    /// Whole-number labels in `color`, two pixels off the bar end
+    pub const fn new(color: C) -> Self {
+        Self {
+            color,
+            decimals: 0,
+            gap: 2,
+        }
+    }
 }
 
 /// Style configuration for bar charts.
@@ -296,6 +332,36 @@ pub enum BarWidth {
     Auto,
 }
 
+/// How bars are positioned along the category direction.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+/// use embedded_graphics::pixelcolor::Rgb565;
+///
+/// // Hourly buckets with gaps: bars at x = 0, 3, 4 and 9 keep their spacing
+/// let chart: BarChart<Rgb565> = BarChart::builder()
+///     .placement(BarPlacement::Linear)
+///     .bar_width(BarWidth::Fixed(6))
+///     .build()?;
+/// # Ok::<(), embedded_charts::error::ChartError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarPlacement {
+    /// Bars follow each other in data order, separated by the style spacing
+    #[default]
+    Sequential,
+    /// Each bar is centered in an equally sized category slot, see [`CategoryScale`]
+    Category,
+    /// Each bar is centered on its x-value on a linear axis.
+    ///
+    /// Use [`BarChart::linear_x_range`] for the matching axis range. With
+    /// [`BarWidth::Auto`] and [`BarWidth::Percentage`] the width is derived
+    /// from the smallest gap between x-values.
+    Linear,
+}
+
 impl<C: PixelColor> BarChart<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -323,6 +389,12 @@ where
             style: BarChartStyle::default(),
             config: ChartConfig::default(),
             orientation: BarOrientation::Vertical,
+            placement: BarPlacement::Sequential,
+            baseline: None,
+            negative_color: None,
+            category_axis: None,
+            value_labels: None,
+            monochrome: None,
         }
     }
 
@@ -421,6 +493,174 @@ where
         self.orientation
     }
 
+    /// Enable or disable category alignment.
+    ///
+    /// When enabled, every bar is centered in an equally sized category slot
+    /// computed by [`CategoryScale`], the same mapping used by categorical axis
+    /// ticks. Horizontal bars are laid out bottom-up to match a vertical axis.
+    pub fn set_category_aligned(&mut self, aligned: bool) {
+        self.placement = if aligned {
+            BarPlacement::Category
+        } else {
+            BarPlacement::Sequential
+        };
+    }
+
+    /// Check whether bars are centered in category slots
+    pub fn is_category_aligned(&self) -> bool {
+        self.placement == BarPlacement::Category
+    }
+
+    /// Set how bars are positioned along the category direction
+    pub fn set_placement(&mut self, placement: BarPlacement) {
+        self.placement = placement;
+    }
+
+    /// Get how bars are positioned along the category direction
+    pub fn placement(&self) -> BarPlacement {
+        self.placement
+    }
+
+    /// Set the value bars are measured from.
+    ///
+    /// With a baseline, each bar spans from the baseline to its value, so values
+    /// below the baseline produce bars extending in the negative direction.
+    /// `None` measures from zero when the data has negative values and
+    /// otherwise stretches the bars over the data range.
+    pub fn set_baseline(&mut self, baseline: Option<f32>) {
+        self.baseline = baseline;
+    }
+
+    /// Get the value bars are measured from.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Set the color of bars below the baseline.
+    ///
+    /// `None` draws them from the bar colors like the other bars.
+    pub fn set_negative_color(&mut self, color: Option<C>) {
+        self.negative_color = color;
+    }
+
+    /// Get the color of bars below the baseline.
+    pub fn negative_color(&self) -> Option<C> {
+        self.negative_color
+    }
+
+    /// Get the monochrome style the chart was built for, if any.
+    pub fn monochrome(&self) -> Option<&MonochromeStyle<C>> {
+        self.monochrome.as_ref()
+    }
+
+    /// Baseline used for data with `data_bounds`
+    ///
+    /// Signed data without a configured baseline is measured from zero.
+    fn effective_baseline(&self, data_bounds: &DataBounds<f32, f32>) -> Option<f32> {
+        self.baseline
+            .or_else(|| (data_bounds.min_y < 0.0).then_some(0.0))
+    }
+
+    /// Set or clear the category axis.
+    ///
+    /// Setting an axis centers the bars in its category slots, one bar per
+    /// category in data order; points beyond the last category are not drawn.
+    /// The axis is moved to run along the bars' category direction: a left
+    /// axis becomes a bottom axis for vertical bars, a bottom axis becomes a
+    /// left axis for horizontal bars, and likewise for top and right.
+    pub fn set_category_axis(&mut self, axis: Option<CategoryAxis<C>>) {
+        self.category_axis = axis.map(|axis| {
+            self.placement = BarPlacement::Category;
+            Self::place_category_axis(axis, self.orientation)
+        });
+    }
+
+    /// Get the category axis
+    pub fn category_axis(&self) -> Option<&CategoryAxis<C>> {
+        self.category_axis.as_ref()
+    }
+
+    /// Set or clear the value labels at the bar ends
+    pub fn set_value_labels(&mut self, labels: Option<BarValueLabels<C>>) {
+        self.value_labels = labels;
+    }
+
+    /// Get the value label configuration
+    pub fn value_labels(&self) -> Option<&BarValueLabels<C>> {
+        self.value_labels.as_ref()
+    }
+
+    /// Move `axis` to the side of the plot matching the bar orientation
+    fn place_category_axis(axis: CategoryAxis<C>, orientation: BarOrientation) -> CategoryAxis<C> {
+        let position = match (orientation, axis.position()) {
+            (BarOrientation::Vertical, AxisPosition::Left) => AxisPosition::Bottom,
+            (BarOrientation::Vertical, AxisPosition::Right) => AxisPosition::Top,
+            (BarOrientation::Horizontal, AxisPosition::Bottom) => AxisPosition::Left,
+            (BarOrientation::Horizontal, AxisPosition::Top) => AxisPosition::Right,
+            (_, position) => position,
+        };
+        axis.with_position(position)
+    }
+
+    /// Get the value range covered by the bars for `data`.
+    ///
+    /// The range includes the baseline, so a value axis built from it lines up
+    /// with the bars.
+    pub fn value_range(
+        &self,
+        data: &crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>,
+    ) -> ChartResult<(f32, f32)> {
+        let bounds = data.bounds()?;
+        Ok(self.value_span(&bounds))
+    }
+
+    /// Value range mapped onto the draw area
+    fn value_span(&self, data_bounds: &DataBounds<f32, f32>) -> (f32, f32) {
+        match self.effective_baseline(data_bounds) {
+            Some(base) => (data_bounds.min_y.min(base), data_bounds.max_y.max(base)),
+            None => (data_bounds.min_y, data_bounds.max_y),
+        }
+    }
+
+    /// Get the category scale matching the bar layout for `data`.
+    ///
+    /// Use the returned scale's [`CategoryScale::range`] and
+    /// [`CategoryScale::tick_generator`] to build an axis whose ticks sit under
+    /// the bar centers of a category-aligned chart. Any series works, so the
+    /// scale can be built from the same data the chart is drawn with.
+    pub fn category_scale<S: DataSeries>(&self, data: &S) -> CategoryScale {
+        CategoryScale::new(data.len())
+    }
+
+    /// Get the x range that [`BarPlacement::Linear`] maps onto the draw area.
+    ///
+    /// The data range is padded by half of the smallest gap between x-values
+    /// on both sides, so the outer bars fit. Build the x-axis over this range
+    /// to put its ticks under the bar centers.
+    pub fn linear_x_range(
+        &self,
+        data: &crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>,
+    ) -> ChartResult<(f32, f32)> {
+        let bounds = data.bounds()?;
+        let half_unit = Self::smallest_x_gap(data) / 2.0;
+        Ok((bounds.min_x - half_unit, bounds.max_x + half_unit))
+    }
+
+    /// Smallest distance between distinct x-values, or 1 with fewer than two
+    fn smallest_x_gap(
+        data: &crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>,
+    ) -> f32 {
+        let mut xs: Vec<f32, 256> = data.iter().map(|point| point.x()).collect();
+        xs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        xs.windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|gap| *gap > 0.0)
+            .fold(None, |smallest: Option<f32>, gap| {
+                Some(smallest.map_or(gap, |smallest| smallest.min(gap)))
+            })
+            .unwrap_or(1.0)
+    }
+
     /// Calculate bar dimensions and positions
     fn calculate_bar_layout(
         &self,
@@ -436,21 +676,36 @@ where
             return Ok(bars);
         }
 
+        let available_width = match self.orientation {
+            BarOrientation::Vertical => draw_area.size.width,
+            BarOrientation::Horizontal => draw_area.size.height,
+        };
+        let categories = self
+            .category_axis
+            .as_ref()
+            .map_or(CategoryScale::new(data_count), CategoryAxis::scale);
+
+        // Linear placement maps x-values onto the draw area; the slot is the
+        // width of the smallest x gap
+        let (x_min, x_max) = match self.placement {
+            BarPlacement::Linear => self.linear_x_range(data)?,
+            _ => (0.0, 0.0),
+        };
+        let slot_size = match self.placement {
+            BarPlacement::Category => categories.slot_size(available_width),
+            BarPlacement::Linear if x_max > x_min => {
+                (Self::smallest_x_gap(data) / (x_max - x_min) * available_width as f32) as u32
+            }
+            _ => available_width,
+        };
+        let slotted = self.placement != BarPlacement::Sequential;
+
         // Calculate bar width
         let bar_width = match self.style.bar_width {
             BarWidth::Fixed(width) => width,
-            BarWidth::Percentage(pct) => {
-                let available_width = match self.orientation {
-                    BarOrientation::Vertical => draw_area.size.width,
-                    BarOrientation::Horizontal => draw_area.size.height,
-                };
-                (available_width as f32 * pct.clamp(0.0, 1.0)) as u32
-            }
+            BarWidth::Percentage(pct) => (slot_size as f32 * pct.clamp(0.0, 1.0)) as u32,
+            BarWidth::Auto if slotted => slot_size.saturating_sub(self.style.spacing).max(1),
             BarWidth::Auto => {
-                let available_width = match self.orientation {
-                    BarOrientation::Vertical => draw_area.size.width,
-                    BarOrientation::Horizontal => draw_area.size.height,
-                };
                 let total_spacing = self.style.spacing * (data_count as u32).saturating_sub(1);
                 let calculated_width =
                     (available_width.saturating_sub(total_spacing)) / data_count as u32;
@@ -459,12 +714,74 @@ where
             }
         };
 
+        // Value mapping used when bars are measured from a baseline
+        let baseline = self.effective_baseline(data_bounds);
+        let base = baseline.unwrap_or(0.0);
+        let (range_min, range_max) = self.value_span(data_bounds);
+        let normalize = |value: f32| {
+            if range_max > range_min {
+                (value - range_min) / (range_max - range_min)
+            } else {
+                0.5
+            }
+        };
+        // Leading edge and length of the span between two screen positions
+        let span_between = |a: i32, b: i32| (a.min(b), (a - b).unsigned_abs().max(1));
+
         // Calculate positions and sizes for each bar
         let mut current_pos = 0;
-        for point in data.iter() {
+        let bar_count = match self.category_axis {
+            Some(ref axis) => data_count.min(axis.count()),
+            None => data_count,
+        };
+        for (index, point) in data.iter().enumerate().take(bar_count) {
+            // Leading edge of the bar along the category direction
+            let category_start = |axis_start: i32, orientation: AxisOrientation| {
+                let center = match self.placement {
+                    BarPlacement::Sequential => return axis_start + current_pos as i32,
+                    BarPlacement::Category => categories.center(index, draw_area, orientation),
+                    BarPlacement::Linear => {
+                        let norm = if x_max > x_min {
+                            (point.x() - x_min) / (x_max - x_min)
+                        } else {
+                            0.5
+                        };
+                        match orientation {
+                            AxisOrientation::Horizontal => {
+                                axis_start + (norm * draw_area.size.width as f32) as i32
+                            }
+                            // Vertical axes grow upward
+                            AxisOrientation::Vertical => {
+                                let extent = draw_area.size.height as f32;
+                                axis_start + extent as i32 - (norm * extent) as i32
+                            }
+                        }
+                    }
+                };
+                center - bar_width as i32 / 2
+            };
+
             let bar_rect = match self.orientation {
+                BarOrientation::Vertical if baseline.is_some() => {
+                    let x = category_start(draw_area.top_left.x, AxisOrientation::Horizontal);
+                    let bottom = draw_area.top_left.y + draw_area.size.height as i32;
+                    let height = draw_area.size.height as f32;
+                    let to_screen = |value: f32| bottom - (normalize(value) * height) as i32;
+
+                    let (top, bar_height) = span_between(to_screen(base), to_screen(point.y()));
+                    Rectangle::new(Point::new(x, top), Size::new(bar_width, bar_height))
+                }
+                BarOrientation::Horizontal if baseline.is_some() => {
+                    let y = category_start(draw_area.top_left.y, AxisOrientation::Vertical);
+                    let width = draw_area.size.width as f32;
+                    let to_screen =
+                        |value: f32| draw_area.top_left.x + (normalize(value) * width) as i32;
+
+                    let (left, bar_length) = span_between(to_screen(base), to_screen(point.y()));
+                    Rectangle::new(Point::new(left, y), Size::new(bar_length, bar_width))
+                }
                 BarOrientation::Vertical => {
-                    let x = draw_area.top_left.x + current_pos as i32;
+                    let x = category_start(draw_area.top_left.x, AxisOrientation::Horizontal);
                     let data_y: f32 = point.y();
                     let min_y: f32 = data_bounds.min_y;
                     let max_y: f32 = data_bounds.max_y;
@@ -483,7 +800,7 @@ where
                     Rectangle::new(Point::new(x, y), Size::new(bar_width, bar_height))
                 }
                 BarOrientation::Horizontal => {
-                    let y = draw_area.top_left.y + current_pos as i32;
+                    let y = category_start(draw_area.top_left.y, AxisOrientation::Vertical);
                     let data_y: f32 = point.y();
                     let min_y: f32 = data_bounds.min_y;
                     let max_y: f32 = data_bounds.max_y;
@@ -516,23 +833,36 @@ where
         &self,
         bar_rect: Rectangle,
         color_index: usize,
+        negative: bool,
         target: &mut D,
     ) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>,
     {
-        // Get bar color (cycle through available colors)
-        let bar_color = if !self.style.bar_colors.is_empty() {
-            self.style.bar_colors[color_index % self.style.bar_colors.len()]
+        if let Some(ref monochrome) = self.monochrome {
+            // Bars are told apart by pattern; with a negative color configured,
+            // bars below the baseline swap ink and paper
+            let mut fill = monochrome.series_fill(color_index);
+            if negative && self.negative_color.is_some() {
+                core::mem::swap(&mut fill.foreground, &mut fill.background);
+            }
+            ChartRenderer::draw_filled_rectangle(bar_rect, &FillStyle::pattern(fill), target)?;
         } else {
-            return Err(ChartError::InvalidConfiguration);
-        };
+            // Get bar color (cycle through available colors)
+            let bar_color = match self.negative_color {
+                Some(color) if negative => color,
+                _ if !self.style.bar_colors.is_empty() => {
+                    self.style.bar_colors[color_index % self.style.bar_colors.len()]
+                }
+                _ => return Err(ChartError::InvalidConfiguration),
+            };
 
-        // Draw filled bar directly
-        bar_rect
-            .into_styled(PrimitiveStyle::with_fill(bar_color))
-            .draw(target)
-            .map_err(|_| ChartError::RenderingError)?;
+            // Draw filled bar directly
+            bar_rect
+                .into_styled(PrimitiveStyle::with_fill(bar_color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
 
         // Draw border if specified
         if let Some(border) = &self.style.border {
@@ -549,6 +879,64 @@ where
 
         Ok(())
     }
+
+    /// Draw the value label at the end of a bar
+    fn draw_value_label<D>(
+        &self,
+        bar_rect: Rectangle,
+        value: f32,
+        below_baseline: bool,
+        labels: &BarValueLabels<C>,
+        locale: NumberLocale,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let gap = labels.gap as i32;
+        let center = bar_rect.center();
+        let (anchor, alignment, baseline) = match (self.orientation, below_baseline) {
+            (BarOrientation::Vertical, false) => (
+                Point::new(center.x, bar_rect.top_left.y - gap),
+                Alignment::Center,
+                Baseline::Bottom,
+            ),
+            (BarOrientation::Vertical, true) => (
+                Point::new(
+                    center.x,
+                    bar_rect.top_left.y + bar_rect.size.height as i32 + gap,
+                ),
+                Alignment::Center,
+                Baseline::Top,
+            ),
+            (BarOrientation::Horizontal, false) => (
+                Point::new(
+                    bar_rect.top_left.x + bar_rect.size.width as i32 + gap,
+                    center.y,
+                ),
+                Alignment::Left,
+                Baseline::Middle,
+            ),
+            (BarOrientation::Horizontal, true) => (
+                Point::new(bar_rect.top_left.x - gap, center.y),
+                Alignment::Right,
+                Baseline::Middle,
+            ),
+        };
+
+        Text::with_text_style(
+            &locale.format::<16>(value, labels.decimals as usize),
+            anchor,
+            MonoTextStyle::new(&FONT_6X10, labels.color),
+            TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(baseline)
+                .build(),
+        )
+        .draw(target)
+        .map_err(|_| ChartError::RenderingError)?;
+        Ok(())
+    }
 }
 
 impl<C: PixelColor> Default for BarChart<C>
@@ -582,16 +970,15 @@ where
         <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
     {
         if data.is_empty() {
-            return Err(ChartError::InsufficientData);
+            return config.fallback(ChartError::InsufficientData, viewport, target);
         }
 
         // Draw background if specified
-        if let Some(bg_color) = config.background_color {
-            Rectangle::new(viewport.top_left, viewport.size)
-                .into_styled(PrimitiveStyle::with_fill(bg_color))
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-        }
+        config.draw_background(viewport, target)?;
+
+        // The title takes a band at the top; everything else is laid out below it
+        config.draw_title(viewport, target)?;
+        let viewport = config.content_area(viewport);
 
         // Calculate data bounds
         let data_bounds = data.bounds()?;
@@ -599,15 +986,44 @@ where
         // Calculate bar layout
         let bars = self.calculate_bar_layout(data, &data_bounds, viewport)?;
 
-        // Draw each bar
-        for (index, bar_rect) in bars.iter().enumerate() {
-            self.draw_bar(*bar_rect, index, target)?;
+        // Draw each bar, then its value label
+        let baseline = self.effective_baseline(&data_bounds);
+        let below_baseline = |value: f32| baseline.is_some_and(|base| value < base);
+        for (index, (bar_rect, point)) in bars.iter().zip(data.iter()).enumerate() {
+            self.draw_bar(*bar_rect, index, below_baseline(point.y()), target)?;
+        }
+        if let Some(ref labels) = self.value_labels {
+            for (bar_rect, point) in bars.iter().zip(data.iter()) {
+                let value = point.y();
+                self.draw_value_label(
+                    *bar_rect,
+                    value,
+                    below_baseline(value),
+                    labels,
+                    config.locale,
+                    target,
+                )?;
+            }
+        }
+
+        // The category axis goes on top, next to the bar ends it labels
+        if let Some(ref axis) = self.category_axis {
+            axis.draw_axis_only(self.config.margins.apply_to(viewport), target)?;
         }
 
         Ok(())
     }
 }
 
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for BarChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
 impl<C: PixelColor> Default for BarChartStyle<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -635,6 +1051,12 @@ pub struct BarChartBuilder<C: PixelColor> {
     style: BarChartStyle<C>,
     config: ChartConfig<C>,
     orientation: BarOrientation,
+    placement: BarPlacement,
+    baseline: Option<f32>,
+    negative_color: Option<C>,
+    category_axis: Option<ChartResult<CategoryAxis<C>>>,
+    value_labels: Option<BarValueLabels<C>>,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 impl<C: PixelColor> BarChartBuilder<C>
@@ -647,6 +1069,12 @@ where
             style: BarChartStyle::default(),
             config: ChartConfig::default(),
             orientation: BarOrientation::Vertical,
+            placement: BarPlacement::Sequential,
+            baseline: None,
+            negative_color: None,
+            category_axis: None,
+            value_labels: None,
+            monochrome: None,
         }
     }
 
@@ -656,6 +1084,70 @@ where
         self
     }
 
+    /// Center bars in category slots so they line up with categorical axis ticks
+    pub fn category_aligned(mut self, aligned: bool) -> Self {
+        self.placement = if aligned {
+            BarPlacement::Category
+        } else {
+            BarPlacement::Sequential
+        };
+        self
+    }
+
+    /// Set how bars are positioned along the category direction
+    pub fn placement(mut self, placement: BarPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Measure bars from `value` instead of the bottom of the data range
+    ///
+    /// Without a baseline, data with negative values is measured from zero.
+    pub fn baseline(mut self, value: f32) -> Self {
+        self.baseline = Some(value);
+        self
+    }
+
+    /// Draw bars below the baseline in `color`
+    pub fn negative_color(mut self, color: C) -> Self {
+        self.negative_color = Some(color);
+        self
+    }
+
+    /// Draw for a monochrome or grayscale display, such as e-paper
+    ///
+    /// Bars are filled with the fill patterns of `style` instead of the bar
+    /// colors and outlined in ink, unless a border was set. The background is
+    /// set to paper and value labels to ink when the chart is built.
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.monochrome = Some(style);
+        self
+    }
+
+    /// Label the bars with category names, one per bar in data order
+    ///
+    /// The labels go under vertical bars and beside horizontal ones. See
+    /// [`category_axis`](Self::category_axis) for styling the axis.
+    pub fn categories(mut self, labels: &[&str]) -> Self {
+        self.category_axis = Some(CategoryAxis::new(labels, AxisPosition::Bottom));
+        self
+    }
+
+    /// Draw a category axis and center the bars in its slots
+    ///
+    /// The axis is moved to run along the bars when the chart is built, see
+    /// [`BarChart::set_category_axis`].
+    pub fn category_axis(mut self, axis: CategoryAxis<C>) -> Self {
+        self.category_axis = Some(Ok(axis));
+        self
+    }
+
+    /// Draw each bar's value at its end
+    pub fn value_labels(mut self, labels: BarValueLabels<C>) -> Self {
+        self.value_labels = Some(labels);
+        self
+    }
+
     /// Set bar colors
     pub fn colors(mut self, colors: &[C]) -> Self {
         self.style.bar_colors.clear();
@@ -699,11 +1191,29 @@ where
         self
     }
 
+    /// Set a subtitle drawn below the title
+    pub fn with_subtitle(mut self, subtitle: &str) -> Self {
+        self.config.subtitle = heapless::String::try_from(subtitle).ok();
+        self
+    }
+
+    /// Set the fonts, colors and alignment of the title and subtitle
+    pub fn title_style(mut self, style: TitleStyle<C>) -> Self {
+        self.config.title_style = Some(style);
+        self
+    }
+
     /// Set the background color
     pub fn background_color(mut self, color: C) -> Self {
         self.config.background_color = Some(color);
         self
     }
+
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
 }
 
 impl<C: PixelColor> ChartBuilder<C> for BarChartBuilder<C>
@@ -713,12 +1223,36 @@ where
     type Chart = BarChart<C>;
     type Error = ChartError;
 
-    fn build(self) -> Result<Self::Chart, Self::Error> {
-        Ok(BarChart {
+    /// Build the bar chart
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`CategoryAxis::new`] if the labels passed to
+    /// [`categories`](BarChartBuilder::categories) are empty or too many.
+    fn build(mut self) -> Result<Self::Chart, Self::Error> {
+        if let Some(monochrome) = self.monochrome {
+            self.config.background_color = Some(monochrome.paper);
+            if self.style.border.is_none() {
+                self.style.border = Some(BorderStyle::new(LineStyle::solid(monochrome.ink)));
+            }
+            if let Some(labels) = self.value_labels.as_mut() {
+                labels.color = monochrome.ink;
+            }
+        }
+
+        let mut chart = BarChart {
             style: self.style,
             config: self.config,
             orientation: self.orientation,
-        })
+            placement: self.placement,
+            baseline: self.baseline,
+            negative_color: self.negative_color,
+            category_axis: None,
+            value_labels: self.value_labels,
+            monochrome: self.monochrome,
+        };
+        chart.set_category_axis(self.category_axis.transpose()?);
+        Ok(chart)
     }
 }
 
@@ -774,6 +1308,266 @@ mod tests {
 
         assert_eq!(BarWidth::Auto, BarWidth::Auto);
     }
+
+    #[test]
+    fn test_category_aligned_bars_centered_on_ticks() {
+        let chart: BarChart<Rgb565> = BarChart::builder()
+            .category_aligned(true)
+            .spacing(4)
+            .build()
+            .unwrap();
+        assert!(chart.is_category_aligned());
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[
+                (0.0, 5.0),
+                (1.0, 10.0),
+                (2.0, 7.0),
+            ])
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(120, 80));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+
+        let draw_area = chart.config().margins.apply_to(viewport);
+        let scale = chart.category_scale(&data);
+        for (index, bar) in bars.iter().enumerate() {
+            let center = bar.top_left.x + bar.size.width as i32 / 2;
+            let tick = scale.center(index, draw_area, AxisOrientation::Horizontal);
+            assert!((center - tick).abs() <= 1);
+        }
+
+        // Series of any capacity give the same scale
+        let small: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 8> =
+            crate::data::series::StaticDataSeries::from_tuples(&[
+                (0.0, 5.0),
+                (1.0, 10.0),
+                (2.0, 7.0),
+            ])
+            .unwrap();
+        assert_eq!(chart.category_scale(&small).range(), scale.range());
+    }
+
+    #[test]
+    fn test_linear_placement_keeps_x_gaps() {
+        let chart: BarChart<Rgb565> = BarChart::builder()
+            .placement(BarPlacement::Linear)
+            .bar_width(BarWidth::Fixed(4))
+            .build()
+            .unwrap();
+        assert_eq!(chart.placement(), BarPlacement::Linear);
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[
+                (0.0, 5.0),
+                (3.0, 10.0),
+                (4.0, 7.0),
+                (9.0, 2.0),
+            ])
+            .unwrap();
+        assert_eq!(chart.linear_x_range(&data).unwrap(), (-0.5, 9.5));
+
+        // 100px wide draw area, 10px per x unit
+        let viewport = Rectangle::new(Point::zero(), Size::new(120, 80));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+        let centers: Vec<i32, 4> = bars
+            .iter()
+            .map(|bar| bar.top_left.x + bar.size.width as i32 / 2)
+            .collect();
+        for (center, expected) in centers.iter().zip([15, 45, 55, 105]) {
+            assert!((center - expected).abs() <= 1);
+        }
+        assert!(bars.iter().all(|bar| bar.size.width == 4));
+    }
+
+    #[test]
+    fn test_baseline_bars_extend_both_directions() {
+        let chart: BarChart<Rgb565> = BarChart::builder().baseline(100.0).build().unwrap();
+        assert_eq!(chart.baseline(), Some(100.0));
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 120.0), (1.0, 90.0)])
+                .unwrap();
+        assert_eq!(chart.value_range(&data).unwrap(), (90.0, 120.0));
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(120, 80));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+
+        // Both bars meet at the baseline, one above and one below
+        let above = bars[0];
+        let below = bars[1];
+        let baseline_y = above.top_left.y + above.size.height as i32;
+        assert_eq!(below.top_left.y, baseline_y);
+        assert!(above.size.height > below.size.height);
+    }
+
+    #[test]
+    fn test_signed_values_measured_from_zero() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart: BarChart<Rgb565> = BarChart::builder()
+            .colors(&[Rgb565::BLUE])
+            .negative_color(Rgb565::RED)
+            .category_aligned(true)
+            .build()
+            .unwrap();
+        assert_eq!(chart.baseline(), None);
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 10.0), (1.0, -30.0)])
+                .unwrap();
+        assert_eq!(chart.value_range(&data).unwrap(), (-30.0, 10.0));
+
+        // 40 px high draw area: zero sits a quarter of the way down
+        let viewport = Rectangle::new(Point::zero(), Size::new(60, 60));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+        assert_eq!(bars[0].top_left.y, 10);
+        assert_eq!(bars[0].size.height, 10);
+        assert_eq!(bars[1].top_left.y, 20);
+        assert_eq!(bars[1].size.height, 30);
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert_eq!(display.get_pixel(bars[0].center()), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(bars[1].center()), Some(Rgb565::RED));
+    }
+
+    #[test]
+    fn test_monochrome_bars_use_patterns() {
+        use crate::style::MonochromeStyle;
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        let chart: BarChart<BinaryColor> = BarChart::builder()
+            .monochrome(MonochromeStyle::BINARY)
+            .build()
+            .unwrap();
+        assert_eq!(chart.config().background_color, Some(BinaryColor::Off));
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 20.0), (1.0, 20.0)])
+                .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(60, 60));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Bars are outlined in ink and filled with different patterns
+        let interior = |bar: &Rectangle| {
+            let inner = bar.offset(-1);
+            let display = &display;
+            inner
+                .points()
+                .map(move |point| display.get_pixel(point) == Some(BinaryColor::On))
+                .collect::<heapless::Vec<bool, 1024>>()
+        };
+        assert_eq!(display.get_pixel(bars[0].top_left), Some(BinaryColor::On));
+        let (first, second) = (interior(&bars[0]), interior(&bars[1]));
+        assert!(first.contains(&true) && first.contains(&false));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_categories_beside_horizontal_bars() {
+        let chart: BarChart<Rgb565> = BarChart::builder()
+            .categories(&["Mon", "Tue", "Wed"])
+            .orientation(BarOrientation::Horizontal)
+            .build()
+            .unwrap();
+        assert!(chart.is_category_aligned());
+        let axis = chart.category_axis().unwrap();
+        assert_eq!(axis.position(), AxisPosition::Left);
+        assert_eq!(axis.label(2), Some("Wed"));
+
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[
+                (0.0, 5.0),
+                (1.0, 10.0),
+                (2.0, 7.0),
+                (3.0, 4.0),
+            ])
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(120, 80));
+        let bounds = data.bounds().unwrap();
+        let bars = chart
+            .calculate_bar_layout(&data, &bounds, viewport)
+            .unwrap();
+
+        // One bar per category, centered on its label
+        assert_eq!(bars.len(), 3);
+        let draw_area = chart.config().margins.apply_to(viewport);
+        for (index, bar) in bars.iter().enumerate() {
+            let center = bar.top_left.y + bar.size.height as i32 / 2;
+            let tick = axis.transform_value(index as f32, draw_area);
+            assert!((center - tick).abs() <= 1);
+        }
+
+        assert_eq!(
+            BarChart::<Rgb565>::builder().categories(&[]).build().err(),
+            Some(ChartError::InsufficientData)
+        );
+    }
+
+    #[test]
+    fn test_value_labels_at_bar_ends() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart: BarChart<Rgb565> = BarChart::builder()
+            .categories(&["A", "B"])
+            .baseline(0.0)
+            .colors(&[Rgb565::BLUE])
+            .value_labels(BarValueLabels::new(Rgb565::GREEN))
+            .build()
+            .unwrap();
+        let data: crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256> =
+            crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 5.0), (1.0, -5.0)]).unwrap();
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 44));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Draw area spans y 10..34 with the baseline in the middle; the label
+        // of the positive bar sits above it and the negative one's below
+        let display = &display;
+        let label_rows = |columns: core::ops::Range<i32>| {
+            (0..64).filter(move |&y| {
+                columns
+                    .clone()
+                    .any(|x| display.get_pixel(Point::new(x, y)) == Some(Rgb565::GREEN))
+            })
+        };
+        assert!(label_rows(10..32).all(|y| y < 10));
+        assert!(label_rows(10..32).count() > 0);
+        assert!(label_rows(32..54).all(|y| y >= 34));
+        assert!(label_rows(32..54).count() > 0);
+
+        // Category labels are drawn under the plot
+        assert!((34..64)
+            .any(|y| (0..32).any(|x| display.get_pixel(Point::new(x, y)) == Some(Rgb565::BLACK))));
+    }
 }
 
 /// Animated bar chart that extends BarChart with animation capabilities
@@ -986,6 +1780,36 @@ where
         self
     }
 
+    /// Measure bars from `value` instead of the bottom of the data range
+    pub fn baseline(mut self, value: f32) -> Self {
+        self.base_builder = self.base_builder.baseline(value);
+        self
+    }
+
+    /// Draw bars below the baseline in `color`
+    pub fn negative_color(mut self, color: C) -> Self {
+        self.base_builder = self.base_builder.negative_color(color);
+        self
+    }
+
+    /// Draw for a monochrome or grayscale display
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.base_builder = self.base_builder.monochrome(style);
+        self
+    }
+
+    /// Label the bars with category names, one per bar in data order
+    pub fn categories(mut self, labels: &[&str]) -> Self {
+        self.base_builder = self.base_builder.categories(labels);
+        self
+    }
+
+    /// Draw each bar's value at its end
+    pub fn value_labels(mut self, labels: BarValueLabels<C>) -> Self {
+        self.base_builder = self.base_builder.value_labels(labels);
+        self
+    }
+
     /// Set bar colors
     pub fn colors(mut self, colors: &[C]) -> Self {
         self.base_builder = self.base_builder.colors(colors);
@@ -1028,6 +1852,12 @@ where
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.base_builder = self.base_builder.background_mode(mode);
+        self
+    }
+
     /// Build the animated bar chart
     pub fn build(self) -> ChartResult<AnimatedBarChart<C>> {
         let base_chart = self.base_builder.build()?;
@@ -0,0 +1,564 @@
+//! Statistical process control (SPC) chart implementation.
+//!
+//! A control chart plots a measured value over time against its center line
+//! and ±1σ, ±2σ and ±3σ limits. The limits are either supplied from a
+//! reference run or estimated from the plotted data. Points that break one of
+//! the enabled [`ControlRule`]s (the Western Electric rules) are highlighted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let data = data_points![(0.0, 10.1), (1.0, 9.8), (2.0, 10.0), (3.0, 13.5), (4.0, 10.2)];
+//!
+//! let chart = ControlChart::builder()
+//!     .limits(ControlLimits::new(10.0, 1.0))
+//!     .violation_color(Rgb565::RED)
+//!     .build()?;
+//!
+//! let violations = chart.violations(&data)?;
+//! assert!(violations[3]);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig};
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::data::{DataBounds, DataPoint, DataSeries, SeriesStatistics};
+use crate::error::{ChartError, ChartResult};
+use crate::style::LineStyle;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+};
+use heapless::Vec;
+
+/// Center line and standard deviation of a controlled process.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlLimits {
+    /// Process mean
+    pub center: f32,
+    /// Process standard deviation
+    pub sigma: f32,
+}
+
+impl ControlLimits {
+    /// Create control limits from a known mean and standard deviation
+    pub const fn new(center: f32, sigma: f32) -> Self {
+        Self { center, sigma }
+    }
+
+    /// Estimate control limits from the y values of `data`
+    pub fn from_data(data: &StaticDataSeries<Point2D, 256>) -> ChartResult<Self> {
+        let stats = data.describe()?;
+        Ok(Self {
+            center: stats.mean,
+            sigma: stats.std_dev,
+        })
+    }
+
+    /// Upper limit at `k` standard deviations
+    pub fn upper(&self, k: f32) -> f32 {
+        self.center + k * self.sigma
+    }
+
+    /// Lower limit at `k` standard deviations
+    pub fn lower(&self, k: f32) -> f32 {
+        self.center - k * self.sigma
+    }
+
+    /// Signed distance of `value` from the center in standard deviations
+    pub fn z_score(&self, value: f32) -> f32 {
+        if self.sigma > 0.0 {
+            (value - self.center) / self.sigma
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Western Electric rules for detecting an out-of-control process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlRule {
+    /// A point beyond ±3σ
+    BeyondThreeSigma,
+    /// Two of three consecutive points beyond 2σ on the same side
+    TwoOfThreeBeyondTwoSigma,
+    /// Four of five consecutive points beyond 1σ on the same side
+    FourOfFiveBeyondOneSigma,
+    /// Eight consecutive points on the same side of the center line
+    EightOnOneSide,
+}
+
+impl ControlRule {
+    /// All Western Electric rules
+    pub const ALL: [ControlRule; 4] = [
+        ControlRule::BeyondThreeSigma,
+        ControlRule::TwoOfThreeBeyondTwoSigma,
+        ControlRule::FourOfFiveBeyondOneSigma,
+        ControlRule::EightOnOneSide,
+    ];
+
+    /// Check whether the point at `index` completes a violation of this rule
+    ///
+    /// # Arguments
+    /// * `z_scores` - Distance of each point from the center in standard deviations
+    /// * `index` - Point to check
+    pub fn is_violated(&self, z_scores: &[f32], index: usize) -> bool {
+        let z = z_scores[index];
+        match self {
+            ControlRule::BeyondThreeSigma => z.abs() > 3.0,
+            ControlRule::TwoOfThreeBeyondTwoSigma => {
+                Self::count_beyond(z_scores, index, 3, 2.0) >= 2
+            }
+            ControlRule::FourOfFiveBeyondOneSigma => {
+                Self::count_beyond(z_scores, index, 5, 1.0) >= 4
+            }
+            ControlRule::EightOnOneSide => {
+                index >= 7
+                    && z != 0.0
+                    && z_scores[index - 7..=index]
+                        .iter()
+                        .all(|other| other.signum() == z.signum() && *other != 0.0)
+            }
+        }
+    }
+
+    /// Count points in the window ending at `index` beyond `limit` on the same side as `index`
+    fn count_beyond(z_scores: &[f32], index: usize, window: usize, limit: f32) -> usize {
+        let z = z_scores[index];
+        if index + 1 < window || z.abs() <= limit {
+            return 0;
+        }
+        z_scores[index + 1 - window..=index]
+            .iter()
+            .filter(|other| other.abs() > limit && other.signum() == z.signum())
+            .count()
+    }
+}
+
+/// Style configuration for control charts
+#[derive(Debug, Clone)]
+pub struct ControlChartStyle<C: PixelColor> {
+    /// Style of the line connecting the measurements
+    pub line: LineStyle<C>,
+    /// Style of the center line
+    pub center_line: LineStyle<C>,
+    /// Style of the ±3σ control limit lines
+    pub limit_line: LineStyle<C>,
+    /// Fill colors of the ±1σ, ±2σ and ±3σ bands, innermost first
+    pub band_colors: [Option<C>; 3],
+    /// Color of in-control points
+    pub point_color: C,
+    /// Color of points that violate a rule
+    pub violation_color: C,
+    /// Diameter of the points in pixels
+    pub point_size: u32,
+}
+
+impl<C: PixelColor> Default for ControlChartStyle<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        Self {
+            line: LineStyle::solid(Rgb565::BLUE.into()),
+            center_line: LineStyle::solid(Rgb565::new(0, 40, 0).into()),
+            limit_line: LineStyle::dashed(Rgb565::RED.into()),
+            band_colors: [
+                Some(Rgb565::new(26, 58, 26).into()),
+                Some(Rgb565::new(30, 60, 22).into()),
+                Some(Rgb565::new(31, 54, 20).into()),
+            ],
+            point_color: Rgb565::BLUE.into(),
+            violation_color: Rgb565::RED.into(),
+            point_size: 5,
+        }
+    }
+}
+
+/// Control chart with sigma bands and rule-violation highlighting
+#[derive(Debug, Clone)]
+pub struct ControlChart<C: PixelColor> {
+    style: ControlChartStyle<C>,
+    config: ChartConfig<C>,
+    /// Supplied limits; estimated from the data when `None`
+    limits: Option<ControlLimits>,
+    /// Rules used to flag out-of-control points
+    rules: Vec<ControlRule, 4>,
+}
+
+impl<C: PixelColor> ControlChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Create a new control chart with limits estimated from the data
+    pub fn new() -> Self {
+        Self {
+            style: ControlChartStyle::default(),
+            config: ChartConfig::default(),
+            limits: None,
+            rules: Vec::from_slice(&ControlRule::ALL).unwrap_or_default(),
+        }
+    }
+
+    /// Create a builder for configuring the control chart
+    pub fn builder() -> ControlChartBuilder<C> {
+        ControlChartBuilder::new()
+    }
+
+    /// Get the chart style
+    pub fn style(&self) -> &ControlChartStyle<C> {
+        &self.style
+    }
+
+    /// Get the chart configuration
+    pub fn config(&self) -> &ChartConfig<C> {
+        &self.config
+    }
+
+    /// Get the enabled rules
+    pub fn rules(&self) -> &[ControlRule] {
+        &self.rules
+    }
+
+    /// Get the limits used for `data`, either supplied or estimated
+    pub fn limits_for(&self, data: &StaticDataSeries<Point2D, 256>) -> ChartResult<ControlLimits> {
+        match self.limits {
+            Some(limits) => Ok(limits),
+            None => ControlLimits::from_data(data),
+        }
+    }
+
+    /// Flag each point of `data` that violates any enabled rule
+    pub fn violations(&self, data: &StaticDataSeries<Point2D, 256>) -> ChartResult<Vec<bool, 256>> {
+        let limits = self.limits_for(data)?;
+
+        let mut z_scores: Vec<f32, 256> = Vec::new();
+        for point in data.iter() {
+            z_scores
+                .push(limits.z_score(point.y()))
+                .map_err(|_| ChartError::MemoryFull)?;
+        }
+
+        let mut flags = Vec::new();
+        for index in 0..z_scores.len() {
+            let violated = self
+                .rules
+                .iter()
+                .any(|rule| rule.is_violated(&z_scores, index));
+            flags.push(violated).map_err(|_| ChartError::MemoryFull)?;
+        }
+        Ok(flags)
+    }
+
+    /// Y range shown by the chart: the data and the ±3σ limits with some headroom
+    fn value_range(&self, bounds: &DataBounds<f32, f32>, limits: &ControlLimits) -> (f32, f32) {
+        let min = bounds.min_y.min(limits.lower(3.5));
+        let max = bounds.max_y.max(limits.upper(3.5));
+        if max > min {
+            (min, max)
+        } else {
+            (min - 1.0, max + 1.0)
+        }
+    }
+
+    /// Draw a horizontal line across the chart area
+    fn draw_level<D>(
+        &self,
+        y: i32,
+        chart_area: Rectangle,
+        style: &LineStyle<C>,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let right = chart_area.top_left.x + chart_area.size.width as i32 - 1;
+        crate::render::ChartRenderer::draw_line(
+            Point::new(chart_area.top_left.x, y),
+            Point::new(right, y),
+            style,
+            target,
+        )
+        .map_err(|_| ChartError::RenderingError)
+    }
+}
+
+impl<C: PixelColor> Default for ControlChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: PixelColor> Chart<C> for ControlChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Data = StaticDataSeries<Point2D, 256>;
+    type Config = ChartConfig<C>;
+
+    fn draw<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+        Self::Data: DataSeries,
+        <Self::Data as DataSeries>::Item: DataPoint,
+        <<Self::Data as DataSeries>::Item as DataPoint>::X: Into<f32> + Copy + PartialOrd,
+        <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
+    {
+        if data.is_empty() {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
+        }
+
+        config.draw_background(viewport, target)?;
+
+        let chart_area = config.margins.apply_to(viewport);
+        let bounds = data.bounds()?;
+        let limits = self.limits_for(data)?;
+        let (min_y, max_y) = self.value_range(&bounds, &limits);
+
+        let width = chart_area.size.width.saturating_sub(1) as f32;
+        let height = chart_area.size.height.saturating_sub(1) as f32;
+        let bottom = chart_area.top_left.y + chart_area.size.height as i32 - 1;
+        let to_y = |value: f32| bottom - ((value - min_y) / (max_y - min_y) * height) as i32;
+        let to_x = |value: f32| {
+            let span = bounds.max_x - bounds.min_x;
+            let norm = if span > 0.0 {
+                (value - bounds.min_x) / span
+            } else {
+                0.5
+            };
+            chart_area.top_left.x + (norm * width) as i32
+        };
+
+        // Sigma bands, outermost first so inner bands paint over them
+        for (index, color) in self.style.band_colors.iter().enumerate().rev() {
+            if let Some(color) = color {
+                let k = (index + 1) as f32;
+                let top = to_y(limits.upper(k));
+                let band_bottom = to_y(limits.lower(k));
+                Rectangle::new(
+                    Point::new(chart_area.top_left.x, top),
+                    Size::new(chart_area.size.width, (band_bottom - top + 1).max(1) as u32),
+                )
+                .intersection(&chart_area)
+                .into_styled(PrimitiveStyle::with_fill(*color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+
+        // Center line and control limits
+        self.draw_level(
+            to_y(limits.center),
+            chart_area,
+            &self.style.center_line,
+            target,
+        )?;
+        self.draw_level(
+            to_y(limits.upper(3.0)),
+            chart_area,
+            &self.style.limit_line,
+            target,
+        )?;
+        self.draw_level(
+            to_y(limits.lower(3.0)),
+            chart_area,
+            &self.style.limit_line,
+            target,
+        )?;
+
+        // Measurement line
+        let mut points: Vec<Point, 256> = Vec::new();
+        for point in data.iter() {
+            points
+                .push(Point::new(to_x(point.x()), to_y(point.y())))
+                .map_err(|_| ChartError::MemoryFull)?;
+        }
+        let line_style = PrimitiveStyle::with_stroke(self.style.line.color, self.style.line.width);
+        for segment in points.windows(2) {
+            Line::new(segment[0], segment[1])
+                .into_styled(line_style)
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        // Points, with rule violations highlighted
+        let violations = self.violations(data)?;
+        for (point, violated) in points.iter().zip(violations.iter()) {
+            let color = if *violated {
+                self.style.violation_color
+            } else {
+                self.style.point_color
+            };
+            Circle::with_center(*point, self.style.point_size)
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for ControlChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
+/// Builder for control charts
+#[derive(Debug)]
+pub struct ControlChartBuilder<C: PixelColor> {
+    chart: ControlChart<C>,
+}
+
+impl<C: PixelColor> ControlChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Create a new control chart builder
+    pub fn new() -> Self {
+        Self {
+            chart: ControlChart::new(),
+        }
+    }
+
+    /// Use fixed control limits instead of estimating them from the data
+    pub fn limits(mut self, limits: ControlLimits) -> Self {
+        self.chart.limits = Some(limits);
+        self
+    }
+
+    /// Set the rules used to flag out-of-control points
+    pub fn rules(mut self, rules: &[ControlRule]) -> Self {
+        self.chart.rules.clear();
+        for rule in rules.iter().take(4) {
+            let _ = self.chart.rules.push(*rule);
+        }
+        self
+    }
+
+    /// Set the measurement line color
+    pub fn line_color(mut self, color: C) -> Self {
+        self.chart.style.line = self.chart.style.line.color(color);
+        self.chart.style.point_color = color;
+        self
+    }
+
+    /// Set the color of points that violate a rule
+    pub fn violation_color(mut self, color: C) -> Self {
+        self.chart.style.violation_color = color;
+        self
+    }
+
+    /// Set the fill colors of the ±1σ, ±2σ and ±3σ bands, innermost first
+    pub fn band_colors(mut self, colors: [Option<C>; 3]) -> Self {
+        self.chart.style.band_colors = colors;
+        self
+    }
+
+    /// Set the chart title
+    pub fn with_title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = heapless::String::try_from(title) {
+            self.chart.config.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Set the background color
+    pub fn background_color(mut self, color: C) -> Self {
+        self.chart.config.background_color = Some(color);
+        self
+    }
+
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.chart.config.background_mode = mode;
+        self
+    }
+}
+
+impl<C: PixelColor> ChartBuilder<C> for ControlChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Chart = ControlChart<C>;
+    type Error = ChartError;
+
+    fn build(self) -> Result<Self::Chart, Self::Error> {
+        if let Some(limits) = self.chart.limits {
+            if limits.sigma < 0.0 {
+                return Err(ChartError::InvalidConfiguration);
+            }
+        }
+        Ok(self.chart)
+    }
+}
+
+impl<C: PixelColor> Default for ControlChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_limits_from_data() {
+        let data = StaticDataSeries::from_tuples(&[(0.0, 2.0), (1.0, 4.0), (2.0, 6.0)]).unwrap();
+        let limits = ControlLimits::from_data(&data).unwrap();
+        assert_eq!(limits.center, 4.0);
+        // micromath's sqrt is only accurate to a few percent
+        assert!((limits.sigma - 1.633).abs() < 0.1);
+        assert_eq!(limits.upper(3.0), 4.0 + 3.0 * limits.sigma);
+    }
+
+    #[test]
+    fn test_western_electric_rules() {
+        let z = [0.5, 2.5, 0.1, 2.2, -3.5];
+        assert!(ControlRule::BeyondThreeSigma.is_violated(&z, 4));
+        assert!(ControlRule::TwoOfThreeBeyondTwoSigma.is_violated(&z, 3));
+        assert!(!ControlRule::TwoOfThreeBeyondTwoSigma.is_violated(&z, 1));
+
+        let same_side = [0.2; 8];
+        assert!(ControlRule::EightOnOneSide.is_violated(&same_side, 7));
+        assert!(!ControlRule::EightOnOneSide.is_violated(&same_side, 6));
+    }
+
+    #[test]
+    fn test_violations_with_supplied_limits() {
+        let chart: ControlChart<Rgb565> = ControlChart::builder()
+            .limits(ControlLimits::new(10.0, 1.0))
+            .rules(&[ControlRule::BeyondThreeSigma])
+            .build()
+            .unwrap();
+        let data = StaticDataSeries::from_tuples(&[(0.0, 10.0), (1.0, 14.0), (2.0, 9.0)]).unwrap();
+
+        let violations = chart.violations(&data).unwrap();
+        assert_eq!(violations.as_slice(), &[false, true, false]);
+    }
+}
@@ -5,10 +5,13 @@
 //! with advanced curve generation capabilities.
 
 use crate::chart::line::{LineChart, LineChartBuilder, LineChartStyle, MarkerStyle};
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig};
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig};
 use crate::data::{DataPoint, DataSeries, Point2D};
 use crate::error::{ChartError, ChartResult};
-use crate::math::interpolation::{CurveInterpolator, InterpolationConfig, InterpolationType};
+use crate::math::interpolation::{
+    CurveInterpolator, EndpointMode, InterpolationConfig, InterpolationType,
+};
+use crate::style::MonochromeStyle;
 use embedded_graphics::{draw_target::DrawTarget, prelude::*};
 use heapless::Vec;
 
@@ -151,8 +154,13 @@ where
             points.push(point).map_err(|_| ChartError::MemoryFull)?;
         }
 
-        // Perform interpolation
-        CurveInterpolator::interpolate(&points, &self.interpolation_config)
+        // Perform interpolation at the detail level of the current quality profile
+        let mut config = self.interpolation_config.clone();
+        config.subdivisions = self
+            .base_chart
+            .effective_quality()
+            .subdivisions(config.subdivisions);
+        CurveInterpolator::interpolate(&points, &config)
     }
 
     /// Transform a data point to screen coordinates using the same logic as LineChart
@@ -162,41 +170,18 @@ where
         data_bounds: &crate::data::DataBounds<f32, f32>,
         viewport: embedded_graphics::primitives::Rectangle,
     ) -> embedded_graphics::prelude::Point {
-        use crate::math::NumericConversion;
-
-        // Convert to math abstraction layer (same as LineChart)
-        let data_x = point.x.to_number();
-        let data_y = point.y.to_number();
+        use crate::math::{NumericConversion, RangeTransform};
 
         // Use the same bounds as LineChart would
-        let min_x = data_bounds.min_x.to_number();
-        let max_x = data_bounds.max_x.to_number();
-        let min_y = data_bounds.min_y.to_number();
-        let max_y = data_bounds.max_y.to_number();
+        let x_range = RangeTransform::new(data_bounds.min_x, data_bounds.max_x);
+        let y_range = RangeTransform::new(data_bounds.min_y, data_bounds.max_y);
 
         // Apply margins to get the actual drawing area (same as LineChart)
         let draw_area = self.base_chart.config().margins.apply_to(viewport);
 
-        // Normalize to 0-1 range using math abstraction (same as LineChart)
-        let norm_x = if f32::from_number(max_x) > f32::from_number(min_x) {
-            let range_x = f32::from_number(max_x - min_x);
-            let offset_x = f32::from_number(data_x - min_x);
-            (offset_x / range_x).to_number()
-        } else {
-            0.5f32.to_number()
-        };
-
-        let norm_y = if f32::from_number(max_y) > f32::from_number(min_y) {
-            let range_y = f32::from_number(max_y - min_y);
-            let offset_y = f32::from_number(data_y - min_y);
-            (offset_y / range_y).to_number()
-        } else {
-            0.5f32.to_number()
-        };
-
-        // Transform to screen coordinates (Y is flipped) - same as LineChart
-        let norm_x_f32 = f32::from_number(norm_x);
-        let norm_y_f32 = f32::from_number(norm_y);
+        // Normalize to 0-1 range in f32 before converting (same as LineChart)
+        let norm_x_f32 = f32::from_number(x_range.normalize(point.x));
+        let norm_y_f32 = f32::from_number(y_range.normalize(point.y));
 
         let screen_x =
             draw_area.top_left.x + (norm_x_f32 * (draw_area.size.width as f32 - 1.0)) as i32;
@@ -239,7 +224,7 @@ where
         <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
     {
         if data.is_empty() {
-            return Err(ChartError::InsufficientData);
+            return config.fallback(ChartError::InsufficientData, viewport, target);
         }
 
         // Handle case with only one point (can't interpolate)
@@ -292,6 +277,7 @@ where
         }
 
         // Draw the smooth curve without markers
+        temp_chart.set_quality(self.base_chart.quality());
         temp_chart.draw(&curve_data, config, viewport, target)?;
 
         // Now draw markers at original data points manually
@@ -301,7 +287,8 @@ where
 
                 let data_bounds = data.bounds()?;
 
-                for original_point in data.iter() {
+                let stride = self.base_chart.effective_quality().marker_stride();
+                for original_point in data.iter().step_by(stride) {
                     // Convert to Point2D for transformation
                     let point_2d = crate::data::Point2D::new(original_point.x, original_point.y);
                     // Transform original data point to screen coordinates
@@ -330,6 +317,15 @@ where
     }
 }
 
+impl<C: PixelColor + 'static> crate::chart::traits::ConfiguredChart<C> for CurveChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
 /// Builder for curve charts with fluent configuration API.
 #[derive(Debug)]
 pub struct CurveChartBuilder<C: PixelColor> {
@@ -390,6 +386,12 @@ where
         self
     }
 
+    /// Set how Catmull-Rom curves treat their first and last segment
+    pub fn endpoints(mut self, endpoints: EndpointMode) -> Self {
+        self.interpolation_config.endpoints = endpoints;
+        self
+    }
+
     /// Set the line color.
     pub fn line_color(mut self, color: C) -> Self {
         self.line_builder = self.line_builder.line_color(color);
@@ -416,6 +418,12 @@ where
         self
     }
 
+    /// Draw for a monochrome or grayscale display, such as e-paper.
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.line_builder = self.line_builder.monochrome(style);
+        self
+    }
+
     /// Set the chart title.
     pub fn with_title(mut self, title: &str) -> Self {
         self.line_builder = self.line_builder.with_title(title);
@@ -428,6 +436,12 @@ where
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.line_builder = self.line_builder.background_mode(mode);
+        self
+    }
+
     /// Set the chart margins.
     pub fn margins(mut self, margins: crate::chart::traits::Margins) -> Self {
         self.line_builder = self.line_builder.margins(margins);
@@ -702,6 +716,7 @@ mod tests {
             subdivisions: 20,
             tension: 0.3,
             closed: true,
+            ..Default::default()
         };
         chart.set_interpolation_config(config.clone());
         assert_eq!(
@@ -719,6 +734,10 @@ mod tests {
             markers: None,
             smooth: true,
             smooth_subdivisions: 10,
+            simplify_epsilon: None,
+            decimation: None,
+            smooth_endpoints: EndpointMode::Duplicate,
+            line_pattern: crate::style::LinePattern::Solid,
         };
         chart.set_style(style);
         assert_eq!(chart.style().line_color, Rgb565::MAGENTA);
@@ -727,10 +746,15 @@ mod tests {
         // Test config setter
         let config = ChartConfig {
             title: None,
+            subtitle: None,
+            title_style: None,
             background_color: Some(Rgb565::WHITE),
             margins: Margins::all(15),
             show_grid: true,
             grid_color: Some(Rgb565::CSS_GRAY),
+            background_mode: BackgroundMode::Fill,
+            placeholder: None,
+            locale: crate::style::NumberLocale::PLAIN,
         };
         chart.set_config(config);
         assert_eq!(chart.config().margins.top, 15);
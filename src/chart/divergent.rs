@@ -0,0 +1,502 @@
+//! Divergent (tornado) bar chart implementation.
+//!
+//! A divergent bar chart compares two groups category by category: the first
+//! group's bars extend to the left of a central column, the second group's to
+//! the right, and the category labels sit in the column between them. Both
+//! halves share one scale, so bar lengths can be compared across the center.
+//! The layout suits wide, short displays.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! // Day vs night consumption per room
+//! let mut data: DivergentData<16> = DivergentData::new();
+//! data.add_category("Kitchen", 12.0, 4.0)?;
+//! data.add_category("Office", 9.0, 1.5)?;
+//!
+//! let chart = DivergentBarChart::builder()
+//!     .left_color(Rgb565::YELLOW)
+//!     .right_color(Rgb565::BLUE)
+//!     .label_color(Rgb565::WHITE)
+//!     .center_gap(48)
+//!     .build()?;
+//!
+//! assert_eq!(data.value_limit(), 12.0);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::bar::BarWidth;
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig};
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::data::{DataPoint, DataSeries};
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+use heapless::{String, Vec};
+
+/// Maximum number of category labels stored by [`DivergentData`]
+pub const MAX_DIVERGENT_LABELS: usize = 32;
+
+/// Paired values for a divergent bar chart, one pair per category
+///
+/// Category `i` is drawn from `left().get(i)` and `right().get(i)`; the x value
+/// of each point is the category index.
+#[derive(Debug, Clone)]
+pub struct DivergentData<const N: usize> {
+    /// Values drawn to the left of the center
+    left: StaticDataSeries<Point2D, N>,
+    /// Values drawn to the right of the center
+    right: StaticDataSeries<Point2D, N>,
+    /// Category labels, drawn in the center column
+    labels: Vec<String<16>, MAX_DIVERGENT_LABELS>,
+}
+
+impl<const N: usize> DivergentData<N> {
+    /// Create an empty data set
+    pub fn new() -> Self {
+        Self {
+            left: StaticDataSeries::new(),
+            right: StaticDataSeries::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    /// Add a category with its left and right value
+    ///
+    /// Labels longer than 16 bytes are truncated. Categories beyond
+    /// [`MAX_DIVERGENT_LABELS`] are drawn without a label.
+    pub fn add_category(&mut self, label: &str, left: f32, right: f32) -> ChartResult<()> {
+        let index = self.left.len() as f32;
+        if self.left.len() >= N {
+            return Err(ChartError::MemoryFull);
+        }
+        self.left.push(Point2D::new(index, left))?;
+        self.right.push(Point2D::new(index, right))?;
+
+        let mut text = String::new();
+        for ch in label.chars() {
+            if text.push(ch).is_err() {
+                break;
+            }
+        }
+        // Running out of label slots only drops the label, not the category
+        let _ = self.labels.push(text);
+        Ok(())
+    }
+
+    /// Number of categories
+    pub fn category_count(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Values drawn to the left of the center
+    pub fn left(&self) -> &StaticDataSeries<Point2D, N> {
+        &self.left
+    }
+
+    /// Values drawn to the right of the center
+    pub fn right(&self) -> &StaticDataSeries<Point2D, N> {
+        &self.right
+    }
+
+    /// Label of the category at `index`
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.labels.get(index).map(|label| label.as_str())
+    }
+
+    /// Largest value on either side, which both halves are scaled to
+    ///
+    /// Negative values are drawn as empty bars and do not count.
+    pub fn value_limit(&self) -> f32 {
+        self.left
+            .iter()
+            .chain(self.right.iter())
+            .fold(0.0f32, |acc, point| acc.max(point.y()))
+    }
+
+    /// Remove all categories
+    pub fn clear(&mut self) {
+        self.left.clear();
+        self.right.clear();
+        self.labels.clear();
+    }
+}
+
+impl<const N: usize> Default for DivergentData<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implement DataSeries for DivergentData to make it compatible with Chart trait
+impl<const N: usize> DataSeries for DivergentData<N> {
+    type Item = Point2D;
+    type Iter = crate::data::series::StaticDataSeriesIter<Point2D, N>;
+
+    fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        // For DataSeries compatibility, return the left value
+        self.left.get(index)
+    }
+
+    fn iter(&self) -> Self::Iter {
+        self.left.iter()
+    }
+}
+
+/// Divergent bar chart: two groups extending left and right from a central column
+#[derive(Debug, Clone)]
+pub struct DivergentBarChart<C: PixelColor> {
+    /// Color of the bars left of the center
+    left_color: C,
+    /// Color of the bars right of the center
+    right_color: C,
+    /// Color of the category labels; labels are hidden when `None`
+    label_color: Option<C>,
+    /// Width of the central label column in pixels
+    center_gap: u32,
+    /// Thickness of each bar relative to its category row
+    bar_width: BarWidth,
+    config: ChartConfig<C>,
+}
+
+impl<C: PixelColor> DivergentBarChart<C>
+where
+    C: From<Rgb565>,
+{
+    /// Create a new divergent bar chart with default styling
+    pub fn new() -> Self {
+        Self::builder()
+            .build()
+            .expect("default divergent chart configuration is valid")
+    }
+
+    /// Create a builder for configuring the divergent bar chart
+    pub fn builder() -> DivergentBarChartBuilder<C> {
+        DivergentBarChartBuilder::new()
+    }
+
+    /// Get the chart configuration
+    pub fn config(&self) -> &ChartConfig<C> {
+        &self.config
+    }
+
+    /// Get the color of the left and right bars
+    pub fn colors(&self) -> (C, C) {
+        (self.left_color, self.right_color)
+    }
+
+    /// Get the width of the central label column
+    pub fn center_gap(&self) -> u32 {
+        self.center_gap
+    }
+
+    /// Thickness of a bar in a category row of `slot` pixels
+    fn bar_thickness(&self, slot: u32) -> u32 {
+        let thickness = match self.bar_width {
+            BarWidth::Fixed(width) => width,
+            BarWidth::Percentage(ratio) => (slot as f32 * ratio.clamp(0.0, 1.0)) as u32,
+            BarWidth::Auto => slot.saturating_sub(2),
+        };
+        thickness.clamp(1, slot.max(1))
+    }
+
+    /// Draw one bar, skipping bars with no length
+    fn draw_bar<D>(&self, bar: Rectangle, color: C, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if bar.size.width == 0 || bar.size.height == 0 {
+            return Ok(());
+        }
+        bar.into_styled(PrimitiveStyle::with_fill(color))
+            .draw(target)
+            .map_err(|_| ChartError::RenderingError)
+    }
+}
+
+impl<C: PixelColor> Default for DivergentBarChart<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: PixelColor> Chart<C> for DivergentBarChart<C>
+where
+    C: From<Rgb565>,
+{
+    type Data = DivergentData<256>;
+    type Config = ChartConfig<C>;
+
+    fn draw<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let count = data.category_count();
+        if count == 0 {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
+        }
+
+        config.draw_background(viewport, target)?;
+
+        let area = config.margins.apply_to(viewport);
+        let gap = self.center_gap.min(area.size.width);
+        let half = (area.size.width - gap) / 2;
+        let center_x = area.top_left.x + (area.size.width / 2) as i32;
+        let left_edge = center_x - (gap / 2) as i32;
+        let right_edge = left_edge + gap as i32;
+
+        let slot = area.size.height / count as u32;
+        if slot == 0 {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        let thickness = self.bar_thickness(slot);
+
+        let limit = data.value_limit();
+        let length = |value: f32| {
+            if limit > 0.0 {
+                (value.clamp(0.0, limit) / limit * half as f32) as u32
+            } else {
+                0
+            }
+        };
+
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+
+        // Categories run top to bottom in data order
+        for index in 0..count {
+            let row_top = area.top_left.y + (slot * index as u32) as i32;
+            let bar_top = row_top + ((slot - thickness) / 2) as i32;
+
+            if let Some(point) = data.left().get(index) {
+                let len = length(point.y());
+                let bar = Rectangle::new(
+                    Point::new(left_edge - len as i32, bar_top),
+                    Size::new(len, thickness),
+                );
+                self.draw_bar(bar, self.left_color, target)?;
+            }
+            if let Some(point) = data.right().get(index) {
+                let bar = Rectangle::new(
+                    Point::new(right_edge, bar_top),
+                    Size::new(length(point.y()), thickness),
+                );
+                self.draw_bar(bar, self.right_color, target)?;
+            }
+
+            if let (Some(color), Some(label)) = (self.label_color, data.label(index)) {
+                if !label.is_empty() {
+                    Text::with_text_style(
+                        label,
+                        Point::new(center_x, row_top + (slot / 2) as i32),
+                        MonoTextStyle::new(&FONT_6X10, color),
+                        text_style,
+                    )
+                    .draw(target)
+                    .map_err(|_| ChartError::RenderingError)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for DivergentBarChart<C>
+where
+    C: From<Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
+/// Builder for divergent bar charts
+#[derive(Debug)]
+pub struct DivergentBarChartBuilder<C: PixelColor> {
+    left_color: C,
+    right_color: C,
+    label_color: Option<C>,
+    center_gap: u32,
+    bar_width: BarWidth,
+    config: ChartConfig<C>,
+}
+
+impl<C: PixelColor> DivergentBarChartBuilder<C>
+where
+    C: From<Rgb565>,
+{
+    /// Create a new divergent bar chart builder
+    pub fn new() -> Self {
+        Self {
+            left_color: Rgb565::BLUE.into(),
+            right_color: Rgb565::RED.into(),
+            label_color: Some(Rgb565::BLACK.into()),
+            center_gap: 30,
+            bar_width: BarWidth::Percentage(0.8),
+            config: ChartConfig::default(),
+        }
+    }
+
+    /// Set the color of the bars left of the center
+    pub fn left_color(mut self, color: C) -> Self {
+        self.left_color = color;
+        self
+    }
+
+    /// Set the color of the bars right of the center
+    pub fn right_color(mut self, color: C) -> Self {
+        self.right_color = color;
+        self
+    }
+
+    /// Set the color of the category labels
+    pub fn label_color(mut self, color: C) -> Self {
+        self.label_color = Some(color);
+        self
+    }
+
+    /// Hide the category labels
+    pub fn hide_labels(mut self) -> Self {
+        self.label_color = None;
+        self
+    }
+
+    /// Set the width of the central label column in pixels
+    pub fn center_gap(mut self, gap: u32) -> Self {
+        self.center_gap = gap;
+        self
+    }
+
+    /// Set the bar thickness relative to its category row
+    pub fn bar_width(mut self, width: BarWidth) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Set the chart title
+    pub fn with_title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = heapless::String::try_from(title) {
+            self.config.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Set the background color
+    pub fn background_color(mut self, color: C) -> Self {
+        self.config.background_color = Some(color);
+        self
+    }
+
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+}
+
+impl<C: PixelColor> ChartBuilder<C> for DivergentBarChartBuilder<C>
+where
+    C: From<Rgb565>,
+{
+    type Chart = DivergentBarChart<C>;
+    type Error = ChartError;
+
+    fn build(self) -> Result<Self::Chart, Self::Error> {
+        if let BarWidth::Percentage(ratio) = self.bar_width {
+            if !(ratio > 0.0 && ratio <= 1.0) {
+                return Err(ChartError::InvalidConfiguration);
+            }
+        }
+
+        Ok(DivergentBarChart {
+            left_color: self.left_color,
+            right_color: self.right_color,
+            label_color: self.label_color,
+            center_gap: self.center_gap,
+            bar_width: self.bar_width,
+            config: self.config,
+        })
+    }
+}
+
+impl<C: PixelColor> Default for DivergentBarChartBuilder<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    #[test]
+    fn test_divergent_data() {
+        let mut data: DivergentData<2> = DivergentData::new();
+        data.add_category("Day", 10.0, 4.0).unwrap();
+        data.add_category("A very long category", -1.0, 12.0)
+            .unwrap();
+        assert!(data.add_category("Full", 1.0, 1.0).is_err());
+
+        assert_eq!(data.category_count(), 2);
+        assert_eq!(data.value_limit(), 12.0);
+        assert_eq!(data.label(0), Some("Day"));
+        assert_eq!(data.label(1), Some("A very long cate"));
+        assert_eq!(data.right().get(1).map(|p| p.x()), Some(1.0));
+    }
+
+    #[test]
+    fn test_bars_extend_from_center() {
+        let chart: DivergentBarChart<Rgb565> = DivergentBarChart::builder()
+            .left_color(Rgb565::GREEN)
+            .right_color(Rgb565::RED)
+            .hide_labels()
+            .center_gap(10)
+            .build()
+            .unwrap();
+        let mut data = DivergentData::new();
+        data.add_category("", 10.0, 5.0).unwrap();
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Plot area is x = 10..54 with the center column at x = 27..37
+        assert_eq!(display.get_pixel(Point::new(11, 32)), Some(Rgb565::GREEN));
+        assert_eq!(display.get_pixel(Point::new(26, 32)), Some(Rgb565::GREEN));
+        assert_eq!(display.get_pixel(Point::new(32, 32)), None);
+        assert_eq!(display.get_pixel(Point::new(37, 32)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(50, 32)), None);
+    }
+}
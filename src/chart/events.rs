@@ -0,0 +1,400 @@
+//! Discrete event markers for time-series charts.
+//!
+//! An [`EventOverlay`] holds events such as "reboot" or "setpoint change", each
+//! with a timestamp on the X axis, a glyph and a short label. Attached to a
+//! [`LineChart`](crate::chart::LineChart), every event inside the visible X range
+//! is drawn as a vertical marker line with its glyph and label at the top of the
+//! plot area. Everything is clipped to the plot area, and when markers are too
+//! dense only the labels that fit without overlapping are drawn.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut events: EventOverlay<Rgb565> = EventOverlay::new();
+//! events.add_event(12.0, "reboot", EventGlyph::Triangle)?;
+//! events.add_event(30.0, "setpoint", EventGlyph::Diamond)?;
+//!
+//! let chart = LineChart::builder()
+//!     .line_color(Rgb565::BLUE)
+//!     .with_events(events)
+//!     .build()?;
+//! assert_eq!(chart.events().map(|events| events.len()), Some(2));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::error::{ChartError, ChartResult};
+use crate::math::{NumericConversion, RangeTransform};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle, Triangle},
+    text::{Baseline, Text},
+};
+
+/// Default number of events a line chart can hold
+pub const DEFAULT_EVENT_CAPACITY: usize = 16;
+
+/// Glyph drawn at the top of an event marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventGlyph {
+    /// Only the marker line
+    None,
+    /// Downward pointing triangle
+    #[default]
+    Triangle,
+    /// Diamond
+    Diamond,
+    /// Filled circle
+    Circle,
+    /// Filled square
+    Square,
+}
+
+/// A single event on the time axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartEvent<C: PixelColor> {
+    /// Position of the event on the X axis
+    pub timestamp: f32,
+    /// Short label drawn next to the marker
+    pub label: heapless::String<16>,
+    /// Glyph drawn at the top of the marker
+    pub glyph: EventGlyph,
+    /// Color overriding the overlay's marker color
+    pub color: Option<C>,
+}
+
+impl<C: PixelColor> ChartEvent<C> {
+    /// Create an event, truncating the label to 16 bytes
+    pub fn new(timestamp: f32, label: &str, glyph: EventGlyph) -> Self {
+        let mut text = heapless::String::new();
+        for c in label.chars() {
+            if text.push(c).is_err() {
+                break;
+            }
+        }
+        Self {
+            timestamp,
+            label: text,
+            glyph,
+            color: None,
+        }
+    }
+
+    /// Draw this event in its own color
+    pub fn with_color(mut self, color: C) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Appearance of event markers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventStyle<C: PixelColor> {
+    /// Color of marker lines and glyphs
+    pub marker_color: C,
+    /// Color of the labels
+    pub label_color: C,
+    /// Glyph size in pixels
+    pub glyph_size: u32,
+    /// Whether labels are drawn
+    pub show_labels: bool,
+    /// Minimum horizontal gap in pixels between two labelled markers
+    pub min_spacing: u32,
+}
+
+impl<C: PixelColor> Default for EventStyle<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            marker_color: Rgb565::CSS_DARK_ORANGE.into(),
+            label_color: Rgb565::BLACK.into(),
+            glyph_size: 6,
+            show_labels: true,
+            min_spacing: 4,
+        }
+    }
+}
+
+/// Collection of events drawn as markers over a time-series chart
+#[derive(Debug, Clone)]
+pub struct EventOverlay<C: PixelColor, const N: usize = DEFAULT_EVENT_CAPACITY> {
+    events: heapless::Vec<ChartEvent<C>, N>,
+    style: EventStyle<C>,
+}
+
+impl<C: PixelColor, const N: usize> EventOverlay<C, N>
+where
+    C: From<Rgb565>,
+{
+    /// Create an empty overlay with the default style
+    pub fn new() -> Self {
+        Self {
+            events: heapless::Vec::new(),
+            style: EventStyle::default(),
+        }
+    }
+
+    /// Set the marker style
+    pub fn with_style(mut self, style: EventStyle<C>) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<C: PixelColor, const N: usize> EventOverlay<C, N> {
+    /// Add an event
+    pub fn add(&mut self, event: ChartEvent<C>) -> ChartResult<()> {
+        self.events.push(event).map_err(|_| ChartError::MemoryFull)
+    }
+
+    /// Add an event from its timestamp, label and glyph
+    pub fn add_event(&mut self, timestamp: f32, label: &str, glyph: EventGlyph) -> ChartResult<()> {
+        self.add(ChartEvent::new(timestamp, label, glyph))
+    }
+
+    /// Remove all events
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Remove events before `timestamp`, e.g. once they scrolled out of view
+    pub fn retain_after(&mut self, timestamp: f32) {
+        self.events.retain(|event| event.timestamp >= timestamp);
+    }
+
+    /// Get the registered events
+    pub fn events(&self) -> &[ChartEvent<C>] {
+        &self.events
+    }
+
+    /// Number of registered events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no events are registered
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Get the marker style
+    pub fn style(&self) -> &EventStyle<C> {
+        &self.style
+    }
+
+    /// Draw the events that fall inside `x_min..=x_max` over `plot_area`.
+    ///
+    /// Markers use the same X mapping as the chart's data points. Events are
+    /// processed left to right, and a label is skipped when it would overlap
+    /// the previous label; the marker line and glyph are still drawn.
+    pub fn draw<D>(
+        &self,
+        x_min: f32,
+        x_max: f32,
+        plot_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.events.is_empty() || plot_area.size.width == 0 || plot_area.size.height == 0 {
+            return Ok(());
+        }
+
+        // Collect the visible events by screen position
+        let range = RangeTransform::new(x_min, x_max);
+        let width = plot_area.size.width as f32 - 1.0;
+        let mut visible: heapless::Vec<(i32, usize), N> = heapless::Vec::new();
+        for (index, event) in self.events.iter().enumerate() {
+            if event.timestamp < x_min || event.timestamp > x_max {
+                continue;
+            }
+            let norm = f32::from_number(range.normalize(event.timestamp));
+            let x = plot_area.top_left.x + (norm * width) as i32;
+            let _ = visible.push((x, index));
+        }
+        visible.sort_unstable_by_key(|&(x, _)| x);
+
+        let mut clipped = target.clipped(&plot_area);
+        let top = plot_area.top_left.y;
+        let bottom = top + plot_area.size.height as i32 - 1;
+        let mut previous_x = None;
+        let mut label_end = i32::MIN;
+
+        for &(x, index) in visible.iter() {
+            let Some(event) = self.events.get(index) else {
+                continue;
+            };
+            let color = event.color.unwrap_or(self.style.marker_color);
+
+            // Markers on the same pixel column are drawn once
+            if previous_x != Some(x) {
+                Line::new(Point::new(x, top), Point::new(x, bottom))
+                    .into_styled(PrimitiveStyle::with_stroke(color, 1))
+                    .draw(&mut clipped)
+                    .map_err(|_| ChartError::RenderingError)?;
+                self.draw_glyph(event.glyph, Point::new(x, top), color, &mut clipped)?;
+                previous_x = Some(x);
+            }
+
+            if !self.style.show_labels || event.label.is_empty() {
+                continue;
+            }
+            let label_x = x + self.style.glyph_size as i32 / 2 + 2;
+            if label_x < label_end.saturating_add(self.style.min_spacing as i32) {
+                continue;
+            }
+            let char_width = FONT_6X10.character_size.width as i32;
+            Text::with_baseline(
+                &event.label,
+                Point::new(label_x, top + 1),
+                MonoTextStyle::new(&FONT_6X10, self.style.label_color),
+                Baseline::Top,
+            )
+            .draw(&mut clipped)
+            .map_err(|_| ChartError::RenderingError)?;
+            label_end = label_x + event.label.chars().count() as i32 * char_width;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a glyph hanging down from `anchor`
+    fn draw_glyph<D>(
+        &self,
+        glyph: EventGlyph,
+        anchor: Point,
+        color: C,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let size = self.style.glyph_size.max(2);
+        let half = size as i32 / 2;
+        let fill = PrimitiveStyle::with_fill(color);
+        let result = match glyph {
+            EventGlyph::None => Ok(()),
+            EventGlyph::Triangle => Triangle::new(
+                Point::new(anchor.x - half, anchor.y),
+                Point::new(anchor.x + half, anchor.y),
+                Point::new(anchor.x, anchor.y + half),
+            )
+            .into_styled(fill)
+            .draw(target),
+            EventGlyph::Diamond => {
+                let center = Point::new(anchor.x, anchor.y + half);
+                Triangle::new(
+                    Point::new(center.x - half, center.y),
+                    Point::new(center.x + half, center.y),
+                    Point::new(center.x, center.y - half),
+                )
+                .into_styled(fill)
+                .draw(target)
+                .and_then(|_| {
+                    Triangle::new(
+                        Point::new(center.x - half, center.y),
+                        Point::new(center.x + half, center.y),
+                        Point::new(center.x, center.y + half),
+                    )
+                    .into_styled(fill)
+                    .draw(target)
+                })
+            }
+            EventGlyph::Circle => Circle::new(Point::new(anchor.x - half, anchor.y), size)
+                .into_styled(fill)
+                .draw(target),
+            EventGlyph::Square => {
+                Rectangle::new(Point::new(anchor.x - half, anchor.y), Size::new(size, size))
+                    .into_styled(fill)
+                    .draw(target)
+            }
+        };
+        result.map_err(|_| ChartError::RenderingError)
+    }
+}
+
+impl<C: PixelColor, const N: usize> Default for EventOverlay<C, N>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    #[test]
+    fn test_events_clipped_to_range() {
+        let mut overlay: EventOverlay<Rgb565, 4> = EventOverlay::new().with_style(EventStyle {
+            show_labels: false,
+            ..EventStyle::default()
+        });
+        overlay.add_event(5.0, "in", EventGlyph::None).unwrap();
+        overlay.add_event(50.0, "out", EventGlyph::None).unwrap();
+
+        let plot_area = Rectangle::new(Point::new(0, 0), Size::new(11, 20));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        overlay.draw(0.0, 10.0, plot_area, &mut display).unwrap();
+
+        // Only the event at x = 5 is inside the range
+        let marked = Rectangle::new(Point::new(5, 0), Size::new(1, 20));
+        assert_eq!(display.affected_area(), marked);
+    }
+
+    #[test]
+    fn test_dense_labels_are_decluttered() {
+        let mut overlay: EventOverlay<Rgb565, 4> = EventOverlay::new();
+        overlay.add_event(1.0, "a", EventGlyph::None).unwrap();
+        overlay.add_event(1.5, "b", EventGlyph::None).unwrap();
+
+        let plot_area = Rectangle::new(Point::new(0, 0), Size::new(60, 30));
+        let mut dense: MockDisplay<Rgb565> = MockDisplay::new();
+        dense.set_allow_overdraw(true);
+        overlay.draw(0.0, 10.0, plot_area, &mut dense).unwrap();
+
+        let mut single: EventOverlay<Rgb565, 4> = EventOverlay::new();
+        single.add_event(1.0, "a", EventGlyph::None).unwrap();
+        single.add_event(1.5, "", EventGlyph::None).unwrap();
+        let mut expected: MockDisplay<Rgb565> = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        single.draw(0.0, 10.0, plot_area, &mut expected).unwrap();
+
+        // The second label would overlap the first and is skipped
+        assert_eq!(dense, expected);
+    }
+
+    #[test]
+    fn test_overlay_capacity() {
+        let mut overlay: EventOverlay<Rgb565, 1> = EventOverlay::new();
+        overlay.add_event(0.0, "boot", EventGlyph::Circle).unwrap();
+        assert_eq!(
+            overlay.add_event(1.0, "reboot", EventGlyph::Circle),
+            Err(ChartError::MemoryFull)
+        );
+
+        overlay.retain_after(0.5);
+        assert!(overlay.is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+//! Linear forecast overlay for line charts.
+//!
+//! A [`ForecastOverlay`] extends the least-squares trend of a series a fixed
+//! distance beyond its last point as a dashed line, optionally surrounded by a
+//! confidence cone that widens with the distance from the data. Combined with
+//! [`LinearTrend::time_until`] it covers "estimated time to full / empty"
+//! dashboards.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let data = data_points![(0.0, 40.0), (1.0, 46.0), (2.0, 51.0), (3.0, 57.0)];
+//! let chart = LineChart::builder()
+//!     .with_forecast(ForecastOverlay::new(4.0).with_cone(Rgb565::CSS_LIGHT_GRAY, 2.0))
+//!     .build()?;
+//!
+//! // When does the tank reach 100%?
+//! let trend = LinearTrend::fit(&data)?;
+//! assert!(trend.time_until(100.0).is_some());
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::data::{LinearTrend, Point2D};
+use crate::error::{ChartError, ChartResult};
+use crate::grid::{DefaultGridRenderer, GridRenderer};
+use crate::style::LineStyle;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{PrimitiveStyle, Triangle},
+};
+
+/// Appearance of a forecast
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastStyle<C: PixelColor> {
+    /// Style of the projected trend line
+    pub line: LineStyle<C>,
+    /// Fill color of the confidence cone, or `None` to draw only the line
+    pub cone_color: Option<C>,
+    /// Width of the cone in residual standard deviations
+    pub cone_sigmas: f32,
+}
+
+impl<C: PixelColor> Default for ForecastStyle<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            line: LineStyle::dashed(Rgb565::CSS_GRAY.into()),
+            cone_color: None,
+            cone_sigmas: 2.0,
+        }
+    }
+}
+
+/// Dashed trend projection beyond the last data point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastOverlay<C: PixelColor> {
+    /// How far beyond the last point the trend is extended, in x units
+    pub horizon: f32,
+    /// Appearance of the forecast
+    pub style: ForecastStyle<C>,
+}
+
+impl<C: PixelColor> ForecastOverlay<C>
+where
+    C: From<Rgb565>,
+{
+    /// Create a forecast extending `horizon` x units beyond the data
+    pub fn new(horizon: f32) -> Self {
+        Self {
+            horizon: horizon.max(0.0),
+            style: ForecastStyle::default(),
+        }
+    }
+}
+
+impl<C: PixelColor> ForecastOverlay<C> {
+    /// Set the forecast style
+    pub fn with_style(mut self, style: ForecastStyle<C>) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the color of the projected line
+    pub fn line_color(mut self, color: C) -> Self {
+        self.style.line.color = color;
+        self
+    }
+
+    /// Draw a confidence cone `sigmas` residual standard deviations wide
+    pub fn with_cone(mut self, color: C, sigmas: f32) -> Self {
+        self.style.cone_color = Some(color);
+        self.style.cone_sigmas = sigmas;
+        self
+    }
+
+    /// X value where the forecast ends
+    pub fn end_x(&self, trend: &LinearTrend) -> f32 {
+        trend.last_x + self.horizon
+    }
+
+    /// Y range covered by the forecast, including the cone if one is drawn
+    pub fn value_range(&self, trend: &LinearTrend) -> (f32, f32) {
+        let spread = |x: f32| {
+            if self.style.cone_color.is_some() {
+                trend.spread_at(x, self.style.cone_sigmas)
+            } else {
+                0.0
+            }
+        };
+        let (start, end) = (trend.last_x, self.end_x(trend));
+        let low = (trend.value_at(start) - spread(start)).min(trend.value_at(end) - spread(end));
+        let high = (trend.value_at(start) + spread(start)).max(trend.value_at(end) + spread(end));
+        (low, high)
+    }
+
+    /// Draw the forecast, mapping data coordinates with `to_screen`
+    pub fn draw<D, F>(&self, trend: &LinearTrend, to_screen: F, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+        F: Fn(Point2D) -> Point,
+    {
+        let start_x = trend.last_x;
+        let end_x = self.end_x(trend);
+        let start = to_screen(Point2D::new(start_x, trend.value_at(start_x)));
+        let end = to_screen(Point2D::new(end_x, trend.value_at(end_x)));
+
+        if let Some(cone_color) = self.style.cone_color {
+            let sigmas = self.style.cone_sigmas;
+            let corner = |x: f32, sign: f32| {
+                to_screen(Point2D::new(
+                    x,
+                    trend.value_at(x) + sign * trend.spread_at(x, sigmas),
+                ))
+            };
+            let (start_high, start_low) = (corner(start_x, 1.0), corner(start_x, -1.0));
+            let (end_high, end_low) = (corner(end_x, 1.0), corner(end_x, -1.0));
+
+            let fill = PrimitiveStyle::with_fill(cone_color);
+            for triangle in [
+                Triangle::new(start_high, end_high, end_low),
+                Triangle::new(start_high, end_low, start_low),
+            ] {
+                triangle
+                    .into_styled(fill)
+                    .draw(target)
+                    .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+
+        DefaultGridRenderer.draw_grid_line(start, end, &self.style.line, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataSeries, StaticDataSeries};
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn rising() -> StaticDataSeries<Point2D, 8> {
+        StaticDataSeries::from_tuples(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.5), (3.0, 3.0)]).unwrap()
+    }
+
+    #[test]
+    fn test_forecast_value_range_includes_cone() {
+        let trend = LinearTrend::fit(&rising()).unwrap();
+        let plain: ForecastOverlay<Rgb565> = ForecastOverlay::new(2.0);
+        let coned = plain.with_cone(Rgb565::CSS_LIGHT_GRAY, 2.0);
+
+        let (low, high) = plain.value_range(&trend);
+        let (cone_low, cone_high) = coned.value_range(&trend);
+        assert_eq!(plain.end_x(&trend), 5.0);
+        assert!((high - trend.value_at(5.0)).abs() < 0.001);
+        assert!(cone_low < low && cone_high > high);
+    }
+
+    #[test]
+    fn test_forecast_draws_from_last_point() {
+        let data = rising();
+        let trend = LinearTrend::fit(&data).unwrap();
+        let forecast: ForecastOverlay<Rgb565> = ForecastOverlay::new(3.0).line_color(Rgb565::RED);
+
+        // Eight pixels per x unit, y growing upwards from row 40
+        let to_screen =
+            |point: Point2D| Point::new(point.x as i32 * 8, 40 - (point.y * 5.0) as i32);
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        forecast.draw(&trend, to_screen, &mut display).unwrap();
+
+        let area = display.affected_area();
+        assert_eq!(area.top_left.x, data.iter().last().unwrap().x as i32 * 8);
+        assert!(area.size.width > 0);
+    }
+}
@@ -4,14 +4,20 @@
 //! needle animations, and threshold zones. Supports various gauge styles including speedometer,
 //! temperature gauge, and progress indicators.
 
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig};
+use crate::chart::traits::{Chart, ChartBuilder, ChartConfig, TitleStyle};
 use crate::data::{DataPoint, DataSeries};
-use crate::error::{ChartError, ChartResult};
+use crate::error::{ChartError, ChartResult, ErrorContext};
 use crate::math::{Math, NumericConversion};
+use crate::render::{ChartDrawTarget, FallbackTarget, SpanRasterizer};
+use crate::style::{
+    FixedDecimals, FormattedValue, LabelFormat, NumberLocale, Percent, ValueFormatter,
+};
 use embedded_graphics::{
     draw_target::DrawTarget,
+    mono_font::{iso_8859_1, MonoFont, MonoTextStyle},
     prelude::*,
-    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+    primitives::{Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StrokeAlignment},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 use heapless::Vec;
 
@@ -32,15 +38,27 @@ pub struct GaugeChartStyle<C: PixelColor> {
     /// Needle configuration
     pub needle_style: NeedleStyle<C>,
     /// Threshold zones with colors
-    pub threshold_zones: Vec<ThresholdZone<C>, 8>,
+    pub threshold_zones: Vec<ThresholdZone<C>, MAX_THRESHOLD_ZONES>,
     /// Center hub style
     pub center_style: CenterStyle<C>,
     /// Tick marks configuration
     pub tick_style: Option<TickStyle<C>>,
     /// Value display configuration
     pub value_display: Option<ValueDisplayStyle<C>>,
+    /// Value labels along the arc, if any
+    pub scale_labels: Option<ScaleLabelStyle<C>>,
+    /// Discrete step segments replacing the arc, zones and needle, if any
+    pub segments: Option<SegmentStyle<C>>,
+    /// Additional needles, e.g. for a setpoint, drawn behind the main needle
+    pub extra_needles: Vec<NeedleStyle<C>, MAX_EXTRA_NEEDLES>,
 }
 
+/// Number of threshold zones a gauge can hold
+pub const MAX_THRESHOLD_ZONES: usize = 8;
+
+/// Number of needles a gauge can draw besides its main needle
+pub const MAX_EXTRA_NEEDLES: usize = 2;
+
 /// Arc style configuration for the gauge background
 #[derive(Debug, Clone, Copy)]
 pub struct ArcStyle<C: PixelColor> {
@@ -69,6 +87,10 @@ pub struct NeedleStyle<C: PixelColor> {
     pub width: u32,
     /// Whether needle has animation
     pub animated: bool,
+    /// Length of the tail behind the pivot as percentage of radius (0.0 for no tail)
+    pub tail_length: f32,
+    /// Radius of the counterweight disc at the end of the tail, if any
+    pub counterweight: Option<u32>,
 }
 
 /// Available needle shapes
@@ -104,6 +126,10 @@ pub struct CenterStyle<C: PixelColor> {
     pub radius: u32,
     /// Whether to show center hub
     pub visible: bool,
+    /// Border color of the center hub, if any
+    pub border_color: Option<C>,
+    /// Border width of the center hub
+    pub border_width: u32,
 }
 
 /// Tick marks style
@@ -127,6 +153,36 @@ pub struct TickStyle<C: PixelColor> {
     pub minor_count: u32,
 }
 
+/// Segmented ring style for step-based processes
+///
+/// The arc is split into `count` equal segments separated by `gap` degrees.
+/// Segments before the current step use `done_color`, the segment of the
+/// current step uses `current_color` and the rest use `pending_color`.
+#[derive(Debug, Clone, Copy)]
+pub struct SegmentStyle<C: PixelColor> {
+    /// Number of steps
+    pub count: u32,
+    /// Gap between adjacent segments in degrees
+    pub gap: f32,
+    /// Color of completed steps
+    pub done_color: C,
+    /// Color of the step in progress
+    pub current_color: C,
+    /// Color of steps not yet started
+    pub pending_color: C,
+}
+
+/// Progress state of one segment of a segmented gauge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentState {
+    /// The step is completed
+    Done,
+    /// The step is in progress
+    Current,
+    /// The step has not started yet
+    Pending,
+}
+
 /// Value display style
 #[derive(Debug, Clone)]
 pub struct ValueDisplayStyle<C: PixelColor> {
@@ -134,6 +190,8 @@ pub struct ValueDisplayStyle<C: PixelColor> {
     pub color: C,
     /// Font size (if supported)
     pub font_size: u32,
+    /// Font of the readout
+    pub font: &'static MonoFont<'static>,
     /// Position relative to center
     pub position: ValueDisplayPosition,
     /// Number format
@@ -144,6 +202,47 @@ pub struct ValueDisplayStyle<C: PixelColor> {
     pub units: Option<heapless::String<8>>,
 }
 
+impl<C: PixelColor> Default for ValueDisplayStyle<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            color: embedded_graphics::pixelcolor::Rgb565::BLACK.into(),
+            font_size: 12,
+            font: GAUGE_FONT,
+            position: ValueDisplayPosition::Below,
+            format: ValueFormat::Integer,
+            show_units: false,
+            units: None,
+        }
+    }
+}
+
+/// Default font of gauge text
+///
+/// The ISO 8859-1 variant covers unit symbols such as `°` and `µ`.
+pub const GAUGE_FONT: &MonoFont<'static> = &iso_8859_1::FONT_6X10;
+
+/// Value labels along the gauge arc
+///
+/// Labels are placed just inside the arc at `divisions + 1` evenly spaced
+/// values, from the range minimum at the start of the arc to the maximum at
+/// its end. With one division only the minimum and maximum are labelled.
+#[derive(Debug, Clone, Copy)]
+pub struct ScaleLabelStyle<C: PixelColor> {
+    /// Text color
+    pub color: C,
+    /// Font of the labels
+    pub font: &'static MonoFont<'static>,
+    /// Number format
+    pub format: ValueFormat,
+    /// Number of intervals between labels
+    pub divisions: u32,
+    /// Gap in pixels between the inner edge of the arc and the labels
+    pub gap: u32,
+}
+
 /// Value display position
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValueDisplayPosition {
@@ -164,11 +263,59 @@ pub enum ValueFormat {
     OneDecimal,
     /// Two decimal places
     TwoDecimal,
-    /// Percentage
+    /// Percentage of the gauge range
     Percentage,
+    /// Any of the shared value formatters, e.g. with units or SI prefixes
+    Formatted(LabelFormat),
+}
+
+impl ValueFormat {
+    /// Format `value` for display on a gauge covering `range`
+    pub fn format(&self, value: f32, range: ValueRange) -> FormattedValue {
+        match *self {
+            Self::Integer => FixedDecimals::new(0).format(value),
+            Self::OneDecimal => FixedDecimals::new(1).format(value),
+            Self::TwoDecimal => FixedDecimals::new(2).format(value),
+            Self::Percentage => {
+                let span = range.max - range.min;
+                let ratio = if span > 0.0 {
+                    (value - range.min) / span
+                } else {
+                    0.0
+                };
+                Percent::of_ratio(0).format(ratio)
+            }
+            Self::Formatted(format) => format.format(value),
+        }
+    }
+}
+
+impl<C: PixelColor> ValueDisplayStyle<C> {
+    /// Text shown for `value`, including the units if enabled
+    pub fn text(&self, value: f32, range: ValueRange) -> FormattedValue {
+        let mut text = self.format.format(value, range);
+        if let (true, Some(units)) = (self.show_units, self.units.as_ref()) {
+            let _ = text.push_str(units);
+        }
+        text
+    }
+
+    /// Text shown for `value` with the decimal separator and grouping of `locale`
+    pub fn localized_text(
+        &self,
+        value: f32,
+        range: ValueRange,
+        locale: NumberLocale,
+    ) -> FormattedValue {
+        locale.localize(&self.text(value, range))
+    }
 }
 
 /// Gauge type configuration
+///
+/// Angles are in degrees, with 0° pointing right and positive angles turning
+/// clockwise on screen. The minimum value sits at the start angle and the
+/// maximum value at the end angle.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GaugeType {
     /// Semicircle gauge (180 degrees)
@@ -186,6 +333,38 @@ pub enum GaugeType {
     },
 }
 
+impl GaugeType {
+    /// Arc starting at `start_angle` and sweeping `sweep` degrees
+    ///
+    /// The sweep runs clockwise on screen and must be positive, at most 360°.
+    /// `arc(135.0, 270.0)` is the classic 270° dial open at the bottom.
+    pub fn arc(start_angle: f32, sweep: f32) -> Self {
+        Self::Custom {
+            start_angle,
+            end_angle: start_angle + sweep,
+        }
+    }
+
+    /// Start and end angles of the arc
+    pub fn angles(self) -> (f32, f32) {
+        match self {
+            Self::Semicircle => (-90.0, 90.0),
+            Self::ThreeQuarter => (-135.0, 135.0),
+            Self::FullCircle => (0.0, 360.0),
+            Self::Custom {
+                start_angle,
+                end_angle,
+            } => (start_angle, end_angle),
+        }
+    }
+
+    /// Degrees swept from the start to the end angle, negative for counter-clockwise
+    pub fn sweep(self) -> f32 {
+        let (start, end) = self.angles();
+        end - start
+    }
+}
+
 /// Value range for the gauge
 #[derive(Debug, Clone, Copy)]
 pub struct ValueRange {
@@ -195,6 +374,62 @@ pub struct ValueRange {
     pub max: f32,
 }
 
+/// Sort threshold zones, clamp them to the value range and reject overlaps
+///
+/// With `strict` set, zones lying entirely outside the range are an error;
+/// otherwise they are dropped.
+///
+/// # Errors
+///
+/// - [`ChartError::InvalidRange`] if the range is empty or a zone ends before
+///   it starts
+/// - [`ChartError::ConfigError`] (in strict mode) if a zone lies outside the
+///   range, or if two zones overlap
+fn normalize_threshold_zones<C: PixelColor>(
+    zones: &mut Vec<ThresholdZone<C>, MAX_THRESHOLD_ZONES>,
+    range: ValueRange,
+    strict: bool,
+) -> ChartResult<()> {
+    if range.min.partial_cmp(&range.max) != Some(core::cmp::Ordering::Less) {
+        return Err(ChartError::InvalidRange);
+    }
+
+    let mut index = 0;
+    while index < zones.len() {
+        let zone = &mut zones[index];
+        if zone.start.partial_cmp(&zone.end) != Some(core::cmp::Ordering::Less) {
+            return Err(ChartError::InvalidRange);
+        }
+        if zone.end <= range.min || zone.start >= range.max {
+            if strict {
+                return Err(ChartError::ConfigError(ErrorContext::new(
+                    "normalize threshold zones",
+                    "Keep threshold zones inside the gauge value range",
+                )));
+            }
+            zones.remove(index);
+            continue;
+        }
+        zone.start = zone.start.max(range.min);
+        zone.end = zone.end.min(range.max);
+        index += 1;
+    }
+
+    zones.sort_unstable_by(|a, b| {
+        a.start
+            .partial_cmp(&b.start)
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    if zones.windows(2).any(|pair| pair[0].end > pair[1].start) {
+        return Err(ChartError::ConfigError(ErrorContext::new(
+            "normalize threshold zones",
+            "Threshold zones must not overlap",
+        )));
+    }
+
+    Ok(())
+}
+
 impl<C: PixelColor> GaugeChart<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -237,34 +472,73 @@ where
         &self.config
     }
 
+    /// Copy of this gauge with a different arc radius
+    pub fn with_radius(&self, radius: u32) -> Self {
+        let mut gauge = self.clone();
+        gauge.style.arc_style.radius = radius;
+        gauge
+    }
+
+    /// Copy of this gauge covering another value range
+    ///
+    /// Threshold zones keep their position relative to the range, so a zone
+    /// covering the top 20% of the old range covers the top 20% of the new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChartError::InvalidRange`] if `min` is not below `max`.
+    pub fn with_value_range(&self, min: f32, max: f32) -> ChartResult<Self> {
+        let range = ValueRange { min, max };
+        if min.partial_cmp(&max) != Some(core::cmp::Ordering::Less) {
+            return Err(ChartError::InvalidRange);
+        }
+
+        let old = self.value_range;
+        let scale = (range.max - range.min) / (old.max - old.min);
+        let mut gauge = self.clone();
+        for zone in gauge.style.threshold_zones.iter_mut() {
+            zone.start = range.min + (zone.start - old.min) * scale;
+            zone.end = range.min + (zone.end - old.min) * scale;
+        }
+        gauge.value_range = range;
+        Ok(gauge)
+    }
+
+    /// Progress state of segment `index` when the gauge shows `value`
+    ///
+    /// The value range is divided evenly into the segments, so on a gauge
+    /// with range `0..12` and 12 segments a value of `5.0` means steps 0 to 4
+    /// are done and step 5 is current. The maximum value completes every step.
+    /// Gauges without segments treat the whole arc as a single segment.
+    pub fn segment_state(&self, index: u32, value: f32) -> SegmentState {
+        let count = self
+            .style
+            .segments
+            .map_or(1, |segments| segments.count.max(1));
+        let span = self.value_range.max - self.value_range.min;
+        let normalized = ((value - self.value_range.min) / span).clamp(0.0, 1.0);
+        let current = (normalized * count as f32) as u32;
+
+        match index.cmp(&current) {
+            core::cmp::Ordering::Less => SegmentState::Done,
+            core::cmp::Ordering::Equal => SegmentState::Current,
+            core::cmp::Ordering::Greater => SegmentState::Pending,
+        }
+    }
+
     /// Calculate the angle for a given value
     fn value_to_angle(&self, value: f32) -> f32 {
         let normalized =
             (value - self.value_range.min) / (self.value_range.max - self.value_range.min);
         let normalized = normalized.clamp(0.0, 1.0);
 
-        match self.gauge_type {
-            GaugeType::Semicircle => -90.0 + (normalized * 180.0),
-            GaugeType::ThreeQuarter => -135.0 + (normalized * 270.0),
-            GaugeType::FullCircle => normalized * 360.0,
-            GaugeType::Custom {
-                start_angle,
-                end_angle,
-            } => start_angle + (normalized * (end_angle - start_angle)),
-        }
+        let (start_angle, _) = self.gauge_type.angles();
+        start_angle + normalized * self.gauge_type.sweep()
     }
 
     /// Get the start and end angles for the gauge type
     fn get_angle_range(&self) -> (f32, f32) {
-        match self.gauge_type {
-            GaugeType::Semicircle => (-90.0, 90.0),
-            GaugeType::ThreeQuarter => (-135.0, 135.0),
-            GaugeType::FullCircle => (0.0, 360.0),
-            GaugeType::Custom {
-                start_angle,
-                end_angle,
-            } => (start_angle, end_angle),
-        }
+        self.gauge_type.angles()
     }
 
     /// Draw the gauge background arc
@@ -308,11 +582,19 @@ where
     }
 
     /// Draw threshold zones
+    ///
+    /// Each zone is filled as an exact annular sector around the arc radius.
+    /// Pixels are assigned to zones by the half-open angle range
+    /// `[start, end)`, so adjacent zones share an edge without gaps or
+    /// overlap at any radius or thickness.
     fn draw_threshold_zones<D>(&self, center: Point, target: &mut D) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
-        let radius = self.style.arc_style.radius;
+        let radius = self.style.arc_style.radius as f32;
+        let half_width = self.style.arc_style.value_width as f32 / 2.0;
+        let inner = (radius - half_width).max(0.0);
+        let outer = radius + half_width;
         let (start_angle, end_angle) = self.get_angle_range();
 
         for zone in &self.style.threshold_zones {
@@ -329,44 +611,123 @@ where
                 continue;
             }
 
-            let segments = ((zone_end_angle - zone_start_angle).abs() / 3.0).max(1.0) as u32;
-            let angle_step = (zone_end_angle - zone_start_angle) / segments as f32;
-
-            for i in 0..segments {
-                let angle1 = zone_start_angle + (i as f32 * angle_step);
-                let angle2 = zone_start_angle + ((i + 1) as f32 * angle_step);
-                let angle1_rad = angle1.to_radians();
-                let angle2_rad = angle2.to_radians();
-                let angle1_num = angle1_rad.to_number();
-                let angle2_num = angle2_rad.to_number();
-                let radius_num = (radius as f32).to_number();
-
-                let x1 = center.x + f32::from_number(radius_num * Math::cos(angle1_num)) as i32;
-                let y1 = center.y + f32::from_number(radius_num * Math::sin(angle1_num)) as i32;
-                let x2 = center.x + f32::from_number(radius_num * Math::cos(angle2_num)) as i32;
-                let y2 = center.y + f32::from_number(radius_num * Math::sin(angle2_num)) as i32;
-
-                Line::new(Point::new(x1, y1), Point::new(x2, y2))
-                    .into_styled(PrimitiveStyle::with_stroke(
-                        zone.color,
-                        self.style.arc_style.value_width,
-                    ))
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
+            // The final ray of the gauge belongs to the zone that ends there
+            let closed_end = zone_end_angle >= end_angle;
+            self.fill_annular_sector(
+                center,
+                (inner, outer),
+                (zone_start_angle, zone_end_angle, closed_end),
+                zone.color,
+                target,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draw the step segments, colored by their progress state
+    fn draw_segments<D>(
+        &self,
+        center: Point,
+        segments: &SegmentStyle<C>,
+        value: f32,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        let radius = self.style.arc_style.radius as f32;
+        let half_width = self.style.arc_style.value_width as f32 / 2.0;
+        let inner = (radius - half_width).max(0.0);
+        let outer = radius + half_width;
+        let (start_angle, end_angle) = self.get_angle_range();
+        let step = (end_angle - start_angle) / segments.count as f32;
+        let half_gap = (segments.gap / 2.0).clamp(0.0, step / 2.0);
+
+        for index in 0..segments.count {
+            let segment_start = start_angle + index as f32 * step + half_gap;
+            let segment_end = start_angle + (index + 1) as f32 * step - half_gap;
+            if segment_start >= segment_end {
+                continue;
             }
+
+            let color = match self.segment_state(index, value) {
+                SegmentState::Done => segments.done_color,
+                SegmentState::Current => segments.current_color,
+                SegmentState::Pending => segments.pending_color,
+            };
+            self.fill_annular_sector(
+                center,
+                (inner, outer),
+                (segment_start, segment_end, half_gap == 0.0),
+                color,
+                target,
+            )?;
         }
         Ok(())
     }
 
-    /// Draw the needle
-    fn draw_needle<D>(&self, center: Point, value: f32, target: &mut D) -> ChartResult<()>
+    /// Fill the pixels between two radii whose angle lies in `[start, end)`,
+    /// or `[start, end]` when `closed_end` is set
+    fn fill_annular_sector<D>(
+        &self,
+        center: Point,
+        (inner, outer): (f32, f32),
+        (start, end, closed_end): (f32, f32, bool),
+        color: C,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        let (gauge_start, _) = self.get_angle_range();
+        let inner_sq = inner * inner;
+        let outer_sq = outer * outer;
+
+        let in_sector = |x: i32, y: i32| {
+            let dx = (x - center.x) as f32;
+            let dy = (y - center.y) as f32;
+            let distance_sq = dx * dx + dy * dy;
+            if distance_sq < inner_sq || distance_sq >= outer_sq {
+                return false;
+            }
+
+            // Screen angle in the gauge convention (x = cos, y = sin), unwrapped
+            // into the turn that starts at the gauge start angle
+            let mut angle =
+                f32::from_number(Math::atan2(dy.to_number(), dx.to_number())).to_degrees();
+            while angle < gauge_start {
+                angle += 360.0;
+            }
+            while angle >= gauge_start + 360.0 {
+                angle -= 360.0;
+            }
+
+            angle >= start && (angle < end || (closed_end && angle <= end))
+        };
+
+        let extent = outer as i32 + 1;
+        let area = Rectangle::new(
+            center - Point::new(extent, extent),
+            Size::new(2 * extent as u32 + 1, 2 * extent as u32 + 1),
+        );
+        SpanRasterizer::for_target(target).fill_mask(area, in_sector, color, target)?;
+        Ok(())
+    }
+
+    /// Draw a needle pointing at `value`
+    fn draw_needle<D>(
+        &self,
+        center: Point,
+        needle: &NeedleStyle<C>,
+        value: f32,
+        target: &mut D,
+    ) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>,
     {
         let angle = self.value_to_angle(value);
         let angle_rad = angle.to_radians();
-        let needle_length =
-            (self.style.arc_style.radius as f32 * self.style.needle_style.length) as u32;
+        let needle_length = (self.style.arc_style.radius as f32 * needle.length) as u32;
         let angle_num = angle_rad.to_number();
         let needle_length_num = (needle_length as f32).to_number();
 
@@ -376,17 +737,11 @@ where
             center.y + f32::from_number(needle_length_num * Math::sin(angle_num)) as i32;
 
         Line::new(center, Point::new(needle_end_x, needle_end_y))
-            .into_styled(PrimitiveStyle::with_stroke(
-                self.style.needle_style.color,
-                self.style.needle_style.width,
-            ))
+            .into_styled(PrimitiveStyle::with_stroke(needle.color, needle.width))
             .draw(target)
             .map_err(|_| ChartError::RenderingError)?;
 
-        if matches!(
-            self.style.needle_style.shape,
-            NeedleShape::Arrow | NeedleShape::Pointer
-        ) {
+        if matches!(needle.shape, NeedleShape::Arrow | NeedleShape::Pointer) {
             let arrow_length = 8;
             let arrow_angle = 0.5;
             let arrow_angle1 = angle_rad + arrow_angle;
@@ -408,22 +763,54 @@ where
                 Point::new(needle_end_x, needle_end_y),
                 Point::new(arrow_x1, arrow_y1),
             )
-            .into_styled(PrimitiveStyle::with_stroke(
-                self.style.needle_style.color,
-                self.style.needle_style.width,
-            ))
+            .into_styled(PrimitiveStyle::with_stroke(needle.color, needle.width))
             .draw(target)
             .map_err(|_| ChartError::RenderingError)?;
             Line::new(
                 Point::new(needle_end_x, needle_end_y),
                 Point::new(arrow_x2, arrow_y2),
             )
-            .into_styled(PrimitiveStyle::with_stroke(
-                self.style.needle_style.color,
-                self.style.needle_style.width,
-            ))
+            .into_styled(PrimitiveStyle::with_stroke(needle.color, needle.width))
+            .draw(target)
+            .map_err(|_| ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+
+    /// Draw the tail and counterweight of a needle behind the pivot
+    fn draw_needle_tail<D>(
+        &self,
+        center: Point,
+        needle: &NeedleStyle<C>,
+        value: f32,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let tail_length = self.style.arc_style.radius as f32 * needle.tail_length;
+        if tail_length < 1.0 {
+            return Ok(());
+        }
+
+        // The tail points away from the value, through the pivot
+        let angle_num = self.value_to_angle(value).to_radians().to_number();
+        let tail_length_num = tail_length.to_number();
+        let tail_end = Point::new(
+            center.x - f32::from_number(tail_length_num * Math::cos(angle_num)) as i32,
+            center.y - f32::from_number(tail_length_num * Math::sin(angle_num)) as i32,
+        );
+
+        Line::new(center, tail_end)
+            .into_styled(PrimitiveStyle::with_stroke(needle.color, needle.width))
             .draw(target)
             .map_err(|_| ChartError::RenderingError)?;
+
+        if let Some(radius) = needle.counterweight {
+            Circle::with_center(tail_end, radius * 2 + 1)
+                .into_styled(PrimitiveStyle::with_fill(needle.color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
         }
         Ok(())
     }
@@ -433,20 +820,237 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        if self.style.center_style.visible {
+        let hub = &self.style.center_style;
+        if hub.visible {
+            let mut style = PrimitiveStyleBuilder::new().fill_color(hub.color);
+            if let Some(border_color) = hub.border_color {
+                style = style
+                    .stroke_color(border_color)
+                    .stroke_width(hub.border_width)
+                    .stroke_alignment(StrokeAlignment::Inside);
+            }
+
             Circle::new(
-                Point::new(
-                    center.x - self.style.center_style.radius as i32,
-                    center.y - self.style.center_style.radius as i32,
-                ),
-                self.style.center_style.radius * 2,
+                Point::new(center.x - hub.radius as i32, center.y - hub.radius as i32),
+                hub.radius * 2,
             )
-            .into_styled(PrimitiveStyle::with_fill(self.style.center_style.color))
+            .into_styled(style.build())
             .draw(target)
             .map_err(|_| ChartError::RenderingError)?;
         }
         Ok(())
     }
+
+    /// Draw the value readout in the chart's locale
+    fn draw_value_display<D>(
+        &self,
+        center: Point,
+        value: f32,
+        locale: NumberLocale,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let Some(display) = self.style.value_display.as_ref() else {
+            return Ok(());
+        };
+
+        let offset = self.style.arc_style.radius as i32 / 2;
+        let position = match display.position {
+            ValueDisplayPosition::Center => center,
+            ValueDisplayPosition::Below => center + Point::new(0, offset),
+            ValueDisplayPosition::Above => center - Point::new(0, offset),
+        };
+
+        Text::with_text_style(
+            &display.localized_text(value, self.value_range, locale),
+            position,
+            MonoTextStyle::new(display.font, display.color),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build(),
+        )
+        .draw(target)
+        .map_err(|_| ChartError::RenderingError)?;
+        Ok(())
+    }
+
+    /// Draw the value labels along the arc in the chart's locale
+    fn draw_scale_labels<D>(
+        &self,
+        center: Point,
+        locale: NumberLocale,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let Some(labels) = self.style.scale_labels.as_ref() else {
+            return Ok(());
+        };
+
+        let divisions = labels.divisions.max(1);
+        // On a full circle the maximum would be drawn over the minimum
+        let count = if self.gauge_type.sweep() >= 360.0 {
+            divisions
+        } else {
+            divisions + 1
+        };
+        let text_style = MonoTextStyle::new(labels.font, labels.color);
+        let centered = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+
+        for i in 0..count {
+            let value = self.value_range.min
+                + (self.value_range.max - self.value_range.min) * i as f32 / divisions as f32;
+            let text: FormattedValue =
+                locale.localize(&labels.format.format(value, self.value_range));
+            let position = self.scale_label_center(
+                center,
+                self.value_to_angle(value),
+                text_size(labels.font, &text),
+                labels.gap,
+            );
+            Text::with_text_style(&text, position, text_style, centered)
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+
+    /// Center of a label of `size` placed just inside the arc at `angle`
+    ///
+    /// The label box touches the circle `gap` pixels inside the arc's inner
+    /// edge, so wide labels move further in at the sides of the dial than at
+    /// its top and bottom.
+    fn scale_label_center(&self, center: Point, angle: f32, size: Size, gap: u32) -> Point {
+        let angle_num = angle.to_radians().to_number();
+        let cos = f32::from_number(Math::cos(angle_num));
+        let sin = f32::from_number(Math::sin(angle_num));
+
+        let arc = &self.style.arc_style;
+        let inner = arc.radius as f32 - arc.background_width as f32 / 2.0 - gap as f32;
+        let inset = cos.abs() * size.width as f32 / 2.0 + sin.abs() * size.height as f32 / 2.0;
+        let distance = (inner - inset).max(0.0);
+
+        center + Point::new((distance * cos) as i32, (distance * sin) as i32)
+    }
+
+    /// Needle pivot for a chart drawn with `config` into `viewport`
+    ///
+    /// Everything the gauge draws is laid out around this point, so text or
+    /// markers overlaid by the application line up with the needle.
+    pub fn pivot(&self, config: &ChartConfig<C>, viewport: Rectangle) -> Point {
+        let draw_area = config.margins.apply_to(config.content_area(viewport));
+        Point::new(
+            draw_area.top_left.x + draw_area.size.width as i32 / 2,
+            draw_area.top_left.y + draw_area.size.height as i32 / 2,
+        )
+    }
+}
+
+/// Size of `text` set in `font` on a single line
+fn text_size(font: &MonoFont<'_>, text: &str) -> Size {
+    let chars = text.chars().count() as u32;
+    let spacing = chars.saturating_sub(1) * font.character_spacing;
+    Size::new(
+        chars * font.character_size.width + spacing,
+        font.character_size.height,
+    )
+}
+
+impl<C: PixelColor> GaugeChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Draw the gauge with one value per needle
+    ///
+    /// `values[0]` drives the main needle and the value readout, and the
+    /// following values drive the [extra needles](GaugeChartBuilder::add_needle)
+    /// in the order they were added. Needles without a value are not drawn, and
+    /// values without a needle are ignored. [`Chart::draw`] draws the first
+    /// point of its series this way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_charts::prelude::*;
+    /// use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+    ///
+    /// // Current temperature and setpoint on a 270° dial
+    /// let gauge = GaugeChart::builder()
+    ///     .arc(135.0, 270.0)
+    ///     .value_range(0.0, 120.0)
+    ///     .radius(24)
+    ///     .add_needle(NeedleShape::Line, Rgb565::BLUE, 0.9, 1)
+    ///     .build()?;
+    ///
+    /// let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+    /// gauge.draw_values(&[72.5, 80.0], gauge.config(), viewport, &mut display)?;
+    /// # Ok::<(), embedded_charts::error::ChartError>(())
+    /// ```
+    pub fn draw_values<D>(
+        &self,
+        values: &[f32],
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_values_accelerated(values, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    /// Draw the gauge with one value per needle using the target's fast paths
+    ///
+    /// Same as [`draw_values`](Self::draw_values), with threshold zones and
+    /// segments written through [`ChartDrawTarget::fill_span`].
+    pub fn draw_values_accelerated<D>(
+        &self,
+        values: &[f32],
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        config.draw_background(viewport, target)?;
+        config.draw_title(viewport, target)?;
+
+        let center = self.pivot(config, viewport);
+        let current_value = values.first().copied().unwrap_or(0.0);
+
+        if let Some(segments) = self.style.segments.as_ref() {
+            self.draw_segments(center, segments, current_value, target)?;
+            self.draw_scale_labels(center, config.locale, target)?;
+        } else {
+            self.draw_background_arc(center, target)?;
+            self.draw_threshold_zones(center, target)?;
+            self.draw_scale_labels(center, config.locale, target)?;
+
+            // Extra needles sit behind the main needle
+            for (needle, &value) in self.style.extra_needles.iter().zip(values.iter().skip(1)) {
+                self.draw_needle_tail(center, needle, value, target)?;
+                self.draw_needle(center, needle, value, target)?;
+            }
+
+            let needle = &self.style.needle_style;
+            self.draw_needle_tail(center, needle, current_value, target)?;
+            self.draw_needle(center, needle, current_value, target)?;
+            self.draw_center_hub(center, target)?;
+        }
+        self.draw_value_display(center, current_value, config.locale, target)?;
+
+        Ok(())
+    }
 }
 
 impl<C: PixelColor> Default for GaugeChart<C>
@@ -475,31 +1079,36 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        if let Some(bg_color) = config.background_color {
-            Rectangle::new(viewport.top_left, viewport.size)
-                .into_styled(PrimitiveStyle::with_fill(bg_color))
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-        }
-
-        let draw_area = config.margins.apply_to(viewport);
-        let center = Point::new(
-            draw_area.top_left.x + draw_area.size.width as i32 / 2,
-            draw_area.top_left.y + draw_area.size.height as i32 / 2,
-        );
-
         let current_value = if let Some(point) = data.iter().next() {
             point.y()
         } else {
             0.0
         };
 
-        self.draw_background_arc(center, target)?;
-        self.draw_threshold_zones(center, target)?;
-        self.draw_needle(center, current_value, target)?;
-        self.draw_center_hub(center, target)?;
+        self.draw_values(&[current_value], config, viewport, target)
+    }
 
-        Ok(())
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        let current_value = data.iter().next().map_or(0.0, |point| point.y());
+        self.draw_values_accelerated(&[current_value], config, viewport, target)
+    }
+}
+
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for GaugeChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
     }
 }
 
@@ -542,12 +1151,16 @@ where
                 length: 0.8,
                 width: 2,
                 animated: true,
+                tail_length: 0.0,
+                counterweight: None,
             },
             threshold_zones,
             center_style: CenterStyle {
                 color: embedded_graphics::pixelcolor::Rgb565::BLACK.into(),
                 radius: 5,
                 visible: true,
+                border_color: None,
+                border_width: 0,
             },
             tick_style: Some(TickStyle {
                 major_color: embedded_graphics::pixelcolor::Rgb565::BLACK.into(),
@@ -559,14 +1172,10 @@ where
                 major_count: 10,
                 minor_count: 5,
             }),
-            value_display: Some(ValueDisplayStyle {
-                color: embedded_graphics::pixelcolor::Rgb565::BLACK.into(),
-                font_size: 12,
-                position: ValueDisplayPosition::Below,
-                format: ValueFormat::Integer,
-                show_units: false,
-                units: None,
-            }),
+            value_display: Some(ValueDisplayStyle::default()),
+            scale_labels: None,
+            segments: None,
+            extra_needles: Vec::new(),
         }
     }
 }
@@ -578,6 +1187,12 @@ pub struct GaugeChartBuilder<C: PixelColor> {
     config: ChartConfig<C>,
     gauge_type: GaugeType,
     value_range: ValueRange,
+    /// Whether the default threshold zones were replaced by custom ones
+    custom_zones: bool,
+    /// Whether more zones were added than the style can hold
+    zones_overflow: bool,
+    /// Whether more needles were added than the style can hold
+    needles_overflow: bool,
 }
 
 impl<C: PixelColor> GaugeChartBuilder<C>
@@ -594,6 +1209,9 @@ where
                 min: 0.0,
                 max: 100.0,
             },
+            custom_zones: false,
+            zones_overflow: false,
+            needles_overflow: false,
         }
     }
 
@@ -603,6 +1221,14 @@ where
         self
     }
 
+    /// Use an arc starting at `start_angle` and sweeping `sweep` degrees
+    ///
+    /// See [`GaugeType::arc`]; a sweep of 360° draws a full circle.
+    pub fn arc(mut self, start_angle: f32, sweep: f32) -> Self {
+        self.gauge_type = GaugeType::arc(start_angle, sweep);
+        self
+    }
+
     /// Set the value range
     pub fn value_range(mut self, min: f32, max: f32) -> Self {
         self.value_range = ValueRange { min, max };
@@ -622,20 +1248,124 @@ where
             color,
             length: length.clamp(0.0, 1.0),
             width,
-            animated: self.style.needle_style.animated,
+            ..self.style.needle_style
+        };
+        self
+    }
+
+    /// Add a needle driven by its own value, e.g. a setpoint
+    ///
+    /// Up to [`MAX_EXTRA_NEEDLES`] needles can be added besides the main one.
+    /// Values are passed to [`GaugeChart::draw_values`] in the order the
+    /// needles were added, after the value of the main needle.
+    pub fn add_needle(mut self, shape: NeedleShape, color: C, length: f32, width: u32) -> Self {
+        let needle = NeedleStyle {
+            shape,
+            color,
+            length: length.clamp(0.0, 1.0),
+            width,
+            animated: false,
+            tail_length: 0.0,
+            counterweight: None,
         };
+        if self.style.extra_needles.push(needle).is_err() {
+            self.needles_overflow = true;
+        }
+        self
+    }
+
+    /// Add a tail behind the needle pivot
+    ///
+    /// `length` is a percentage of the radius (0.0 to 1.0). A counterweight
+    /// disc of the given radius is drawn at the end of the tail.
+    pub fn needle_tail(mut self, length: f32, counterweight: Option<u32>) -> Self {
+        self.style.needle_style.tail_length = length.clamp(0.0, 1.0);
+        self.style.needle_style.counterweight = counterweight;
+        self
+    }
+
+    /// Set the center cap covering the needle pivot
+    pub fn center_cap(mut self, radius: u32, color: C) -> Self {
+        self.style.center_style.radius = radius;
+        self.style.center_style.color = color;
+        self.style.center_style.visible = true;
+        self
+    }
+
+    /// Set the border of the center cap
+    pub fn center_cap_border(mut self, color: C, width: u32) -> Self {
+        self.style.center_style.border_color = Some(color);
+        self.style.center_style.border_width = width;
+        self
+    }
+
+    /// Show or hide the center cap
+    pub fn show_center_cap(mut self, show: bool) -> Self {
+        self.style.center_style.visible = show;
         self
     }
 
     /// Add a threshold zone
+    ///
+    /// The first zone added replaces the default zones. Zones may be added in
+    /// any order; they are sorted and clamped to the value range when the chart
+    /// is built, and overlapping zones are rejected.
     pub fn add_threshold_zone(mut self, start: f32, end: f32, color: C) -> Self {
-        if self.style.threshold_zones.len() < 8 {
-            let _ = self.style.threshold_zones.push(ThresholdZone {
-                start,
-                end,
-                color,
-                label: None,
-            });
+        if !self.custom_zones {
+            self.style.threshold_zones.clear();
+            self.custom_zones = true;
+        }
+        let zone = ThresholdZone {
+            start,
+            end,
+            color,
+            label: None,
+        };
+        if self.style.threshold_zones.push(zone).is_err() {
+            self.zones_overflow = true;
+        }
+        self
+    }
+
+    /// Draw the gauge as `count` discrete step segments separated by `gap` degrees
+    ///
+    /// The segments replace the background arc, threshold zones, needle and
+    /// center cap; see [`GaugeChart::segment_state`] for how the value maps to
+    /// steps. Segments are green when done, orange when current and gray when
+    /// pending until changed with [`segment_colors`](Self::segment_colors).
+    pub fn segmented(mut self, count: u32, gap: f32) -> Self {
+        let colors = self.style.segments.map_or(
+            (
+                embedded_graphics::pixelcolor::Rgb565::GREEN.into(),
+                embedded_graphics::pixelcolor::Rgb565::CSS_ORANGE.into(),
+                embedded_graphics::pixelcolor::Rgb565::CSS_GRAY.into(),
+            ),
+            |segments| {
+                (
+                    segments.done_color,
+                    segments.current_color,
+                    segments.pending_color,
+                )
+            },
+        );
+        self.style.segments = Some(SegmentStyle {
+            count,
+            gap: gap.max(0.0),
+            done_color: colors.0,
+            current_color: colors.1,
+            pending_color: colors.2,
+        });
+        self
+    }
+
+    /// Set the colors of done, current and pending segments
+    ///
+    /// Has no effect unless [`segmented`](Self::segmented) was called first.
+    pub fn segment_colors(mut self, done: C, current: C, pending: C) -> Self {
+        if let Some(segments) = self.style.segments.as_mut() {
+            segments.done_color = done;
+            segments.current_color = current;
+            segments.pending_color = pending;
         }
         self
     }
@@ -648,8 +1378,157 @@ where
         self
     }
 
+    /// Set a subtitle drawn below the title
+    pub fn with_subtitle(mut self, subtitle: &str) -> Self {
+        self.config.subtitle = heapless::String::try_from(subtitle).ok();
+        self
+    }
+
+    /// Set the fonts, colors and alignment of the title and subtitle
+    pub fn title_style(mut self, style: TitleStyle<C>) -> Self {
+        self.config.title_style = Some(style);
+        self
+    }
+
+    /// Set the number locale used for the value readout
+    pub fn with_locale(mut self, locale: NumberLocale) -> Self {
+        self.config.locale = locale;
+        self
+    }
+
+    /// Hide the value readout
+    pub fn without_value_display(mut self) -> Self {
+        self.style.value_display = None;
+        self
+    }
+
+    /// Show the value readout with the given format and color
+    pub fn value_display(mut self, format: ValueFormat, color: C) -> Self {
+        let display = self
+            .style
+            .value_display
+            .get_or_insert_with(ValueDisplayStyle::default);
+        display.format = format;
+        display.color = color;
+        self
+    }
+
+    /// Append a unit suffix such as `" °C"` to the value readout
+    ///
+    /// Units longer than 8 bytes are ignored. The default font covers
+    /// ISO 8859-1, which includes `°` and `µ`.
+    pub fn value_units(mut self, units: &str) -> Self {
+        let display = self
+            .style
+            .value_display
+            .get_or_insert_with(ValueDisplayStyle::default);
+        display.units = heapless::String::try_from(units).ok();
+        display.show_units = display.units.is_some();
+        self
+    }
+
+    /// Set where the value readout sits relative to the needle pivot
+    pub fn value_position(mut self, position: ValueDisplayPosition) -> Self {
+        self.style
+            .value_display
+            .get_or_insert_with(ValueDisplayStyle::default)
+            .position = position;
+        self
+    }
+
+    /// Set the font of the value readout
+    pub fn value_font(mut self, font: &'static MonoFont<'static>) -> Self {
+        self.style
+            .value_display
+            .get_or_insert_with(ValueDisplayStyle::default)
+            .font = font;
+        self
+    }
+
+    /// Label the arc at `divisions + 1` evenly spaced values
+    ///
+    /// One division labels only the minimum and maximum at the arc ends.
+    /// Labels use the integer format and the default gauge font until changed
+    /// with [`scale_label_format`](Self::scale_label_format) and
+    /// [`scale_label_font`](Self::scale_label_font).
+    pub fn scale_labels(mut self, divisions: u32, color: C) -> Self {
+        let (format, font) = self
+            .style
+            .scale_labels
+            .map_or((ValueFormat::Integer, GAUGE_FONT), |labels| {
+                (labels.format, labels.font)
+            });
+        self.style.scale_labels = Some(ScaleLabelStyle {
+            color,
+            font,
+            format,
+            divisions,
+            gap: 2,
+        });
+        self
+    }
+
+    /// Set the number format of the arc labels
+    ///
+    /// Has no effect unless [`scale_labels`](Self::scale_labels) was called first.
+    pub fn scale_label_format(mut self, format: ValueFormat) -> Self {
+        if let Some(labels) = self.style.scale_labels.as_mut() {
+            labels.format = format;
+        }
+        self
+    }
+
+    /// Set the font of the arc labels
+    ///
+    /// Has no effect unless [`scale_labels`](Self::scale_labels) was called first.
+    pub fn scale_label_font(mut self, font: &'static MonoFont<'static>) -> Self {
+        if let Some(labels) = self.style.scale_labels.as_mut() {
+            labels.font = font;
+        }
+        self
+    }
+
     /// Build the gauge chart
-    pub fn build(self) -> ChartResult<GaugeChart<C>> {
+    ///
+    /// Threshold zones are sorted by start value and clamped to the value
+    /// range. Default zones outside a custom range are dropped.
+    ///
+    /// # Errors
+    ///
+    /// - [`ChartError::InvalidRange`] if the value range is empty or a zone ends
+    ///   before it starts
+    /// - [`ChartError::ConfigError`] if a custom zone lies entirely outside the
+    ///   range, or if threshold zones overlap
+    /// - [`ChartError::MemoryFull`] if more than [`MAX_THRESHOLD_ZONES`] threshold
+    ///   zones or [`MAX_EXTRA_NEEDLES`] needles were added
+    /// - [`ChartError::InvalidConfiguration`] if a segmented gauge has no segments,
+    ///   arc labels have no divisions, or the arc does not sweep clockwise by up
+    ///   to a full turn
+    pub fn build(mut self) -> ChartResult<GaugeChart<C>> {
+        if self.zones_overflow || self.needles_overflow {
+            return Err(ChartError::MemoryFull);
+        }
+        let sweep = self.gauge_type.sweep();
+        if !(sweep > 0.0 && sweep <= 360.0) {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        if self
+            .style
+            .segments
+            .is_some_and(|segments| segments.count == 0)
+            || self
+                .style
+                .scale_labels
+                .is_some_and(|labels| labels.divisions == 0)
+        {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        normalize_threshold_zones(
+            &mut self.style.threshold_zones,
+            self.value_range,
+            self.custom_zones,
+        )?;
+
         Ok(GaugeChart {
             style: self.style,
             config: self.config,
@@ -667,12 +1546,7 @@ where
     type Error = ChartError;
 
     fn build(self) -> Result<Self::Chart, Self::Error> {
-        Ok(GaugeChart {
-            style: self.style,
-            config: self.config,
-            gauge_type: self.gauge_type,
-            value_range: self.value_range,
-        })
+        GaugeChartBuilder::build(self)
     }
 }
 
@@ -727,4 +1601,352 @@ mod tests {
         assert_eq!(chart.value_to_angle(50.0), 0.0);
         assert_eq!(chart.value_to_angle(100.0), 90.0);
     }
+
+    #[test]
+    fn test_threshold_zones_sorted_and_clamped() {
+        let chart = GaugeChart::<Rgb565>::builder()
+            .value_range(0.0, 50.0)
+            .add_threshold_zone(40.0, 80.0, Rgb565::RED)
+            .add_threshold_zone(-10.0, 20.0, Rgb565::GREEN)
+            .build()
+            .unwrap();
+
+        let zones = &chart.style().threshold_zones;
+        assert_eq!(zones.len(), 2);
+        assert_eq!((zones[0].start, zones[0].end), (0.0, 20.0));
+        assert_eq!((zones[1].start, zones[1].end), (40.0, 50.0));
+
+        // Default zones are clipped to a custom range instead of rejected
+        let chart = GaugeChart::<Rgb565>::builder()
+            .value_range(0.0, 50.0)
+            .build()
+            .unwrap();
+        assert_eq!(chart.style().threshold_zones.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_threshold_zones() {
+        let overlapping = GaugeChart::<Rgb565>::builder()
+            .add_threshold_zone(0.0, 60.0, Rgb565::GREEN)
+            .add_threshold_zone(50.0, 100.0, Rgb565::RED)
+            .build();
+        assert_eq!(
+            overlapping.err(),
+            Some(ChartError::ConfigError(ErrorContext::new(
+                "normalize threshold zones",
+                "Threshold zones must not overlap",
+            )))
+        );
+
+        let outside = GaugeChart::<Rgb565>::builder()
+            .add_threshold_zone(120.0, 150.0, Rgb565::RED)
+            .build();
+        let error = outside.err().unwrap();
+        assert!(error.is_configuration_error());
+        assert_eq!(
+            error,
+            ChartError::ConfigError(ErrorContext::new(
+                "normalize threshold zones",
+                "Keep threshold zones inside the gauge value range",
+            ))
+        );
+
+        let reversed = GaugeChart::<Rgb565>::builder()
+            .add_threshold_zone(80.0, 20.0, Rgb565::RED)
+            .build();
+        assert_eq!(reversed.err(), Some(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_with_value_range_rescales_zones() {
+        let chart = GaugeChart::<Rgb565>::builder()
+            .add_threshold_zone(0.0, 80.0, Rgb565::GREEN)
+            .add_threshold_zone(80.0, 100.0, Rgb565::RED)
+            .build()
+            .unwrap();
+
+        let rpm = chart.with_value_range(0.0, 8000.0).unwrap();
+        assert_eq!(rpm.value_range().max, 8000.0);
+        let zones = &rpm.style().threshold_zones;
+        assert_eq!((zones[1].start, zones[1].end), (6400.0, 8000.0));
+
+        assert_eq!(
+            chart.with_value_range(10.0, 10.0).err(),
+            Some(ChartError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn test_threshold_zones_meet_without_gaps_or_overlap() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart = GaugeChart::<Rgb565>::builder()
+            .radius(20)
+            .add_threshold_zone(0.0, 50.0, Rgb565::GREEN)
+            .add_threshold_zone(50.0, 100.0, Rgb565::RED)
+            .build()
+            .unwrap();
+
+        // Overdraw is not allowed, so any pixel shared by two zones panics
+        let mut display = MockDisplay::<Rgb565>::new();
+        let center = Point::new(32, 32);
+        chart
+            .draw_threshold_zones(center, &mut FallbackTarget::new(&mut display))
+            .unwrap();
+
+        // The semicircle's midpoint (value 50) is the ray pointing right
+        assert_eq!(display.get_pixel(Point::new(52, 32)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(52, 31)), Some(Rgb565::GREEN));
+
+        // Every pixel inside the 8px wide band on the gauge's side is filled
+        for y in 0..64 {
+            for x in 33..64 {
+                let (dx, dy) = (x - center.x, y - center.y);
+                let distance_sq = dx * dx + dy * dy;
+                if (17 * 17..23 * 23).contains(&distance_sq) {
+                    assert!(display.get_pixel(Point::new(x, y)).is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_needle_tail_and_center_cap() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart = GaugeChart::<Rgb565>::builder()
+            .radius(20)
+            .needle_tail(0.25, Some(2))
+            .center_cap(4, Rgb565::WHITE)
+            .center_cap_border(Rgb565::BLACK, 1)
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.style().needle_style.tail_length, 0.25);
+        assert_eq!(chart.style().center_style.border_color, Some(Rgb565::BLACK));
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let data = crate::data::StaticDataSeries::<crate::data::Point2D, 1>::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Value 0 points the needle up, so the counterweight hangs below the pivot
+        let center = Point::new(32, 32);
+        assert_eq!(display.get_pixel(center), Some(Rgb565::WHITE));
+        assert_eq!(display.get_pixel(Point::new(35, 32)), Some(Rgb565::BLACK));
+        assert_eq!(display.get_pixel(Point::new(32, 38)), Some(Rgb565::BLACK));
+    }
+
+    #[test]
+    fn test_arc_sweep() {
+        let chart = GaugeChart::<Rgb565>::builder()
+            .arc(135.0, 270.0)
+            .value_range(0.0, 100.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.gauge_type().angles(), (135.0, 405.0));
+        assert_eq!(chart.value_to_angle(0.0), 135.0);
+        assert_eq!(chart.value_to_angle(50.0), 270.0);
+        assert_eq!(chart.value_to_angle(100.0), 405.0);
+        assert_eq!(GaugeType::FullCircle.sweep(), 360.0);
+
+        for sweep in [0.0, -90.0, 400.0, f32::NAN] {
+            let invalid = GaugeChart::<Rgb565>::builder().arc(0.0, sweep).build();
+            assert_eq!(invalid.err(), Some(ChartError::InvalidConfiguration));
+        }
+    }
+
+    #[test]
+    fn test_extra_needles() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart = GaugeChart::<Rgb565>::builder()
+            .gauge_type(GaugeType::FullCircle)
+            .radius(20)
+            .needle_style(NeedleShape::Line, Rgb565::RED, 1.0, 1)
+            .add_needle(NeedleShape::Line, Rgb565::BLUE, 1.0, 1)
+            .show_center_cap(false)
+            .without_value_display()
+            .build()
+            .unwrap();
+        assert_eq!(chart.style().extra_needles.len(), 1);
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_values(&[25.0, 50.0], chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // A quarter turn points down and half a turn points left
+        let center = Point::new(32, 32);
+        assert_eq!(
+            display.get_pixel(center + Point::new(0, 10)),
+            Some(Rgb565::RED)
+        );
+        assert_eq!(
+            display.get_pixel(center + Point::new(-10, 0)),
+            Some(Rgb565::BLUE)
+        );
+
+        let overflow = GaugeChart::<Rgb565>::builder()
+            .add_needle(NeedleShape::Line, Rgb565::BLUE, 1.0, 1)
+            .add_needle(NeedleShape::Line, Rgb565::BLUE, 1.0, 1)
+            .add_needle(NeedleShape::Line, Rgb565::BLUE, 1.0, 1)
+            .build();
+        assert_eq!(overflow.err(), Some(ChartError::MemoryFull));
+    }
+
+    #[test]
+    fn test_segmented_gauge() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart = GaugeChart::<Rgb565>::builder()
+            .gauge_type(GaugeType::FullCircle)
+            .value_range(0.0, 4.0)
+            .radius(20)
+            .segmented(4, 10.0)
+            .segment_colors(Rgb565::GREEN, Rgb565::YELLOW, Rgb565::BLUE)
+            .without_value_display()
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.segment_state(0, 1.0), SegmentState::Done);
+        assert_eq!(chart.segment_state(1, 1.0), SegmentState::Current);
+        assert_eq!(chart.segment_state(2, 1.0), SegmentState::Pending);
+        assert_eq!(chart.segment_state(3, 4.0), SegmentState::Done);
+
+        let mut data = crate::data::StaticDataSeries::<crate::data::Point2D, 1>::new();
+        data.push(crate::data::Point2D::new(0.0, 1.0)).unwrap();
+        let mut display = MockDisplay::<Rgb565>::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // A full circle starts pointing right and runs clockwise on screen
+        let center = Point::new(32, 32);
+        assert_eq!(
+            display.get_pixel(center + Point::new(14, 14)),
+            Some(Rgb565::GREEN)
+        );
+        assert_eq!(
+            display.get_pixel(center + Point::new(-14, 14)),
+            Some(Rgb565::YELLOW)
+        );
+        assert_eq!(
+            display.get_pixel(center + Point::new(-14, -14)),
+            Some(Rgb565::BLUE)
+        );
+        // Gaps separate the segments
+        assert_eq!(display.get_pixel(center + Point::new(0, 20)), None);
+        assert_eq!(display.get_pixel(center + Point::new(20, 0)), None);
+
+        let empty = GaugeChart::<Rgb565>::builder().segmented(0, 0.0).build();
+        assert_eq!(empty.err(), Some(ChartError::InvalidConfiguration));
+    }
+
+    #[test]
+    fn test_value_display_text() {
+        let range = ValueRange {
+            min: 0.0,
+            max: 200.0,
+        };
+        let mut display = ValueDisplayStyle {
+            color: Rgb565::BLACK,
+            font_size: 12,
+            font: GAUGE_FONT,
+            position: ValueDisplayPosition::Below,
+            format: ValueFormat::Percentage,
+            show_units: false,
+            units: None,
+        };
+        assert_eq!(display.text(50.0, range), "25%");
+
+        display.format =
+            ValueFormat::Formatted(LabelFormat::SiPrefix(crate::style::SiPrefix::new(1, "W")));
+        assert_eq!(display.text(1500.0, range), "1.5 kW");
+
+        display.format = ValueFormat::OneDecimal;
+        display.show_units = true;
+        display.units = Some(heapless::String::try_from(" bar").unwrap());
+        assert_eq!(display.text(3.25, range), "3.3 bar");
+        assert_eq!(
+            display.localized_text(3.25, range, NumberLocale::GERMAN),
+            "3,3 bar"
+        );
+    }
+
+    #[test]
+    fn test_value_units() {
+        let chart: GaugeChart<Rgb565> = GaugeChart::builder()
+            .without_value_display()
+            .value_display(ValueFormat::OneDecimal, Rgb565::BLUE)
+            .value_units(" °C")
+            .build()
+            .unwrap();
+        let display = chart.style().value_display.as_ref().unwrap();
+        assert_eq!(display.color, Rgb565::BLUE);
+        assert_eq!(display.text(23.44, chart.value_range()), "23.4 °C");
+
+        // Units that do not fit are dropped rather than truncated
+        let chart: GaugeChart<Rgb565> = GaugeChart::builder()
+            .value_units(" furlongs")
+            .build()
+            .unwrap();
+        let display = chart.style().value_display.as_ref().unwrap();
+        assert!(!display.show_units);
+        assert_eq!(display.text(5.0, chart.value_range()), "5");
+    }
+
+    #[test]
+    fn test_scale_labels() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        // Classic dial from the left through the top to the right
+        let chart: GaugeChart<Rgb565> = GaugeChart::builder()
+            .arc(180.0, 180.0)
+            .radius(28)
+            .without_value_display()
+            .scale_labels(2, Rgb565::BLUE)
+            .build()
+            .unwrap();
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_values(&[0.0], chart.config(), viewport, &mut display)
+            .unwrap();
+
+        let center = chart.pivot(chart.config(), viewport);
+        assert_eq!(center, Point::new(32, 32));
+        let labels: heapless::Vec<Point, 128> = (0..64)
+            .flat_map(|y| (0..64).map(move |x| Point::new(x, y)))
+            .filter(|&p| display.get_pixel(p) == Some(Rgb565::BLUE))
+            .collect();
+
+        // "0" at the left end, "50" at the top and "100" at the right end
+        assert!(labels.iter().any(|p| p.x < 20 && (p.y - 32).abs() <= 5));
+        assert!(labels.iter().any(|p| p.y < 20 && (p.x - 32).abs() <= 6));
+        assert!(labels.iter().any(|p| p.x > 40 && (p.y - 32).abs() <= 5));
+
+        // Every label stays inside the inner edge of the arc
+        let inner = 28 - 8 / 2;
+        assert!(labels.iter().all(|p| {
+            let d = *p - center;
+            d.x * d.x + d.y * d.y < inner * inner
+        }));
+    }
+
+    #[test]
+    fn test_scale_labels_need_divisions() {
+        let result: ChartResult<GaugeChart<Rgb565>> =
+            GaugeChart::builder().scale_labels(0, Rgb565::BLACK).build();
+        assert_eq!(result.err(), Some(ChartError::InvalidConfiguration));
+    }
 }
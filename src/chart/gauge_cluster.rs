@@ -0,0 +1,490 @@
+//! Gauge cluster widget.
+//!
+//! A [`GaugeCluster`] lays out two to six gauges in one viewport, the way
+//! instrument clusters on vehicles and machine panels do: a title strip on
+//! top, the gauges in a row or a 2×2 / 3×2 grid, and an optional readout
+//! table below that lists each gauge's label and current value.
+//!
+//! Gauges share styling through a template gauge; each one only adds a label
+//! and its own value range. Radii are fitted to the per-gauge cells when
+//! drawing, so the same cluster works on any display size.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut cluster: GaugeCluster<Rgb565> = GaugeCluster::new("Engine").with_readout(true);
+//! cluster.add_range("RPM", 0.0, 8000.0)?;
+//! cluster.add_range("Temp", 40.0, 120.0)?;
+//! cluster.add_range("Oil", 0.0, 6.0)?;
+//!
+//! let layout = cluster.layout(Rectangle::new(Point::zero(), Size::new(240, 120)));
+//! assert_eq!(layout.gauges.len(), 3);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::gauge::{GaugeChart, ValueFormat};
+use crate::chart::traits::Chart;
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+use heapless::{String, Vec};
+
+/// Maximum number of gauges in a cluster
+pub const MAX_CLUSTER_GAUGES: usize = 6;
+
+/// Minimum number of gauges needed to draw a cluster
+pub const MIN_CLUSTER_GAUGES: usize = 2;
+
+/// Appearance shared by all parts of a gauge cluster
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaugeClusterStyle<C: PixelColor> {
+    /// Background color of the whole cluster, or `None` to leave it untouched
+    pub background: Option<C>,
+    /// Title strip fill color, or `None` to use the background
+    pub title_bar_color: Option<C>,
+    /// Title text color
+    pub title_color: C,
+    /// Height of the title strip in pixels
+    pub title_height: u32,
+    /// Space between gauge cells in pixels
+    pub spacing: u32,
+    /// Height of one readout table row in pixels
+    pub readout_row_height: u32,
+    /// Readout label and value color
+    pub readout_color: C,
+    /// Color of the line separating the gauges from the readout, if any
+    pub divider_color: Option<C>,
+}
+
+impl<C: PixelColor> Default for GaugeClusterStyle<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            background: Some(Rgb565::WHITE.into()),
+            title_bar_color: Some(Rgb565::CSS_WHITE_SMOKE.into()),
+            title_color: Rgb565::BLACK.into(),
+            title_height: 14,
+            spacing: 4,
+            readout_row_height: 12,
+            readout_color: Rgb565::BLACK.into(),
+            divider_color: Some(Rgb565::CSS_LIGHT_GRAY.into()),
+        }
+    }
+}
+
+/// Areas of a gauge cluster, as computed by [`GaugeCluster::layout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaugeClusterLayout {
+    /// Title strip at the top; zero-sized without a title
+    pub title: Rectangle,
+    /// Viewport of each gauge, in the order the gauges were added
+    pub gauges: Vec<Rectangle, MAX_CLUSTER_GAUGES>,
+    /// Readout cell of each gauge; empty when the readout is disabled
+    pub readout: Vec<Rectangle, MAX_CLUSTER_GAUGES>,
+}
+
+/// A gauge in a cluster together with its readout label
+#[derive(Debug, Clone)]
+struct ClusterGauge<C: PixelColor> {
+    label: String<16>,
+    gauge: GaugeChart<C>,
+}
+
+/// Two to six gauges sharing a title strip, styling and a readout table
+#[derive(Debug, Clone)]
+pub struct GaugeCluster<C: PixelColor> {
+    title: String<32>,
+    template: GaugeChart<C>,
+    gauges: Vec<ClusterGauge<C>, MAX_CLUSTER_GAUGES>,
+    style: GaugeClusterStyle<C>,
+    show_readout: bool,
+}
+
+impl<C: PixelColor> GaugeCluster<C>
+where
+    C: From<Rgb565>,
+{
+    /// Create an empty cluster with the given title; longer titles are truncated
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: truncated(title),
+            template: GaugeChart::new(),
+            gauges: Vec::new(),
+            style: GaugeClusterStyle::default(),
+            show_readout: false,
+        }
+    }
+
+    /// Set the gauge used as the styling template for [`add_range`](Self::add_range)
+    pub fn with_template(mut self, template: GaugeChart<C>) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// Set the cluster style
+    pub fn with_style(mut self, style: GaugeClusterStyle<C>) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Show or hide the readout table below the gauges
+    pub fn with_readout(mut self, show: bool) -> Self {
+        self.show_readout = show;
+        self
+    }
+
+    /// Add a gauge styled like the template and covering `min..max`
+    ///
+    /// # Errors
+    ///
+    /// - [`ChartError::InvalidRange`] if `min` is not below `max`
+    /// - [`ChartError::MemoryFull`] if the cluster already holds
+    ///   [`MAX_CLUSTER_GAUGES`] gauges
+    pub fn add_range(&mut self, label: &str, min: f32, max: f32) -> ChartResult<()> {
+        let gauge = self.template.with_value_range(min, max)?;
+        self.add_gauge(label, gauge)
+    }
+
+    /// Add a fully configured gauge
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChartError::MemoryFull`] if the cluster already holds
+    /// [`MAX_CLUSTER_GAUGES`] gauges.
+    pub fn add_gauge(&mut self, label: &str, gauge: GaugeChart<C>) -> ChartResult<()> {
+        self.gauges
+            .push(ClusterGauge {
+                label: truncated(label),
+                gauge,
+            })
+            .map_err(|_| ChartError::MemoryFull)
+    }
+
+    /// Get the title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &GaugeClusterStyle<C> {
+        &self.style
+    }
+
+    /// Get the number of gauges
+    pub fn len(&self) -> usize {
+        self.gauges.len()
+    }
+
+    /// Check whether the cluster has no gauges
+    pub fn is_empty(&self) -> bool {
+        self.gauges.is_empty()
+    }
+
+    /// Columns and rows of the gauge grid
+    fn grid(&self) -> (u32, u32) {
+        match self.gauges.len() {
+            0..=3 => (self.gauges.len().max(1) as u32, 1),
+            4 => (2, 2),
+            _ => (3, 2),
+        }
+    }
+
+    /// Split `viewport` into the title strip, gauge cells and readout cells
+    ///
+    /// Gauges fill rows left to right; a short last row is centered.
+    pub fn layout(&self, viewport: Rectangle) -> GaugeClusterLayout {
+        let (cols, rows) = self.grid();
+        let width = viewport.size.width;
+
+        let title_height = if self.title.is_empty() {
+            0
+        } else {
+            self.style.title_height.min(viewport.size.height)
+        };
+        let title = Rectangle::new(viewport.top_left, Size::new(width, title_height));
+
+        let remaining = viewport.size.height - title_height;
+        let readout_height = if self.show_readout {
+            (self.style.readout_row_height * rows).min(remaining)
+        } else {
+            0
+        };
+        let body = Rectangle::new(
+            viewport.top_left + Point::new(0, title_height as i32),
+            Size::new(width, remaining - readout_height),
+        );
+        let readout = Rectangle::new(
+            body.top_left + Point::new(0, body.size.height as i32),
+            Size::new(width, readout_height),
+        );
+
+        let spacing = self.style.spacing;
+        let cell_width = width.saturating_sub(spacing * (cols - 1)) / cols;
+        let cell_height = body.size.height.saturating_sub(spacing * (rows - 1)) / rows;
+        let row_height = readout_height / rows;
+
+        let mut layout = GaugeClusterLayout {
+            title,
+            gauges: Vec::new(),
+            readout: Vec::new(),
+        };
+
+        let count = self.gauges.len() as u32;
+        for index in 0..count {
+            let row = index / cols;
+            let col = index % cols;
+            let in_row = (count - row * cols).min(cols);
+            let indent = (cols - in_row) * (cell_width + spacing) / 2;
+            let x = (indent + col * (cell_width + spacing)) as i32;
+
+            let _ = layout.gauges.push(Rectangle::new(
+                body.top_left + Point::new(x, (row * (cell_height + spacing)) as i32),
+                Size::new(cell_width, cell_height),
+            ));
+            if self.show_readout {
+                let _ = layout.readout.push(Rectangle::new(
+                    readout.top_left + Point::new(x, (row * row_height) as i32),
+                    Size::new(cell_width, row_height),
+                ));
+            }
+        }
+
+        layout
+    }
+
+    /// Draw the cluster with one value per gauge, returning the layout used
+    ///
+    /// Each gauge's radius is fitted to its cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`ChartError::InsufficientData`] if the cluster has fewer than
+    ///   [`MIN_CLUSTER_GAUGES`] gauges
+    /// - [`ChartError::InvalidConfiguration`] if `values` does not hold
+    ///   exactly one value per gauge
+    pub fn draw<D>(
+        &self,
+        values: &[f32],
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<GaugeClusterLayout>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.gauges.len() < MIN_CLUSTER_GAUGES {
+            return Err(ChartError::InsufficientData);
+        }
+        if values.len() != self.gauges.len() {
+            return Err(ChartError::InvalidConfiguration);
+        }
+
+        let layout = self.layout(viewport);
+
+        if let Some(background) = self.style.background {
+            viewport
+                .into_styled(PrimitiveStyle::with_fill(background))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+        self.draw_title(layout.title, target)?;
+
+        for ((entry, cell), &value) in self.gauges.iter().zip(&layout.gauges).zip(values) {
+            let arc_width = entry.gauge.style().arc_style.background_width;
+            let radius =
+                (cell.size.width.min(cell.size.height) / 2).saturating_sub(arc_width / 2 + 1);
+            let gauge = entry.gauge.with_radius(radius);
+
+            let mut reading = StaticDataSeries::<Point2D, 1>::new();
+            reading.push(Point2D::new(0.0, value))?;
+            gauge.draw(&reading, gauge.config(), *cell, target)?;
+        }
+
+        if self.show_readout {
+            self.draw_readout(&layout, values, target)?;
+        }
+
+        Ok(layout)
+    }
+
+    fn draw_title<D>(&self, area: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+        if let Some(bar_color) = self.style.title_bar_color {
+            area.into_styled(PrimitiveStyle::with_fill(bar_color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(
+            &self.title,
+            area.center(),
+            MonoTextStyle::new(&FONT_6X10, self.style.title_color),
+            text_style,
+        )
+        .draw(&mut target.clipped(&area))
+        .map_err(|_| ChartError::RenderingError)?;
+
+        Ok(())
+    }
+
+    fn draw_readout<D>(
+        &self,
+        layout: &GaugeClusterLayout,
+        values: &[f32],
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let Some(first) = layout.readout.first() else {
+            return Ok(());
+        };
+        if let Some(divider) = self.style.divider_color {
+            let y = first.top_left.y;
+            Line::new(
+                Point::new(layout.title.top_left.x, y),
+                Point::new(
+                    layout.title.top_left.x + layout.title.size.width as i32 - 1,
+                    y,
+                ),
+            )
+            .into_styled(PrimitiveStyle::with_stroke(divider, 1))
+            .draw(target)
+            .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        let font = MonoTextStyle::new(&FONT_6X10, self.style.readout_color);
+        for ((entry, cell), &value) in self.gauges.iter().zip(&layout.readout).zip(values) {
+            let mut clipped = target.clipped(cell);
+            let middle = cell.top_left.y + cell.size.height as i32 / 2;
+
+            Text::with_baseline(
+                &entry.label,
+                Point::new(cell.top_left.x + 2, middle),
+                font,
+                Baseline::Middle,
+            )
+            .draw(&mut clipped)
+            .map_err(|_| ChartError::RenderingError)?;
+
+            let range = entry.gauge.value_range();
+            let locale = entry.gauge.config().locale;
+            let text = match entry.gauge.style().value_display.as_ref() {
+                Some(display) => display.localized_text(value, range, locale),
+                None => locale.localize(&ValueFormat::OneDecimal.format(value, range)),
+            };
+            Text::with_text_style(
+                &text,
+                Point::new(cell.top_left.x + cell.size.width as i32 - 2, middle),
+                font,
+                TextStyleBuilder::new()
+                    .alignment(Alignment::Right)
+                    .baseline(Baseline::Middle)
+                    .build(),
+            )
+            .draw(&mut clipped)
+            .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn truncated<const N: usize>(text: &str) -> String<N> {
+    let mut result = String::new();
+    for c in text.chars() {
+        if result.push(c).is_err() {
+            break;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn cluster(count: usize) -> GaugeCluster<Rgb565> {
+        let mut cluster = GaugeCluster::new("Engine").with_readout(true);
+        for _ in 0..count {
+            cluster.add_range("G", 0.0, 100.0).unwrap();
+        }
+        cluster
+    }
+
+    #[test]
+    fn test_cluster_layout_grid() {
+        let viewport = Rectangle::new(Point::zero(), Size::new(124, 100));
+
+        let layout = cluster(3).layout(viewport);
+        assert_eq!(layout.title.size, Size::new(124, 14));
+        assert_eq!(layout.gauges.len(), 3);
+        assert_eq!(
+            layout.gauges[0],
+            Rectangle::new(Point::new(0, 14), Size::new(38, 74))
+        );
+        assert_eq!(layout.gauges[2].top_left, Point::new(84, 14));
+        assert_eq!(layout.readout[1].top_left, Point::new(42, 88));
+
+        // Five gauges: 3 on top, 2 centered below
+        let layout = cluster(5).layout(viewport);
+        assert_eq!(layout.gauges[0].size, Size::new(38, 29));
+        assert_eq!(layout.gauges[3].top_left, Point::new(21, 47));
+        assert_eq!(layout.gauges[4].top_left, Point::new(63, 47));
+        assert_eq!(layout.readout[4].top_left, Point::new(63, 88));
+    }
+
+    #[test]
+    fn test_cluster_limits() {
+        let mut full = cluster(MAX_CLUSTER_GAUGES);
+        assert_eq!(full.add_range("X", 0.0, 1.0), Err(ChartError::MemoryFull));
+        assert_eq!(
+            cluster(2).add_range("X", 5.0, 5.0),
+            Err(ChartError::InvalidRange)
+        );
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let mut display = MockDisplay::<Rgb565>::new();
+        assert_eq!(
+            cluster(1).draw(&[1.0], viewport, &mut display),
+            Err(ChartError::InsufficientData)
+        );
+        assert_eq!(
+            cluster(2).draw(&[1.0], viewport, &mut display),
+            Err(ChartError::InvalidConfiguration)
+        );
+    }
+
+    #[test]
+    fn test_cluster_draws_within_viewport() {
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        cluster(4)
+            .draw(&[10.0, 40.0, 70.0, 95.0], viewport, &mut display)
+            .unwrap();
+        assert_eq!(display.affected_area(), viewport);
+    }
+}
@@ -7,8 +7,8 @@
 //! # Features
 //!
 //! - **Multi-series support**: Display multiple data series with different colors
-//! - **Marker customization**: Various shapes (circle, square, diamond, triangle) with configurable size and color
-//! - **Area filling**: Fill the area under the line with customizable colors
+//! - **Marker customization**: Various shapes (circle, square, diamond, triangle, plus, cross, star or custom) with configurable size and color
+//! - **Area filling**: Fill the area under the line with customizable colors or gradients
 //! - **Smooth curves**: Optional bezier curve smoothing for professional appearance
 //! - **Grid integration**: Support for both legacy and modern grid systems
 //! - **Axis integration**: Full support for linear axes with labels and ticks
@@ -42,6 +42,31 @@
 //! # Ok::<(), embedded_charts::error::ChartError>(())
 //! ```
 //!
+//! # Multiple Series
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let mut data: MultiSeries<Point2D, 8, 256> = MultiSeries::new();
+//! data.add_series(data_points![(0.0, 22.5), (1.0, 23.1), (2.0, 24.2)])?;
+//! data.add_series(data_points![(0.0, 18.0), (1.0, 19.5), (2.0, 19.0)])?;
+//!
+//! // Series are colored from the chart's palette and share one set of axes
+//! let chart: LineChart<Rgb565> = LineChart::builder()
+//!     .series_palette(SeriesPalette::default())
+//!     .build()?;
+//!
+//! // One legend entry per series, in the color the series is drawn with
+//! let legend = chart
+//!     .add_series_legend(&data, StandardLegendBuilder::new())?
+//!     .build()?;
+//! assert_eq!(legend.entries().len(), 2);
+//!
+//! // chart.draw_multi_series(&data, chart.config(), viewport, &mut display)?;
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+//!
 //! # Advanced Styling
 //!
 //! ```rust
@@ -79,17 +104,51 @@
 //! // This is a simplified example focusing on basic line chart usage
 //! # Ok::<(), embedded_charts::error::ChartError>(())
 //! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
 
 use crate::axes::traits::Axis;
+use crate::chart::annotations::AnnotationOverlay;
+use crate::chart::events::EventOverlay;
+use crate::chart::forecast::ForecastOverlay;
+use crate::chart::stats_overlay::StatsOverlay;
 use crate::chart::traits::AxisChart;
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig, Margins};
-use crate::data::{DataBounds, DataPoint, DataSeries};
+use crate::chart::traits::{
+    BackgroundMode, Chart, ChartBuilder, ChartConfig, DataChange, Margins, TitleStyle,
+};
+use crate::chart::transform_overlay::{TransformOverlay, MAX_TRANSFORM_OVERLAYS};
+use crate::data::{
+    calculate_bounds, DataBounds, DataPoint, LinearTrend, MultiSeries, Point2D, SeriesAxis,
+    SeriesStats,
+};
 use crate::error::{ChartError, ChartResult};
-use crate::math::NumericConversion;
+use crate::legend::{LegendEntryType, StandardLegendBuilder};
+use crate::math::interpolation::EndpointMode;
+use crate::math::{NumericConversion, RangeTransform};
+use crate::render::{
+    ChartDrawTarget, ChartRenderer, DashStroke, Decimation, FallbackTarget, PolylineSimplifier,
+    QualityProfile, SpanRasterizer,
+};
+use crate::style::{
+    FillStyle, GradientDirection, LinePattern, LineStyle, LinearGradient, MonochromeStyle,
+    SeriesPalette, ValueGradient,
+};
+
+use crate::chart::marker::MarkerRenderer;
+
+pub use crate::chart::marker::MarkerShape;
 use embedded_graphics::{
     draw_target::DrawTarget,
     prelude::*,
-    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+    primitives::{Line, PrimitiveStyle, Rectangle},
 };
 
 /// Line chart implementation for displaying continuous data series.
@@ -109,12 +168,24 @@ use embedded_graphics::{
 ///
 /// # Memory Usage
 ///
-/// The line chart uses static allocation with a maximum of 256 data points per series.
-/// Additional memory is used for:
-/// - Screen coordinate transformation (256 points)
-/// - Area fill polygon vertices (258 points maximum)
+/// The line chart uses static allocation with a maximum of `N` data points per
+/// series, 256 unless chosen with [`LineChartBuilder::capacity`]. The same
+/// capacity sizes the buffers used while drawing:
+/// - Screen coordinate transformation (`N` points)
+/// - Smooth curve interpolation (`N` points; subdivisions are reduced to fit)
 /// - Grid and axis rendering buffers
 ///
+/// A chart for a 16 KB MCU might use 64 points, a desktop simulator 1024:
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+/// use embedded_graphics::pixelcolor::Rgb565;
+///
+/// let chart: LineChart<Rgb565, 64> = LineChart::builder().capacity::<64>().build()?;
+/// let data: StaticDataSeries<Point2D, 64> = StaticDataSeries::new();
+/// # Ok::<(), embedded_charts::error::ChartError>(())
+/// ```
+///
 /// # Examples
 ///
 /// Basic line chart:
@@ -147,12 +218,26 @@ use embedded_graphics::{
 /// # Ok::<(), embedded_charts::error::ChartError>(())
 /// ```
 #[derive(Debug)]
-pub struct LineChart<C: PixelColor> {
+pub struct LineChart<C: PixelColor, const N: usize = 256> {
     style: LineChartStyle<C>,
     config: ChartConfig<C>,
     grid: Option<crate::grid::GridSystem<C>>,
     x_axis: Option<crate::axes::LinearAxis<f32, C>>,
     y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    secondary_y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    palette: SeriesPalette<C, 8>,
+    x_window: Option<(f32, f32)>,
+    events: Option<EventOverlay<C>>,
+    annotations: Option<AnnotationOverlay<C>>,
+    forecast: Option<ForecastOverlay<C>>,
+    transform_overlays: heapless::Vec<TransformOverlay<C>, MAX_TRANSFORM_OVERLAYS>,
+    stats_overlay: Option<StatsOverlay<C>>,
+    quality: Option<QualityProfile>,
+    fill_baseline: Option<f32>,
+    fill_below_color: Option<C>,
+    fill_gradient: Option<ValueGradient<C>>,
+    fill_style: Option<FillStyle<C>>,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 /// Style configuration for line charts.
@@ -174,6 +259,10 @@ pub struct LineChart<C: PixelColor> {
 ///     markers: Some(MarkerStyle::default()),
 ///     smooth: false,
 ///     smooth_subdivisions: 8,
+///     simplify_epsilon: None,
+///     decimation: None,
+///     smooth_endpoints: EndpointMode::Duplicate,
+///     line_pattern: LinePattern::Solid,
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -204,6 +293,25 @@ pub struct LineChartStyle<C: PixelColor> {
     pub smooth: bool,
     /// Number of subdivisions for smooth curves (only used when smooth = true)
     pub smooth_subdivisions: u32,
+    /// Polyline simplification tolerance in pixels.
+    ///
+    /// When `Some`, vertices within this distance of the simplified line are dropped
+    /// before stroking, reducing draw calls for dense data. Area fills and markers
+    /// still use every data point.
+    pub simplify_epsilon: Option<f32>,
+    /// Reduction applied to series with more points than the plot is wide.
+    ///
+    /// When `Some`, dense series are decimated while they are mapped to the
+    /// screen, so the point capacity only has to cover the plot width. Smoothing
+    /// is skipped and markers are drawn only at the points that are kept.
+    pub decimation: Option<Decimation>,
+    /// How the ends of smoothed lines are shaped (only used when smooth = true)
+    pub smooth_endpoints: EndpointMode,
+    /// Dash pattern of the line.
+    ///
+    /// The pattern runs on through the data points instead of restarting at
+    /// each one.
+    pub line_pattern: LinePattern,
 }
 
 /// Marker style configuration for data points.
@@ -242,30 +350,6 @@ pub struct MarkerStyle<C: PixelColor> {
     pub visible: bool,
 }
 
-/// Available shapes for data point markers.
-///
-/// Each shape provides different visual characteristics:
-/// - `Circle`: Smooth, traditional marker shape
-/// - `Square`: Sharp, geometric appearance
-/// - `Diamond`: Distinctive diamond shape
-/// - `Triangle`: Directional appearance, good for indicating trends
-///
-/// # Performance Notes
-///
-/// - `Circle` and `Square` use embedded-graphics primitives (fastest)
-/// - `Diamond` and `Triangle` use custom rendering (slightly slower)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MarkerShape {
-    /// Circular marker - smooth and traditional appearance.
-    Circle,
-    /// Square marker - sharp, geometric appearance.
-    Square,
-    /// Diamond marker - distinctive diamond shape.
-    Diamond,
-    /// Triangle marker - directional appearance.
-    Triangle,
-}
-
 impl<C: PixelColor> LineChart<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -289,13 +373,7 @@ where
     /// let chart: LineChart<Rgb565> = LineChart::new();
     /// ```
     pub fn new() -> Self {
-        Self {
-            style: LineChartStyle::default(),
-            config: ChartConfig::default(),
-            grid: None,
-            x_axis: None,
-            y_axis: None,
-        }
+        Self::default()
     }
 
     /// Create a builder for configuring the line chart.
@@ -319,7 +397,12 @@ where
     pub fn builder() -> LineChartBuilder<C> {
         LineChartBuilder::new()
     }
+}
 
+impl<C: PixelColor, const N: usize> LineChart<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
     /// Set the line style configuration.
     ///
     /// This replaces the entire style configuration with the provided one.
@@ -344,6 +427,10 @@ where
     ///     markers: None,
     ///     smooth: false,
     ///     smooth_subdivisions: 8,
+    ///     simplify_epsilon: None,
+    ///     decimation: None,
+    ///     smooth_endpoints: EndpointMode::Duplicate,
+    ///     line_pattern: LinePattern::Solid,
     /// };
     /// chart.set_style(style);
     /// ```
@@ -418,6 +505,181 @@ where
         self.grid.as_ref()
     }
 
+    /// Set a fixed visible X range, or `None` to fit the data.
+    ///
+    /// Points outside the window are clipped to the chart area, which lets a
+    /// sliding-window series scroll smoothly (see
+    /// [`ScrollInterpolator`](crate::animation::ScrollInterpolator)).
+    /// The X-axis range, if an axis is set, follows the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Visible `(min, max)` X range
+    pub fn set_x_window(&mut self, window: Option<(f32, f32)>) {
+        self.x_window = window;
+        if let (Some((min, max)), Some(axis)) = (window, self.x_axis.take()) {
+            self.x_axis = Some(axis.with_range(min, max));
+        }
+    }
+
+    /// Get the fixed visible X range, if any.
+    pub fn x_window(&self) -> Option<(f32, f32)> {
+        self.x_window
+    }
+
+    /// Set or clear the event markers drawn over the plot area.
+    pub fn set_events(&mut self, events: Option<EventOverlay<C>>) {
+        self.events = events;
+    }
+
+    /// Set the colors series of a multi-series chart are drawn with.
+    pub fn set_series_palette(&mut self, palette: SeriesPalette<C, 8>) {
+        self.palette = palette;
+    }
+
+    /// Get the colors series of a multi-series chart are drawn with.
+    pub fn series_palette(&self) -> &SeriesPalette<C, 8> {
+        &self.palette
+    }
+
+    /// Get the event markers, if any.
+    pub fn events(&self) -> Option<&EventOverlay<C>> {
+        self.events.as_ref()
+    }
+
+    /// Get mutable access to the event markers, e.g. to register new events.
+    pub fn events_mut(&mut self) -> Option<&mut EventOverlay<C>> {
+        self.events.as_mut()
+    }
+
+    /// Set or clear the reference lines and bands drawn behind the data.
+    pub fn set_annotations(&mut self, annotations: Option<AnnotationOverlay<C>>) {
+        self.annotations = annotations;
+    }
+
+    /// Get the reference lines and bands, if any.
+    pub fn annotations(&self) -> Option<&AnnotationOverlay<C>> {
+        self.annotations.as_ref()
+    }
+
+    /// Get mutable access to the reference lines and bands, e.g. to move a limit.
+    pub fn annotations_mut(&mut self) -> Option<&mut AnnotationOverlay<C>> {
+        self.annotations.as_mut()
+    }
+
+    /// Set or clear the trend forecast drawn beyond the last data point.
+    pub fn set_forecast(&mut self, forecast: Option<ForecastOverlay<C>>) {
+        self.forecast = forecast;
+    }
+
+    /// Get the trend forecast, if any.
+    pub fn forecast(&self) -> Option<&ForecastOverlay<C>> {
+        self.forecast.as_ref()
+    }
+
+    /// Get the transformed series drawn over the data.
+    pub fn transform_overlays(&self) -> &[TransformOverlay<C>] {
+        &self.transform_overlays
+    }
+
+    /// Set or clear the mean line and standard deviation bands.
+    pub fn set_stats_overlay(&mut self, overlay: Option<StatsOverlay<C>>) {
+        self.stats_overlay = overlay;
+    }
+
+    /// Get the mean line and standard deviation bands, if any.
+    pub fn stats_overlay(&self) -> Option<&StatsOverlay<C>> {
+        self.stats_overlay.as_ref()
+    }
+
+    /// Override the global rendering quality profile for this chart.
+    ///
+    /// `None` follows [`QualityProfile::global`], so the chart degrades
+    /// together with the rest of the application.
+    pub fn set_quality(&mut self, quality: Option<QualityProfile>) {
+        self.quality = quality;
+    }
+
+    /// Get the quality override, if any.
+    pub fn quality(&self) -> Option<QualityProfile> {
+        self.quality
+    }
+
+    /// Get the profile used for the next draw.
+    pub fn effective_quality(&self) -> QualityProfile {
+        QualityProfile::resolve(self.quality)
+    }
+
+    /// Set the value the area fill extends to.
+    ///
+    /// With a baseline the fill runs from the line to that value, e.g. `0.0`,
+    /// instead of the bottom of the chart area, and the y range is extended to
+    /// include it. `None` fills to the bottom of the chart area.
+    pub fn set_fill_baseline(&mut self, baseline: Option<f32>) {
+        self.fill_baseline = baseline;
+    }
+
+    /// Get the value the area fill extends to, if any.
+    pub fn fill_baseline(&self) -> Option<f32> {
+        self.fill_baseline
+    }
+
+    /// Set the fill color used where the line runs below the fill baseline.
+    ///
+    /// `None` uses the regular fill color on both sides.
+    pub fn set_fill_below_color(&mut self, color: Option<C>) {
+        self.fill_below_color = color;
+    }
+
+    /// Get the fill color used below the fill baseline, if any.
+    pub fn fill_below_color(&self) -> Option<C> {
+        self.fill_below_color
+    }
+
+    /// Color the area fill by the Y value each row represents.
+    ///
+    /// The gradient takes precedence over the fill colors, including the one
+    /// below the fill baseline. `None` returns to flat fills.
+    pub fn set_fill_gradient(&mut self, gradient: Option<ValueGradient<C>>) {
+        self.fill_gradient = gradient;
+    }
+
+    /// Get the value gradient used for the area fill, if any.
+    pub fn fill_gradient(&self) -> Option<&ValueGradient<C>> {
+        self.fill_gradient.as_ref()
+    }
+
+    /// Paint the area fill with a fill style, such as a linear gradient.
+    ///
+    /// The style spans the box from the highest point of the line to the fill
+    /// baseline, so a vertical gradient starts at its first stop along the
+    /// top of the line. It takes precedence over the fill colors but not over
+    /// a value gradient. `None` returns to flat fills.
+    pub fn set_fill_style(&mut self, style: Option<FillStyle<C>>) {
+        self.fill_style = style;
+    }
+
+    /// Get the fill style used for the area fill, if any.
+    pub fn fill_style(&self) -> Option<&FillStyle<C>> {
+        self.fill_style.as_ref()
+    }
+
+    /// Get the monochrome style the chart was built for, if any.
+    pub fn monochrome(&self) -> Option<&MonochromeStyle<C>> {
+        self.monochrome.as_ref()
+    }
+
+    /// Get the line pattern of series `index` of a [`MultiSeries`]
+    ///
+    /// Monochrome charts tell series apart by pattern, others use the line
+    /// pattern for every series.
+    fn series_pattern(&self, index: usize) -> LinePattern {
+        match self.monochrome {
+            Some(ref monochrome) => monochrome.series_pattern(index),
+            None => self.style.line_pattern,
+        }
+    }
+
     /// Transform data coordinates to screen coordinates using math abstraction
     fn transform_point<P>(
         &self,
@@ -430,57 +692,40 @@ where
         P::X: NumericConversion<P::X> + Into<f32> + Copy,
         P::Y: NumericConversion<P::Y> + Into<f32> + Copy,
     {
-        // Convert to our math abstraction layer
-        let data_x = point.x().into().to_number();
-        let data_y = point.y().into().to_number();
-
-        // Use axis ranges if available, otherwise fall back to data bounds
-        let (min_x, max_x) = if let Some(ref x_axis) = self.x_axis {
-            let axis_min: f32 = x_axis.min();
-            let axis_max: f32 = x_axis.max();
-            (axis_min.to_number(), axis_max.to_number())
+        let data_x: f32 = point.x().into();
+        let data_y: f32 = point.y().into();
+
+        // Use the fixed window or axis ranges if available, otherwise fall back to data bounds
+        let x_range = if let Some((window_min, window_max)) = self.x_window {
+            RangeTransform::new(window_min, window_max)
+        } else if let Some(ref x_axis) = self.x_axis {
+            RangeTransform::new(x_axis.min(), x_axis.max())
         } else {
-            (
-                data_bounds.min_x.into().to_number(),
-                data_bounds.max_x.into().to_number(),
-            )
+            RangeTransform::new(data_bounds.min_x.into(), data_bounds.max_x.into())
         };
 
-        let (min_y, max_y) = if let Some(ref y_axis) = self.y_axis {
-            let axis_min: f32 = y_axis.min();
-            let axis_max: f32 = y_axis.max();
-            (axis_min.to_number(), axis_max.to_number())
+        let y_range = if let Some(ref y_axis) = self.y_axis {
+            RangeTransform::new(y_axis.min(), y_axis.max())
         } else {
-            (
-                data_bounds.min_y.into().to_number(),
-                data_bounds.max_y.into().to_number(),
-            )
+            RangeTransform::new(data_bounds.min_y.into(), data_bounds.max_y.into())
         };
 
         // Apply margins to get the actual drawing area
         let draw_area = self.config.margins.apply_to(viewport);
 
-        // Normalize to 0-1 range using math abstraction
-        let norm_x = if f32::from_number(max_x) > f32::from_number(min_x) {
-            let range_x = f32::from_number(max_x - min_x);
-            let offset_x = f32::from_number(data_x - min_x);
-            (offset_x / range_x).to_number()
-        } else {
-            0.5f32.to_number()
+        // Normalize to 0-1 range; offsets are taken in f32 first so large
+        // ranges don't overflow fixed-point numbers
+        let norm_x_f32 = match self.x_axis {
+            Some(ref x_axis) if self.x_window.is_none() && x_axis.is_logarithmic() => {
+                x_axis.normalize(data_x)
+            }
+            _ => f32::from_number(x_range.normalize(data_x)),
         };
-
-        let norm_y = if f32::from_number(max_y) > f32::from_number(min_y) {
-            let range_y = f32::from_number(max_y - min_y);
-            let offset_y = f32::from_number(data_y - min_y);
-            (offset_y / range_y).to_number()
-        } else {
-            0.5f32.to_number()
+        let norm_y_f32 = match self.y_axis {
+            Some(ref y_axis) if y_axis.is_logarithmic() => y_axis.normalize(data_y),
+            _ => f32::from_number(y_range.normalize(data_y)),
         };
 
-        // Transform to screen coordinates (Y is flipped)
-        let norm_x_f32 = f32::from_number(norm_x);
-        let norm_y_f32 = f32::from_number(norm_y);
-
         let screen_x =
             draw_area.top_left.x + (norm_x_f32 * (draw_area.size.width as f32 - 1.0)) as i32;
         let screen_y = draw_area.top_left.y + draw_area.size.height as i32
@@ -490,20 +735,142 @@ where
         Point::new(screen_x, screen_y)
     }
 
+    /// Draw the line, area fill and markers for a data series
+    fn draw_series<I, D>(
+        &self,
+        points: I,
+        line: LineStyle<C>,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D> + Clone,
+        D: ChartDrawTarget<Color = C>,
+    {
+        // Transform data points to screen coordinates, decimating dense series
+        // to the plot width or smoothing them if enabled
+        let quality = self.effective_quality();
+        let columns = self.config.margins.apply_to(viewport).size.width;
+        let decimation = self
+            .style
+            .decimation
+            .filter(|_| points.clone().nth(columns as usize).is_some());
+        let mut screen_points = heapless::Vec::<Point, N>::new();
+        if let Some(decimation) = decimation {
+            decimation.decimate(
+                points
+                    .clone()
+                    .map(|point| self.transform_point(&point, data_bounds, viewport)),
+                columns,
+                &mut screen_points,
+            )?;
+        } else if self.style.smooth && quality.smoothing() && points.clone().nth(2).is_some() {
+            // Create interpolated smooth curve
+            use crate::math::interpolation::{
+                CurveInterpolator, InterpolationConfig, InterpolationType,
+            };
+
+            let mut input_points = heapless::Vec::<Point2D, N>::new();
+            for point in points.clone() {
+                input_points
+                    .push(point)
+                    .map_err(|_| ChartError::MemoryFull)?;
+            }
+
+            // Subdivide less when the curve would not fit the point capacity
+            let segments = input_points.len().saturating_sub(1).max(1);
+            let max_subdivisions = (N.saturating_sub(1) / segments).max(1) as u32;
+            let interpolation_config = InterpolationConfig {
+                interpolation_type: InterpolationType::CatmullRom,
+                subdivisions: quality
+                    .subdivisions(self.style.smooth_subdivisions)
+                    .min(max_subdivisions),
+                tension: 0.5,
+                closed: false,
+                endpoints: self.style.smooth_endpoints,
+            };
+
+            let interpolated = CurveInterpolator::interpolate_with_capacity::<N>(
+                &input_points,
+                &interpolation_config,
+            )?;
+            for point in interpolated.iter() {
+                let screen_point = self.transform_point(point, data_bounds, viewport);
+                screen_points
+                    .push(screen_point)
+                    .map_err(|_| ChartError::MemoryFull)?;
+            }
+        } else {
+            for point in points.clone() {
+                let screen_point = self.transform_point(&point, data_bounds, viewport);
+                screen_points
+                    .push(screen_point)
+                    .map_err(|_| ChartError::MemoryFull)?;
+            }
+        }
+
+        // Draw area fill if enabled
+        if self.style.fill_area {
+            if let Some(gradient) = &self.fill_gradient {
+                self.draw_gradient_fill(&screen_points, gradient, viewport, data_bounds, target)?;
+            } else if let Some(fill_style) = &self.fill_style {
+                self.draw_style_fill(&screen_points, fill_style, viewport, data_bounds, target)?;
+            } else if let Some(fill_color) = self.style.fill_color {
+                self.draw_area_fill(&screen_points, fill_color, viewport, data_bounds, target)?;
+            }
+        }
+
+        // Drop vertices that don't visibly change the stroked line
+        if let Some(epsilon) = self.style.simplify_epsilon {
+            PolylineSimplifier::new(epsilon).simplify_in_place(&mut screen_points);
+        }
+
+        // Draw lines between consecutive points
+        if line.pattern == LinePattern::Solid {
+            let line_style = PrimitiveStyle::with_stroke(line.color, line.width);
+            for window in screen_points.windows(2) {
+                if let [p1, p2] = window {
+                    Line::new(*p1, *p2)
+                        .into_styled(line_style)
+                        .draw(target)
+                        .map_err(|_| ChartError::RenderingError)?;
+                }
+            }
+        } else {
+            DashStroke::new(&line).polyline(&screen_points, target)?;
+        }
+
+        // Draw markers, only at the kept points of a decimated series
+        if decimation.is_some() {
+            if let Some(marker_style) = self.style.markers.as_ref().filter(|m| m.visible) {
+                for point in screen_points.iter().step_by(quality.marker_stride()) {
+                    self.draw_marker(*point, marker_style, target)?;
+                }
+            }
+        } else {
+            self.draw_markers(points, data_bounds, viewport, target)?;
+        }
+
+        Ok(())
+    }
+
     /// Draw markers at data points
-    fn draw_markers<D>(
+    fn draw_markers<I, D>(
         &self,
-        data: &crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>,
+        points: I,
         data_bounds: &DataBounds<f32, f32>,
         viewport: Rectangle,
         target: &mut D,
     ) -> ChartResult<()>
     where
+        I: Iterator<Item = Point2D>,
         D: DrawTarget<Color = C>,
     {
         if let Some(marker_style) = &self.style.markers {
             if marker_style.visible {
-                for point in data.iter() {
+                let stride = self.effective_quality().marker_stride();
+                for point in points.step_by(stride) {
                     let screen_point = self.transform_point(&point, data_bounds, viewport);
                     self.draw_marker(screen_point, marker_style, target)?;
                 }
@@ -522,71 +889,26 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        let style = PrimitiveStyle::with_fill(marker_style.color);
-        let radius = marker_style.size / 2;
-
-        match marker_style.shape {
-            MarkerShape::Circle => {
-                Circle::new(
-                    Point::new(center.x - radius as i32, center.y - radius as i32),
-                    marker_style.size,
-                )
-                .into_styled(style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            MarkerShape::Square => {
-                Rectangle::new(
-                    Point::new(center.x - radius as i32, center.y - radius as i32),
-                    Size::new(marker_style.size, marker_style.size),
-                )
-                .into_styled(style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            MarkerShape::Diamond => {
-                use crate::render::PrimitiveRenderer;
-                use crate::style::FillStyle;
-
-                let fill_style = FillStyle::solid(marker_style.color);
-                PrimitiveRenderer::draw_diamond(
-                    center,
-                    marker_style.size,
-                    None,
-                    Some(&fill_style),
-                    target,
-                )
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            MarkerShape::Triangle => {
-                use crate::render::PrimitiveRenderer;
-                use crate::style::FillStyle;
-
-                let fill_style = FillStyle::solid(marker_style.color);
-                let half_size = marker_style.size as i32 / 2;
-                let p1 = Point::new(center.x, center.y - half_size);
-                let p2 = Point::new(center.x - half_size, center.y + half_size);
-                let p3 = Point::new(center.x + half_size, center.y + half_size);
-
-                PrimitiveRenderer::draw_triangle(p1, p2, p3, None, Some(&fill_style), target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-        }
-
-        Ok(())
+        MarkerRenderer::draw(
+            marker_style.shape,
+            center,
+            marker_style.size,
+            marker_style.color,
+            target,
+        )
     }
 
     /// Draw area fill under the line
     fn draw_area_fill<D>(
         &self,
-        screen_points: &heapless::Vec<Point, 512>,
+        screen_points: &[Point],
         fill_color: C,
         viewport: Rectangle,
-        _data_bounds: &DataBounds<f32, f32>,
+        data_bounds: &DataBounds<f32, f32>,
         target: &mut D,
     ) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
         if screen_points.len() < 2 {
             return Ok(());
@@ -594,220 +916,845 @@ where
 
         // Get the chart area (with margins applied)
         let chart_area = self.config.margins.apply_to(viewport);
-        let baseline_y = chart_area.top_left.y + chart_area.size.height as i32 - 1;
+        let raster = SpanRasterizer::new(chart_area);
 
-        use embedded_graphics::primitives::{Line, PrimitiveStyle};
-        let line_style = PrimitiveStyle::with_stroke(fill_color, 1);
+        let Some(baseline) = self.fill_baseline else {
+            // Fill between the line and the bottom of the chart area
+            let baseline_y = chart_area.top_left.y + chart_area.size.height as i32 - 1;
+            raster.fill_under_polyline(screen_points, baseline_y, fill_color, target)?;
+            return Ok(());
+        };
 
-        // Draw horizontal fill lines using scanline approach
-        let min_x = screen_points
-            .iter()
-            .map(|p| p.x)
-            .min()
-            .unwrap_or(chart_area.top_left.x);
-        let max_x = screen_points
-            .iter()
-            .map(|p| p.x)
-            .max()
-            .unwrap_or(chart_area.top_left.x);
+        // Fill towards the baseline value, switching color where the line crosses it
+        let baseline_point = crate::data::Point2D::new(data_bounds.min_x, baseline);
+        let baseline_y = self
+            .transform_point(&baseline_point, data_bounds, viewport)
+            .y;
+        raster.fill_to_baseline(
+            screen_points,
+            baseline_y,
+            fill_color,
+            self.fill_below_color.unwrap_or(fill_color),
+            target,
+        )?;
 
-        // For each x position, find the curve y and draw a vertical line to baseline
-        for x in min_x..=max_x {
-            if x < chart_area.top_left.x
-                || x >= chart_area.top_left.x + chart_area.size.width as i32
-            {
-                continue;
-            }
+        Ok(())
+    }
 
-            // Find the y value on the curve at this x position
-            let mut curve_y = baseline_y;
+    /// Draw the area fill colored by the Y value of each row
+    ///
+    /// Rows sharing a color are filled together as one band, clipped to the
+    /// band's rows, so a gradient with few stops costs only a few passes.
+    fn draw_gradient_fill<D>(
+        &self,
+        screen_points: &[Point],
+        gradient: &ValueGradient<C>,
+        viewport: Rectangle,
+        data_bounds: &DataBounds<f32, f32>,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        if screen_points.len() < 2 {
+            return Ok(());
+        }
 
-            // Linear interpolation between adjacent points
-            for window in screen_points.windows(2) {
-                if let [p1, p2] = window {
-                    if (p1.x <= x && x <= p2.x) || (p2.x <= x && x <= p1.x) {
-                        if p1.x == p2.x {
-                            curve_y = p1.y.min(p2.y);
-                        } else {
-                            let t = (x - p1.x) as f32 / (p2.x - p1.x) as f32;
-                            curve_y = (p1.y as f32 + t * (p2.y - p1.y) as f32) as i32;
-                        }
-                        break;
-                    }
-                }
+        let chart_area = self.config.margins.apply_to(viewport);
+        let raster = SpanRasterizer::new(chart_area);
+        let Some(bottom_right) = chart_area.bottom_right() else {
+            return Ok(());
+        };
+
+        // The screen mapping is linear, so two reference rows give the value of any row
+        let low = data_bounds.min_y;
+        let high = if data_bounds.max_y > low {
+            data_bounds.max_y
+        } else {
+            low + 1.0
+        };
+        let row_of = |value: f32| {
+            self.transform_point(
+                &crate::data::Point2D::new(data_bounds.min_x, value),
+                data_bounds,
+                viewport,
+            )
+            .y
+        };
+        let (low_y, high_y) = (row_of(low), row_of(high));
+        let value_at = |y: i32| {
+            if low_y == high_y {
+                low
+            } else {
+                low + (low_y - y) as f32 / (low_y - high_y) as f32 * (high - low)
             }
+        };
 
-            // Clip curve_y to chart area
-            curve_y = curve_y.clamp(
-                chart_area.top_left.y,
-                chart_area.top_left.y + chart_area.size.height as i32 - 1,
-            );
+        let baseline_y = match self.fill_baseline {
+            Some(baseline) => row_of(baseline),
+            None => bottom_right.y,
+        };
 
-            // Draw vertical line from curve to baseline
-            if curve_y <= baseline_y {
-                let top_point = Point::new(x, curve_y);
-                let bottom_point = Point::new(x, baseline_y);
+        // Only rows between the line and the baseline can be covered
+        let line_top = screen_points
+            .iter()
+            .map(|p| p.y)
+            .min()
+            .unwrap_or(baseline_y);
+        let line_bottom = screen_points
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .unwrap_or(baseline_y);
+        let top = line_top.min(baseline_y).max(chart_area.top_left.y);
+        let bottom = line_bottom.max(baseline_y).min(bottom_right.y);
+
+        let mut band_start = top;
+        let mut band_color = gradient.color_for_value(value_at(top));
+        for y in top + 1..=bottom + 1 {
+            let color = if y <= bottom {
+                gradient.color_for_value(value_at(y))
+            } else {
+                None
+            };
+            if color == band_color && y <= bottom {
+                continue;
+            }
 
-                Line::new(top_point, bottom_point)
-                    .into_styled(line_style)
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
+            if let Some(band_color) = band_color {
+                let band = Rectangle::new(
+                    Point::new(chart_area.top_left.x, band_start),
+                    Size::new(chart_area.size.width, (y - band_start) as u32),
+                );
+                raster.clipped_to(band).fill_to_baseline(
+                    screen_points,
+                    baseline_y,
+                    band_color,
+                    band_color,
+                    target,
+                )?;
             }
+            band_start = y;
+            band_color = color;
         }
 
         Ok(())
     }
-}
-
-impl<C: PixelColor> Default for LineChart<C>
-where
-    C: From<embedded_graphics::pixelcolor::Rgb565>,
-{
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<C: PixelColor + 'static> Chart<C> for LineChart<C>
-where
-    C: From<embedded_graphics::pixelcolor::Rgb565>,
-{
-    type Data = crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>;
-    type Config = ChartConfig<C>;
 
-    fn draw<D>(
+    /// Draw the area fill with a fill style
+    ///
+    /// The style is rendered over the box spanned by the line and the
+    /// baseline, through a stencil that keeps only the pixels of the area.
+    fn draw_style_fill<D>(
         &self,
-        data: &Self::Data,
-        config: &Self::Config,
+        screen_points: &[Point],
+        fill_style: &FillStyle<C>,
         viewport: Rectangle,
+        data_bounds: &DataBounds<f32, f32>,
         target: &mut D,
     ) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>,
-        Self::Data: DataSeries,
-        <Self::Data as DataSeries>::Item: DataPoint,
-        <<Self::Data as DataSeries>::Item as DataPoint>::X: Into<f32> + Copy + PartialOrd,
-        <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
     {
-        if data.is_empty() {
-            return Err(ChartError::InsufficientData);
+        if screen_points.len() < 2 {
+            return Ok(());
         }
 
-        // Calculate data bounds
-        let data_bounds = data.bounds()?;
+        let chart_area = self.config.margins.apply_to(viewport);
+        let Some(bottom_right) = chart_area.bottom_right() else {
+            return Ok(());
+        };
+        let baseline_y = match self.fill_baseline {
+            Some(baseline) => {
+                let baseline_point = crate::data::Point2D::new(data_bounds.min_x, baseline);
+                self.transform_point(&baseline_point, data_bounds, viewport)
+                    .y
+            }
+            None => bottom_right.y,
+        };
+
+        let mut corners = (
+            Point::new(i32::MAX, baseline_y),
+            Point::new(i32::MIN, baseline_y),
+        );
+        for &point in screen_points {
+            corners = (
+                corners.0.component_min(point),
+                corners.1.component_max(point),
+            );
+        }
+        let fill_box = Rectangle::with_corners(corners.0, corners.1).intersection(&chart_area);
+        if fill_box.is_zero_sized() {
+            return Ok(());
+        }
+
+        let mut stencil = AreaStencil {
+            target,
+            points: screen_points,
+            baseline_y,
+        };
+        ChartRenderer::draw_filled_rectangle(fill_box, fill_style, &mut stencil)?;
+
+        Ok(())
+    }
+}
+
+/// Draw target passing on only the pixels between a polyline and a baseline
+///
+/// The polyline must run left to right. Coverage is computed with integer
+/// interpolation along the segment above or below each pixel.
+struct AreaStencil<'a, D> {
+    target: &'a mut D,
+    points: &'a [Point],
+    baseline_y: i32,
+}
+
+/// Check whether `pixel` lies between a left-to-right polyline and a baseline
+fn area_covers(points: &[Point], baseline_y: i32, pixel: Point) -> bool {
+    let index = points.partition_point(|p| p.x < pixel.x);
+    let line_y = match (
+        index.checked_sub(1).and_then(|i| points.get(i)),
+        points.get(index),
+    ) {
+        (_, Some(b)) if b.x == pixel.x => b.y,
+        (Some(a), Some(b)) => {
+            let offset = i64::from(b.y - a.y) * i64::from(pixel.x - a.x) / i64::from(b.x - a.x);
+            a.y + offset as i32
+        }
+        _ => return false,
+    };
+    (line_y.min(baseline_y)..=line_y.max(baseline_y)).contains(&pixel.y)
+}
+
+impl<D: DrawTarget> Dimensions for AreaStencil<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for AreaStencil<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (points, baseline_y) = (self.points, self.baseline_y);
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(point, _)| area_covers(points, baseline_y, *point)),
+        )
+    }
+}
+
+impl<C: PixelColor, const N: usize> Default for LineChart<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            style: LineChartStyle::default(),
+            config: ChartConfig::default(),
+            grid: None,
+            x_axis: None,
+            y_axis: None,
+            secondary_y_axis: None,
+            palette: SeriesPalette::default(),
+            x_window: None,
+            events: None,
+            annotations: None,
+            forecast: None,
+            transform_overlays: heapless::Vec::new(),
+            stats_overlay: None,
+            quality: None,
+            fill_baseline: None,
+            fill_below_color: None,
+            fill_gradient: None,
+            fill_style: None,
+            monochrome: None,
+        }
+    }
+}
+
+impl<C: PixelColor + 'static, const N: usize> LineChart<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Draw the chart from any cloneable iterator of points.
+    ///
+    /// This is what [`Chart::draw`] uses internally. Passing an iterator
+    /// directly avoids copying streaming data into a [`StaticDataSeries`]
+    /// every frame; the iterator is cloned for each pass over the points, so
+    /// it should borrow rather than own the data, e.g.
+    /// `window.iter_chronological()` of a sliding window series.
+    ///
+    /// [`StaticDataSeries`]: crate::data::series::StaticDataSeries
+    pub fn draw_points<I, D>(
+        &self,
+        points: I,
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D> + Clone,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_points_accelerated(points, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    /// Draw the chart from an iterator of points using the target's fast paths
+    ///
+    /// Same as [`draw_points`](Self::draw_points), with area fills written
+    /// through [`ChartDrawTarget::fill_span`].
+    pub fn draw_points_accelerated<I, D>(
+        &self,
+        points: I,
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D> + Clone,
+        D: ChartDrawTarget<Color = C>,
+    {
+        if points.clone().next().is_none() {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
+        }
+
+        // Calculate data bounds, rejecting ranges that cannot be plotted
+        let mut data_bounds = calculate_bounds(points.clone())?;
+        RangeTransform::checked(data_bounds.min_x, data_bounds.max_x)?;
+        RangeTransform::checked(data_bounds.min_y, data_bounds.max_y)?;
+
+        // Make room for the forecast so the series and its projection share one mapping
+        let trend = match self.forecast {
+            Some(_) => LinearTrend::fit_points(points.clone()).ok(),
+            None => None,
+        };
+        if let (Some(forecast), Some(trend)) = (self.forecast.as_ref(), trend.as_ref()) {
+            let (low, high) = forecast.value_range(trend);
+            data_bounds.max_x = data_bounds.max_x.max(forecast.end_x(trend));
+            data_bounds.min_y = data_bounds.min_y.min(low);
+            data_bounds.max_y = data_bounds.max_y.max(high);
+        }
+
+        // Keep the fill baseline in view so fills above and below it are visible
+        if let (true, Some(baseline)) = (self.style.fill_area, self.fill_baseline) {
+            data_bounds.min_y = data_bounds.min_y.min(baseline);
+            data_bounds.max_y = data_bounds.max_y.max(baseline);
+        }
 
         // Draw background if specified
-        if let Some(bg_color) = config.background_color {
-            Rectangle::new(viewport.top_left, viewport.size)
-                .into_styled(PrimitiveStyle::with_fill(bg_color))
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
+        config.draw_background(viewport, target)?;
+
+        // The title takes a band at the top; everything else is laid out below it
+        config.draw_title(viewport, target)?;
+        let viewport = config.content_area(viewport);
+
+        let chart_area = config.margins.apply_to(viewport);
+        self.draw_underlay(chart_area, target)?;
+        self.draw_annotations(&data_bounds, viewport, target)?;
+        self.draw_stats_overlay(points.clone(), &data_bounds, viewport, target)?;
+        self.draw_series_in_window(
+            points.clone(),
+            self.series_line(self.style.line_color, self.style.line_pattern),
+            &data_bounds,
+            viewport,
+            target,
+        )?;
+        self.draw_transform_overlays(points, &data_bounds, viewport, target)?;
+
+        // The forecast continues from the last point, clipped to the chart area
+        if let (Some(forecast), Some(trend)) = (self.forecast.as_ref(), trend.as_ref()) {
+            let mut clipped = target.clipped(&chart_area);
+            forecast.draw(
+                trend,
+                |point| self.transform_point(&point, &data_bounds, viewport),
+                &mut clipped,
+            )?;
         }
 
-        // First, draw grid lines from axes (background layer)
-        {
-            let chart_area = config.margins.apply_to(viewport);
+        self.draw_events(&data_bounds, chart_area, target)?;
+        self.draw_axes(chart_area, target)?;
+
+        Ok(())
+    }
 
-            // Draw grid lines from X-axis
-            if let Some(ref x_axis) = self.x_axis {
-                x_axis.draw_grid_lines(chart_area, chart_area, target)?;
+    /// Draw every series of a [`MultiSeries`] over one background, grid and set of axes.
+    ///
+    /// Series `i` is drawn in color `i` of the [series palette](Self::series_palette)
+    /// and the data bounds are combined over all series. Series assigned to [`SeriesAxis::Secondary`] are scaled to the
+    /// secondary Y-axis, or to their combined range when no secondary axis is
+    /// set, and share the X mapping of the primary series. Secondary series
+    /// cannot be combined with a logarithmic primary Y-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_charts::prelude::*;
+    /// use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+    ///
+    /// let chart = LineChart::builder()
+    ///     .with_y_axis(LinearAxis::new(0.0, 50.0, AxisOrientation::Vertical, AxisPosition::Left))
+    ///     .with_secondary_y_axis(
+    ///         LinearAxis::new(900.0, 1100.0, AxisOrientation::Vertical, AxisPosition::Right),
+    ///     )
+    ///     .build()?;
+    ///
+    /// let mut data: MultiSeries<Point2D, 2, 256> = MultiSeries::new();
+    /// data.add_series(data_points![(0.0, 21.0), (1.0, 23.5), (2.0, 22.0)])?;
+    /// data.add_series_on(
+    ///     data_points![(0.0, 1013.0), (1.0, 1009.0), (2.0, 1002.0)],
+    ///     SeriesAxis::Secondary,
+    /// )?;
+    ///
+    /// let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// display.set_allow_out_of_bounds_drawing(true);
+    /// let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+    /// chart.draw_multi_series(&data, chart.config(), viewport, &mut display)?;
+    /// # Ok::<(), embedded_charts::error::ChartError>(())
+    /// ```
+    ///
+    /// [`MultiSeries`]: crate::data::MultiSeries
+    pub fn draw_multi_series<const S: usize, const P: usize, D>(
+        &self,
+        data: &MultiSeries<Point2D, S, P>,
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_multi_series_accelerated(data, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    /// Draw every series of a [`MultiSeries`] using the target's fast paths
+    ///
+    /// Same as [`draw_multi_series`](Self::draw_multi_series), with area
+    /// fills written through [`ChartDrawTarget::fill_span`].
+    ///
+    /// [`MultiSeries`]: crate::data::MultiSeries
+    pub fn draw_multi_series_accelerated<const S: usize, const P: usize, D>(
+        &self,
+        data: &MultiSeries<Point2D, S, P>,
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        let points_on = |axis: SeriesAxis| {
+            data.iter_series_with_axes()
+                .filter(move |(_, on)| *on == axis)
+                .flat_map(|(series, _)| series.as_slice().iter().copied())
+        };
+        let primary = points_on(SeriesAxis::Primary);
+        let secondary = points_on(SeriesAxis::Secondary);
+        if primary.clone().chain(secondary.clone()).next().is_none() {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
+        }
+
+        // Secondary values are mapped into the primary Y range so every series
+        // shares one transform
+        let secondary_range = match (self.secondary_y_axis.as_ref(), secondary.clone().next()) {
+            (_, None) => None,
+            (Some(axis), Some(_)) => Some((axis.min(), axis.max())),
+            (None, Some(_)) => {
+                let bounds = calculate_bounds(secondary.clone())?;
+                Some((bounds.min_y, bounds.max_y))
             }
+        };
+        let primary_range = match (self.y_axis.as_ref(), primary.clone().next()) {
+            (Some(axis), _) => (axis.min(), axis.max()),
+            (None, Some(_)) => {
+                let bounds = calculate_bounds(primary.clone())?;
+                (bounds.min_y, bounds.max_y)
+            }
+            (None, None) => secondary_range.unwrap_or((0.0, 1.0)),
+        };
+        if let Some((low, high)) = secondary_range {
+            if self
+                .y_axis
+                .as_ref()
+                .is_some_and(|axis| axis.is_logarithmic())
+            {
+                return Err(ChartError::InvalidConfiguration);
+            }
+            RangeTransform::checked(low, high)?;
+        }
+        let to_primary = move |point: Point2D| {
+            let Some((low, high)) = secondary_range else {
+                return point;
+            };
+            let norm = match self.secondary_y_axis {
+                Some(ref axis) if axis.is_logarithmic() => axis.normalize(point.y),
+                _ => (point.y - low) / (high - low),
+            };
+            let (primary_low, primary_high) = primary_range;
+            Point2D::new(point.x, primary_low + norm * (primary_high - primary_low))
+        };
 
-            // Draw grid lines from Y-axis
-            if let Some(ref y_axis) = self.y_axis {
-                y_axis.draw_grid_lines(chart_area, chart_area, target)?;
+        // Calculate data bounds, rejecting ranges that cannot be plotted
+        let mut data_bounds = calculate_bounds(primary.chain(secondary.map(to_primary)))?;
+        RangeTransform::checked(data_bounds.min_x, data_bounds.max_x)?;
+        RangeTransform::checked(data_bounds.min_y, data_bounds.max_y)?;
+
+        // Keep the fill baseline in view so fills above and below it are visible
+        if let (true, Some(baseline)) = (self.style.fill_area, self.fill_baseline) {
+            data_bounds.min_y = data_bounds.min_y.min(baseline);
+            data_bounds.max_y = data_bounds.max_y.max(baseline);
+        }
+
+        config.draw_background(viewport, target)?;
+        config.draw_title(viewport, target)?;
+        let viewport = config.content_area(viewport);
+        let chart_area = config.margins.apply_to(viewport);
+        self.draw_underlay(chart_area, target)?;
+        self.draw_annotations(&data_bounds, viewport, target)?;
+
+        let series = data.iter_series_with_axes().zip(self.palette.colors());
+        for (index, ((series, axis), color)) in series.enumerate() {
+            let points = series.as_slice().iter().copied();
+            let line = self.series_line(color, self.series_pattern(index));
+            match axis {
+                SeriesAxis::Primary => {
+                    self.draw_series_in_window(points, line, &data_bounds, viewport, target)?
+                }
+                SeriesAxis::Secondary => self.draw_series_in_window(
+                    points.map(to_primary),
+                    line,
+                    &data_bounds,
+                    viewport,
+                    target,
+                )?,
             }
         }
 
+        self.draw_events(&data_bounds, chart_area, target)?;
+        self.draw_axes(chart_area, target)
+    }
+
+    /// Add one legend line entry per series of `data`
+    ///
+    /// Entries use the series labels, or `Series 1`, `Series 2`, ... for
+    /// unlabeled series, and the colors [`draw_multi_series`](Self::draw_multi_series)
+    /// draws them with.
+    pub fn add_series_legend<const S: usize, const P: usize, const L: usize>(
+        &self,
+        data: &MultiSeries<Point2D, S, P>,
+        mut legend: StandardLegendBuilder<C, L>,
+    ) -> ChartResult<StandardLegendBuilder<C, L>> {
+        use core::fmt::Write;
+
+        for (index, (series, color)) in data.iter_series().zip(self.palette.colors()).enumerate() {
+            let mut fallback = heapless::String::<16>::new();
+            let label = match series.label() {
+                Some(label) => label,
+                None => {
+                    write!(fallback, "Series {}", index + 1)
+                        .map_err(|_| ChartError::ConfigurationError)?;
+                    fallback.as_str()
+                }
+            };
+            legend = legend.add_entry(
+                label,
+                LegendEntryType::Line {
+                    color,
+                    width: self.style.line_width,
+                    pattern: self.series_pattern(index),
+                    marker: None,
+                },
+            )?;
+        }
+        Ok(legend)
+    }
+
+    /// Draw axis grid lines, zero lines and the legacy grid (background layer)
+    fn draw_underlay<D>(&self, chart_area: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Draw grid lines from X-axis
+        if let Some(ref x_axis) = self.x_axis {
+            x_axis.draw_grid_lines(chart_area, chart_area, target)?;
+        }
+
+        // Draw grid lines from Y-axis
+        if let Some(ref y_axis) = self.y_axis {
+            y_axis.draw_grid_lines(chart_area, chart_area, target)?;
+        }
+
+        // Zero lines are drawn even when grid lines are disabled
+        if let Some(ref x_axis) = self.x_axis {
+            x_axis.draw_zero_line(chart_area, chart_area, target)?;
+        }
+        if let Some(ref y_axis) = self.y_axis {
+            y_axis.draw_zero_line(chart_area, chart_area, target)?;
+        }
+
         // Draw grid if present (legacy grid system)
         if let Some(ref grid) = self.grid {
-            let chart_area = config.margins.apply_to(viewport);
             grid.draw(chart_area, target)?;
+            grid.draw_zero_lines(
+                chart_area,
+                self.x_axis.as_ref(),
+                self.y_axis.as_ref(),
+                target,
+            )?;
         }
 
-        // Collect and potentially smooth the data points
-        let data_to_render = if self.style.smooth && data.len() > 2 {
-            // Create interpolated smooth curve
-            use crate::math::interpolation::{
-                CurveInterpolator, InterpolationConfig, InterpolationType,
-            };
+        Ok(())
+    }
 
-            let mut input_points = heapless::Vec::<crate::data::Point2D, 256>::new();
-            for point in data.iter() {
-                input_points
-                    .push(point)
-                    .map_err(|_| ChartError::MemoryFull)?;
-            }
+    /// Draw reference lines and bands, which share the mapping of the series
+    fn draw_annotations<D>(
+        &self,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(ref annotations) = self.annotations {
+            annotations.draw(
+                |point| self.transform_point(&point, data_bounds, viewport),
+                self.config.margins.apply_to(viewport),
+                target,
+            )?;
+        }
+        Ok(())
+    }
 
-            let interpolation_config = InterpolationConfig {
-                interpolation_type: InterpolationType::CatmullRom,
-                subdivisions: self.style.smooth_subdivisions,
-                tension: 0.5,
-                closed: false,
-            };
+    /// Draw the mean and standard deviation bands of a series behind it
+    fn draw_stats_overlay<I, D>(
+        &self,
+        points: I,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D>,
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(ref overlay) = self.stats_overlay {
+            let stats = SeriesStats::from_values(points.map(|point| point.y))?;
+            overlay.draw(
+                &stats,
+                |point| self.transform_point(&point, data_bounds, viewport),
+                self.config.margins.apply_to(viewport),
+                target,
+            )?;
+        }
+        Ok(())
+    }
 
-            let interpolated =
-                CurveInterpolator::interpolate(&input_points, &interpolation_config)?;
+    /// Draw a series, clipped to the chart area when showing a fixed window
+    fn draw_series_in_window<I, D>(
+        &self,
+        points: I,
+        line: LineStyle<C>,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D> + Clone,
+        D: ChartDrawTarget<Color = C>,
+    {
+        if self.x_window.is_some() {
+            let chart_area = self.config.margins.apply_to(viewport);
+            let mut clipped = target.clipped(&chart_area);
+            self.draw_series(points, line, data_bounds, viewport, &mut clipped)
+        } else {
+            self.draw_series(points, line, data_bounds, viewport, target)
+        }
+    }
 
-            // Create a temporary data series with interpolated points
-            let mut smooth_data = crate::data::series::StaticDataSeries::new();
-            for point in interpolated.iter() {
-                smooth_data
-                    .push(*point)
+    /// Draw the transformed copies of a series over it
+    fn draw_transform_overlays<I, D>(
+        &self,
+        points: I,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point2D> + Clone,
+        D: DrawTarget<Color = C>,
+    {
+        let chart_area = self.config.margins.apply_to(viewport);
+        for overlay in &self.transform_overlays {
+            let mut screen_points = heapless::Vec::<Point, N>::new();
+            for point in overlay.apply(points.clone()) {
+                screen_points
+                    .push(self.transform_point(&point, data_bounds, viewport))
                     .map_err(|_| ChartError::MemoryFull)?;
             }
-            smooth_data
-        } else {
-            // Use original data
-            data.clone()
-        };
+            ChartRenderer::draw_polyline(
+                &screen_points,
+                &overlay.line,
+                &mut target.clipped(&chart_area),
+            )?;
+        }
+        Ok(())
+    }
 
-        // Transform data points to screen coordinates
-        let mut screen_points = heapless::Vec::<Point, 512>::new();
-        for point in data_to_render.iter() {
-            let screen_point = self.transform_point(&point, &data_bounds, viewport);
-            screen_points
-                .push(screen_point)
-                .map_err(|_| ChartError::MemoryFull)?;
+    /// Get the stroke of a series drawn in `color` with `pattern`
+    fn series_line(&self, color: C, pattern: LinePattern) -> LineStyle<C> {
+        LineStyle::solid(color)
+            .width(self.style.line_width)
+            .pattern(pattern)
+    }
+
+    /// Draw event markers, which share the X mapping of the series
+    fn draw_events<D>(
+        &self,
+        data_bounds: &DataBounds<f32, f32>,
+        chart_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(ref events) = self.events {
+            let (x_min, x_max) = if let Some(window) = self.x_window {
+                window
+            } else if let Some(ref x_axis) = self.x_axis {
+                (x_axis.min(), x_axis.max())
+            } else {
+                (data_bounds.min_x, data_bounds.max_x)
+            };
+            events.draw(x_min, x_max, chart_area, target)?;
         }
+        Ok(())
+    }
 
-        // Draw area fill if enabled
-        if self.style.fill_area {
-            if let Some(fill_color) = self.style.fill_color {
-                self.draw_area_fill(&screen_points, fill_color, viewport, &data_bounds, target)?;
-            }
+    /// Draw axis lines, ticks, and labels (foreground layer)
+    fn draw_axes<D>(&self, chart_area: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Draw X-axis (without grid lines)
+        if let Some(ref x_axis) = self.x_axis {
+            x_axis.draw_axis_only(chart_area, target)?;
         }
 
-        // Draw lines between consecutive points
-        let line_style = PrimitiveStyle::with_stroke(self.style.line_color, self.style.line_width);
-        for window in screen_points.windows(2) {
-            if let [p1, p2] = window {
-                Line::new(*p1, *p2)
-                    .into_styled(line_style)
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
+        // Draw Y-axes (without grid lines)
+        if let Some(ref y_axis) = self.y_axis {
+            y_axis.draw_axis_only(chart_area, target)?;
+        }
+        if let Some(ref secondary_y_axis) = self.secondary_y_axis {
+            secondary_y_axis.draw_axis_only(chart_area, target)?;
         }
 
-        // Draw markers
-        self.draw_markers(data, &data_bounds, viewport, target)?;
+        Ok(())
+    }
+}
 
-        // Finally, draw axis lines, ticks, and labels (foreground layer)
+impl<C: PixelColor + 'static, const N: usize> Chart<C> for LineChart<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Data = crate::data::series::StaticDataSeries<crate::data::point::Point2D, N>;
+    type Config = ChartConfig<C>;
+
+    fn draw<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_points(data.as_slice().iter().copied(), config, viewport, target)
+    }
+
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        self.draw_points_accelerated(data.as_slice().iter().copied(), config, viewport, target)
+    }
+
+    /// Narrow the redraw to the segments around the change when both axis
+    /// ranges are fixed
+    ///
+    /// Without fixed ranges, decimation or with a forecast, transform or
+    /// stats overlays, any change can rescale or reshape the whole line and
+    /// the viewport is returned.
+    fn invalidate(&self, data: &Self::Data, change: DataChange, viewport: Rectangle) -> Rectangle {
+        let fixed = (self.x_window.is_some() || self.x_axis.is_some()) && self.y_axis.is_some();
+        if !fixed
+            || self.style.decimation.is_some()
+            || self.forecast.is_some()
+            || !self.transform_overlays.is_empty()
+            || self.stats_overlay.is_some()
         {
-            let chart_area = config.margins.apply_to(viewport);
+            return viewport;
+        }
 
-            // Draw X-axis (without grid lines)
-            if let Some(ref x_axis) = self.x_axis {
-                x_axis.draw_axis_only(chart_area, target)?;
+        // Smoothed curves also bend between the neighbours of a point
+        let reach = if self.style.smooth { 2 } else { 1 };
+        let points = data.as_slice();
+        let (first, last) = match change {
+            DataChange::Appended(count) => {
+                (points.len().saturating_sub(count + reach), points.len())
             }
+            DataChange::Updated(index) => (index.saturating_sub(reach), index + reach + 1),
+            DataChange::Replaced => return viewport,
+        };
+        let Some(points) = points.get(first..last.min(points.len())) else {
+            return viewport;
+        };
 
-            // Draw Y-axis (without grid lines)
-            if let Some(ref y_axis) = self.y_axis {
-                y_axis.draw_axis_only(chart_area, target)?;
-            }
-        }
+        let Ok(bounds) = calculate_bounds(data.as_slice().iter().copied()) else {
+            return viewport;
+        };
+        let columns = points
+            .iter()
+            .map(|point| self.transform_point(point, &bounds, viewport).x);
+        let (Some(left), Some(right)) = (columns.clone().min(), columns.max()) else {
+            return viewport;
+        };
 
-        Ok(())
+        // Strokes and markers reach past the points they are centered on
+        let marker = self.style.markers.as_ref().map_or(0, |marker| marker.size);
+        let pad = (self.style.line_width.max(marker) / 2 + 1) as i32;
+        let chart_area = self.config.margins.apply_to(viewport);
+        Rectangle::with_corners(
+            Point::new(left - pad, chart_area.top_left.y - pad),
+            Point::new(
+                right + pad,
+                chart_area.top_left.y + chart_area.size.height as i32 + pad,
+            ),
+        )
+        .intersection(&viewport)
+    }
+}
+
+impl<C: PixelColor + 'static, const N: usize> crate::chart::traits::ConfiguredChart<C>
+    for LineChart<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
     }
 }
 
@@ -824,6 +1771,10 @@ where
             markers: None,
             smooth: false,
             smooth_subdivisions: 8,
+            simplify_epsilon: None,
+            decimation: None,
+            smooth_endpoints: EndpointMode::Duplicate,
+            line_pattern: LinePattern::Solid,
         }
     }
 }
@@ -843,13 +1794,30 @@ where
 }
 
 /// Builder for line charts
+///
+/// `N` is the point capacity of the chart being built, see
+/// [`capacity`](Self::capacity).
 #[derive(Debug)]
-pub struct LineChartBuilder<C: PixelColor> {
+pub struct LineChartBuilder<C: PixelColor, const N: usize = 256> {
     style: LineChartStyle<C>,
     config: ChartConfig<C>,
     grid: Option<crate::grid::GridSystem<C>>,
     x_axis: Option<crate::axes::LinearAxis<f32, C>>,
     y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    secondary_y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    palette: SeriesPalette<C, 8>,
+    events: Option<EventOverlay<C>>,
+    annotations: Option<AnnotationOverlay<C>>,
+    forecast: Option<ForecastOverlay<C>>,
+    transform_overlays: heapless::Vec<TransformOverlay<C>, MAX_TRANSFORM_OVERLAYS>,
+    stats_overlay: Option<StatsOverlay<C>>,
+    quality: Option<QualityProfile>,
+    fill_baseline: Option<f32>,
+    fill_below_color: Option<C>,
+    fill_gradient: Option<ValueGradient<C>>,
+    fill_style: Option<FillStyle<C>>,
+    fill_fade: Option<C>,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 impl<C: PixelColor> LineChartBuilder<C>
@@ -858,12 +1826,41 @@ where
 {
     /// Create a new line chart builder
     pub fn new() -> Self {
-        Self {
-            style: LineChartStyle::default(),
-            config: ChartConfig::default(),
-            grid: None,
-            x_axis: None,
-            y_axis: None,
+        Self::default()
+    }
+}
+
+impl<C: PixelColor, const N: usize> LineChartBuilder<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Set the maximum number of data points of the chart
+    ///
+    /// The chart draws [`StaticDataSeries`] of this capacity, and the
+    /// transform and smoothing buffers are sized to match.
+    ///
+    /// [`StaticDataSeries`]: crate::data::series::StaticDataSeries
+    pub fn capacity<const M: usize>(self) -> LineChartBuilder<C, M> {
+        LineChartBuilder {
+            style: self.style,
+            config: self.config,
+            grid: self.grid,
+            x_axis: self.x_axis,
+            y_axis: self.y_axis,
+            secondary_y_axis: self.secondary_y_axis,
+            palette: self.palette,
+            events: self.events,
+            annotations: self.annotations,
+            forecast: self.forecast,
+            transform_overlays: self.transform_overlays,
+            stats_overlay: self.stats_overlay,
+            quality: self.quality,
+            fill_baseline: self.fill_baseline,
+            fill_below_color: self.fill_below_color,
+            fill_gradient: self.fill_gradient,
+            fill_style: self.fill_style,
+            fill_fade: self.fill_fade,
+            monochrome: self.monochrome,
         }
     }
 
@@ -879,6 +1876,12 @@ where
         self
     }
 
+    /// Set the dash pattern of the line
+    pub fn line_pattern(mut self, pattern: LinePattern) -> Self {
+        self.style.line_pattern = pattern;
+        self
+    }
+
     /// Enable area filling with the specified color
     pub fn fill_area(mut self, color: C) -> Self {
         self.style.fill_area = true;
@@ -886,6 +1889,46 @@ where
         self
     }
 
+    /// Fill to `value` instead of the bottom of the chart area
+    pub fn fill_baseline(mut self, value: f32) -> Self {
+        self.fill_baseline = Some(value);
+        self
+    }
+
+    /// Use `color` for the fill where the line is below the fill baseline
+    pub fn fill_below_color(mut self, color: C) -> Self {
+        self.fill_below_color = Some(color);
+        self
+    }
+
+    /// Fill the area with a gradient mapped to the Y value, e.g. blue for low
+    /// temperatures and red for high ones
+    pub fn fill_gradient(mut self, gradient: ValueGradient<C>) -> Self {
+        self.style.fill_area = true;
+        self.fill_gradient = Some(gradient);
+        self
+    }
+
+    /// Fill the area with a fill style, e.g. a vertical [`LinearGradient`]
+    /// running from the top of the line down to the baseline
+    ///
+    /// Generic colors step between gradient stops rather than blending, so
+    /// add intermediate stops for a smoother fade.
+    pub fn fill_style(mut self, style: FillStyle<C>) -> Self {
+        self.style.fill_area = true;
+        self.fill_style = Some(style);
+        self.fill_fade = None;
+        self
+    }
+
+    /// Fill the area with a vertical gradient from the line color to `to`,
+    /// usually the background color
+    pub fn fill_fade(mut self, to: C) -> Self {
+        self.style.fill_area = true;
+        self.fill_fade = Some(to);
+        self
+    }
+
     /// Add markers to data points
     pub fn with_markers(mut self, marker_style: MarkerStyle<C>) -> Self {
         self.style.markers = Some(marker_style);
@@ -900,12 +1943,30 @@ where
         self
     }
 
+    /// Set a subtitle drawn below the title
+    pub fn with_subtitle(mut self, subtitle: &str) -> Self {
+        self.config.subtitle = heapless::String::try_from(subtitle).ok();
+        self
+    }
+
+    /// Set the fonts, colors and alignment of the title and subtitle
+    pub fn title_style(mut self, style: TitleStyle<C>) -> Self {
+        self.config.title_style = Some(style);
+        self
+    }
+
     /// Set the background color
     pub fn background_color(mut self, color: C) -> Self {
         self.config.background_color = Some(color);
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
     /// Set the chart margins
     pub fn margins(mut self, margins: Margins) -> Self {
         self.config.margins = margins;
@@ -924,6 +1985,24 @@ where
         self
     }
 
+    /// Set how the ends of smooth curves are shaped
+    pub fn smooth_endpoints(mut self, endpoints: EndpointMode) -> Self {
+        self.style.smooth_endpoints = endpoints;
+        self
+    }
+
+    /// Simplify the stroked line, dropping vertices within `epsilon` pixels
+    pub fn simplify(mut self, epsilon: f32) -> Self {
+        self.style.simplify_epsilon = Some(epsilon);
+        self
+    }
+
+    /// Decimate series with more points than the plot is wide while drawing
+    pub fn decimate(mut self, decimation: Decimation) -> Self {
+        self.style.decimation = Some(decimation);
+        self
+    }
+
     /// Set the grid system
     pub fn with_grid(mut self, grid: crate::grid::GridSystem<C>) -> Self {
         self.grid = Some(grid);
@@ -941,32 +2020,178 @@ where
         self.y_axis = Some(axis);
         self
     }
+
+    /// Set a secondary Y-axis with its own range, usually on the right
+    ///
+    /// Series of a [`MultiSeries`] assigned to [`SeriesAxis::Secondary`] are
+    /// plotted against this axis by [`LineChart::draw_multi_series`].
+    ///
+    /// [`MultiSeries`]: crate::data::MultiSeries
+    /// [`SeriesAxis::Secondary`]: crate::data::SeriesAxis::Secondary
+    pub fn with_secondary_y_axis(mut self, axis: crate::axes::LinearAxis<f32, C>) -> Self {
+        self.secondary_y_axis = Some(axis);
+        self
+    }
+
+    /// Set the colors series of a [`MultiSeries`] are drawn with
+    ///
+    /// [`MultiSeries`]: crate::data::MultiSeries
+    pub fn series_palette(mut self, palette: SeriesPalette<C, 8>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Draw event markers over the plot area
+    pub fn with_events(mut self, events: EventOverlay<C>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Draw reference lines and bands between the grid and the data
+    pub fn with_annotations(mut self, annotations: AnnotationOverlay<C>) -> Self {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    /// Override the global rendering quality profile
+    pub fn quality(mut self, quality: QualityProfile) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Extend the linear trend of the data beyond its last point
+    pub fn with_forecast(mut self, forecast: ForecastOverlay<C>) -> Self {
+        self.forecast = Some(forecast);
+        self
+    }
+
+    /// Draw a transformed copy of the data over it, such as a moving average
+    ///
+    /// Returns an error if the chart already has
+    /// [`MAX_TRANSFORM_OVERLAYS`] overlays.
+    pub fn with_transform_overlay(mut self, overlay: TransformOverlay<C>) -> ChartResult<Self> {
+        self.transform_overlays
+            .push(overlay)
+            .map_err(|_| ChartError::MemoryFull)?;
+        Ok(self)
+    }
+
+    /// Draw the mean of the data as a line over standard deviation bands
+    pub fn with_stats_overlay(mut self, overlay: StatsOverlay<C>) -> Self {
+        self.stats_overlay = Some(overlay);
+        self
+    }
+
+    /// Draw for a monochrome or grayscale display, such as e-paper
+    ///
+    /// When the chart is built, the line, markers, series and axes are set to
+    /// the ink of `style` and the background to its paper. Solid transform
+    /// overlays are drawn with the line patterns of the following series. Area fills use its
+    /// first fill pattern unless a fill style was set, grid lines are dotted
+    /// and the series of a [`MultiSeries`] are told apart by line patterns.
+    ///
+    /// [`MultiSeries`]: crate::data::MultiSeries
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.monochrome = Some(style);
+        self
+    }
 }
 
-impl<C: PixelColor + 'static> ChartBuilder<C> for LineChartBuilder<C>
+impl<C: PixelColor + 'static, const N: usize> ChartBuilder<C> for LineChartBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    type Chart = LineChart<C>;
+    type Chart = LineChart<C, N>;
     type Error = ChartError;
 
-    fn build(self) -> Result<Self::Chart, Self::Error> {
+    fn build(mut self) -> Result<Self::Chart, Self::Error> {
+        if let Some(monochrome) = self.monochrome {
+            self.style.line_color = monochrome.ink;
+            if let Some(markers) = self.style.markers.as_mut() {
+                markers.color = monochrome.ink;
+            }
+            self.config.background_color = Some(monochrome.paper);
+            self.palette = SeriesPalette::from_colors(&[monochrome.ink])?;
+            for (index, overlay) in self.transform_overlays.iter_mut().enumerate() {
+                overlay.line.color = monochrome.ink;
+                if overlay.line.pattern == LinePattern::Solid {
+                    overlay.line.pattern = monochrome.series_pattern(index + 1);
+                }
+            }
+            if self.fill_style.is_none() {
+                self.fill_style = Some(monochrome.series_fill_style(0));
+                self.fill_fade = None;
+            }
+            for axis in [
+                &mut self.x_axis,
+                &mut self.y_axis,
+                &mut self.secondary_y_axis,
+            ] {
+                *axis = axis
+                    .take()
+                    .map(|axis| axis.with_style(monochrome.axis_style()));
+            }
+        }
+
+        let fill_style = match self.fill_fade {
+            Some(to) => Some(FillStyle::linear_gradient(LinearGradient::simple(
+                self.style.line_color,
+                to,
+                GradientDirection::Vertical,
+            )?)),
+            None => self.fill_style,
+        };
+
         Ok(LineChart {
             style: self.style,
             config: self.config,
             grid: self.grid,
             x_axis: self.x_axis,
             y_axis: self.y_axis,
+            secondary_y_axis: self.secondary_y_axis,
+            palette: self.palette,
+            x_window: None,
+            events: self.events,
+            annotations: self.annotations,
+            forecast: self.forecast,
+            transform_overlays: self.transform_overlays,
+            stats_overlay: self.stats_overlay,
+            quality: self.quality,
+            fill_baseline: self.fill_baseline,
+            fill_below_color: self.fill_below_color,
+            fill_gradient: self.fill_gradient,
+            fill_style,
+            monochrome: self.monochrome,
         })
     }
 }
 
-impl<C: PixelColor> Default for LineChartBuilder<C>
+impl<C: PixelColor, const N: usize> Default for LineChartBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
     fn default() -> Self {
-        Self::new()
+        Self {
+            style: LineChartStyle::default(),
+            config: ChartConfig::default(),
+            grid: None,
+            x_axis: None,
+            y_axis: None,
+            secondary_y_axis: None,
+            palette: SeriesPalette::default(),
+            events: None,
+            annotations: None,
+            forecast: None,
+            transform_overlays: heapless::Vec::new(),
+            stats_overlay: None,
+            quality: None,
+            fill_baseline: None,
+            fill_below_color: None,
+            fill_gradient: None,
+            fill_style: None,
+            fill_fade: None,
+            monochrome: None,
+        }
     }
 }
 
@@ -974,6 +2199,7 @@ where
 mod tests {
     use super::*;
     use crate::axes::{AxisOrientation, AxisPosition, LinearAxis};
+    use crate::chart::events::{ChartEvent, EventGlyph};
     use crate::data::series::StaticDataSeries;
     use crate::data::{DataBounds, Point2D};
     use crate::grid::GridSystem;
@@ -1068,6 +2294,10 @@ mod tests {
             markers: Some(MarkerStyle::default()),
             smooth: true,
             smooth_subdivisions: 12,
+            simplify_epsilon: None,
+            decimation: None,
+            smooth_endpoints: EndpointMode::Duplicate,
+            line_pattern: LinePattern::Solid,
         };
         chart.set_style(style.clone());
         assert_eq!(chart.style().line_color, Rgb565::MAGENTA);
@@ -1077,10 +2307,15 @@ mod tests {
         // Test config setter
         let config = ChartConfig {
             title: None,
+            subtitle: None,
+            title_style: None,
             background_color: Some(Rgb565::BLACK),
             margins: Margins::all(20),
             show_grid: true,
             grid_color: Some(Rgb565::CSS_GRAY),
+            background_mode: BackgroundMode::Fill,
+            placeholder: None,
+            locale: crate::style::NumberLocale::PLAIN,
         };
         chart.set_config(config);
         assert_eq!(chart.config().margins.top, 20);
@@ -1166,6 +2401,156 @@ mod tests {
         assert_eq!(chart.style().smooth_subdivisions, 2);
     }
 
+    #[test]
+    fn test_simplified_line_draws() {
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .simplify(1.0)
+            .build()
+            .unwrap();
+        assert_eq!(chart.style().simplify_epsilon, Some(1.0));
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for i in 0..100 {
+            data.push(Point2D::new(i as f32, (i % 2) as f32 * 0.01))
+                .unwrap();
+        }
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        assert!(chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_decimated_line_fits_plot_width() {
+        let data = (0..500).map(|i| Point2D::new(i as f32, (i % 7) as f32));
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+
+        // 500 points overflow a 64 point chart unless reduced to the 44 pixel plot
+        let plain: LineChart<Rgb565, 64> = LineChart::builder().capacity::<64>().build().unwrap();
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        assert_eq!(
+            plain.draw_points(data.clone(), plain.config(), viewport, &mut display),
+            Err(ChartError::MemoryFull)
+        );
+
+        let chart: LineChart<Rgb565, 64> = LineChart::builder()
+            .capacity::<64>()
+            .decimate(Decimation::Lttb)
+            .with_markers(MarkerStyle::default())
+            .build()
+            .unwrap();
+        assert_eq!(chart.style().decimation, Some(Decimation::Lttb));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+        assert!(chart
+            .draw_points(data, chart.config(), viewport, &mut display)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_secondary_axis_series_share_plot() {
+        use crate::data::{MultiSeries, SeriesAxis};
+        use crate::style::SeriesPalette;
+
+        let mut chart: LineChart<Rgb565> = LineChart::builder()
+            .with_y_axis(LinearAxis::new(
+                0.0,
+                50.0,
+                AxisOrientation::Vertical,
+                AxisPosition::Left,
+            ))
+            .build()
+            .unwrap();
+        assert!(chart.secondary_y_axis().is_err());
+        chart.set_secondary_y_axis(LinearAxis::new(
+            900.0,
+            1100.0,
+            AxisOrientation::Vertical,
+            AxisPosition::Right,
+        ));
+        assert_eq!(chart.secondary_y_axis().unwrap().max(), 1100.0);
+
+        // 10 of 0..50 and 1000 of 900..1100 land on different rows
+        let mut data: MultiSeries<Point2D, 2, 8> = MultiSeries::new();
+        let mut temperature = StaticDataSeries::new();
+        temperature
+            .extend([Point2D::new(0.0, 10.0), Point2D::new(10.0, 10.0)])
+            .unwrap();
+        let mut pressure = StaticDataSeries::new();
+        pressure
+            .extend([Point2D::new(0.0, 1000.0), Point2D::new(10.0, 1000.0)])
+            .unwrap();
+        data.add_series(temperature).unwrap();
+        data.add_series_on(pressure, SeriesAxis::Secondary).unwrap();
+
+        chart.set_series_palette(SeriesPalette::from_colors(&[Rgb565::RED, Rgb565::BLUE]).unwrap());
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_multi_series(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Plot area is 44 pixels high starting at y = 10
+        assert_eq!(display.get_pixel(Point::new(30, 45)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(30, 32)), Some(Rgb565::BLUE));
+    }
+
+    #[test]
+    fn test_multi_series_combined_bounds_and_legend() {
+        use crate::data::MultiSeries;
+        use crate::legend::{Legend, LegendBuilder, LegendEntry, StandardLegendBuilder};
+        use crate::style::SeriesPalette;
+
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .series_palette(
+                SeriesPalette::from_colors(&[Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE]).unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let mut data: MultiSeries<Point2D, 3, 8> = MultiSeries::new();
+        let mut low = StaticDataSeries::with_label("Low");
+        low.extend([Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0)])
+            .unwrap();
+        let mut high = StaticDataSeries::new();
+        high.extend([Point2D::new(0.0, 10.0), Point2D::new(10.0, 10.0)])
+            .unwrap();
+        data.add_series(low).unwrap();
+        data.add_series(high).unwrap();
+
+        // Both series share the combined 0..10 range, so they sit on the plot edges
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_multi_series(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert_eq!(display.get_pixel(Point::new(30, 53)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(30, 10)), Some(Rgb565::GREEN));
+
+        let legend = chart
+            .add_series_legend(&data, StandardLegendBuilder::new())
+            .unwrap()
+            .build()
+            .unwrap();
+        let entries = legend.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].label(), "Low");
+        assert_eq!(entries[1].label(), "Series 2");
+        assert!(matches!(
+            entries[1].entry_type(),
+            LegendEntryType::Line { color, .. } if *color == Rgb565::GREEN
+        ));
+    }
+
     #[test]
     fn test_transform_point_no_axes() {
         let chart: LineChart<Rgb565> = LineChart::new();
@@ -1206,9 +2591,273 @@ mod tests {
         let point = Point2D::new(5.0, 10.0);
         let screen_point = chart.transform_point(&point, &bounds, viewport);
 
-        // Should center the point
-        assert_eq!(screen_point.x, 99); // Center X
-        assert_eq!(screen_point.y, 50); // Center Y
+        // Should center the point
+        assert_eq!(screen_point.x, 99); // Center X
+        assert_eq!(screen_point.y, 50); // Center Y
+    }
+
+    #[test]
+    fn test_transform_point_large_and_small_ranges() {
+        let chart: LineChart<Rgb565> = LineChart::new();
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(200, 100));
+
+        // Beyond what I16F16 can hold; must still land mid-plot
+        let large = DataBounds::<f32, f32> {
+            min_x: 0.0,
+            max_x: 100_000.0,
+            min_y: 0.0,
+            max_y: 100_000.0,
+        };
+        let screen_point =
+            chart.transform_point(&Point2D::new(50_000.0, 50_000.0), &large, viewport);
+        assert!((screen_point.x - 99).abs() <= 1);
+        assert!((screen_point.y - 50).abs() <= 1);
+
+        let small = DataBounds::<f32, f32> {
+            min_x: 0.0,
+            max_x: 0.000_01,
+            min_y: 0.0,
+            max_y: 0.000_01,
+        };
+        let screen_point = chart.transform_point(&Point2D::new(0.000_01, 0.0), &small, viewport);
+        let plot = chart.config().margins.apply_to(viewport);
+        assert_eq!(Some(screen_point), plot.bottom_right());
+    }
+
+    #[test]
+    fn test_draw_rejects_non_finite_range() {
+        let chart: LineChart<Rgb565> = LineChart::new();
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 0.0)).unwrap();
+        data.push(Point2D::new(1.0, f32::INFINITY)).unwrap();
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let result = chart.draw(&data, chart.config(), viewport, &mut display);
+        assert_eq!(result, Err(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_quality_profile_decimates_markers() {
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for i in 0..8 {
+            data.push(Point2D::new(i as f32, 5.0)).unwrap();
+        }
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+
+        let marker_pixels = |quality: QualityProfile| {
+            let chart: LineChart<Rgb565> = LineChart::builder()
+                .line_color(Rgb565::BLUE)
+                .with_markers(MarkerStyle {
+                    shape: MarkerShape::Square,
+                    size: 3,
+                    color: Rgb565::RED,
+                    visible: true,
+                })
+                .quality(quality)
+                .build()
+                .unwrap();
+            assert_eq!(chart.effective_quality(), quality);
+
+            let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            chart
+                .draw(&data, chart.config(), viewport, &mut display)
+                .unwrap();
+            (0..64)
+                .flat_map(|x| (0..64).map(move |y| Point::new(x, y)))
+                .filter(|&point| display.get_pixel(point) == Some(Rgb565::RED))
+                .count()
+        };
+
+        let full = marker_pixels(QualityProfile::Full);
+        assert_eq!(marker_pixels(QualityProfile::Balanced) * 2, full);
+        assert_eq!(marker_pixels(QualityProfile::PowerSaver) * 4, full);
+    }
+
+    #[test]
+    fn test_draw_forecast_beyond_data() {
+        let forecast = ForecastOverlay::new(3.0)
+            .line_color(Rgb565::RED)
+            .with_cone(Rgb565::CSS_LIGHT_GRAY, 2.0);
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .with_forecast(forecast)
+            .build()
+            .unwrap();
+        assert!(chart.forecast().is_some());
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for (x, y) in [(0.0, 1.0), (1.0, 2.0), (2.0, 2.5), (3.0, 4.0)] {
+            data.push(Point2D::new(x, y)).unwrap();
+        }
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The series ends halfway across and the forecast fills the right half
+        let mut right_half = (36..54)
+            .flat_map(|x| (10..54).map(move |y| Point::new(x, y)))
+            .filter_map(|point| display.get_pixel(point));
+        assert!(right_half.clone().any(|color| color == Rgb565::RED));
+        assert!(!right_half.any(|color| color == Rgb565::BLUE));
+    }
+
+    #[test]
+    fn test_draw_transform_overlay() {
+        use crate::data::transforms::MovingAverage;
+
+        let overlay = TransformOverlay::new(MovingAverage::new(2).unwrap(), Rgb565::RED);
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .with_transform_overlay(overlay.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(chart.transform_overlays().len(), 1);
+
+        // Alternating values average to a flat line through the middle
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for x in 0..8 {
+            data.push(Point2D::new(x as f32, (x % 2) as f32 * 10.0))
+                .unwrap();
+        }
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        let rows = |color| {
+            let rows = viewport
+                .points()
+                .filter(|&point| display.get_pixel(point) == Some(color))
+                .map(|point| point.y);
+            (rows.clone().min().unwrap(), rows.max().unwrap())
+        };
+        let (raw_top, raw_bottom) = rows(Rgb565::BLUE);
+        let (average_top, average_bottom) = rows(Rgb565::RED);
+        assert!(average_top > raw_top && average_bottom <= raw_bottom);
+        assert!(average_top >= (raw_top + raw_bottom) / 2 - 1);
+
+        let mut full = LineChart::<Rgb565>::builder();
+        for _ in 0..MAX_TRANSFORM_OVERLAYS {
+            full = full.with_transform_overlay(overlay.clone()).unwrap();
+        }
+        assert!(full.with_transform_overlay(overlay).is_err());
+    }
+
+    #[test]
+    fn test_draw_stats_overlay() {
+        let overlay = StatsOverlay::new(Rgb565::RED)
+            .with_band(0.5, Rgb565::GREEN)
+            .unwrap();
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .with_stats_overlay(overlay)
+            .build()
+            .unwrap();
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for x in 0..8 {
+            data.push(Point2D::new(x as f32, (x % 2) as f32 * 10.0))
+                .unwrap();
+        }
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        let rows = |color| {
+            let rows = viewport
+                .points()
+                .filter(|&point| display.get_pixel(point) == Some(color))
+                .map(|point| point.y);
+            (rows.clone().min().unwrap(), rows.max().unwrap())
+        };
+        // The band covers the middle half of the data, centered on the mean
+        let (top, bottom) = rows(Rgb565::BLUE);
+        let (band_top, band_bottom) = rows(Rgb565::GREEN);
+        let (mean_top, mean_bottom) = rows(Rgb565::RED);
+        assert_eq!(mean_top, mean_bottom);
+        assert!((mean_top - (top + bottom) / 2).abs() <= 1);
+        assert!(band_top > top && band_bottom < bottom);
+        assert!((band_bottom - band_top - (bottom - top) / 2).abs() <= 2);
+    }
+
+    #[test]
+    fn test_draw_event_markers() {
+        let mut events: EventOverlay<Rgb565> = EventOverlay::new();
+        events
+            .add(ChartEvent::new(5.0, "", EventGlyph::None).with_color(Rgb565::GREEN))
+            .unwrap();
+        let chart: LineChart<Rgb565> = LineChart::builder().with_events(events).build().unwrap();
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 0.0)).unwrap();
+        data.push(Point2D::new(10.0, 10.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Plot area is 10..54, so x = 5 maps to the middle column
+        assert_eq!(display.get_pixel(Point::new(31, 11)), Some(Rgb565::GREEN));
+        assert_eq!(display.get_pixel(Point::new(31, 9)), None);
+    }
+
+    #[test]
+    fn test_draw_annotations_behind_data() {
+        use crate::chart::annotations::{ReferenceBand, ReferenceLine};
+        use crate::style::LineStyle;
+
+        let mut annotations: AnnotationOverlay<Rgb565> = AnnotationOverlay::new();
+        annotations
+            .add_band(ReferenceBand::horizontal(0.0, 2.0, Rgb565::YELLOW))
+            .unwrap();
+        annotations
+            .add_line(ReferenceLine::horizontal(
+                5.0,
+                LineStyle::solid(Rgb565::RED),
+            ))
+            .unwrap();
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .with_annotations(annotations)
+            .build()
+            .unwrap();
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 0.0)).unwrap();
+        data.push(Point2D::new(10.0, 10.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Plot area is 10..54; y = 5 maps to row 32 and the band to rows 45..=53
+        assert_eq!(display.get_pixel(Point::new(15, 32)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(50, 50)), Some(Rgb565::YELLOW));
+        // The series is drawn over both
+        assert_eq!(display.get_pixel(Point::new(10, 53)), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(Point::new(31, 32)), Some(Rgb565::BLUE));
     }
 
     #[test]
@@ -1226,6 +2875,54 @@ mod tests {
         assert!(matches!(result, Err(ChartError::InsufficientData)));
     }
 
+    #[test]
+    fn test_transparent_background_keeps_underlying_pixels() {
+        let chart = LineChart::builder()
+            .line_color(Rgb565::RED)
+            .background_color(Rgb565::BLACK)
+            .background_mode(BackgroundMode::Transparent)
+            .build()
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 0.0)).unwrap();
+        data.push(Point2D::new(10.0, 10.0)).unwrap();
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::zero()), None);
+        assert!(display.affected_area().size.width > 0);
+    }
+
+    #[test]
+    fn test_draw_empty_data_placeholder() {
+        use crate::chart::traits::{PlaceholderKind, PlaceholderStyle};
+        use crate::style::Theme;
+
+        let chart: LineChart<Rgb565> = LineChart::new();
+        let theme = Theme::dark();
+        let config = ChartConfig {
+            placeholder: Some(PlaceholderStyle::themed(
+                PlaceholderKind::Message("No data"),
+                &theme,
+            )),
+            ..ChartConfig::default()
+        };
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(60, 30));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        chart.draw(&data, &config, viewport, &mut display).unwrap();
+
+        assert_eq!(display.get_pixel(Point::zero()), Some(theme.background));
+        assert_eq!(display.affected_area(), viewport);
+    }
+
     #[test]
     fn test_draw_single_point() {
         let chart: LineChart<Rgb565> = LineChart::new();
@@ -1274,6 +2971,9 @@ mod tests {
             MarkerShape::Square,
             MarkerShape::Diamond,
             MarkerShape::Triangle,
+            MarkerShape::Plus,
+            MarkerShape::Cross,
+            MarkerShape::Star,
         ];
 
         for shape in shapes {
@@ -1352,6 +3052,318 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_draw_accelerated_uses_fill_spans() {
+        use crate::render::ChartDrawTarget;
+
+        struct SpanCounter {
+            spans: u32,
+        }
+
+        impl Dimensions for SpanCounter {
+            fn bounding_box(&self) -> Rectangle {
+                Rectangle::new(Point::zero(), Size::new(200, 100))
+            }
+        }
+
+        impl DrawTarget for SpanCounter {
+            type Color = Rgb565;
+            type Error = core::convert::Infallible;
+
+            fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+            where
+                I: IntoIterator<Item = Pixel<Self::Color>>,
+            {
+                Ok(())
+            }
+        }
+
+        impl ChartDrawTarget for SpanCounter {
+            fn fill_span(
+                &mut self,
+                _start: Point,
+                _width: u32,
+                _color: Rgb565,
+            ) -> Result<(), Self::Error> {
+                self.spans += 1;
+                Ok(())
+            }
+        }
+
+        let chart = LineChart::builder()
+            .fill_area(Rgb565::CSS_LIGHT_BLUE)
+            .build()
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(200, 100));
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 5.0)).unwrap();
+        data.push(Point2D::new(10.0, 10.0)).unwrap();
+
+        // The area fill reaches the driver's override
+        let mut target = SpanCounter { spans: 0 };
+        chart
+            .draw_accelerated(&data, chart.config(), viewport, &mut target)
+            .unwrap();
+        assert!(target.spans > 0);
+
+        // Plain draw goes through the DrawTarget defaults
+        let mut target = SpanCounter { spans: 0 };
+        chart
+            .draw(&data, chart.config(), viewport, &mut target)
+            .unwrap();
+        assert_eq!(target.spans, 0);
+    }
+
+    #[test]
+    fn test_fill_to_baseline_with_below_color() {
+        let chart = LineChart::builder()
+            .line_color(Rgb565::BLUE)
+            .fill_area(Rgb565::GREEN)
+            .fill_baseline(0.0)
+            .fill_below_color(Rgb565::RED)
+            .build()
+            .unwrap();
+        assert_eq!(chart.fill_baseline(), Some(0.0));
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, -5.0)).unwrap();
+        data.push(Point2D::new(1.0, 5.0)).unwrap();
+        data.push(Point2D::new(2.0, -5.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The baseline sits at the middle of the 44px high chart area
+        let baseline_y = 32;
+        let rows_with = |color: Rgb565| {
+            let display = &display;
+            (0..64).filter(move |&y| {
+                (0..64).any(|x| display.get_pixel(Point::new(x, y)) == Some(color))
+            })
+        };
+        assert!(rows_with(Rgb565::GREEN).all(|y| y <= baseline_y));
+        assert!(rows_with(Rgb565::RED).all(|y| y >= baseline_y));
+        assert!(rows_with(Rgb565::GREEN).any(|y| y < baseline_y - 5));
+        assert!(rows_with(Rgb565::RED).any(|y| y > baseline_y + 5));
+    }
+
+    #[test]
+    fn test_fill_gradient_follows_y_value() {
+        let gradient = ValueGradient::simple(Rgb565::BLUE, Rgb565::RED, 0.0, 10.0).unwrap();
+        let chart = LineChart::builder()
+            .line_color(Rgb565::GREEN)
+            .fill_gradient(gradient)
+            .build()
+            .unwrap();
+        assert!(chart.fill_gradient().is_some());
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 10.0)).unwrap();
+        data.push(Point2D::new(1.0, 10.0)).unwrap();
+        data.push(Point2D::new(2.0, 0.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // High values sit above low ones, whatever column they are in
+        let rows_with = |color: Rgb565| {
+            let display = &display;
+            (0..64).filter(move |&y| {
+                (0..64).any(|x| display.get_pixel(Point::new(x, y)) == Some(color))
+            })
+        };
+        let lowest_red = rows_with(Rgb565::RED).max().unwrap();
+        let highest_blue = rows_with(Rgb565::BLUE).min().unwrap();
+        assert!(lowest_red < highest_blue);
+    }
+
+    #[test]
+    fn test_invalidate_with_fixed_axes() {
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for x in 0..=10 {
+            data.push(Point2D::new(x as f32, 5.0)).unwrap();
+        }
+        let viewport = Rectangle::new(Point::zero(), Size::new(120, 60));
+
+        // Autoscaled charts may rescale on any change
+        let chart: LineChart<Rgb565> = LineChart::builder().build().unwrap();
+        assert_eq!(
+            chart.invalidate(&data, DataChange::Updated(5), viewport),
+            viewport
+        );
+
+        let chart: LineChart<Rgb565> = LineChart::builder()
+            .with_x_axis(LinearAxis::new(
+                0.0,
+                10.0,
+                AxisOrientation::Horizontal,
+                AxisPosition::Bottom,
+            ))
+            .with_y_axis(LinearAxis::new(
+                0.0,
+                10.0,
+                AxisOrientation::Vertical,
+                AxisPosition::Left,
+            ))
+            .build()
+            .unwrap();
+        let chart_area = chart.config().margins.apply_to(viewport);
+        let column = |index: usize| {
+            let bounds = calculate_bounds(data.as_slice().iter().copied()).unwrap();
+            chart
+                .transform_point(&data.as_slice()[index], &bounds, viewport)
+                .x
+        };
+
+        // An updated point redraws the segments to its neighbours
+        let area = chart.invalidate(&data, DataChange::Updated(5), viewport);
+        assert!(area.size.width < viewport.size.width / 2);
+        assert!(area.contains(Point::new(column(4), chart_area.top_left.y)));
+        assert!(area.contains(Point::new(column(6), chart_area.top_left.y)));
+        assert!(!area.contains(Point::new(column(3), chart_area.top_left.y)));
+
+        // Appended points redraw from the previous last point onwards
+        let area = chart.invalidate(&data, DataChange::Appended(2), viewport);
+        assert!(area.contains(Point::new(column(8), chart_area.top_left.y)));
+        assert!(!area.contains(Point::new(column(7), chart_area.top_left.y)));
+        assert_eq!(
+            chart.invalidate(&data, DataChange::Replaced, viewport),
+            viewport
+        );
+    }
+
+    #[test]
+    fn test_dashed_line_has_gaps() {
+        let chart = LineChart::builder()
+            .line_color(Rgb565::GREEN)
+            .line_pattern(LinePattern::Dashed)
+            .build()
+            .unwrap();
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for x in 0..4 {
+            data.push(Point2D::new(x as f32, 5.0)).unwrap();
+        }
+        data.push(Point2D::new(4.0, 6.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The flat part of the line alternates between dashes and gaps
+        let row = (0..64)
+            .find(|&y| {
+                (0..64)
+                    .filter(|&x| display.get_pixel(Point::new(x, y)).is_some())
+                    .count()
+                    > 8
+            })
+            .unwrap();
+        let painted: heapless::Vec<bool, 64> = (0..64)
+            .map(|x| display.get_pixel(Point::new(x, row)).is_some())
+            .collect();
+        let first = painted.iter().position(|&on| on).unwrap();
+        assert!(painted[first..first + 8].iter().all(|&on| on));
+        assert!(painted[first + 8..first + 12].iter().all(|&on| !on));
+        assert!(painted[first + 12]);
+    }
+
+    #[test]
+    fn test_monochrome_uses_ink_and_patterns() {
+        use crate::style::{FillPattern, MonochromeStyle};
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        let chart: LineChart<BinaryColor> = LineChart::builder()
+            .line_color(BinaryColor::Off)
+            .fill_area(BinaryColor::On)
+            .with_y_axis(LinearAxis::new(
+                0.0,
+                10.0,
+                AxisOrientation::Vertical,
+                AxisPosition::Left,
+            ))
+            .monochrome(MonochromeStyle::BINARY)
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.style().line_color, BinaryColor::On);
+        assert_eq!(chart.config().background_color, Some(BinaryColor::Off));
+        assert!(matches!(
+            chart.fill_style().map(|style| &style.pattern),
+            Some(FillPattern::Pattern(_))
+        ));
+        let y_style = chart.y_axis.as_ref().unwrap().style();
+        assert_eq!(
+            y_style.grid_lines.as_ref().map(|line| line.pattern),
+            Some(LinePattern::Dotted)
+        );
+
+        // Series of a multi-series chart differ by pattern instead of color
+        assert_eq!(chart.series_pattern(0), LinePattern::Solid);
+        assert_eq!(chart.series_pattern(1), LinePattern::Dashed);
+        let plain: LineChart<BinaryColor> = LineChart::builder().build().unwrap();
+        assert_eq!(plain.series_pattern(1), LinePattern::Solid);
+    }
+
+    #[test]
+    fn test_fill_fade_stays_under_line() {
+        let chart = LineChart::builder()
+            .line_color(Rgb565::GREEN)
+            .fill_fade(Rgb565::BLUE)
+            .build()
+            .unwrap();
+        assert!(chart.fill_style().is_some());
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 10.0)).unwrap();
+        data.push(Point2D::new(1.0, 10.0)).unwrap();
+        data.push(Point2D::new(2.0, 0.0)).unwrap();
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The fade runs from the line color at the top to the end color below
+        let display = &display;
+        let rows_with = |color: Rgb565| {
+            (0..64).filter(move |&y| {
+                (0..64).any(|x| display.get_pixel(Point::new(x, y)) == Some(color))
+            })
+        };
+        let highest_green = rows_with(Rgb565::GREEN).min().unwrap();
+        let highest_blue = rows_with(Rgb565::BLUE).min().unwrap();
+        assert!(highest_green < highest_blue);
+
+        // Nothing is filled above the falling segment
+        let painted = |x: i32| (0..64).any(|y| display.get_pixel(Point::new(x, y)).is_some());
+        let first = (0..64).find(|&x| painted(x)).unwrap();
+        let last = (0..64).rev().find(|&x| painted(x)).unwrap();
+        let top_of = |x: i32| {
+            (0..64)
+                .find(|&y| display.get_pixel(Point::new(x, y)).is_some())
+                .unwrap()
+        };
+        assert!(top_of(last - 2) > top_of(first + 2) + 20);
+    }
+
     #[test]
     fn test_draw_smooth_curve() {
         let chart = LineChart::builder()
@@ -1395,6 +3407,37 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_custom_capacity_limits_smoothing() {
+        let chart: LineChart<Rgb565, 16> = LineChart::builder()
+            .capacity::<16>()
+            .smooth(true)
+            .smooth_subdivisions(8)
+            .build()
+            .unwrap();
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        // 6 points with 8 subdivisions would need 41 curve points; 3 fit in 16
+        let mut data: StaticDataSeries<Point2D, 16> = StaticDataSeries::new();
+        for (x, y) in [
+            (0.0, 0.0),
+            (1.0, 5.0),
+            (2.0, 2.0),
+            (3.0, 8.0),
+            (4.0, 4.0),
+            (5.0, 6.0),
+        ] {
+            data.push(Point2D::new(x, y)).unwrap();
+        }
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert!(display.affected_area().size.width > 0);
+    }
+
     #[test]
     fn test_draw_with_axes() {
         let mut chart: LineChart<Rgb565> = LineChart::new();
@@ -1549,9 +3592,80 @@ mod tests {
         assert_eq!(screen_point.x, 99); // Center X with margins
         assert_eq!(screen_point.y, 50); // Center Y with margins
     }
+
+    #[test]
+    fn test_x_window_overrides_data_bounds() {
+        let mut chart: LineChart<Rgb565> = LineChart::new();
+        chart.set_x_window(Some((10.0, 20.0)));
+        assert_eq!(chart.x_window(), Some((10.0, 20.0)));
+
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(200, 100));
+        let bounds = DataBounds::<f32, f32> {
+            min_x: 0.0,
+            max_x: 20.0,
+            min_y: 0.0,
+            max_y: 10.0,
+        };
+        let left = chart.transform_point(&Point2D::new(10.0, 0.0), &bounds, viewport);
+        let right = chart.transform_point(&Point2D::new(20.0, 0.0), &bounds, viewport);
+        assert_eq!(left.x, 10);
+        assert_eq!(right.x, 189);
+    }
+
+    #[test]
+    fn test_x_window_clips_to_chart_area() {
+        let mut chart: LineChart<Rgb565> = LineChart::new();
+        chart.set_x_window(Some((10.0, 20.0)));
+        let config = ChartConfig::default();
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        // The first point lies far left of the window and must not be drawn off-screen
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 5.0)).unwrap();
+        data.push(Point2D::new(15.0, 8.0)).unwrap();
+        data.push(Point2D::new(20.0, 2.0)).unwrap();
+
+        assert!(chart.draw(&data, &config, viewport, &mut display).is_ok());
+        assert!(display.affected_area().top_left.x >= 10);
+    }
+
+    #[cfg(feature = "animations")]
+    #[test]
+    fn test_draw_points_from_sliding_window() {
+        use crate::data::SlidingWindowSeries;
+
+        let chart: LineChart<Rgb565> = LineChart::new();
+        let config = ChartConfig::default();
+        let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+
+        // The window has wrapped, so its oldest points were overwritten
+        let mut window: SlidingWindowSeries<Point2D, 4> = SlidingWindowSeries::new();
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for i in 0..6 {
+            let point = Point2D::new(i as f32, (i * i % 5) as f32);
+            window.push(point);
+            if i >= 2 {
+                data.push(point).unwrap();
+            }
+        }
+
+        let mut expected: MockDisplay<Rgb565> = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        chart.draw(&data, &config, viewport, &mut expected).unwrap();
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw_points(window.iter_chronological(), &config, viewport, &mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
 }
 
-impl<C: PixelColor + 'static> AxisChart<C> for LineChart<C>
+impl<C: PixelColor + 'static, const N: usize> AxisChart<C> for LineChart<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
@@ -1573,6 +3687,16 @@ where
     fn y_axis(&self) -> ChartResult<&crate::axes::LinearAxis<f32, C>> {
         self.y_axis.as_ref().ok_or(ChartError::InvalidConfiguration)
     }
+
+    fn set_secondary_y_axis(&mut self, axis: crate::axes::LinearAxis<f32, C>) {
+        self.secondary_y_axis = Some(axis);
+    }
+
+    fn secondary_y_axis(&self) -> ChartResult<&crate::axes::LinearAxis<f32, C>> {
+        self.secondary_y_axis
+            .as_ref()
+            .ok_or(ChartError::InvalidConfiguration)
+    }
 }
 
 /// Animated line chart that extends LineChart with animation capabilities
@@ -1658,6 +3782,37 @@ where
         &mut self.base_chart
     }
 
+    /// Draw a sliding window that scrolls smoothly between data arrivals
+    ///
+    /// The visible X range is taken from the scroll interpolator, so the trace
+    /// moves continuously even when new points arrive far less often than frames.
+    ///
+    /// # Arguments
+    /// * `window` - Sliding window holding the most recent samples
+    /// * `scroll` - Scroll interpolator updated with the same pushes
+    /// * `config` - Chart configuration
+    /// * `viewport` - Area to draw in
+    /// * `target` - Display target
+    pub fn draw_scrolling<D, const N: usize>(
+        &mut self,
+        window: &crate::data::series::SlidingWindowSeries<crate::data::point::Point2D, N>,
+        scroll: &crate::animation::ScrollInterpolator,
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut data = crate::data::series::StaticDataSeries::new();
+        for point in window.iter_chronological() {
+            data.push(point).map_err(|_| ChartError::MemoryFull)?;
+        }
+
+        self.base_chart.set_x_window(scroll.x_range());
+        self.base_chart.draw(&data, config, viewport, target)
+    }
+
     /// Interpolate between two data series using a ChartAnimator
     pub fn interpolate_with_animator(
         animator: &crate::animation::ChartAnimator<
@@ -1789,12 +3944,54 @@ where
         self
     }
 
+    /// Set the dash pattern of the line
+    pub fn line_pattern(mut self, pattern: LinePattern) -> Self {
+        self.base_builder = self.base_builder.line_pattern(pattern);
+        self
+    }
+
     /// Enable area fill with color
     pub fn fill_area(mut self, color: C) -> Self {
         self.base_builder = self.base_builder.fill_area(color);
         self
     }
 
+    /// Fill to `value` instead of the bottom of the chart area
+    pub fn fill_baseline(mut self, value: f32) -> Self {
+        self.base_builder = self.base_builder.fill_baseline(value);
+        self
+    }
+
+    /// Use `color` for the fill where the line is below the fill baseline
+    pub fn fill_below_color(mut self, color: C) -> Self {
+        self.base_builder = self.base_builder.fill_below_color(color);
+        self
+    }
+
+    /// Fill the area with a gradient mapped to the Y value
+    pub fn fill_gradient(mut self, gradient: ValueGradient<C>) -> Self {
+        self.base_builder = self.base_builder.fill_gradient(gradient);
+        self
+    }
+
+    /// Fill the area with a fill style
+    pub fn fill_style(mut self, style: FillStyle<C>) -> Self {
+        self.base_builder = self.base_builder.fill_style(style);
+        self
+    }
+
+    /// Fill the area with a vertical gradient from the line color to `to`
+    pub fn fill_fade(mut self, to: C) -> Self {
+        self.base_builder = self.base_builder.fill_fade(to);
+        self
+    }
+
+    /// Draw for a monochrome or grayscale display
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.base_builder = self.base_builder.monochrome(style);
+        self
+    }
+
     /// Add markers to data points
     pub fn with_markers(mut self, marker_style: MarkerStyle<C>) -> Self {
         self.base_builder = self.base_builder.with_markers(marker_style);
@@ -1813,6 +4010,12 @@ where
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.base_builder = self.base_builder.background_mode(mode);
+        self
+    }
+
     /// Set chart margins
     pub fn margins(mut self, margins: Margins) -> Self {
         self.base_builder = self.base_builder.margins(margins);
@@ -1831,6 +4034,24 @@ where
         self
     }
 
+    /// Set how the ends of smooth curves are shaped
+    pub fn smooth_endpoints(mut self, endpoints: EndpointMode) -> Self {
+        self.base_builder = self.base_builder.smooth_endpoints(endpoints);
+        self
+    }
+
+    /// Simplify the stroked line, dropping vertices within `epsilon` pixels
+    pub fn simplify(mut self, epsilon: f32) -> Self {
+        self.base_builder = self.base_builder.simplify(epsilon);
+        self
+    }
+
+    /// Decimate series with more points than the plot is wide while drawing
+    pub fn decimate(mut self, decimation: Decimation) -> Self {
+        self.base_builder = self.base_builder.decimate(decimation);
+        self
+    }
+
     /// Add grid system
     pub fn with_grid(mut self, grid: crate::grid::GridSystem<C>) -> Self {
         self.base_builder = self.base_builder.with_grid(grid);
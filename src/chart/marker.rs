@@ -0,0 +1,330 @@
+//! Marker shapes shared by line and scatter charts.
+//!
+//! [`MarkerRenderer`] draws every [`MarkerShape`], so line chart markers and
+//! scatter chart points look the same for the same shape and size. Shapes that
+//! the built-in set does not cover can be supplied as a [`CustomMarker`]: a
+//! function that draws the marker onto a [`MarkerCanvas`] with any
+//! embedded-graphics primitive. The canvas is monochrome; every pixel set to
+//! [`BinaryColor::On`] is drawn in the marker color.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::{BinaryColor, Rgb565};
+//! use embedded_graphics::primitives::{Line, PrimitiveStyle};
+//!
+//! // A horizontal bar marker
+//! fn dash(center: Point, size: u32, canvas: &mut MarkerCanvas<'_>) -> MarkerResult {
+//!     let half = size as i32 / 2;
+//!     Line::new(center - Point::new(half, 0), center + Point::new(half, 0))
+//!         .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2))
+//!         .draw(canvas)
+//! }
+//!
+//! let chart = LineChart::builder()
+//!     .with_markers(MarkerStyle {
+//!         shape: MarkerShape::Custom(CustomMarker(dash)),
+//!         size: 8,
+//!         color: Rgb565::RED,
+//!         visible: true,
+//!     })
+//!     .build()?;
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::error::{ChartError, ChartResult};
+use crate::math::{Math, NumericConversion};
+use crate::render::PrimitiveRenderer;
+use crate::style::FillStyle;
+use core::convert::Infallible;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+};
+
+/// Result returned by custom marker functions.
+pub type MarkerResult = Result<(), Infallible>;
+
+/// Function drawing a custom marker of `size` pixels around `center`.
+pub type MarkerFn = fn(center: Point, size: u32, canvas: &mut MarkerCanvas<'_>) -> MarkerResult;
+
+/// Available shapes for data point markers.
+///
+/// Each shape provides different visual characteristics:
+/// - `Circle`: Smooth, traditional marker shape
+/// - `Square`: Sharp, geometric appearance
+/// - `Diamond`: Distinctive diamond shape
+/// - `Triangle`: Directional appearance, good for indicating trends
+/// - `Plus` and `Cross`: Open shapes that keep overlapping points readable
+/// - `Star`: Five-pointed star for highlighting points
+/// - `Custom`: User-defined drawing function
+///
+/// # Performance Notes
+///
+/// - `Circle` and `Square` use embedded-graphics primitives (fastest)
+/// - `Plus` and `Cross` draw two lines
+/// - `Diamond`, `Triangle` and `Star` use custom rendering (slightly slower)
+/// - `Custom` draws pixel by pixel (slowest)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerShape {
+    /// Circular marker - smooth and traditional appearance.
+    Circle,
+    /// Square marker - sharp, geometric appearance.
+    Square,
+    /// Diamond marker - distinctive diamond shape.
+    Diamond,
+    /// Triangle marker - directional appearance.
+    Triangle,
+    /// Plus-shaped marker (+).
+    Plus,
+    /// Diagonal cross marker (X).
+    Cross,
+    /// Five-pointed star marker.
+    Star,
+    /// Marker drawn by a user-supplied function.
+    Custom(CustomMarker),
+}
+
+/// User-defined marker drawing function.
+#[derive(Clone, Copy)]
+pub struct CustomMarker(pub MarkerFn);
+
+impl core::fmt::Debug for CustomMarker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CustomMarker")
+    }
+}
+
+impl PartialEq for CustomMarker {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+impl Eq for CustomMarker {}
+
+/// Monochrome draw target handed to custom marker functions.
+///
+/// Pixels drawn with [`BinaryColor::On`] are forwarded to the chart's target
+/// in the marker color; [`BinaryColor::Off`] pixels are ignored.
+pub struct MarkerCanvas<'a> {
+    sink: &'a mut dyn FnMut(Point),
+    bounds: Rectangle,
+}
+
+impl Dimensions for MarkerCanvas<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl DrawTarget for MarkerCanvas<'_> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if color.is_on() {
+                (self.sink)(point);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renderer for data point markers
+pub struct MarkerRenderer;
+
+impl MarkerRenderer {
+    /// Draw a marker of `size` pixels centered on `center`
+    pub fn draw<C, D>(
+        shape: MarkerShape,
+        center: Point,
+        size: u32,
+        color: C,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let radius = size / 2;
+        let half_size = radius as i32;
+        let top_left = Point::new(center.x - half_size, center.y - half_size);
+
+        match shape {
+            MarkerShape::Circle => Circle::new(top_left, size)
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError),
+            MarkerShape::Square => Rectangle::new(top_left, Size::new(size, size))
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError),
+            MarkerShape::Diamond => {
+                let fill_style = FillStyle::solid(color);
+                PrimitiveRenderer::draw_diamond(center, size, None, Some(&fill_style), target)
+                    .map_err(|_| ChartError::RenderingError)
+            }
+            MarkerShape::Triangle => {
+                let fill_style = FillStyle::solid(color);
+                let p1 = Point::new(center.x, center.y - half_size);
+                let p2 = Point::new(center.x - half_size, center.y + half_size);
+                let p3 = Point::new(center.x + half_size, center.y + half_size);
+
+                PrimitiveRenderer::draw_triangle(p1, p2, p3, None, Some(&fill_style), target)
+                    .map_err(|_| ChartError::RenderingError)
+            }
+            MarkerShape::Plus => Self::draw_strokes(
+                [
+                    (Point::new(0, -half_size), Point::new(0, half_size)),
+                    (Point::new(-half_size, 0), Point::new(half_size, 0)),
+                ],
+                center,
+                size,
+                color,
+                target,
+            ),
+            MarkerShape::Cross => Self::draw_strokes(
+                [
+                    (
+                        Point::new(-half_size, -half_size),
+                        Point::new(half_size, half_size),
+                    ),
+                    (
+                        Point::new(-half_size, half_size),
+                        Point::new(half_size, -half_size),
+                    ),
+                ],
+                center,
+                size,
+                color,
+                target,
+            ),
+            MarkerShape::Star => Self::draw_star(center, radius, color, target),
+            MarkerShape::Custom(CustomMarker(draw_marker)) => {
+                let bounds = target.bounding_box();
+                let mut result = Ok(());
+                let mut sink = |point: Point| {
+                    if result.is_ok() {
+                        result = target.draw_iter(core::iter::once(Pixel(point, color)));
+                    }
+                };
+
+                let mut canvas = MarkerCanvas {
+                    sink: &mut sink,
+                    bounds,
+                };
+                let _ = draw_marker(center, size, &mut canvas);
+
+                result.map_err(|_| ChartError::RenderingError)
+            }
+        }
+    }
+
+    /// Draw open marker shapes as two strokes relative to the center
+    fn draw_strokes<C, D>(
+        strokes: [(Point, Point); 2],
+        center: Point,
+        size: u32,
+        color: C,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let width = if size >= 6 { 2 } else { 1 };
+        let style = PrimitiveStyle::with_stroke(color, width);
+
+        for (start, end) in strokes {
+            Line::new(center + start, center + end)
+                .into_styled(style)
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a filled five-pointed star as a fan of triangles
+    fn draw_star<C, D>(center: Point, radius: u32, color: C, target: &mut D) -> ChartResult<()>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let outer = radius as f32;
+        let inner = outer * 0.4;
+        let fill_style = FillStyle::solid(color);
+
+        // Alternate outer and inner vertices, starting at the top point
+        let mut vertices = [Point::zero(); 10];
+        for (index, vertex) in vertices.iter_mut().enumerate() {
+            let angle = (-90.0 + index as f32 * 36.0).to_radians().to_number();
+            let distance = (if index % 2 == 0 { outer } else { inner }).to_number();
+            *vertex = Point::new(
+                center.x + f32::from_number(distance * Math::cos(angle)) as i32,
+                center.y + f32::from_number(distance * Math::sin(angle)) as i32,
+            );
+        }
+
+        let next_vertices = vertices.iter().cycle().skip(1);
+        for (&vertex, &next) in vertices.iter().zip(next_vertices) {
+            PrimitiveRenderer::draw_triangle(center, vertex, next, None, Some(&fill_style), target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    fn dot(center: Point, _size: u32, canvas: &mut MarkerCanvas<'_>) -> MarkerResult {
+        Pixel(center, BinaryColor::On).draw(canvas)?;
+        Pixel(center + Point::new(1, 0), BinaryColor::Off).draw(canvas)
+    }
+
+    #[test]
+    fn test_custom_marker() {
+        let mut display = MockDisplay::<Rgb565>::new();
+        let shape = MarkerShape::Custom(CustomMarker(dot));
+        MarkerRenderer::draw(shape, Point::new(5, 5), 4, Rgb565::RED, &mut display).unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(5, 5)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(6, 5)), None);
+        assert_eq!(shape, MarkerShape::Custom(CustomMarker(dot)));
+    }
+
+    #[test]
+    fn test_open_and_star_markers() {
+        for shape in [MarkerShape::Plus, MarkerShape::Cross, MarkerShape::Star] {
+            let mut display = MockDisplay::<Rgb565>::new();
+            display.set_allow_overdraw(true);
+            MarkerRenderer::draw(shape, Point::new(10, 10), 10, Rgb565::BLUE, &mut display)
+                .unwrap();
+
+            let area = display.affected_area();
+            assert!(area.size.width >= 7, "{shape:?} too narrow");
+            assert!(area.size.width <= 12, "{shape:?} too wide");
+        }
+    }
+}
@@ -150,20 +150,46 @@
 //! # }
 //! ```
 
+#[cfg(feature = "line")]
+pub mod annotations;
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
+pub mod any;
 #[cfg(feature = "bar")]
 pub mod bar;
 #[cfg(feature = "line")]
+pub mod events;
+#[cfg(feature = "line")]
+pub mod forecast;
+#[cfg(feature = "line")]
 pub mod line;
+#[cfg(any(feature = "line", feature = "scatter"))]
+pub mod marker;
 #[cfg(feature = "pie")]
 pub mod pie;
+pub mod static_data;
+#[cfg(feature = "line")]
+pub mod stats_overlay;
 pub mod traits;
+#[cfg(feature = "line")]
+pub mod transform_overlay;
 
+#[cfg(feature = "scatter")]
+pub mod regression;
 #[cfg(feature = "scatter")]
 pub mod scatter;
 
 #[cfg(feature = "gauge")]
 pub mod gauge;
 
+#[cfg(feature = "gauge")]
+pub mod gauge_cluster;
+
 #[cfg(feature = "stacked-charts")]
 pub mod stacked;
 
@@ -173,20 +199,55 @@ pub mod custom;
 #[cfg(feature = "line")]
 pub mod curve;
 
+#[cfg(feature = "bar")]
+pub mod pareto;
+
+#[cfg(feature = "bar")]
+pub mod divergent;
+
+#[cfg(feature = "line")]
+pub mod control;
+
+#[cfg(feature = "line")]
+pub use annotations::*;
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
+pub use any::*;
 #[cfg(feature = "bar")]
 pub use bar::*;
 #[cfg(feature = "line")]
+pub use events::*;
+#[cfg(feature = "line")]
+pub use forecast::*;
+#[cfg(feature = "line")]
 pub use line::*;
+#[cfg(any(feature = "line", feature = "scatter"))]
+pub use marker::*;
 #[cfg(feature = "pie")]
 pub use pie::*;
+pub use static_data::*;
+#[cfg(feature = "line")]
+pub use stats_overlay::*;
 pub use traits::*;
+#[cfg(feature = "line")]
+pub use transform_overlay::*;
 
+#[cfg(feature = "scatter")]
+pub use regression::*;
 #[cfg(feature = "scatter")]
 pub use scatter::*;
 
 #[cfg(feature = "gauge")]
 pub use gauge::*;
 
+#[cfg(feature = "gauge")]
+pub use gauge_cluster::*;
+
 #[cfg(feature = "stacked-charts")]
 pub use stacked::*;
 
@@ -195,3 +256,12 @@ pub use custom::*;
 
 #[cfg(feature = "line")]
 pub use curve::*;
+
+#[cfg(feature = "bar")]
+pub use pareto::*;
+
+#[cfg(feature = "bar")]
+pub use divergent::*;
+
+#[cfg(feature = "line")]
+pub use control::*;
@@ -0,0 +1,377 @@
+//! Pareto chart implementation.
+//!
+//! A Pareto chart sorts categories by value in descending order, draws them as
+//! bars and overlays a line with the cumulative percentage of the total. A
+//! percentage axis on the right-hand side reads off the cumulative line, which
+//! makes it easy to see which few categories account for most of the total.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! // Defect counts per category; x identifies the category
+//! let data = data_points![(0.0, 12.0), (1.0, 45.0), (2.0, 7.0), (3.0, 28.0)];
+//!
+//! let chart = ParetoChart::builder()
+//!     .bar_color(Rgb565::BLUE)
+//!     .line_color(Rgb565::RED)
+//!     .build()?;
+//!
+//! // Categories in drawing order: 1, 3, 0, 2
+//! let sorted = chart.sorted(&data)?;
+//! assert_eq!(sorted.get(0).map(|p| p.x()), Some(1.0));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::axes::{AxisOrientation, AxisPosition, CategoryScale, LinearAxis};
+use crate::chart::bar::{BarChart, BarChartBuilder, BarWidth};
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig};
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::data::{DataPoint, DataSeries};
+use crate::error::{ChartError, ChartResult};
+use crate::style::LineStyle;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+};
+use heapless::Vec;
+
+/// Pareto chart: descending bars with a cumulative percentage line.
+#[derive(Debug, Clone)]
+pub struct ParetoChart<C: PixelColor> {
+    /// Chart drawing the sorted bars
+    bars: BarChart<C>,
+    /// Style of the cumulative percentage line
+    line_style: LineStyle<C>,
+    /// Right-hand axis for the cumulative percentage
+    percentage_axis: Option<LinearAxis<f32, C>>,
+    config: ChartConfig<C>,
+}
+
+impl<C: PixelColor> ParetoChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Create a new Pareto chart with default styling
+    pub fn new() -> Self {
+        Self::builder()
+            .build()
+            .expect("default Pareto chart configuration is valid")
+    }
+
+    /// Create a builder for configuring the Pareto chart
+    pub fn builder() -> ParetoChartBuilder<C> {
+        ParetoChartBuilder::new()
+    }
+
+    /// Get the chart configuration
+    pub fn config(&self) -> &ChartConfig<C> {
+        &self.config
+    }
+
+    /// Get the style of the cumulative percentage line
+    pub fn line_style(&self) -> &LineStyle<C> {
+        &self.line_style
+    }
+
+    /// Get the right-hand percentage axis, if shown
+    pub fn percentage_axis(&self) -> Option<&LinearAxis<f32, C>> {
+        self.percentage_axis.as_ref()
+    }
+
+    /// Sort the data by value in descending order
+    ///
+    /// The x values are kept so each bar can be mapped back to its category.
+    pub fn sorted(
+        &self,
+        data: &StaticDataSeries<Point2D, 256>,
+    ) -> ChartResult<StaticDataSeries<Point2D, 256>> {
+        let mut points: Vec<Point2D, 256> = data.iter().collect();
+        points.sort_unstable_by(|a, b| {
+            b.y()
+                .partial_cmp(&a.y())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let mut sorted = StaticDataSeries::new();
+        for point in points {
+            sorted.push(point)?;
+        }
+        Ok(sorted)
+    }
+
+    /// Calculate the cumulative percentage of the total after each value
+    ///
+    /// Values are taken in the order given, so pass the output of
+    /// [`ParetoChart::sorted`] to get the percentages shown by the chart.
+    /// Negative values are treated as zero.
+    pub fn cumulative_percentages(
+        &self,
+        data: &StaticDataSeries<Point2D, 256>,
+    ) -> ChartResult<Vec<f32, 256>> {
+        let total: f32 = data.iter().map(|point| point.y().max(0.0)).sum();
+
+        let mut percentages = Vec::new();
+        let mut running = 0.0;
+        for point in data.iter() {
+            running += point.y().max(0.0);
+            let percentage = if total > 0.0 {
+                running / total * 100.0
+            } else {
+                0.0
+            };
+            percentages
+                .push(percentage)
+                .map_err(|_| ChartError::MemoryFull)?;
+        }
+        Ok(percentages)
+    }
+
+    /// Draw the cumulative percentage line over the bars
+    fn draw_cumulative_line<D>(
+        &self,
+        percentages: &[f32],
+        chart_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let categories = CategoryScale::new(percentages.len());
+        let bottom = chart_area.top_left.y + chart_area.size.height as i32;
+        let height = chart_area.size.height as f32;
+
+        let mut points: Vec<Point, 256> = Vec::new();
+        for (index, percentage) in percentages.iter().enumerate() {
+            let x = categories.center(index, chart_area, AxisOrientation::Horizontal);
+            let y = bottom - (percentage / 100.0 * height) as i32;
+            points
+                .push(Point::new(x, y))
+                .map_err(|_| ChartError::MemoryFull)?;
+        }
+
+        let style = PrimitiveStyle::with_stroke(self.line_style.color, self.line_style.width);
+        for segment in points.windows(2) {
+            Line::new(segment[0], segment[1])
+                .into_styled(style)
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> Default for ParetoChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: PixelColor> Chart<C> for ParetoChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Data = StaticDataSeries<Point2D, 256>;
+    type Config = ChartConfig<C>;
+
+    fn draw<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+        Self::Data: DataSeries,
+        <Self::Data as DataSeries>::Item: DataPoint,
+        <<Self::Data as DataSeries>::Item as DataPoint>::X: Into<f32> + Copy + PartialOrd,
+        <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
+    {
+        if data.is_empty() {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
+        }
+
+        let sorted = self.sorted(data)?;
+        let percentages = self.cumulative_percentages(&sorted)?;
+        let chart_area = config.margins.apply_to(viewport);
+
+        self.bars.draw(&sorted, config, viewport, target)?;
+        self.draw_cumulative_line(&percentages, chart_area, target)?;
+
+        if let Some(ref axis) = self.percentage_axis {
+            axis.draw_axis_only(chart_area, target)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for ParetoChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
+/// Builder for Pareto charts
+#[derive(Debug)]
+pub struct ParetoChartBuilder<C: PixelColor> {
+    bars: BarChartBuilder<C>,
+    line_style: LineStyle<C>,
+    show_percentage_axis: bool,
+    config: ChartConfig<C>,
+}
+
+impl<C: PixelColor> ParetoChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Create a new Pareto chart builder
+    pub fn new() -> Self {
+        Self {
+            bars: BarChart::builder().category_aligned(true).baseline(0.0),
+            line_style: LineStyle::solid(embedded_graphics::pixelcolor::Rgb565::RED.into())
+                .width(2),
+            show_percentage_axis: true,
+            config: ChartConfig::default(),
+        }
+    }
+
+    /// Set the bar color
+    pub fn bar_color(mut self, color: C) -> Self {
+        self.bars = self.bars.colors(&[color]);
+        self
+    }
+
+    /// Set the bar width
+    pub fn bar_width(mut self, width: BarWidth) -> Self {
+        self.bars = self.bars.bar_width(width);
+        self
+    }
+
+    /// Set the spacing between bars
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.bars = self.bars.spacing(spacing);
+        self
+    }
+
+    /// Set the cumulative line color
+    pub fn line_color(mut self, color: C) -> Self {
+        self.line_style = self.line_style.color(color);
+        self
+    }
+
+    /// Set the cumulative line width
+    pub fn line_width(mut self, width: u32) -> Self {
+        self.line_style = self.line_style.width(width);
+        self
+    }
+
+    /// Show or hide the right-hand percentage axis
+    pub fn show_percentage_axis(mut self, show: bool) -> Self {
+        self.show_percentage_axis = show;
+        self
+    }
+
+    /// Set the chart title
+    pub fn with_title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = heapless::String::try_from(title) {
+            self.config.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Set the background color
+    pub fn background_color(mut self, color: C) -> Self {
+        self.config.background_color = Some(color);
+        self
+    }
+
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+}
+
+impl<C: PixelColor> ChartBuilder<C> for ParetoChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    type Chart = ParetoChart<C>;
+    type Error = ChartError;
+
+    fn build(self) -> Result<Self::Chart, Self::Error> {
+        let mut bars = self.bars.build()?;
+        bars.set_config(self.config.clone());
+
+        let percentage_axis = self
+            .show_percentage_axis
+            .then(|| LinearAxis::new(0.0, 100.0, AxisOrientation::Vertical, AxisPosition::Right));
+
+        Ok(ParetoChart {
+            bars,
+            line_style: self.line_style,
+            percentage_axis,
+            config: self.config,
+        })
+    }
+}
+
+impl<C: PixelColor> Default for ParetoChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_sorted_and_cumulative() {
+        let chart: ParetoChart<Rgb565> = ParetoChart::new();
+        let data = StaticDataSeries::from_tuples(&[(0.0, 10.0), (1.0, 50.0), (2.0, 40.0)]).unwrap();
+
+        let sorted = chart.sorted(&data).unwrap();
+        let order: Vec<f32, 3> = sorted.iter().map(|p| p.x()).collect();
+        assert_eq!(order.as_slice(), &[1.0, 2.0, 0.0]);
+
+        let percentages = chart.cumulative_percentages(&sorted).unwrap();
+        assert_eq!(percentages.as_slice(), &[50.0, 90.0, 100.0]);
+    }
+
+    #[test]
+    fn test_pareto_draws() {
+        let chart: ParetoChart<Rgb565> = ParetoChart::builder()
+            .show_percentage_axis(false)
+            .build()
+            .unwrap();
+        let data = StaticDataSeries::from_tuples(&[(0.0, 3.0), (1.0, 5.0)]).unwrap();
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        assert!(display.affected_area().size.width > 0);
+    }
+}
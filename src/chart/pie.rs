@@ -1,11 +1,14 @@
 //! Pie chart implementation.
 
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig};
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig, TitleStyle};
 use crate::data::{DataPoint, DataSeries};
 use crate::error::{ChartError, ChartResult};
 use crate::math::Math;
 use crate::math::NumericConversion;
-use crate::style::BorderStyle;
+use crate::render::{ChartDrawTarget, FallbackTarget, SpanRasterizer};
+use crate::style::{
+    BorderStyle, LabelFormat, MonochromeStyle, NumberLocale, PatternFill, ValueFormatter,
+};
 use embedded_graphics::{
     draw_target::DrawTarget,
     prelude::*,
@@ -20,6 +23,7 @@ pub struct PieChart<C: PixelColor> {
     config: ChartConfig<C>,
     center: Point,
     radius: u32,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 /// Style configuration for pie charts
@@ -33,6 +37,8 @@ pub struct PieChartStyle<C: PixelColor> {
     pub labels: LabelStyle,
     /// Starting angle in degrees (0 = right, 90 = top)
     pub start_angle: f32,
+    /// Angular span covered by all slices in degrees (360 = full pie, 180 = half pie)
+    pub sweep_angle: f32,
     /// Inner radius for donut charts (None = full pie)
     pub donut_inner_radius: Option<u32>,
 }
@@ -48,6 +54,10 @@ pub struct LabelStyle {
     pub show_values: bool,
     /// Distance from pie edge to label
     pub offset: u32,
+    /// Decimal separator and digit grouping for the values
+    pub locale: NumberLocale,
+    /// Formatter for slice values, e.g. with units; whole numbers if `None`
+    pub value_format: Option<LabelFormat>,
 }
 
 /// Represents a pie slice with its properties
@@ -63,6 +73,22 @@ pub struct PieSlice {
     pub percentage: f32,
 }
 
+impl PieSlice {
+    /// Angle in radians halfway through the slice
+    pub fn mid_angle(&self) -> f32 {
+        (self.start_angle + self.end_angle) / 2.0
+    }
+}
+
+/// How the pixels of a slice are colored
+#[derive(Debug, Clone, Copy)]
+enum SliceFill<C: PixelColor> {
+    /// One color for the whole slice
+    Solid(C),
+    /// Pattern anchored to the display, so adjacent slices stay aligned
+    Pattern(PatternFill<C>),
+}
+
 impl<C: PixelColor> PieChart<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -74,6 +100,7 @@ where
             config: ChartConfig::default(),
             center,
             radius,
+            monochrome: None,
         }
     }
 
@@ -122,6 +149,11 @@ where
         self.radius
     }
 
+    /// Get the monochrome style the chart was built for, if any
+    pub fn monochrome(&self) -> Option<&MonochromeStyle<C>> {
+        self.monochrome.as_ref()
+    }
+
     /// Calculate pie slices from data
     fn calculate_slices(
         &self,
@@ -142,6 +174,7 @@ where
 
         // Convert start angle to radians
         let start_angle_rad = self.style.start_angle.to_radians();
+        let sweep_rad = self.sweep_degrees().to_radians();
         let mut current_angle = start_angle_rad;
 
         // Create slices
@@ -152,7 +185,7 @@ where
             }
 
             let percentage = value / total;
-            let angle_span = percentage * 2.0 * core::f32::consts::PI;
+            let angle_span = percentage * sweep_rad;
             let end_angle = current_angle + angle_span;
 
             let slice = PieSlice {
@@ -169,11 +202,137 @@ where
         Ok(slices)
     }
 
+    /// Angular span of the pie in degrees, clamped to a full circle
+    fn sweep_degrees(&self) -> f32 {
+        self.style.sweep_angle.clamp(0.0, 360.0)
+    }
+
+    /// Offset from the center of the drawing area to the pie center
+    ///
+    /// Partial pies are shifted so the occupied part of the circle, rather than
+    /// the full circle, is centered.
+    fn span_offset(&self) -> Point {
+        let sweep = self.sweep_degrees();
+        if sweep >= 360.0 {
+            return Point::zero();
+        }
+
+        let start = self.style.start_angle;
+        let unit = |degrees: f32| {
+            let radians = degrees.to_radians().to_number();
+            (
+                f32::from_number(Math::cos(radians)),
+                f32::from_number(Math::sin(radians)),
+            )
+        };
+
+        // Bounding box of the sector on the unit circle, including the center
+        let (mut min_x, mut max_x, mut min_y, mut max_y) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+        let mut include = |(x, y): (f32, f32)| {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        };
+        include(unit(start));
+        include(unit(start + sweep));
+        // Axis extremes crossed by the sweep
+        let mut quadrant = f32::from_number(Math::floor((start / 90.0).to_number())) * 90.0 + 90.0;
+        while quadrant < start + sweep {
+            include(unit(quadrant));
+            quadrant += 90.0;
+        }
+
+        let radius = self.radius as f32;
+        let round = |value: f32| f32::from_number(Math::floor((value + 0.5).to_number())) as i32;
+        // Screen y grows downward, so the vertical offset is inverted
+        Point::new(
+            round(-(min_x + max_x) / 2.0 * radius),
+            round((min_y + max_y) / 2.0 * radius),
+        )
+    }
+
+    /// Get the anchor point for a slice label
+    ///
+    /// The label sits outside the slice at its middle angle, `labels.offset`
+    /// pixels beyond the edge.
+    pub fn label_position(&self, slice: &PieSlice) -> Point {
+        let distance = (self.radius + self.style.labels.offset) as f32;
+        let angle = slice.mid_angle().to_number();
+        let dx = f32::from_number(Math::cos(angle)) * distance;
+        let dy = f32::from_number(Math::sin(angle)) * distance;
+        self.center + Point::new(dx as i32, -dy as i32)
+    }
+
+    /// Draw the label of a slice
+    fn draw_label<D>(&self, slice: &PieSlice, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        use core::fmt::Write;
+        use embedded_graphics::{
+            mono_font::{ascii::FONT_6X10, MonoTextStyle},
+            text::{Alignment, Baseline, Text, TextStyleBuilder},
+        };
+
+        let labels = &self.style.labels;
+        let mut text: heapless::String<24> = heapless::String::new();
+        if labels.show_values {
+            let value: heapless::String<16> = match labels.value_format {
+                Some(format) => labels.locale.localize(&format.format(slice.value)),
+                None => labels.locale.format(slice.value, 0),
+            };
+            let _ = text.push_str(&value);
+        }
+        if labels.show_percentage {
+            if !text.is_empty() {
+                let _ = text.push(' ');
+            }
+            let _ = write!(text, "{:.0}%", slice.percentage);
+        }
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        // Align the label away from the pie so it doesn't overlap the slice
+        let position = self.label_position(slice);
+        let alignment = match position.x - self.center.x {
+            dx if dx > 2 => Alignment::Left,
+            dx if dx < -2 => Alignment::Right,
+            _ => Alignment::Center,
+        };
+        let text_style = TextStyleBuilder::new()
+            .alignment(alignment)
+            .baseline(Baseline::Middle)
+            .build();
+
+        let text_color = match self.monochrome {
+            Some(ref monochrome) => monochrome.ink,
+            None => embedded_graphics::pixelcolor::Rgb565::BLACK.into(),
+        };
+        Text::with_text_style(
+            &text,
+            position,
+            MonoTextStyle::new(&FONT_6X10, text_color),
+            text_style,
+        )
+        .draw(target)
+        .map_err(|_| ChartError::RenderingError)?;
+
+        Ok(())
+    }
+
     /// Draw a pie slice using a custom implementation to avoid pixel overlap
     fn draw_slice<D>(&self, slice: &PieSlice, color_index: usize, target: &mut D) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
+        // Monochrome slices are told apart by pattern rather than color
+        if let Some(ref monochrome) = self.monochrome {
+            let fill = monochrome.series_fill(color_index);
+            return self.draw_pie_slice_custom(slice, SliceFill::Pattern(fill), target);
+        }
+
         // Get slice color
         let slice_color = if !self.style.colors.is_empty() {
             self.style.colors[color_index % self.style.colors.len()]
@@ -182,7 +341,7 @@ where
         };
 
         // Custom pie slice drawing to avoid embedded-graphics Sector overlap issues
-        self.draw_pie_slice_custom(slice, slice_color, target)?;
+        self.draw_pie_slice_custom(slice, SliceFill::Solid(slice_color), target)?;
 
         Ok(())
     }
@@ -191,103 +350,85 @@ where
     fn draw_pie_slice_custom<D>(
         &self,
         slice: &PieSlice,
-        color: C,
+        fill: SliceFill<C>,
         target: &mut D,
     ) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
-        use embedded_graphics::Drawable;
-        use embedded_graphics::Pixel;
-
         let center_x = self.center.x;
         let center_y = self.center.y;
         let radius_num = (self.radius as i32).to_number();
 
-        // Fill the slice by checking each pixel in the bounding box
-        let min_x = (center_x - self.radius as i32).max(0);
-        let max_x = center_x + self.radius as i32;
-        let min_y = (center_y - self.radius as i32).max(0);
-        let max_y = center_y + self.radius as i32;
+        // Bounding box of the slice's circle
+        let area = Rectangle::new(
+            Point::new(center_x - self.radius as i32, center_y - self.radius as i32),
+            Size::new(self.radius * 2 + 1, self.radius * 2 + 1),
+        );
 
         // Constants using Number type
         let zero = 0i32.to_number();
         let pi = core::f32::consts::PI.to_number();
         let two_pi = pi + pi;
 
-        for y in min_y..=max_y {
-            for x in min_x..=max_x {
-                let dx_num = (x - center_x).to_number();
-                let dy_num = (y - center_y).to_number();
-                let distance_squared = dx_num * dx_num + dy_num * dy_num;
-                let distance = Math::sqrt(distance_squared);
-
-                // Skip pixels outside the circle or at the exact center (to avoid overlap)
-                // Add small tolerance for better boundary handling
-                let tolerance = 0.5f32.to_number();
-                if distance > radius_num + tolerance || distance < tolerance {
-                    continue;
-                }
-
-                // Calculate angle from center to this pixel using proper atan2
-                // Note: Screen coordinates have y-axis flipped, so we negate dy for proper mathematical angles
-                let angle = Math::atan2(-dy_num, dx_num);
-
-                // Normalize angle to [0, 2π] using modulo operations
-                let normalized_angle = {
-                    let mut a = angle;
-                    if a < zero {
-                        a += two_pi;
-                    }
-                    // Use a simple normalization since we don't have modulo for Number type
-                    while a >= two_pi {
-                        a -= two_pi;
-                    }
-                    while a < zero {
-                        a += two_pi;
-                    }
-                    a
-                };
-
-                // Check if this pixel is within the slice
-                let start_angle_num = slice.start_angle.to_number();
-                let end_angle_num = slice.end_angle.to_number();
-
-                // Normalize slice angles to [0, 2π] using modulo operations
-                let start_norm = {
-                    let mut a = start_angle_num;
-                    while a >= two_pi {
-                        a -= two_pi;
-                    }
-                    while a < zero {
-                        a += two_pi;
-                    }
-                    a
-                };
-                let end_norm = {
-                    let mut a = end_angle_num;
-                    while a >= two_pi {
-                        a -= two_pi;
-                    }
-                    while a < zero {
-                        a += two_pi;
-                    }
-                    a
-                };
-
-                let in_slice = if start_norm <= end_norm {
-                    normalized_angle >= start_norm && normalized_angle <= end_norm
-                } else {
-                    // Handle wrap-around case
-                    normalized_angle >= start_norm || normalized_angle <= end_norm
-                };
-
-                if in_slice {
-                    let point = Point::new(x, y);
-                    Pixel(point, color)
-                        .draw(target)
-                        .map_err(|_| ChartError::RenderingError)?;
-                }
+        // Normalize an angle to [0, 2π]
+        let normalize = |angle| {
+            let mut a = angle;
+            while a >= two_pi {
+                a -= two_pi;
+            }
+            while a < zero {
+                a += two_pi;
+            }
+            a
+        };
+
+        let start_norm = normalize(slice.start_angle.to_number());
+        let end_norm = normalize(slice.end_angle.to_number());
+
+        // Small tolerance for better boundary handling
+        let tolerance = 0.5f32.to_number();
+
+        let in_slice = |x: i32, y: i32| {
+            let dx_num = (x - center_x).to_number();
+            let dy_num = (y - center_y).to_number();
+            let distance_squared = dx_num * dx_num + dy_num * dy_num;
+            let distance = Math::sqrt(distance_squared);
+
+            // Skip pixels outside the circle or at the exact center (to avoid overlap)
+            if distance > radius_num + tolerance || distance < tolerance {
+                return false;
+            }
+
+            // Calculate angle from center to this pixel using proper atan2
+            // Note: Screen coordinates have y-axis flipped, so we negate dy for proper mathematical angles
+            let angle = normalize(Math::atan2(-dy_num, dx_num));
+
+            if start_norm <= end_norm {
+                angle >= start_norm && angle <= end_norm
+            } else {
+                // Handle wrap-around case
+                angle >= start_norm || angle <= end_norm
+            }
+        };
+
+        let rasterizer = SpanRasterizer::for_target(target);
+        match fill {
+            SliceFill::Solid(color) => rasterizer.fill_mask(area, in_slice, color, target)?,
+            SliceFill::Pattern(pattern) => {
+                // One pass per color, so every pixel is drawn once
+                rasterizer.fill_mask(
+                    area,
+                    |x, y| in_slice(x, y) && pattern.is_foreground(x, y),
+                    pattern.foreground,
+                    target,
+                )?;
+                rasterizer.fill_mask(
+                    area,
+                    |x, y| in_slice(x, y) && !pattern.is_foreground(x, y),
+                    pattern.background,
+                    target,
+                )?;
             }
         }
 
@@ -350,34 +491,43 @@ where
         Self::Data: DataSeries,
         <Self::Data as DataSeries>::Item: DataPoint,
         <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
+    {
+        self.draw_accelerated(data, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
     {
         if data.is_empty() {
-            return Err(ChartError::InsufficientData);
+            return config.fallback(ChartError::InsufficientData, viewport, target);
         }
 
         // Draw background if specified
-        if let Some(bg_color) = config.background_color {
-            Rectangle::new(viewport.top_left, viewport.size)
-                .into_styled(PrimitiveStyle::with_fill(bg_color))
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-        }
+        config.draw_background(viewport, target)?;
 
-        // Calculate the actual center position within the viewport
-        let title_height = if config.title.is_some() { 30 } else { 0 };
-        let available_height = viewport.size.height.saturating_sub(title_height);
-
-        // Center the pie chart in the available space
-        let center_x = viewport.top_left.x + (viewport.size.width as i32) / 2;
-        let center_y = viewport.top_left.y + title_height as i32 + (available_height as i32) / 2;
-        let actual_center = Point::new(center_x, center_y);
+        // Center the pie chart in the space left below the title
+        config.draw_title(viewport, target)?;
+        let area = config.content_area(viewport);
+        let center_x = area.top_left.x + (area.size.width as i32) / 2;
+        let center_y = area.top_left.y + (area.size.height as i32) / 2;
+        let actual_center = Point::new(center_x, center_y) + self.span_offset();
 
         // Create a temporary pie chart with the calculated center for drawing
         let mut chart_for_drawing = self.clone();
         chart_for_drawing.center = actual_center;
 
         // Calculate slices
-        let slices = chart_for_drawing.calculate_slices(data)?;
+        let slices = match chart_for_drawing.calculate_slices(data) {
+            Ok(slices) => slices,
+            Err(error) => return config.fallback(error, viewport, target),
+        };
 
         // Draw each slice using the chart with correct center
         for (index, slice) in slices.iter().enumerate() {
@@ -387,33 +537,26 @@ where
         // Draw donut center if applicable
         chart_for_drawing.draw_donut_center(target)?;
 
-        // Draw title if present
-        if let Some(title) = &config.title {
-            use embedded_graphics::{
-                mono_font::{ascii::FONT_6X10, MonoTextStyle},
-                text::{Alignment, Text},
-            };
-
-            let text_color = embedded_graphics::pixelcolor::Rgb565::BLACK.into();
-            let text_style = MonoTextStyle::new(&FONT_6X10, text_color);
-
-            let title_x = viewport.top_left.x + (viewport.size.width as i32) / 2;
-            let title_y = viewport.top_left.y + 15;
-
-            Text::with_alignment(
-                title,
-                Point::new(title_x, title_y),
-                text_style,
-                Alignment::Center,
-            )
-            .draw(target)
-            .map_err(|_| ChartError::RenderingError)?;
+        // Draw slice labels
+        if self.style.labels.visible {
+            for slice in slices.iter() {
+                chart_for_drawing.draw_label(slice, target)?;
+            }
         }
 
         Ok(())
     }
 }
 
+impl<C: PixelColor> crate::chart::traits::ConfiguredChart<C> for PieChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
 impl<C: PixelColor> Default for PieChartStyle<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -432,6 +575,7 @@ where
             border: None,
             labels: LabelStyle::default(),
             start_angle: 0.0,
+            sweep_angle: 360.0,
             donut_inner_radius: None,
         }
     }
@@ -444,6 +588,8 @@ impl Default for LabelStyle {
             show_percentage: true,
             show_values: false,
             offset: 10,
+            locale: NumberLocale::PLAIN,
+            value_format: None,
         }
     }
 }
@@ -455,6 +601,7 @@ pub struct PieChartBuilder<C: PixelColor> {
     config: ChartConfig<C>,
     center: Point,
     radius: u32,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 impl<C: PixelColor> PieChartBuilder<C>
@@ -468,6 +615,7 @@ where
             config: ChartConfig::default(),
             center: Point::new(50, 50),
             radius: 40,
+            monochrome: None,
         }
     }
 
@@ -500,6 +648,19 @@ where
         self
     }
 
+    /// Set the angular span covered by the slices in degrees
+    ///
+    /// Use 180 for a half pie or 90 for a quarter pie; slices are scaled to fit.
+    pub fn sweep_angle(mut self, angle: f32) -> Self {
+        self.style.sweep_angle = angle;
+        self
+    }
+
+    /// Draw a half pie over the top semicircle
+    pub fn half_pie(self) -> Self {
+        self.start_angle(0.0).sweep_angle(180.0)
+    }
+
     /// Make this a donut chart with the specified inner radius
     pub fn donut(mut self, inner_radius: u32) -> Self {
         self.style.donut_inner_radius = Some(inner_radius);
@@ -590,11 +751,39 @@ where
         self
     }
 
+    /// Set a subtitle drawn below the title
+    pub fn with_subtitle(mut self, subtitle: &str) -> Self {
+        self.config.subtitle = heapless::String::try_from(subtitle).ok();
+        self
+    }
+
+    /// Set the fonts, colors and alignment of the title and subtitle
+    pub fn title_style(mut self, style: TitleStyle<C>) -> Self {
+        self.config.title_style = Some(style);
+        self
+    }
+
     /// Set the background color
     pub fn background_color(mut self, color: C) -> Self {
         self.config.background_color = Some(color);
         self
     }
+
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
+    /// Draw for a monochrome or grayscale display, such as e-paper
+    ///
+    /// Slices are filled with the fill patterns of `style` instead of the
+    /// slice colors and labels are drawn in ink. The background, and with it
+    /// the hole of a donut, is set to paper when the chart is built.
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.monochrome = Some(style);
+        self
+    }
 }
 
 impl<C: PixelColor> ChartBuilder<C> for PieChartBuilder<C>
@@ -604,12 +793,17 @@ where
     type Chart = PieChart<C>;
     type Error = ChartError;
 
-    fn build(self) -> Result<Self::Chart, Self::Error> {
+    fn build(mut self) -> Result<Self::Chart, Self::Error> {
+        if let Some(monochrome) = self.monochrome {
+            self.config.background_color = Some(monochrome.paper);
+        }
+
         Ok(PieChart {
             style: self.style,
             config: self.config,
             center: self.center,
             radius: self.radius,
+            monochrome: self.monochrome,
         })
     }
 }
@@ -666,6 +860,8 @@ mod tests {
             show_percentage: true,
             show_values: false,
             offset: 15,
+            locale: NumberLocale::PLAIN,
+            value_format: None,
         };
 
         assert!(labels.visible);
@@ -688,6 +884,21 @@ mod tests {
         assert_eq!(slice.start_angle, 0.0);
     }
 
+    #[test]
+    fn test_half_pie_span() {
+        let chart: PieChart<Rgb565> = PieChart::builder().radius(40).half_pie().build().unwrap();
+        let data =
+            crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 1.0), (1.0, 1.0)]).unwrap();
+
+        let slices = chart.calculate_slices(&data).unwrap();
+        let pi = core::f32::consts::PI;
+        assert!((slices[0].end_angle - pi / 2.0).abs() < 0.001);
+        assert!((slices[1].end_angle - pi).abs() < 0.001);
+
+        // The flat edge is centered, so the pie center moves down by half the radius
+        assert_eq!(chart.span_offset(), Point::new(0, 20));
+    }
+
     #[test]
     fn test_donut_percentage() {
         // Test 50% donut
@@ -756,4 +967,40 @@ mod tests {
 
         assert_eq!(donut.style().donut_inner_radius, Some(20));
     }
+
+    #[test]
+    fn test_monochrome_slices_use_patterns() {
+        use crate::data::{Point2D, StaticDataSeries};
+        use crate::style::MonochromeStyle;
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        let chart: PieChart<BinaryColor> = PieChart::builder()
+            .center(Point::new(32, 32))
+            .radius(24)
+            .monochrome(MonochromeStyle::BINARY)
+            .build()
+            .unwrap();
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 1.0)).unwrap();
+        data.push(Point2D::new(1.0, 1.0)).unwrap();
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // Each half of the pie mixes ink and paper in its own pattern
+        let row = |y: i32, columns: core::ops::Range<i32>| {
+            columns
+                .map(|x| display.get_pixel(Point::new(x, y)) == Some(BinaryColor::On))
+                .collect::<heapless::Vec<bool, 16>>()
+        };
+        let (top, bottom) = (row(24, 28..36), row(40, 28..36));
+        assert!(top.contains(&true) && top.contains(&false));
+        assert!(bottom.contains(&true));
+        assert_ne!(top, bottom);
+    }
 }
@@ -0,0 +1,229 @@
+//! Regression overlay for scatter charts.
+//!
+//! A [`RegressionOverlay`] fits a least-squares line, or a parabola, through
+//! the points of a scatter chart and draws it across their x range. The fit
+//! itself is available as a [`RegressionFit`], so a display can print its
+//! parameters next to the chart.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let chart = ScatterChart::builder()
+//!     .with_regression(RegressionOverlay::linear(Rgb565::RED))
+//!     .build()?;
+//!
+//! let data = data_points![(0.0, 1.0), (1.0, 3.1), (2.0, 4.9), (3.0, 7.0)];
+//! let fit = chart.regression(data.as_slice()).expect("enough points");
+//! assert!((fit.slope_at(0.0) - 2.0).abs() < 0.1);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::data::{LinearTrend, Point2D, QuadraticTrend};
+use crate::error::{ChartResult, DataResult};
+use crate::render::ChartRenderer;
+use crate::style::LineStyle;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*};
+use heapless::Vec;
+
+/// Number of segments a quadratic fit is drawn with
+const CURVE_SEGMENTS: usize = 24;
+
+/// Degree of the fitted polynomial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegressionOrder {
+    /// Straight line, needs two distinct x values
+    Linear,
+    /// Parabola, needs three distinct x values
+    Quadratic,
+}
+
+/// Result of a regression over the points of a chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegressionFit {
+    /// Least-squares line
+    Linear(LinearTrend),
+    /// Least-squares parabola
+    Quadratic(QuadraticTrend),
+}
+
+impl RegressionFit {
+    /// Fit a polynomial of `order` through `points`
+    pub fn fit(order: RegressionOrder, points: &[Point2D]) -> DataResult<Self> {
+        let points = points.iter().copied();
+        match order {
+            RegressionOrder::Linear => LinearTrend::fit_points(points).map(Self::Linear),
+            RegressionOrder::Quadratic => QuadraticTrend::fit_points(points).map(Self::Quadratic),
+        }
+    }
+
+    /// Degree of the fit
+    pub fn order(&self) -> RegressionOrder {
+        match self {
+            Self::Linear(_) => RegressionOrder::Linear,
+            Self::Quadratic(_) => RegressionOrder::Quadratic,
+        }
+    }
+
+    /// Value of the fit at `x`
+    pub fn value_at(&self, x: f32) -> f32 {
+        match self {
+            Self::Linear(trend) => trend.value_at(x),
+            Self::Quadratic(trend) => trend.value_at(x),
+        }
+    }
+
+    /// Slope of the fit at `x`, the same everywhere for a line
+    pub fn slope_at(&self, x: f32) -> f32 {
+        match self {
+            Self::Linear(trend) => trend.slope,
+            Self::Quadratic(trend) => trend.slope_at(x),
+        }
+    }
+
+    /// Standard deviation of the points around the fit
+    pub fn residual_std_dev(&self) -> f32 {
+        match self {
+            Self::Linear(trend) => trend.residual_std_dev,
+            Self::Quadratic(trend) => trend.residual_std_dev,
+        }
+    }
+}
+
+/// Fitted line or parabola drawn over a scatter chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionOverlay<C: PixelColor> {
+    /// Degree of the fitted polynomial
+    pub order: RegressionOrder,
+    /// Style of the drawn fit
+    pub line: LineStyle<C>,
+}
+
+impl<C: PixelColor> RegressionOverlay<C> {
+    /// Fit a straight line, drawn solid in `color`
+    pub const fn linear(color: C) -> Self {
+        Self {
+            order: RegressionOrder::Linear,
+            line: LineStyle::solid(color),
+        }
+    }
+
+    /// Fit a parabola, drawn solid in `color`
+    pub const fn quadratic(color: C) -> Self {
+        Self {
+            order: RegressionOrder::Quadratic,
+            line: LineStyle::solid(color),
+        }
+    }
+
+    /// Set the style of the drawn fit
+    pub const fn with_line(mut self, line: LineStyle<C>) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Fit the overlay's polynomial through `points`
+    pub fn fit(&self, points: &[Point2D]) -> DataResult<RegressionFit> {
+        RegressionFit::fit(self.order, points)
+    }
+
+    /// Draw `fit` from `start_x` to `end_x`, mapping data coordinates with
+    /// `to_screen`
+    pub fn draw<D, F>(
+        &self,
+        fit: &RegressionFit,
+        start_x: f32,
+        end_x: f32,
+        to_screen: F,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+        F: Fn(Point2D) -> Point,
+    {
+        let segments = match fit {
+            RegressionFit::Linear(_) => 1,
+            RegressionFit::Quadratic(_) => CURVE_SEGMENTS,
+        };
+        let mut points: Vec<Point, { CURVE_SEGMENTS + 1 }> = Vec::new();
+        for step in 0..=segments {
+            let x = start_x + (end_x - start_x) * step as f32 / segments as f32;
+            let point = to_screen(Point2D::new(x, fit.value_at(x)));
+            // Consecutive samples can land on the same pixel
+            if points.last() != Some(&point) {
+                let _ = points.push(point);
+            }
+        }
+
+        ChartRenderer::draw_polyline(&points, &self.line, target)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_fit_orders() {
+        let points = [
+            Point2D::new(0.0, 4.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(4.0, 4.0),
+        ];
+
+        let line = RegressionOverlay::linear(Rgb565::RED).fit(&points).unwrap();
+        assert_eq!(line.order(), RegressionOrder::Linear);
+        assert!(line.slope_at(0.0).abs() < 0.001);
+
+        let curve = RegressionOverlay::quadratic(Rgb565::RED)
+            .fit(&points)
+            .unwrap();
+        assert_eq!(curve.order(), RegressionOrder::Quadratic);
+        assert!(curve.value_at(2.0).abs() < 0.01);
+        assert!((curve.slope_at(4.0) - 4.0).abs() < 0.01);
+        assert!(curve.residual_std_dev() < 0.01);
+
+        assert!(RegressionFit::fit(RegressionOrder::Quadratic, &points[..2]).is_err());
+    }
+
+    #[test]
+    fn test_draw_spans_range() {
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 4.0),
+        ];
+        let overlay = RegressionOverlay::quadratic(Rgb565::RED);
+        let fit = overlay.fit(&points).unwrap();
+
+        // Ten pixels per x unit, five per y unit upwards from row 30
+        let to_screen =
+            |point: Point2D| Point::new((point.x * 10.0) as i32, 30 - (point.y * 5.0) as i32);
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        overlay
+            .draw(&fit, 0.0, 2.0, to_screen, &mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(0, 30)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(20, 10)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(10, 25)), Some(Rgb565::RED));
+    }
+}
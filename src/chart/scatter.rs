@@ -3,13 +3,20 @@
 //! This module provides scatter chart functionality for plotting discrete data points
 //! with various shapes, sizes, and colors. Supports bubble charts with size mapping
 //! and collision detection for large datasets.
+//!
+//! Datasets too dense for individual markers can be drawn as a density map,
+//! see [`DensitySettings`], and a fitted trend can be drawn over the points,
+//! see [`RegressionOverlay`].
 
 use crate::axes::traits::Axis;
+use crate::chart::marker::{MarkerRenderer, MarkerShape};
+use crate::chart::regression::{RegressionFit, RegressionOverlay};
 use crate::chart::traits::AxisChart;
-use crate::chart::traits::{Chart, ChartBuilder, ChartConfig, Margins};
-use crate::data::{DataBounds, DataPoint, DataSeries};
+use crate::chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig, Margins, TitleStyle};
+use crate::data::{calculate_bounds, DataBounds, DataPoint, DataSeries, Point2D};
 use crate::error::{ChartError, ChartResult};
 use crate::math::{Math, NumericConversion};
+use crate::style::{ColorScale, ColorScaling, MonochromeStyle, PatternFill, PatternType};
 use embedded_graphics::{
     draw_target::DrawTarget,
     prelude::*,
@@ -42,6 +49,12 @@ pub struct ScatterChartStyle<C: PixelColor> {
     pub show_connections: bool,
     /// Connection line style
     pub connection_style: Option<ConnectionStyle<C>>,
+    /// Horizontal jitter for points stacked on categorical X values
+    pub jitter: Option<JitterSettings>,
+    /// Density rendering for large datasets
+    pub density: Option<DensitySettings<C>>,
+    /// Fitted line or parabola drawn over the points
+    pub regression: Option<RegressionOverlay<C>>,
 }
 
 /// Style configuration for individual points
@@ -109,6 +122,20 @@ pub enum PointShape {
     Star,
 }
 
+impl From<PointShape> for MarkerShape {
+    fn from(shape: PointShape) -> Self {
+        match shape {
+            PointShape::Circle => MarkerShape::Circle,
+            PointShape::Square => MarkerShape::Square,
+            PointShape::Diamond => MarkerShape::Diamond,
+            PointShape::Triangle => MarkerShape::Triangle,
+            PointShape::Cross => MarkerShape::Plus,
+            PointShape::X => MarkerShape::Cross,
+            PointShape::Star => MarkerShape::Star,
+        }
+    }
+}
+
 /// Size mapping configuration for bubble charts
 #[derive(Debug, Clone, Copy)]
 pub struct SizeMapping {
@@ -138,6 +165,8 @@ pub struct ColorMapping<C: PixelColor> {
     pub colors: Vec<C, 16>,
     /// Mapping strategy
     pub strategy: ColorMappingStrategy,
+    /// How values are normalized for [`ColorMappingStrategy::ValueBased`]
+    pub scaling: ColorScaling,
 }
 
 /// Color mapping strategies
@@ -175,6 +204,128 @@ pub enum CollisionStrategy {
     None,
 }
 
+/// Deterministic horizontal jitter for categorical X values
+///
+/// Each point is shifted along X by a pseudo-random amount derived from `seed`
+/// and its index, so the layout is the same on every redraw.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JitterSettings {
+    /// Distance between neighbouring categories in X data units
+    pub slot_width: f32,
+    /// Share of the slot points may spread over (0.0-1.0)
+    pub spread: f32,
+    /// Seed for the point offsets
+    pub seed: u32,
+}
+
+impl JitterSettings {
+    /// Offset in X data units for the point at `index`
+    pub fn offset(&self, index: usize) -> f32 {
+        // Integer hash of seed and index, mapped to -0.5..0.5
+        let mut hash = self.seed ^ (index as u32).wrapping_mul(0x9e37_79b9);
+        hash ^= hash >> 16;
+        hash = hash.wrapping_mul(0x7feb_352d);
+        hash ^= hash >> 15;
+        hash = hash.wrapping_mul(0x846c_a68b);
+        hash ^= hash >> 16;
+        let unit = (hash >> 8) as f32 / (1u32 << 24) as f32 - 0.5;
+
+        unit * self.slot_width * self.spread.clamp(0.0, 1.0)
+    }
+}
+
+/// Number of points a scatter chart draws as markers
+pub const MAX_SCATTER_POINTS: usize = 256;
+
+/// Number of cells a density grid can hold
+///
+/// Cells are enlarged until the grid over the plot area fits.
+pub const MAX_DENSITY_CELLS: usize = 2048;
+
+/// Density rendering for datasets too large for individual markers
+///
+/// Points are counted in a grid of square screen cells and each non-empty
+/// cell is shaded by its count, relative to the other cells as set by
+/// `scaling`. Markers, connections and collision handling are skipped.
+#[derive(Debug, Clone)]
+pub struct DensitySettings<C: PixelColor> {
+    /// When points are binned instead of drawn
+    pub mode: DensityMode,
+    /// Side of a cell in pixels
+    pub cell_size: u32,
+    /// How cells are shaded
+    pub shading: DensityShading<C>,
+    /// How cell counts are normalized before shading
+    pub scaling: ColorScaling,
+}
+
+/// When a scatter chart switches to density rendering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DensityMode {
+    /// Always bin points
+    Always,
+    /// Bin points once they can't be drawn apart
+    ///
+    /// That is the case when there are more points than the point buffer
+    /// holds, or more than fit the plot area with each point taking its
+    /// size plus the collision distance in both directions.
+    Auto,
+}
+
+/// How the cells of a density map are shaded
+#[derive(Debug, Clone)]
+pub enum DensityShading<C: PixelColor> {
+    /// Colors from sparse to dense
+    Colors(Vec<C, 16>),
+    /// Ordered dither of one color over the background, for 1-bit displays
+    Dither(C),
+}
+
+impl<C: PixelColor> DensitySettings<C> {
+    /// Shade cells with `colors`, from sparse to dense
+    ///
+    /// Returns an error if `colors` is empty or holds more than 16 colors.
+    pub fn colors(colors: &[C]) -> ChartResult<Self> {
+        if colors.is_empty() {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        let colors = Vec::from_slice(colors).map_err(|_| ChartError::MemoryFull)?;
+        Ok(Self::new(DensityShading::Colors(colors)))
+    }
+
+    /// Shade cells with an ordered dither of `color`
+    pub fn dither(color: C) -> Self {
+        Self::new(DensityShading::Dither(color))
+    }
+
+    /// Switch to density rendering only when points can't be drawn apart
+    pub fn auto(mut self) -> Self {
+        self.mode = DensityMode::Auto;
+        self
+    }
+
+    /// Set the side of a cell in pixels
+    pub fn cell_size(mut self, size: u32) -> Self {
+        self.cell_size = size.max(1);
+        self
+    }
+
+    /// Set how cell counts are normalized
+    pub fn scaling(mut self, scaling: ColorScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    fn new(shading: DensityShading<C>) -> Self {
+        Self {
+            mode: DensityMode::Always,
+            cell_size: 2,
+            shading,
+            scaling: ColorScaling::MinMax,
+        }
+    }
+}
+
 /// Builder for scatter charts
 #[derive(Debug)]
 pub struct ScatterChartBuilder<C: PixelColor> {
@@ -183,6 +334,7 @@ pub struct ScatterChartBuilder<C: PixelColor> {
     grid: Option<crate::grid::GridSystem<C>>,
     x_axis: Option<crate::axes::LinearAxis<f32, C>>,
     y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    monochrome: Option<MonochromeStyle<C>>,
 }
 
 impl<C: PixelColor> ScatterChart<C>
@@ -236,9 +388,12 @@ where
     }
 
     /// Transform data coordinates to screen coordinates
+    ///
+    /// `x_offset` is added to the X value, in data units.
     fn transform_point<P>(
         &self,
         point: &P,
+        x_offset: f32,
         data_bounds: &DataBounds<P::X, P::Y>,
         viewport: Rectangle,
     ) -> Point
@@ -247,7 +402,7 @@ where
         P::X: Into<f32> + Copy,
         P::Y: Into<f32> + Copy,
     {
-        let data_x: f32 = point.x().into();
+        let data_x: f32 = point.x().into() + x_offset;
         let data_y: f32 = point.y().into();
 
         // Use axis ranges if available, otherwise fall back to data bounds
@@ -266,23 +421,38 @@ where
         // Apply margins to get the actual drawing area
         let draw_area = self.config.margins.apply_to(viewport);
 
-        // Normalize to 0-1 range
-        let norm_x = if max_x > min_x {
-            (data_x - min_x) / (max_x - min_x)
+        // Auto-ranged dimensions keep the largest point inside the drawing area;
+        // axis-ranged dimensions stay aligned with their ticks
+        let padding = self.point_padding() as i32;
+        let inset_x = if self.x_axis.is_some() {
+            0
         } else {
-            0.5
+            padding.min((draw_area.size.width as i32 - 1) / 2)
         };
-
-        let norm_y = if max_y > min_y {
-            (data_y - min_y) / (max_y - min_y)
+        let inset_y = if self.y_axis.is_some() {
+            0
         } else {
-            0.5
+            padding.min((draw_area.size.height as i32 - 1) / 2)
+        };
+
+        // Normalize to 0-1 range, on a log scale where the axis asks for one
+        let norm_x = match self.x_axis {
+            Some(ref x_axis) if x_axis.is_logarithmic() => x_axis.normalize(data_x),
+            _ if max_x > min_x => (data_x - min_x) / (max_x - min_x),
+            _ => 0.5,
+        };
+
+        let norm_y = match self.y_axis {
+            Some(ref y_axis) if y_axis.is_logarithmic() => y_axis.normalize(data_y),
+            _ if max_y > min_y => (data_y - min_y) / (max_y - min_y),
+            _ => 0.5,
         };
 
         // Transform to screen coordinates (Y is flipped)
         // Ensure we don't go outside the drawing area bounds
         let screen_x = if draw_area.size.width > 0 {
-            let x = draw_area.top_left.x + (norm_x * (draw_area.size.width as f32 - 1.0)) as i32;
+            let span = draw_area.size.width as f32 - 1.0 - 2.0 * inset_x as f32;
+            let x = draw_area.top_left.x + inset_x + (norm_x * span) as i32;
             x.max(draw_area.top_left.x)
                 .min(draw_area.top_left.x + draw_area.size.width as i32 - 1)
         } else {
@@ -290,9 +460,11 @@ where
         };
 
         let screen_y = if draw_area.size.height > 0 {
+            let span = draw_area.size.height as f32 - 1.0 - 2.0 * inset_y as f32;
             let y = draw_area.top_left.y + draw_area.size.height as i32
                 - 1
-                - (norm_y * (draw_area.size.height as f32 - 1.0)) as i32;
+                - inset_y
+                - (norm_y * span) as i32;
             y.max(draw_area.top_left.y)
                 .min(draw_area.top_left.y + draw_area.size.height as i32 - 1)
         } else {
@@ -302,6 +474,23 @@ where
         Point::new(screen_x, screen_y)
     }
 
+    /// Get the padding that keeps the largest point inside the drawing area
+    ///
+    /// This is the radius of the largest point, including bubbles scaled by
+    /// the size mapping, plus the border width.
+    pub fn point_padding(&self) -> u32 {
+        let max_size = match &self.style.size_mapping {
+            Some(size_mapping) => size_mapping.max_size.max(size_mapping.min_size),
+            None => self.style.point_style.size,
+        };
+        let border = self
+            .style
+            .point_style
+            .border
+            .map_or(0, |border| border.width);
+        max_size / 2 + border
+    }
+
     /// Calculate point size based on size mapping
     fn calculate_point_size<P>(&self, point: &P, data_bounds: &DataBounds<P::X, P::Y>) -> u32
     where
@@ -345,12 +534,145 @@ where
         }
     }
 
+    /// Fit the value scale used by value-based color mapping to `data`
+    ///
+    /// Returns `None` unless the chart maps colors by value. Use the scale to
+    /// label a color legend with the values its colors represent.
+    pub fn color_scale(
+        &self,
+        data: &crate::data::series::StaticDataSeries<crate::data::point::Point2D, 256>,
+    ) -> ChartResult<Option<ColorScale>> {
+        self.fit_color_scale(data.as_slice())
+    }
+
+    /// Fit the value scale of value-based color mapping to `points`
+    fn fit_color_scale(&self, points: &[Point2D]) -> ChartResult<Option<ColorScale>> {
+        match &self.style.color_mapping {
+            Some(mapping) if mapping.strategy == ColorMappingStrategy::ValueBased => {
+                ColorScale::fit(mapping.scaling, points.iter().map(|point| point.y())).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Check whether `count` points are drawn as a density map
+    fn uses_density(&self, count: usize, viewport: Rectangle) -> bool {
+        let Some(density) = &self.style.density else {
+            return false;
+        };
+        match density.mode {
+            DensityMode::Always => true,
+            DensityMode::Auto => {
+                let plot = self.config.margins.apply_to(viewport).size;
+                let spacing = (self.style.point_style.size
+                    + self.style.collision_detection.min_distance)
+                    .max(1) as usize;
+                let room = (plot.width as usize / spacing) * (plot.height as usize / spacing);
+                count > MAX_SCATTER_POINTS || count > room
+            }
+        }
+    }
+
+    /// Count points in screen cells and shade the cells
+    fn draw_density<D>(
+        &self,
+        points: &[Point2D],
+        density: &DensitySettings<C>,
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let area = self.config.margins.apply_to(viewport);
+        let grid = |cell: u32| {
+            (
+                area.size.width.div_ceil(cell) as usize,
+                area.size.height.div_ceil(cell) as usize,
+            )
+        };
+        let mut cell = density.cell_size.max(1);
+        while grid(cell).0 * grid(cell).1 > MAX_DENSITY_CELLS {
+            cell += 1;
+        }
+        let (columns, rows) = grid(cell);
+
+        let mut counts: Vec<u16, MAX_DENSITY_CELLS> = Vec::new();
+        counts
+            .resize(columns * rows, 0)
+            .map_err(|_| ChartError::MemoryFull)?;
+        for (index, point) in points.iter().enumerate() {
+            let x_offset = self.style.jitter.map_or(0.0, |jitter| jitter.offset(index));
+            let screen = self.transform_point(point, x_offset, data_bounds, viewport);
+            if !area.contains(screen) {
+                continue;
+            }
+            let offset = screen - area.top_left;
+            let cell_index =
+                offset.y as usize / cell as usize * columns + offset.x as usize / cell as usize;
+            if let Some(count) = counts.get_mut(cell_index) {
+                *count = count.saturating_add(1);
+            }
+        }
+
+        let filled = counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| f32::from(count));
+        let scale = match density.scaling {
+            ColorScaling::MinMax => ColorScale::linear(1.0, filled.fold(1.0, f32::max)),
+            scaling => match ColorScale::fit(scaling, filled) {
+                Ok(scale) => scale,
+                // No point fell inside the plot area
+                Err(_) => return Ok(()),
+            },
+        };
+
+        for (index, &count) in counts.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let top_left = area.top_left
+                + Point::new(
+                    (index % columns) as i32 * cell as i32,
+                    (index / columns) as i32 * cell as i32,
+                );
+            let bounds = Rectangle::new(top_left, Size::new(cell, cell)).intersection(&area);
+            let value = f32::from(count);
+            match &density.shading {
+                DensityShading::Colors(colors) => {
+                    let color = colors[scale.color_index(value, colors.len())];
+                    target
+                        .fill_solid(&bounds, color)
+                        .map_err(|_| ChartError::RenderingError)?;
+                }
+                DensityShading::Dither(color) => {
+                    // Even the sparsest cell shows some pixels
+                    let level = 1 + (scale.normalize(value) * 15.0 + 0.5) as u8;
+                    let pattern = PatternFill::new(*color, *color, PatternType::Dither { level });
+                    target
+                        .draw_iter(
+                            bounds
+                                .points()
+                                .filter(|point| pattern.is_foreground(point.x, point.y))
+                                .map(|point| Pixel(point, *color)),
+                        )
+                        .map_err(|_| ChartError::RenderingError)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Calculate point color based on color mapping
     fn calculate_point_color<P>(
         &self,
         point: &P,
         index: usize,
         data_bounds: &DataBounds<P::X, P::Y>,
+        color_scale: Option<&ColorScale>,
     ) -> C
     where
         P: DataPoint,
@@ -361,36 +683,39 @@ where
             let color_index = match color_mapping.strategy {
                 ColorMappingStrategy::ValueBased => {
                     let data_y: f32 = point.y().into();
-                    let min_y: f32 = data_bounds.min_y.into();
+                    match color_scale {
+                        Some(scale) => scale.color_index(data_y, color_mapping.colors.len()),
+                        None => {
+                            ColorScale::linear(data_bounds.min_y.into(), data_bounds.max_y.into())
+                                .color_index(data_y, color_mapping.colors.len())
+                        }
+                    }
+                }
+                ColorMappingStrategy::IndexBased => index % color_mapping.colors.len(),
+                ColorMappingStrategy::DistanceBased => {
+                    let max_x: f32 = data_bounds.max_x.into();
                     let max_y: f32 = data_bounds.max_y.into();
+                    let min_x: f32 = data_bounds.min_x.into();
+                    let min_y: f32 = data_bounds.min_y.into();
 
-                    let norm_value = if max_y > min_y {
-                        (data_y - min_y) / (max_y - min_y)
-                    } else {
-                        0.5
+                    // Pre-scale so the squared distances stay within the
+                    // range of fixed-point numbers
+                    let magnitude = |v: f32| if v < 0.0 { -v } else { v };
+                    let scale = [max_x, max_y, min_x, min_y]
+                        .into_iter()
+                        .fold(1.0f32, |acc, v| acc.max(magnitude(v)));
+                    let length = |x: f32, y: f32| {
+                        let x_num = (x / scale).to_number();
+                        let y_num = (y / scale).to_number();
+                        f32::from_number(Math::sqrt(x_num * x_num + y_num * y_num))
                     };
 
-                    ((norm_value * (color_mapping.colors.len() - 1) as f32) as usize)
-                        .min(color_mapping.colors.len() - 1)
-                }
-                ColorMappingStrategy::IndexBased => index % color_mapping.colors.len(),
-                ColorMappingStrategy::DistanceBased => {
                     let data_x: f32 = point.x().into();
                     let data_y: f32 = point.y().into();
-                    let data_x_num = data_x.to_number();
-                    let data_y_num = data_y.to_number();
-                    let distance_squared = data_x_num * data_x_num + data_y_num * data_y_num;
-                    let distance = f32::from_number(Math::sqrt(distance_squared));
+                    let distance = length(data_x, data_y);
 
                     // Normalize distance and map to color index
-                    let max_distance = {
-                        let max_x: f32 = data_bounds.max_x.into();
-                        let max_y: f32 = data_bounds.max_y.into();
-                        let max_x_num = max_x.to_number();
-                        let max_y_num = max_y.to_number();
-                        let max_distance_squared = max_x_num * max_x_num + max_y_num * max_y_num;
-                        f32::from_number(Math::sqrt(max_distance_squared))
-                    };
+                    let max_distance = length(max_x, max_y);
 
                     let norm_distance = if max_distance > 0.0 {
                         distance / max_distance
@@ -455,104 +780,13 @@ where
             return Ok(());
         }
 
-        let fill_style = PrimitiveStyle::with_fill(point_style.color);
-
-        match point_style.shape {
-            PointShape::Circle => {
-                Circle::new(
-                    Point::new(center.x - radius as i32, center.y - radius as i32),
-                    size,
-                )
-                .into_styled(fill_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::Square => {
-                Rectangle::new(
-                    Point::new(center.x - radius as i32, center.y - radius as i32),
-                    Size::new(size, size),
-                )
-                .into_styled(fill_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::Diamond => {
-                use crate::render::PrimitiveRenderer;
-                use crate::style::FillStyle;
-
-                let fill_style = FillStyle::solid(point_style.color);
-                PrimitiveRenderer::draw_diamond(center, size, None, Some(&fill_style), target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::Triangle => {
-                use crate::render::PrimitiveRenderer;
-                use crate::style::FillStyle;
-
-                let fill_style = FillStyle::solid(point_style.color);
-                let half_size = size as i32 / 2;
-                let p1 = Point::new(center.x, center.y - half_size);
-                let p2 = Point::new(center.x - half_size, center.y + half_size);
-                let p3 = Point::new(center.x + half_size, center.y + half_size);
-
-                PrimitiveRenderer::draw_triangle(p1, p2, p3, None, Some(&fill_style), target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::Cross => {
-                let stroke_style = PrimitiveStyle::with_stroke(point_style.color, 2);
-                let half_size = radius as i32;
-
-                // Vertical line
-                Line::new(
-                    Point::new(center.x, center.y - half_size),
-                    Point::new(center.x, center.y + half_size),
-                )
-                .into_styled(stroke_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-
-                // Horizontal line
-                Line::new(
-                    Point::new(center.x - half_size, center.y),
-                    Point::new(center.x + half_size, center.y),
-                )
-                .into_styled(stroke_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::X => {
-                let stroke_style = PrimitiveStyle::with_stroke(point_style.color, 2);
-                let half_size = radius as i32;
-
-                // Diagonal line 1
-                Line::new(
-                    Point::new(center.x - half_size, center.y - half_size),
-                    Point::new(center.x + half_size, center.y + half_size),
-                )
-                .into_styled(stroke_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-
-                // Diagonal line 2
-                Line::new(
-                    Point::new(center.x - half_size, center.y + half_size),
-                    Point::new(center.x + half_size, center.y - half_size),
-                )
-                .into_styled(stroke_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-            PointShape::Star => {
-                // For star, draw a simple filled circle for now
-                // A full star implementation would be more complex
-                Circle::new(
-                    Point::new(center.x - radius as i32, center.y - radius as i32),
-                    size,
-                )
-                .into_styled(fill_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-            }
-        }
+        MarkerRenderer::draw(
+            point_style.shape.into(),
+            center,
+            size,
+            point_style.color,
+            target,
+        )?;
 
         // Draw border if specified
         if let Some(border) = &point_style.border {
@@ -668,35 +902,139 @@ where
         <<Self::Data as DataSeries>::Item as DataPoint>::X: Into<f32> + Copy + PartialOrd,
         <<Self::Data as DataSeries>::Item as DataPoint>::Y: Into<f32> + Copy + PartialOrd,
     {
-        if data.is_empty() {
-            return Err(ChartError::InsufficientData);
+        self.draw_points(data.as_slice(), config, viewport, target)
+    }
+}
+
+impl<C: PixelColor + 'static> ScatterChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    /// Draw points from a slice of any length
+    ///
+    /// Unlike [`Chart::draw`] this isn't limited to the capacity of a
+    /// [`StaticDataSeries`](crate::data::series::StaticDataSeries), so large
+    /// datasets can be drawn as a density map. Drawn as markers, at most
+    /// [`MAX_SCATTER_POINTS`] points fit.
+    pub fn draw_points<D>(
+        &self,
+        points: &[Point2D],
+        config: &ChartConfig<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if points.is_empty() {
+            return config.fallback(ChartError::InsufficientData, viewport, target);
         }
 
         // Calculate data bounds
-        let data_bounds = data.bounds()?;
+        let data_bounds = calculate_bounds(points.iter().copied())?;
 
         // Draw background if specified
-        if let Some(bg_color) = config.background_color {
-            Rectangle::new(viewport.top_left, viewport.size)
-                .into_styled(PrimitiveStyle::with_fill(bg_color))
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-        }
+        config.draw_background(viewport, target)?;
+
+        // The title takes a band at the top; everything else is laid out below it
+        config.draw_title(viewport, target)?;
+        let viewport = config.content_area(viewport);
 
         // Draw grid if present
         if let Some(ref grid) = self.grid {
             let chart_area = config.margins.apply_to(viewport);
             grid.draw(chart_area, target)?;
+            grid.draw_zero_lines(
+                chart_area,
+                self.x_axis.as_ref(),
+                self.y_axis.as_ref(),
+                target,
+            )?;
+        }
+
+        // Draw zero lines, even when no grid is configured
+        {
+            let chart_area = config.margins.apply_to(viewport);
+            if let Some(ref x_axis) = self.x_axis {
+                x_axis.draw_zero_line(chart_area, chart_area, target)?;
+            }
+            if let Some(ref y_axis) = self.y_axis {
+                y_axis.draw_zero_line(chart_area, chart_area, target)?;
+            }
+        }
+
+        match self.style.density.as_ref() {
+            Some(density) if self.uses_density(points.len(), viewport) => {
+                self.draw_density(points, density, &data_bounds, viewport, target)?
+            }
+            _ => self.draw_markers(points, &data_bounds, viewport, target)?,
         }
 
+        // The fit spans the x range of the data, clipped to the chart area
+        if let (Some(overlay), Some(fit)) =
+            (self.style.regression.as_ref(), self.regression(points))
+        {
+            let chart_area = config.margins.apply_to(viewport);
+            overlay.draw(
+                &fit,
+                data_bounds.min_x,
+                data_bounds.max_x,
+                |point| self.transform_point(&point, 0.0, &data_bounds, viewport),
+                &mut target.clipped(&chart_area),
+            )?;
+        }
+
+        // Draw axes if configured
+        {
+            let chart_area = config.margins.apply_to(viewport);
+
+            // Draw X-axis using the axis system
+            if let Some(ref x_axis) = self.x_axis {
+                x_axis.draw(chart_area, target)?;
+            }
+
+            // Draw Y-axis using the axis system
+            if let Some(ref y_axis) = self.y_axis {
+                y_axis.draw(chart_area, target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fit the regression overlay through `points`
+    ///
+    /// Returns `None` without an overlay or with too few distinct x values
+    /// for its order. Call it with the drawn data to show the fit parameters
+    /// next to the chart.
+    pub fn regression(&self, points: &[Point2D]) -> Option<RegressionFit> {
+        self.style.regression.as_ref()?.fit(points).ok()
+    }
+
+    /// Draw a marker per point, handling collisions
+    fn draw_markers<D>(
+        &self,
+        points: &[Point2D],
+        data_bounds: &DataBounds<f32, f32>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Fit the value scale once for all points
+        let color_scale = self.fit_color_scale(points)?;
+
         // Collect screen points and handle collisions
-        let mut screen_points = Vec::<Point, 256>::new();
-        let mut point_data = Vec::<(Point, PointStyle<C>, u32), 256>::new();
+        let mut screen_points = Vec::<Point, MAX_SCATTER_POINTS>::new();
+        let mut point_data = Vec::<(Point, PointStyle<C>, u32), MAX_SCATTER_POINTS>::new();
 
-        for (index, point) in data.iter().enumerate() {
-            let screen_point = self.transform_point(&point, &data_bounds, viewport);
-            let point_size = self.calculate_point_size(&point, &data_bounds);
-            let point_color = self.calculate_point_color(&point, index, &data_bounds);
+        for (index, point) in points.iter().enumerate() {
+            let x_offset = self.style.jitter.map_or(0.0, |jitter| jitter.offset(index));
+            let screen_point = self.transform_point(point, x_offset, data_bounds, viewport);
+            let point_size = self.calculate_point_size(point, data_bounds);
+            let point_color =
+                self.calculate_point_color(point, index, data_bounds, color_scale.as_ref());
 
             let mut point_style = self.style.point_style;
             point_style.color = point_color;
@@ -761,25 +1099,19 @@ where
             self.draw_point(*screen_point, point_style, *point_size, target)?;
         }
 
-        // Draw axes if configured
-        {
-            let chart_area = config.margins.apply_to(viewport);
-
-            // Draw X-axis using the axis system
-            if let Some(ref x_axis) = self.x_axis {
-                x_axis.draw(chart_area, target)?;
-            }
-
-            // Draw Y-axis using the axis system
-            if let Some(ref y_axis) = self.y_axis {
-                y_axis.draw(chart_area, target)?;
-            }
-        }
-
         Ok(())
     }
 }
 
+impl<C: PixelColor + 'static> crate::chart::traits::ConfiguredChart<C> for ScatterChart<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn chart_config(&self) -> &Self::Config {
+        self.config()
+    }
+}
+
 impl<C: PixelColor> Default for ScatterChartStyle<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
@@ -792,6 +1124,9 @@ where
             collision_detection: CollisionSettings::default(),
             show_connections: false,
             connection_style: None,
+            jitter: None,
+            density: None,
+            regression: None,
         }
     }
 }
@@ -821,6 +1156,16 @@ impl Default for CollisionSettings {
     }
 }
 
+impl Default for JitterSettings {
+    fn default() -> Self {
+        Self {
+            slot_width: 1.0,
+            spread: 0.8,
+            seed: 0,
+        }
+    }
+}
+
 impl Default for SizeMapping {
     fn default() -> Self {
         Self {
@@ -843,6 +1188,7 @@ where
             grid: None,
             x_axis: None,
             y_axis: None,
+            monochrome: None,
         }
     }
 
@@ -888,6 +1234,24 @@ where
         self
     }
 
+    /// Spread points on shared X values horizontally within their category slot
+    pub fn with_jitter(mut self, settings: JitterSettings) -> Self {
+        self.style.jitter = Some(settings);
+        self
+    }
+
+    /// Draw large datasets as a density map, see [`DensitySettings`]
+    pub fn with_density(mut self, settings: DensitySettings<C>) -> Self {
+        self.style.density = Some(settings);
+        self
+    }
+
+    /// Draw a fitted line or parabola over the points
+    pub fn with_regression(mut self, overlay: RegressionOverlay<C>) -> Self {
+        self.style.regression = Some(overlay);
+        self
+    }
+
     /// Enable connection lines between points
     pub fn with_connections(mut self, style: ConnectionStyle<C>) -> Self {
         self.style.show_connections = true;
@@ -902,12 +1266,30 @@ where
         self
     }
 
+    /// Set a subtitle drawn below the title
+    pub fn with_subtitle(mut self, subtitle: &str) -> Self {
+        self.config.subtitle = heapless::String::try_from(subtitle).ok();
+        self
+    }
+
+    /// Set the fonts, colors and alignment of the title and subtitle
+    pub fn title_style(mut self, style: TitleStyle<C>) -> Self {
+        self.config.title_style = Some(style);
+        self
+    }
+
     /// Set the background color
     pub fn background_color(mut self, color: C) -> Self {
         self.config.background_color = Some(color);
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
     /// Set the chart margins
     pub fn margins(mut self, margins: Margins) -> Self {
         self.config.margins = margins;
@@ -932,8 +1314,44 @@ where
         self
     }
 
+    /// Draw for a monochrome or grayscale display, such as e-paper
+    ///
+    /// When the chart is built, points, borders, connections and axes are
+    /// set to the ink of `style` and the background to its paper. Regression
+    /// lines keep their pattern in ink. Color
+    /// mapping is dropped, as its colors can't be told apart, and density
+    /// maps are dithered.
+    pub fn monochrome(mut self, style: MonochromeStyle<C>) -> Self {
+        self.monochrome = Some(style);
+        self
+    }
+
     /// Build the scatter chart
-    pub fn build(self) -> ChartResult<ScatterChart<C>> {
+    pub fn build(mut self) -> ChartResult<ScatterChart<C>> {
+        if let Some(monochrome) = self.monochrome {
+            let point_style = &mut self.style.point_style;
+            point_style.color = monochrome.ink;
+            if let Some(border) = point_style.border.as_mut() {
+                border.color = monochrome.ink;
+            }
+            self.style.color_mapping = None;
+            if let Some(density) = self.style.density.as_mut() {
+                density.shading = DensityShading::Dither(monochrome.ink);
+            }
+            if let Some(connections) = self.style.connection_style.as_mut() {
+                connections.color = monochrome.ink;
+            }
+            if let Some(regression) = self.style.regression.as_mut() {
+                regression.line.color = monochrome.ink;
+            }
+            self.config.background_color = Some(monochrome.paper);
+            for axis in [&mut self.x_axis, &mut self.y_axis] {
+                *axis = axis
+                    .take()
+                    .map(|axis| axis.with_style(monochrome.axis_style()));
+            }
+        }
+
         Ok(ScatterChart {
             style: self.style,
             config: self.config,
@@ -1076,4 +1494,194 @@ mod tests {
             CollisionStrategy::Offset
         );
     }
+
+    #[test]
+    fn test_bubbles_stay_inside_viewport() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart = ScatterChart::<Rgb565>::builder()
+            .with_size_mapping(SizeMapping {
+                min_size: 4,
+                max_size: 20,
+                scaling: SizeScaling::Linear,
+            })
+            .margins(Margins::all(0))
+            .build()
+            .unwrap();
+        assert_eq!(chart.point_padding(), 10);
+
+        let data = crate::data::series::StaticDataSeries::from_tuples(&[(0.0, 0.0), (10.0, 10.0)])
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+
+        // MockDisplay panics on drawing outside its 64x64 area
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        let area = display.affected_area();
+        assert!(area.top_left.x >= 0 && area.top_left.y >= 0);
+    }
+
+    #[test]
+    fn test_jitter_stays_in_slot() {
+        let jitter = JitterSettings {
+            slot_width: 1.0,
+            spread: 0.5,
+            seed: 7,
+        };
+        let offsets: Vec<f32, 16> = (0..16).map(|index| jitter.offset(index)).collect();
+        assert!(offsets.iter().all(|offset| offset.abs() <= 0.25));
+        assert!(offsets.windows(2).any(|pair| pair[0] != pair[1]));
+        assert_eq!(offsets[3], jitter.offset(3));
+
+        // Stacked samples of one category land on different columns
+        let chart = ScatterChart::<Rgb565>::builder()
+            .with_jitter(jitter)
+            .margins(Margins::all(0))
+            .build()
+            .unwrap();
+        let data =
+            crate::data::series::StaticDataSeries::<crate::data::Point2D, 8>::from_tuples(&[
+                (0.0, 1.0),
+                (1.0, 2.0),
+                (1.0, 3.0),
+                (2.0, 4.0),
+            ])
+            .unwrap();
+        let bounds = data.bounds().unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let columns: Vec<i32, 4> = data
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                chart
+                    .transform_point(&point, jitter.offset(index), &bounds, viewport)
+                    .x
+            })
+            .collect();
+        assert_ne!(columns[1], columns[2]);
+        assert!((columns[1] - 31).abs() <= 16 && (columns[2] - 31).abs() <= 16);
+    }
+
+    #[test]
+    fn test_density_switches_automatically() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let chart: ScatterChart<Rgb565> = ScatterChart::builder()
+            .point_color(Rgb565::BLUE)
+            .with_density(
+                DensitySettings::colors(&[Rgb565::GREEN, Rgb565::RED])
+                    .unwrap()
+                    .auto(),
+            )
+            .build()
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let has = |display: &MockDisplay<Rgb565>, color| {
+            display
+                .bounding_box()
+                .points()
+                .any(|point| display.get_pixel(point) == Some(color))
+        };
+
+        // A few points are drawn as markers
+        let few = [Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0)];
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        chart
+            .draw_points(&few, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert!(has(&display, Rgb565::BLUE));
+        assert!(!has(&display, Rgb565::GREEN));
+
+        // Too many to draw apart, so they are binned; the crowded cell is densest
+        let many: Vec<Point2D, 1000> = (0..1000)
+            .map(|i| match i {
+                0..900 => Point2D::new(5.0, 5.0),
+                _ => Point2D::new((i - 900) as f32 / 10.0, (i - 900) as f32 / 10.0),
+            })
+            .collect();
+        let mut display = MockDisplay::<Rgb565>::new();
+        chart
+            .draw_points(&many, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert!(has(&display, Rgb565::RED));
+        assert!(has(&display, Rgb565::GREEN));
+        assert!(!has(&display, Rgb565::BLUE));
+    }
+
+    #[test]
+    fn test_density_dither_follows_count() {
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        let chart: ScatterChart<BinaryColor> = ScatterChart::builder()
+            .margins(Margins::all(0))
+            .with_density(DensitySettings::dither(BinaryColor::On).cell_size(4))
+            .build()
+            .unwrap();
+        let mut points: Vec<Point2D, 17> = Vec::new();
+        for _ in 0..16 {
+            points.push(Point2D::new(0.0, 0.0)).unwrap();
+        }
+        points.push(Point2D::new(10.0, 10.0)).unwrap();
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_points(&points, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The full cell is solid, the single point shows one pixel
+        let lit = display
+            .bounding_box()
+            .points()
+            .filter(|&point| display.get_pixel(point) == Some(BinaryColor::On))
+            .count();
+        assert_eq!(lit, 17);
+    }
+
+    #[test]
+    fn test_regression_overlay() {
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let points = [
+            Point2D::new(0.0, 1.0),
+            Point2D::new(1.0, 2.9),
+            Point2D::new(2.0, 5.1),
+            Point2D::new(3.0, 7.0),
+        ];
+        let plain: ScatterChart<Rgb565> = ScatterChart::builder().build().unwrap();
+        assert!(plain.regression(&points).is_none());
+
+        let chart: ScatterChart<Rgb565> = ScatterChart::builder()
+            .point_color(Rgb565::BLUE)
+            .with_regression(RegressionOverlay::linear(Rgb565::RED))
+            .build()
+            .unwrap();
+        let fit = chart.regression(&points).unwrap();
+        assert!((fit.slope_at(0.0) - 2.0).abs() < 0.05);
+        assert!((fit.value_at(0.0) - 0.95).abs() < 0.05);
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw_points(&points, chart.config(), viewport, &mut display)
+            .unwrap();
+
+        // The line rises from the bottom left to the top right of the chart area
+        let chart_area = chart.config().margins.apply_to(viewport);
+        let red: Vec<Point, 256> = chart_area
+            .points()
+            .filter(|&point| display.get_pixel(point) == Some(Rgb565::RED))
+            .collect();
+        let (first, last) = (red.first().unwrap(), red.last().unwrap());
+        assert!(first.y < last.y && first.x > last.x);
+        assert!(red.len() > chart_area.size.width as usize / 2);
+    }
 }
@@ -5,17 +5,21 @@
 
 #[cfg(feature = "animations")]
 use crate::animation::Interpolatable;
+use crate::axes::traits::Axis;
 #[cfg(feature = "animations")]
 use crate::chart::traits::AnimatedChart;
-use crate::chart::traits::{Chart, ChartConfig, Margins};
+use crate::chart::traits::{AxisChart, BackgroundMode, Chart, ChartConfig, Margins};
 use crate::data::{DataPoint, DataSeries};
 use crate::error::{ChartError, ChartResult};
-use crate::math::{Math, NumericConversion};
+use crate::render::{ChartDrawTarget, FallbackTarget, SpanRasterizer};
+use crate::style::NumberLocale;
 use embedded_graphics::{
     draw_target::DrawTarget,
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
     pixelcolor::Rgb565,
     prelude::*,
     primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 
 /// Multi-layer data structure for stacked charts
@@ -27,6 +31,10 @@ pub struct StackedData<T: Copy + Clone + DataPoint, const N: usize> {
     labels: heapless::Vec<heapless::String<32>, 8>,
     /// Colors for each layer
     colors: heapless::Vec<Rgb565, 8>,
+    /// Visibility flag for each layer
+    visible: heapless::Vec<bool, 8>,
+    /// Layer indices in stacking order, bottom first
+    order: heapless::Vec<usize, 8>,
 }
 
 impl<T: Copy + Clone + DataPoint, const N: usize> StackedData<T, N> {
@@ -36,6 +44,8 @@ impl<T: Copy + Clone + DataPoint, const N: usize> StackedData<T, N> {
             layers: heapless::Vec::new(),
             labels: heapless::Vec::new(),
             colors: heapless::Vec::new(),
+            visible: heapless::Vec::new(),
+            order: heapless::Vec::new(),
         }
     }
 
@@ -53,6 +63,9 @@ impl<T: Copy + Clone + DataPoint, const N: usize> StackedData<T, N> {
         self.colors
             .push(color)
             .map_err(|_| ChartError::MemoryFull)?;
+        // Capacities match the layer vector, so these cannot fail once the layer fit
+        let _ = self.visible.push(true);
+        let _ = self.order.push(self.layers.len() - 1);
         Ok(())
     }
 
@@ -76,7 +89,73 @@ impl<T: Copy + Clone + DataPoint, const N: usize> StackedData<T, N> {
         self.colors.get(index).copied()
     }
 
+    /// Show or hide a layer
+    ///
+    /// Hidden layers keep their data but add nothing to the stack, so the
+    /// layers above them move down.
+    pub fn set_layer_visible(&mut self, index: usize, visible: bool) -> ChartResult<()> {
+        let flag = self
+            .visible
+            .get_mut(index)
+            .ok_or(ChartError::InvalidConfiguration)?;
+        *flag = visible;
+        Ok(())
+    }
+
+    /// Check if a layer is visible; unknown layers are not
+    pub fn is_layer_visible(&self, index: usize) -> bool {
+        self.visible.get(index).copied().unwrap_or(false)
+    }
+
+    /// Set the stacking order as layer indices, bottom layer first
+    ///
+    /// The order must name every layer exactly once; layers are drawn and
+    /// accumulated in this order.
+    pub fn set_layer_order(&mut self, order: &[usize]) -> ChartResult<()> {
+        let count = self.layers.len();
+        let mut seen = [false; 8];
+        if order.len() != count {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        for &index in order {
+            if index >= count || seen[index] {
+                return Err(ChartError::InvalidConfiguration);
+            }
+            seen[index] = true;
+        }
+
+        self.order.clear();
+        for &index in order {
+            let _ = self.order.push(index);
+        }
+        Ok(())
+    }
+
+    /// Get the stacking order as layer indices, bottom layer first
+    pub fn layer_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Iterate over the visible layer indices in stacking order, bottom first
+    pub fn visible_layers(&self) -> impl Iterator<Item = usize> + '_ {
+        self.order
+            .iter()
+            .copied()
+            .filter(move |&index| self.is_layer_visible(index))
+    }
+
+    /// Copy visibility and stacking order from another data set with the same layers
+    fn copy_layer_state(&mut self, other: &Self) {
+        if other.layer_count() == self.layer_count() {
+            self.visible = other.visible.clone();
+            self.order = other.order.clone();
+        }
+    }
+
     /// Calculate cumulative values for stacking
+    ///
+    /// The result is indexed by layer. Layers are accumulated in stacking
+    /// order and hidden layers contribute nothing.
     pub fn calculate_cumulative(&self) -> ChartResult<heapless::Vec<heapless::Vec<T::Y, N>, 8>>
     where
         T::Y: Copy + Clone + core::ops::Add<Output = T::Y> + Default,
@@ -96,15 +175,24 @@ impl<T: Copy + Clone + DataPoint, const N: usize> StackedData<T, N> {
             }
         }
 
-        // Calculate cumulative values for each layer
+        // Calculate cumulative values for each layer, indexed by layer
         for layer_idx in 0..self.layers.len() {
             let mut cumulative_values = heapless::Vec::new();
+            let position = self
+                .order
+                .iter()
+                .position(|&index| index == layer_idx)
+                .unwrap_or(layer_idx);
 
             for point_idx in 0..data_length {
                 let mut cumulative_y = T::Y::default();
 
-                // Sum up all values from bottom to current layer
-                for bottom_layer_idx in 0..=layer_idx {
+                // Sum up the visible layers stacked up to and including this one;
+                // a hidden layer ends where the visible stack below it ends
+                for &bottom_layer_idx in &self.order[..=position] {
+                    if !self.is_layer_visible(bottom_layer_idx) {
+                        continue;
+                    }
                     if let Some(point) = self.layers[bottom_layer_idx].get(point_idx) {
                         cumulative_y = cumulative_y + point.y();
                     }
@@ -164,6 +252,7 @@ where
                 let _ = result.add_layer(interpolated_layer, label, color);
             }
         }
+        result.copy_layer_state(&other);
 
         Some(result)
     }
@@ -212,8 +301,73 @@ pub struct AnimatedStackedBarChart<C: PixelColor> {
     bar_width: StackedBarWidth,
     /// Spacing between bars
     spacing: u32,
+    /// Upper limit for the bar width in pixels
+    max_bar_width: Option<u32>,
     /// Frame rate for animations
     frame_rate: u32,
+    /// Optional X-axis
+    x_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    /// Optional Y-axis, which also fixes the value range of the bars
+    y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    /// Optional total and segment value labels
+    labels: Option<StackedBarLabels<C>>,
+}
+
+/// Value label configuration for stacked bars
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StackedBarLabels<C: PixelColor> {
+    /// Show the stack total above each bar
+    pub show_totals: bool,
+    /// Show each segment's value centered inside the segment
+    pub show_segments: bool,
+    /// Minimum segment height in pixels for its label to be drawn
+    pub min_segment_height: u32,
+    /// Color of the total labels
+    pub total_color: C,
+    /// Color of the segment labels
+    pub segment_color: C,
+    /// Decimal separator and digit grouping for the values
+    pub locale: NumberLocale,
+}
+
+impl<C: PixelColor> Default for StackedBarLabels<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            show_totals: true,
+            show_segments: false,
+            min_segment_height: 12,
+            total_color: Rgb565::BLACK.into(),
+            segment_color: Rgb565::WHITE.into(),
+            locale: NumberLocale::PLAIN,
+        }
+    }
+}
+
+/// Horizontal placement of the bars within the plot area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackedBarLayout {
+    /// Width of each bar in pixels
+    pub bar_width: u32,
+    /// Gap between neighbouring bars in pixels
+    pub gap: u32,
+    /// X coordinate of the left edge of the first bar
+    pub start_x: i32,
+}
+
+impl StackedBarLayout {
+    /// X coordinate of the left edge of the bar at `index`
+    pub fn bar_x(&self, index: usize) -> i32 {
+        self.start_x + (index as u32 * (self.bar_width + self.gap)) as i32
+    }
+
+    /// Total width covered by `bar_count` bars and the gaps between them
+    pub fn total_width(&self, bar_count: usize) -> u32 {
+        let count = bar_count as u32;
+        count * self.bar_width + count.saturating_sub(1) * self.gap
+    }
 }
 
 /// Bar width configuration for stacked charts
@@ -238,7 +392,11 @@ where
             config: ChartConfig::default(),
             bar_width: StackedBarWidth::Auto,
             spacing: 5,
+            max_bar_width: None,
             frame_rate: 60,
+            x_axis: None,
+            y_axis: None,
+            labels: None,
         }
     }
 
@@ -257,26 +415,89 @@ where
         self.spacing = spacing;
     }
 
+    /// Limit the bar width, e.g. to keep a few bars from filling the whole area
+    pub fn set_max_bar_width(&mut self, max_width: Option<u32>) {
+        self.max_bar_width = max_width;
+    }
+
     /// Set the frame rate for animations
     pub fn set_frame_rate(&mut self, fps: u32) {
         self.frame_rate = fps.clamp(1, 120);
     }
 
-    /// Calculate the actual bar width based on configuration and available space
-    fn calculate_bar_width(&self, available_width: u32, bar_count: usize) -> u32 {
-        match self.bar_width {
-            StackedBarWidth::Auto => {
-                if bar_count == 0 {
-                    return 0;
-                }
-                // Simple auto-sizing without spacing
-                available_width / (bar_count as u32).max(1)
-            }
-            StackedBarWidth::Fixed(width) => width,
-            StackedBarWidth::Percentage(pct) => {
-                ((available_width as f32) * pct.clamp(0.0, 1.0)) as u32
-            }
+    /// Set or clear the value labels
+    pub fn set_labels(&mut self, labels: Option<StackedBarLabels<C>>) {
+        self.labels = labels;
+    }
+
+    /// Get the value label configuration
+    pub fn labels(&self) -> Option<&StackedBarLabels<C>> {
+        self.labels.as_ref()
+    }
+
+    /// Get the value range covered by the stacks of `data`.
+    ///
+    /// The range starts at zero and ends at the largest stack total, so a
+    /// Y-axis built from it lines up with the bars.
+    pub fn value_range(
+        &self,
+        data: &StackedData<crate::data::point::Point2D, 256>,
+    ) -> ChartResult<(f32, f32)> {
+        let cumulative = data.calculate_cumulative()?;
+        let max_total = cumulative
+            .iter()
+            .flat_map(|layer| layer.iter())
+            .fold(0.0f32, |acc, &val| acc.max(val));
+        Ok((0.0, max_total))
+    }
+
+    /// Calculate the bar layout for `bar_count` bars inside `viewport`.
+    ///
+    /// The chart margins are removed first. The bars and the gaps between them
+    /// always fit the remaining width: if the configured spacing leaves no
+    /// room, the gap shrinks so every bar keeps at least one pixel, and bars
+    /// that would overflow the area are narrowed. The result is centered.
+    pub fn layout(&self, viewport: Rectangle, bar_count: usize) -> StackedBarLayout {
+        self.layout_in(self.config.margins.apply_to(viewport), bar_count)
+    }
+
+    /// Calculate the bar layout inside an area that already excludes margins
+    fn layout_in(&self, draw_area: Rectangle, bar_count: usize) -> StackedBarLayout {
+        let available = draw_area.size.width;
+        let count = bar_count as u32;
+        if count == 0 || available == 0 {
+            return StackedBarLayout {
+                bar_width: 0,
+                gap: 0,
+                start_x: draw_area.top_left.x,
+            };
         }
+
+        // Shrink the gap until every bar gets at least one pixel
+        let gaps = count - 1;
+        let gap = match gaps {
+            0 => 0,
+            _ => self.spacing.min(available.saturating_sub(count) / gaps),
+        };
+        let max_fit = (available - gap * gaps) / count;
+
+        let requested = match self.bar_width {
+            StackedBarWidth::Auto => max_fit,
+            StackedBarWidth::Fixed(width) => width,
+            StackedBarWidth::Percentage(pct) => ((max_fit as f32) * pct.clamp(0.0, 1.0)) as u32,
+        };
+        let bar_width = requested
+            .min(self.max_bar_width.unwrap_or(u32::MAX))
+            .min(max_fit);
+
+        let mut layout = StackedBarLayout {
+            bar_width,
+            gap,
+            start_x: 0,
+        };
+        layout.start_x =
+            draw_area.top_left.x + ((available - layout.total_width(bar_count)) / 2) as i32;
+        layout
     }
 
     /// Interpolate between two stacked data sets based on animation progress
@@ -324,6 +545,7 @@ where
                 result.add_layer(interpolated_layer, label, color)?;
             }
         }
+        result.copy_layer_state(to_data);
 
         Ok(result)
     }
@@ -390,6 +612,8 @@ where
             return Ok(());
         }
 
+        config.draw_background(viewport, target)?;
+
         // Calculate drawing area with margins
         let draw_area = config.margins.apply_to(viewport);
 
@@ -404,40 +628,51 @@ where
         // Calculate cumulative values for stacking
         let cumulative_values = data.calculate_cumulative()?;
 
-        // Find the maximum total value for scaling
-        let max_total = cumulative_values
-            .last()
-            .map(|last_layer| last_layer.iter().fold(0.0f32, |acc, &val| acc.max(val)))
-            .unwrap_or(1.0);
+        // The Y-axis range takes precedence over the data range for scaling
+        let (min_value, max_value) = match self.y_axis {
+            Some(ref y_axis) => (y_axis.min(), y_axis.max()),
+            None => self.value_range(data)?,
+        };
+        let span = if max_value > min_value {
+            max_value - min_value
+        } else {
+            1.0
+        };
+
+        // Grid and zero lines go behind the bars
+        if let Some(ref x_axis) = self.x_axis {
+            x_axis.draw_grid_lines(draw_area, draw_area, target)?;
+            x_axis.draw_zero_line(draw_area, draw_area, target)?;
+        }
+        if let Some(ref y_axis) = self.y_axis {
+            y_axis.draw_grid_lines(draw_area, draw_area, target)?;
+            y_axis.draw_zero_line(draw_area, draw_area, target)?;
+        }
 
         // Calculate bar dimensions
-        let bar_width = self.calculate_bar_width(draw_area.size.width, data_point_count);
-        let total_bar_space = bar_width * data_point_count as u32;
-        let total_spacing = self.spacing * (data_point_count.saturating_sub(1) as u32);
-        let start_x = draw_area.top_left.x
-            + ((draw_area
-                .size
-                .width
-                .saturating_sub(total_bar_space + total_spacing))
-                / 2) as i32;
+        let layout = self.layout_in(draw_area, data_point_count);
+        let bar_width = layout.bar_width;
 
         // Draw stacked bars for each data point
         for point_idx in 0..data_point_count {
-            let bar_x = start_x + (point_idx as u32 * (bar_width + self.spacing)) as i32;
+            let bar_x = layout.bar_x(point_idx);
             let base_y = draw_area.top_left.y + draw_area.size.height as i32;
+            let value_to_y = |value: f32| {
+                let normalized = ((value - min_value) / span).clamp(0.0, 1.0);
+                base_y - (normalized * (draw_area.size.height as f32 - 1.0)) as i32
+            };
 
             // Draw segments from bottom to top
-            let mut current_bottom = base_y;
+            let mut current_bottom = value_to_y(0.0);
+            let mut previous_value = 0.0f32;
 
-            for layer_idx in 0..data.layer_count() {
+            for layer_idx in data.visible_layers() {
                 if let Some(cumulative_layer) = cumulative_values.get(layer_idx) {
                     if let Some(&cumulative_value) = cumulative_layer.get(point_idx) {
                         let cumulative_f32: f32 = cumulative_value;
 
                         // Calculate segment height
-                        let segment_top_y = base_y
-                            - ((cumulative_f32 / max_total) * (draw_area.size.height as f32 - 1.0))
-                                as i32;
+                        let segment_top_y = value_to_y(cumulative_f32);
 
                         // Only draw if there's a visible height
                         if current_bottom > segment_top_y {
@@ -456,15 +691,105 @@ where
                                     )
                                 })?;
 
+                            if let Some(ref labels) = self.labels {
+                                if labels.show_segments
+                                    && segment_rect.size.height >= labels.min_segment_height
+                                {
+                                    self.draw_value_label(
+                                        &labels
+                                            .locale
+                                            .format::<16>(cumulative_f32 - previous_value, 0),
+                                        segment_rect.center(),
+                                        Baseline::Middle,
+                                        labels.segment_color,
+                                        target,
+                                    )?;
+                                }
+                            }
+
                             current_bottom = segment_top_y;
                         }
+                        previous_value = cumulative_f32;
                     }
                 }
             }
+
+            if let Some(ref labels) = self.labels {
+                if labels.show_totals {
+                    let anchor = Point::new(bar_x + bar_width as i32 / 2, current_bottom - 2);
+                    self.draw_value_label(
+                        &labels.locale.format::<16>(previous_value, 0),
+                        anchor,
+                        Baseline::Bottom,
+                        labels.total_color,
+                        target,
+                    )?;
+                }
+            }
+        }
+
+        // Axis lines, ticks and labels go on top
+        if let Some(ref x_axis) = self.x_axis {
+            x_axis.draw_axis_only(draw_area, target)?;
+        }
+        if let Some(ref y_axis) = self.y_axis {
+            y_axis.draw_axis_only(draw_area, target)?;
         }
 
         Ok(())
     }
+
+    /// Draw a value label centered horizontally on `anchor`
+    fn draw_value_label<D>(
+        &self,
+        text: &str,
+        anchor: Point,
+        baseline: Baseline,
+        color: C,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(baseline)
+            .build();
+        Text::with_text_style(
+            text,
+            anchor,
+            MonoTextStyle::new(&FONT_6X10, color),
+            text_style,
+        )
+        .draw(target)
+        .map_err(|_| ChartError::RenderingError)?;
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> AxisChart<C> for AnimatedStackedBarChart<C>
+where
+    C: From<Rgb565>,
+{
+    type XAxis = crate::axes::LinearAxis<f32, C>;
+    type YAxis = crate::axes::LinearAxis<f32, C>;
+
+    fn set_x_axis(&mut self, axis: crate::axes::LinearAxis<f32, C>) {
+        self.x_axis = Some(axis);
+    }
+
+    fn set_y_axis(&mut self, axis: crate::axes::LinearAxis<f32, C>) {
+        self.y_axis = Some(axis);
+    }
+
+    fn x_axis(&self) -> ChartResult<&crate::axes::LinearAxis<f32, C>> {
+        self.x_axis.as_ref().ok_or(ChartError::InvalidConfiguration)
+    }
+
+    fn y_axis(&self) -> ChartResult<&crate::axes::LinearAxis<f32, C>> {
+        self.y_axis.as_ref().ok_or(ChartError::InvalidConfiguration)
+    }
 }
 
 #[cfg(feature = "animations")]
@@ -509,8 +834,12 @@ where
 pub struct AnimatedStackedBarChartBuilder<C: PixelColor> {
     bar_width: StackedBarWidth,
     spacing: u32,
+    max_bar_width: Option<u32>,
     frame_rate: u32,
     config: ChartConfig<C>,
+    x_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    y_axis: Option<crate::axes::LinearAxis<f32, C>>,
+    labels: Option<StackedBarLabels<C>>,
 }
 
 impl<C: PixelColor> AnimatedStackedBarChartBuilder<C>
@@ -522,8 +851,12 @@ where
         Self {
             bar_width: StackedBarWidth::Auto,
             spacing: 5,
+            max_bar_width: None,
             frame_rate: 60,
             config: ChartConfig::default(),
+            x_axis: None,
+            y_axis: None,
+            labels: None,
         }
     }
 
@@ -539,6 +872,12 @@ where
         self
     }
 
+    /// Limit the bar width in pixels
+    pub fn max_bar_width(mut self, max_width: u32) -> Self {
+        self.max_bar_width = Some(max_width);
+        self
+    }
+
     /// Set the frame rate
     pub fn frame_rate(mut self, fps: u32) -> Self {
         self.frame_rate = fps;
@@ -557,19 +896,47 @@ where
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
     /// Set the margins
     pub fn margins(mut self, margins: Margins) -> Self {
         self.config.margins = margins;
         self
     }
 
+    /// Add an X-axis
+    pub fn with_x_axis(mut self, axis: crate::axes::LinearAxis<f32, C>) -> Self {
+        self.x_axis = Some(axis);
+        self
+    }
+
+    /// Add a Y-axis; its range sets the value range of the bars
+    pub fn with_y_axis(mut self, axis: crate::axes::LinearAxis<f32, C>) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// Show total and segment value labels
+    pub fn with_labels(mut self, labels: StackedBarLabels<C>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
     /// Build the animated stacked bar chart
     pub fn build(self) -> ChartResult<AnimatedStackedBarChart<C>> {
         let mut chart = AnimatedStackedBarChart::new();
         chart.set_bar_width(self.bar_width);
         chart.set_spacing(self.spacing);
+        chart.set_max_bar_width(self.max_bar_width);
         chart.set_frame_rate(self.frame_rate);
+        chart.set_labels(self.labels);
         chart.config = self.config;
+        chart.x_axis = self.x_axis;
+        chart.y_axis = self.y_axis;
         Ok(chart)
     }
 }
@@ -669,6 +1036,19 @@ where
     ) -> ChartResult<()>
     where
         D: DrawTarget<Color = C>,
+    {
+        self.draw_accelerated(data, config, viewport, &mut FallbackTarget::new(target))
+    }
+
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
     {
         // Use animated data if available, otherwise use provided data
         let render_data = if self.current_data.is_some() {
@@ -694,12 +1074,14 @@ where
         target: &mut D,
     ) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
         if data.layer_count() == 0 {
             return Ok(());
         }
 
+        config.draw_background(viewport, target)?;
+
         // Calculate drawing area with margins
         let draw_area = config.margins.apply_to(viewport);
 
@@ -716,9 +1098,10 @@ where
 
         // Find the maximum total value for scaling
         let max_total = cumulative_values
-            .last()
-            .map(|last_layer| last_layer.iter().fold(0.0f32, |acc, &val| acc.max(val)))
-            .unwrap_or(1.0);
+            .iter()
+            .flat_map(|layer| layer.iter())
+            .fold(0.0f32, |acc, &val| acc.max(val));
+        let max_total = if max_total > 0.0 { max_total } else { 1.0 };
 
         // Convert cumulative data to screen coordinates for each layer
         let mut screen_points = heapless::Vec::<heapless::Vec<Point, 256>, 8>::new();
@@ -750,14 +1133,21 @@ where
             }
         }
 
+        // Visible layers in stacking order, bottom first
+        let mut visible: heapless::Vec<usize, 8> = heapless::Vec::new();
+        for layer_idx in data.visible_layers() {
+            let _ = visible.push(layer_idx);
+        }
+
         // Draw stacked areas from top to bottom
-        for layer_idx in (0..data.layer_count()).rev() {
+        for position in (0..visible.len()).rev() {
+            let layer_idx = visible[position];
             if let Some(current_layer_points) = screen_points.get(layer_idx) {
                 let color = data.color(layer_idx).unwrap_or(Rgb565::BLUE);
 
-                // Get the bottom boundary (previous layer or baseline)
-                if layer_idx > 0 {
-                    if let Some(bottom_layer_points) = screen_points.get(layer_idx - 1) {
+                // Get the bottom boundary (previous visible layer or baseline)
+                if position > 0 {
+                    if let Some(bottom_layer_points) = screen_points.get(visible[position - 1]) {
                         // Draw the area between current layer and bottom layer
                         self.draw_area_between_curves(
                             current_layer_points,
@@ -798,7 +1188,7 @@ where
         Ok(())
     }
 
-    /// Draw area between two curves using scan-line filling
+    /// Draw area between two curves using span filling
     fn draw_area_between_curves<D>(
         &self,
         top_curve: &[Point],
@@ -807,135 +1197,27 @@ where
         target: &mut D,
     ) -> ChartResult<()>
     where
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
         if top_curve.len() != bottom_curve.len() || top_curve.is_empty() {
             return Ok(());
         }
 
-        // For each segment between consecutive points
+        let raster = SpanRasterizer::for_target(target);
+
+        // Fill the quadrilateral between each pair of consecutive points
         for i in 0..top_curve.len() - 1 {
             let top_start = top_curve[i];
             let top_end = top_curve[i + 1];
             let bottom_start = bottom_curve[i];
             let bottom_end = bottom_curve[i + 1];
 
-            // Draw filled quadrilateral using scan lines
-            self.draw_filled_quad(top_start, top_end, bottom_end, bottom_start, color, target)?;
-        }
-
-        Ok(())
-    }
-
-    /// Draw a filled quadrilateral using horizontal scan lines
-    fn draw_filled_quad<D>(
-        &self,
-        p1: Point,
-        p2: Point,
-        p3: Point,
-        p4: Point,
-        color: C,
-        target: &mut D,
-    ) -> ChartResult<()>
-    where
-        D: DrawTarget<Color = C>,
-    {
-        // Split the quadrilateral into two triangles and fill them
-        // Triangle 1: p1, p2, p3
-        self.draw_filled_triangle(p1, p2, p3, color, target)?;
-        // Triangle 2: p1, p3, p4
-        self.draw_filled_triangle(p1, p3, p4, color, target)?;
-
-        Ok(())
-    }
-
-    /// Draw a filled triangle using scan line algorithm
-    fn draw_filled_triangle<D>(
-        &self,
-        p1: Point,
-        p2: Point,
-        p3: Point,
-        color: C,
-        target: &mut D,
-    ) -> ChartResult<()>
-    where
-        D: DrawTarget<Color = C>,
-    {
-        // Find bounding box
-        let min_x = p1.x.min(p2.x).min(p3.x);
-        let max_x = p1.x.max(p2.x).max(p3.x);
-        let min_y = p1.y.min(p2.y).min(p3.y);
-        let max_y = p1.y.max(p2.y).max(p3.y);
-
-        // For each horizontal scan line
-        for y in min_y..=max_y {
-            let mut intersections = heapless::Vec::<i32, 8>::new();
-
-            // Check intersection with each edge of the triangle
-            let edges = [(p1, p2), (p2, p3), (p3, p1)];
-            for (start, end) in edges.iter() {
-                if let Some(x) = self.line_intersection_x(*start, *end, y) {
-                    if x >= min_x && x <= max_x {
-                        intersections.push(x).ok(); // Ignore if buffer is full
-                    }
-                }
-            }
-
-            // Remove duplicates and sort
-            intersections.sort();
-
-            // Manual deduplication for heapless::Vec
-            let mut unique_intersections = heapless::Vec::<i32, 8>::new();
-            let mut last_value: Option<i32> = None;
-            for &value in &intersections {
-                if last_value != Some(value) {
-                    unique_intersections.push(value).ok(); // Ignore if buffer is full
-                    last_value = Some(value);
-                }
-            }
-            let intersections = unique_intersections;
-
-            // Draw horizontal line between the two intersection points
-            if intersections.len() >= 2 {
-                let start_x = intersections[0];
-                let end_x = intersections[intersections.len() - 1];
-                if start_x != end_x {
-                    let rect = Rectangle::new(
-                        Point::new(start_x, y),
-                        Size::new((end_x - start_x) as u32, 1),
-                    );
-                    rect.into_styled(PrimitiveStyle::with_fill(color))
-                        .draw(target)
-                        .map_err(|_| {
-                            ChartError::RenderError(crate::error::RenderError::DrawingFailed)
-                        })?;
-                }
-            }
+            raster.fill_quad(top_start, top_end, bottom_end, bottom_start, color, target)?;
         }
 
         Ok(())
     }
 
-    /// Find x-coordinate where a line segment intersects a horizontal line at y
-    fn line_intersection_x(&self, start: Point, end: Point, y: i32) -> Option<i32> {
-        if start.y == end.y {
-            // Horizontal line - no single intersection point
-            return None;
-        }
-
-        if (start.y <= y && y <= end.y) || (end.y <= y && y <= start.y) {
-            // Linear interpolation
-            let t = (y - start.y) as f32 / (end.y - start.y) as f32;
-            let x = start.x as f32 + t * (end.x - start.x) as f32;
-            let x_num = x.to_number();
-            let half = 0.5f32.to_number();
-            let rounded = Math::floor(x_num + half);
-            Some(f32::from_number(rounded) as i32)
-        } else {
-            None
-        }
-    }
-
     /// Draw the outline for a layer
     fn draw_layer_outline<D>(&self, points: &[Point], color: C, target: &mut D) -> ChartResult<()>
     where
@@ -973,7 +1255,7 @@ where
         D: embedded_graphics::draw_target::DrawTarget<Color = C>,
     {
         // Use the provided data which should already be interpolated by the caller
-        self.draw_stacked_areas(data, config, viewport, target)
+        self.draw_stacked_areas(data, config, viewport, &mut FallbackTarget::new(target))
     }
 
     fn create_transition_animator(
@@ -1044,6 +1326,12 @@ where
         self
     }
 
+    /// Set how the background color is painted
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.config.background_mode = mode;
+        self
+    }
+
     /// Set the margins
     pub fn margins(mut self, margins: Margins) -> Self {
         self.config.margins = margins;
@@ -1124,12 +1412,90 @@ mod tests {
         assert_eq!(cumulative[1][1], 23.0); // 15 + 8
     }
 
+    #[test]
+    fn test_hidden_layers_and_stacking_order() {
+        let mut stacked_data = StackedData::<Point2D, 10>::new();
+        for (value, color) in [
+            (10.0, Rgb565::BLUE),
+            (5.0, Rgb565::RED),
+            (2.0, Rgb565::GREEN),
+        ] {
+            let mut layer = StaticDataSeries::new();
+            layer.push(Point2D::new(0.0, value)).unwrap();
+            stacked_data.add_layer(layer, "Layer", color).unwrap();
+        }
+        assert_eq!(stacked_data.layer_order(), &[0, 1, 2]);
+
+        // Hiding the middle layer drops the top layer onto the bottom one
+        stacked_data.set_layer_visible(1, false).unwrap();
+        assert!(!stacked_data.is_layer_visible(1));
+        let cumulative = stacked_data.calculate_cumulative().unwrap();
+        assert_eq!(cumulative[0][0], 10.0);
+        assert_eq!(cumulative[1][0], 10.0);
+        assert_eq!(cumulative[2][0], 12.0);
+
+        // Results stay indexed by layer when the order changes
+        stacked_data.set_layer_visible(1, true).unwrap();
+        stacked_data.set_layer_order(&[2, 0, 1]).unwrap();
+        let cumulative = stacked_data.calculate_cumulative().unwrap();
+        assert_eq!(cumulative[2][0], 2.0);
+        assert_eq!(cumulative[0][0], 12.0);
+        assert_eq!(cumulative[1][0], 17.0);
+
+        assert!(stacked_data.set_layer_order(&[0, 0, 1]).is_err());
+        assert!(stacked_data.set_layer_order(&[0, 1]).is_err());
+        assert!(stacked_data.set_layer_visible(3, false).is_err());
+    }
+
     #[test]
     fn test_animated_stacked_bar_chart_creation() {
         let chart = AnimatedStackedBarChart::<Rgb565>::new();
         assert_eq!(chart.frame_rate, 60);
     }
 
+    #[test]
+    fn test_stacked_bars_follow_y_axis_and_show_totals() {
+        use crate::axes::{AxisOrientation, AxisPosition, LinearAxis};
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let mut data = StackedData::<Point2D, 256>::new();
+        let mut bottom = StaticDataSeries::new();
+        bottom.push(Point2D::new(0.0, 10.0)).unwrap();
+        bottom.push(Point2D::new(1.0, 20.0)).unwrap();
+        let mut top = StaticDataSeries::new();
+        top.push(Point2D::new(0.0, 10.0)).unwrap();
+        top.push(Point2D::new(1.0, 10.0)).unwrap();
+        data.add_layer(bottom, "Bottom", Rgb565::CYAN).unwrap();
+        data.add_layer(top, "Top", Rgb565::MAGENTA).unwrap();
+
+        let y_axis = LinearAxis::new(0.0, 40.0, AxisOrientation::Vertical, AxisPosition::Left)
+            .show_labels(false);
+        let chart = AnimatedStackedBarChart::<Rgb565>::builder()
+            .margins(Margins::all(10))
+            .with_y_axis(y_axis)
+            .with_labels(StackedBarLabels {
+                total_color: Rgb565::GREEN,
+                ..StackedBarLabels::default()
+            })
+            .build()
+            .unwrap();
+        assert_eq!(chart.value_range(&data).unwrap(), (0.0, 30.0));
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        chart
+            .draw(&data, &chart.config, viewport, &mut display)
+            .unwrap();
+
+        // A total of 20 on a 0..40 axis reaches halfway up the 44px plot area
+        assert_eq!(display.get_pixel(Point::new(20, 33)), Some(Rgb565::MAGENTA));
+        assert_eq!(display.get_pixel(Point::new(20, 32)), None);
+        let has_total_label = (0..33)
+            .any(|y| (12..30).any(|x| display.get_pixel(Point::new(x, y)) == Some(Rgb565::GREEN)));
+        assert!(has_total_label);
+    }
+
     #[test]
     fn test_animated_stacked_line_chart_creation() {
         let chart = AnimatedStackedLineChart::<Rgb565>::new();
@@ -1138,17 +1504,49 @@ mod tests {
 
     #[test]
     fn test_bar_width_calculation() {
-        let chart = AnimatedStackedBarChart::<Rgb565>::new();
-
-        // Test auto width (simple division, no spacing considered)
-        let width = chart.calculate_bar_width(400, 4);
-        assert_eq!(width, 100); // 400 / 4 = 100
+        let area = Rectangle::new(Point::new(0, 0), Size::new(420, 100));
+        let mut chart = AnimatedStackedBarChart::<Rgb565>::new();
+
+        // Margins of 10 leave 400 pixels, three gaps of 5 take 15 of them
+        let layout = chart.layout(area, 4);
+        assert_eq!(layout.bar_width, 96);
+        assert_eq!(layout.gap, 5);
+        assert_eq!(layout.start_x, 10);
+        assert_eq!(layout.total_width(4), 399);
+
+        // Wider spacing narrows the bars instead of overflowing
+        chart.set_spacing(10);
+        let layout = chart.layout(area, 4);
+        assert_eq!(layout.bar_width, 92);
+        assert_eq!(layout.gap, 10);
+        assert_eq!(layout.start_x, 11);
+
+        // The max width clamp keeps the bars centered
+        chart.set_max_bar_width(Some(40));
+        let layout = chart.layout(area, 4);
+        assert_eq!(layout.bar_width, 40);
+        assert_eq!(layout.start_x, 10 + (400 - 190) / 2);
+    }
 
-        // Test with spacing (spacing doesn't affect auto calculation in current implementation)
-        let mut chart_with_spacing = AnimatedStackedBarChart::<Rgb565>::new();
-        chart_with_spacing.set_spacing(10);
-        let width = chart_with_spacing.calculate_bar_width(400, 4);
-        assert_eq!(width, 100); // 400 / 4 = 100 (spacing not considered in auto mode)
+    #[test]
+    fn test_bar_layout_never_overflows() {
+        let area = Rectangle::new(Point::new(0, 0), Size::new(60, 40));
+        let mut chart = AnimatedStackedBarChart::<Rgb565>::new();
+
+        // Spacing alone would need 3 * 30 pixels of the 40 available
+        chart.set_spacing(30);
+        let layout = chart.layout(area, 4);
+        assert!(layout.bar_width >= 1);
+        assert!(layout.total_width(4) <= 40);
+        assert!(layout.start_x >= 10);
+
+        // Fixed widths are narrowed to fit as well
+        chart.set_spacing(2);
+        chart.set_bar_width(StackedBarWidth::Fixed(50));
+        let layout = chart.layout(area, 4);
+        assert_eq!(layout.bar_width, 8);
+        assert_eq!(layout.bar_x(3) + 8, layout.start_x + 38);
+        assert!(layout.total_width(4) <= 40);
     }
 
     #[test]
@@ -1187,19 +1585,32 @@ mod tests {
 
     #[test]
     fn test_line_intersection() {
-        let chart = AnimatedStackedLineChart::<Rgb565>::new();
+        use embedded_graphics::mock_display::MockDisplay;
 
-        // Test horizontal line intersection
-        let start = Point::new(0, 0);
-        let end = Point::new(10, 10);
-        let y = 5;
+        let chart = AnimatedStackedLineChart::<Rgb565>::new();
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+
+        // The area between the edge from (0, 0) to (10, 10) and the bottom
+        // curve is filled up to where the edge crosses each row
+        let top = [Point::new(0, 0), Point::new(10, 10)];
+        let bottom = [Point::new(0, 10), Point::new(10, 10)];
+        chart
+            .draw_area_between_curves(
+                &top,
+                &bottom,
+                Rgb565::RED,
+                &mut FallbackTarget::new(&mut display),
+            )
+            .unwrap();
 
-        let intersection = chart.line_intersection_x(start, end, y);
-        assert_eq!(intersection, Some(5));
+        assert_eq!(display.get_pixel(Point::new(5, 5)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(6, 5)), None);
 
-        // Test no intersection
-        let y_outside = 15;
-        let no_intersection = chart.line_intersection_x(start, end, y_outside);
-        assert_eq!(no_intersection, None);
+        // Rows the edge doesn't cross stay empty
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::zero(), Size::new(11, 11))
+        );
     }
 }
@@ -0,0 +1,195 @@
+//! Charts that own their data.
+//!
+//! Fixed charts such as reference or calibration curves never change after they are
+//! created. Attaching the data at build time with
+//! [`ChartBuilder::with_static_data`] keeps the chart and its data together and
+//! reduces drawing to a single [`StaticChart::draw_owned`] call, which makes such
+//! charts convenient as overlays drawn beneath live data.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "line")]
+//! # {
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let calibration = data_points![(0.0, 0.0), (50.0, 48.0), (100.0, 101.0)];
+//!
+//! let reference = LineChart::builder()
+//!     .line_color(Rgb565::BLUE)
+//!     .with_static_data(calibration)
+//!     .build()?;
+//!
+//! let viewport = Rectangle::new(Point::zero(), Size::new(320, 240));
+//! // reference.draw_owned(viewport, &mut display)?;
+//! # }
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::{Chart, ChartBuilder, ConfiguredChart};
+use crate::error::ChartResult;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// A chart bundled with the data it renders.
+pub struct StaticChart<C: PixelColor, T: Chart<C>> {
+    /// The wrapped chart
+    chart: T,
+    /// Data owned by the chart
+    data: T::Data,
+    _color: core::marker::PhantomData<C>,
+}
+
+impl<C: PixelColor, T: Chart<C>> StaticChart<C, T> {
+    /// Create a chart that owns the given data
+    ///
+    /// # Arguments
+    /// * `chart` - The chart used for rendering
+    /// * `data` - The data to render
+    pub fn new(chart: T, data: T::Data) -> Self {
+        Self {
+            chart,
+            data,
+            _color: core::marker::PhantomData,
+        }
+    }
+
+    /// Get the wrapped chart
+    pub fn chart(&self) -> &T {
+        &self.chart
+    }
+
+    /// Get mutable access to the wrapped chart
+    pub fn chart_mut(&mut self) -> &mut T {
+        &mut self.chart
+    }
+
+    /// Get the owned data
+    pub fn data(&self) -> &T::Data {
+        &self.data
+    }
+
+    /// Replace the owned data
+    pub fn set_data(&mut self, data: T::Data) {
+        self.data = data;
+    }
+
+    /// Split into the chart and its data
+    pub fn into_parts(self) -> (T, T::Data) {
+        (self.chart, self.data)
+    }
+
+    /// Draw the owned data with an explicit configuration
+    ///
+    /// # Arguments
+    /// * `config` - Chart configuration
+    /// * `viewport` - The area to draw the chart in
+    /// * `target` - The display target to draw to
+    pub fn draw_with_config<D>(
+        &self,
+        config: &T::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.chart.draw(&self.data, config, viewport, target)
+    }
+}
+
+impl<C: PixelColor, T: ConfiguredChart<C>> StaticChart<C, T> {
+    /// Draw the owned data using the chart's own configuration
+    ///
+    /// # Arguments
+    /// * `viewport` - The area to draw the chart in
+    /// * `target` - The display target to draw to
+    pub fn draw_owned<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.chart
+            .draw(&self.data, self.chart.chart_config(), viewport, target)
+    }
+}
+
+impl<C: PixelColor, T> core::fmt::Debug for StaticChart<C, T>
+where
+    T: Chart<C> + core::fmt::Debug,
+    T::Data: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticChart")
+            .field("chart", &self.chart)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+/// Builder produced by [`ChartBuilder::with_static_data`].
+pub struct StaticChartBuilder<C: PixelColor, B: ChartBuilder<C>> {
+    /// Builder for the wrapped chart
+    builder: B,
+    /// Data the built chart will own
+    data: <B::Chart as Chart<C>>::Data,
+}
+
+impl<C: PixelColor, B: ChartBuilder<C>> StaticChartBuilder<C, B> {
+    /// Wrap a chart builder together with the data the chart will own
+    ///
+    /// # Arguments
+    /// * `builder` - Builder for the wrapped chart
+    /// * `data` - The data the chart will render
+    pub fn new(builder: B, data: <B::Chart as Chart<C>>::Data) -> Self {
+        Self { builder, data }
+    }
+
+    /// Build the chart and attach its data
+    pub fn build(self) -> Result<StaticChart<C, B::Chart>, B::Error> {
+        let chart = self.builder.build()?;
+        Ok(StaticChart::new(chart, self.data))
+    }
+}
+
+impl<C: PixelColor, B> core::fmt::Debug for StaticChartBuilder<C, B>
+where
+    B: ChartBuilder<C> + core::fmt::Debug,
+    <B::Chart as Chart<C>>::Data: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticChartBuilder")
+            .field("builder", &self.builder)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "line")]
+mod tests {
+    use super::*;
+    use crate::chart::LineChart;
+    use crate::data::{DataSeries, Point2D, StaticDataSeries};
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_builder_attaches_static_data() {
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 0.0)).unwrap();
+        data.push(Point2D::new(10.0, 10.0)).unwrap();
+
+        let chart = LineChart::builder()
+            .line_color(Rgb565::RED)
+            .with_static_data(data)
+            .build()
+            .unwrap();
+        assert_eq!(chart.data().len(), 2);
+        assert_eq!(chart.chart().style().line_color, Rgb565::RED);
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        assert!(chart.draw_owned(viewport, &mut display).is_ok());
+    }
+}
@@ -0,0 +1,156 @@
+//! Mean and standard deviation bands for line charts.
+//!
+//! A [`StatsOverlay`] computes the mean and standard deviation of the drawn
+//! series each time the chart is drawn, and shows them as a horizontal mean
+//! line over shaded bands of a given number of standard deviations around
+//! it. Like annotations, they are drawn above the grid and below the data.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let chart = LineChart::builder()
+//!     .line_color(Rgb565::BLUE)
+//!     .with_stats_overlay(
+//!         StatsOverlay::new(Rgb565::CSS_DARK_GRAY)
+//!             .with_band(2.0, Rgb565::CSS_LIGHT_GRAY)?
+//!             .with_band(1.0, Rgb565::CSS_GAINSBORO)?,
+//!     )
+//!     .build()?;
+//! assert_eq!(chart.stats_overlay().map(|overlay| overlay.bands.len()), Some(2));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::annotations::{AnnotationOverlay, ReferenceBand, ReferenceLine};
+use crate::data::{Point2D, SeriesStats};
+use crate::error::{ChartError, ChartResult};
+use crate::style::LineStyle;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+
+/// Number of bands a [`StatsOverlay`] can hold
+pub const MAX_SIGMA_BANDS: usize = 3;
+
+/// Band of `sigmas` standard deviations on either side of the mean
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SigmaBand<C: PixelColor> {
+    /// Half width of the band in standard deviations
+    pub sigmas: f32,
+    /// Fill color
+    pub color: C,
+}
+
+/// Mean line and standard deviation bands of the drawn series
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsOverlay<C: PixelColor> {
+    /// Style of the mean line, or `None` to draw only the bands
+    pub mean_line: Option<LineStyle<C>>,
+    /// Bands around the mean, drawn widest first
+    pub bands: heapless::Vec<SigmaBand<C>, MAX_SIGMA_BANDS>,
+}
+
+impl<C: PixelColor> StatsOverlay<C> {
+    /// Draw a solid mean line in `color`, without bands
+    pub fn new(color: C) -> Self {
+        Self {
+            mean_line: Some(LineStyle::solid(color)),
+            bands: heapless::Vec::new(),
+        }
+    }
+
+    /// Set the style of the mean line, or hide it with `None`
+    pub fn mean_line(mut self, line: Option<LineStyle<C>>) -> Self {
+        self.mean_line = line;
+        self
+    }
+
+    /// Shade `sigmas` standard deviations on either side of the mean
+    ///
+    /// Returns an error if the overlay already has [`MAX_SIGMA_BANDS`] bands.
+    pub fn with_band(mut self, sigmas: f32, color: C) -> ChartResult<Self> {
+        self.bands
+            .push(SigmaBand {
+                sigmas: sigmas.abs(),
+                color,
+            })
+            .map_err(|_| ChartError::MemoryFull)?;
+        Ok(self)
+    }
+
+    /// Get the mean line and bands for `stats` as annotations
+    pub fn annotations(
+        &self,
+        stats: &SeriesStats,
+    ) -> ChartResult<AnnotationOverlay<C, { MAX_SIGMA_BANDS + 1 }>> {
+        let mut annotations = AnnotationOverlay::new();
+
+        // Narrower bands go on top of wider ones
+        let mut bands = self.bands.clone();
+        bands.sort_unstable_by(|a, b| b.sigmas.total_cmp(&a.sigmas));
+        for band in bands {
+            let spread = band.sigmas * stats.std_dev;
+            annotations.add_band(ReferenceBand::horizontal(
+                stats.mean - spread,
+                stats.mean + spread,
+                band.color,
+            ))?;
+        }
+        if let Some(line) = self.mean_line {
+            annotations.add_line(ReferenceLine::horizontal(stats.mean, line))?;
+        }
+        Ok(annotations)
+    }
+
+    /// Draw the overlay for `stats`, clipped to `plot_area`
+    ///
+    /// `to_screen` maps data coordinates like the chart's data points.
+    pub fn draw<F, D>(
+        &self,
+        stats: &SeriesStats,
+        to_screen: F,
+        plot_area: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        F: Fn(Point2D) -> Point,
+        D: DrawTarget<Color = C>,
+    {
+        self.annotations(stats)?.draw(to_screen, plot_area, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::annotations::Annotation;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_bands_widest_first() {
+        let overlay = StatsOverlay::new(Rgb565::BLACK)
+            .with_band(1.0, Rgb565::GREEN)
+            .unwrap()
+            .with_band(2.0, Rgb565::YELLOW)
+            .unwrap();
+        let stats = SeriesStats::from_values([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+        let annotations = overlay.annotations(&stats).unwrap();
+
+        match annotations.annotations() {
+            [Annotation::Band(wide), Annotation::Band(narrow), Annotation::Line(mean)] => {
+                assert_eq!(wide.color, Rgb565::YELLOW);
+                assert!((wide.from - 1.0).abs() < 0.2 && (wide.to - 9.0).abs() < 0.2);
+                assert_eq!(narrow.color, Rgb565::GREEN);
+                assert_eq!(mean.value, 5.0);
+            }
+            other => panic!("unexpected annotations {other:?}"),
+        }
+
+        let mut full = StatsOverlay::new(Rgb565::BLACK).mean_line(None);
+        for sigmas in 1..=MAX_SIGMA_BANDS {
+            full = full.with_band(sigmas as f32, Rgb565::GREEN).unwrap();
+        }
+        assert!(full.clone().with_band(4.0, Rgb565::GREEN).is_err());
+        assert_eq!(full.annotations(&stats).unwrap().len(), MAX_SIGMA_BANDS);
+    }
+}
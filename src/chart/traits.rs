@@ -1,8 +1,21 @@
 //! Core traits for chart implementations.
 
+use crate::axes::{traits::Axis, AxisPosition};
 use crate::data::DataSeries;
-use crate::error::ChartResult;
-use embedded_graphics::{prelude::*, primitives::Rectangle};
+use crate::error::{ChartError, ChartResult};
+use crate::legend::LegendPosition;
+use crate::render::ChartDrawTarget;
+use crate::style::{NumberLocale, Theme};
+use embedded_graphics::{
+    mono_font::{
+        ascii::{FONT_5X8, FONT_6X10},
+        MonoFont, MonoTextStyle,
+    },
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
 
 /// Main trait for all chart types
 pub trait Chart<C: PixelColor> {
@@ -28,12 +41,52 @@ pub trait Chart<C: PixelColor> {
     where
         D: DrawTarget<Color = C>;
 
+    /// Draw the chart using the fast paths of a [`ChartDrawTarget`]
+    ///
+    /// Charts with area, sector or band fills override this to write them
+    /// through [`ChartDrawTarget::fill_span`] and friends, so a driver's
+    /// accelerated implementations are used. The default draws through
+    /// [`draw`](Self::draw).
+    fn draw_accelerated<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: ChartDrawTarget<Color = C>,
+    {
+        self.draw(data, config, viewport, target)
+    }
+
     /// Get the data bounds for this chart
     fn data_bounds(&self, _data: &Self::Data) -> ChartResult<()> {
         // Default implementation - concrete charts should override this
         // if they need specific bounds calculation
         Ok(())
     }
+
+    /// Get the area that must be redrawn after `change` to `data`
+    ///
+    /// `data` is the series after the change. The default is the whole
+    /// viewport; charts whose layout doesn't depend on the data range can
+    /// narrow it down, so e-paper and DMA displays refresh only that area.
+    fn invalidate(&self, data: &Self::Data, change: DataChange, viewport: Rectangle) -> Rectangle {
+        let _ = (data, change);
+        viewport
+    }
+}
+
+/// Change to the data of a chart, see [`Chart::invalidate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataChange {
+    /// This many points were appended at the end of the series
+    Appended(usize),
+    /// The point at this index changed its value
+    Updated(usize),
+    /// Points were removed, reordered or replaced
+    Replaced,
 }
 
 /// Trait for charts that support real-time data streaming
@@ -173,6 +226,21 @@ pub trait AxisChart<C: PixelColor>: Chart<C> {
 
     /// Get the Y-axis configuration
     fn y_axis(&self) -> ChartResult<&Self::YAxis>;
+
+    /// Set a secondary Y-axis with its own range, drawn opposite the primary one
+    ///
+    /// Charts without secondary axis support ignore it.
+    ///
+    /// # Arguments
+    /// * `axis` - Secondary Y-axis configuration
+    fn set_secondary_y_axis(&mut self, axis: Self::YAxis) {
+        let _ = axis;
+    }
+
+    /// Get the secondary Y-axis configuration
+    fn secondary_y_axis(&self) -> ChartResult<&Self::YAxis> {
+        Err(ChartError::InvalidConfiguration)
+    }
 }
 
 /// Trait for charts that support legends
@@ -202,6 +270,29 @@ pub trait ChartBuilder<C: PixelColor> {
 
     /// Build the chart with current configuration
     fn build(self) -> Result<Self::Chart, Self::Error>;
+
+    /// Attach data that the built chart will own
+    ///
+    /// Useful for fixed charts such as reference or calibration curves, which can
+    /// then be drawn with [`StaticChart::draw_owned`](crate::chart::StaticChart::draw_owned).
+    ///
+    /// # Arguments
+    /// * `data` - The data the chart will render
+    fn with_static_data(
+        self,
+        data: <Self::Chart as Chart<C>>::Data,
+    ) -> crate::chart::StaticChartBuilder<C, Self>
+    where
+        Self: Sized,
+    {
+        crate::chart::StaticChartBuilder::new(self, data)
+    }
+}
+
+/// Trait for charts that carry their own configuration
+pub trait ConfiguredChart<C: PixelColor>: Chart<C> {
+    /// Get the configuration the chart was built with
+    fn chart_config(&self) -> &Self::Config;
 }
 
 /// Trait for charts that can be rendered incrementally
@@ -280,16 +371,355 @@ pub trait AnimationRenderer<C: PixelColor> {
 /// Common chart configuration
 #[derive(Debug, Clone)]
 pub struct ChartConfig<C: PixelColor> {
-    /// Chart title
+    /// Chart title, drawn above the plot area
     pub title: Option<heapless::String<64>>,
+    /// Smaller line drawn below the title
+    pub subtitle: Option<heapless::String<64>>,
+    /// Fonts, colors and alignment of the title and subtitle
+    ///
+    /// `None` uses [`TitleStyle::default`].
+    pub title_style: Option<TitleStyle<C>>,
     /// Background color
     pub background_color: Option<C>,
+    /// Which part of the viewport the background color fills
+    pub background_mode: BackgroundMode,
     /// Chart margins
     pub margins: Margins,
     /// Whether to show grid lines
     pub show_grid: bool,
     /// Grid color
     pub grid_color: Option<C>,
+    /// Placeholder drawn instead of returning a data error
+    pub placeholder: Option<PlaceholderStyle<C>>,
+    /// Decimal separator and digit grouping for numbers drawn by the chart
+    pub locale: NumberLocale,
+}
+
+impl<C: PixelColor> ChartConfig<C> {
+    /// Fill the background according to the background mode
+    ///
+    /// Nothing is drawn without a background color or in
+    /// [`BackgroundMode::Transparent`] mode.
+    pub fn draw_background<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let area = match (self.background_color, self.background_mode) {
+            (None, _) | (_, BackgroundMode::Transparent) => return Ok(()),
+            (Some(_), BackgroundMode::Fill) => viewport,
+            (Some(_), BackgroundMode::FillPlotAreaOnly) => self.margins.apply_to(viewport),
+        };
+
+        if let Some(color) = self.background_color {
+            area.into_styled(PrimitiveStyle::with_fill(color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+
+    /// Height of the band above the plot taken by the title and subtitle
+    ///
+    /// Zero when neither is set.
+    pub fn title_height(&self) -> u32 {
+        let (title_font, subtitle_font, spacing) = match self.title_style {
+            Some(style) => (style.font, style.subtitle_font, style.spacing),
+            None => (TitleStyle::<C>::FONT, TitleStyle::<C>::SUBTITLE_FONT, 2),
+        };
+
+        let mut height = 0;
+        if self.title.is_some() {
+            height += title_font.character_size.height;
+        }
+        if self.subtitle.is_some() {
+            height += subtitle_font.character_size.height;
+        }
+        if height > 0 {
+            height += spacing;
+        }
+        height
+    }
+
+    /// Area left for the chart below the title band
+    ///
+    /// Charts apply their margins to this area, so the title never overlaps
+    /// the plot. Use it in place of the full viewport when mapping screen
+    /// coordinates back to a chart that has a title.
+    pub fn content_area(&self, viewport: Rectangle) -> Rectangle {
+        let height = self.title_height().min(viewport.size.height);
+        Rectangle::new(
+            viewport.top_left + Point::new(0, height as i32),
+            Size::new(viewport.size.width, viewport.size.height - height),
+        )
+    }
+
+    /// Draw the title and subtitle at the top of the viewport
+    ///
+    /// Nothing is drawn when neither is set. Text wider than the viewport is
+    /// clipped to it.
+    pub fn draw_title<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+        C: From<Rgb565>,
+    {
+        if self.title.is_none() && self.subtitle.is_none() {
+            return Ok(());
+        }
+
+        let style = self.title_style.unwrap_or_default();
+        let x = match style.alignment {
+            Alignment::Left => viewport.top_left.x + self.margins.left as i32,
+            Alignment::Center => viewport.top_left.x + viewport.size.width as i32 / 2,
+            Alignment::Right => {
+                viewport.top_left.x + viewport.size.width as i32 - 1 - self.margins.right as i32
+            }
+        };
+        let text_style = TextStyleBuilder::new()
+            .alignment(style.alignment)
+            .baseline(Baseline::Top)
+            .build();
+
+        let mut clipped = target.clipped(&viewport);
+        let mut y = viewport.top_left.y;
+        let lines = [
+            (self.title.as_deref(), style.font, style.color),
+            (
+                self.subtitle.as_deref(),
+                style.subtitle_font,
+                style.subtitle_color,
+            ),
+        ];
+        for (text, font, color) in lines {
+            let Some(text) = text else { continue };
+            Text::with_text_style(
+                text,
+                Point::new(x, y),
+                MonoTextStyle::new(font, color),
+                text_style,
+            )
+            .draw(&mut clipped)
+            .map_err(|_| ChartError::RenderingError)?;
+            y += font.character_size.height as i32;
+        }
+        Ok(())
+    }
+
+    /// Handle a chart that cannot be drawn from its data
+    ///
+    /// With a placeholder configured, data errors such as
+    /// [`ChartError::InsufficientData`] draw the placeholder over the viewport
+    /// and succeed, so stale pixels are not left on screen. Any other error, or
+    /// any error without a placeholder, is returned unchanged.
+    pub fn fallback<D>(
+        &self,
+        error: ChartError,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match (&self.placeholder, error) {
+            (
+                Some(placeholder),
+                ChartError::InsufficientData | ChartError::InvalidData | ChartError::DataError(_),
+            ) => placeholder.draw(viewport, target),
+            _ => Err(error),
+        }
+    }
+}
+
+/// Fonts, colors and alignment of the chart title and subtitle
+#[derive(Debug, Clone, Copy)]
+pub struct TitleStyle<C: PixelColor> {
+    /// Font of the title
+    pub font: &'static MonoFont<'static>,
+    /// Color of the title
+    pub color: C,
+    /// Font of the subtitle
+    pub subtitle_font: &'static MonoFont<'static>,
+    /// Color of the subtitle
+    pub subtitle_color: C,
+    /// Horizontal alignment; left and right alignment follow the chart margins
+    pub alignment: Alignment,
+    /// Gap in pixels between the title band and the area below it
+    pub spacing: u32,
+}
+
+impl<C: PixelColor> TitleStyle<C> {
+    /// Default title font
+    pub const FONT: &'static MonoFont<'static> = &FONT_6X10;
+    /// Default subtitle font
+    pub const SUBTITLE_FONT: &'static MonoFont<'static> = &FONT_5X8;
+
+    /// Create a centered title style with the default fonts
+    pub const fn new(color: C, subtitle_color: C) -> Self {
+        Self {
+            font: Self::FONT,
+            color,
+            subtitle_font: Self::SUBTITLE_FONT,
+            subtitle_color,
+            alignment: Alignment::Center,
+            spacing: 2,
+        }
+    }
+
+    /// Create a title style using the text colors of a theme
+    pub fn themed(theme: &Theme<C>) -> Self {
+        Self::new(theme.text, theme.text)
+    }
+
+    /// Set the title font
+    pub const fn with_font(mut self, font: &'static MonoFont<'static>) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set the subtitle font
+    pub const fn with_subtitle_font(mut self, font: &'static MonoFont<'static>) -> Self {
+        self.subtitle_font = font;
+        self
+    }
+
+    /// Set the horizontal alignment
+    pub const fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the gap below the title band
+    pub const fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl<C: PixelColor + From<Rgb565>> Default for TitleStyle<C> {
+    fn default() -> Self {
+        Self::new(Rgb565::BLACK.into(), Rgb565::CSS_DIM_GRAY.into())
+    }
+}
+
+/// How a chart paints its background
+///
+/// Use [`BackgroundMode::Transparent`] to draw a chart over existing screen
+/// content, such as a second plot or an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackgroundMode {
+    /// Fill the whole viewport with the background color
+    #[default]
+    Fill,
+    /// Never fill, leaving the underlying pixels visible
+    Transparent,
+    /// Fill only the plot area inside the margins
+    FillPlotAreaOnly,
+}
+
+/// What a placeholder shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderKind {
+    /// Outlined box crossed by both diagonals
+    CrossedBox,
+    /// Centered text message
+    Message(&'static str),
+}
+
+/// Style for the placeholder drawn in place of a chart without usable data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaceholderStyle<C: PixelColor> {
+    /// What to draw
+    pub kind: PlaceholderKind,
+    /// Line and text color
+    pub color: C,
+    /// Fill for the viewport before drawing, clearing stale pixels
+    pub background: Option<C>,
+}
+
+impl<C: PixelColor> PlaceholderStyle<C> {
+    /// Create a crossed-out box placeholder
+    pub const fn crossed_box(color: C) -> Self {
+        Self {
+            kind: PlaceholderKind::CrossedBox,
+            color,
+            background: None,
+        }
+    }
+
+    /// Create a text placeholder
+    pub const fn message(text: &'static str, color: C) -> Self {
+        Self {
+            kind: PlaceholderKind::Message(text),
+            color,
+            background: None,
+        }
+    }
+
+    /// Create a placeholder using the text and background colors of a theme
+    pub fn themed(kind: PlaceholderKind, theme: &Theme<C>) -> Self {
+        Self {
+            kind,
+            color: theme.text,
+            background: Some(theme.background),
+        }
+    }
+
+    /// Set the background fill
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Draw the placeholder within the viewport
+    pub fn draw<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(background) = self.background {
+            viewport
+                .into_styled(PrimitiveStyle::with_fill(background))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        let Some(bottom_right) = viewport.bottom_right() else {
+            return Ok(());
+        };
+
+        match self.kind {
+            PlaceholderKind::CrossedBox => {
+                let style = PrimitiveStyle::with_stroke(self.color, 1);
+                let top_right = Point::new(bottom_right.x, viewport.top_left.y);
+                let bottom_left = Point::new(viewport.top_left.x, bottom_right.y);
+
+                viewport
+                    .into_styled(style)
+                    .draw(target)
+                    .map_err(|_| ChartError::RenderingError)?;
+                for (start, end) in [(viewport.top_left, bottom_right), (bottom_left, top_right)] {
+                    Line::new(start, end)
+                        .into_styled(style)
+                        .draw(target)
+                        .map_err(|_| ChartError::RenderingError)?;
+                }
+            }
+            PlaceholderKind::Message(text) => {
+                let text_style = TextStyleBuilder::new()
+                    .alignment(Alignment::Center)
+                    .baseline(Baseline::Middle)
+                    .build();
+                Text::with_text_style(
+                    text,
+                    viewport.center(),
+                    MonoTextStyle::new(&FONT_6X10, self.color),
+                    text_style,
+                )
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Chart margins configuration
@@ -346,6 +776,40 @@ impl Margins {
         self.top + self.bottom
     }
 
+    /// Add the space required by an axis to the side it is drawn on
+    ///
+    /// Only that side grows, so a left Y-axis widens the left margin without
+    /// touching the other three.
+    pub fn with_axis<T, C, A>(mut self, axis: &A) -> Self
+    where
+        C: PixelColor,
+        A: Axis<T, C>,
+    {
+        let space = axis.required_space();
+        match axis.position() {
+            AxisPosition::Top => self.top += space,
+            AxisPosition::Bottom => self.bottom += space,
+            AxisPosition::Left => self.left += space,
+            AxisPosition::Right => self.right += space,
+        }
+        self
+    }
+
+    /// Add room for a legend of the given size on the side it is placed on
+    ///
+    /// Corner, custom and floating legends overlay the chart and leave the
+    /// margins unchanged.
+    pub fn with_legend(mut self, position: LegendPosition, size: Size) -> Self {
+        match position {
+            LegendPosition::Top => self.top += size.height,
+            LegendPosition::Bottom => self.bottom += size.height,
+            LegendPosition::Left => self.left += size.width,
+            LegendPosition::Right => self.right += size.width,
+            _ => {}
+        }
+        self
+    }
+
     /// Apply margins to a rectangle, returning the inner area
     pub fn apply_to(&self, rect: Rectangle) -> Rectangle {
         let top_left = Point::new(
@@ -370,10 +834,15 @@ impl<C: PixelColor> Default for ChartConfig<C> {
     fn default() -> Self {
         Self {
             title: None,
+            subtitle: None,
+            title_style: None,
             background_color: None,
+            background_mode: BackgroundMode::Fill,
             margins: Margins::default(),
             show_grid: false,
             grid_color: None,
+            placeholder: None,
+            locale: NumberLocale::PLAIN,
         }
     }
 }
@@ -416,4 +885,110 @@ mod tests {
         assert_eq!(inner.top_left, Point::new(10, 10));
         assert_eq!(inner.size, Size::new(80, 60));
     }
+
+    #[test]
+    fn test_margins_pad_single_side() {
+        use crate::axes::{AxisOrientation, AxisStyle, LinearAxis};
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let axis: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left)
+                .with_style(AxisStyle::new().with_plot_gap(2));
+        let margins = Margins::all(5)
+            .with_axis(&axis)
+            .with_legend(LegendPosition::Right, Size::new(30, 12));
+
+        assert_eq!(margins.left, 5 + axis.required_space());
+        assert_eq!(margins.right, 35);
+        assert_eq!((margins.top, margins.bottom), (5, 5));
+
+        let overlay = Margins::all(5).with_legend(LegendPosition::TopRight, Size::new(30, 12));
+        assert_eq!(overlay, Margins::all(5));
+    }
+
+    #[test]
+    fn test_placeholder_fallback() {
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let viewport = Rectangle::new(Point::new(2, 2), Size::new(20, 10));
+        let mut config: ChartConfig<Rgb565> = ChartConfig::default();
+        let mut display = MockDisplay::new();
+        assert_eq!(
+            config.fallback(ChartError::InsufficientData, viewport, &mut display),
+            Err(ChartError::InsufficientData)
+        );
+
+        config.placeholder = Some(PlaceholderStyle::crossed_box(Rgb565::RED));
+        display.set_allow_overdraw(true);
+        config
+            .fallback(ChartError::InsufficientData, viewport, &mut display)
+            .unwrap();
+        assert_eq!(display.get_pixel(Point::new(2, 2)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(21, 11)), Some(Rgb565::RED));
+        assert_eq!(display.affected_area(), viewport);
+
+        // Only data errors are replaced by the placeholder
+        assert_eq!(
+            config.fallback(ChartError::MemoryFull, viewport, &mut display),
+            Err(ChartError::MemoryFull)
+        );
+    }
+
+    #[test]
+    fn test_background_modes() {
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let mut config = ChartConfig {
+            background_color: Some(Rgb565::BLUE),
+            margins: Margins::all(5),
+            ..ChartConfig::default()
+        };
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        config.draw_background(viewport, &mut display).unwrap();
+        assert_eq!(display.affected_area(), viewport);
+
+        config.background_mode = BackgroundMode::FillPlotAreaOnly;
+        let mut display = MockDisplay::<Rgb565>::new();
+        config.draw_background(viewport, &mut display).unwrap();
+        assert_eq!(display.affected_area(), Margins::all(5).apply_to(viewport));
+
+        config.background_mode = BackgroundMode::Transparent;
+        let mut display = MockDisplay::<Rgb565>::new();
+        config.draw_background(viewport, &mut display).unwrap();
+        assert_eq!(display.affected_area().size, Size::zero());
+    }
+
+    #[test]
+    fn test_title_band() {
+        use embedded_graphics::mock_display::MockDisplay;
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let mut config: ChartConfig<Rgb565> = ChartConfig::default();
+        assert_eq!(config.title_height(), 0);
+        assert_eq!(config.content_area(viewport), viewport);
+
+        config.title = heapless::String::try_from("Temp").ok();
+        config.subtitle = heapless::String::try_from("C").ok();
+        // 10 pixel title, 8 pixel subtitle and the default 2 pixel spacing
+        assert_eq!(config.title_height(), 20);
+        assert_eq!(
+            config.content_area(viewport),
+            Rectangle::new(Point::new(0, 20), Size::new(64, 44))
+        );
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        config.draw_title(viewport, &mut display).unwrap();
+        let drawn = display.affected_area();
+        assert!(drawn.size.height > 0);
+        assert!(drawn.top_left.y + drawn.size.height as i32 <= 20);
+
+        // A small viewport is never given a negative content height
+        let tiny = Rectangle::new(Point::zero(), Size::new(64, 12));
+        assert_eq!(config.content_area(tiny).size.height, 0);
+    }
 }
@@ -0,0 +1,91 @@
+//! Derived series drawn over a line chart.
+//!
+//! A [`TransformOverlay`] runs the chart's data through a
+//! [`TransformPipeline`] while drawing and strokes the result over the raw
+//! series, for example a moving average over a noisy signal, or the upper and
+//! lower edge of its min/max envelope. The derived points are computed lazily
+//! and never stored.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::data::transforms::MovingAverage;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let chart = LineChart::builder()
+//!     .line_color(Rgb565::CSS_LIGHT_GRAY)
+//!     .with_transform_overlay(TransformOverlay::new(MovingAverage::new(8)?, Rgb565::BLUE))?
+//!     .build()?;
+//! assert_eq!(chart.transform_overlays().len(), 1);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::data::transforms::{TransformPipeline, Transformed};
+use crate::data::Point2D;
+use crate::style::LineStyle;
+use embedded_graphics::prelude::*;
+
+/// Number of transform overlays a line chart can hold
+pub const MAX_TRANSFORM_OVERLAYS: usize = 3;
+
+/// Transformed copy of the series, drawn as a line over it
+#[derive(Debug, Clone)]
+pub struct TransformOverlay<C: PixelColor> {
+    /// Transform applied to the y values of the series
+    pub transform: TransformPipeline,
+    /// Style of the drawn line
+    pub line: LineStyle<C>,
+}
+
+impl<C: PixelColor> TransformOverlay<C> {
+    /// Draw `transform` of the series as a solid line in `color`
+    ///
+    /// `transform` is a single transform or a [`TransformPipeline`].
+    pub fn new(transform: impl Into<TransformPipeline>, color: C) -> Self {
+        Self {
+            transform: transform.into(),
+            line: LineStyle::solid(color),
+        }
+    }
+
+    /// Set the style of the drawn line
+    pub fn with_line(mut self, line: LineStyle<C>) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Iterate over `points` with the transform applied, starting afresh
+    pub fn apply<I>(&self, points: I) -> Transformed<I, TransformPipeline>
+    where
+        I: Iterator<Item = Point2D>,
+    {
+        Transformed::new(points, self.transform.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::transforms::{Envelope, MovingAverage};
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_overlay_applies_afresh() {
+        let overlay = TransformOverlay::new(MovingAverage::new(2).unwrap(), Rgb565::BLUE);
+        let points = [
+            Point2D::new(0.0, 2.0),
+            Point2D::new(1.0, 4.0),
+            Point2D::new(2.0, 8.0),
+        ];
+
+        let first: heapless::Vec<Point2D, 4> = overlay.apply(points.iter().copied()).collect();
+        let again: heapless::Vec<Point2D, 4> = overlay.apply(points.iter().copied()).collect();
+        assert_eq!(first, again);
+        assert_eq!(first.as_slice()[2], Point2D::new(2.0, 6.0));
+
+        let upper = TransformOverlay::new(Envelope::upper(2).unwrap(), Rgb565::RED);
+        let peaks: heapless::Vec<Point2D, 4> = upper.apply(points.iter().copied()).collect();
+        assert_eq!(peaks.as_slice()[1].y, 4.0);
+    }
+}
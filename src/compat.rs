@@ -0,0 +1,167 @@
+//! Conversion helpers for projects migrating from other plotting crates.
+//!
+//! Crates such as `embedded-plots` describe curves as slices of
+//! embedded-graphics [`Point`]s with `Range<i32>` axes, while `plotters`
+//! style code usually works with `(x, y)` tuples and `Range<f32>` extents.
+//! The helpers here turn those shapes into this crate's series, bounds and
+//! axis types so existing data pipelines can be kept while the drawing code
+//! is switched over.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::compat;
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! // Data as it would be handed to an embedded-plots `Curve`
+//! let points = [Point::new(0, 0), Point::new(1, 4), Point::new(2, 9)];
+//! let series: StaticDataSeries<Point2D, 256> = compat::series_from_points(&points)?;
+//!
+//! // Axis ranges as used by embedded-plots and plotters
+//! let x_axis: LinearAxis<f32, Rgb565> = compat::x_axis_from_range(0..2);
+//! let y_axis: LinearAxis<f32, Rgb565> = compat::y_axis_from_range(0.0..10.0);
+//!
+//! let chart = LineChart::builder()
+//!     .with_x_axis(x_axis)
+//!     .with_y_axis(y_axis)
+//!     .build()?;
+//! # let _ = (chart, series);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::axes::{AxisOrientation, AxisPosition, LinearAxis};
+use crate::data::{DataBounds, Point2D, StaticDataSeries};
+use crate::error::{ChartError, ChartResult};
+use core::ops::Range;
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+
+/// Convert a slice of embedded-graphics points into a series
+pub fn series_from_points<const N: usize>(
+    points: &[Point],
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    let mut series = StaticDataSeries::new();
+    for point in points {
+        series.push(Point2D::new(point.x as f32, point.y as f32))?;
+    }
+    Ok(series)
+}
+
+/// Convert a slice of `(x, y)` tuples into a series
+pub fn series_from_tuples<const N: usize>(
+    tuples: &[(f32, f32)],
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    Ok(StaticDataSeries::from_tuples(tuples)?)
+}
+
+/// Convert a slice of y values into a series, using the index as x
+pub fn series_from_values<const N: usize>(
+    values: &[f32],
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    let mut series = StaticDataSeries::new();
+    for (index, &value) in values.iter().enumerate() {
+        series.push(Point2D::new(index as f32, value))?;
+    }
+    Ok(series)
+}
+
+/// Convert x and y ranges into data bounds
+pub fn bounds_from_ranges<T: CompatValue>(
+    x: Range<T>,
+    y: Range<T>,
+) -> ChartResult<DataBounds<f32, f32>> {
+    let (min_x, max_x) = range_to_f32(x);
+    let (min_y, max_y) = range_to_f32(y);
+    if min_x > max_x || min_y > max_y {
+        return Err(ChartError::InvalidRange);
+    }
+    Ok(DataBounds {
+        min_x,
+        max_x,
+        min_y,
+        max_y,
+    })
+}
+
+/// Create a bottom X-axis covering `range`
+pub fn x_axis_from_range<R, C>(range: Range<R>) -> LinearAxis<f32, C>
+where
+    R: CompatValue,
+    C: PixelColor + From<Rgb565>,
+{
+    let (min, max) = range_to_f32(range);
+    LinearAxis::new(min, max, AxisOrientation::Horizontal, AxisPosition::Bottom)
+}
+
+/// Create a left Y-axis covering `range`
+pub fn y_axis_from_range<R, C>(range: Range<R>) -> LinearAxis<f32, C>
+where
+    R: CompatValue,
+    C: PixelColor + From<Rgb565>,
+{
+    let (min, max) = range_to_f32(range);
+    LinearAxis::new(min, max, AxisOrientation::Vertical, AxisPosition::Left)
+}
+
+/// Range element types accepted by the axis helpers.
+///
+/// Implemented for `i32`, as used by embedded-plots, and `f32`, as used by
+/// plotters, so both kinds of range literals work without casts.
+pub trait CompatValue: Copy {
+    /// Convert to the value type used by the axes
+    fn to_f32(self) -> f32;
+}
+
+impl CompatValue for i32 {
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+impl CompatValue for f32 {
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+fn range_to_f32<T: CompatValue>(range: Range<T>) -> (f32, f32) {
+    (range.start.to_f32(), range.end.to_f32())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axes::traits::Axis;
+    use crate::data::DataSeries;
+
+    #[test]
+    fn test_series_conversions() {
+        let points = [Point::new(0, 5), Point::new(10, -3)];
+        let series: StaticDataSeries<Point2D, 8> = series_from_points(&points).unwrap();
+        assert_eq!(series.get(1), Some(Point2D::new(10.0, -3.0)));
+
+        let series: StaticDataSeries<Point2D, 8> = series_from_values(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(series.get(2), Some(Point2D::new(2.0, 3.0)));
+
+        let too_many: ChartResult<StaticDataSeries<Point2D, 2>> =
+            series_from_tuples(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+        assert!(too_many.is_err());
+    }
+
+    #[test]
+    fn test_range_conversions() {
+        let axis: LinearAxis<f32, Rgb565> = x_axis_from_range(-10..10);
+        assert_eq!((axis.min(), axis.max()), (-10.0, 10.0));
+        assert_eq!(axis.orientation(), AxisOrientation::Horizontal);
+
+        let axis: LinearAxis<f32, Rgb565> = y_axis_from_range(0.0..2.5);
+        assert_eq!(axis.position(), AxisPosition::Left);
+
+        let bounds = bounds_from_ranges(0.0..4.0, 1.0..2.0).unwrap();
+        assert_eq!((bounds.min_x, bounds.max_y), (0.0, 2.0));
+        assert_eq!(
+            bounds_from_ranges(5.0..1.0, 0.0..1.0),
+            Err(ChartError::InvalidRange)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Titled card container for dashboard charts
+//!
+//! A [`ChartCard`] draws the frame of a typical dashboard tile - background,
+//! border and a title bar with an optional icon slot - and hands the remaining
+//! body area to a chart, so applications no longer compute the title strip
+//! themselves.
+
+use crate::chart::traits::Chart;
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StrokeAlignment},
+    text::{Baseline, Text},
+};
+use heapless::String;
+
+/// Appearance of a chart card
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardStyle<C: PixelColor> {
+    /// Card background color, or `None` to leave the body untouched
+    pub background: Option<C>,
+    /// Border color, or `None` for a borderless card
+    pub border_color: Option<C>,
+    /// Border width in pixels
+    pub border_width: u32,
+    /// Title bar fill color, or `None` to use the card background
+    pub title_bar_color: Option<C>,
+    /// Title text color
+    pub title_color: C,
+    /// Height of the title bar in pixels
+    pub title_bar_height: u32,
+    /// Space between the border and the body in pixels
+    pub padding: u32,
+}
+
+impl<C: PixelColor> Default for CardStyle<C>
+where
+    C: From<Rgb565>,
+{
+    fn default() -> Self {
+        Self {
+            background: Some(Rgb565::WHITE.into()),
+            border_color: Some(Rgb565::CSS_LIGHT_GRAY.into()),
+            border_width: 1,
+            title_bar_color: Some(Rgb565::CSS_WHITE_SMOKE.into()),
+            title_color: Rgb565::BLACK.into(),
+            title_bar_height: 14,
+            padding: 2,
+        }
+    }
+}
+
+/// Areas of a card, as computed by [`ChartCard::layout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardLayout {
+    /// Whole card including the border
+    pub outer: Rectangle,
+    /// Title bar inside the border
+    pub title_bar: Rectangle,
+    /// Icon slot at the left of the title bar, if reserved
+    pub icon_area: Option<Rectangle>,
+    /// Area where the title text is drawn
+    pub title_area: Rectangle,
+    /// Viewport handed to the chart
+    pub body: Rectangle,
+}
+
+/// Bordered card with a title bar that hosts a chart
+#[derive(Debug, Clone)]
+pub struct ChartCard<C: PixelColor> {
+    title: String<32>,
+    icon_width: u32,
+    style: CardStyle<C>,
+}
+
+impl<C: PixelColor> ChartCard<C>
+where
+    C: From<Rgb565>,
+{
+    /// Create a card with the given title; longer titles are truncated
+    pub fn new(title: &str) -> Self {
+        let mut card_title = String::new();
+        for c in title.chars() {
+            if card_title.push(c).is_err() {
+                break;
+            }
+        }
+        Self {
+            title: card_title,
+            icon_width: 0,
+            style: CardStyle::default(),
+        }
+    }
+}
+
+impl<C: PixelColor> ChartCard<C> {
+    /// Set the card style
+    pub fn with_style(mut self, style: CardStyle<C>) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Reserve a slot of `width` pixels at the left of the title bar for an icon
+    pub fn with_icon_area(mut self, width: u32) -> Self {
+        self.icon_width = width;
+        self
+    }
+
+    /// Get the title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &CardStyle<C> {
+        &self.style
+    }
+
+    /// Split `viewport` into the card areas
+    pub fn layout(&self, viewport: Rectangle) -> CardLayout {
+        let inset = |area: Rectangle, amount: u32| {
+            Rectangle::new(
+                area.top_left + Point::new(amount as i32, amount as i32),
+                Size::new(
+                    area.size.width.saturating_sub(2 * amount),
+                    area.size.height.saturating_sub(2 * amount),
+                ),
+            )
+        };
+
+        let inner = inset(viewport, self.style.border_width);
+        let bar_height = self.style.title_bar_height.min(inner.size.height);
+        let title_bar = Rectangle::new(inner.top_left, Size::new(inner.size.width, bar_height));
+
+        let icon_width = self.icon_width.min(title_bar.size.width);
+        let icon_area = (icon_width > 0)
+            .then(|| Rectangle::new(title_bar.top_left, Size::new(icon_width, bar_height)));
+        let title_area = Rectangle::new(
+            title_bar.top_left + Point::new(icon_width as i32, 0),
+            Size::new(title_bar.size.width - icon_width, bar_height),
+        );
+
+        let below_bar = Rectangle::new(
+            inner.top_left + Point::new(0, bar_height as i32),
+            Size::new(inner.size.width, inner.size.height - bar_height),
+        );
+
+        CardLayout {
+            outer: viewport,
+            title_bar,
+            icon_area,
+            title_area,
+            body: inset(below_bar, self.style.padding),
+        }
+    }
+
+    /// Draw the card frame and title, returning the layout for the contents
+    pub fn draw_frame<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<CardLayout>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let layout = self.layout(viewport);
+
+        let mut frame = PrimitiveStyleBuilder::new();
+        if let Some(background) = self.style.background {
+            frame = frame.fill_color(background);
+        }
+        if let (Some(border), true) = (self.style.border_color, self.style.border_width > 0) {
+            frame = frame
+                .stroke_color(border)
+                .stroke_width(self.style.border_width)
+                .stroke_alignment(StrokeAlignment::Inside);
+        }
+        viewport
+            .into_styled(frame.build())
+            .draw(target)
+            .map_err(|_| ChartError::RenderingError)?;
+
+        if let Some(bar_color) = self.style.title_bar_color {
+            layout
+                .title_bar
+                .into_styled(PrimitiveStyle::with_fill(bar_color))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        if !self.title.is_empty() {
+            // Clip to the title area so long titles never run into the border
+            let mut clipped = target.clipped(&layout.title_area);
+            let position = Point::new(
+                layout.title_area.top_left.x + 3,
+                layout.title_area.top_left.y + layout.title_area.size.height as i32 / 2,
+            );
+            Text::with_baseline(
+                &self.title,
+                position,
+                MonoTextStyle::new(&FONT_6X10, self.style.title_color),
+                Baseline::Middle,
+            )
+            .draw(&mut clipped)
+            .map_err(|_| ChartError::RenderingError)?;
+        }
+
+        Ok(layout)
+    }
+
+    /// Draw the card and render `chart` inside its body
+    pub fn draw<CH, D>(
+        &self,
+        chart: &CH,
+        data: &CH::Data,
+        config: &CH::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<CardLayout>
+    where
+        CH: Chart<C>,
+        D: DrawTarget<Color = C>,
+    {
+        let layout = self.draw_frame(viewport, target)?;
+        if !layout.body.is_zero_sized() {
+            chart.draw(data, config, layout.body, target)?;
+        }
+        Ok(layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    #[test]
+    fn test_card_layout() {
+        let card: ChartCard<Rgb565> = ChartCard::new("Temperature").with_icon_area(12);
+        let layout = card.layout(Rectangle::new(Point::new(10, 20), Size::new(100, 80)));
+
+        assert_eq!(
+            layout.title_bar,
+            Rectangle::new(Point::new(11, 21), Size::new(98, 14))
+        );
+        assert_eq!(
+            layout.icon_area,
+            Some(Rectangle::new(Point::new(11, 21), Size::new(12, 14)))
+        );
+        assert_eq!(layout.title_area.top_left, Point::new(23, 21));
+        assert_eq!(
+            layout.body,
+            Rectangle::new(Point::new(13, 37), Size::new(94, 60))
+        );
+    }
+
+    #[test]
+    fn test_card_layout_degrades_in_tiny_viewport() {
+        let card: ChartCard<Rgb565> = ChartCard::new("CPU");
+        let layout = card.layout(Rectangle::new(Point::zero(), Size::new(8, 10)));
+        assert_eq!(layout.title_bar.size.height, 8);
+        assert!(layout.body.is_zero_sized());
+    }
+
+    #[test]
+    fn test_card_draws_frame_and_title() {
+        let style = CardStyle {
+            background: None,
+            border_color: Some(Rgb565::BLUE),
+            title_bar_color: None,
+            title_color: Rgb565::RED,
+            ..CardStyle::default()
+        };
+        let card = ChartCard::new("Load").with_style(style);
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let layout = card
+            .draw_frame(
+                Rectangle::new(Point::zero(), Size::new(64, 40)),
+                &mut display,
+            )
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(Point::new(63, 39)), Some(Rgb565::BLUE));
+        let title_drawn = (layout.title_area.top_left.y..layout.body.top_left.y)
+            .any(|y| (0..64).any(|x| display.get_pixel(Point::new(x, y)) == Some(Rgb565::RED)));
+        assert!(title_drawn);
+        assert_eq!(display.get_pixel(layout.body.center()), None);
+    }
+}
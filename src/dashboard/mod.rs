@@ -26,10 +26,12 @@
 //! // chart.draw(data, config, chart1_viewport, &mut display)?;
 //! ```
 
+mod card;
 mod grid;
 mod layout;
 mod simple;
 
+pub use card::{CardLayout, CardStyle, ChartCard};
 pub use grid::{GridLayout, GridPosition};
 pub use layout::{DashboardLayout, LayoutPreset};
 pub use simple::{SimpleDashboard, MAX_DASHBOARD_CHARTS};
@@ -2,7 +2,7 @@
 
 use crate::data::point::DataPoint;
 use crate::error::{DataError, DataResult};
-use crate::math::{Math, NumericConversion};
+use crate::math::{power_of_ten, split_power_of_ten};
 
 /// Represents the bounds of a dataset in 2D space
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -140,13 +140,11 @@ impl FloatBounds {
                 return 0.0;
             }
 
-            let value_num = value.to_number();
-            let abs_val = Math::abs(value_num);
-            let exp = Math::floor(Math::log10(abs_val));
-            let ten = 10.0f32.to_number();
-            let divisor = Math::pow(ten, exp);
-            let divisor_f32 = f32::from_number(divisor);
-            let f = value / divisor_f32;
+            // Work in f32 so ranges beyond the fixed-point limit stay exact
+            let abs_val = if value < 0.0 { -value } else { value };
+            let (_, exp) = split_power_of_ten(abs_val);
+            let ten_pow_exp = power_of_ten(exp);
+            let f = value / ten_pow_exp;
 
             let nice_f = if round {
                 if f < 1.5 {
@@ -168,8 +166,6 @@ impl FloatBounds {
                 10.0
             };
 
-            let _exp_f32 = f32::from_number(exp);
-            let ten_pow_exp = f32::from_number(Math::pow(10.0f32.to_number(), exp));
             nice_f * ten_pow_exp
         }
 
@@ -0,0 +1,201 @@
+//! Demo data generators for documentation, tests and showcases.
+//!
+//! The generators produce plausible-looking sensor and monitoring data without
+//! any hardware. Noise comes from [`DemoRng`], a small xorshift generator, so
+//! the same seed always yields the same series on every target and math
+//! backend.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::data::demo::{self, SystemMetric};
+//! use embedded_charts::prelude::*;
+//!
+//! let temperature: StaticDataSeries<Point2D, 64> = demo::temperature_profile(24, 7)?;
+//! let cpu: StaticDataSeries<Point2D, 64> = demo::system_metrics(60, SystemMetric::CpuUsage, 7)?;
+//!
+//! assert_eq!(temperature.len(), 24);
+//! assert_eq!(cpu.len(), 60);
+//!
+//! // Same seed, same data
+//! let again: StaticDataSeries<Point2D, 64> = demo::temperature_profile(24, 7)?;
+//! assert_eq!(temperature.as_slice(), again.as_slice());
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::data::{Point2D, StaticDataSeries};
+use crate::error::{ChartError, ChartResult};
+use crate::math::{Math, NumericConversion};
+use core::f32::consts::PI;
+
+/// Deterministic pseudo-random number generator for demo data
+///
+/// Uses xorshift32, which is fast and small enough for any target. It is not
+/// suitable for anything beyond generating demo noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DemoRng {
+    state: u32,
+}
+
+impl DemoRng {
+    /// Create a generator from a seed (a zero seed is replaced by a fixed constant)
+    pub const fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { 0x9E37_79B9 } else { seed },
+        }
+    }
+
+    /// Get the next raw 32-bit value
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Get the next value in `[0.0, 1.0)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Get the next value in `[-amplitude, amplitude)`
+    pub fn noise(&mut self, amplitude: f32) -> f32 {
+        (self.next_f32() * 2.0 - 1.0) * amplitude
+    }
+}
+
+/// System metric types for monitoring data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemMetric {
+    /// CPU usage with periodic spikes
+    CpuUsage,
+    /// Memory usage with a slow upward trend
+    MemoryUsage,
+    /// Network I/O with bursts
+    NetworkIO,
+    /// Disk usage with smooth activity waves
+    DiskUsage,
+}
+
+fn sin(x: f32) -> f32 {
+    f32::from_number(Math::sin(x.to_number()))
+}
+
+fn cos(x: f32) -> f32 {
+    f32::from_number(Math::cos(x.to_number()))
+}
+
+/// Generate `points` samples of `amplitude * sin(frequency * x + phase)`
+pub fn sine_wave<const N: usize>(
+    points: usize,
+    amplitude: f32,
+    frequency: f32,
+    phase: f32,
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    let mut series = StaticDataSeries::new();
+
+    for i in 0..points {
+        let x = i as f32;
+        let y = amplitude * sin(frequency * x + phase);
+        series.push(Point2D::new(x, y)).map_err(ChartError::from)?;
+    }
+
+    Ok(series)
+}
+
+/// Generate hourly temperature readings following a daily cycle
+///
+/// The profile is coolest around 06:00 and warmest around 18:00, centered on
+/// 20 °C with ±5 °C swing and up to ±1.5 °C of seeded sensor noise.
+pub fn temperature_profile<const N: usize>(
+    hours: usize,
+    seed: u32,
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    let mut series = StaticDataSeries::new();
+    let mut rng = DemoRng::new(seed);
+
+    for i in 0..hours {
+        let hour = i as f32;
+        let daily_cycle = 5.0 * sin(2.0 * PI * hour / 24.0 - PI / 2.0);
+        let temperature = 20.0 + daily_cycle + rng.noise(1.5);
+
+        series
+            .push(Point2D::new(hour, temperature))
+            .map_err(ChartError::from)?;
+    }
+
+    Ok(series)
+}
+
+/// Generate system monitoring data as percentages in `[0, 100]`
+pub fn system_metrics<const N: usize>(
+    points: usize,
+    metric: SystemMetric,
+    seed: u32,
+) -> ChartResult<StaticDataSeries<Point2D, N>> {
+    let mut series = StaticDataSeries::new();
+    let mut rng = DemoRng::new(seed);
+
+    for i in 0..points {
+        let time = i as f32;
+        let value = match metric {
+            SystemMetric::CpuUsage => {
+                let spike = if i % 20 == 0 { 30.0 } else { 0.0 };
+                25.0 + 15.0 * sin(time * 0.1) + spike + rng.noise(3.0)
+            }
+            SystemMetric::MemoryUsage => {
+                40.0 + time * 0.5 + 5.0 * cos(time * 0.15) + rng.noise(1.0)
+            }
+            SystemMetric::NetworkIO => {
+                let burst = if i % 15 == 0 { 40.0 } else { 0.0 };
+                10.0 + 8.0 * sin(time * 0.2) + burst + rng.noise(4.0)
+            }
+            SystemMetric::DiskUsage => {
+                let wave = sin(time * 0.05);
+                let activity = 20.0 * if wave < 0.0 { -wave } else { wave };
+                5.0 + activity + 3.0 * cos(time * 0.3) + rng.noise(1.0)
+            }
+        };
+
+        series
+            .push(Point2D::new(time, value.clamp(0.0, 100.0)))
+            .map_err(ChartError::from)?;
+    }
+
+    Ok(series)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataSeries;
+
+    #[test]
+    fn test_seeded_generators_are_deterministic() {
+        let a: StaticDataSeries<Point2D, 32> = temperature_profile(24, 42).unwrap();
+        let b: StaticDataSeries<Point2D, 32> = temperature_profile(24, 42).unwrap();
+        let c: StaticDataSeries<Point2D, 32> = temperature_profile(24, 43).unwrap();
+
+        assert_eq!(a.as_slice(), b.as_slice());
+        assert_ne!(a.as_slice(), c.as_slice());
+        assert!(a.iter().all(|p| (13.0..=27.0).contains(&p.y)));
+    }
+
+    #[test]
+    fn test_system_metrics_and_capacity() {
+        for metric in [
+            SystemMetric::CpuUsage,
+            SystemMetric::MemoryUsage,
+            SystemMetric::NetworkIO,
+            SystemMetric::DiskUsage,
+        ] {
+            let series: StaticDataSeries<Point2D, 64> = system_metrics(64, metric, 1).unwrap();
+            assert!(series.iter().all(|p| (0.0..=100.0).contains(&p.y)));
+        }
+
+        let overflow: ChartResult<StaticDataSeries<Point2D, 8>> = sine_wave(9, 1.0, 0.5, 0.0);
+        assert!(overflow.is_err());
+    }
+}
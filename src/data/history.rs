@@ -0,0 +1,412 @@
+//! Multi-resolution history for data loggers.
+//!
+//! A [`TieredHistory`] keeps the same signal at three resolutions, such as the
+//! last five minutes at one second, the last day at one minute and the last
+//! month at one hour. Every sample is folded into the open bucket of each
+//! tier, so a tier never has to be rebuilt from a finer one and all storage
+//! has a fixed size.
+//!
+//! Each bucket keeps the minimum, maximum and mean of its samples. A query for
+//! a time range picks the finest tier that still reaches back to the start of
+//! the range, so zooming out moves to coarser data automatically.
+//!
+//! Timestamps are `u64` in any unit, typically Unix seconds. Query results use
+//! offsets from the start of the range as X values, the same values a
+//! [`TimeAxis`](crate::axes::TimeAxis) created for that range expects.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::data::{DataSeries, Point2D, TieredHistory};
+//!
+//! // 1 s for 2 minutes, 10 s for 20 minutes, 1 min for 2 hours
+//! let mut history: TieredHistory<120, 120, 120> = TieredHistory::new(1, 10, 60);
+//! for second in 0..3600 {
+//!     history.push(1_709_640_000 + second, 20.0)?;
+//! }
+//!
+//! // The last minute comes from the 1 s tier
+//! let recent = history.query(1_709_643_540, 1_709_643_599);
+//! assert_eq!(recent.resolution(), 1);
+//!
+//! // The whole hour only fits in the 1 min tier
+//! let hour = history.query(1_709_640_000, 1_709_643_599);
+//! assert_eq!(hour.resolution(), 60);
+//! let series = hour.series::<64>()?;
+//! assert_eq!(series.len(), 60);
+//! assert_eq!(series.get(1), Some(Point2D::new(60.0, 20.0)));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::data::point::Point2D;
+use crate::data::series::StaticDataSeries;
+use crate::error::{ChartError, ChartResult};
+use heapless::Deque;
+
+/// Aggregated samples for one interval of a history tier
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryBucket {
+    /// Timestamp at the start of the interval
+    pub start: u64,
+    /// Smallest sample in the interval
+    pub min: f32,
+    /// Largest sample in the interval
+    pub max: f32,
+    /// Number of samples in the interval
+    pub count: u32,
+    sum: f32,
+}
+
+impl HistoryBucket {
+    fn new(start: u64, value: f32) -> Self {
+        Self {
+            start,
+            min: value,
+            max: value,
+            count: 1,
+            sum: value,
+        }
+    }
+
+    fn add(&mut self, value: f32) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.count = self.count.saturating_add(1);
+        self.sum += value;
+    }
+
+    /// Mean of the samples in the interval
+    pub fn mean(&self) -> f32 {
+        self.sum / self.count as f32
+    }
+}
+
+/// One resolution of a [`TieredHistory`]
+///
+/// Keeps up to `N` completed buckets plus the bucket currently being filled.
+/// The oldest bucket is dropped when a new one is completed on a full tier.
+#[derive(Debug, Clone)]
+pub struct HistoryTier<const N: usize> {
+    resolution: u64,
+    buckets: Deque<HistoryBucket, N>,
+    open: Option<HistoryBucket>,
+}
+
+impl<const N: usize> HistoryTier<N> {
+    /// Create an empty tier with buckets `resolution` timestamp units wide
+    ///
+    /// A resolution of zero is treated as one.
+    pub const fn new(resolution: u64) -> Self {
+        Self {
+            resolution: if resolution == 0 { 1 } else { resolution },
+            buckets: Deque::new(),
+            open: None,
+        }
+    }
+
+    /// Width of each bucket in timestamp units
+    pub fn resolution(&self) -> u64 {
+        self.resolution
+    }
+
+    /// Maximum number of completed buckets
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of buckets, including the one being filled
+    pub fn len(&self) -> usize {
+        self.buckets.len() + usize::from(self.open.is_some())
+    }
+
+    /// Check whether the tier holds no samples
+    pub fn is_empty(&self) -> bool {
+        self.open.is_none()
+    }
+
+    /// Start of the oldest bucket
+    pub fn oldest(&self) -> Option<u64> {
+        self.buckets
+            .front()
+            .or(self.open.as_ref())
+            .map(|bucket| bucket.start)
+    }
+
+    /// Start of the bucket being filled
+    pub fn newest(&self) -> Option<u64> {
+        self.open.map(|bucket| bucket.start)
+    }
+
+    /// Add a sample
+    ///
+    /// Returns [`ChartError::InvalidData`] for a timestamp that falls before
+    /// the bucket being filled.
+    pub fn push(&mut self, timestamp: u64, value: f32) -> ChartResult<()> {
+        let start = timestamp - timestamp % self.resolution;
+
+        if let Some(open) = self.open.as_mut() {
+            if start < open.start {
+                return Err(ChartError::InvalidData);
+            }
+            if start == open.start {
+                open.add(value);
+                return Ok(());
+            }
+        }
+
+        if let Some(done) = self.open.take() {
+            if self.buckets.is_full() {
+                self.buckets.pop_front();
+            }
+            // Only fails for a tier without capacity, which keeps no history
+            let _ = self.buckets.push_back(done);
+        }
+        self.open = Some(HistoryBucket::new(start, value));
+        Ok(())
+    }
+
+    /// Remove all buckets
+    pub fn clear(&mut self) {
+        self.buckets.clear();
+        self.open = None;
+    }
+
+    /// Buckets in chronological order, independent of the tier capacity
+    pub fn view(&self) -> HistoryView<'_> {
+        let (older, newer) = self.buckets.as_slices();
+        HistoryView {
+            resolution: self.resolution,
+            older,
+            newer,
+            open: self.open.as_ref(),
+        }
+    }
+}
+
+/// Read-only view of the buckets of one tier
+#[derive(Debug, Clone, Copy)]
+pub struct HistoryView<'a> {
+    resolution: u64,
+    older: &'a [HistoryBucket],
+    newer: &'a [HistoryBucket],
+    open: Option<&'a HistoryBucket>,
+}
+
+impl<'a> HistoryView<'a> {
+    /// Width of each bucket in timestamp units
+    pub fn resolution(&self) -> u64 {
+        self.resolution
+    }
+
+    /// All buckets in chronological order
+    pub fn buckets(&self) -> impl Iterator<Item = &'a HistoryBucket> + Clone {
+        self.older.iter().chain(self.newer).chain(self.open)
+    }
+
+    /// Start of the oldest bucket
+    pub fn oldest(&self) -> Option<u64> {
+        self.buckets().next().map(|bucket| bucket.start)
+    }
+}
+
+/// Buckets of one tier overlapping a time range
+#[derive(Debug, Clone, Copy)]
+pub struct HistoryQuery<'a> {
+    view: HistoryView<'a>,
+    from: u64,
+    to: u64,
+}
+
+impl<'a> HistoryQuery<'a> {
+    /// Width of each bucket in timestamp units
+    pub fn resolution(&self) -> u64 {
+        self.view.resolution
+    }
+
+    /// Buckets overlapping the range, in chronological order
+    pub fn buckets(&self) -> impl Iterator<Item = &'a HistoryBucket> + Clone {
+        let (from, to, resolution) = (self.from, self.to, self.view.resolution);
+        self.view
+            .buckets()
+            .filter(move |bucket| bucket.start <= to && bucket.start + resolution > from)
+    }
+
+    /// Bucket means as chart points
+    ///
+    /// X is the offset of the bucket start from the start of the range, so the
+    /// first bucket may lie slightly left of zero. The iterator can be passed
+    /// to [`LineChart::draw_points`](crate::chart::LineChart::draw_points)
+    /// without copying the data.
+    pub fn points(&self) -> impl Iterator<Item = Point2D> + Clone + 'a {
+        let from = self.from;
+        self.buckets().map(move |bucket| {
+            let x = bucket.start as i64 - from as i64;
+            Point2D::new(x as f32, bucket.mean())
+        })
+    }
+
+    /// Collect the bucket means into a data series
+    ///
+    /// Fails with a capacity error when the range holds more than `M` buckets;
+    /// a capacity of at least the largest tier never fails.
+    pub fn series<const M: usize>(&self) -> ChartResult<StaticDataSeries<Point2D, M>> {
+        let mut series = StaticDataSeries::new();
+        for point in self.points() {
+            series.push(point)?;
+        }
+        Ok(series)
+    }
+}
+
+/// History kept at three resolutions, from fine and recent to coarse and old
+///
+/// `FINE`, `MEDIUM` and `COARSE` are the bucket capacities of the three tiers.
+/// Each resolution should be a multiple of the one before it, so that
+/// samples arriving in order never fall behind the bucket being filled in
+/// any tier.
+#[derive(Debug, Clone)]
+pub struct TieredHistory<const FINE: usize, const MEDIUM: usize, const COARSE: usize> {
+    fine: HistoryTier<FINE>,
+    medium: HistoryTier<MEDIUM>,
+    coarse: HistoryTier<COARSE>,
+}
+
+/// Data logger history in Unix seconds
+///
+/// Five minutes at 1 s, a day at 1 min and 30 days at 1 h, created with
+/// `DataLoggerHistory::new(1, 60, 3600)`. Takes about 59 KiB.
+pub type DataLoggerHistory = TieredHistory<300, 1440, 720>;
+
+impl<const FINE: usize, const MEDIUM: usize, const COARSE: usize>
+    TieredHistory<FINE, MEDIUM, COARSE>
+{
+    /// Create an empty history with the given bucket widths
+    pub const fn new(fine_resolution: u64, medium_resolution: u64, coarse_resolution: u64) -> Self {
+        Self {
+            fine: HistoryTier::new(fine_resolution),
+            medium: HistoryTier::new(medium_resolution),
+            coarse: HistoryTier::new(coarse_resolution),
+        }
+    }
+
+    /// Add a sample to every tier
+    ///
+    /// Samples must arrive in order. A timestamp before the bucket being
+    /// filled in the fine tier is rejected with [`ChartError::InvalidData`]
+    /// and leaves the history unchanged.
+    pub fn push(&mut self, timestamp: u64, value: f32) -> ChartResult<()> {
+        self.fine.push(timestamp, value)?;
+        self.medium.push(timestamp, value)?;
+        self.coarse.push(timestamp, value)
+    }
+
+    /// The tier with the finest resolution
+    pub fn fine(&self) -> &HistoryTier<FINE> {
+        &self.fine
+    }
+
+    /// The tier with the middle resolution
+    pub fn medium(&self) -> &HistoryTier<MEDIUM> {
+        &self.medium
+    }
+
+    /// The tier with the coarsest resolution
+    pub fn coarse(&self) -> &HistoryTier<COARSE> {
+        &self.coarse
+    }
+
+    /// Check whether no samples have been added
+    pub fn is_empty(&self) -> bool {
+        self.fine.is_empty()
+    }
+
+    /// Remove all samples
+    pub fn clear(&mut self) {
+        self.fine.clear();
+        self.medium.clear();
+        self.coarse.clear();
+    }
+
+    /// Buckets covering `from..=to` from the finest tier that reaches `from`
+    ///
+    /// When no tier reaches back that far, the tier with the oldest data is
+    /// used and the result starts later than requested.
+    pub fn query(&self, from: u64, to: u64) -> HistoryQuery<'_> {
+        let tiers = [self.fine.view(), self.medium.view(), self.coarse.view()];
+        let covering = tiers
+            .iter()
+            .find(|view| view.oldest().is_some_and(|oldest| oldest <= from));
+        let oldest = tiers
+            .iter()
+            .filter(|view| view.oldest().is_some())
+            .min_by_key(|view| view.oldest());
+
+        HistoryQuery {
+            view: covering.or(oldest).copied().unwrap_or(tiers[0]),
+            from,
+            to,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataSeries;
+
+    #[test]
+    fn test_tier_aggregates_and_drops_oldest() {
+        let mut tier: HistoryTier<2> = HistoryTier::new(10);
+        for (timestamp, value) in [(0, 1.0), (5, 3.0), (12, 4.0), (25, 6.0), (31, 8.0)] {
+            tier.push(timestamp, value).unwrap();
+        }
+
+        // Bucket 0 was dropped; 10 and 20 are complete, 30 is being filled
+        assert_eq!(tier.len(), 3);
+        assert_eq!(tier.oldest(), Some(10));
+        assert_eq!(tier.newest(), Some(30));
+        assert_eq!(tier.push(29, 0.0), Err(ChartError::InvalidData));
+
+        let mut tier: HistoryTier<4> = HistoryTier::new(10);
+        tier.push(0, 1.0).unwrap();
+        tier.push(5, 3.0).unwrap();
+        let bucket = *tier.view().buckets().next().unwrap();
+        assert_eq!((bucket.min, bucket.max, bucket.count), (1.0, 3.0, 2));
+        assert_eq!(bucket.mean(), 2.0);
+    }
+
+    #[test]
+    fn test_query_picks_finest_covering_tier() {
+        let mut history: TieredHistory<10, 10, 10> = TieredHistory::new(1, 10, 100);
+        assert!(history.is_empty());
+        for t in 0..500 {
+            history.push(1000 + t, t as f32).unwrap();
+        }
+
+        // The fine tier holds the last 11 seconds
+        assert_eq!(history.query(1495, 1499).resolution(), 1);
+        // The medium tier holds the last 110 seconds
+        assert_eq!(history.query(1400, 1499).resolution(), 10);
+        assert_eq!(history.query(1000, 1499).resolution(), 100);
+        // Older than anything kept: fall back to the coarsest data
+        assert_eq!(history.query(0, 1499).resolution(), 100);
+
+        let query = history.query(1400, 1449);
+        let series = query.series::<16>().unwrap();
+        assert_eq!(series.len(), 5);
+        assert_eq!(series.get(0), Some(Point2D::new(0.0, 404.5)));
+        assert_eq!(series.get(4).unwrap().x, 40.0);
+    }
+
+    #[test]
+    fn test_rejected_sample_leaves_history_unchanged() {
+        let mut history: TieredHistory<4, 4, 4> = TieredHistory::new(1, 10, 100);
+        history.push(50, 1.0).unwrap();
+        assert_eq!(history.push(49, 2.0), Err(ChartError::InvalidData));
+        assert_eq!(history.coarse().view().buckets().next().unwrap().count, 1);
+
+        history.clear();
+        assert!(history.is_empty());
+        assert_eq!(history.query(0, 100).buckets().count(), 0);
+    }
+}
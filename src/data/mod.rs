@@ -127,6 +127,12 @@
 //! # Ok::<(), embedded_charts::error::DataError>(())
 //! ```
 //!
+//! ## Multi-Resolution History
+//!
+//! [`TieredHistory`] keeps recent data at a fine resolution and older data in
+//! coarser buckets, and answers a time range query from the finest tier that
+//! still covers it. See the [`history`] module.
+//!
 //! ## Memory Efficiency
 //!
 //! All data structures use static allocation for predictable memory usage:
@@ -187,18 +193,28 @@
 
 pub mod aggregation;
 pub mod bounds;
+#[cfg(feature = "demo-data")]
+pub mod demo;
+pub mod history;
+pub mod persistence;
 pub mod point;
 pub mod ring_buffer;
 pub mod series;
+pub mod stats;
+pub mod transforms;
 
 #[cfg(feature = "animations")]
 pub mod streaming;
 
 pub use aggregation::*;
 pub use bounds::*;
+pub use history::*;
+pub use persistence::*;
 pub use point::*;
 pub use ring_buffer::*;
 pub use series::*;
+pub use stats::*;
+pub use transforms::*;
 
 #[cfg(feature = "animations")]
 pub use streaming::*;
@@ -0,0 +1,551 @@
+//! Persistence of streaming data to flash-like storage.
+//!
+//! A [`RingLog`] appends points to a user-provided [`LogStorage`] backend as a
+//! circular log of fixed-size records, so a device can restore the last
+//! minutes of a chart after a reboot. Writes are buffered and flushed in
+//! chunks, and every sector is erased only when the log wraps around to it,
+//! spreading wear evenly across the whole region.
+//!
+//! Each record holds a sequence number, the point and a check word. Records
+//! that were torn by a power loss or never written fail the check and are
+//! skipped when the log is opened or read back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::data::{LogStorage, PersistentSeries, Point2D, RingBuffer, RingLog};
+//! use embedded_charts::error::ChartResult;
+//!
+//! /// RAM stand-in for a flash region with 64 byte sectors
+//! struct Ram([u8; 256]);
+//!
+//! impl LogStorage for Ram {
+//!     fn capacity(&self) -> u32 { 256 }
+//!     fn erase_size(&self) -> u32 { 64 }
+//!     fn read(&mut self, offset: u32, bytes: &mut [u8]) -> ChartResult<()> {
+//!         let start = offset as usize;
+//!         bytes.copy_from_slice(&self.0[start..start + bytes.len()]);
+//!         Ok(())
+//!     }
+//!     fn write(&mut self, offset: u32, bytes: &[u8]) -> ChartResult<()> {
+//!         let start = offset as usize;
+//!         self.0[start..start + bytes.len()].copy_from_slice(bytes);
+//!         Ok(())
+//!     }
+//!     fn erase(&mut self, from: u32, to: u32) -> ChartResult<()> {
+//!         self.0[from as usize..to as usize].fill(0xFF);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut log: RingLog<Ram> = RingLog::open(Ram([0xFF; 256]))?;
+//! let mut window: RingBuffer<Point2D, 8> = RingBuffer::new();
+//! for i in 0..5 {
+//!     window.push_logged(Point2D::new(i as f32, 20.0 + i as f32), &mut log)?;
+//! }
+//! log.flush()?;
+//!
+//! // After a reboot
+//! let mut log: RingLog<Ram> = RingLog::open(log.into_storage())?;
+//! let mut restored: RingBuffer<Point2D, 8> = RingBuffer::new();
+//! assert_eq!(restored.restore_from(&mut log)?, 5);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::data::point::Point2D;
+use crate::data::ring_buffer::RingBuffer;
+use crate::error::{ChartError, ChartResult};
+use heapless::Vec;
+
+#[cfg(feature = "flash-storage")]
+use embedded_storage::nor_flash::NorFlash;
+
+/// Size of one log record in bytes
+pub const LOG_RECORD_SIZE: u32 = 16;
+
+/// Number of records encoded per storage write during a flush
+const WRITE_BATCH: usize = 8;
+
+/// Mixed into the check word so erased or zeroed storage never validates
+const RECORD_MAGIC: u32 = 0x4543_4C47;
+
+/// Storage backend for a [`RingLog`].
+///
+/// The interface mirrors NOR flash: a region of `capacity` bytes divided into
+/// sectors of `erase_size` bytes that must be erased before being written.
+/// Backends report failures as [`ChartError::StorageError`].
+pub trait LogStorage {
+    /// Size of the storage region in bytes
+    fn capacity(&self) -> u32;
+
+    /// Size of an erasable sector in bytes
+    fn erase_size(&self) -> u32;
+
+    /// Required alignment of write offsets and lengths in bytes
+    fn write_size(&self) -> u32 {
+        1
+    }
+
+    /// Read `bytes.len()` bytes starting at `offset`
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> ChartResult<()>;
+
+    /// Write `bytes` starting at `offset` into erased storage
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> ChartResult<()>;
+
+    /// Erase the sectors covering `from..to`
+    fn erase(&mut self, from: u32, to: u32) -> ChartResult<()>;
+}
+
+/// Adapter that stores a [`RingLog`] in any `embedded-storage` NOR flash
+#[cfg(feature = "flash-storage")]
+#[derive(Debug)]
+pub struct NorFlashStorage<F> {
+    flash: F,
+}
+
+#[cfg(feature = "flash-storage")]
+impl<F: NorFlash> NorFlashStorage<F> {
+    /// Wrap a flash driver or partition
+    pub fn new(flash: F) -> Self {
+        Self { flash }
+    }
+
+    /// Get the wrapped flash back
+    pub fn into_inner(self) -> F {
+        self.flash
+    }
+}
+
+#[cfg(feature = "flash-storage")]
+impl<F: NorFlash> LogStorage for NorFlashStorage<F> {
+    fn capacity(&self) -> u32 {
+        self.flash.capacity() as u32
+    }
+
+    fn erase_size(&self) -> u32 {
+        F::ERASE_SIZE as u32
+    }
+
+    fn write_size(&self) -> u32 {
+        F::WRITE_SIZE as u32
+    }
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> ChartResult<()> {
+        self.flash
+            .read(offset, bytes)
+            .map_err(|_| ChartError::StorageError)
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> ChartResult<()> {
+        self.flash
+            .write(offset, bytes)
+            .map_err(|_| ChartError::StorageError)
+    }
+
+    fn erase(&mut self, from: u32, to: u32) -> ChartResult<()> {
+        self.flash
+            .erase(from, to)
+            .map_err(|_| ChartError::StorageError)
+    }
+}
+
+/// Circular log of data points in a [`LogStorage`] region.
+///
+/// Points are buffered until `CHUNK` of them are pending, then written in one
+/// pass. Call [`flush`](Self::flush) before a planned shutdown to persist the
+/// remainder.
+#[derive(Debug)]
+pub struct RingLog<S: LogStorage, const CHUNK: usize = 8> {
+    storage: S,
+    slots: u32,
+    erase_size: u32,
+    next_seq: u32,
+    pending: Vec<Point2D, CHUNK>,
+}
+
+impl<S: LogStorage, const CHUNK: usize> RingLog<S, CHUNK> {
+    /// Open the log in `storage`, resuming after the newest valid record.
+    ///
+    /// The region must hold at least two sectors, so that erasing the sector
+    /// being rewritten always leaves older data behind, and records must not
+    /// straddle sectors or violate the write alignment.
+    pub fn open(mut storage: S) -> ChartResult<Self> {
+        let erase_size = storage.erase_size();
+        let write_size = storage.write_size().max(1);
+        let sectors = storage.capacity() / erase_size.max(1);
+        if erase_size == 0
+            || sectors < 2
+            || erase_size % LOG_RECORD_SIZE != 0
+            || LOG_RECORD_SIZE % write_size != 0
+        {
+            return Err(ChartError::InvalidConfiguration);
+        }
+
+        let slots = sectors * erase_size / LOG_RECORD_SIZE;
+        let mut newest: Option<u32> = None;
+        for slot in 0..slots {
+            if let Some((seq, _)) = Self::read_slot(&mut storage, slot)? {
+                newest = Some(newest.map_or(seq, |current| current.max(seq)));
+            }
+        }
+
+        // A torn record cannot be overwritten in place, so continue in the
+        // next sector, which is erased before it is written
+        let mut next_seq = newest.map_or(0, |seq| seq.wrapping_add(1));
+        let slots_per_sector = erase_size / LOG_RECORD_SIZE;
+        if next_seq % slots_per_sector != 0 && !Self::slot_is_blank(&mut storage, next_seq % slots)?
+        {
+            next_seq = next_seq.wrapping_add(slots_per_sector - next_seq % slots_per_sector);
+        }
+
+        Ok(Self {
+            storage,
+            slots,
+            erase_size,
+            next_seq,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Append a point, flushing when `CHUNK` points are pending
+    pub fn append(&mut self, point: Point2D) -> ChartResult<()> {
+        if self.pending.is_full() {
+            self.flush()?;
+        }
+        self.pending
+            .push(point)
+            .map_err(|_| ChartError::MemoryFull)?;
+        self.next_seq = self.next_seq.wrapping_add(1);
+        if self.pending.is_full() {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write all pending points to storage
+    pub fn flush(&mut self) -> ChartResult<()> {
+        let first_seq = self.next_seq.wrapping_sub(self.pending.len() as u32);
+        let mut buffer = [0u8; WRITE_BATCH * LOG_RECORD_SIZE as usize];
+        let mut index = 0;
+
+        while index < self.pending.len() {
+            let seq = first_seq.wrapping_add(index as u32);
+            let offset = (seq % self.slots) * LOG_RECORD_SIZE;
+            if offset % self.erase_size == 0 {
+                self.storage.erase(offset, offset + self.erase_size)?;
+            }
+
+            // Batch records up to the end of the current sector
+            let sector_room = (self.erase_size - offset % self.erase_size) / LOG_RECORD_SIZE;
+            let run = (sector_room as usize)
+                .min(self.pending.len() - index)
+                .min(WRITE_BATCH);
+            for i in 0..run {
+                let start = i * LOG_RECORD_SIZE as usize;
+                encode_record(
+                    seq.wrapping_add(i as u32),
+                    self.pending[index + i],
+                    &mut buffer[start..start + LOG_RECORD_SIZE as usize],
+                );
+            }
+            self.storage
+                .write(offset, &buffer[..run * LOG_RECORD_SIZE as usize])?;
+            index += run;
+        }
+
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Read back up to `max` of the newest points, oldest first.
+    ///
+    /// Pending points that were not flushed yet are included. Returns the
+    /// number of points passed to `visit`.
+    pub fn read_recent<F>(&mut self, max: usize, mut visit: F) -> ChartResult<usize>
+    where
+        F: FnMut(Point2D),
+    {
+        let pending = self.pending.len() as u32;
+        let stored_end = self.next_seq.wrapping_sub(pending);
+        let wanted = (max as u32).min(self.slots + pending);
+        let from_storage = wanted.saturating_sub(pending).min(stored_end);
+
+        let mut visited = 0;
+        for i in 0..from_storage {
+            let seq = stored_end - from_storage + i;
+            if let Some((stored_seq, point)) = Self::read_slot(&mut self.storage, seq % self.slots)?
+            {
+                // Slots overwritten or erased since do not carry the expected number
+                if stored_seq == seq {
+                    visit(point);
+                    visited += 1;
+                }
+            }
+        }
+
+        let skip = self.pending.len() - (wanted.min(pending) as usize);
+        for &point in self.pending.iter().skip(skip) {
+            visit(point);
+            visited += 1;
+        }
+        Ok(visited)
+    }
+
+    /// Number of records the storage region holds
+    pub fn slot_count(&self) -> u32 {
+        self.slots
+    }
+
+    /// Number of points appended but not yet written
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Get the storage backend
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Take the storage backend back, discarding pending points
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    fn slot_is_blank(storage: &mut S, slot: u32) -> ChartResult<bool> {
+        let mut record = [0u8; LOG_RECORD_SIZE as usize];
+        storage.read(slot * LOG_RECORD_SIZE, &mut record)?;
+        Ok(record.iter().all(|&b| b == 0xFF))
+    }
+
+    fn read_slot(storage: &mut S, slot: u32) -> ChartResult<Option<(u32, Point2D)>> {
+        let mut record = [0u8; LOG_RECORD_SIZE as usize];
+        storage.read(slot * LOG_RECORD_SIZE, &mut record)?;
+        Ok(decode_record(&record))
+    }
+}
+
+/// Series that can be mirrored into and restored from a [`RingLog`]
+pub trait PersistentSeries {
+    /// Push a point into the series and append it to `log`
+    fn push_logged<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        point: Point2D,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<()>;
+
+    /// Refill the series with the newest points from `log`, returning how many were restored
+    fn restore_from<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<usize>;
+}
+
+impl<const N: usize> PersistentSeries for RingBuffer<Point2D, N> {
+    fn push_logged<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        point: Point2D,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<()> {
+        self.push_point(point)?;
+        log.append(point)
+    }
+
+    fn restore_from<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<usize> {
+        let mut result = Ok(());
+        let restored = log.read_recent(N, |point| {
+            if result.is_ok() {
+                result = self.push_point(point);
+            }
+        })?;
+        result.map(|_| restored)
+    }
+}
+
+#[cfg(feature = "animations")]
+impl<const N: usize> PersistentSeries for crate::data::series::SlidingWindowSeries<Point2D, N> {
+    fn push_logged<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        point: Point2D,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<()> {
+        self.push(point);
+        log.append(point)
+    }
+
+    fn restore_from<S: LogStorage, const CHUNK: usize>(
+        &mut self,
+        log: &mut RingLog<S, CHUNK>,
+    ) -> ChartResult<usize> {
+        log.read_recent(N, |point| self.push(point))
+    }
+}
+
+fn check_word(seq: u32, x: u32, y: u32) -> u32 {
+    seq.rotate_left(7) ^ x.rotate_left(13) ^ y ^ RECORD_MAGIC
+}
+
+fn encode_record(seq: u32, point: Point2D, out: &mut [u8]) {
+    let x = point.x.to_bits();
+    let y = point.y.to_bits();
+    out[0..4].copy_from_slice(&seq.to_le_bytes());
+    out[4..8].copy_from_slice(&x.to_le_bytes());
+    out[8..12].copy_from_slice(&y.to_le_bytes());
+    out[12..16].copy_from_slice(&check_word(seq, x, y).to_le_bytes());
+}
+
+fn decode_record(record: &[u8; LOG_RECORD_SIZE as usize]) -> Option<(u32, Point2D)> {
+    let word = |index: usize| {
+        u32::from_le_bytes([
+            record[index],
+            record[index + 1],
+            record[index + 2],
+            record[index + 3],
+        ])
+    };
+    let (seq, x, y) = (word(0), word(4), word(8));
+    (word(12) == check_word(seq, x, y))
+        .then(|| (seq, Point2D::new(f32::from_bits(x), f32::from_bits(y))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RAM flash model that counts operations and rejects writes to unerased bytes
+    struct MockFlash {
+        bytes: [u8; 256],
+        writes: usize,
+        erases: usize,
+    }
+
+    impl MockFlash {
+        fn new() -> Self {
+            Self {
+                bytes: [0xFF; 256],
+                writes: 0,
+                erases: 0,
+            }
+        }
+    }
+
+    impl LogStorage for MockFlash {
+        fn capacity(&self) -> u32 {
+            256
+        }
+
+        fn erase_size(&self) -> u32 {
+            64
+        }
+
+        fn write_size(&self) -> u32 {
+            4
+        }
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> ChartResult<()> {
+            let start = offset as usize;
+            bytes.copy_from_slice(&self.bytes[start..start + bytes.len()]);
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> ChartResult<()> {
+            let start = offset as usize;
+            let target = &mut self.bytes[start..start + bytes.len()];
+            if target.iter().any(|&b| b != 0xFF) {
+                return Err(ChartError::StorageError);
+            }
+            target.copy_from_slice(bytes);
+            self.writes += 1;
+            Ok(())
+        }
+
+        fn erase(&mut self, from: u32, to: u32) -> ChartResult<()> {
+            self.bytes[from as usize..to as usize].fill(0xFF);
+            self.erases += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ring_log_restores_after_reopen() {
+        let mut log: RingLog<MockFlash, 4> = RingLog::open(MockFlash::new()).unwrap();
+        assert_eq!(log.slot_count(), 16);
+
+        // Wrap the 16 slot log almost twice
+        for i in 0..30 {
+            log.append(Point2D::new(i as f32, i as f32 * 2.0)).unwrap();
+        }
+        log.flush().unwrap();
+
+        // Writes are batched, and each sector is erased once per lap
+        assert_eq!(log.storage().writes, 8);
+        assert_eq!(log.storage().erases, 8);
+
+        let mut log: RingLog<MockFlash, 4> = RingLog::open(log.into_storage()).unwrap();
+        let mut restored: RingBuffer<Point2D, 8> = RingBuffer::new();
+        assert_eq!(restored.restore_from(&mut log).unwrap(), 8);
+        let xs: Vec<f32, 8> = restored.iter_chronological().map(|p| p.x).collect();
+        assert_eq!(
+            xs.as_slice(),
+            &[22.0, 23.0, 24.0, 25.0, 26.0, 27.0, 28.0, 29.0]
+        );
+
+        // Appending continues the sequence instead of restarting it
+        log.append(Point2D::new(30.0, 60.0)).unwrap();
+        let mut newest = Point2D::new(0.0, 0.0);
+        log.read_recent(1, |point| newest = point).unwrap();
+        assert_eq!(newest, Point2D::new(30.0, 60.0));
+    }
+
+    #[test]
+    fn test_ring_log_skips_torn_records() {
+        let mut log: RingLog<MockFlash, 4> = RingLog::open(MockFlash::new()).unwrap();
+        for i in 0..4 {
+            log.append(Point2D::new(i as f32, 0.0)).unwrap();
+        }
+        let mut flash = log.into_storage();
+        // Corrupt the newest record as if power failed mid-write
+        flash.bytes[3 * LOG_RECORD_SIZE as usize + 5] ^= 0x10;
+
+        let mut log: RingLog<MockFlash, 4> = RingLog::open(flash).unwrap();
+        let mut count = 0;
+        log.read_recent(16, |_| count += 1).unwrap();
+        assert_eq!(count, 3);
+
+        // New records skip the damaged sector instead of failing to write
+        for i in 0..4 {
+            log.append(Point2D::new(10.0 + i as f32, 0.0)).unwrap();
+        }
+        let mut newest = Vec::<f32, 16>::new();
+        log.read_recent(16, |point| newest.push(point.x).unwrap())
+            .unwrap();
+        assert_eq!(newest.as_slice(), &[0.0, 1.0, 2.0, 10.0, 11.0, 12.0, 13.0]);
+    }
+
+    #[test]
+    fn test_ring_log_rejects_unsuitable_storage() {
+        struct OneSector;
+        impl LogStorage for OneSector {
+            fn capacity(&self) -> u32 {
+                64
+            }
+            fn erase_size(&self) -> u32 {
+                64
+            }
+            fn read(&mut self, _: u32, _: &mut [u8]) -> ChartResult<()> {
+                Ok(())
+            }
+            fn write(&mut self, _: u32, _: &[u8]) -> ChartResult<()> {
+                Ok(())
+            }
+            fn erase(&mut self, _: u32, _: u32) -> ChartResult<()> {
+                Ok(())
+            }
+        }
+
+        let result: ChartResult<RingLog<OneSector>> = RingLog::open(OneSector);
+        assert_eq!(result.err(), Some(ChartError::InvalidConfiguration));
+    }
+}
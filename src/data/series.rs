@@ -3,6 +3,7 @@
 use crate::data::bounds::DataBounds;
 use crate::data::point::DataPoint;
 use crate::error::{DataError, DataResult};
+use crate::heapless_utils::{LabelId, LabelLookup};
 use heapless::Vec;
 
 /// Memory-efficient iterator for StaticDataSeries that uses index-based access
@@ -96,6 +97,7 @@ where
 {
     data: Vec<T, N>,
     label: Option<heapless::String<32>>,
+    label_id: Option<LabelId>,
 }
 
 impl<T, const N: usize> StaticDataSeries<T, N>
@@ -107,6 +109,7 @@ where
         Self {
             data: Vec::new(),
             label: None,
+            label_id: None,
         }
     }
 
@@ -130,6 +133,23 @@ where
         self.label.as_ref().map(|s| s.as_str())
     }
 
+    /// Refer to a label interned in a shared [`LabelPool`](crate::heapless_utils::LabelPool)
+    pub fn set_label_id(&mut self, id: LabelId) {
+        self.label_id = Some(id);
+    }
+
+    /// Get the pooled label handle for this series
+    pub fn label_id(&self) -> Option<LabelId> {
+        self.label_id
+    }
+
+    /// Get the label for this series, resolving a pooled handle first
+    pub fn label_in<'a>(&'a self, pool: &'a dyn LabelLookup) -> Option<&'a str> {
+        self.label_id
+            .and_then(|id| pool.resolve(id))
+            .or_else(|| self.label())
+    }
+
     /// Add a data point to the series
     pub fn push(&mut self, point: T) -> DataResult<()> {
         self.data
@@ -466,6 +486,125 @@ where
     }
 }
 
+/// Read-only data series backed by a `'static` slice of `(x, y)` tuples.
+///
+/// The points are read directly from the slice, so a large reference curve
+/// declared as a `static` or `const` array stays in flash instead of being
+/// copied into a RAM-based [`StaticDataSeries`]. Arrays of any length coerce
+/// to the slice, so `&CURVE` works for `static CURVE: [(f32, f32); N]`.
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+///
+/// static REFERENCE: [(f32, f32); 3] = [(0.0, 0.0), (1.0, 0.8), (2.0, 1.9)];
+/// const SERIES: ConstDataSeries = ConstDataSeries::new(&REFERENCE).with_label("Reference");
+///
+/// assert_eq!(SERIES.len(), 3);
+/// assert_eq!(SERIES.get(1), Some(Point2D::new(1.0, 0.8)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConstDataSeries {
+    data: &'static [(f32, f32)],
+    label: Option<&'static str>,
+}
+
+impl ConstDataSeries {
+    /// Create a series reading from the given points
+    pub const fn new(data: &'static [(f32, f32)]) -> Self {
+        Self { data, label: None }
+    }
+
+    /// Set the label for this series
+    pub const fn with_label(mut self, label: &'static str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Get the label for this series
+    pub fn label(&self) -> Option<&'static str> {
+        self.label
+    }
+
+    /// Get the underlying points
+    pub fn as_slice(&self) -> &'static [(f32, f32)] {
+        self.data
+    }
+
+    /// Get the bounds of this data series
+    pub fn bounds(&self) -> DataResult<DataBounds<f32, f32>> {
+        use crate::data::bounds::calculate_bounds;
+        calculate_bounds(self.iter())
+    }
+
+    /// Copy the points into a [`StaticDataSeries`] for charts that take owned data
+    ///
+    /// Fails if the series has more points than the target capacity.
+    pub fn to_static_series<const N: usize>(
+        &self,
+    ) -> DataResult<StaticDataSeries<crate::data::point::Point2D, N>> {
+        let mut series = StaticDataSeries::from_tuples(self.data)?;
+        if let Some(label) = self.label {
+            series.set_label(label);
+        }
+        Ok(series)
+    }
+}
+
+/// Iterator over the points of a [`ConstDataSeries`]
+#[derive(Debug, Clone)]
+pub struct ConstDataSeriesIter {
+    data: core::slice::Iter<'static, (f32, f32)>,
+}
+
+impl Iterator for ConstDataSeriesIter {
+    type Item = crate::data::point::Point2D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data
+            .next()
+            .map(|&(x, y)| crate::data::point::Point2D::new(x, y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ConstDataSeriesIter {}
+
+impl DataSeries for ConstDataSeries {
+    type Item = crate::data::point::Point2D;
+    type Iter = ConstDataSeriesIter;
+
+    fn iter(&self) -> Self::Iter {
+        ConstDataSeriesIter {
+            data: self.data.iter(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        self.data
+            .get(index)
+            .map(|&(x, y)| crate::data::point::Point2D::new(x, y))
+    }
+}
+
+/// Which Y axis a series in a [`MultiSeries`] is plotted against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesAxis {
+    /// The primary Y axis, usually on the left
+    #[default]
+    Primary,
+    /// The secondary Y axis, usually on the right, with its own range
+    Secondary,
+}
+
 /// A multi-series container for holding multiple data series
 #[derive(Debug, Clone)]
 pub struct MultiSeries<T, const SERIES: usize, const POINTS: usize>
@@ -473,6 +612,8 @@ where
     T: DataPoint,
 {
     series: Vec<StaticDataSeries<T, POINTS>, SERIES>,
+    /// Bit `i` is set when series `i` is plotted against the secondary axis
+    secondary: u64,
 }
 
 impl<T, const SERIES: usize, const POINTS: usize> MultiSeries<T, SERIES, POINTS>
@@ -481,18 +622,81 @@ where
 {
     /// Create a new empty multi-series container
     pub fn new() -> Self {
-        Self { series: Vec::new() }
+        Self {
+            series: Vec::new(),
+            secondary: 0,
+        }
     }
 
-    /// Add a new data series
+    /// Add a new data series plotted against the primary Y axis
     pub fn add_series(&mut self, series: StaticDataSeries<T, POINTS>) -> DataResult<usize> {
+        self.add_series_on(series, SeriesAxis::Primary)
+    }
+
+    /// Add a new data series plotted against the given Y axis
+    pub fn add_series_on(
+        &mut self,
+        series: StaticDataSeries<T, POINTS>,
+        axis: SeriesAxis,
+    ) -> DataResult<usize> {
         let index = self.series.len();
+        if axis == SeriesAxis::Secondary && index >= u64::BITS as usize {
+            return Err(DataError::buffer_full("add secondary axis series", 64));
+        }
         self.series
             .push(series)
             .map_err(|_| DataError::buffer_full("add data series", SERIES))?;
+        self.set_series_axis(index, axis)?;
         Ok(index)
     }
 
+    /// Assign the series at `index` to the primary or secondary Y axis
+    ///
+    /// Only the first 64 series can be assigned to the secondary axis.
+    pub fn set_series_axis(&mut self, index: usize, axis: SeriesAxis) -> DataResult<()> {
+        if index >= self.series.len() {
+            return Err(DataError::index_out_of_bounds(
+                "set series axis",
+                index,
+                self.series.len(),
+            ));
+        }
+        match (axis, 1u64.checked_shl(index as u32)) {
+            (SeriesAxis::Secondary, Some(bit)) => self.secondary |= bit,
+            (SeriesAxis::Secondary, None) => {
+                return Err(DataError::buffer_full("set secondary axis series", 64))
+            }
+            (SeriesAxis::Primary, Some(bit)) => self.secondary &= !bit,
+            (SeriesAxis::Primary, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Get the Y axis the series at `index` is plotted against
+    pub fn series_axis(&self, index: usize) -> Option<SeriesAxis> {
+        if index >= self.series.len() {
+            return None;
+        }
+        let secondary = 1u64
+            .checked_shl(index as u32)
+            .is_some_and(|bit| self.secondary & bit != 0);
+        Some(if secondary {
+            SeriesAxis::Secondary
+        } else {
+            SeriesAxis::Primary
+        })
+    }
+
+    /// Get an iterator over all series paired with the Y axis they are plotted against
+    pub fn iter_series_with_axes(
+        &self,
+    ) -> impl Iterator<Item = (&StaticDataSeries<T, POINTS>, SeriesAxis)> + Clone + '_ {
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(index, series)| (series, self.series_axis(index).unwrap_or_default()))
+    }
+
     /// Get a reference to a series by index
     pub fn get_series(&self, index: usize) -> Option<&StaticDataSeries<T, POINTS>> {
         self.series.get(index)
@@ -518,6 +722,20 @@ where
         self.series.iter()
     }
 
+    /// Get an iterator over all series paired with their palette color
+    ///
+    /// Series `i` is paired with `palette.color(i)`, matching legend entries
+    /// built from the same palette.
+    pub fn iter_series_with_colors<'a, C, const N: usize>(
+        &'a self,
+        palette: &'a crate::style::SeriesPalette<C, N>,
+    ) -> impl Iterator<Item = (&'a StaticDataSeries<T, POINTS>, C)> + 'a
+    where
+        C: embedded_graphics::prelude::PixelColor,
+    {
+        self.series.iter().zip(palette.colors())
+    }
+
     /// Calculate combined bounds for all series
     pub fn combined_bounds(&self) -> DataResult<DataBounds<T::X, T::Y>>
     where
@@ -546,6 +764,7 @@ where
     /// Clear all series
     pub fn clear(&mut self) {
         self.series.clear();
+        self.secondary = 0;
     }
 }
 
@@ -647,7 +866,10 @@ where
     }
 
     /// Get an iterator over the current data points in chronological order
-    pub fn iter_chronological(&self) -> impl Iterator<Item = T> + '_ {
+    ///
+    /// The iterator borrows the buffer, so cloning it is cheap; this makes it
+    /// suitable for `LineChart::draw_points`.
+    pub fn iter_chronological(&self) -> impl Iterator<Item = T> + Clone + '_ {
         let start_idx = if self.full { self.head } else { 0 };
         let len = if self.full { N } else { self.count };
 
@@ -733,6 +955,20 @@ mod tests {
         assert_eq!(series.get(2), Some(Point2D::new(5.0, 6.0)));
     }
 
+    #[test]
+    fn test_static_series_pooled_label() {
+        let mut pool: crate::heapless_utils::LabelPool<32, 4> =
+            crate::heapless_utils::LabelPool::new();
+        let id = pool.intern("Sensor A").unwrap();
+
+        let mut series: StaticDataSeries<Point2D, 10> = StaticDataSeries::with_label("Fallback");
+        assert_eq!(series.label_in(&pool), Some("Fallback"));
+
+        series.set_label_id(id);
+        assert_eq!(series.label_id(), Some(id));
+        assert_eq!(series.label_in(&pool), Some("Sensor A"));
+    }
+
     #[test]
     fn test_multi_series() {
         let mut multi: MultiSeries<Point2D, 5, 10> = MultiSeries::new();
@@ -748,6 +984,57 @@ mod tests {
         assert_eq!(retrieved_series.len(), 1);
     }
 
+    #[test]
+    fn test_multi_series_axes() {
+        let mut multi: MultiSeries<Point2D, 4, 10> = MultiSeries::new();
+        let temperature = multi.add_series(StaticDataSeries::new()).unwrap();
+        let pressure = multi
+            .add_series_on(StaticDataSeries::new(), SeriesAxis::Secondary)
+            .unwrap();
+
+        assert_eq!(multi.series_axis(temperature), Some(SeriesAxis::Primary));
+        assert_eq!(multi.series_axis(pressure), Some(SeriesAxis::Secondary));
+        assert_eq!(multi.series_axis(2), None);
+
+        multi
+            .set_series_axis(pressure, SeriesAxis::Primary)
+            .unwrap();
+        multi
+            .set_series_axis(temperature, SeriesAxis::Secondary)
+            .unwrap();
+        let axes: heapless::Vec<SeriesAxis, 4> = multi
+            .iter_series_with_axes()
+            .map(|(_, axis)| axis)
+            .collect();
+        assert_eq!(
+            axes.as_slice(),
+            &[SeriesAxis::Secondary, SeriesAxis::Primary]
+        );
+        assert!(multi.set_series_axis(2, SeriesAxis::Secondary).is_err());
+
+        multi.clear();
+        multi.add_series(StaticDataSeries::new()).unwrap();
+        assert_eq!(multi.series_axis(0), Some(SeriesAxis::Primary));
+    }
+
+    #[test]
+    fn test_sized_series_aliases_ram_cost() {
+        use crate::prelude::types::{
+            LargeMultiSeries, LargeSeries, MediumMultiSeries, MediumSeries, SmallMultiSeries,
+            SmallSeries,
+        };
+        use core::mem::size_of;
+
+        // Keep the documented RAM estimates honest: points plus a small overhead
+        assert!(size_of::<SmallSeries>() <= 64 * 8 + 64);
+        assert!(size_of::<MediumSeries>() <= 256 * 8 + 64);
+        assert!(size_of::<LargeSeries>() <= 1024 * 8 + 64);
+        assert!(size_of::<SmallMultiSeries>() <= 4 * size_of::<SmallSeries>() + 16);
+        assert!(size_of::<MediumMultiSeries>() <= 8 * size_of::<MediumSeries>() + 16);
+        assert!(size_of::<LargeMultiSeries>() <= 8 * size_of::<LargeSeries>() + 16);
+        assert_eq!(SmallSeries::new().capacity(), 64);
+    }
+
     #[cfg(feature = "animations")]
     #[test]
     fn test_sliding_window_series() {
@@ -771,4 +1058,25 @@ mod tests {
         assert_eq!(points[1], Point2D::new(3.0, 3.0));
         assert_eq!(points[2], Point2D::new(4.0, 4.0));
     }
+
+    #[test]
+    fn test_const_data_series() {
+        static CURVE: [(f32, f32); 4] = [(0.0, 1.0), (1.0, 3.0), (2.0, -2.0), (3.0, 0.0)];
+        let series = ConstDataSeries::new(&CURVE).with_label("Reference");
+
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.label(), Some("Reference"));
+        assert_eq!(series.get(2), Some(Point2D::new(2.0, -2.0)));
+        assert_eq!(series.get(4), None);
+        assert_eq!(series.iter().count(), 4);
+
+        let bounds = series.bounds().unwrap();
+        assert_eq!(bounds.min_y, -2.0);
+        assert_eq!(bounds.max_x, 3.0);
+
+        let copy: StaticDataSeries<Point2D, 8> = series.to_static_series().unwrap();
+        assert_eq!(copy.len(), 4);
+        assert_eq!(copy.label(), Some("Reference"));
+        assert!(series.to_static_series::<2>().is_err());
+    }
 }
@@ -0,0 +1,643 @@
+//! Summary statistics for data series.
+//!
+//! [`SeriesStatistics::describe`] reduces a series to its count, extremes,
+//! mean, standard deviation and end points in a single pass. The standard
+//! deviation's square root goes through the active math backend, so the same
+//! call works with floating-point, fixed-point and integer math.
+//!
+//! `stats()` of [`StaticDataSeries`] and `SlidingWindowSeries` adds
+//! percentiles to that summary, sorting a copy of the values on the stack.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//!
+//! let data = data_points![(0.0, 2.0), (1.0, 4.0), (2.0, 6.0)];
+//! let stats = data.describe()?;
+//!
+//! assert_eq!(stats.mean, 4.0);
+//! assert_eq!((stats.first, stats.last), (2.0, 6.0));
+//! # Ok::<(), embedded_charts::error::DataError>(())
+//! ```
+
+#[cfg(feature = "animations")]
+use crate::data::SlidingWindowSeries;
+use crate::data::{DataPoint, DataSeries, StaticDataSeries};
+use crate::error::{DataError, DataResult};
+use crate::math::{Math, NumericConversion};
+use heapless::Vec;
+
+/// Summary of the y values of a data series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    /// Number of points
+    pub count: usize,
+    /// Smallest value
+    pub min: f32,
+    /// Largest value
+    pub max: f32,
+    /// Arithmetic mean
+    pub mean: f32,
+    /// Population standard deviation
+    pub std_dev: f32,
+    /// Value of the first point
+    pub first: f32,
+    /// Value of the last point
+    pub last: f32,
+}
+
+impl SeriesStats {
+    /// Calculate summary statistics of `values`
+    ///
+    /// Returns an insufficient data error if there are no values.
+    pub fn from_values<I>(values: I) -> DataResult<Self>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        let mut values = values.into_iter();
+        let first = values.next().ok_or(DataError::INSUFFICIENT_DATA)?;
+
+        let mut stats = SeriesStats {
+            count: 1,
+            min: first,
+            max: first,
+            mean: first,
+            std_dev: 0.0,
+            first,
+            last: first,
+        };
+
+        // Welford's algorithm keeps the variance accurate for large offsets
+        let mut sum_sq_diff = 0.0;
+        for value in values {
+            stats.count += 1;
+            stats.min = stats.min.min(value);
+            stats.max = stats.max.max(value);
+            stats.last = value;
+
+            let delta = value - stats.mean;
+            stats.mean += delta / stats.count as f32;
+            sum_sq_diff += delta * (value - stats.mean);
+        }
+
+        let variance = sum_sq_diff / stats.count as f32;
+        stats.std_dev = f32::from_number(Math::sqrt(variance.to_number()));
+
+        Ok(stats)
+    }
+
+    /// Difference between the largest and smallest value
+    pub fn range(&self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Change from the first to the last value
+    pub fn change(&self) -> f32 {
+        self.last - self.first
+    }
+}
+
+/// Least-squares linear fit of a data series.
+///
+/// Besides the line itself the fit keeps what is needed for a prediction
+/// interval, so a forecast can be drawn with a widening confidence cone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearTrend {
+    /// Change of y per unit of x
+    pub slope: f32,
+    /// Value of the fitted line at x = 0
+    pub intercept: f32,
+    /// Standard deviation of the residuals around the line
+    pub residual_std_dev: f32,
+    /// X value of the last point of the series
+    pub last_x: f32,
+    count: usize,
+    mean_x: f32,
+    sum_sq_x: f32,
+}
+
+impl LinearTrend {
+    /// Fit a least-squares line through the points of `series`
+    ///
+    /// Needs at least two points with different x values.
+    pub fn fit<S>(series: &S) -> DataResult<Self>
+    where
+        S: DataSeries,
+        <S::Item as DataPoint>::X: Into<f32>,
+        <S::Item as DataPoint>::Y: Into<f32>,
+    {
+        Self::fit_with(|| series.iter())
+    }
+
+    /// Fit a least-squares line through `points` without collecting them
+    ///
+    /// The iterator is cloned for each pass over the points, so borrowing
+    /// iterators such as a ring buffer's are never copied into a series.
+    pub fn fit_points<P, I>(points: I) -> DataResult<Self>
+    where
+        P: DataPoint,
+        P::X: Into<f32>,
+        P::Y: Into<f32>,
+        I: Iterator<Item = P> + Clone,
+    {
+        Self::fit_with(|| points.clone())
+    }
+
+    fn fit_with<P, I, F>(points: F) -> DataResult<Self>
+    where
+        P: DataPoint,
+        P::X: Into<f32>,
+        P::Y: Into<f32>,
+        I: Iterator<Item = P>,
+        F: Fn() -> I,
+    {
+        // First pass: means, so the sums below are taken around the center
+        let mut count = 0usize;
+        let mut sum_x = 0.0f32;
+        let mut sum_y = 0.0f32;
+        let mut last_x = 0.0f32;
+        for point in points() {
+            let x: f32 = point.x().into();
+            let y: f32 = point.y().into();
+            count += 1;
+            sum_x += x;
+            sum_y += y;
+            last_x = x;
+        }
+        if count < 2 {
+            return Err(DataError::INSUFFICIENT_DATA);
+        }
+        let mean_x = sum_x / count as f32;
+        let mean_y = sum_y / count as f32;
+
+        // Second pass: centered sums for the slope
+        let mut sum_sq_x = 0.0f32;
+        let mut sum_xy = 0.0f32;
+        for point in points() {
+            let (x, y): (f32, f32) = (point.x().into(), point.y().into());
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            sum_sq_x += dx * dx;
+            sum_xy += dx * dy;
+        }
+        if sum_sq_x <= 0.0 {
+            return Err(DataError::INSUFFICIENT_DATA);
+        }
+        let slope = sum_xy / sum_sq_x;
+        let intercept = mean_y - slope * mean_x;
+
+        // Third pass: residual spread, with two degrees of freedom used by the fit
+        let mut sum_sq_residual = 0.0f32;
+        for point in points() {
+            let (x, y): (f32, f32) = (point.x().into(), point.y().into());
+            let residual = y - (intercept + slope * x);
+            sum_sq_residual += residual * residual;
+        }
+        let residual_variance = if count > 2 {
+            sum_sq_residual / (count - 2) as f32
+        } else {
+            0.0
+        };
+
+        Ok(Self {
+            slope,
+            intercept,
+            residual_std_dev: f32::from_number(Math::sqrt(residual_variance.to_number())),
+            last_x,
+            count,
+            mean_x,
+            sum_sq_x,
+        })
+    }
+
+    /// Value of the fitted line at `x`
+    pub fn value_at(&self, x: f32) -> f32 {
+        self.intercept + self.slope * x
+    }
+
+    /// X value where the fitted line reaches `level`, if it ever does
+    pub fn x_at(&self, level: f32) -> Option<f32> {
+        if self.slope == 0.0 {
+            None
+        } else {
+            Some((level - self.intercept) / self.slope)
+        }
+    }
+
+    /// Distance in x from the last point until the trend reaches `level`.
+    ///
+    /// This is the "estimated time to full / empty" of a dashboard. Returns
+    /// `None` if the trend is flat or moves away from `level`.
+    pub fn time_until(&self, level: f32) -> Option<f32> {
+        self.x_at(level)
+            .map(|x| x - self.last_x)
+            .filter(|remaining| *remaining >= 0.0)
+    }
+
+    /// Half width of the prediction interval at `x`, in units of y.
+    ///
+    /// `sigmas` scales the interval; 2.0 covers roughly 95% of new values when
+    /// the residuals are normally distributed.
+    pub fn spread_at(&self, x: f32, sigmas: f32) -> f32 {
+        if self.count == 0 || self.sum_sq_x <= 0.0 {
+            return 0.0;
+        }
+        let offset = x - self.mean_x;
+        let factor = 1.0 + 1.0 / self.count as f32 + offset * offset / self.sum_sq_x;
+        sigmas * self.residual_std_dev * f32::from_number(Math::sqrt(factor.to_number()))
+    }
+}
+
+/// Least-squares fit of a parabola `y = constant + linear·x + quadratic·x²`.
+///
+/// The normal equations are solved around the center of the x range, scaled
+/// to -1..1, and the curve is evaluated around that center, so wide or offset
+/// x values such as timestamps keep their precision in `f32`. The public
+/// coefficients are for display; far from x = 0 they lose precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticTrend {
+    /// Value of the fitted curve at x = 0
+    pub constant: f32,
+    /// Coefficient of x
+    pub linear: f32,
+    /// Coefficient of x²
+    pub quadratic: f32,
+    /// Standard deviation of the residuals around the curve
+    pub residual_std_dev: f32,
+    center: f32,
+    /// Coefficients of powers of `x - center`
+    centered: [f32; 3],
+}
+
+impl QuadraticTrend {
+    /// Fit a least-squares parabola through the points of `series`
+    ///
+    /// Needs at least three points with different x values.
+    pub fn fit<S>(series: &S) -> DataResult<Self>
+    where
+        S: DataSeries,
+        <S::Item as DataPoint>::X: Into<f32>,
+        <S::Item as DataPoint>::Y: Into<f32>,
+    {
+        Self::fit_with(|| series.iter())
+    }
+
+    /// Fit a least-squares parabola through `points` without collecting them
+    ///
+    /// The iterator is cloned for each pass over the points.
+    pub fn fit_points<P, I>(points: I) -> DataResult<Self>
+    where
+        P: DataPoint,
+        P::X: Into<f32>,
+        P::Y: Into<f32>,
+        I: Iterator<Item = P> + Clone,
+    {
+        Self::fit_with(|| points.clone())
+    }
+
+    fn fit_with<P, I, F>(points: F) -> DataResult<Self>
+    where
+        P: DataPoint,
+        P::X: Into<f32>,
+        P::Y: Into<f32>,
+        I: Iterator<Item = P>,
+        F: Fn() -> I,
+    {
+        // First pass: center and half width of the x range
+        let mut count = 0usize;
+        let mut sum_x = 0.0f32;
+        let mut min_x = f32::INFINITY;
+        let mut max_x = f32::NEG_INFINITY;
+        for point in points() {
+            let x: f32 = point.x().into();
+            count += 1;
+            sum_x += x;
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+        }
+        if count < 3 {
+            return Err(DataError::INSUFFICIENT_DATA);
+        }
+        let center = sum_x / count as f32;
+        let scale = (max_x - center).max(center - min_x);
+        if scale <= 0.0 {
+            return Err(DataError::INSUFFICIENT_DATA);
+        }
+
+        // Second pass: power sums of the scaled x values for the normal equations
+        let mut sum_u = [0.0f32; 5];
+        let mut sum_uy = [0.0f32; 3];
+        for point in points() {
+            let (x, y): (f32, f32) = (point.x().into(), point.y().into());
+            let u = (x - center) / scale;
+            let mut power = 1.0;
+            for (index, sum) in sum_u.iter_mut().enumerate() {
+                *sum += power;
+                if let Some(sum) = sum_uy.get_mut(index) {
+                    *sum += power * y;
+                }
+                power *= u;
+            }
+        }
+        let [s0, s1, s2, s3, s4] = sum_u;
+        let [t0, t1, t2] = sum_uy;
+
+        // Cramer's rule; fewer than three distinct x values leave it singular
+        let det = det3([[s0, s1, s2], [s1, s2, s3], [s2, s3, s4]]);
+        if det.abs() <= s0 * s0 * s0 * 1e-6 {
+            return Err(DataError::INSUFFICIENT_DATA);
+        }
+        let a = det3([[t0, s1, s2], [t1, s2, s3], [t2, s3, s4]]) / det;
+        let b = det3([[s0, t0, s2], [s1, t1, s3], [s2, t2, s4]]) / det / scale;
+        let c = det3([[s0, s1, t0], [s1, s2, t1], [s2, s3, t2]]) / det / (scale * scale);
+
+        // Expand a + b·(x - center) + c·(x - center)² into powers of x
+        let mut trend = Self {
+            constant: a - b * center + c * center * center,
+            linear: b - 2.0 * c * center,
+            quadratic: c,
+            residual_std_dev: 0.0,
+            center,
+            centered: [a, b, c],
+        };
+
+        // Third pass: residual spread, with three degrees of freedom used by the fit
+        let mut sum_sq_residual = 0.0f32;
+        for point in points() {
+            let (x, y): (f32, f32) = (point.x().into(), point.y().into());
+            let offset = x - center;
+            let residual = y - (a + b * offset + c * offset * offset);
+            sum_sq_residual += residual * residual;
+        }
+        if count > 3 {
+            let variance = sum_sq_residual / (count - 3) as f32;
+            trend.residual_std_dev = f32::from_number(Math::sqrt(variance.to_number()));
+        }
+
+        Ok(trend)
+    }
+
+    /// Value of the fitted curve at `x`
+    pub fn value_at(&self, x: f32) -> f32 {
+        let [a, b, c] = self.centered;
+        let offset = x - self.center;
+        a + (b + c * offset) * offset
+    }
+
+    /// Slope of the fitted curve at `x`
+    pub fn slope_at(&self, x: f32) -> f32 {
+        let [_, b, c] = self.centered;
+        b + 2.0 * c * (x - self.center)
+    }
+
+    /// X value of the curve's minimum or maximum, if it isn't a straight line
+    pub fn vertex_x(&self) -> Option<f32> {
+        let [_, b, c] = self.centered;
+        (c != 0.0).then(|| self.center - b / (2.0 * c))
+    }
+}
+
+/// Determinant of a 3×3 matrix given by rows
+fn det3(m: [[f32; 3]; 3]) -> f32 {
+    let [[a, b, c], [d, e, f], [g, h, i]] = m;
+    a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+}
+
+/// Summary statistics for any data series with numeric y values.
+pub trait SeriesStatistics: DataSeries {
+    /// Calculate summary statistics of the y values
+    ///
+    /// Returns an insufficient data error for an empty series.
+    fn describe(&self) -> DataResult<SeriesStats>;
+}
+
+impl<S> SeriesStatistics for S
+where
+    S: DataSeries,
+    <S::Item as DataPoint>::Y: Into<f32>,
+{
+    fn describe(&self) -> DataResult<SeriesStats> {
+        SeriesStats::from_values(self.iter().map(|point| point.y().into()))
+    }
+}
+
+/// Summary statistics with percentiles, see [`StaticDataSeries::stats`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesSummary {
+    /// Count, extremes, mean and standard deviation
+    pub stats: SeriesStats,
+    /// 5th percentile
+    pub p5: f32,
+    /// 25th percentile, the lower quartile
+    pub p25: f32,
+    /// 50th percentile
+    pub median: f32,
+    /// 75th percentile, the upper quartile
+    pub p75: f32,
+    /// 95th percentile
+    pub p95: f32,
+}
+
+impl SeriesSummary {
+    /// Summarize the values of a series of at most `N` points
+    fn of<const N: usize, I>(values: I) -> DataResult<Self>
+    where
+        I: Iterator<Item = f32> + Clone,
+    {
+        let stats = SeriesStats::from_values(values.clone())?;
+        let sorted = sorted_values::<N, _>(values)?;
+        let at = |p| percentile(&sorted, p).unwrap_or(stats.mean);
+        Ok(Self {
+            stats,
+            p5: at(5.0),
+            p25: at(25.0),
+            median: at(50.0),
+            p75: at(75.0),
+            p95: at(95.0),
+        })
+    }
+
+    /// Spread of the middle half of the values
+    pub fn interquartile_range(&self) -> f32 {
+        self.p75 - self.p25
+    }
+}
+
+/// Get the `p`th percentile of ascending `sorted` values
+///
+/// `p` is clamped to 0..=100, and values between ranks are interpolated
+/// linearly. Returns `None` for an empty slice.
+pub fn percentile(sorted: &[f32], p: f32) -> Option<f32> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = p.clamp(0.0, 100.0) / 100.0 * last as f32;
+    let lower = f32::from_number(Math::floor(rank.to_number()));
+    let below = *sorted.get(lower as usize)?;
+    let above = sorted.get(lower as usize + 1).copied().unwrap_or(below);
+    Some(below + (above - below) * (rank - lower))
+}
+
+/// Copy up to `N` values into a buffer and sort it
+fn sorted_values<const N: usize, I>(values: I) -> DataResult<Vec<f32, N>>
+where
+    I: Iterator<Item = f32>,
+{
+    let mut sorted: Vec<f32, N> = Vec::new();
+    for value in values {
+        sorted
+            .push(value)
+            .map_err(|_| DataError::buffer_full("sort values", N))?;
+    }
+    sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+    Ok(sorted)
+}
+
+impl<T, const N: usize> StaticDataSeries<T, N>
+where
+    T: DataPoint + Clone,
+    T::Y: Into<f32>,
+{
+    /// Calculate summary statistics with percentiles of the y values
+    ///
+    /// Returns an insufficient data error for an empty series.
+    pub fn stats(&self) -> DataResult<SeriesSummary> {
+        SeriesSummary::of::<N, _>(self.as_slice().iter().map(|point| point.y().into()))
+    }
+
+    /// Get the `p`th percentile of the y values, see [`percentile`]
+    pub fn percentile(&self, p: f32) -> DataResult<f32> {
+        let sorted = sorted_values::<N, _>(self.as_slice().iter().map(|point| point.y().into()))?;
+        percentile(&sorted, p).ok_or(DataError::INSUFFICIENT_DATA)
+    }
+}
+
+#[cfg(feature = "animations")]
+impl<T, const N: usize> SlidingWindowSeries<T, N>
+where
+    T: DataPoint + Copy,
+    T::Y: Into<f32>,
+{
+    /// Calculate summary statistics with percentiles of the y values in the
+    /// window, from oldest to newest
+    ///
+    /// Returns an insufficient data error for an empty window.
+    pub fn stats(&self) -> DataResult<SeriesSummary> {
+        SeriesSummary::of::<N, _>(self.iter_chronological().map(|point| point.y().into()))
+    }
+
+    /// Get the `p`th percentile of the y values in the window, see
+    /// [`percentile`]
+    pub fn percentile(&self, p: f32) -> DataResult<f32> {
+        let sorted =
+            sorted_values::<N, _>(self.iter_chronological().map(|point| point.y().into()))?;
+        percentile(&sorted, p).ok_or(DataError::INSUFFICIENT_DATA)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Point2D, StaticDataSeries};
+
+    #[test]
+    fn test_describe() {
+        let data: StaticDataSeries<Point2D, 8> =
+            StaticDataSeries::from_tuples(&[(0.0, 2.0), (1.0, 4.0), (2.0, 4.0), (3.0, 6.0)])
+                .unwrap();
+        let stats = data.describe().unwrap();
+
+        assert_eq!(stats.count, 4);
+        assert_eq!((stats.min, stats.max), (2.0, 6.0));
+        assert_eq!(stats.mean, 4.0);
+        // micromath's sqrt is only accurate to a few percent
+        assert!((stats.std_dev - 1.414).abs() < 0.1);
+        assert_eq!(stats.change(), 4.0);
+    }
+
+    #[test]
+    fn test_linear_trend() {
+        let data: StaticDataSeries<Point2D, 8> =
+            StaticDataSeries::from_tuples(&[(0.0, 10.0), (1.0, 12.0), (2.0, 14.0), (3.0, 16.0)])
+                .unwrap();
+        let trend = LinearTrend::fit(&data).unwrap();
+
+        assert!((trend.slope - 2.0).abs() < 0.001);
+        assert!((trend.intercept - 10.0).abs() < 0.001);
+        assert!(trend.residual_std_dev < 0.01);
+        assert!((trend.time_until(20.0).unwrap() - 2.0).abs() < 0.001);
+        assert_eq!(trend.time_until(0.0), None);
+        assert!(trend.spread_at(10.0, 2.0) < 0.1);
+
+        let single: StaticDataSeries<Point2D, 8> =
+            StaticDataSeries::from_tuples(&[(1.0, 1.0)]).unwrap();
+        assert!(LinearTrend::fit(&single).is_err());
+    }
+
+    #[test]
+    fn test_quadratic_trend() {
+        // y = 1 + 2x - 0.5x², far from x = 0 like timestamps
+        let offset = 1000.0;
+        let mut data: StaticDataSeries<Point2D, 8> = StaticDataSeries::new();
+        for x in 0..6 {
+            let x = x as f32;
+            data.push(Point2D::new(offset + x, 1.0 + 2.0 * x - 0.5 * x * x))
+                .unwrap();
+        }
+        let trend = QuadraticTrend::fit(&data).unwrap();
+
+        assert!((trend.quadratic + 0.5).abs() < 0.001);
+        assert!((trend.value_at(offset + 2.0) - 3.0).abs() < 0.05);
+        assert!((trend.slope_at(offset) - 2.0).abs() < 0.01);
+        assert!((trend.vertex_x().unwrap() - (offset + 2.0)).abs() < 0.01);
+        assert!(trend.residual_std_dev < 0.05);
+
+        // Two distinct x values don't determine a parabola
+        let flat: StaticDataSeries<Point2D, 8> =
+            StaticDataSeries::from_tuples(&[(1.0, 1.0), (2.0, 2.0), (2.0, 3.0)]).unwrap();
+        assert!(QuadraticTrend::fit(&flat).is_err());
+    }
+
+    #[test]
+    fn test_stats_percentiles() {
+        let mut data: StaticDataSeries<Point2D, 16> = StaticDataSeries::new();
+        // 11 values from 0 to 100 in shuffled order
+        for (x, y) in [50, 10, 90, 0, 30, 100, 70, 20, 60, 80, 40]
+            .iter()
+            .enumerate()
+        {
+            data.push(Point2D::new(x as f32, *y as f32)).unwrap();
+        }
+        let summary = data.stats().unwrap();
+
+        assert_eq!(summary.stats.count, 11);
+        assert_eq!(summary.stats.mean, 50.0);
+        assert_eq!(summary.stats.first, 50.0);
+        assert_eq!(summary.median, 50.0);
+        assert_eq!((summary.p25, summary.p75), (25.0, 75.0));
+        assert_eq!((summary.p5, summary.p95), (5.0, 95.0));
+        assert_eq!(summary.interquartile_range(), 50.0);
+        assert_eq!(data.percentile(10.0).unwrap(), 10.0);
+        assert_eq!(percentile(&[1.0, 3.0], 50.0), Some(2.0));
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[cfg(feature = "animations")]
+    #[test]
+    fn test_sliding_window_stats() {
+        let mut window: SlidingWindowSeries<Point2D, 4> = SlidingWindowSeries::new();
+        for x in 0..6 {
+            window.push(Point2D::new(x as f32, x as f32));
+        }
+        // Only the last four values remain
+        let summary = window.stats().unwrap();
+        assert_eq!((summary.stats.min, summary.stats.max), (2.0, 5.0));
+        assert_eq!(summary.stats.first, 2.0);
+        assert_eq!(summary.median, 3.5);
+        assert_eq!(window.percentile(100.0).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_describe_empty() {
+        let data: StaticDataSeries<Point2D, 8> = StaticDataSeries::new();
+        assert!(data.describe().is_err());
+    }
+}
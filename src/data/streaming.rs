@@ -112,7 +112,7 @@ impl<const N: usize> UnifiedStreamingBuffer<N> {
 
         // Update performance metrics
         let end_time = self.get_current_time_us();
-        self.update_latency_metrics(end_time - start_time);
+        self.update_latency_metrics(end_time.wrapping_sub(start_time));
         self.update_memory_metrics();
 
         self.last_update = timestamp;
@@ -177,6 +177,17 @@ impl<const N: usize> UnifiedStreamingBuffer<N> {
         &self.config
     }
 
+    /// Timestamp passed to the last [`push_with_timestamp`](Self::push_with_timestamp)
+    pub fn last_update(&self) -> u32 {
+        self.last_update
+    }
+
+    /// Milliseconds since the last update, correct across a wrap of the
+    /// 32-bit millisecond counter
+    pub fn elapsed_since_update(&self, now: u32) -> u32 {
+        crate::time::wrapping_elapsed_ms(self.last_update, now)
+    }
+
     /// Prune old data points based on age
     fn prune_old_data(&mut self, _current_time: u32) -> ChartResult<()> {
         // For now, we'll implement a simple approach since we can't easily remove
@@ -223,7 +234,7 @@ impl<const N: usize> UnifiedStreamingBuffer<N> {
     /// Get current time in microseconds (placeholder implementation)
     fn get_current_time_us(&self) -> u32 {
         // In a real embedded system, this would use a high-resolution timer
-        self.get_current_time_ms() * 1000
+        self.get_current_time_ms().wrapping_mul(1000)
     }
 }
 
@@ -349,7 +360,7 @@ impl<T: Copy + Clone + DataPoint, const N: usize> StreamingDataPipeline<T, N> {
         // Update metrics if monitoring is enabled
         if self.config.monitoring_enabled {
             let end_time = self.get_current_time_us();
-            self.update_pipeline_metrics(end_time - start_time);
+            self.update_pipeline_metrics(end_time.wrapping_sub(start_time));
         }
 
         Ok(updated)
@@ -702,7 +713,7 @@ impl<const MAX_CHARTS: usize> StreamingChartManager<MAX_CHARTS> {
         // Update performance metrics if monitoring is enabled
         if self.config.monitoring_level != MonitoringLevel::None {
             let end_time = self.get_current_time_us();
-            self.update_manager_metrics(end_time - start_time);
+            self.update_manager_metrics(end_time.wrapping_sub(start_time));
         }
 
         Ok(())
@@ -748,7 +759,7 @@ impl<const MAX_CHARTS: usize> StreamingChartManager<MAX_CHARTS> {
 
     /// Get current time in microseconds (placeholder)
     fn get_current_time_us(&self) -> u32 {
-        self.get_current_time_ms() * 1000
+        self.get_current_time_ms().wrapping_mul(1000)
     }
 }
 
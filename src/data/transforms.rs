@@ -0,0 +1,546 @@
+//! Smoothing transforms for data series.
+//!
+//! A [`Transform`] turns a stream of y values into a derived stream, one
+//! value at a time: moving averages, a median filter against spikes, or the
+//! upper and lower edge of a min/max envelope. Transforms keep their window
+//! in a fixed buffer of up to [`MAX_TRANSFORM_WINDOW`] values and never
+//! allocate.
+//!
+//! Transforms compose with [`Transform::then`], or as a [`TransformPipeline`]
+//! where a single type is needed, such as the overlays of a line chart. They
+//! apply lazily while iterating with [`TransformSeries::transformed`], or
+//! produce a new series with [`TransformSeries::transform`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::data::transforms::{MedianFilter, MovingAverage, Transform, TransformSeries};
+//!
+//! let data = data_points![(0.0, 10.0), (1.0, 12.0), (2.0, 80.0), (3.0, 14.0), (4.0, 16.0)];
+//!
+//! // Drop the spike, then average what is left
+//! let smoothing = MedianFilter::new(3)?.then(MovingAverage::new(2)?);
+//! let smooth: StaticDataSeries<Point2D, 8> = data.transform(smoothing)?;
+//! assert!(smooth.iter().all(|point| point.y < 20.0));
+//! # Ok::<(), embedded_charts::error::DataError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::data::{DataPoint, DataSeries, Point2D, StaticDataSeries};
+use crate::error::{DataError, DataResult};
+use heapless::{Deque, Vec};
+
+/// Largest window a transform can hold
+pub const MAX_TRANSFORM_WINDOW: usize = 32;
+
+/// Number of stages a [`TransformPipeline`] can hold
+pub const MAX_PIPELINE_STAGES: usize = 4;
+
+/// Stateful transform of a stream of values
+///
+/// Windowed transforms start before their window is full, using the values
+/// seen so far, so the derived series has a value for every point.
+pub trait Transform {
+    /// Take the next value and return the transformed one
+    fn apply(&mut self, value: f32) -> f32;
+
+    /// Forget all values seen so far
+    fn reset(&mut self);
+
+    /// Feed the output of this transform into `next`
+    fn then<T: Transform>(self, next: T) -> Chain<Self, T>
+    where
+        Self: Sized,
+    {
+        Chain { first: self, next }
+    }
+}
+
+/// Two transforms applied one after the other, see [`Transform::then`]
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    next: B,
+}
+
+impl<A: Transform, B: Transform> Transform for Chain<A, B> {
+    fn apply(&mut self, value: f32) -> f32 {
+        self.next.apply(self.first.apply(value))
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.next.reset();
+    }
+}
+
+/// Last values seen by a windowed transform
+#[derive(Debug, Clone)]
+struct Window {
+    values: Deque<f32, MAX_TRANSFORM_WINDOW>,
+    size: usize,
+}
+
+impl Window {
+    fn new(size: usize) -> DataResult<Self> {
+        if size > MAX_TRANSFORM_WINDOW {
+            return Err(DataError::buffer_full(
+                "create transform window",
+                MAX_TRANSFORM_WINDOW,
+            ));
+        }
+        Ok(Self {
+            values: Deque::new(),
+            size: size.max(1),
+        })
+    }
+
+    fn push(&mut self, value: f32) {
+        if self.values.len() >= self.size {
+            self.values.pop_front();
+        }
+        // There is always room after dropping the oldest value
+        let _ = self.values.push_back(value);
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// Simple moving average over the last `size` values
+#[derive(Debug, Clone)]
+pub struct MovingAverage {
+    window: Window,
+}
+
+impl MovingAverage {
+    /// Average over windows of `size` values
+    ///
+    /// Returns an error if `size` exceeds [`MAX_TRANSFORM_WINDOW`].
+    pub fn new(size: usize) -> DataResult<Self> {
+        Ok(Self {
+            window: Window::new(size)?,
+        })
+    }
+}
+
+impl Transform for MovingAverage {
+    fn apply(&mut self, value: f32) -> f32 {
+        self.window.push(value);
+        // Summing the window each time avoids the drift of a running sum
+        let sum: f32 = self.window.values.iter().sum();
+        sum / self.window.values.len() as f32
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Exponential moving average
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialMovingAverage {
+    /// Weight of each new value, from 0 (ignore new values) to 1 (no smoothing)
+    pub alpha: f32,
+    value: Option<f32>,
+}
+
+impl ExponentialMovingAverage {
+    /// Weigh each new value by `alpha`, clamped to 0..=1
+    pub fn new(alpha: f32) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            value: None,
+        }
+    }
+
+    /// Smooth about as much as a moving average over `period` values
+    pub fn with_period(period: usize) -> Self {
+        Self::new(2.0 / (period.max(1) as f32 + 1.0))
+    }
+}
+
+impl Transform for ExponentialMovingAverage {
+    fn apply(&mut self, value: f32) -> f32 {
+        let smoothed = match self.value {
+            Some(previous) => previous + self.alpha * (value - previous),
+            None => value,
+        };
+        self.value = Some(smoothed);
+        smoothed
+    }
+
+    fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Median of the last `size` values, which removes isolated spikes
+#[derive(Debug, Clone)]
+pub struct MedianFilter {
+    window: Window,
+}
+
+impl MedianFilter {
+    /// Take the median of windows of `size` values
+    ///
+    /// Returns an error if `size` exceeds [`MAX_TRANSFORM_WINDOW`].
+    pub fn new(size: usize) -> DataResult<Self> {
+        Ok(Self {
+            window: Window::new(size)?,
+        })
+    }
+}
+
+impl Transform for MedianFilter {
+    fn apply(&mut self, value: f32) -> f32 {
+        self.window.push(value);
+        let mut sorted: Vec<f32, MAX_TRANSFORM_WINDOW> =
+            self.window.values.iter().copied().collect();
+        sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        // Even windows average the two middle values
+        let middle = sorted.len() / 2;
+        match (sorted.get(middle.wrapping_sub(1)), sorted.get(middle)) {
+            (Some(low), Some(high)) if sorted.len() % 2 == 0 => (low + high) / 2.0,
+            (_, Some(median)) => *median,
+            _ => value,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Edge of a min/max envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeEdge {
+    /// Largest value of the window
+    Upper,
+    /// Smallest value of the window
+    Lower,
+}
+
+/// One edge of the min/max envelope over the last `size` values
+///
+/// Draw an upper and a lower envelope of the same size to show the range a
+/// noisy signal moves in.
+#[derive(Debug, Clone)]
+pub struct Envelope {
+    /// Which edge is produced
+    pub edge: EnvelopeEdge,
+    window: Window,
+}
+
+impl Envelope {
+    /// Largest of the last `size` values
+    ///
+    /// Returns an error if `size` exceeds [`MAX_TRANSFORM_WINDOW`].
+    pub fn upper(size: usize) -> DataResult<Self> {
+        Ok(Self {
+            edge: EnvelopeEdge::Upper,
+            window: Window::new(size)?,
+        })
+    }
+
+    /// Smallest of the last `size` values
+    ///
+    /// Returns an error if `size` exceeds [`MAX_TRANSFORM_WINDOW`].
+    pub fn lower(size: usize) -> DataResult<Self> {
+        Ok(Self {
+            edge: EnvelopeEdge::Lower,
+            window: Window::new(size)?,
+        })
+    }
+}
+
+impl Transform for Envelope {
+    fn apply(&mut self, value: f32) -> f32 {
+        self.window.push(value);
+        let values = self.window.values.iter().copied();
+        match self.edge {
+            EnvelopeEdge::Upper => values.fold(value, f32::max),
+            EnvelopeEdge::Lower => values.fold(value, f32::min),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Any of the transforms of this module, so they can be stored as one type
+#[derive(Debug, Clone)]
+pub enum SeriesTransform {
+    /// Simple moving average
+    MovingAverage(MovingAverage),
+    /// Exponential moving average
+    Exponential(ExponentialMovingAverage),
+    /// Median filter
+    Median(MedianFilter),
+    /// Min/max envelope edge
+    Envelope(Envelope),
+}
+
+impl Transform for SeriesTransform {
+    fn apply(&mut self, value: f32) -> f32 {
+        match self {
+            Self::MovingAverage(transform) => transform.apply(value),
+            Self::Exponential(transform) => transform.apply(value),
+            Self::Median(transform) => transform.apply(value),
+            Self::Envelope(transform) => transform.apply(value),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::MovingAverage(transform) => transform.reset(),
+            Self::Exponential(transform) => transform.reset(),
+            Self::Median(transform) => transform.reset(),
+            Self::Envelope(transform) => transform.reset(),
+        }
+    }
+}
+
+impl From<MovingAverage> for SeriesTransform {
+    fn from(transform: MovingAverage) -> Self {
+        Self::MovingAverage(transform)
+    }
+}
+
+impl From<ExponentialMovingAverage> for SeriesTransform {
+    fn from(transform: ExponentialMovingAverage) -> Self {
+        Self::Exponential(transform)
+    }
+}
+
+impl From<MedianFilter> for SeriesTransform {
+    fn from(transform: MedianFilter) -> Self {
+        Self::Median(transform)
+    }
+}
+
+impl From<Envelope> for SeriesTransform {
+    fn from(transform: Envelope) -> Self {
+        Self::Envelope(transform)
+    }
+}
+
+/// Sequence of up to [`MAX_PIPELINE_STAGES`] transforms with a single type
+///
+/// Use it where a transform is stored, for example in a chart. An empty
+/// pipeline passes values through unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct TransformPipeline {
+    stages: Vec<SeriesTransform, MAX_PIPELINE_STAGES>,
+}
+
+impl TransformPipeline {
+    /// Create an empty pipeline
+    pub const fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Append a stage
+    ///
+    /// Returns an error if the pipeline already holds
+    /// [`MAX_PIPELINE_STAGES`] stages.
+    pub fn stage(mut self, transform: impl Into<SeriesTransform>) -> DataResult<Self> {
+        self.stages
+            .push(transform.into())
+            .map_err(|_| DataError::buffer_full("add transform stage", MAX_PIPELINE_STAGES))?;
+        Ok(self)
+    }
+
+    /// Get the stages in the order they are applied
+    pub fn stages(&self) -> &[SeriesTransform] {
+        &self.stages
+    }
+}
+
+impl<T: Into<SeriesTransform>> From<T> for TransformPipeline {
+    fn from(transform: T) -> Self {
+        let mut stages = Vec::new();
+        // A new pipeline always has room for one stage
+        let _ = stages.push(transform.into());
+        Self { stages }
+    }
+}
+
+impl Transform for TransformPipeline {
+    fn apply(&mut self, value: f32) -> f32 {
+        self.stages
+            .iter_mut()
+            .fold(value, |value, stage| stage.apply(value))
+    }
+
+    fn reset(&mut self) {
+        self.stages.iter_mut().for_each(Transform::reset);
+    }
+}
+
+/// Iterator applying a transform to the y values of points, see
+/// [`TransformSeries::transformed`]
+#[derive(Debug, Clone)]
+pub struct Transformed<I, T> {
+    points: I,
+    transform: T,
+}
+
+impl<I, T: Transform> Transformed<I, T> {
+    /// Apply `transform` to `points`, starting from a reset state
+    pub fn new(points: I, mut transform: T) -> Self {
+        transform.reset();
+        Self { points, transform }
+    }
+}
+
+impl<I, P, T> Iterator for Transformed<I, T>
+where
+    I: Iterator<Item = P>,
+    P: DataPoint,
+    P::X: Into<f32>,
+    P::Y: Into<f32>,
+    T: Transform,
+{
+    type Item = Point2D;
+
+    fn next(&mut self) -> Option<Point2D> {
+        let point = self.points.next()?;
+        let y = self.transform.apply(point.y().into());
+        Some(Point2D::new(point.x().into(), y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+}
+
+/// Transforms for any data series with numeric coordinates
+pub trait TransformSeries: DataSeries {
+    /// Iterate over the points with `transform` applied to their y values
+    fn transformed<T: Transform>(&self, transform: T) -> Transformed<Self::Iter, T> {
+        Transformed::new(self.iter(), transform)
+    }
+
+    /// Collect the transformed points into a new series
+    ///
+    /// Returns a buffer full error if the series has more than `N` points.
+    fn transform<T: Transform, const N: usize>(
+        &self,
+        transform: T,
+    ) -> DataResult<StaticDataSeries<Point2D, N>>;
+}
+
+impl<S> TransformSeries for S
+where
+    S: DataSeries,
+    <S::Item as DataPoint>::X: Into<f32>,
+    <S::Item as DataPoint>::Y: Into<f32>,
+{
+    fn transform<T: Transform, const N: usize>(
+        &self,
+        transform: T,
+    ) -> DataResult<StaticDataSeries<Point2D, N>> {
+        let mut series = StaticDataSeries::new();
+        for point in self.transformed(transform) {
+            series.push(point)?;
+        }
+        Ok(series)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_all<T: Transform>(transform: &mut T, values: &[f32]) -> Vec<f32, 16> {
+        values.iter().map(|&value| transform.apply(value)).collect()
+    }
+
+    #[test]
+    fn test_moving_averages() {
+        let mut average = MovingAverage::new(3).unwrap();
+        assert_eq!(
+            apply_all(&mut average, &[3.0, 6.0, 9.0, 12.0]).as_slice(),
+            &[3.0, 4.5, 6.0, 9.0]
+        );
+        average.reset();
+        assert_eq!(average.apply(1.0), 1.0);
+        assert!(MovingAverage::new(MAX_TRANSFORM_WINDOW + 1).is_err());
+
+        let mut ema = ExponentialMovingAverage::new(0.5);
+        assert_eq!(
+            apply_all(&mut ema, &[4.0, 8.0, 8.0]).as_slice(),
+            &[4.0, 6.0, 7.0]
+        );
+        assert_eq!(ExponentialMovingAverage::with_period(3).alpha, 0.5);
+    }
+
+    #[test]
+    fn test_median_and_envelope() {
+        let mut median = MedianFilter::new(3).unwrap();
+        assert_eq!(
+            apply_all(&mut median, &[1.0, 3.0, 100.0, 2.0, 4.0]).as_slice(),
+            &[1.0, 2.0, 3.0, 3.0, 4.0]
+        );
+
+        let values = [5.0, 1.0, 4.0, 2.0, 3.0];
+        let mut upper = Envelope::upper(2).unwrap();
+        let mut lower = Envelope::lower(2).unwrap();
+        assert_eq!(
+            apply_all(&mut upper, &values).as_slice(),
+            &[5.0, 5.0, 4.0, 4.0, 3.0]
+        );
+        assert_eq!(
+            apply_all(&mut lower, &values).as_slice(),
+            &[5.0, 1.0, 1.0, 2.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_chain_pipeline_and_series() {
+        let values = [1.0, 3.0, 100.0, 2.0, 4.0];
+        let mut chain = MedianFilter::new(3)
+            .unwrap()
+            .then(MovingAverage::new(2).unwrap());
+        let mut pipeline = TransformPipeline::from(MedianFilter::new(3).unwrap())
+            .stage(MovingAverage::new(2).unwrap())
+            .unwrap();
+        assert_eq!(pipeline.stages().len(), 2);
+        assert_eq!(
+            apply_all(&mut chain, &values),
+            apply_all(&mut pipeline, &values)
+        );
+
+        let mut full = TransformPipeline::new();
+        for _ in 0..MAX_PIPELINE_STAGES {
+            full = full.stage(ExponentialMovingAverage::new(0.5)).unwrap();
+        }
+        assert!(full.stage(ExponentialMovingAverage::new(0.5)).is_err());
+
+        // Transforming a series keeps x and restarts the transform
+        let data: StaticDataSeries<Point2D, 8> =
+            StaticDataSeries::from_tuples(&[(0.0, 2.0), (1.0, 4.0), (2.0, 6.0)]).unwrap();
+        let mut average = MovingAverage::new(2).unwrap();
+        average.apply(100.0);
+        let smooth: StaticDataSeries<Point2D, 8> = data.transform(average).unwrap();
+        assert_eq!(smooth.get(0), Some(Point2D::new(0.0, 2.0)));
+        assert_eq!(smooth.get(2), Some(Point2D::new(2.0, 5.0)));
+        assert!(data
+            .transform::<_, 2>(MovingAverage::new(2).unwrap())
+            .is_err());
+    }
+}
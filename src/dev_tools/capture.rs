@@ -0,0 +1,123 @@
+//! Screenshot and GIF capture of preview displays.
+
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay};
+use std::path::Path;
+
+/// Display size for README hero images
+pub const HERO_SIZE: Size = Size::new(800, 600);
+/// Display size for feature images
+pub const FEATURE_SIZE: Size = Size::new(400, 300);
+/// Display size for thumbnails
+pub const THUMBNAIL_SIZE: Size = Size::new(200, 150);
+
+/// What a preview window records, see [`WindowConfig::capture`](super::WindowConfig::capture)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureMode {
+    /// Save one PNG of the rendered frame
+    Screenshot,
+    /// Record the frames as a GIF, saved when the window closes
+    Animation,
+}
+
+/// Convert the display content to an RGB image
+fn to_rgb_image(display: &SimulatorDisplay<Rgb565>) -> image::RgbImage {
+    let output_image = display.to_rgb_output_image(&OutputSettingsBuilder::new().build());
+    let size = output_image.size();
+    let mut img_buffer = image::RgbImage::new(size.width, size.height);
+
+    for (x, y, pixel) in output_image.as_image_buffer().enumerate_pixels() {
+        img_buffer.put_pixel(x, y, image::Rgb([pixel.0[0], pixel.0[1], pixel.0[2]]));
+    }
+    img_buffer
+}
+
+/// Save the display content as a PNG
+///
+/// # Errors
+///
+/// Returns [`ChartError::StorageError`] if the file cannot be written.
+pub fn capture_screenshot<P: AsRef<Path>>(
+    display: &SimulatorDisplay<Rgb565>,
+    path: P,
+) -> ChartResult<()> {
+    to_rgb_image(display)
+        .save(path)
+        .map_err(|_| ChartError::StorageError)
+}
+
+/// Frames recorded for a GIF animation.
+#[derive(Debug)]
+pub struct GifCapture {
+    frames: Vec<image::RgbImage>,
+    delay_ms: u16,
+}
+
+impl GifCapture {
+    /// Start a capture showing each frame for `delay_ms` milliseconds
+    pub fn new(delay_ms: u16) -> Self {
+        Self {
+            frames: Vec::new(),
+            delay_ms,
+        }
+    }
+
+    /// Record the current display content as the next frame
+    pub fn add_frame(&mut self, display: &SimulatorDisplay<Rgb565>) {
+        self.frames.push(to_rgb_image(display));
+    }
+
+    /// Get the number of recorded frames
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Encode the recorded frames as a looping GIF
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChartError::StorageError`] if the file cannot be written.
+    pub fn save_gif<P: AsRef<Path>>(&self, path: P) -> ChartResult<()> {
+        use image::codecs::gif::{GifEncoder, Repeat};
+        use image::{Delay, DynamicImage, Frame};
+
+        let file = std::fs::File::create(path).map_err(|_| ChartError::StorageError)?;
+        let mut encoder = GifEncoder::new(file);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|_| ChartError::StorageError)?;
+
+        let delay = Delay::from_numer_denom_ms(u32::from(self.delay_ms), 1);
+        for frame in &self.frames {
+            let rgba_image = DynamicImage::ImageRgb8(frame.clone()).to_rgba8();
+            encoder
+                .encode_frame(Frame::from_parts(rgba_image, 0, 0, delay))
+                .map_err(|_| ChartError::StorageError)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gif_capture_saves_frames() {
+        let display = SimulatorDisplay::<Rgb565>::new(Size::new(8, 8));
+        let mut gif = GifCapture::new(50);
+        gif.add_frame(&display);
+        gif.add_frame(&display);
+        assert_eq!(gif.frame_count(), 2);
+
+        let path = std::env::temp_dir().join(format!(
+            "embedded_charts_capture_test_{}.gif",
+            std::process::id()
+        ));
+        gif.save_gif(&path).unwrap();
+        assert!(path.exists());
+        std::fs::remove_file(path).unwrap();
+    }
+}
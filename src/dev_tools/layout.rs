@@ -0,0 +1,82 @@
+//! Automatic legend layout for previews.
+
+use crate::error::ChartResult;
+use crate::legend::{Legend, LegendRenderer};
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+
+/// Legend and renderer drawn next to a chart.
+///
+/// `N` is the entry capacity of the legend.
+pub struct ChartWithLegend<'a, C: PixelColor, R: LegendRenderer<C, N>, const N: usize = 8> {
+    /// The legend to draw
+    pub legend: Option<&'a R::Legend>,
+    /// Renderer used for the legend
+    pub renderer: Option<&'a R>,
+}
+
+impl<'a, C: PixelColor, R: LegendRenderer<C, N>, const N: usize> ChartWithLegend<'a, C, R, N> {
+    /// Draw the chart with the given legend
+    pub fn new(legend: &'a R::Legend, renderer: &'a R) -> Self {
+        Self {
+            legend: Some(legend),
+            renderer: Some(renderer),
+        }
+    }
+
+    /// Draw the chart without a legend
+    pub fn none() -> Self {
+        Self {
+            legend: None,
+            renderer: None,
+        }
+    }
+}
+
+/// Draw a chart with its legend placed on the right of the viewport
+///
+/// # Arguments
+/// * `chart_drawer` - Draws the chart into the area it is given
+/// * `viewport` - The area available for the chart and legend
+/// * `display` - The display target to draw to
+/// * `legend_setup` - The legend to place, if any
+pub fn draw_chart_with_auto_legend<C, D, R, const N: usize>(
+    chart_drawer: impl FnOnce(Rectangle, &mut D) -> ChartResult<()>,
+    viewport: Rectangle,
+    display: &mut D,
+    legend_setup: ChartWithLegend<'_, C, R, N>,
+) -> ChartResult<()>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+    R: LegendRenderer<C, N>,
+{
+    if let (Some(legend), Some(renderer)) = (legend_setup.legend, legend_setup.renderer) {
+        // Simple layout: legend on right, chart on left
+        let legend_size = legend.calculate_size();
+        let legend_width = legend_size.width + 20; // Add padding
+
+        let chart_area = Rectangle::new(
+            viewport.top_left,
+            Size::new(
+                viewport.size.width.saturating_sub(legend_width),
+                viewport.size.height,
+            ),
+        );
+
+        let legend_rect = Rectangle::new(
+            Point::new(
+                viewport.top_left.x + chart_area.size.width as i32 + 10,
+                viewport.top_left.y + 20,
+            ),
+            legend_size,
+        );
+
+        chart_drawer(chart_area, display)?;
+        renderer.render(legend, legend_rect, display)?;
+    } else {
+        // No legend, use full viewport
+        chart_drawer(viewport, display)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! Desktop preview helpers for application development.
+//!
+//! This module is available with the `dev-tools` feature. It exposes the window
+//! and layout scaffolding used by the crate's own examples, so an application can
+//! open a quick desktop preview of its exact chart configuration using the
+//! embedded-graphics simulator:
+//!
+//! - [`WindowConfig`] and [`WindowTheme`] describe the preview window
+//! - [`run_static`] shows a single frame, [`run`] drives an animation loop
+//! - [`layout::draw_chart_with_auto_legend`] places a legend beside a chart
+//! - with the `capture` feature, `capture` saves screenshots and GIF
+//!   animations of a preview, e.g. for README assets
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use embedded_charts::dev_tools::{self, WindowConfig};
+//! use embedded_charts::prelude::*;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let data = data_points![(0.0, 10.0), (1.0, 20.0), (2.0, 15.0)];
+//! let chart = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//!
+//! dev_tools::run_static(WindowConfig::new("Preview"), |display, viewport| {
+//!     chart.draw(&data, chart.config(), viewport, display)
+//! })?;
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+#[cfg(feature = "capture")]
+pub mod capture;
+pub mod layout;
+mod window;
+
+pub use window::{presets, run, run_static, WindowConfig, WindowManager, WindowTheme};
@@ -0,0 +1,375 @@
+//! Simulator window management for chart previews.
+
+#[cfg(feature = "capture")]
+use super::capture::{self, CaptureMode, GifCapture};
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+use embedded_graphics_simulator::{
+    BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+
+/// Preview window theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowTheme {
+    /// Default theme with white background
+    Default,
+    /// Dark theme with black background
+    Dark,
+    /// OLED blue theme
+    OledBlue,
+    /// OLED white theme
+    OledWhite,
+    /// Custom pixel spacing and scale
+    Custom {
+        /// Gap between rendered pixels
+        pixel_spacing: u32,
+        /// Size of each rendered pixel
+        scale: u32,
+    },
+}
+
+impl WindowTheme {
+    fn to_binary_color_theme(self) -> BinaryColorTheme {
+        match self {
+            WindowTheme::Default => BinaryColorTheme::Default,
+            WindowTheme::Dark => BinaryColorTheme::OledBlue,
+            WindowTheme::OledBlue => BinaryColorTheme::OledBlue,
+            WindowTheme::OledWhite => BinaryColorTheme::OledWhite,
+            WindowTheme::Custom { .. } => BinaryColorTheme::Default,
+        }
+    }
+
+    /// Gap between rendered pixels
+    pub fn pixel_spacing(self) -> u32 {
+        match self {
+            WindowTheme::Custom { pixel_spacing, .. } => pixel_spacing,
+            _ => 0, // No spacing between pixels for solid appearance
+        }
+    }
+
+    /// Size of each rendered pixel
+    pub fn scale(self) -> u32 {
+        match self {
+            WindowTheme::Custom { scale, .. } => scale,
+            WindowTheme::Default | WindowTheme::Dark => 1,
+            WindowTheme::OledBlue | WindowTheme::OledWhite => 2,
+        }
+    }
+}
+
+/// Preview window configuration.
+#[derive(Debug, Clone)]
+pub struct WindowConfig {
+    /// Window title
+    pub title: &'static str,
+    /// Window theme
+    pub theme: WindowTheme,
+    /// Target frame rate for animated previews
+    pub target_fps: u32,
+    /// Close the window automatically after five seconds
+    pub auto_close: bool,
+    /// Color the display is cleared to before each frame
+    pub background_color: Rgb565,
+    /// Display size in pixels
+    pub size: Size,
+    /// What the window records, if anything
+    #[cfg(feature = "capture")]
+    pub capture: Option<CaptureMode>,
+    /// Directory captures are saved to
+    #[cfg(feature = "capture")]
+    pub capture_dir: &'static str,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "Chart Preview",
+            theme: WindowTheme::Default,
+            target_fps: 60,
+            auto_close: false,
+            background_color: Rgb565::WHITE,
+            size: Size::new(640, 480),
+            #[cfg(feature = "capture")]
+            capture: None,
+            #[cfg(feature = "capture")]
+            capture_dir: ".",
+        }
+    }
+}
+
+impl WindowConfig {
+    /// Create a new window configuration
+    pub fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            ..Default::default()
+        }
+    }
+
+    /// Set the window theme
+    pub fn theme(mut self, theme: WindowTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the target frame rate
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.target_fps = fps.max(1);
+        self
+    }
+
+    /// Close the window automatically after five seconds
+    pub fn auto_close(mut self) -> Self {
+        self.auto_close = true;
+        self
+    }
+
+    /// Set the background color
+    pub fn background(mut self, color: Rgb565) -> Self {
+        self.background_color = color;
+        self
+    }
+
+    /// Set the display size
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Record the preview into `dir`
+    ///
+    /// The file is named after the title in lower case, with spaces replaced
+    /// by underscores. [`run_static`] always saves a screenshot; [`run`] saves
+    /// one a second after start, or records an animation from half a second
+    /// until the window closes.
+    #[cfg(feature = "capture")]
+    pub fn capture(mut self, mode: CaptureMode, dir: &'static str) -> Self {
+        self.capture = Some(mode);
+        self.capture_dir = dir;
+        self
+    }
+
+    /// Get the path of the capture file with the given extension, creating its directory
+    #[cfg(feature = "capture")]
+    fn capture_path(&self, extension: &str) -> ChartResult<std::path::PathBuf> {
+        std::fs::create_dir_all(self.capture_dir).map_err(|_| ChartError::StorageError)?;
+        let name = self.title.replace(' ', "_").to_lowercase();
+        Ok(std::path::Path::new(self.capture_dir).join(format!("{name}.{extension}")))
+    }
+
+    /// Get the chart viewport, inset 20 pixels from the display edges
+    pub fn viewport(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new(20, 20),
+            Size::new(
+                self.size.width.saturating_sub(40),
+                self.size.height.saturating_sub(40),
+            ),
+        )
+    }
+
+    /// Get the frame delay in milliseconds
+    pub fn frame_delay_ms(&self) -> u64 {
+        1000 / self.target_fps.max(1) as u64
+    }
+}
+
+/// Simulator window showing a preview display.
+pub struct WindowManager {
+    window: Window,
+    /// Configuration the window was created with
+    pub config: WindowConfig,
+    start_time: std::time::Instant,
+}
+
+impl WindowManager {
+    /// Open a window for the given configuration
+    pub fn new(window_config: &WindowConfig) -> Self {
+        let output_settings = OutputSettingsBuilder::new()
+            .theme(window_config.theme.to_binary_color_theme())
+            .pixel_spacing(window_config.theme.pixel_spacing())
+            .scale(window_config.theme.scale())
+            .build();
+
+        Self {
+            window: Window::new(window_config.title, &output_settings),
+            config: window_config.clone(),
+            start_time: std::time::Instant::now(),
+        }
+    }
+
+    /// Show the current display content
+    pub fn update(&mut self, display: &SimulatorDisplay<Rgb565>) {
+        self.window.update(display);
+    }
+
+    /// Check whether the window was closed or the auto-close delay elapsed
+    pub fn should_close(&mut self) -> bool {
+        if self.window.events().any(|e| e == SimulatorEvent::Quit) {
+            return true;
+        }
+
+        self.config.auto_close && self.start_time.elapsed().as_secs() > 5
+    }
+
+    fn wait_frame(&self) {
+        std::thread::sleep(std::time::Duration::from_millis(
+            self.config.frame_delay_ms(),
+        ));
+    }
+}
+
+impl core::fmt::Debug for WindowManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WindowManager")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+/// Run an animated preview until the window is closed
+///
+/// The display is cleared before every frame and `animation_fn` is called with
+/// the display, the chart viewport and the elapsed time in seconds.
+///
+/// # Errors
+///
+/// Returns the first error of `animation_fn`, or [`ChartError::StorageError`]
+/// if a [capture](WindowConfig::capture) cannot be saved.
+pub fn run<F>(window_config: WindowConfig, mut animation_fn: F) -> ChartResult<()>
+where
+    F: FnMut(&mut SimulatorDisplay<Rgb565>, Rectangle, f32) -> ChartResult<()>,
+{
+    let mut display = SimulatorDisplay::new(window_config.size);
+    let viewport = window_config.viewport();
+    let background_color = window_config.background_color;
+
+    let mut window_manager = WindowManager::new(&window_config);
+    let start_time = std::time::Instant::now();
+    window_manager.update(&display);
+
+    #[cfg(feature = "capture")]
+    let mut screenshot_pending = window_config.capture == Some(CaptureMode::Screenshot);
+    #[cfg(feature = "capture")]
+    let mut animation = (window_config.capture == Some(CaptureMode::Animation))
+        .then(|| GifCapture::new(window_config.frame_delay_ms() as u16));
+
+    while !window_manager.should_close() {
+        display
+            .clear(background_color)
+            .map_err(|_| ChartError::RenderingError)?;
+
+        let elapsed = start_time.elapsed().as_secs_f32();
+        animation_fn(&mut display, viewport, elapsed)?;
+
+        #[cfg(feature = "capture")]
+        {
+            if let Some(animation) = animation.as_mut().filter(|_| elapsed > 0.5) {
+                animation.add_frame(&display);
+            }
+            if screenshot_pending && elapsed > 1.0 {
+                capture::capture_screenshot(&display, window_config.capture_path("png")?)?;
+                screenshot_pending = false;
+            }
+        }
+
+        window_manager.update(&display);
+        window_manager.wait_frame();
+    }
+
+    #[cfg(feature = "capture")]
+    if let Some(animation) = animation {
+        animation.save_gif(window_config.capture_path("gif")?)?;
+    }
+
+    Ok(())
+}
+
+/// Show a single rendered frame until the window is closed
+pub fn run_static<F>(window_config: WindowConfig, mut render_fn: F) -> ChartResult<()>
+where
+    F: FnMut(&mut SimulatorDisplay<Rgb565>, Rectangle) -> ChartResult<()>,
+{
+    let mut display = SimulatorDisplay::new(window_config.size);
+    display
+        .clear(window_config.background_color)
+        .map_err(|_| ChartError::RenderingError)?;
+
+    render_fn(&mut display, window_config.viewport())?;
+
+    #[cfg(feature = "capture")]
+    if window_config.capture.is_some() {
+        capture::capture_screenshot(&display, window_config.capture_path("png")?)?;
+    }
+
+    let mut window_manager = WindowManager::new(&window_config);
+    loop {
+        window_manager.update(&display);
+        if window_manager.should_close() {
+            break;
+        }
+        window_manager.wait_frame();
+    }
+
+    Ok(())
+}
+
+/// Preset window configurations
+pub mod presets {
+    use super::*;
+
+    /// Default window configuration
+    pub fn default(title: &'static str) -> WindowConfig {
+        WindowConfig::new(title)
+    }
+
+    /// Dark theme window configuration
+    pub fn dark_theme(title: &'static str) -> WindowConfig {
+        WindowConfig::new(title).theme(WindowTheme::Dark)
+    }
+
+    /// OLED theme window configuration
+    pub fn oled_theme(title: &'static str) -> WindowConfig {
+        WindowConfig::new(title).theme(WindowTheme::OledBlue)
+    }
+
+    /// High performance window configuration (30 FPS)
+    pub fn performance(title: &'static str) -> WindowConfig {
+        WindowConfig::new(title).fps(30)
+    }
+
+    /// Demo window configuration (auto-closes after 5 seconds)
+    pub fn demo(title: &'static str) -> WindowConfig {
+        WindowConfig::new(title).auto_close()
+    }
+
+    /// Custom scaled window configuration
+    pub fn scaled(title: &'static str, scale: u32) -> WindowConfig {
+        WindowConfig::new(title).theme(WindowTheme::Custom {
+            pixel_spacing: 1,
+            scale,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_config() {
+        let config = WindowConfig::new("Test Window")
+            .theme(WindowTheme::Dark)
+            .fps(30)
+            .background(Rgb565::BLACK);
+
+        assert_eq!(config.title, "Test Window");
+        assert_eq!(config.background_color, Rgb565::BLACK);
+        assert_eq!(config.frame_delay_ms(), 33);
+        assert_eq!(
+            config.viewport(),
+            Rectangle::new(Point::new(20, 20), Size::new(600, 440))
+        );
+        assert!(presets::demo("Demo").auto_close);
+    }
+}
@@ -175,6 +175,14 @@ pub enum ChartError {
     ///
     /// More specific configuration error, typically with additional context.
     ConfigurationError,
+    /// Invalid configuration with context.
+    ///
+    /// Names the setting that was rejected and how to fix it.
+    ConfigError(ErrorContext),
+    /// Reading, writing or erasing persistent storage failed.
+    ///
+    /// Returned by storage backends used to persist chart data.
+    StorageError,
     /// Render error occurred.
     ///
     /// Specific rendering error with detailed error information.
@@ -194,6 +202,19 @@ pub enum ChartError {
     AnimationError(AnimationError),
 }
 
+impl ChartError {
+    /// Check whether the error reports an invalid or conflicting configuration
+    pub const fn is_configuration_error(&self) -> bool {
+        matches!(
+            self,
+            ChartError::InvalidConfiguration
+                | ChartError::ConfigurationError
+                | ChartError::ConfigError(_)
+                | ChartError::LayoutError(LayoutError::InvalidConfiguration)
+        )
+    }
+}
+
 /// Error type for data operations.
 ///
 /// This error type covers all data-related operations including data series
@@ -638,6 +659,12 @@ impl core::fmt::Display for ChartError {
             ChartError::RenderingError => write!(f, "Error occurred during rendering"),
             ChartError::InvalidConfiguration => write!(f, "Invalid configuration provided"),
             ChartError::ConfigurationError => write!(f, "Configuration error occurred"),
+            ChartError::ConfigError(ctx) => write!(
+                f,
+                "Invalid configuration during {} (hint: {})",
+                ctx.operation, ctx.hint
+            ),
+            ChartError::StorageError => write!(f, "Persistent storage operation failed"),
             ChartError::RenderError(err) => write!(f, "Render error: {err}"),
             ChartError::LayoutError(err) => write!(f, "Layout error: {err}"),
             ChartError::DataError(err) => write!(f, "Data error: {err}"),
@@ -65,17 +65,35 @@
 //! # }
 //! ```
 
-#[cfg(any(feature = "line", feature = "bar"))]
+#[cfg(any(feature = "line", feature = "bar", feature = "pie"))]
 use crate::chart::traits::ChartBuilder;
 #[allow(unused_imports)]
 use crate::data::MultiSeries;
 #[cfg(any(feature = "line", feature = "bar"))]
 use crate::data::{Point2D, StaticDataSeries};
-#[cfg(any(feature = "line", feature = "bar"))]
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
 use crate::error::ChartResult;
-#[cfg(any(feature = "line", feature = "bar"))]
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
 use embedded_graphics::prelude::*;
-#[cfg(any(feature = "line", feature = "bar"))]
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
 use heapless::String;
 
 /// Chart presets for common styling patterns
@@ -114,6 +132,33 @@ impl Chart {
     {
         FluentBarChartBuilder::new()
     }
+
+    /// Start building a pie chart
+    #[cfg(feature = "pie")]
+    pub fn pie<C>() -> FluentPieChartBuilder<C>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        FluentPieChartBuilder::new()
+    }
+
+    /// Start building a scatter chart
+    #[cfg(feature = "scatter")]
+    pub fn scatter<C>() -> FluentScatterChartBuilder<C>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        FluentScatterChartBuilder::new()
+    }
+
+    /// Start building a gauge chart
+    #[cfg(feature = "gauge")]
+    pub fn gauge<C>() -> FluentGaugeChartBuilder<C>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        FluentGaugeChartBuilder::new()
+    }
 }
 
 /// Fluent builder for line charts
@@ -419,11 +464,331 @@ where
     }
 }
 
-// Similar implementations for other chart types would follow...
+/// Colors used by the presets for charts that show several categories
+#[cfg(any(feature = "pie", feature = "scatter", feature = "gauge"))]
+fn preset_palette(preset: ChartPreset) -> [embedded_graphics::pixelcolor::Rgb565; 4] {
+    use crate::style::Theme;
+    use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+    let series =
+        |theme: Theme<Rgb565>| [theme.primary, theme.secondary, theme.accent, theme.success];
+    match preset {
+        ChartPreset::Professional => series(Theme::LIGHT),
+        ChartPreset::Embedded => [Rgb565::GREEN, Rgb565::YELLOW, Rgb565::RED, Rgb565::CYAN],
+        ChartPreset::Vibrant => series(Theme::VIBRANT),
+        ChartPreset::Pastel => series(Theme::PASTEL),
+        ChartPreset::Dark => series(Theme::DARK),
+    }
+}
+
+/// Fluent builder for pie and donut charts
+#[cfg(feature = "pie")]
+pub struct FluentPieChartBuilder<C: PixelColor> {
+    colors: heapless::Vec<C, 16>,
+    title: Option<String<64>>,
+    preset: Option<ChartPreset>,
+    radius: Option<u32>,
+    inner_radius: Option<u32>,
+}
+
+#[cfg(feature = "pie")]
+impl<C: PixelColor + 'static> FluentPieChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn new() -> Self {
+        Self {
+            colors: heapless::Vec::new(),
+            title: None,
+            preset: None,
+            radius: None,
+            inner_radius: None,
+        }
+    }
+
+    /// Set the slice colors
+    pub fn colors(mut self, colors: &[C]) -> Self {
+        self.colors.clear();
+        for &color in colors {
+            if self.colors.push(color).is_err() {
+                break; // Reached capacity
+            }
+        }
+        self
+    }
+
+    /// Set the chart title
+    pub fn title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = String::try_from(title) {
+            self.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Apply a preset style
+    pub fn preset(mut self, preset: ChartPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Set the outer radius
+    pub fn radius(mut self, radius: u32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Turn the chart into a donut with the given inner radius
+    pub fn donut(mut self, inner_radius: u32) -> Self {
+        self.inner_radius = Some(inner_radius);
+        self
+    }
+
+    /// Build the pie chart
+    pub fn build(self) -> ChartResult<crate::chart::PieChart<C>> {
+        let mut builder = crate::chart::PieChart::builder();
+
+        // Apply preset styling first
+        if let Some(preset) = self.preset {
+            let palette = preset_palette(preset).map(C::from);
+            builder = builder.colors(&palette);
+        }
+
+        // Apply specific customizations
+        if !self.colors.is_empty() {
+            builder = builder.colors(&self.colors);
+        }
+
+        if let Some(radius) = self.radius {
+            builder = builder.radius(radius);
+        }
+
+        if let Some(inner_radius) = self.inner_radius {
+            builder = builder.donut(inner_radius);
+        }
+
+        if let Some(ref title) = self.title {
+            builder = builder.with_title(title);
+        }
+
+        builder.build()
+    }
+}
+
+/// Fluent builder for scatter charts
+#[cfg(feature = "scatter")]
+pub struct FluentScatterChartBuilder<C: PixelColor> {
+    color: Option<C>,
+    title: Option<String<64>>,
+    preset: Option<ChartPreset>,
+    point_size: Option<u32>,
+    point_shape: Option<crate::chart::PointShape>,
+}
+
+#[cfg(feature = "scatter")]
+impl<C: PixelColor + 'static> FluentScatterChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn new() -> Self {
+        Self {
+            color: None,
+            title: None,
+            preset: None,
+            point_size: None,
+            point_shape: None,
+        }
+    }
+
+    /// Set the point color
+    pub fn color(mut self, color: C) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the chart title
+    pub fn title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = String::try_from(title) {
+            self.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Apply a preset style
+    pub fn preset(mut self, preset: ChartPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Set point size
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = Some(size);
+        self
+    }
+
+    /// Set point shape
+    pub fn point_shape(mut self, shape: crate::chart::PointShape) -> Self {
+        self.point_shape = Some(shape);
+        self
+    }
+
+    /// Build the scatter chart
+    pub fn build(self) -> ChartResult<crate::chart::ScatterChart<C>> {
+        let mut builder = crate::chart::ScatterChart::builder();
+
+        // Apply preset styling first
+        if let Some(preset) = self.preset {
+            let [color, ..] = preset_palette(preset);
+            builder = builder.point_color(C::from(color));
+        }
+
+        // Apply specific customizations
+        if let Some(color) = self.color {
+            builder = builder.point_color(color);
+        }
+
+        if let Some(size) = self.point_size {
+            builder = builder.point_size(size);
+        }
+
+        if let Some(shape) = self.point_shape {
+            builder = builder.point_shape(shape);
+        }
+
+        if let Some(ref title) = self.title {
+            builder = builder.with_title(title);
+        }
+
+        builder.build()
+    }
+}
+
+/// Fluent builder for gauge charts
+#[cfg(feature = "gauge")]
+pub struct FluentGaugeChartBuilder<C: PixelColor> {
+    range: Option<(f32, f32)>,
+    zones: heapless::Vec<(f32, f32, C), { crate::chart::MAX_THRESHOLD_ZONES }>,
+    zones_overflow: bool,
+    title: Option<String<64>>,
+    preset: Option<ChartPreset>,
+    gauge_type: Option<crate::chart::GaugeType>,
+    radius: Option<u32>,
+}
+
+#[cfg(feature = "gauge")]
+impl<C: PixelColor + 'static> FluentGaugeChartBuilder<C>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
+    fn new() -> Self {
+        Self {
+            range: None,
+            zones: heapless::Vec::new(),
+            zones_overflow: false,
+            title: None,
+            preset: None,
+            gauge_type: None,
+            radius: None,
+        }
+    }
+
+    /// Set the value range shown by the gauge
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Add a colored threshold zone
+    ///
+    /// Zones beyond [`MAX_THRESHOLD_ZONES`](crate::chart::MAX_THRESHOLD_ZONES)
+    /// make [`build`](Self::build) fail.
+    pub fn zone(mut self, start: f32, end: f32, color: C) -> Self {
+        if self.zones.push((start, end, color)).is_err() {
+            self.zones_overflow = true;
+        }
+        self
+    }
+
+    /// Set the chart title
+    pub fn title(mut self, title: &str) -> Self {
+        if let Ok(title_string) = String::try_from(title) {
+            self.title = Some(title_string);
+        }
+        self
+    }
+
+    /// Apply a preset style
+    pub fn preset(mut self, preset: ChartPreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Set the gauge shape
+    pub fn gauge_type(mut self, gauge_type: crate::chart::GaugeType) -> Self {
+        self.gauge_type = Some(gauge_type);
+        self
+    }
+
+    /// Set the gauge radius
+    pub fn radius(mut self, radius: u32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Build the gauge chart
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`GaugeChartBuilder::build`](crate::chart::GaugeChartBuilder::build),
+    /// including [`ChartError::MemoryFull`](crate::error::ChartError::MemoryFull)
+    /// when more zones were added than a gauge can hold.
+    pub fn build(self) -> ChartResult<crate::chart::GaugeChart<C>> {
+        if self.zones_overflow {
+            return Err(crate::error::ChartError::MemoryFull);
+        }
+
+        let mut builder = crate::chart::GaugeChart::builder();
+
+        // Apply preset styling first
+        if let Some(preset) = self.preset {
+            let [needle, ..] = preset_palette(preset);
+            builder =
+                builder.needle_style(crate::chart::NeedleShape::Line, C::from(needle), 0.8, 2);
+        }
+
+        // Apply specific customizations
+        if let Some(gauge_type) = self.gauge_type {
+            builder = builder.gauge_type(gauge_type);
+        }
+
+        if let Some((min, max)) = self.range {
+            builder = builder.value_range(min, max);
+        }
+
+        if let Some(radius) = self.radius {
+            builder = builder.radius(radius);
+        }
+
+        for &(start, end, color) in self.zones.iter() {
+            builder = builder.add_threshold_zone(start, end, color);
+        }
+
+        if let Some(ref title) = self.title {
+            builder = builder.with_title(title);
+        }
+
+        builder.build()
+    }
+}
 
 /// Quick creation functions for common chart types
 pub mod quick {
-    #[cfg(any(feature = "line", feature = "bar"))]
+    #[cfg(any(
+        feature = "line",
+        feature = "bar",
+        feature = "pie",
+        feature = "scatter",
+        feature = "gauge"
+    ))]
     use super::*;
 
     /// Create a simple line chart from data tuples
@@ -470,11 +835,59 @@ pub mod quick {
             .preset(ChartPreset::Embedded)
             .build()
     }
+
+    /// Create a simple pie chart
+    #[cfg(feature = "pie")]
+    pub fn pie_chart<C>() -> ChartResult<crate::chart::PieChart<C>>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        Chart::pie().preset(ChartPreset::Professional).build()
+    }
+
+    /// Create a donut chart with the given outer and inner radius
+    #[cfg(feature = "pie")]
+    pub fn donut_chart<C>(radius: u32, inner_radius: u32) -> ChartResult<crate::chart::PieChart<C>>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        Chart::pie()
+            .preset(ChartPreset::Professional)
+            .radius(radius)
+            .donut(inner_radius)
+            .build()
+    }
+
+    /// Create a simple scatter chart
+    #[cfg(feature = "scatter")]
+    pub fn scatter_chart<C>() -> ChartResult<crate::chart::ScatterChart<C>>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        Chart::scatter().preset(ChartPreset::Professional).build()
+    }
+
+    /// Create a semicircle gauge for the given value range
+    #[cfg(feature = "gauge")]
+    pub fn gauge_chart<C>(min: f32, max: f32) -> ChartResult<crate::chart::GaugeChart<C>>
+    where
+        C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565> + 'static,
+    {
+        Chart::gauge()
+            .preset(ChartPreset::Professional)
+            .range(min, max)
+            .build()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "line")]
+    #[cfg(any(
+        feature = "line",
+        feature = "pie",
+        feature = "scatter",
+        feature = "gauge"
+    ))]
     use super::*;
     #[cfg(feature = "line")]
     use embedded_graphics::pixelcolor::Rgb565;
@@ -524,4 +937,59 @@ mod tests {
 
         assert!(chart.is_ok());
     }
+
+    #[test]
+    #[cfg(feature = "pie")]
+    fn test_fluent_pie_chart() {
+        let chart = Chart::pie::<embedded_graphics::pixelcolor::Rgb565>()
+            .preset(ChartPreset::Pastel)
+            .radius(40)
+            .donut(20)
+            .title("Storage")
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.radius(), 40);
+        assert_eq!(chart.style().colors.len(), 4);
+        assert!(quick::donut_chart::<embedded_graphics::pixelcolor::Rgb565>(40, 20).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "gauge")]
+    fn test_fluent_gauge_chart() {
+        use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+        let chart = Chart::gauge::<Rgb565>()
+            .range(0.0, 200.0)
+            .zone(150.0, 200.0, Rgb565::RED)
+            .preset(ChartPreset::Embedded)
+            .build();
+        assert!(chart.is_ok());
+        assert!(quick::gauge_chart::<Rgb565>(0.0, 100.0).is_ok());
+
+        // Zones the gauge cannot hold are reported, as by the gauge builder
+        let mut overflowing = Chart::gauge::<Rgb565>().range(0.0, 100.0);
+        for zone in 0..=crate::chart::MAX_THRESHOLD_ZONES {
+            let start = zone as f32 * 10.0;
+            overflowing = overflowing.zone(start, start + 5.0, Rgb565::RED);
+        }
+        assert_eq!(
+            overflowing.build().err(),
+            Some(crate::error::ChartError::MemoryFull)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "scatter")]
+    fn test_fluent_scatter_chart() {
+        use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+        let chart = Chart::scatter::<Rgb565>()
+            .color(Rgb565::GREEN)
+            .point_size(6)
+            .point_shape(crate::chart::PointShape::Diamond)
+            .build();
+        assert!(chart.is_ok());
+        assert!(quick::scatter_chart::<Rgb565>().is_ok());
+    }
 }
@@ -124,6 +124,18 @@ where
         self
     }
 
+    /// Use dot grid styling
+    pub fn dot_grid(mut self) -> Self {
+        self.style = GridStyle::dot_grid();
+        self
+    }
+
+    /// Use graph paper styling with a zero line
+    pub fn graph_paper(mut self) -> Self {
+        self.style = GridStyle::graph_paper();
+        self
+    }
+
     /// Set grid visibility
     pub fn visibility(mut self, visibility: GridVisibility) -> Self {
         self.style.visibility = visibility;
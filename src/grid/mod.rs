@@ -19,7 +19,9 @@ use std::boxed::Box;
 
 // Re-export main types
 pub use builder::{CustomGridBuilder, GridBuilder, LinearGridBuilder, TickBasedGridBuilder};
-pub use style::{GridLineStyle, GridStyle, GridVisibility, MajorGridStyle, MinorGridStyle};
+pub use style::{
+    GridLineStyle, GridPaper, GridStyle, GridVisibility, MajorGridStyle, MinorGridStyle,
+};
 pub use traits::{DefaultGridRenderer, Grid, GridConfiguration, GridOrientation, GridRenderer};
 pub use types::{CustomGrid, GridSpacing, GridType, LinearGrid, TickBasedGrid};
 
@@ -82,6 +84,16 @@ impl<C: PixelColor + 'static> GridContainer<C> {
         }
     }
 
+    /// Calculate the screen positions of the grid lines
+    pub fn calculate_positions(&self, viewport: Rectangle) -> heapless::Vec<i32, 64> {
+        match self {
+            GridContainer::Linear(grid) => grid.calculate_positions(viewport),
+            GridContainer::TickBasedF32(grid) => grid.calculate_positions(viewport),
+            GridContainer::TickBasedI32(grid) => grid.calculate_positions(viewport),
+            GridContainer::Custom(grid) => grid.calculate_positions(viewport),
+        }
+    }
+
     /// Check if grid is visible
     pub fn is_visible(&self) -> bool {
         match self {
@@ -141,6 +153,22 @@ where
             return Ok(());
         }
 
+        match self.style.paper {
+            GridPaper::Lines => {}
+            GridPaper::Dots => return self.draw_dots(viewport, target),
+            GridPaper::GraphPaper => {
+                // Dashed minor lines go underneath the major lines
+                if self.style.minor.enabled && self.style.visibility.minor {
+                    if let Some(ref horizontal_grid) = self.horizontal {
+                        self.draw_subdivisions(horizontal_grid, viewport, target)?;
+                    }
+                    if let Some(ref vertical_grid) = self.vertical {
+                        self.draw_subdivisions(vertical_grid, viewport, target)?;
+                    }
+                }
+            }
+        }
+
         // Draw horizontal grid lines
         if let Some(ref horizontal_grid) = self.horizontal {
             horizontal_grid.draw(viewport, target)?;
@@ -154,6 +182,159 @@ where
         Ok(())
     }
 
+    /// Draw a dot at each intersection of the horizontal and vertical grid lines
+    fn draw_dots<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let (Some(horizontal), Some(vertical)) = (&self.horizontal, &self.vertical) else {
+            return Ok(());
+        };
+        if !horizontal.is_visible() || !vertical.is_visible() {
+            return Ok(());
+        }
+
+        let rows = horizontal.calculate_positions(viewport);
+        let columns = vertical.calculate_positions(viewport);
+        let line_style = &self.style.major.line.line_style;
+        let size = line_style.width.max(1);
+        let offset = (size / 2) as i32;
+
+        for &y in rows.iter() {
+            for &x in columns.iter() {
+                Rectangle::new(Point::new(x - offset, y - offset), Size::new(size, size))
+                    .into_styled(PrimitiveStyle::with_fill(line_style.color))
+                    .draw(target)
+                    .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw dashed minor lines subdividing the spaces between a grid's lines
+    fn draw_subdivisions<D>(
+        &self,
+        grid: &GridContainer<C>,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let subdivisions = self.style.minor.subdivisions as i32;
+        if !grid.is_visible() || subdivisions < 2 {
+            return Ok(());
+        }
+
+        let orientation = grid.orientation();
+        let (start, end) = match orientation {
+            GridOrientation::Horizontal => (
+                viewport.top_left.y,
+                viewport.top_left.y + viewport.size.height as i32,
+            ),
+            GridOrientation::Vertical => (
+                viewport.top_left.x,
+                viewport.top_left.x + viewport.size.width as i32,
+            ),
+        };
+
+        // Subdivide between the viewport edges and every major line
+        let mut bounds: heapless::Vec<i32, 66> = heapless::Vec::new();
+        let _ = bounds.push(start);
+        for &pos in grid.calculate_positions(viewport).iter() {
+            let _ = bounds.push(pos);
+        }
+        let _ = bounds.push(end);
+        bounds.sort_unstable();
+
+        let minor_style = self
+            .style
+            .minor
+            .line
+            .line_style
+            .pattern(crate::style::LinePattern::Dashed);
+        let renderer = DefaultGridRenderer;
+
+        for pair in bounds.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            for k in 1..subdivisions {
+                let pos = a + (b - a) * k / subdivisions;
+                if pos == a || pos == b {
+                    continue;
+                }
+                let (line_start, line_end) = match orientation {
+                    GridOrientation::Horizontal => (
+                        Point::new(viewport.top_left.x, pos),
+                        Point::new(viewport.top_left.x + viewport.size.width as i32, pos),
+                    ),
+                    GridOrientation::Vertical => (
+                        Point::new(pos, viewport.top_left.y),
+                        Point::new(pos, viewport.top_left.y + viewport.size.height as i32),
+                    ),
+                };
+                renderer.draw_minor_line(line_start, line_end, &minor_style, target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw the zero emphasis lines for axes whose range includes zero
+    ///
+    /// Does nothing unless the grid style has a zero line.
+    pub fn draw_zero_lines<T, D, XA, YA>(
+        &self,
+        viewport: Rectangle,
+        x_axis: Option<&XA>,
+        y_axis: Option<&YA>,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        T: crate::axes::traits::AxisValue,
+        D: DrawTarget<Color = C>,
+        XA: crate::axes::traits::Axis<T, C>,
+        YA: crate::axes::traits::Axis<T, C>,
+    {
+        let Some(ref zero_line) = self.style.zero_line else {
+            return Ok(());
+        };
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let renderer = DefaultGridRenderer;
+        let zero = T::from_f32(0.0);
+        let right = viewport.top_left.x + viewport.size.width as i32;
+        let bottom = viewport.top_left.y + viewport.size.height as i32;
+
+        if let Some(x_axis) = x_axis {
+            if x_axis.min() <= zero && zero <= x_axis.max() {
+                let x = x_axis.transform_value(zero, viewport);
+                renderer.draw_major_line(
+                    Point::new(x, viewport.top_left.y),
+                    Point::new(x, bottom),
+                    &zero_line.line_style,
+                    target,
+                )?;
+            }
+        }
+
+        if let Some(y_axis) = y_axis {
+            if y_axis.min() <= zero && zero <= y_axis.max() {
+                let y = y_axis.transform_value(zero, viewport);
+                renderer.draw_major_line(
+                    Point::new(viewport.top_left.x, y),
+                    Point::new(right, y),
+                    &zero_line.line_style,
+                    target,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Draw grid lines that align with axis ticks
     pub fn draw_with_axes<T, D, XA, YA>(
         &self,
@@ -229,7 +410,7 @@ where
             }
         }
 
-        Ok(())
+        self.draw_zero_lines(viewport, x_axis, y_axis, target)
     }
 }
 
@@ -266,4 +447,50 @@ mod tests {
         grid.set_enabled(true);
         assert!(grid.is_enabled());
     }
+
+    #[test]
+    fn test_dot_grid_draws_intersections() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let mut grid: GridSystem<Rgb565> = GridSystem::new();
+        grid.style = GridStyle::dot_grid();
+        grid.set_horizontal_grid(GridContainer::Linear(LinearGrid::horizontal(
+            GridSpacing::Pixels(10),
+        )));
+        grid.set_vertical_grid(GridContainer::Linear(LinearGrid::vertical(
+            GridSpacing::Pixels(10),
+        )));
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(30, 30));
+        grid.draw(viewport, &mut display).unwrap();
+
+        assert!(display.get_pixel(Point::new(10, 10)).is_some());
+        assert!(display.get_pixel(Point::new(20, 20)).is_some());
+        assert!(display.get_pixel(Point::new(15, 10)).is_none());
+    }
+
+    #[test]
+    fn test_zero_line_drawn_within_axis_range() {
+        use crate::axes::{Axis, AxisOrientation, AxisPosition, LinearAxis};
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let mut grid: GridSystem<Rgb565> = GridSystem::new();
+        grid.style = GridStyle::new().with_zero_line(crate::style::LineStyle::solid(Rgb565::RED));
+
+        let y_axis = LinearAxis::new(-10.0, 10.0, AxisOrientation::Vertical, AxisPosition::Left);
+        let viewport = Rectangle::new(Point::zero(), Size::new(20, 21));
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        grid.draw_zero_lines::<f32, _, LinearAxis<f32, Rgb565>, _>(
+            viewport,
+            None,
+            Some(&y_axis),
+            &mut display,
+        )
+        .unwrap();
+
+        let y = y_axis.transform_value(0.0, viewport);
+        assert_eq!(display.get_pixel(Point::new(5, y)), Some(Rgb565::RED));
+    }
 }
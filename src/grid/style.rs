@@ -14,6 +14,22 @@ pub struct GridStyle<C: PixelColor> {
     pub visibility: GridVisibility,
     /// Grid opacity (0.0 = transparent, 1.0 = opaque)
     pub opacity: f32,
+    /// Visual variant of the grid
+    pub paper: GridPaper,
+    /// Emphasis line drawn where an axis crosses zero, if any
+    pub zero_line: Option<GridLineStyle<C>>,
+}
+
+/// Visual variant of the grid background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridPaper {
+    /// Continuous grid lines
+    #[default]
+    Lines,
+    /// A dot at each intersection of horizontal and vertical grid lines
+    Dots,
+    /// Solid major lines with dashed minor lines between them
+    GraphPaper,
 }
 
 /// Style configuration for major grid lines
@@ -75,6 +91,8 @@ impl<C: PixelColor> GridStyle<C> {
             minor: MinorGridStyle::default(),
             visibility: GridVisibility::default(),
             opacity: 1.0,
+            paper: GridPaper::Lines,
+            zero_line: None,
         }
     }
 
@@ -108,6 +126,8 @@ impl<C: PixelColor> GridStyle<C> {
             },
             visibility: GridVisibility::all(),
             opacity: 1.0,
+            paper: GridPaper::Lines,
+            zero_line: None,
         }
     }
 
@@ -145,6 +165,8 @@ impl<C: PixelColor> GridStyle<C> {
                 minor: false,
             },
             opacity: 1.0,
+            paper: GridPaper::Lines,
+            zero_line: None,
         }
     }
 
@@ -178,9 +200,47 @@ impl<C: PixelColor> GridStyle<C> {
             },
             visibility: GridVisibility::all(),
             opacity: 1.0,
+            paper: GridPaper::Lines,
+            zero_line: None,
         }
     }
 
+    /// Create a dot grid style (a dot at each major intersection)
+    pub fn dot_grid() -> Self
+    where
+        C: From<embedded_graphics::pixelcolor::Rgb565>,
+    {
+        Self::minimal().with_paper(GridPaper::Dots)
+    }
+
+    /// Create a graph paper style with a zero line
+    ///
+    /// Major lines are solid, minor subdivisions are dashed and the line where an
+    /// axis crosses zero is drawn darker.
+    pub fn graph_paper() -> Self
+    where
+        C: From<embedded_graphics::pixelcolor::Rgb565>,
+    {
+        let zero_color = embedded_graphics::pixelcolor::Rgb565::new(8, 16, 8).into();
+
+        let mut style = Self::professional();
+        style.minor.line.line_style = style.minor.line.line_style.pattern(LinePattern::Dashed);
+        style.paper = GridPaper::GraphPaper;
+        style.with_zero_line(LineStyle::solid(zero_color).width(1))
+    }
+
+    /// Set the visual variant of the grid
+    pub fn with_paper(mut self, paper: GridPaper) -> Self {
+        self.paper = paper;
+        self
+    }
+
+    /// Emphasize the line where an axis crosses zero
+    pub fn with_zero_line(mut self, line_style: LineStyle<C>) -> Self {
+        self.zero_line = Some(GridLineStyle::new(line_style));
+        self
+    }
+
     /// Set the overall grid opacity
     pub fn with_opacity(mut self, opacity: f32) -> Self {
         self.opacity = opacity.clamp(0.0, 1.0);
@@ -3,7 +3,7 @@
 use crate::error::ChartResult;
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
-use crate::math::{Math, NumericConversion};
+use crate::render::ChartRenderer;
 
 /// Core trait for all grid types
 pub trait Grid<C: PixelColor> {
@@ -228,252 +228,8 @@ impl<C: PixelColor> GridRenderer<C> for DefaultGridRenderer {
     where
         D: DrawTarget<Color = C>,
     {
-        use crate::error::ChartError;
-        use embedded_graphics::primitives::{Line, PrimitiveStyle};
-
-        let primitive_style = PrimitiveStyle::with_stroke(style.color, style.width);
-
-        // For dashed/dotted lines, we need to implement pattern drawing
-        match style.pattern {
-            crate::style::LinePattern::Solid => {
-                Line::new(start, end)
-                    .into_styled(primitive_style)
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-            crate::style::LinePattern::Dashed => {
-                self.draw_dashed_line(start, end, style, target)?;
-            }
-            crate::style::LinePattern::Dotted => {
-                self.draw_dotted_line(start, end, style, target)?;
-            }
-            crate::style::LinePattern::DashDot => {
-                self.draw_dash_dot_line(start, end, style, target)?;
-            }
-            crate::style::LinePattern::Custom => {
-                // Fall back to solid for custom patterns
-                Line::new(start, end)
-                    .into_styled(primitive_style)
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-        }
-
-        Ok(())
-    }
-}
-
-impl DefaultGridRenderer {
-    /// Draw a dashed line
-    fn draw_dashed_line<C, D>(
-        &self,
-        start: Point,
-        end: Point,
-        style: &crate::style::LineStyle<C>,
-        target: &mut D,
-    ) -> ChartResult<()>
-    where
-        C: PixelColor,
-        D: DrawTarget<Color = C>,
-    {
-        use crate::error::ChartError;
-        use embedded_graphics::primitives::{Line, PrimitiveStyle};
-
-        let primitive_style = PrimitiveStyle::with_stroke(style.color, style.width);
-        let dash_length = 8;
-        let gap_length = 4;
-
-        let dx = end.x - start.x;
-        let dy = end.y - start.y;
-        let dx_f32 = dx as f32;
-        let dy_f32 = dy as f32;
-        let dx_num = dx_f32.to_number();
-        let dy_num = dy_f32.to_number();
-        let length_squared = dx_num * dx_num + dy_num * dy_num;
-        let length_num = Math::sqrt(length_squared);
-        let length = f32::from_number(length_num);
-
-        let one = 1.0f32.to_number();
-        if length_num < one {
-            return Ok(());
-        }
-
-        let unit_x = dx as f32 / length;
-        let unit_y = dy as f32 / length;
-
-        let mut current_pos = 0.0;
-        let mut drawing = true;
-
-        while current_pos < length {
-            let segment_length = if drawing {
-                dash_length as f32
-            } else {
-                gap_length as f32
-            };
-            let next_pos = (current_pos + segment_length).min(length);
-
-            if drawing {
-                let seg_start = Point::new(
-                    start.x + (current_pos * unit_x) as i32,
-                    start.y + (current_pos * unit_y) as i32,
-                );
-                let seg_end = Point::new(
-                    start.x + (next_pos * unit_x) as i32,
-                    start.y + (next_pos * unit_y) as i32,
-                );
-
-                Line::new(seg_start, seg_end)
-                    .into_styled(primitive_style)
-                    .draw(target)
-                    .map_err(|_| ChartError::RenderingError)?;
-            }
-
-            current_pos = next_pos;
-            drawing = !drawing;
-        }
-
-        Ok(())
-    }
-
-    /// Draw a dotted line
-    fn draw_dotted_line<C, D>(
-        &self,
-        start: Point,
-        end: Point,
-        style: &crate::style::LineStyle<C>,
-        target: &mut D,
-    ) -> ChartResult<()>
-    where
-        C: PixelColor,
-        D: DrawTarget<Color = C>,
-    {
-        use crate::error::ChartError;
-        use embedded_graphics::primitives::{Circle, PrimitiveStyle};
-
-        let primitive_style = PrimitiveStyle::with_fill(style.color);
-        let dot_spacing = 6;
-
-        let dx = end.x - start.x;
-        let dy = end.y - start.y;
-        let dx_f32 = dx as f32;
-        let dy_f32 = dy as f32;
-        let dx_num = dx_f32.to_number();
-        let dy_num = dy_f32.to_number();
-        let length_squared = dx_num * dx_num + dy_num * dy_num;
-        let length_num = Math::sqrt(length_squared);
-        let length = f32::from_number(length_num);
-
-        let one = 1.0f32.to_number();
-        if length_num < one {
-            return Ok(());
-        }
-
-        let unit_x = dx as f32 / length;
-        let unit_y = dy as f32 / length;
-
-        let mut current_pos = 0.0;
-
-        while current_pos <= length {
-            let dot_center = Point::new(
-                start.x + (current_pos * unit_x) as i32,
-                start.y + (current_pos * unit_y) as i32,
-            );
-
-            Circle::new(Point::new(dot_center.x - 1, dot_center.y - 1), 2)
-                .into_styled(primitive_style)
-                .draw(target)
-                .map_err(|_| ChartError::RenderingError)?;
-
-            current_pos += dot_spacing as f32;
-        }
-
-        Ok(())
-    }
-
-    /// Draw a dash-dot line
-    fn draw_dash_dot_line<C, D>(
-        &self,
-        start: Point,
-        end: Point,
-        style: &crate::style::LineStyle<C>,
-        target: &mut D,
-    ) -> ChartResult<()>
-    where
-        C: PixelColor,
-        D: DrawTarget<Color = C>,
-    {
-        use crate::error::ChartError;
-        use embedded_graphics::primitives::{Circle, Line, PrimitiveStyle};
-
-        let line_style = PrimitiveStyle::with_stroke(style.color, style.width);
-        let dot_style = PrimitiveStyle::with_fill(style.color);
-        let dash_length = 8;
-        let gap_length = 3;
-        let dot_gap = 3;
-
-        let dx = end.x - start.x;
-        let dy = end.y - start.y;
-        let dx_f32 = dx as f32;
-        let dy_f32 = dy as f32;
-        let dx_num = dx_f32.to_number();
-        let dy_num = dy_f32.to_number();
-        let length_squared = dx_num * dx_num + dy_num * dy_num;
-        let length_num = Math::sqrt(length_squared);
-        let length = f32::from_number(length_num);
-
-        let one = 1.0f32.to_number();
-        if length_num < one {
-            return Ok(());
-        }
-
-        let unit_x = dx as f32 / length;
-        let unit_y = dy as f32 / length;
-
-        let mut current_pos = 0.0;
-        let pattern = [dash_length as f32, gap_length as f32, 2.0, dot_gap as f32]; // dash, gap, dot, gap
-        let mut pattern_index = 0;
-
-        while current_pos < length {
-            let segment_length = pattern[pattern_index % pattern.len()];
-            let next_pos = (current_pos + segment_length).min(length);
-
-            match pattern_index % 4 {
-                0 => {
-                    // Draw dash
-                    let seg_start = Point::new(
-                        start.x + (current_pos * unit_x) as i32,
-                        start.y + (current_pos * unit_y) as i32,
-                    );
-                    let seg_end = Point::new(
-                        start.x + (next_pos * unit_x) as i32,
-                        start.y + (next_pos * unit_y) as i32,
-                    );
-
-                    Line::new(seg_start, seg_end)
-                        .into_styled(line_style)
-                        .draw(target)
-                        .map_err(|_| ChartError::RenderingError)?;
-                }
-                2 => {
-                    // Draw dot
-                    let dot_center = Point::new(
-                        start.x + (current_pos * unit_x) as i32,
-                        start.y + (current_pos * unit_y) as i32,
-                    );
-
-                    Circle::new(Point::new(dot_center.x - 1, dot_center.y - 1), 2)
-                        .into_styled(dot_style)
-                        .draw(target)
-                        .map_err(|_| ChartError::RenderingError)?;
-                }
-                _ => {
-                    // Gap - do nothing
-                }
-            }
-
-            current_pos = next_pos;
-            pattern_index += 1;
-        }
+        // Dashed, dotted and dash-dot patterns are handled by the renderer
+        ChartRenderer::draw_line(start, end, style, target)?;
 
         Ok(())
     }
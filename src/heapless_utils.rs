@@ -307,6 +307,133 @@ impl<T, const N: usize> Default for HeaplessPool<T, N> {
     }
 }
 
+/// Compact handle to a label interned in a [`LabelPool`]
+///
+/// Handles are two bytes wide and `Copy`, so legend entries and series can
+/// refer to shared label text without holding their own string buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LabelId(u16);
+
+impl LabelId {
+    /// Get the slot index of this handle within its pool
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Read access to interned label text
+///
+/// Implemented by [`LabelPool`] so that consumers can resolve handles
+/// without naming the pool's capacity parameters.
+pub trait LabelLookup {
+    /// Resolve a handle to its label text
+    fn resolve(&self, id: LabelId) -> Option<&str>;
+
+    /// Find the handle of an already interned label
+    fn lookup(&self, label: &str) -> Option<LabelId>;
+}
+
+/// Interned label storage with static capacity
+///
+/// All label text lives in one byte buffer of `BYTES` bytes and each distinct
+/// label is stored once, no matter how many entries refer to it. Up to
+/// `LABELS` distinct labels can be interned.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_charts::heapless_utils::{LabelLookup, LabelPool};
+///
+/// let mut pool: LabelPool<128, 8> = LabelPool::new();
+/// let temp = pool.intern("Temperature").unwrap();
+/// assert_eq!(pool.intern("Temperature").unwrap(), temp);
+/// assert_eq!(pool.resolve(temp), Some("Temperature"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LabelPool<const BYTES: usize, const LABELS: usize> {
+    bytes: Vec<u8, BYTES>,
+    spans: Vec<(u16, u16), LABELS>,
+}
+
+impl<const BYTES: usize, const LABELS: usize> LabelPool<BYTES, LABELS> {
+    /// Create an empty label pool
+    pub const fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Intern a label, returning the handle of an identical existing label if present
+    pub fn intern(&mut self, label: &str) -> DataResult<LabelId> {
+        if let Some(id) = self.lookup(label) {
+            return Ok(id);
+        }
+        if self.spans.is_full() {
+            return Err(DataError::buffer_full("intern label", LABELS));
+        }
+
+        let start = self.bytes.len();
+        let end = start + label.len();
+        if end > u16::MAX as usize {
+            return Err(DataError::buffer_full("intern label", BYTES));
+        }
+        self.bytes
+            .extend_from_slice(label.as_bytes())
+            .map_err(|_| DataError::buffer_full("intern label", BYTES))?;
+
+        let id = LabelId(self.spans.len() as u16);
+        let _ = self.spans.push((start as u16, end as u16));
+        Ok(id)
+    }
+
+    /// Get the text of an interned label
+    pub fn get(&self, id: LabelId) -> Option<&str> {
+        let &(start, end) = self.spans.get(id.index())?;
+        core::str::from_utf8(&self.bytes[start as usize..end as usize]).ok()
+    }
+
+    /// Get the number of distinct labels in the pool
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Check if the pool holds no labels
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Get the number of text bytes in use
+    pub fn bytes_used(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Remove all labels, invalidating every handle issued so far
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.spans.clear();
+    }
+}
+
+impl<const BYTES: usize, const LABELS: usize> LabelLookup for LabelPool<BYTES, LABELS> {
+    fn resolve(&self, id: LabelId) -> Option<&str> {
+        self.get(id)
+    }
+
+    fn lookup(&self, label: &str) -> Option<LabelId> {
+        self.spans
+            .iter()
+            .position(|&(start, end)| &self.bytes[start as usize..end as usize] == label.as_bytes())
+            .map(|index| LabelId(index as u16))
+    }
+}
+
+impl<const BYTES: usize, const LABELS: usize> Default for LabelPool<BYTES, LABELS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Circular buffer implementation using heapless
 pub struct CircularBuffer<T: Copy, const N: usize> {
     buffer: [Option<T>; N],
@@ -654,4 +781,28 @@ mod tests {
         assert!(config.max_string_length > 0);
         assert!(config.max_data_points > 0);
     }
+
+    #[test]
+    fn test_label_pool_interning() {
+        let mut pool: LabelPool<32, 4> = LabelPool::new();
+        assert!(pool.is_empty());
+
+        let a = pool.intern("Voltage").unwrap();
+        let b = pool.intern("Current").unwrap();
+        assert_ne!(a, b);
+        assert_eq!(pool.intern("Voltage").unwrap(), a);
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.bytes_used(), 14);
+        assert_eq!(pool.get(a), Some("Voltage"));
+        assert_eq!(pool.lookup("Current"), Some(b));
+        assert_eq!(pool.lookup("Power"), None);
+
+        // Text capacity is shared by all labels
+        assert!(pool.intern("A label that does not fit").is_err());
+        assert_eq!(pool.len(), 2);
+
+        pool.clear();
+        assert!(pool.is_empty());
+        assert_eq!(pool.get(a), None);
+    }
 }
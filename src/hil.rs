@@ -0,0 +1,674 @@
+//! Hardware-in-the-loop display smoke test.
+//!
+//! This module is available with the `hil-test` feature. [`HilHarness`] renders a
+//! fixed sequence of test patterns and charts to any [`DrawTarget`] and times every
+//! stage with a [`Clock`], which makes it a quick first check during board bring-up:
+//!
+//! - A color bar pattern shows swapped color channels or a wrong pixel format
+//! - An orientation pattern marks each corner so rotation and offsets are obvious
+//! - Every enabled chart type is then drawn once per built-in [`Theme`]
+//!
+//! Each stage reports its render time and the number of pixels written, and the
+//! final [`HilReport`] summarizes the throughput of the whole run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::hil::HilHarness;
+//! use embedded_charts::time::ManualTimeProvider;
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*};
+//!
+//! let clock = ManualTimeProvider::new();
+//! let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+//! display.set_allow_overdraw(true);
+//! display.set_allow_out_of_bounds_drawing(true);
+//!
+//! let viewport = display.bounding_box();
+//! let report = HilHarness::new(&clock).run(&mut display, viewport)?;
+//! assert!(report.passed());
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::{Chart, ChartBuilder, ChartConfig};
+use crate::data::{Point2D, StaticDataSeries};
+use crate::error::{ChartError, ChartResult};
+use crate::style::themes::Theme;
+use crate::time::{Clock, Microseconds};
+use embedded_graphics::{
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+};
+
+/// Sample values shared by the chart stages
+const SAMPLE_VALUES: [f32; 12] = [
+    12.0, 18.0, 15.0, 24.0, 30.0, 27.0, 35.0, 31.0, 22.0, 26.0, 19.0, 23.0,
+];
+
+/// A single step of the test sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HilStageKind {
+    /// Vertical bars in white, the primary and secondary colors, and black
+    ColorBars,
+    /// Frame, diagonals and colored corner markers
+    Orientation,
+    /// Line chart
+    #[cfg(feature = "line")]
+    Line,
+    /// Smooth curve chart
+    #[cfg(feature = "line")]
+    Curve,
+    /// Vertical bar chart
+    #[cfg(feature = "bar")]
+    Bar,
+    /// Horizontal bar chart
+    #[cfg(feature = "bar")]
+    HorizontalBar,
+    /// Pie chart
+    #[cfg(feature = "pie")]
+    Pie,
+    /// Donut chart
+    #[cfg(feature = "pie")]
+    Donut,
+    /// Scatter chart
+    #[cfg(feature = "scatter")]
+    Scatter,
+    /// Gauge chart
+    #[cfg(feature = "gauge")]
+    Gauge,
+    /// Stacked bar chart
+    #[cfg(feature = "stacked-charts")]
+    StackedBar,
+}
+
+impl HilStageKind {
+    /// Test patterns that are drawn once, independent of the theme
+    pub const PATTERNS: &'static [HilStageKind] =
+        &[HilStageKind::ColorBars, HilStageKind::Orientation];
+
+    /// Chart stages that are drawn once per theme
+    pub const CHARTS: &'static [HilStageKind] = &[
+        #[cfg(feature = "line")]
+        HilStageKind::Line,
+        #[cfg(feature = "line")]
+        HilStageKind::Curve,
+        #[cfg(feature = "bar")]
+        HilStageKind::Bar,
+        #[cfg(feature = "bar")]
+        HilStageKind::HorizontalBar,
+        #[cfg(feature = "pie")]
+        HilStageKind::Pie,
+        #[cfg(feature = "pie")]
+        HilStageKind::Donut,
+        #[cfg(feature = "scatter")]
+        HilStageKind::Scatter,
+        #[cfg(feature = "gauge")]
+        HilStageKind::Gauge,
+        #[cfg(feature = "stacked-charts")]
+        HilStageKind::StackedBar,
+    ];
+
+    /// Short name for logging
+    pub fn name(self) -> &'static str {
+        match self {
+            HilStageKind::ColorBars => "color-bars",
+            HilStageKind::Orientation => "orientation",
+            #[cfg(feature = "line")]
+            HilStageKind::Line => "line",
+            #[cfg(feature = "line")]
+            HilStageKind::Curve => "curve",
+            #[cfg(feature = "bar")]
+            HilStageKind::Bar => "bar",
+            #[cfg(feature = "bar")]
+            HilStageKind::HorizontalBar => "horizontal-bar",
+            #[cfg(feature = "pie")]
+            HilStageKind::Pie => "pie",
+            #[cfg(feature = "pie")]
+            HilStageKind::Donut => "donut",
+            #[cfg(feature = "scatter")]
+            HilStageKind::Scatter => "scatter",
+            #[cfg(feature = "gauge")]
+            HilStageKind::Gauge => "gauge",
+            #[cfg(feature = "stacked-charts")]
+            HilStageKind::StackedBar => "stacked-bar",
+        }
+    }
+}
+
+/// Timing result of one rendered stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HilStage {
+    /// What was drawn
+    pub kind: HilStageKind,
+    /// Name of the theme, or `"pattern"` for the test patterns
+    pub theme: &'static str,
+    /// Render time in microseconds
+    pub elapsed_us: Microseconds,
+    /// Number of pixels written to the target
+    pub pixels: u32,
+    /// Error returned by the stage, if any
+    pub error: Option<ChartError>,
+}
+
+impl HilStage {
+    /// Pixels written per second, or 0 if the stage took no measurable time
+    pub fn pixels_per_second(&self) -> u64 {
+        throughput(self.pixels as u64, self.elapsed_us)
+    }
+}
+
+/// Summary of a full harness run
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HilReport {
+    /// Number of stages rendered
+    pub stages: u32,
+    /// Number of stages that returned an error
+    pub failures: u32,
+    /// Total render time in microseconds
+    pub total_us: Microseconds,
+    /// Total number of pixels written
+    pub total_pixels: u64,
+    /// Stage with the longest render time
+    pub slowest: Option<HilStage>,
+    /// First stage that returned an error
+    pub first_failure: Option<HilStage>,
+}
+
+impl HilReport {
+    /// Whether every stage rendered without an error
+    pub fn passed(&self) -> bool {
+        self.failures == 0
+    }
+
+    /// Average render time per stage in microseconds
+    pub fn average_stage_us(&self) -> Microseconds {
+        if self.stages == 0 {
+            0
+        } else {
+            self.total_us / self.stages as Microseconds
+        }
+    }
+
+    /// Pixels written per second over the whole run
+    pub fn pixels_per_second(&self) -> u64 {
+        throughput(self.total_pixels, self.total_us)
+    }
+
+    fn record(&mut self, stage: HilStage) {
+        self.stages += 1;
+        self.total_us += stage.elapsed_us;
+        self.total_pixels += stage.pixels as u64;
+        if self
+            .slowest
+            .is_none_or(|slowest| stage.elapsed_us > slowest.elapsed_us)
+        {
+            self.slowest = Some(stage);
+        }
+        if stage.error.is_some() {
+            self.failures += 1;
+            if self.first_failure.is_none() {
+                self.first_failure = Some(stage);
+            }
+        }
+    }
+}
+
+fn throughput(pixels: u64, elapsed_us: Microseconds) -> u64 {
+    (pixels * 1_000_000).checked_div(elapsed_us).unwrap_or(0)
+}
+
+/// A theme constructor and the name it is reported under.
+type NamedTheme<C> = (&'static str, fn() -> Theme<C>);
+
+/// Renders the display smoke test sequence and captures timing.
+pub struct HilHarness<'a> {
+    clock: &'a dyn Clock,
+    stop_on_error: bool,
+}
+
+impl<'a> HilHarness<'a> {
+    /// Create a harness that reads its timing from `clock`
+    pub fn new(clock: &'a dyn Clock) -> Self {
+        Self {
+            clock,
+            stop_on_error: false,
+        }
+    }
+
+    /// Stop the sequence at the first failing stage instead of continuing
+    pub fn stop_on_error(mut self, stop: bool) -> Self {
+        self.stop_on_error = stop;
+        self
+    }
+
+    /// Run the full sequence on `target` within `viewport`
+    pub fn run<C, D>(&self, target: &mut D, viewport: Rectangle) -> ChartResult<HilReport>
+    where
+        C: PixelColor + From<Rgb565> + 'static,
+        D: DrawTarget<Color = C>,
+    {
+        self.run_with(target, viewport, |_, _| Ok(()))
+    }
+
+    /// Run the full sequence, calling `after_stage` once each stage is drawn.
+    ///
+    /// The callback receives the stage result and the target, so it can flush
+    /// a framebuffer, log the timing or pause before the next stage. An error
+    /// returned by the callback aborts the run.
+    pub fn run_with<C, D, F>(
+        &self,
+        target: &mut D,
+        viewport: Rectangle,
+        mut after_stage: F,
+    ) -> ChartResult<HilReport>
+    where
+        C: PixelColor + From<Rgb565> + 'static,
+        D: DrawTarget<Color = C>,
+        F: FnMut(&HilStage, &mut D) -> ChartResult<()>,
+    {
+        let themes: [NamedTheme<C>; 10] = [
+            ("light", Theme::light),
+            ("dark", Theme::dark),
+            ("vibrant", Theme::vibrant),
+            ("pastel", Theme::pastel),
+            ("nature", Theme::nature),
+            ("ocean", Theme::ocean),
+            ("sunset", Theme::sunset),
+            ("cyberpunk", Theme::cyberpunk),
+            ("minimal", Theme::minimal),
+            ("retro", Theme::retro),
+        ];
+
+        let mut report = HilReport::default();
+
+        for &kind in HilStageKind::PATTERNS {
+            let stage = self.time_stage(kind, "pattern", target, |counter| {
+                draw_pattern(kind, viewport, counter)
+            });
+            report.record(stage);
+            after_stage(&stage, target)?;
+            if self.stop_on_error && stage.error.is_some() {
+                return Ok(report);
+            }
+        }
+
+        for (theme_name, theme) in themes.iter() {
+            let theme = theme();
+            for &kind in HilStageKind::CHARTS {
+                let stage = self.time_stage(kind, theme_name, target, |counter| {
+                    draw_chart(kind, &theme, viewport, counter)
+                });
+                report.record(stage);
+                after_stage(&stage, target)?;
+                if self.stop_on_error && stage.error.is_some() {
+                    return Ok(report);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn time_stage<D, F>(
+        &self,
+        kind: HilStageKind,
+        theme: &'static str,
+        target: &mut D,
+        draw: F,
+    ) -> HilStage
+    where
+        D: DrawTarget,
+        F: FnOnce(&mut PixelCounter<'_, D>) -> ChartResult<()>,
+    {
+        let mut counter = PixelCounter::new(target);
+        let start = self.clock.now_us();
+        let result = draw(&mut counter);
+        let elapsed_us = self.clock.now_us().saturating_sub(start);
+
+        HilStage {
+            kind,
+            theme,
+            elapsed_us,
+            pixels: counter.pixels,
+            error: result.err(),
+        }
+    }
+}
+
+/// Draw target wrapper that counts the pixels written through it
+struct PixelCounter<'a, D> {
+    target: &'a mut D,
+    pixels: u32,
+}
+
+impl<'a, D: DrawTarget> PixelCounter<'a, D> {
+    fn new(target: &'a mut D) -> Self {
+        Self { target, pixels: 0 }
+    }
+
+    fn count_area(&mut self, area: &Rectangle) {
+        let visible = area.intersection(&self.target.bounding_box());
+        self.pixels = self
+            .pixels
+            .saturating_add(visible.size.width * visible.size.height);
+    }
+}
+
+impl<D: DrawTarget> Dimensions for PixelCounter<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for PixelCounter<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let count = &mut self.pixels;
+        self.target.draw_iter(pixels.into_iter().inspect(|_| {
+            *count = count.saturating_add(1);
+        }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.count_area(area);
+        self.target.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.count_area(area);
+        self.target.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.target.bounding_box();
+        self.count_area(&area);
+        self.target.clear(color)
+    }
+}
+
+/// Draw one of the theme-independent test patterns
+fn draw_pattern<C, D>(kind: HilStageKind, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+where
+    C: PixelColor + From<Rgb565>,
+    D: DrawTarget<Color = C>,
+{
+    let size = viewport.size;
+    match kind {
+        HilStageKind::ColorBars => {
+            let colors = [
+                Rgb565::WHITE,
+                Rgb565::YELLOW,
+                Rgb565::CYAN,
+                Rgb565::GREEN,
+                Rgb565::MAGENTA,
+                Rgb565::RED,
+                Rgb565::BLUE,
+                Rgb565::BLACK,
+            ];
+            let bar_width = (size.width / colors.len() as u32).max(1);
+            for (index, color) in colors.iter().enumerate() {
+                let left = viewport.top_left.x + (index as u32 * bar_width) as i32;
+                let width = if index == colors.len() - 1 {
+                    size.width.saturating_sub(index as u32 * bar_width)
+                } else {
+                    bar_width
+                };
+                target
+                    .fill_solid(
+                        &Rectangle::new(
+                            Point::new(left, viewport.top_left.y),
+                            Size::new(width, size.height),
+                        ),
+                        (*color).into(),
+                    )
+                    .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+        HilStageKind::Orientation => {
+            target
+                .fill_solid(&viewport, Rgb565::BLACK.into())
+                .map_err(|_| ChartError::RenderingError)?;
+
+            let frame = PrimitiveStyle::with_stroke(Rgb565::WHITE.into(), 1);
+            viewport
+                .into_styled(frame)
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)?;
+
+            let bottom_right = viewport.bottom_right().unwrap_or(viewport.top_left);
+            let top_right = Point::new(bottom_right.x, viewport.top_left.y);
+            let bottom_left = Point::new(viewport.top_left.x, bottom_right.y);
+            let diagonal = PrimitiveStyle::with_stroke(Rgb565::CSS_GRAY.into(), 1);
+            for line in [
+                Line::new(viewport.top_left, bottom_right),
+                Line::new(top_right, bottom_left),
+            ] {
+                line.into_styled(diagonal)
+                    .draw(target)
+                    .map_err(|_| ChartError::RenderingError)?;
+            }
+
+            // Red, green, blue and white mark the four corners clockwise
+            // from the top left
+            let marker = (size.width.min(size.height) / 8).max(2);
+            let offset = marker as i32 - 1;
+            let corners = [
+                (viewport.top_left, Rgb565::RED),
+                (top_right - Point::new(offset, 0), Rgb565::GREEN),
+                (bottom_right - Point::new(offset, offset), Rgb565::BLUE),
+                (bottom_left - Point::new(0, offset), Rgb565::WHITE),
+            ];
+            for (corner, color) in corners {
+                target
+                    .fill_solid(
+                        &Rectangle::new(corner, Size::new(marker, marker)),
+                        color.into(),
+                    )
+                    .map_err(|_| ChartError::RenderingError)?;
+            }
+        }
+        _ => return Err(ChartError::InvalidConfiguration),
+    }
+    Ok(())
+}
+
+/// Draw one chart stage styled with `theme`
+fn draw_chart<C, D>(
+    kind: HilStageKind,
+    theme: &Theme<C>,
+    viewport: Rectangle,
+    target: &mut D,
+) -> ChartResult<()>
+where
+    C: PixelColor + From<Rgb565> + 'static,
+    D: DrawTarget<Color = C>,
+{
+    let config = ChartConfig {
+        background_color: Some(theme.background),
+        ..ChartConfig::default()
+    };
+    let palette = [
+        theme.primary,
+        theme.secondary,
+        theme.accent,
+        theme.success,
+        theme.warning,
+    ];
+    let radius = (viewport.size.width.min(viewport.size.height) / 2).saturating_sub(8);
+
+    match kind {
+        #[cfg(feature = "line")]
+        HilStageKind::Line => {
+            let chart = crate::chart::LineChart::builder()
+                .line_color(theme.primary)
+                .line_width(2)
+                .build()?;
+            chart.draw(&sample_series(5)?, &config, viewport, target)
+        }
+        #[cfg(feature = "line")]
+        HilStageKind::Curve => {
+            let chart = crate::chart::CurveChart::builder()
+                .line_color(theme.secondary)
+                .line_width(2)
+                .build()?;
+            chart.draw(&sample_series(5)?, &config, viewport, target)
+        }
+        #[cfg(feature = "bar")]
+        HilStageKind::Bar => {
+            let chart = crate::chart::BarChart::builder().colors(&palette).build()?;
+            chart.draw(&sample_series(6)?, &config, viewport, target)
+        }
+        #[cfg(feature = "bar")]
+        HilStageKind::HorizontalBar => {
+            let chart = crate::chart::BarChart::builder()
+                .orientation(crate::chart::BarOrientation::Horizontal)
+                .colors(&palette)
+                .build()?;
+            chart.draw(&sample_series(6)?, &config, viewport, target)
+        }
+        #[cfg(feature = "pie")]
+        HilStageKind::Pie => {
+            let chart = crate::chart::PieChart::builder()
+                .radius(radius)
+                .colors(&palette)
+                .build()?;
+            chart.draw(&sample_series(5)?, &config, viewport, target)
+        }
+        #[cfg(feature = "pie")]
+        HilStageKind::Donut => {
+            let chart = crate::chart::PieChart::builder()
+                .radius(radius)
+                .donut(radius / 2)
+                .colors(&palette)
+                .build()?;
+            chart.draw(&sample_series(5)?, &config, viewport, target)
+        }
+        #[cfg(feature = "scatter")]
+        HilStageKind::Scatter => {
+            let chart = crate::chart::ScatterChart::builder()
+                .point_color(theme.accent)
+                .point_size(4)
+                .build()?;
+            chart.draw(&sample_series(12)?, &config, viewport, target)
+        }
+        #[cfg(feature = "gauge")]
+        HilStageKind::Gauge => {
+            let chart = crate::chart::GaugeChart::builder()
+                .value_range(0.0, 100.0)
+                .radius(radius)
+                .add_threshold_zone(70.0, 90.0, theme.warning)
+                .add_threshold_zone(90.0, 100.0, theme.error)
+                .build()?;
+            let mut data = StaticDataSeries::new();
+            data.push(Point2D::new(0.0, 65.0))?;
+            chart.draw(&data, &config, viewport, target)
+        }
+        #[cfg(feature = "stacked-charts")]
+        HilStageKind::StackedBar => {
+            let chart = crate::chart::stacked::AnimatedStackedBarChart::builder().build()?;
+            let mut data = crate::chart::stacked::StackedData::new();
+            for (layer, color) in [Rgb565::BLUE, Rgb565::GREEN, Rgb565::RED]
+                .into_iter()
+                .enumerate()
+            {
+                let mut series = StaticDataSeries::new();
+                for (index, value) in SAMPLE_VALUES.iter().take(5).enumerate() {
+                    series.push(Point2D::new(index as f32, value / (layer + 1) as f32))?;
+                }
+                data.add_layer(series, "layer", color)?;
+            }
+            chart.draw(&data, &config, viewport, target)
+        }
+        _ => Err(ChartError::InvalidConfiguration),
+    }
+}
+
+/// Build a series from the first `count` sample values
+#[allow(dead_code)]
+fn sample_series(count: usize) -> ChartResult<StaticDataSeries<Point2D, 256>> {
+    let mut series = StaticDataSeries::new();
+    for (index, value) in SAMPLE_VALUES.iter().take(count).enumerate() {
+        series.push(Point2D::new(index as f32, *value))?;
+    }
+    Ok(series)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::ManualTimeProvider;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    #[test]
+    fn test_harness_runs_every_stage() {
+        let clock = ManualTimeProvider::new();
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+        let viewport = display.bounding_box();
+
+        let mut seen = 0;
+        let report = HilHarness::new(&clock)
+            .run_with(&mut display, viewport, |stage, _| {
+                assert!(stage.pixels > 0, "{} drew nothing", stage.kind.name());
+                seen += 1;
+                Ok(())
+            })
+            .unwrap();
+
+        let expected = HilStageKind::PATTERNS.len() + HilStageKind::CHARTS.len() * 10;
+        assert_eq!(report.stages as usize, expected);
+        assert_eq!(seen, expected);
+        assert!(report.passed(), "{:?}", report.first_failure);
+        assert!(report.total_pixels > 0);
+    }
+
+    #[test]
+    fn test_orientation_pattern_marks_corners() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let viewport = display.bounding_box();
+
+        draw_pattern(HilStageKind::Orientation, viewport, &mut display).unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(63, 0)), Some(Rgb565::GREEN));
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(Point::new(0, 63)), Some(Rgb565::WHITE));
+    }
+
+    #[test]
+    fn test_report_throughput() {
+        let mut report = HilReport::default();
+        report.record(HilStage {
+            kind: HilStageKind::ColorBars,
+            theme: "pattern",
+            elapsed_us: 2_000,
+            pixels: 4_096,
+            error: None,
+        });
+        report.record(HilStage {
+            kind: HilStageKind::Orientation,
+            theme: "pattern",
+            elapsed_us: 6_000,
+            pixels: 4_096,
+            error: Some(ChartError::RenderingError),
+        });
+
+        assert_eq!(report.average_stage_us(), 4_000);
+        assert_eq!(report.pixels_per_second(), 1_024_000);
+        assert_eq!(
+            report.slowest.map(|s| s.kind),
+            Some(HilStageKind::Orientation)
+        );
+        assert!(!report.passed());
+    }
+}
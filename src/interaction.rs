@@ -0,0 +1,389 @@
+//! Input abstraction for chart interaction.
+//!
+//! Buttons, rotary encoders and touch panels are reduced to a small
+//! [`InputEvent`] enum. Controllers consume these events and update the state
+//! that charts and dashboards are drawn from, so the glue between input
+//! drivers and charts can be shared across projects:
+//!
+//! - [`CursorController`] selects a data point for a readout or crosshair
+//! - [`ZoomController`] zooms and pans a [`Viewport`]
+//! - [`PageController`] flips between dashboard pages
+//!
+//! Each controller implements [`InputHandler`], whose `handle` method returns
+//! whether the state changed and the display needs to be redrawn.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::interaction::{CursorController, InputEvent, InputHandler};
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::primitives::Rectangle;
+//!
+//! let plot_area = Rectangle::new(Point::new(10, 10), Size::new(101, 50));
+//! let mut cursor = CursorController::new(11, plot_area);
+//!
+//! assert!(cursor.handle(InputEvent::Right));
+//! assert_eq!(cursor.index(), Some(0));
+//!
+//! cursor.handle(InputEvent::Rotate(3));
+//! assert_eq!(cursor.index(), Some(3));
+//!
+//! // Touching the middle of the plot area selects the middle point
+//! cursor.handle(InputEvent::TouchAt(Point::new(60, 30)));
+//! assert_eq!(cursor.index(), Some(5));
+//! ```
+
+use crate::layout::Viewport;
+use crate::render::{DetailPlan, DetailPolicy};
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// Input event from a button, rotary encoder or touch panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEvent {
+    /// Left button
+    Left,
+    /// Right button
+    Right,
+    /// Up button
+    Up,
+    /// Down button
+    Down,
+    /// Select or confirm button
+    Select,
+    /// Back or cancel button
+    Back,
+    /// Touch at a screen position
+    TouchAt(Point),
+    /// Encoder rotation by a number of detents (positive is clockwise)
+    Rotate(i8),
+}
+
+/// Consumer of input events
+pub trait InputHandler {
+    /// Handle an input event
+    ///
+    /// Returns `true` if the state changed and the display should be redrawn.
+    fn handle(&mut self, event: InputEvent) -> bool;
+}
+
+/// Cursor that selects one of `count` data points
+///
+/// Left/Right and encoder rotation move the cursor, Select locks it in place,
+/// Back hides it. A touch selects the point closest to the touched x position
+/// within the plot area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorController {
+    count: usize,
+    index: Option<usize>,
+    locked: bool,
+    plot_area: Rectangle,
+}
+
+impl CursorController {
+    /// Create a hidden cursor over `count` points drawn in `plot_area`
+    pub fn new(count: usize, plot_area: Rectangle) -> Self {
+        Self {
+            count,
+            index: None,
+            locked: false,
+            plot_area,
+        }
+    }
+
+    /// Get the selected point index, if the cursor is shown
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Check if the cursor is locked
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Update the number of points, keeping the cursor in range
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        self.index = match self.index {
+            Some(_) if count == 0 => None,
+            Some(index) => Some(index.min(count - 1)),
+            None => None,
+        };
+    }
+
+    /// Update the plot area used to map touches to points
+    pub fn set_plot_area(&mut self, plot_area: Rectangle) {
+        self.plot_area = plot_area;
+    }
+
+    /// Move the cursor by `steps` points, showing it if hidden
+    fn step(&mut self, steps: i32) -> bool {
+        if self.count == 0 || self.locked {
+            return false;
+        }
+        let next = match self.index {
+            None => 0,
+            Some(index) => (index as i32 + steps).clamp(0, self.count as i32 - 1) as usize,
+        };
+        let changed = self.index != Some(next);
+        self.index = Some(next);
+        changed
+    }
+
+    /// Map a touch position to the closest point index
+    fn index_at(&self, point: Point) -> Option<usize> {
+        if self.count == 0 || !self.plot_area.contains(point) {
+            return None;
+        }
+        let span = self.plot_area.size.width.saturating_sub(1).max(1) as i32;
+        let offset = point.x - self.plot_area.top_left.x;
+        let last = self.count as i32 - 1;
+        Some(((offset * last + span / 2) / span).clamp(0, last) as usize)
+    }
+}
+
+impl InputHandler for CursorController {
+    fn handle(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Left => self.step(-1),
+            InputEvent::Right => self.step(1),
+            InputEvent::Rotate(detents) => self.step(detents as i32),
+            InputEvent::Select if self.index.is_some() => {
+                self.locked = !self.locked;
+                true
+            }
+            InputEvent::Back if self.index.is_some() => {
+                self.index = None;
+                self.locked = false;
+                true
+            }
+            InputEvent::TouchAt(point) if !self.locked => match self.index_at(point) {
+                Some(index) if self.index != Some(index) => {
+                    self.index = Some(index);
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Zoom and pan control for a [`Viewport`]
+///
+/// Up/Down and encoder rotation zoom in and out, Left/Right pan, Back resets.
+/// A touch pans the touched point to the center of the viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoomController {
+    viewport: Viewport,
+    zoom_step: f32,
+    pan_step: i32,
+}
+
+impl ZoomController {
+    /// Create a zoom controller for a viewport
+    pub fn new(viewport: Viewport) -> Self {
+        Self {
+            viewport,
+            zoom_step: 1.25,
+            pan_step: 10,
+        }
+    }
+
+    /// Set the zoom factor applied per step (clamped to at least 1.01)
+    pub fn with_zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step.max(1.01);
+        self
+    }
+
+    /// Set the pan distance per step in pixels
+    pub fn with_pan_step(mut self, pan_step: u32) -> Self {
+        self.pan_step = pan_step as i32;
+        self
+    }
+
+    /// Get the current viewport
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    /// Plan how much of a `total_points` series to draw at the current zoom
+    pub fn detail(&self, policy: &DetailPolicy, total_points: usize) -> DetailPlan {
+        policy.for_viewport(&self.viewport, total_points)
+    }
+
+    /// Zoom by `steps` steps (negative zooms out)
+    fn zoom(&mut self, steps: i32) -> bool {
+        let mut zoom = self.viewport.zoom;
+        for _ in 0..steps.unsigned_abs() {
+            zoom = if steps > 0 {
+                zoom * self.zoom_step
+            } else {
+                zoom / self.zoom_step
+            };
+        }
+        let previous = self.viewport.zoom;
+        self.viewport = self.viewport.with_zoom(zoom);
+        self.viewport.zoom != previous
+    }
+
+    /// Pan by a screen offset
+    fn pan(&mut self, delta: Point) -> bool {
+        self.viewport.offset += delta;
+        delta != Point::zero()
+    }
+}
+
+impl InputHandler for ZoomController {
+    fn handle(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Up => self.zoom(1),
+            InputEvent::Down => self.zoom(-1),
+            InputEvent::Rotate(detents) => self.zoom(detents as i32),
+            InputEvent::Left => self.pan(Point::new(self.pan_step, 0)),
+            InputEvent::Right => self.pan(Point::new(-self.pan_step, 0)),
+            InputEvent::Back => {
+                let reset = Viewport::new(self.viewport.area);
+                let changed = self.viewport != reset;
+                self.viewport = reset;
+                changed
+            }
+            InputEvent::TouchAt(point) if self.viewport.area.contains(point) => {
+                self.pan(self.viewport.area.center() - point)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Page selection for dashboards with several screens
+///
+/// Left/Right and encoder rotation flip pages, wrapping around at the ends;
+/// Back returns to the first page. A touch on the left or right half of the
+/// screen flips to the previous or next page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageController {
+    page: usize,
+    count: usize,
+    screen: Rectangle,
+}
+
+impl PageController {
+    /// Create a page controller for `count` pages shown on `screen`
+    pub fn new(count: usize, screen: Rectangle) -> Self {
+        Self {
+            page: 0,
+            count,
+            screen,
+        }
+    }
+
+    /// Get the current page index
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Get the number of pages
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Flip by `steps` pages, wrapping around
+    fn flip(&mut self, steps: i32) -> bool {
+        if self.count < 2 {
+            return false;
+        }
+        let count = self.count as i32;
+        let next = (self.page as i32 + steps).rem_euclid(count) as usize;
+        let changed = next != self.page;
+        self.page = next;
+        changed
+    }
+}
+
+impl InputHandler for PageController {
+    fn handle(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Left => self.flip(-1),
+            InputEvent::Right => self.flip(1),
+            InputEvent::Rotate(detents) => self.flip(detents as i32),
+            InputEvent::Back => {
+                let changed = self.page != 0;
+                self.page = 0;
+                changed
+            }
+            InputEvent::TouchAt(point) if self.screen.contains(point) => {
+                if point.x < self.screen.center().x {
+                    self.flip(-1)
+                } else {
+                    self.flip(1)
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> Rectangle {
+        Rectangle::new(Point::zero(), Size::new(100, 50))
+    }
+
+    #[test]
+    fn test_cursor_controller() {
+        let mut cursor = CursorController::new(3, area());
+        assert!(!cursor.handle(InputEvent::Select));
+
+        cursor.handle(InputEvent::Right);
+        cursor.handle(InputEvent::Rotate(10));
+        assert_eq!(cursor.index(), Some(2));
+
+        assert!(cursor.handle(InputEvent::Select));
+        assert!(!cursor.handle(InputEvent::Left));
+        assert!(cursor.handle(InputEvent::Back));
+        assert_eq!(cursor.index(), None);
+
+        cursor.handle(InputEvent::TouchAt(Point::new(2, 10)));
+        assert_eq!(cursor.index(), Some(0));
+    }
+
+    #[test]
+    fn test_zoom_controller() {
+        let mut zoom = ZoomController::new(Viewport::new(area())).with_zoom_step(2.0);
+        assert!(zoom.handle(InputEvent::Rotate(2)));
+        assert_eq!(zoom.viewport().zoom, 4.0);
+
+        zoom.handle(InputEvent::TouchAt(Point::new(59, 24)));
+        assert_eq!(zoom.viewport().offset, Point::new(-10, 0));
+
+        assert!(zoom.handle(InputEvent::Back));
+        assert_eq!(zoom.viewport(), Viewport::new(area()));
+    }
+
+    #[test]
+    fn test_zoom_restores_detail() {
+        use crate::render::DetailLevel;
+
+        let policy = DetailPolicy::default();
+        let mut zoom = ZoomController::new(Viewport::new(area())).with_zoom_step(2.0);
+        assert_eq!(zoom.detail(&policy, 200).level, DetailLevel::Decimated);
+
+        zoom.handle(InputEvent::Rotate(2));
+        assert_eq!(zoom.detail(&policy, 200).level, DetailLevel::Thinned);
+        zoom.handle(InputEvent::Up);
+        assert_eq!(zoom.detail(&policy, 200).level, DetailLevel::Full);
+    }
+
+    #[test]
+    fn test_page_controller() {
+        let mut pages = PageController::new(3, area());
+        assert!(pages.handle(InputEvent::Left));
+        assert_eq!(pages.page(), 2);
+
+        pages.handle(InputEvent::TouchAt(Point::new(80, 10)));
+        assert_eq!(pages.page(), 0);
+        assert!(!pages.handle(InputEvent::Back));
+    }
+}
@@ -1,8 +1,11 @@
 //! Layout management for chart components.
 
-use crate::chart::traits::Margins;
+use crate::axes::{traits::Axis, AxisPosition};
+use crate::chart::traits::{ChartConfig, Margins};
 use crate::error::{LayoutError, LayoutResult};
+use crate::legend::Legend;
 use embedded_graphics::{prelude::*, primitives::Rectangle};
+use heapless::Vec;
 
 /// Layout manager for chart components
 #[derive(Debug, Clone)]
@@ -215,6 +218,26 @@ impl ChartLayout {
         self.chart_area
     }
 
+    /// Margins that place a chart's plot on the chart area
+    ///
+    /// Measured from the total area below the title, the way charts apply
+    /// [`ChartConfig::margins`] to [`ChartConfig::content_area`]. A chart drawn
+    /// into `total_area` with these margins fills exactly the chart area.
+    pub fn plot_margins(&self) -> Margins {
+        let top = self.title_area.map_or(self.total_area.top_left.y, bottom);
+        Margins::new(
+            (self.chart_area.top_left.y - top).max(0) as u32,
+            (right(self.total_area) - right(self.chart_area)).max(0) as u32,
+            (bottom(self.total_area) - bottom(self.chart_area)).max(0) as u32,
+            (self.chart_area.top_left.x - self.total_area.top_left.x).max(0) as u32,
+        )
+    }
+
+    /// Set the margins of `config` to [`plot_margins`](Self::plot_margins)
+    pub fn apply_to_config<C: PixelColor>(&self, config: &mut ChartConfig<C>) {
+        config.margins = self.plot_margins();
+    }
+
     /// Validate that the layout has sufficient space
     pub fn validate(&self) -> LayoutResult<()> {
         if self.chart_area.size.width < 10 || self.chart_area.size.height < 10 {
@@ -222,6 +245,456 @@ impl ChartLayout {
         }
         Ok(())
     }
+
+    /// Find all pairs of regions that overlap each other
+    pub fn overlaps(&self) -> Vec<LayoutOverlap, 10> {
+        let regions = [
+            (LayoutRegion::Chart, Some(self.chart_area)),
+            (LayoutRegion::Title, self.title_area),
+            (LayoutRegion::Legend, self.legend_area),
+            (LayoutRegion::XAxis, self.x_axis_area),
+            (LayoutRegion::YAxis, self.y_axis_area),
+        ];
+
+        let mut overlaps = Vec::new();
+        for (i, &(first, first_area)) in regions.iter().enumerate() {
+            for &(second, second_area) in &regions[i + 1..] {
+                if let (Some(a), Some(b)) = (first_area, second_area) {
+                    if intersects(a, b) {
+                        // At most 10 pairs exist for 5 regions
+                        let _ = overlaps.push(LayoutOverlap { first, second });
+                    }
+                }
+            }
+        }
+        overlaps
+    }
+
+    /// Check whether any regions overlap
+    pub fn has_overlaps(&self) -> bool {
+        !self.overlaps().is_empty()
+    }
+
+    /// Re-layout the chart so that no regions overlap.
+    ///
+    /// The title and legend keep their size. A legend that overlaps the title
+    /// is moved off it, then the chart area and the axis label regions are
+    /// shrunk away from the title and legend. This handles, for example, a
+    /// legend placed at the bottom of the viewport on top of the X-axis labels.
+    pub fn resolve_overlaps(mut self) -> LayoutResult<Self> {
+        if let (Some(title), Some(legend)) = (self.title_area, self.legend_area) {
+            if intersects(title, legend) {
+                let moved = move_clear_of(legend, title);
+                if !contains(self.total_area, moved) {
+                    return Err(LayoutError::InsufficientSpace);
+                }
+                self.legend_area = Some(moved);
+            }
+        }
+
+        let plot_block = self.plot_block();
+        let mut block = plot_block;
+        for fixed in [self.title_area, self.legend_area].into_iter().flatten() {
+            if intersects(block, fixed) {
+                block = cut_away(block, fixed)?;
+            }
+        }
+
+        if block != plot_block {
+            self.split_plot_block(block)?;
+        }
+        Ok(self)
+    }
+
+    /// Bounding box of the chart area and the axis regions
+    fn plot_block(&self) -> Rectangle {
+        [self.x_axis_area, self.y_axis_area]
+            .into_iter()
+            .flatten()
+            .fold(self.chart_area, |block, area| {
+                let left = block.top_left.x.min(area.top_left.x);
+                let top = block.top_left.y.min(area.top_left.y);
+                let right = right(block).max(right(area));
+                let bottom = bottom(block).max(bottom(area));
+                Rectangle::new(
+                    Point::new(left, top),
+                    Size::new((right - left) as u32, (bottom - top) as u32),
+                )
+            })
+    }
+
+    /// Lay out the chart area and axis regions inside `block`, keeping the
+    /// axis sizes and the sides they are on
+    fn split_plot_block(&mut self, block: Rectangle) -> LayoutResult<()> {
+        let x_height = self.x_axis_area.map_or(0, |area| area.size.height);
+        let y_width = self.y_axis_area.map_or(0, |area| area.size.width);
+        if block.size.width <= y_width || block.size.height <= x_height {
+            return Err(LayoutError::InsufficientSpace);
+        }
+
+        let x_at_bottom = self
+            .x_axis_area
+            .is_none_or(|area| area.top_left.y >= self.chart_area.top_left.y);
+        let y_at_left = self
+            .y_axis_area
+            .is_none_or(|area| area.top_left.x <= self.chart_area.top_left.x);
+
+        let chart_area = Rectangle::new(
+            Point::new(
+                block.top_left.x + if y_at_left { y_width as i32 } else { 0 },
+                block.top_left.y + if x_at_bottom { 0 } else { x_height as i32 },
+            ),
+            Size::new(block.size.width - y_width, block.size.height - x_height),
+        );
+
+        if let Some(area) = self.x_axis_area.as_mut() {
+            let y = if x_at_bottom {
+                bottom(chart_area)
+            } else {
+                block.top_left.y
+            };
+            *area = Rectangle::new(
+                Point::new(chart_area.top_left.x, y),
+                Size::new(chart_area.size.width, x_height),
+            );
+        }
+        if let Some(area) = self.y_axis_area.as_mut() {
+            let x = if y_at_left {
+                block.top_left.x
+            } else {
+                right(chart_area)
+            };
+            *area = Rectangle::new(
+                Point::new(x, chart_area.top_left.y),
+                Size::new(y_width, chart_area.size.height),
+            );
+        }
+        self.chart_area = chart_area;
+        Ok(())
+    }
+}
+
+/// Measures chart components and partitions a viewport between them
+///
+/// Collects the title band of a [`ChartConfig`], the size of a legend and the
+/// space axes need for their ticks and labels, then [`solve`](Self::solve)s
+/// the layout from the outside in: the title across the top, then the padding,
+/// the legend and the axes, leaving the rest for the plot.
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+/// use embedded_charts::layout::LayoutSolver;
+/// use embedded_graphics::{pixelcolor::Rgb565, primitives::Rectangle};
+///
+/// let mut config: ChartConfig<Rgb565> = ChartConfig::default();
+/// config.title = heapless::String::try_from("Temperature").ok();
+/// let x_axis: LinearAxis<f32, Rgb565> =
+///     LinearAxis::new(0.0, 60.0, AxisOrientation::Horizontal, AxisPosition::Bottom);
+/// let y_axis: LinearAxis<f32, Rgb565> =
+///     LinearAxis::new(15.0, 30.0, AxisOrientation::Vertical, AxisPosition::Left);
+///
+/// let viewport = Rectangle::new(Point::zero(), Size::new(160, 120));
+/// let layout = LayoutSolver::new(viewport)
+///     .title(&config)
+///     .x_axis(&x_axis)
+///     .y_axis(&y_axis)
+///     .solve()?;
+///
+/// // Draw the chart into the whole viewport; its plot lands on the chart area
+/// layout.apply_to_config(&mut config);
+/// assert_eq!(
+///     config.margins.apply_to(config.content_area(viewport)),
+///     layout.chart_area()
+/// );
+/// # Ok::<(), embedded_charts::error::LayoutError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayoutSolver {
+    area: Rectangle,
+    padding: Margins,
+    title_height: u32,
+    legend: Option<(crate::legend::LegendPosition, Size)>,
+    x_axis: Option<(AxisPosition, u32)>,
+    y_axis: Option<(AxisPosition, u32)>,
+}
+
+impl LayoutSolver {
+    /// Start a layout of `area` without any components
+    pub fn new(area: Rectangle) -> Self {
+        Self {
+            area,
+            padding: Margins::all(0),
+            title_height: 0,
+            legend: None,
+            x_axis: None,
+            y_axis: None,
+        }
+    }
+
+    /// Keep a gap between the title and the edges of the area and everything else
+    pub fn padding(mut self, padding: Margins) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Reserve the title band of a chart configuration
+    pub fn title<C: PixelColor>(mut self, config: &ChartConfig<C>) -> Self {
+        self.title_height = config.title_height();
+        self
+    }
+
+    /// Reserve room for a legend at its position
+    ///
+    /// Corner, custom and floating legends overlay the plot, as with
+    /// [`Margins::with_legend`].
+    pub fn legend<C: PixelColor, L: Legend<C>>(self, legend: &L) -> Self {
+        self.legend_size(legend.position(), legend.calculate_size())
+    }
+
+    /// Reserve room for a legend of a known size
+    pub fn legend_size(mut self, position: crate::legend::LegendPosition, size: Size) -> Self {
+        self.legend = Some((position, size));
+        self
+    }
+
+    /// Reserve the space a horizontal axis needs on its side of the plot
+    pub fn x_axis<T, C: PixelColor, A: Axis<T, C>>(mut self, axis: &A) -> Self {
+        self.x_axis = Some((axis.position(), axis.required_space()));
+        self
+    }
+
+    /// Reserve the space a vertical axis needs on its side of the plot
+    pub fn y_axis<T, C: PixelColor, A: Axis<T, C>>(mut self, axis: &A) -> Self {
+        self.y_axis = Some((axis.position(), axis.required_space()));
+        self
+    }
+
+    /// Partition the area
+    ///
+    /// Fails with [`LayoutError::InsufficientSpace`] when the components leave
+    /// no room for the plot.
+    pub fn solve(self) -> LayoutResult<ChartLayout> {
+        use crate::legend::LegendPosition as Placement;
+
+        let mut layout = ChartLayout::new(self.area);
+        if self.title_height > 0 {
+            layout = layout.with_title(self.title_height)?;
+        }
+        layout.chart_area = self.padding.apply_to(layout.chart_area);
+
+        let overlay = match self.legend {
+            Some((Placement::Top, size)) => {
+                layout = layout.with_legend(LegendPosition::Top, size)?;
+                None
+            }
+            Some((Placement::Bottom, size)) => {
+                layout = layout.with_legend(LegendPosition::Bottom, size)?;
+                None
+            }
+            Some((Placement::Left, size)) => {
+                layout = layout.with_legend(LegendPosition::Left, size)?;
+                None
+            }
+            Some((Placement::Right, size)) => {
+                layout = layout.with_legend(LegendPosition::Right, size)?;
+                None
+            }
+            other => other,
+        };
+
+        if let Some((position, space)) = self.x_axis {
+            let side = match position {
+                AxisPosition::Top => Side::Top,
+                _ => Side::Bottom,
+            };
+            layout.x_axis_area = Some(layout.take_edge(side, space)?);
+        }
+        if let Some((position, space)) = self.y_axis {
+            let side = match position {
+                AxisPosition::Right => Side::Right,
+                _ => Side::Left,
+            };
+            layout.y_axis_area = Some(layout.take_edge(side, space)?);
+        }
+
+        if layout.chart_area.is_zero_sized() {
+            return Err(LayoutError::InsufficientSpace);
+        }
+
+        // Corner legends sit inside the plot
+        if let Some((position, size)) = overlay {
+            let plot = layout.chart_area;
+            let x_right = right(plot) - size.width as i32;
+            let y_bottom = bottom(plot) - size.height as i32;
+            let top_left = match position {
+                Placement::TopRight => Point::new(x_right, plot.top_left.y),
+                Placement::BottomLeft => Point::new(plot.top_left.x, y_bottom),
+                Placement::BottomRight => Point::new(x_right, y_bottom),
+                Placement::Custom(point) | Placement::Floating(point) => point,
+                _ => plot.top_left,
+            };
+            layout.legend_area = Some(Rectangle::new(top_left, size));
+        }
+
+        Ok(layout)
+    }
+}
+
+impl ChartLayout {
+    /// Cut a band `extent` pixels deep off one side of the chart area
+    fn take_edge(&mut self, side: Side, extent: u32) -> LayoutResult<Rectangle> {
+        let area = self.chart_area;
+        let Size { width, height } = area.size;
+        let depth = match side {
+            Side::Top | Side::Bottom => height,
+            Side::Left | Side::Right => width,
+        };
+        if extent >= depth {
+            return Err(LayoutError::InsufficientSpace);
+        }
+
+        let (band, rest) = match side {
+            Side::Top => (
+                Rectangle::new(area.top_left, Size::new(width, extent)),
+                Rectangle::new(
+                    area.top_left + Point::new(0, extent as i32),
+                    Size::new(width, height - extent),
+                ),
+            ),
+            Side::Bottom => (
+                Rectangle::new(
+                    Point::new(area.top_left.x, bottom(area) - extent as i32),
+                    Size::new(width, extent),
+                ),
+                Rectangle::new(area.top_left, Size::new(width, height - extent)),
+            ),
+            Side::Left => (
+                Rectangle::new(area.top_left, Size::new(extent, height)),
+                Rectangle::new(
+                    area.top_left + Point::new(extent as i32, 0),
+                    Size::new(width - extent, height),
+                ),
+            ),
+            Side::Right => (
+                Rectangle::new(
+                    Point::new(right(area) - extent as i32, area.top_left.y),
+                    Size::new(extent, height),
+                ),
+                Rectangle::new(area.top_left, Size::new(width - extent, height)),
+            ),
+        };
+        self.chart_area = rest;
+        Ok(band)
+    }
+}
+
+/// Regions of a [`ChartLayout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutRegion {
+    /// Chart drawing area
+    Chart,
+    /// Title area
+    Title,
+    /// Legend area
+    Legend,
+    /// X-axis label area
+    XAxis,
+    /// Y-axis label area
+    YAxis,
+}
+
+/// Two layout regions that overlap, as reported by [`ChartLayout::overlaps`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOverlap {
+    /// First overlapping region
+    pub first: LayoutRegion,
+    /// Second overlapping region
+    pub second: LayoutRegion,
+}
+
+impl LayoutOverlap {
+    /// Check whether `region` is part of this overlap
+    pub fn involves(&self, region: LayoutRegion) -> bool {
+        self.first == region || self.second == region
+    }
+}
+
+/// Side of a reference rectangle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+fn right(area: Rectangle) -> i32 {
+    area.top_left.x + area.size.width as i32
+}
+
+fn bottom(area: Rectangle) -> i32 {
+    area.top_left.y + area.size.height as i32
+}
+
+fn intersects(a: Rectangle, b: Rectangle) -> bool {
+    !a.intersection(&b).is_zero_sized()
+}
+
+fn contains(outer: Rectangle, inner: Rectangle) -> bool {
+    inner.top_left.x >= outer.top_left.x
+        && inner.top_left.y >= outer.top_left.y
+        && right(inner) <= right(outer)
+        && bottom(inner) <= bottom(outer)
+}
+
+/// Side of `reference` that `area` lies on, judged by the offset between the
+/// centers relative to the reference's aspect ratio
+fn side_of(area: Rectangle, reference: Rectangle) -> Side {
+    let dx = i64::from(area.center().x - reference.center().x);
+    let dy = i64::from(area.center().y - reference.center().y);
+    let horizontal = dx.abs() * i64::from(reference.size.height);
+    let vertical = dy.abs() * i64::from(reference.size.width);
+    if vertical >= horizontal {
+        if dy >= 0 {
+            Side::Bottom
+        } else {
+            Side::Top
+        }
+    } else if dx >= 0 {
+        Side::Right
+    } else {
+        Side::Left
+    }
+}
+
+/// Move `area` so that it sits next to `fixed` instead of on top of it
+fn move_clear_of(area: Rectangle, fixed: Rectangle) -> Rectangle {
+    let top_left = match side_of(area, fixed) {
+        Side::Bottom => Point::new(area.top_left.x, bottom(fixed)),
+        Side::Top => Point::new(area.top_left.x, fixed.top_left.y - area.size.height as i32),
+        Side::Right => Point::new(right(fixed), area.top_left.y),
+        Side::Left => Point::new(fixed.top_left.x - area.size.width as i32, area.top_left.y),
+    };
+    Rectangle::new(top_left, area.size)
+}
+
+/// Shrink `block` so that it no longer overlaps `fixed`
+fn cut_away(block: Rectangle, fixed: Rectangle) -> LayoutResult<Rectangle> {
+    let (mut left, mut top) = (block.top_left.x, block.top_left.y);
+    let (mut right_edge, mut bottom_edge) = (right(block), bottom(block));
+    match side_of(fixed, block) {
+        Side::Bottom => bottom_edge = bottom_edge.min(fixed.top_left.y),
+        Side::Top => top = top.max(bottom(fixed)),
+        Side::Right => right_edge = right_edge.min(fixed.top_left.x),
+        Side::Left => left = left.max(right(fixed)),
+    }
+    if right_edge <= left || bottom_edge <= top {
+        return Err(LayoutError::InsufficientSpace);
+    }
+    Ok(Rectangle::new(
+        Point::new(left, top),
+        Size::new((right_edge - left) as u32, (bottom_edge - top) as u32),
+    ))
 }
 
 /// Legend position options
@@ -471,6 +944,69 @@ mod tests {
         assert_eq!(layout.chart_area.size.height, 270);
     }
 
+    fn labelled_layout() -> ChartLayout {
+        let area = Rectangle::new(Point::zero(), Size::new(200, 150));
+        ChartLayout::new(area)
+            .with_title(20)
+            .unwrap()
+            .with_x_axis(20)
+            .unwrap()
+            .with_y_axis(30)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_bottom_legend_over_x_labels_is_resolved() {
+        let mut layout = labelled_layout();
+        assert!(!layout.has_overlaps());
+
+        // Legend placed at the bottom of the viewport, over the X labels
+        layout.legend_area = Some(Rectangle::new(Point::new(50, 135), Size::new(100, 15)));
+        let overlaps = layout.overlaps();
+        assert!(overlaps.contains(&LayoutOverlap {
+            first: LayoutRegion::Legend,
+            second: LayoutRegion::XAxis,
+        }));
+
+        let layout = layout.resolve_overlaps().unwrap();
+        assert!(!layout.has_overlaps());
+        assert_eq!(
+            layout.chart_area,
+            Rectangle::new(Point::new(30, 20), Size::new(170, 95))
+        );
+        assert_eq!(
+            layout.x_axis_area,
+            Some(Rectangle::new(Point::new(30, 115), Size::new(170, 20)))
+        );
+        assert_eq!(layout.y_axis_area.unwrap().size, Size::new(30, 95));
+    }
+
+    #[test]
+    fn test_legend_over_title_is_moved() {
+        let mut layout = labelled_layout();
+        layout.legend_area = Some(Rectangle::new(Point::new(60, 10), Size::new(80, 20)));
+        assert!(layout
+            .overlaps()
+            .iter()
+            .any(|overlap| overlap.involves(LayoutRegion::Title)));
+
+        let layout = layout.resolve_overlaps().unwrap();
+        assert!(!layout.has_overlaps());
+        assert_eq!(layout.legend_area.unwrap().top_left, Point::new(60, 20));
+        assert_eq!(layout.chart_area.top_left.y, 40);
+        assert_eq!(layout.x_axis_area.unwrap().top_left.y, 130);
+    }
+
+    #[test]
+    fn test_resolve_overlaps_without_space() {
+        let mut layout = labelled_layout();
+        layout.legend_area = Some(Rectangle::new(Point::new(0, 25), Size::new(200, 120)));
+        assert_eq!(
+            layout.resolve_overlaps().unwrap_err(),
+            LayoutError::InsufficientSpace
+        );
+    }
+
     #[test]
     fn test_viewport_creation() {
         let area = Rectangle::new(Point::zero(), Size::new(200, 150));
@@ -518,4 +1054,71 @@ mod tests {
             ComponentPositioning::align_bottom_right(component_size, container, margin);
         assert_eq!(bottom_right, Point::new(75, 65));
     }
+
+    #[test]
+    fn test_solver_measures_components() {
+        use crate::axes::{AxisOrientation, LinearAxis};
+        use crate::legend::LegendPosition as Placement;
+        use embedded_graphics::pixelcolor::Rgb565;
+
+        let area = Rectangle::new(Point::zero(), Size::new(200, 150));
+        let mut config: ChartConfig<Rgb565> = ChartConfig {
+            title: heapless::String::try_from("Title").ok(),
+            ..Default::default()
+        };
+        let x_axis: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 10.0, AxisOrientation::Horizontal, AxisPosition::Bottom);
+        let y_axis: LinearAxis<f32, Rgb565> =
+            LinearAxis::new(0.0, 1000.0, AxisOrientation::Vertical, AxisPosition::Left);
+
+        let layout = LayoutSolver::new(area)
+            .title(&config)
+            .padding(Margins::all(2))
+            .legend_size(Placement::Right, Size::new(40, 30))
+            .x_axis(&x_axis)
+            .y_axis(&y_axis)
+            .solve()
+            .unwrap();
+
+        assert!(!layout.has_overlaps());
+        assert_eq!(
+            layout.title_area.unwrap().size.height,
+            config.title_height()
+        );
+        assert_eq!(layout.legend_area.unwrap().top_left.x, 158);
+        assert_eq!(
+            layout.x_axis_area.unwrap().size.height,
+            x_axis.required_space()
+        );
+        assert_eq!(
+            layout.y_axis_area.unwrap().size.width,
+            y_axis.required_space()
+        );
+
+        layout.apply_to_config(&mut config);
+        assert_eq!(
+            config.margins.apply_to(config.content_area(area)),
+            layout.chart_area()
+        );
+
+        // Corner legends overlay the plot
+        let layout = LayoutSolver::new(area)
+            .legend_size(Placement::TopRight, Size::new(40, 30))
+            .solve()
+            .unwrap();
+        assert_eq!(layout.chart_area(), area);
+        assert_eq!(
+            layout.legend_area,
+            Some(Rectangle::new(Point::new(160, 0), Size::new(40, 30)))
+        );
+
+        let small = Rectangle::new(Point::zero(), Size::new(20, 20));
+        assert_eq!(
+            LayoutSolver::new(small)
+                .y_axis(&y_axis)
+                .solve()
+                .unwrap_err(),
+            LayoutError::InsufficientSpace
+        );
+    }
 }
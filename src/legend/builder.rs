@@ -24,19 +24,27 @@ pub trait LegendBuilder<C: PixelColor> {
 }
 
 /// Builder for standard legends
+///
+/// Builds a legend with room for `N` entries, sixteen by default. Use
+/// [`Default`] to pick another capacity, e.g.
+/// `StandardLegendBuilder::<Rgb565, 24>::default()`.
 #[derive(Debug)]
-pub struct StandardLegendBuilder<C: PixelColor> {
+pub struct StandardLegendBuilder<C: PixelColor, const N: usize = 16> {
     position: LegendPosition,
     orientation: LegendOrientation,
     style: LegendStyle<C>,
     alignment: LegendAlignment,
     margins: LegendMargins,
-    entries: heapless::Vec<StandardLegendEntry<C>, 16>,
+    entries: heapless::Vec<StandardLegendEntry<C>, N>,
 }
 
 /// Builder for compact legends
+///
+/// Builds a legend with room for `N` entries, eight by default. Use
+/// [`Default`] to pick another capacity, e.g.
+/// `CompactLegendBuilder::<Rgb565, 24>::default()`.
 #[derive(Debug)]
-pub struct CompactLegendBuilder<C: PixelColor> {
+pub struct CompactLegendBuilder<C: PixelColor, const N: usize = 8> {
     position: LegendPosition,
     orientation: LegendOrientation,
     style: LegendStyle<C>,
@@ -44,12 +52,16 @@ pub struct CompactLegendBuilder<C: PixelColor> {
     alignment: LegendAlignment,
     #[allow(dead_code)]
     margins: LegendMargins,
-    entries: heapless::Vec<CompactLegendEntry<C>, 8>,
+    entries: heapless::Vec<CompactLegendEntry<C>, N>,
 }
 
 /// Builder for custom legends
+///
+/// Builds a legend with room for `N` entries, twelve by default. Use
+/// [`Default`] to pick another capacity, e.g.
+/// `CustomLegendBuilder::<Rgb565, 24>::default()`.
 #[derive(Debug)]
-pub struct CustomLegendBuilder<C: PixelColor> {
+pub struct CustomLegendBuilder<C: PixelColor, const N: usize = 12> {
     position: LegendPosition,
     orientation: LegendOrientation,
     style: LegendStyle<C>,
@@ -57,7 +69,7 @@ pub struct CustomLegendBuilder<C: PixelColor> {
     alignment: LegendAlignment,
     #[allow(dead_code)]
     margins: LegendMargins,
-    entries: heapless::Vec<CustomLegendEntry<C>, 12>,
+    entries: heapless::Vec<CustomLegendEntry<C>, N>,
     layout_params: crate::legend::types::CustomLayoutParams,
 }
 
@@ -65,18 +77,16 @@ impl<C: PixelColor> StandardLegendBuilder<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    /// Create a new standard legend builder
+    /// Create a new standard legend builder with the default capacity
     pub fn new() -> Self {
-        Self {
-            position: LegendPosition::Right,
-            orientation: LegendOrientation::Vertical,
-            style: LegendStyle::new(),
-            alignment: LegendAlignment::Start,
-            margins: LegendMargins::default(),
-            entries: heapless::Vec::new(),
-        }
+        Self::default()
     }
+}
 
+impl<C: PixelColor, const N: usize> StandardLegendBuilder<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
     /// Set the legend position
     pub fn position(mut self, position: LegendPosition) -> Self {
         self.position = position;
@@ -224,6 +234,34 @@ where
         Ok(self)
     }
 
+    /// Add a line entry per series, colored by the series palette
+    ///
+    /// Entry `i` gets `palette.color(i)`, the same color charts use for series `i`.
+    pub fn add_line_series<const P: usize>(
+        mut self,
+        labels: &[&str],
+        palette: &crate::style::SeriesPalette<C, P>,
+    ) -> ChartResult<Self> {
+        for (label, color) in labels.iter().zip(palette.colors()) {
+            self = self.add_line_entry(label, color)?;
+        }
+        Ok(self)
+    }
+
+    /// Add a bar entry per series, colored by the series palette
+    ///
+    /// Entry `i` gets `palette.color(i)`, the same color charts use for series `i`.
+    pub fn add_bar_series<const P: usize>(
+        mut self,
+        labels: &[&str],
+        palette: &crate::style::SeriesPalette<C, P>,
+    ) -> ChartResult<Self> {
+        for (label, color) in labels.iter().zip(palette.colors()) {
+            self = self.add_bar_entry(label, color)?;
+        }
+        Ok(self)
+    }
+
     /// Add a generic entry to the legend
     pub fn add_entry(mut self, label: &str, entry_type: LegendEntryType<C>) -> ChartResult<Self> {
         let entry = StandardLegendEntry::new(label, entry_type)?;
@@ -240,11 +278,11 @@ where
     }
 }
 
-impl<C: PixelColor> LegendBuilder<C> for StandardLegendBuilder<C>
+impl<C: PixelColor, const N: usize> LegendBuilder<C> for StandardLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    type Legend = StandardLegend<C>;
+    type Legend = StandardLegend<C, N>;
     type Error = ChartError;
 
     fn build(self) -> Result<Self::Legend, Self::Error> {
@@ -261,12 +299,19 @@ where
     }
 }
 
-impl<C: PixelColor> Default for StandardLegendBuilder<C>
+impl<C: PixelColor, const N: usize> Default for StandardLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
     fn default() -> Self {
-        Self::new()
+        Self {
+            position: LegendPosition::Right,
+            orientation: LegendOrientation::Vertical,
+            style: LegendStyle::new(),
+            alignment: LegendAlignment::Start,
+            margins: LegendMargins::default(),
+            entries: heapless::Vec::new(),
+        }
     }
 }
 
@@ -274,18 +319,16 @@ impl<C: PixelColor> CompactLegendBuilder<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    /// Create a new compact legend builder
+    /// Create a new compact legend builder with the default capacity
     pub fn new() -> Self {
-        Self {
-            position: LegendPosition::Right,
-            orientation: LegendOrientation::Vertical,
-            style: LegendStyle::compact(),
-            alignment: LegendAlignment::Start,
-            margins: LegendMargins::all(4),
-            entries: heapless::Vec::new(),
-        }
+        Self::default()
     }
+}
 
+impl<C: PixelColor, const N: usize> CompactLegendBuilder<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
     /// Set the legend position
     pub fn position(mut self, position: LegendPosition) -> Self {
         self.position = position;
@@ -313,11 +356,11 @@ where
     }
 }
 
-impl<C: PixelColor> LegendBuilder<C> for CompactLegendBuilder<C>
+impl<C: PixelColor, const N: usize> LegendBuilder<C> for CompactLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    type Legend = CompactLegend<C>;
+    type Legend = CompactLegend<C, N>;
     type Error = ChartError;
 
     fn build(self) -> Result<Self::Legend, Self::Error> {
@@ -334,12 +377,19 @@ where
     }
 }
 
-impl<C: PixelColor> Default for CompactLegendBuilder<C>
+impl<C: PixelColor, const N: usize> Default for CompactLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
     fn default() -> Self {
-        Self::new()
+        Self {
+            position: LegendPosition::Right,
+            orientation: LegendOrientation::Vertical,
+            style: LegendStyle::compact(),
+            alignment: LegendAlignment::Start,
+            margins: LegendMargins::all(4),
+            entries: heapless::Vec::new(),
+        }
     }
 }
 
@@ -347,19 +397,16 @@ impl<C: PixelColor> CustomLegendBuilder<C>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    /// Create a new custom legend builder
+    /// Create a new custom legend builder with the default capacity
     pub fn new() -> Self {
-        Self {
-            position: LegendPosition::Right,
-            orientation: LegendOrientation::Vertical,
-            style: LegendStyle::new(),
-            alignment: LegendAlignment::Start,
-            margins: LegendMargins::default(),
-            entries: heapless::Vec::new(),
-            layout_params: crate::legend::types::CustomLayoutParams::default(),
-        }
+        Self::default()
     }
+}
 
+impl<C: PixelColor, const N: usize> CustomLegendBuilder<C, N>
+where
+    C: From<embedded_graphics::pixelcolor::Rgb565>,
+{
     /// Set the legend position
     pub fn position(mut self, position: LegendPosition) -> Self {
         self.position = position;
@@ -397,11 +444,11 @@ where
     }
 }
 
-impl<C: PixelColor> LegendBuilder<C> for CustomLegendBuilder<C>
+impl<C: PixelColor, const N: usize> LegendBuilder<C> for CustomLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
-    type Legend = CustomLegend<C>;
+    type Legend = CustomLegend<C, N>;
     type Error = ChartError;
 
     fn build(self) -> Result<Self::Legend, Self::Error> {
@@ -419,12 +466,20 @@ where
     }
 }
 
-impl<C: PixelColor> Default for CustomLegendBuilder<C>
+impl<C: PixelColor, const N: usize> Default for CustomLegendBuilder<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
     fn default() -> Self {
-        Self::new()
+        Self {
+            position: LegendPosition::Right,
+            orientation: LegendOrientation::Vertical,
+            style: LegendStyle::new(),
+            alignment: LegendAlignment::Start,
+            margins: LegendMargins::default(),
+            entries: heapless::Vec::new(),
+            layout_params: crate::legend::types::CustomLayoutParams::default(),
+        }
     }
 }
 
@@ -497,6 +552,24 @@ mod tests {
         assert_eq!(legend.entries().len(), 2);
     }
 
+    #[test]
+    fn test_series_entries_follow_palette() {
+        use crate::legend::traits::LegendEntry;
+
+        let palette = crate::style::SeriesPalette::<Rgb565, 8>::default();
+        let legend = StandardLegendBuilder::new()
+            .add_line_series(&["Temperature", "Humidity"], &palette)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(legend.entries().len(), 2);
+        assert!(matches!(
+            legend.entries()[1].entry_type(),
+            LegendEntryType::Line { color, .. } if *color == palette.color(1)
+        ));
+    }
+
     #[test]
     fn test_preset_legends() {
         let legend = presets::right_legend::<Rgb565>()
@@ -508,4 +581,39 @@ mod tests {
         assert_eq!(legend.position(), LegendPosition::Right);
         assert_eq!(legend.orientation(), LegendOrientation::Vertical);
     }
+
+    #[test]
+    fn test_legend_capacity_beyond_eight() {
+        use crate::legend::{DefaultLegend, DefaultLegendRenderer, LegendRenderer};
+        use embedded_graphics::primitives::Rectangle;
+
+        const LABELS: [&str; 12] = [
+            "CH1", "CH2", "CH3", "CH4", "CH5", "CH6", "CH7", "CH8", "CH9", "CH10", "CH11", "CH12",
+        ];
+
+        let mut builder = CompactLegendBuilder::<Rgb565, 12>::default();
+        for label in LABELS {
+            builder = builder.add_simple_entry(label, Rgb565::RED).unwrap();
+        }
+        assert!(builder.add_simple_entry("CH13", Rgb565::RED).is_err());
+
+        let mut legend: DefaultLegend<Rgb565, 12> = DefaultLegend::new(LegendPosition::Right);
+        for label in LABELS {
+            legend
+                .add_entry(
+                    label,
+                    LegendEntryType::Bar {
+                        color: Rgb565::BLUE,
+                        border_color: None,
+                        border_width: 0,
+                    },
+                )
+                .unwrap();
+        }
+        let viewport = Rectangle::new(Point::zero(), Size::new(80, 240));
+        let layout = DefaultLegendRenderer::new()
+            .calculate_layout(&legend, viewport)
+            .unwrap();
+        assert_eq!(layout.len(), 12);
+    }
 }
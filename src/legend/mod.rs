@@ -24,10 +24,12 @@ use crate::error::ChartResult;
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
 /// Default legend configuration
+///
+/// Holds up to `N` entries, eight by default.
 #[derive(Debug, Clone)]
-pub struct DefaultLegend<C: PixelColor> {
+pub struct DefaultLegend<C: PixelColor, const N: usize = 8> {
     /// Legend entries
-    pub entries: heapless::Vec<DefaultLegendEntry<C>, 8>,
+    pub entries: heapless::Vec<DefaultLegendEntry<C>, N>,
     /// Legend position
     pub position: LegendPosition,
     /// Legend orientation
@@ -47,7 +49,9 @@ pub struct DefaultLegendEntry<C: PixelColor> {
     pub visible: bool,
 }
 
-impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> DefaultLegend<C> {
+impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>, const N: usize>
+    DefaultLegend<C, N>
+{
     /// Create a new default legend
     pub fn new(position: LegendPosition) -> Self {
         Self {
@@ -217,7 +221,9 @@ impl<C: PixelColor> LegendEntry<C> for DefaultLegendEntry<C> {
     }
 }
 
-impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> Legend<C> for DefaultLegend<C> {
+impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>, const N: usize> Legend<C>
+    for DefaultLegend<C, N>
+{
     type Entry = DefaultLegendEntry<C>;
 
     fn entries(&self) -> &[Self::Entry] {
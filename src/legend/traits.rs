@@ -59,7 +59,9 @@ pub trait Legend<C: PixelColor> {
 }
 
 /// Trait for rendering legends to a display target
-pub trait LegendRenderer<C: PixelColor> {
+///
+/// `N` is the entry capacity of the legends the renderer draws.
+pub trait LegendRenderer<C: PixelColor, const N: usize = 8> {
     /// The legend type this renderer can handle
     type Legend: Legend<C>;
 
@@ -87,7 +89,7 @@ pub trait LegendRenderer<C: PixelColor> {
         &self,
         legend: &Self::Legend,
         viewport: Rectangle,
-    ) -> ChartResult<heapless::Vec<Rectangle, 8>>;
+    ) -> ChartResult<heapless::Vec<Rectangle, N>>;
 
     /// Render a single legend entry
     ///
@@ -214,10 +216,10 @@ impl<C: PixelColor> Default for DefaultLegendRenderer<C> {
     }
 }
 
-impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer<C>
-    for DefaultLegendRenderer<C>
+impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>, const N: usize>
+    LegendRenderer<C, N> for DefaultLegendRenderer<C>
 {
-    type Legend = crate::legend::DefaultLegend<C>;
+    type Legend = crate::legend::DefaultLegend<C, N>;
 
     fn render<D>(
         &self,
@@ -252,7 +254,7 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer
             .filter(|e| e.visible)
             .zip(entry_bounds.iter())
         {
-            self.render_entry(entry, *bounds, target)?;
+            <Self as LegendRenderer<C, N>>::render_entry(self, entry, *bounds, target)?;
         }
 
         Ok(())
@@ -262,7 +264,7 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer
         &self,
         legend: &Self::Legend,
         viewport: Rectangle,
-    ) -> ChartResult<heapless::Vec<Rectangle, 8>> {
+    ) -> ChartResult<heapless::Vec<Rectangle, N>> {
         let mut layouts = heapless::Vec::new();
         let visible_entries: Vec<_> = legend.entries.iter().filter(|e| e.visible).collect();
 
@@ -532,10 +534,10 @@ impl<C: PixelColor> Default for StandardLegendRenderer<C> {
     }
 }
 
-impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer<C>
-    for StandardLegendRenderer<C>
+impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>, const N: usize>
+    LegendRenderer<C, N> for StandardLegendRenderer<C>
 {
-    type Legend = crate::legend::types::StandardLegend<C>;
+    type Legend = crate::legend::types::StandardLegend<C, N>;
 
     fn render<D>(
         &self,
@@ -570,7 +572,7 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer
             .filter(|e| e.is_visible())
             .zip(entry_bounds.iter())
         {
-            self.render_entry(entry, *bounds, target)?;
+            <Self as LegendRenderer<C, N>>::render_entry(self, entry, *bounds, target)?;
         }
 
         Ok(())
@@ -580,7 +582,7 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> LegendRenderer
         &self,
         legend: &Self::Legend,
         viewport: Rectangle,
-    ) -> ChartResult<heapless::Vec<Rectangle, 8>> {
+    ) -> ChartResult<heapless::Vec<Rectangle, N>> {
         let mut layouts = heapless::Vec::new();
         let visible_entries: Vec<_> = legend.entries().iter().filter(|e| e.is_visible()).collect();
 
@@ -1,6 +1,7 @@
 //! Legend type implementations.
 
 use crate::error::{ChartError, ChartResult};
+use crate::heapless_utils::{LabelId, LabelLookup};
 use crate::legend::position::LegendPosition;
 use crate::legend::style::{LegendStyle, SymbolStyle};
 use crate::legend::traits::{Legend, LegendEntry};
@@ -115,10 +116,12 @@ pub enum SymbolShape {
 }
 
 /// Standard legend implementation
+///
+/// Holds up to `N` entries, sixteen by default.
 #[derive(Debug, Clone)]
-pub struct StandardLegend<C: PixelColor> {
+pub struct StandardLegend<C: PixelColor, const N: usize = 16> {
     /// Legend entries
-    entries: heapless::Vec<StandardLegendEntry<C>, 16>,
+    entries: heapless::Vec<StandardLegendEntry<C>, N>,
     /// Legend position
     position: LegendPosition,
     /// Legend orientation
@@ -139,10 +142,12 @@ pub struct StandardLegendEntry<C: PixelColor> {
 }
 
 /// Compact legend for space-constrained environments
+///
+/// Holds up to `N` entries, eight by default.
 #[derive(Debug, Clone)]
-pub struct CompactLegend<C: PixelColor> {
-    /// Legend entries (limited capacity)
-    entries: heapless::Vec<CompactLegendEntry<C>, 8>,
+pub struct CompactLegend<C: PixelColor, const N: usize = 8> {
+    /// Legend entries
+    entries: heapless::Vec<CompactLegendEntry<C>, N>,
     /// Legend position
     position: LegendPosition,
     /// Legend orientation
@@ -151,7 +156,7 @@ pub struct CompactLegend<C: PixelColor> {
     style: LegendStyle<C>,
 }
 
-impl<C: PixelColor> CompactLegend<C>
+impl<C: PixelColor, const N: usize> CompactLegend<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
@@ -184,7 +189,7 @@ where
     }
 }
 
-impl<C: PixelColor> crate::legend::traits::Legend<C> for CompactLegend<C>
+impl<C: PixelColor, const N: usize> crate::legend::traits::Legend<C> for CompactLegend<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
@@ -275,10 +280,12 @@ impl<C: PixelColor> CompactLegendEntry<C> {
 }
 
 /// Custom legend for specialized layouts
+///
+/// Holds up to `N` entries, twelve by default.
 #[derive(Debug, Clone)]
-pub struct CustomLegend<C: PixelColor> {
+pub struct CustomLegend<C: PixelColor, const N: usize = 12> {
     /// Legend entries
-    entries: heapless::Vec<CustomLegendEntry<C>, 12>,
+    entries: heapless::Vec<CustomLegendEntry<C>, N>,
     /// Legend position
     position: LegendPosition,
     /// Legend orientation
@@ -289,7 +296,7 @@ pub struct CustomLegend<C: PixelColor> {
     layout_params: CustomLayoutParams,
 }
 
-impl<C: PixelColor> CustomLegend<C>
+impl<C: PixelColor, const N: usize> CustomLegend<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
@@ -328,7 +335,7 @@ where
     }
 }
 
-impl<C: PixelColor> crate::legend::traits::Legend<C> for CustomLegend<C>
+impl<C: PixelColor, const N: usize> crate::legend::traits::Legend<C> for CustomLegend<C, N>
 where
     C: From<embedded_graphics::pixelcolor::Rgb565>,
 {
@@ -446,7 +453,9 @@ pub struct CustomLayoutParams {
 }
 
 // Implementation for StandardLegend
-impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> StandardLegend<C> {
+impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>, const N: usize>
+    StandardLegend<C, N>
+{
     /// Create a new standard legend
     pub fn new(position: LegendPosition) -> Self {
         Self {
@@ -468,7 +477,7 @@ impl<C: PixelColor + From<embedded_graphics::pixelcolor::Rgb565>> StandardLegend
     }
 }
 
-impl<C: PixelColor> Legend<C> for StandardLegend<C> {
+impl<C: PixelColor, const N: usize> Legend<C> for StandardLegend<C, N> {
     type Entry = StandardLegendEntry<C>;
 
     fn entries(&self) -> &[Self::Entry] {
@@ -925,3 +934,143 @@ impl<C: PixelColor> LegendEntry<C> for CustomLegendEntry<C> {
         Ok(())
     }
 }
+
+/// Legend entry whose label is a handle into a shared [`LabelPool`]
+///
+/// The entry stores a [`LabelId`] and a reference to the pool instead of its
+/// own string buffer, so many entries can share label text. Labels must be
+/// interned before the entries are created.
+///
+/// [`LabelPool`]: crate::heapless_utils::LabelPool
+#[derive(Clone)]
+pub struct PooledLegendEntry<'p, C: PixelColor> {
+    /// Pool the label handle refers to
+    pool: &'p dyn LabelLookup,
+    /// Handle of the entry label
+    label: LabelId,
+    /// Entry type
+    entry_type: LegendEntryType<C>,
+    /// Visibility flag
+    visible: bool,
+}
+
+impl<'p, C: PixelColor> PooledLegendEntry<'p, C> {
+    /// Create a new entry from an interned label
+    pub fn new(
+        pool: &'p dyn LabelLookup,
+        label: LabelId,
+        entry_type: LegendEntryType<C>,
+    ) -> ChartResult<Self> {
+        if pool.resolve(label).is_none() {
+            return Err(ChartError::ConfigurationError);
+        }
+        Ok(Self {
+            pool,
+            label,
+            entry_type,
+            visible: true,
+        })
+    }
+
+    /// Get the label handle of this entry
+    pub fn label_id(&self) -> LabelId {
+        self.label
+    }
+}
+
+impl<C: PixelColor + core::fmt::Debug> core::fmt::Debug for PooledLegendEntry<'_, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PooledLegendEntry")
+            .field("label", &self.label)
+            .field("entry_type", &self.entry_type)
+            .field("visible", &self.visible)
+            .finish()
+    }
+}
+
+impl<C: PixelColor> LegendEntry<C> for PooledLegendEntry<'_, C> {
+    fn label(&self) -> &str {
+        self.pool.resolve(self.label).unwrap_or("")
+    }
+
+    /// Switch to another label that is already interned in the pool
+    fn set_label(&mut self, label: &str) -> ChartResult<()> {
+        self.label = self
+            .pool
+            .lookup(label)
+            .ok_or(ChartError::ConfigurationError)?;
+        Ok(())
+    }
+
+    fn entry_type(&self) -> &LegendEntryType<C> {
+        &self.entry_type
+    }
+
+    fn set_entry_type(&mut self, entry_type: LegendEntryType<C>) {
+        self.entry_type = entry_type;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn calculate_size(&self, style: &LegendStyle<C>) -> Size {
+        let text_width = self.label().len() as u32 * style.text.char_width;
+        let total_width = style.spacing.symbol_width + style.spacing.symbol_text_gap + text_width;
+        Size::new(total_width, style.text.line_height)
+    }
+
+    fn render_symbol<D>(
+        &self,
+        bounds: Rectangle,
+        style: &SymbolStyle<C>,
+        target: &mut D,
+    ) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Symbols don't depend on the label, so reuse the standard drawing
+        StandardLegendEntry {
+            label: heapless::String::new(),
+            entry_type: self.entry_type.clone(),
+            visible: self.visible,
+        }
+        .render_symbol(bounds, style, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heapless_utils::LabelPool;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_pooled_entries_share_label_text() {
+        let mut pool: LabelPool<64, 4> = LabelPool::new();
+        let temp = pool.intern("Temperature").unwrap();
+        let humidity = pool.intern("Humidity").unwrap();
+
+        let line = LegendEntryType::Line {
+            color: Rgb565::RED,
+            width: 1,
+            pattern: crate::style::LinePattern::Solid,
+            marker: None,
+        };
+        let mut first = PooledLegendEntry::new(&pool, temp, line.clone()).unwrap();
+        let second = PooledLegendEntry::new(&pool, temp, line).unwrap();
+
+        assert_eq!(first.label(), "Temperature");
+        assert_eq!(second.label(), "Temperature");
+        assert_eq!(pool.bytes_used(), "TemperatureHumidity".len());
+
+        first.set_label("Humidity").unwrap();
+        assert_eq!(first.label_id(), humidity);
+        assert!(first.set_label("Pressure").is_err());
+        assert_eq!(first.label(), "Humidity");
+    }
+}
@@ -326,6 +326,7 @@
 //! - [`grid`] - Grid system for chart backgrounds
 //! - [`legend`] - Legend positioning and styling
 //! - [`animation`] - Real-time animations and transitions (feature-gated)
+//! - [`interaction`] - Button, encoder and touch input controllers (feature-gated)
 //! - [`render`] - Low-level rendering primitives
 //! - [`layout`] - Chart layout and positioning
 //! - [`memory`] - Memory management utilities
@@ -384,6 +385,22 @@ pub mod heapless_utils;
 // Dashboard layout system
 pub mod dashboard;
 
+// Desktop preview helpers
+#[cfg(feature = "dev-tools")]
+pub mod dev_tools;
+
+// Input controllers for interactive charts
+#[cfg(feature = "interaction")]
+pub mod interaction;
+
+// Display bring-up smoke test
+#[cfg(feature = "hil-test")]
+pub mod hil;
+
+// Migration helpers for data from other plotting crates
+#[cfg(feature = "compat")]
+pub mod compat;
+
 // Convenience re-exports
 pub mod prelude;
 
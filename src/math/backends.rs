@@ -96,6 +96,88 @@ impl MathBackend<f32> for FloatingPointBackend {
     }
 }
 
+/// Double-precision backend using `std` for host-side rendering
+#[cfg(feature = "f64-math")]
+pub struct F64Backend;
+
+#[cfg(feature = "f64-math")]
+impl MathBackend<f64> for F64Backend {
+    #[inline]
+    fn sqrt(&self, x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    #[inline]
+    fn abs(&self, x: f64) -> f64 {
+        x.abs()
+    }
+
+    #[inline]
+    fn min(&self, a: f64, b: f64) -> f64 {
+        a.min(b)
+    }
+
+    #[inline]
+    fn max(&self, a: f64, b: f64) -> f64 {
+        a.max(b)
+    }
+
+    #[inline]
+    fn floor(&self, x: f64) -> f64 {
+        x.floor()
+    }
+
+    #[inline]
+    fn ceil(&self, x: f64) -> f64 {
+        x.ceil()
+    }
+
+    #[inline]
+    fn pow(&self, x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+
+    #[inline]
+    fn ln(&self, x: f64) -> f64 {
+        x.ln()
+    }
+
+    #[inline]
+    fn log10(&self, x: f64) -> f64 {
+        x.log10()
+    }
+
+    #[inline]
+    fn sin(&self, x: f64) -> f64 {
+        x.sin()
+    }
+
+    #[inline]
+    fn cos(&self, x: f64) -> f64 {
+        x.cos()
+    }
+
+    #[inline]
+    fn tan(&self, x: f64) -> f64 {
+        x.tan()
+    }
+
+    #[inline]
+    fn to_radians(&self, degrees: f64) -> f64 {
+        degrees.to_radians()
+    }
+
+    #[inline]
+    fn to_degrees(&self, radians: f64) -> f64 {
+        radians.to_degrees()
+    }
+
+    #[inline]
+    fn atan2(&self, y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+}
+
 /// Libm backend for floating-point operations
 #[cfg(feature = "libm-math")]
 pub struct LibmBackend;
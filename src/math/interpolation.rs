@@ -8,6 +8,16 @@
 //!
 //! All algorithms are designed to work with the no_std environment and use
 //! static allocation for memory efficiency.
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
 
 use crate::data::Point2D;
 use crate::error::{ChartError, ChartResult};
@@ -29,6 +39,38 @@ pub enum InterpolationType {
     Bezier,
 }
 
+/// How Catmull-Rom splines treat the first and last segment.
+///
+/// A Catmull-Rom segment needs a neighbour on each side to compute its
+/// tangents. At the ends of the data a phantom neighbour is made up; the mode
+/// decides how.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EndpointMode {
+    /// Repeat the endpoint as its own neighbour.
+    ///
+    /// Halves the end tangent, which can make the first and last segment sag
+    /// or undershoot.
+    #[default]
+    Duplicate,
+    /// Extrapolate the neighbour along the end segment, so the curve leaves
+    /// the endpoint heading straight at the next point
+    Natural,
+    /// Use a horizontal end tangent, so the curve never overshoots the
+    /// endpoint value
+    Clamped,
+}
+
+impl EndpointMode {
+    /// Phantom neighbour of `end`, on the side away from `inner`
+    fn phantom(self, end: Point2D, inner: Point2D) -> Point2D {
+        match self {
+            Self::Duplicate => end,
+            Self::Natural => Point2D::new(2.0 * end.x - inner.x, 2.0 * end.y - inner.y),
+            Self::Clamped => Point2D::new(2.0 * end.x - inner.x, inner.y),
+        }
+    }
+}
+
 /// Configuration for curve interpolation
 #[derive(Debug, Clone)]
 pub struct InterpolationConfig {
@@ -40,6 +82,8 @@ pub struct InterpolationConfig {
     pub tension: f32,
     /// Whether to create a closed curve (connect last point to first)
     pub closed: bool,
+    /// Endpoint handling for Catmull-Rom splines
+    pub endpoints: EndpointMode,
 }
 
 impl Default for InterpolationConfig {
@@ -49,6 +93,7 @@ impl Default for InterpolationConfig {
             subdivisions: 8,
             tension: 0.5,
             closed: false,
+            endpoints: EndpointMode::Duplicate,
         }
     }
 }
@@ -69,6 +114,17 @@ impl CurveInterpolator {
         points: &[Point2D],
         config: &InterpolationConfig,
     ) -> ChartResult<Vec<Point2D, MAX_INTERPOLATED_POINTS>> {
+        Self::interpolate_with_capacity(points, config)
+    }
+
+    /// Interpolate a series of 2D points into a buffer of `M` points
+    ///
+    /// Returns [`ChartError::MemoryFull`] if the curve has more than `M` points;
+    /// a curve through `n` points has `(n - 1) * subdivisions + 1` points.
+    pub fn interpolate_with_capacity<const M: usize>(
+        points: &[Point2D],
+        config: &InterpolationConfig,
+    ) -> ChartResult<Vec<Point2D, M>> {
         if points.len() < 2 {
             return Err(ChartError::InsufficientData);
         }
@@ -82,16 +138,13 @@ impl CurveInterpolator {
     }
 
     /// Linear interpolation - simply subdivides straight lines
-    fn linear_interpolation(
+    fn linear_interpolation<const M: usize>(
         points: &[Point2D],
         config: &InterpolationConfig,
-    ) -> ChartResult<Vec<Point2D, MAX_INTERPOLATED_POINTS>> {
+    ) -> ChartResult<Vec<Point2D, M>> {
         let mut result = Vec::new();
 
-        for i in 0..points.len() - 1 {
-            let p0 = points[i];
-            let p1 = points[i + 1];
-
+        for (&p0, &p1) in points.iter().zip(points.iter().skip(1)) {
             // Add the starting point
             result.push(p0).map_err(|_| ChartError::MemoryFull)?;
 
@@ -115,10 +168,10 @@ impl CurveInterpolator {
     }
 
     /// Cubic spline interpolation for smooth curves
-    fn cubic_spline_interpolation(
+    fn cubic_spline_interpolation<const M: usize>(
         points: &[Point2D],
         config: &InterpolationConfig,
-    ) -> ChartResult<Vec<Point2D, MAX_INTERPOLATED_POINTS>> {
+    ) -> ChartResult<Vec<Point2D, M>> {
         let mut result = Vec::new();
         let n = points.len();
 
@@ -127,27 +180,31 @@ impl CurveInterpolator {
         }
 
         // Calculate derivatives for natural cubic spline
-        let mut derivatives = Vec::<f32, 256>::new();
+        let mut derivatives = Vec::<f32, M>::new();
         for _i in 0..n {
             derivatives.push(0.0).map_err(|_| ChartError::MemoryFull)?;
         }
 
         // Calculate second derivatives using simplified approach
-        for i in 1..n - 1 {
-            let h1 = points[i].x - points[i - 1].x;
-            let h2 = points[i + 1].x - points[i].x;
-            let delta1 = (points[i].y - points[i - 1].y) / h1;
-            let delta2 = (points[i + 1].y - points[i].y) / h2;
-            derivatives[i] = 2.0 * (delta2 - delta1) / (h1 + h2);
+        for (window, derivative) in points.windows(3).zip(derivatives.iter_mut().skip(1)) {
+            let [prev, curr, next] = *window else {
+                continue;
+            };
+            let h1 = curr.x - prev.x;
+            let h2 = next.x - curr.x;
+            // Vertical segments and reversals have no defined curvature
+            if h1 == 0.0 || h2 == 0.0 || h1 + h2 == 0.0 {
+                continue;
+            }
+            let delta1 = (curr.y - prev.y) / h1;
+            let delta2 = (next.y - curr.y) / h2;
+            *derivative = 2.0 * (delta2 - delta1) / (h1 + h2);
         }
 
         // Generate interpolated points
-        for i in 0..n - 1 {
-            let p0 = points[i];
-            let p1 = points[i + 1];
-            let d0 = derivatives[i];
-            let d1 = derivatives[i + 1];
-
+        let segments = points.iter().zip(points.iter().skip(1));
+        let slopes = derivatives.iter().zip(derivatives.iter().skip(1));
+        for ((&p0, &p1), (&d0, &d1)) in segments.zip(slopes) {
             result.push(p0).map_err(|_| ChartError::MemoryFull)?;
 
             let h = p1.x - p0.x;
@@ -179,10 +236,10 @@ impl CurveInterpolator {
     }
 
     /// Catmull-Rom spline interpolation
-    fn catmull_rom_interpolation(
+    fn catmull_rom_interpolation<const M: usize>(
         points: &[Point2D],
         config: &InterpolationConfig,
-    ) -> ChartResult<Vec<Point2D, MAX_INTERPOLATED_POINTS>> {
+    ) -> ChartResult<Vec<Point2D, M>> {
         let mut result = Vec::new();
         let n = points.len();
 
@@ -190,17 +247,22 @@ impl CurveInterpolator {
             return Self::linear_interpolation(points, config);
         }
 
+        let (&[first, second, ..], &[.., before_last, last]) = (points, points) else {
+            return Self::linear_interpolation(points, config);
+        };
+
         // Process each segment
-        for i in 0..n - 1 {
-            // Get control points (with boundary handling)
-            let p0 = if i == 0 { points[0] } else { points[i - 1] };
-            let p1 = points[i];
-            let p2 = points[i + 1];
-            let p3 = if i + 2 < n {
-                points[i + 2]
-            } else {
-                points[n - 1]
-            };
+        for (i, (&p1, &p2)) in points.iter().zip(points.iter().skip(1)).enumerate() {
+            // Get control points, making up phantom neighbours at the ends
+            let p0 = i
+                .checked_sub(1)
+                .and_then(|prev| points.get(prev))
+                .copied()
+                .unwrap_or_else(|| config.endpoints.phantom(first, second));
+            let p3 = points
+                .get(i + 2)
+                .copied()
+                .unwrap_or_else(|| config.endpoints.phantom(last, before_last));
 
             result.push(p1).map_err(|_| ChartError::MemoryFull)?;
 
@@ -237,10 +299,10 @@ impl CurveInterpolator {
     }
 
     /// Bezier curve interpolation
-    fn bezier_interpolation(
+    fn bezier_interpolation<const M: usize>(
         points: &[Point2D],
         config: &InterpolationConfig,
-    ) -> ChartResult<Vec<Point2D, MAX_INTERPOLATED_POINTS>> {
+    ) -> ChartResult<Vec<Point2D, M>> {
         let mut result = Vec::new();
         let n = points.len();
 
@@ -249,10 +311,7 @@ impl CurveInterpolator {
         }
 
         // Generate control points for quadratic Bezier curves
-        for i in 0..n - 1 {
-            let p0 = points[i];
-            let p2 = points[i + 1];
-
+        for (&p0, &p2) in points.iter().zip(points.iter().skip(1)) {
             // Simple control point calculation
             let mid_x = (p0.x + p2.x) * 0.5;
             let mid_y = (p0.y + p2.y) * 0.5;
@@ -294,18 +353,15 @@ impl CurveInterpolator {
         index: usize,
         smoothing_factor: f32,
     ) -> ChartResult<Point2D> {
-        if index >= points.len() {
-            return Err(ChartError::InvalidRange);
-        }
-
-        let n = points.len();
-        if n < 3 || index == 0 || index == n - 1 {
-            return Ok(points[index]);
-        }
-
-        let prev = points[index - 1];
-        let curr = points[index];
-        let next = points[index + 1];
+        let curr = *points.get(index).ok_or(ChartError::InvalidRange)?;
+
+        let (Some(&prev), Some(&next)) = (
+            index.checked_sub(1).and_then(|prev| points.get(prev)),
+            points.get(index + 1),
+        ) else {
+            // End points are kept in place
+            return Ok(curr);
+        };
 
         // Apply smoothing using weighted average
         let factor = smoothing_factor.clamp(0.0, 1.0);
@@ -380,6 +436,48 @@ mod tests {
         assert!(result.len() > points.len());
     }
 
+    #[test]
+    fn test_catmull_rom_endpoint_modes() {
+        // Points on a straight line must stay on it with natural endpoints
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(2.0, 4.0),
+            Point2D::new(3.0, 6.0),
+        ];
+        let config = InterpolationConfig {
+            interpolation_type: InterpolationType::CatmullRom,
+            subdivisions: 4,
+            endpoints: EndpointMode::Natural,
+            ..Default::default()
+        };
+        let result = CurveInterpolator::interpolate(&points, &config).unwrap();
+        assert!(result.iter().all(|p| (p.y - 2.0 * p.x).abs() < 1e-4));
+
+        // Duplicated endpoints bunch the first segment up against the endpoint
+        let config = InterpolationConfig {
+            endpoints: EndpointMode::Duplicate,
+            ..config
+        };
+        let result = CurveInterpolator::interpolate(&points, &config).unwrap();
+        assert!(result[1].x < 0.25);
+
+        // Clamped ends never leave the range of the end segment
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 10.0),
+            Point2D::new(2.0, 0.0),
+        ];
+        let config = InterpolationConfig {
+            interpolation_type: InterpolationType::CatmullRom,
+            subdivisions: 8,
+            endpoints: EndpointMode::Clamped,
+            ..Default::default()
+        };
+        let result = CurveInterpolator::interpolate(&points, &config).unwrap();
+        assert!(result.iter().all(|p| p.y >= 0.0 && p.y <= 10.0));
+    }
+
     #[test]
     fn test_point_smoothing() {
         let mut points = heapless::Vec::<Point2D, 16>::new();
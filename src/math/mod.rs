@@ -8,6 +8,7 @@
 //! - `fixed-point`: Fixed-point arithmetic using the fixed crate
 //! - `integer-math`: Integer-only math for the most constrained environments
 //! - `cordic-math`: CORDIC-based trigonometric functions
+//! - `f64-math`: Double-precision math for desktop hosts, alongside the selected backend
 
 pub mod backends;
 pub mod interpolation;
@@ -19,6 +20,8 @@ pub use traits::{FloatLike, MathOps, TrigOps};
 // Re-export backend implementations
 pub use backends::*;
 
+use crate::error::{ChartError, ChartResult};
+
 /// Primary numeric type used throughout the library
 #[cfg(feature = "floating-point")]
 pub type Number = f32;
@@ -42,9 +45,24 @@ pub type Number = i32;
 /// Default numeric type for mathematical operations
 pub type Number = f32;
 
+/// High-precision numeric type used by the `f64-math` backend
+#[cfg(feature = "f64-math")]
+pub type PreciseNumber = f64;
+
 /// Math operations provider - selects the appropriate backend based on features
 pub struct Math;
 
+#[cfg(feature = "f64-math")]
+impl Math {
+    /// Get the double-precision backend for host-side rendering and export.
+    ///
+    /// This is independent of [`Math::backend`], so embedded code paths keep
+    /// their `f32`/fixed-point [`Number`] while hosts can opt into `f64`.
+    pub fn precise() -> backends::F64Backend {
+        backends::F64Backend
+    }
+}
+
 impl Math {
     /// Get the appropriate math backend for the current feature configuration
     #[cfg(feature = "floating-point")]
@@ -213,6 +231,288 @@ impl Math {
     }
 }
 
+/// Largest magnitude an `f32` can have and still convert to [`Number`] unchanged
+#[cfg(all(feature = "fixed-point", not(feature = "floating-point")))]
+pub const NUMBER_LIMIT: f32 = 32767.0;
+
+/// Largest magnitude an `f32` can have and still convert to [`Number`] unchanged
+#[cfg(all(
+    feature = "integer-math",
+    not(any(feature = "floating-point", feature = "fixed-point"))
+))]
+pub const NUMBER_LIMIT: f32 = 2_147_483.0;
+
+/// Largest magnitude an `f32` can have and still convert to [`Number`] unchanged
+#[cfg(any(
+    feature = "floating-point",
+    not(any(feature = "fixed-point", feature = "integer-math"))
+))]
+pub const NUMBER_LIMIT: f32 = f32::MAX;
+
+/// Convert a value to [`Number`], failing if the active backend cannot hold it.
+///
+/// Returns [`ChartError::InvalidRange`] for NaN, infinities and values beyond
+/// [`NUMBER_LIMIT`] instead of letting the conversion saturate or wrap.
+pub fn checked_number(value: f32) -> ChartResult<Number> {
+    if (-NUMBER_LIMIT..=NUMBER_LIMIT).contains(&value) {
+        Ok(value.to_number())
+    } else {
+        Err(ChartError::InvalidRange)
+    }
+}
+
+/// Split a positive value into a mantissa in `[1, 10)` and its power of ten.
+///
+/// The work is done in `f32`, so magnitudes that would overflow [`Number`]
+/// under fixed-point or integer backends are still handled exactly.
+pub fn split_power_of_ten(value: f32) -> (f32, i32) {
+    if value <= 0.0 || !value.is_finite() {
+        return (value, 0);
+    }
+
+    let mut mantissa = value;
+    let mut exponent = 0;
+    while mantissa >= 10.0 {
+        mantissa /= 10.0;
+        exponent += 1;
+    }
+    while mantissa < 1.0 {
+        mantissa *= 10.0;
+        exponent -= 1;
+    }
+    (mantissa, exponent)
+}
+
+/// Compute `10^exponent` in `f32` without going through [`Number`]
+pub fn power_of_ten(exponent: i32) -> f32 {
+    let mut result = 1.0f32;
+    if exponent >= 0 {
+        for _ in 0..exponent {
+            result *= 10.0;
+        }
+    } else {
+        for _ in 0..-exponent {
+            result /= 10.0;
+        }
+    }
+    result
+}
+
+/// Maps data values in a range onto `0.0..=1.0` with backend-safe precision.
+///
+/// The offset and scale are applied in `f32` before converting to [`Number`],
+/// so data such as `0..100000` normalizes correctly even when the fixed-point
+/// backend can only represent magnitudes up to [`NUMBER_LIMIT`].
+///
+/// Values are halved before the offset is taken, which is exact in `f32` and
+/// keeps the span finite for ranges as wide as `f32::MIN..=f32::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeTransform {
+    half_min: f32,
+    half_span: f32,
+}
+
+impl RangeTransform {
+    /// Normalized values are clamped to this magnitude so points far outside
+    /// the range still convert to [`Number`] without saturating.
+    const MAX_NORMALIZED: f32 = 1000.0;
+
+    /// Create a transform for the range `min..=max`
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            half_min: min * 0.5,
+            half_span: max * 0.5 - min * 0.5,
+        }
+    }
+
+    /// Create a transform, rejecting bounds that cannot produce a usable plot
+    pub fn checked(min: f32, max: f32) -> ChartResult<Self> {
+        let transform = Self::new(min, max);
+        if min.is_finite() && max.is_finite() && transform.half_span.is_finite() {
+            Ok(transform)
+        } else {
+            Err(ChartError::InvalidRange)
+        }
+    }
+
+    /// Normalize `value` to the range, where `min` maps to 0 and `max` to 1.
+    ///
+    /// An empty range maps every value to the midpoint.
+    pub fn normalize(&self, value: f32) -> Number {
+        self.normalize_f32(value).to_number()
+    }
+
+    /// Same as [`normalize`](Self::normalize) but stays in `f32`
+    pub fn normalize_f32(&self, value: f32) -> f32 {
+        if self.half_span <= 0.0 || self.half_span.is_nan() {
+            return 0.5;
+        }
+        let normalized = (value * 0.5 - self.half_min) / self.half_span;
+        if normalized.is_nan() {
+            0.5
+        } else {
+            normalized.clamp(-Self::MAX_NORMALIZED, Self::MAX_NORMALIZED)
+        }
+    }
+}
+
+/// Base-10 logarithm of a positive value in `f32`, without a math backend
+///
+/// Splits off the binary exponent and evaluates the logarithm of the mantissa
+/// with a short series, which is accurate to about `1e-6`. Values that are not
+/// positive are treated as the smallest positive `f32`.
+pub fn log10_f32(value: f32) -> f32 {
+    let value = if value >= f32::MIN_POSITIVE {
+        value
+    } else {
+        f32::MIN_POSITIVE
+    };
+    if value.is_infinite() {
+        return f32::MAX_10_EXP as f32;
+    }
+
+    let bits = value.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    // Mantissa in [1, 2)
+    let mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), with |t| <= 1/3
+    let t = (mantissa - 1.0) / (mantissa + 1.0);
+    let t2 = t * t;
+    let ln_mantissa =
+        2.0 * t * (1.0 + t2 * (1.0 / 3.0 + t2 * (1.0 / 5.0 + t2 * (1.0 / 7.0 + t2 / 9.0))));
+
+    (exponent as f32 * core::f32::consts::LN_2 + ln_mantissa) * core::f32::consts::LOG10_E
+}
+
+/// Compute `10^exponent` for a fractional exponent in `f32`
+///
+/// Inverse of [`log10_f32`]; results beyond the `f32` range saturate to zero
+/// or infinity.
+pub fn pow10_f32(exponent: f32) -> f32 {
+    let power_of_two = exponent * core::f32::consts::LOG2_10;
+    if power_of_two >= 128.0 {
+        return f32::INFINITY;
+    }
+    if power_of_two < -126.0 {
+        return 0.0;
+    }
+
+    // Split into an integer power of two and a fraction in [0, 1)
+    let mut whole = power_of_two as i32;
+    if (whole as f32) > power_of_two {
+        whole -= 1;
+    }
+    let y = (power_of_two - whole as f32) * core::f32::consts::LN_2;
+    let fraction = 1.0
+        + y * (1.0
+            + y / 2.0 * (1.0 + y / 3.0 * (1.0 + y / 4.0 * (1.0 + y / 5.0 * (1.0 + y / 6.0)))));
+
+    f32::from_bits(((whole + 127) as u32) << 23) * fraction
+}
+
+/// Maps positive values onto `0.0..=1.0` on a base-10 logarithmic scale.
+///
+/// Counterpart of [`RangeTransform`] for logarithmic axes; equal ratios of
+/// values map to equal distances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogRangeTransform {
+    log_min: f32,
+    span: f32,
+}
+
+impl LogRangeTransform {
+    /// Create a transform for the range `min..=max`
+    pub fn new(min: f32, max: f32) -> Self {
+        let log_min = log10_f32(min);
+        Self {
+            log_min,
+            span: log10_f32(max) - log_min,
+        }
+    }
+
+    /// Create a transform, rejecting bounds that are not positive and finite
+    pub fn checked(min: f32, max: f32) -> ChartResult<Self> {
+        if min > 0.0 && max > 0.0 && min.is_finite() && max.is_finite() {
+            Ok(Self::new(min, max))
+        } else {
+            Err(ChartError::InvalidRange)
+        }
+    }
+
+    /// Normalize `value` to the range, where `min` maps to 0 and `max` to 1.
+    ///
+    /// Values that are not positive map below the range; an empty range maps
+    /// every value to the midpoint.
+    pub fn normalize_f32(&self, value: f32) -> f32 {
+        if self.span <= 0.0 || self.span.is_nan() || value.is_nan() {
+            return 0.5;
+        }
+        let normalized = (log10_f32(value) - self.log_min) / self.span;
+        normalized.clamp(
+            -RangeTransform::MAX_NORMALIZED,
+            RangeTransform::MAX_NORMALIZED,
+        )
+    }
+
+    /// Value at the normalized position `normalized`
+    pub fn denormalize(&self, normalized: f32) -> f32 {
+        pow10_f32(self.log_min + normalized * self.span)
+    }
+}
+
+/// Double-precision counterpart of [`RangeTransform`] for host-side rendering
+///
+/// Exported images and simulator output can place points at sub-pixel
+/// precision over wide data ranges, where `f32` rounding becomes visible.
+#[cfg(feature = "f64-math")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreciseRangeTransform {
+    min: f64,
+    span: f64,
+}
+
+#[cfg(feature = "f64-math")]
+impl PreciseRangeTransform {
+    /// Create a transform for the range `min..=max`
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            span: max - min,
+        }
+    }
+
+    /// Create a transform, rejecting bounds that cannot produce a usable plot
+    pub fn checked(min: f64, max: f64) -> ChartResult<Self> {
+        let transform = Self::new(min, max);
+        if min.is_finite() && max.is_finite() && transform.span.is_finite() {
+            Ok(transform)
+        } else {
+            Err(ChartError::InvalidRange)
+        }
+    }
+
+    /// Normalize `value` to the range, where `min` maps to 0 and `max` to 1.
+    ///
+    /// An empty range maps every value to the midpoint.
+    pub fn normalize(&self, value: f64) -> PreciseNumber {
+        if self.span <= 0.0 || self.span.is_nan() {
+            return 0.5;
+        }
+        let normalized = (value - self.min) / self.span;
+        if normalized.is_nan() {
+            0.5
+        } else {
+            normalized
+        }
+    }
+
+    /// Map `value` onto a screen span starting at `start` with length `extent`
+    pub fn to_screen(&self, value: f64, start: f64, extent: f64) -> PreciseNumber {
+        start + self.normalize(value) * extent
+    }
+}
+
 /// Type conversion utilities for different numeric types
 pub trait NumericConversion<T> {
     /// Convert from the source type to Number
@@ -228,8 +528,13 @@ impl NumericConversion<f32> for f32 {
         #[cfg(feature = "floating-point")]
         return self;
 
+        // Saturate instead of panicking on values outside the I16F16 range
         #[cfg(all(feature = "fixed-point", not(feature = "floating-point")))]
-        return fixed::types::I16F16::from_num(self);
+        return if self.is_nan() {
+            fixed::types::I16F16::ZERO
+        } else {
+            fixed::types::I16F16::saturating_from_num(self)
+        };
 
         #[cfg(all(
             feature = "integer-math",
@@ -318,6 +623,22 @@ impl NumericConversion<i32> for i32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_log_range_transform() {
+        for value in [1e-6f32, 0.02, 1.0, 3.0, 1e5] {
+            let log = log10_f32(value);
+            assert!((pow10_f32(log) - value).abs() <= value * 1e-4);
+        }
+        assert!((log10_f32(1000.0) - 3.0).abs() < 1e-5);
+
+        // 1 uA to 100 mA spans five decades
+        let transform = LogRangeTransform::checked(1e-6, 0.1).unwrap();
+        assert!(transform.normalize_f32(1e-6).abs() < 1e-4);
+        assert!((transform.normalize_f32(1e-3) - 0.6).abs() < 1e-4);
+        assert!((transform.denormalize(1.0) - 0.1).abs() < 1e-5);
+        assert!(LogRangeTransform::checked(0.0, 1.0).is_err());
+    }
+
     #[test]
     #[cfg(not(feature = "integer-math"))] // Skip for integer-math to avoid precision issues
     fn test_basic_math_operations() {
@@ -355,4 +676,64 @@ mod tests {
         // Should be approximately equal (allowing for precision loss in integer modes)
         assert!((original - back).abs() < 0.1);
     }
+
+    #[test]
+    fn test_range_transform_large_magnitudes() {
+        let transform = RangeTransform::checked(0.0, 100_000.0).unwrap();
+
+        let quarter = f32::from_number(transform.normalize(25_000.0));
+        let top = f32::from_number(transform.normalize(100_000.0));
+        assert!((quarter - 0.25).abs() < 0.01);
+        assert!((top - 1.0).abs() < 0.01);
+
+        // Far outside the range is clamped rather than overflowing
+        let far = f32::from_number(transform.normalize(1.0e12));
+        assert!(far > 1.0);
+    }
+
+    #[test]
+    fn test_range_transform_small_magnitudes() {
+        let transform = RangeTransform::new(0.0, 0.000_001);
+        let half = f32::from_number(transform.normalize(0.000_000_5));
+        assert!((half - 0.5).abs() < 0.01);
+
+        let flat = RangeTransform::new(3.0, 3.0);
+        assert_eq!(flat.normalize_f32(3.0), 0.5);
+
+        assert!(RangeTransform::checked(0.0, f32::INFINITY).is_err());
+        assert!(RangeTransform::checked(f32::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_checked_number_limits() {
+        assert!(checked_number(100.0).is_ok());
+        assert_eq!(checked_number(f32::NAN), Err(ChartError::InvalidRange));
+        assert_eq!(checked_number(f32::INFINITY), Err(ChartError::InvalidRange));
+
+        #[cfg(all(feature = "fixed-point", not(feature = "floating-point")))]
+        assert_eq!(checked_number(100_000.0), Err(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_power_of_ten_split() {
+        assert_eq!(split_power_of_ten(25_000.0).1, 4);
+        assert_eq!(split_power_of_ten(0.003).1, -3);
+        assert_eq!(power_of_ten(5), 100_000.0);
+        assert!((power_of_ten(-2) - 0.01).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "f64-math")]
+    fn test_precise_range_transform() {
+        use crate::math::traits::MathBackend;
+
+        // Offsets this small relative to the range vanish in f32
+        let transform = PreciseRangeTransform::new(100_000_000.0, 100_000_001.0);
+        let x = transform.to_screen(100_000_000.25, 10.0, 400.0);
+        assert!((x - 110.0).abs() < 1e-6);
+        assert_eq!(PreciseRangeTransform::new(5.0, 5.0).normalize(7.0), 0.5);
+        assert!(PreciseRangeTransform::checked(0.0, f64::INFINITY).is_err());
+
+        assert!((Math::precise().sqrt(2.0) - core::f64::consts::SQRT_2).abs() < 1e-15);
+    }
 }
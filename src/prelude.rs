@@ -50,7 +50,9 @@
 //!
 //! ## Utility Macros
 //! - [`data_points!`] - Create data series from tuples
+//! - [`data_values!`] - Create data series from plain values
 //! - [`chart_config!`] - Fluent chart configuration syntax
+//! - [`chart_context!`] - Concrete type aliases for a single color type
 //!
 //! # Quick Start Examples
 //!
@@ -125,9 +127,12 @@ pub use crate::math::{Math, Number, NumericConversion};
 
 // Core traits
 pub use crate::chart::traits::{
-    Chart, ChartBuilder, ChartConfig, IncrementalChart, Margins, StylableChart,
+    BackgroundMode, Chart, ChartBuilder, ChartConfig, ConfiguredChart, DataChange,
+    IncrementalChart, Margins, PlaceholderKind, PlaceholderStyle, StylableChart, TitleStyle,
 };
 
+pub use crate::chart::{StaticChart, StaticChartBuilder};
+
 #[cfg(feature = "animations")]
 pub use crate::chart::traits::{AnimatedChart, StreamingChart};
 
@@ -144,15 +149,16 @@ pub use crate::legend::{
 
 pub use crate::legend::types::{
     CompactLegendEntry, CustomLayoutParams, CustomLegendEntry, MarkerShape as LegendMarkerShape,
-    MarkerStyle as LegendMarkerStyle, StandardLegendEntry, SymbolShape,
+    MarkerStyle as LegendMarkerStyle, PooledLegendEntry, StandardLegendEntry, SymbolShape,
 };
 
 pub use crate::legend::position::LegendPosition as LegendPos;
 
 // Axes types
 pub use crate::axes::{
-    AxisConfig, AxisOrientation, AxisPosition, AxisStyle, AxisValue, CustomAxisBuilder,
-    CustomTickGenerator, LinearAxis, LinearAxisBuilder, LinearTickGenerator, TickStyle,
+    AxisConfig, AxisOrientation, AxisPosition, AxisStyle, AxisValue, CategoryAxis, CategoryScale,
+    CustomAxisBuilder, CustomTickGenerator, ExplicitTicks, LinearAxis, LinearAxisBuilder,
+    LinearTickGenerator, TickPlacement, TickStyle, TimeAxis, TimeTickGenerator, TimestampUnit,
 };
 
 pub use crate::axes::builder::presets;
@@ -180,13 +186,37 @@ pub use crate::grid::traits::TickAlignedGrid;
 
 // Chart types
 #[cfg(feature = "line")]
-pub use crate::chart::{LineChart, LineChartBuilder, LineChartStyle, MarkerShape, MarkerStyle};
+pub use crate::chart::{LineChart, LineChartBuilder, LineChartStyle, MarkerStyle};
+
+#[cfg(feature = "line")]
+pub use crate::chart::{
+    Annotation, AnnotationAxis, AnnotationOverlay, ReferenceBand, ReferenceLine,
+};
+
+#[cfg(feature = "line")]
+pub use crate::chart::{ChartEvent, EventGlyph, EventOverlay, EventStyle};
+
+#[cfg(feature = "line")]
+pub use crate::chart::{ForecastOverlay, ForecastStyle, TransformOverlay};
+
+#[cfg(feature = "line")]
+pub use crate::chart::{SigmaBand, StatsOverlay};
+
+#[cfg(any(feature = "line", feature = "scatter"))]
+pub use crate::chart::{
+    CustomMarker, MarkerCanvas, MarkerFn, MarkerRenderer, MarkerResult, MarkerShape,
+};
 
 #[cfg(feature = "line")]
 pub use crate::chart::{CurveChart, CurveChartBuilder};
 
 #[cfg(feature = "line")]
-pub use crate::math::interpolation::{InterpolationConfig, InterpolationType};
+pub use crate::chart::{
+    ControlChart, ControlChartBuilder, ControlChartStyle, ControlLimits, ControlRule,
+};
+
+#[cfg(feature = "line")]
+pub use crate::math::interpolation::{EndpointMode, InterpolationConfig, InterpolationType};
 
 #[cfg(all(feature = "line", feature = "animations"))]
 pub use crate::chart::{AnimatedLineChart, AnimatedLineChartBuilder};
@@ -198,33 +228,56 @@ pub use crate::chart::{BarChart, BarChartBuilder, BarChartStyle, BarOrientation}
 pub use crate::chart::{AnimatedBarChart, AnimatedBarChartBuilder};
 
 #[cfg(feature = "bar")]
-pub use crate::chart::bar::BarWidth;
+pub use crate::chart::bar::{BarPlacement, BarValueLabels, BarWidth};
+
+#[cfg(feature = "bar")]
+pub use crate::chart::{ParetoChart, ParetoChartBuilder};
+
+#[cfg(feature = "bar")]
+pub use crate::chart::{DivergentBarChart, DivergentBarChartBuilder, DivergentData};
 
 #[cfg(feature = "pie")]
 pub use crate::chart::{PieChart, PieChartBuilder, PieChartStyle};
 
+#[cfg(any(
+    feature = "line",
+    feature = "bar",
+    feature = "pie",
+    feature = "scatter",
+    feature = "gauge"
+))]
+pub use crate::chart::{AnyChart, ChartKind};
+
 #[cfg(feature = "scatter")]
 pub use crate::chart::{
-    CollisionSettings, CollisionStrategy, ColorMapping, ColorMappingStrategy, PointShape,
-    PointStyle, ScatterChart, ScatterChartBuilder, ScatterChartStyle, SizeMapping, SizeScaling,
+    CollisionSettings, CollisionStrategy, ColorMapping, ColorMappingStrategy, JitterSettings,
+    PointShape, PointStyle, RegressionFit, RegressionOrder, RegressionOverlay, ScatterChart,
+    ScatterChartBuilder, ScatterChartStyle, SizeMapping, SizeScaling,
 };
 
 #[cfg(feature = "gauge")]
 pub use crate::chart::{
     ArcStyle, CenterStyle, GaugeChart, GaugeChartBuilder, GaugeChartStyle, GaugeType, NeedleShape,
-    NeedleStyle, ThresholdZone, TickStyle as GaugeTickStyle, ValueDisplayStyle, ValueRange,
+    NeedleStyle, ScaleLabelStyle, SegmentState, SegmentStyle, ThresholdZone,
+    TickStyle as GaugeTickStyle, ValueDisplayPosition, ValueDisplayStyle, ValueFormat, ValueRange,
 };
 
+#[cfg(feature = "gauge")]
+pub use crate::chart::{GaugeCluster, GaugeClusterLayout, GaugeClusterStyle};
+
 #[cfg(feature = "stacked-charts")]
 pub use crate::chart::stacked::{
     AnimatedStackedBarChart, AnimatedStackedBarChartBuilder, AnimatedStackedLineChart,
-    AnimatedStackedLineChartBuilder, StackedBarWidth, StackedData,
+    AnimatedStackedLineChartBuilder, StackedBarLabels, StackedBarLayout, StackedBarWidth,
+    StackedData,
 };
 
 // Data types
 pub use crate::data::{
-    calculate_bounds, calculate_multi_series_bounds, DataBounds, DataPoint, DataSeries,
-    FloatBounds, IntBounds, IntPoint, MultiSeries, Point2D, StaticDataSeries, TimestampedPoint,
+    calculate_bounds, calculate_multi_series_bounds, ConstDataSeries, DataBounds, DataPoint,
+    DataSeries, FloatBounds, IntBounds, IntPoint, LinearTrend, MultiSeries, Point2D,
+    QuadraticTrend, SeriesAxis, SeriesStatistics, SeriesStats, SeriesSummary, StaticDataSeries,
+    TimestampedPoint, TransformSeries,
 };
 
 #[cfg(feature = "animations")]
@@ -241,28 +294,41 @@ pub use crate::data::streaming::{
 
 // Style types
 pub use crate::style::{
-    BorderStyle, ColorInterpolation, ColorPalette, ColorUtils, FillPattern, FillStyle, LineCap,
-    LineJoin, LinePattern, LineStyle, StrokeStyle,
+    BorderStyle, ColorInterpolation, ColorPalette, ColorScale, ColorScaling, ColorUtils,
+    FillPattern, FillStyle, LineCap, LineJoin, LinePattern, LineStyle, MonochromeStyle,
+    NumberLocale, SeriesPalette, StrokeStyle,
+};
+
+// Value formatting types
+pub use crate::style::format::{
+    DurationFormat, FixedDecimals, FormattedValue, LabelFormat, Percent, SiPrefix, ValueFormatter,
 };
 
 // Theme types
+pub use crate::style::schedule::{DayPhase, ThemeSchedule};
 pub use crate::style::themes::Theme;
 
 #[cfg(feature = "color-support")]
 pub use crate::style::rgb565_palettes;
 
 // Layout types
-pub use crate::layout::{ChartLayout, ComponentPositioning, Viewport};
+pub use crate::layout::{
+    ChartLayout, ComponentPositioning, LayoutOverlap, LayoutRegion, LayoutSolver, Viewport,
+};
 
 // Rendering types
 pub use crate::render::{
-    ChartRenderer, ClippingRenderer, EnhancedChartRenderer, PrimitiveRenderer,
+    ChartDrawTarget, ChartRenderer, ClippingRenderer, DetailLevel, DetailPlan, DetailPolicy,
+    EnhancedChartRenderer, FallbackTarget, PrimitiveRenderer, QualityProfile,
 };
 
 #[cfg(feature = "animations")]
 pub use crate::render::AnimationFrameRenderer;
 
-pub use crate::render::text::TextRenderer;
+#[cfg(feature = "std")]
+pub use crate::render::{TerminalDisplay, TerminalMode};
+
+pub use crate::render::text::{TextRenderer, TextRotation};
 
 // Memory management
 pub use crate::memory::{
@@ -281,17 +347,18 @@ pub use crate::error::{AnimationError, AnimationResult};
 // Animation types
 #[cfg(feature = "animations")]
 pub use crate::animation::{
-    ChartAnimator, EasingFunction, Interpolatable, MultiStateAnimator, Progress, StreamingAnimator,
-    TimeBasedProgress,
+    ChartAnimator, EasingFunction, Interpolatable, MultiStateAnimator, PlaybackMode, Progress,
+    ScrollInterpolator, StreamingAnimator, TimeBasedProgress, TimedAnimator,
 };
 
 // Time abstraction types
 pub use crate::time::{
-    ManualTimeProvider, Microseconds, Milliseconds, MonotonicTimeProvider, TimeProvider,
+    wrapping_elapsed_ms, Clock, ClockTimeProvider, ManualTimeProvider, Microseconds, Milliseconds,
+    MonotonicTimeProvider, TickUnwrapper, TimeProvider,
 };
 
 #[cfg(feature = "std")]
-pub use crate::time::StdTimeProvider;
+pub use crate::time::{StdClock, StdTimeProvider};
 
 // Fluent API for convenient chart creation
 pub use crate::fluent::quick as fluent_quick;
@@ -308,7 +375,10 @@ pub use embedded_graphics::{
 pub use heapless::{String, Vec};
 
 // Enhanced heapless utilities for no_std support
-pub use crate::heapless_utils::{sizes, string, vec, CircularBuffer, HeaplessConfig, HeaplessPool};
+pub use crate::heapless_utils::{
+    sizes, string, vec, CircularBuffer, HeaplessConfig, HeaplessPool, LabelId, LabelLookup,
+    LabelPool,
+};
 
 // Re-export heapless utility macros
 pub use crate::{heapless_string, heapless_vec};
@@ -356,6 +426,28 @@ pub mod types {
     /// Standard multi-series container (8 series, 256 points each)
     pub type StandardMultiSeries = MultiSeries<Point2D, 8, 256>;
 
+    // Sized series aliases. A `Point2D` takes 8 bytes and every series carries
+    // about 48 bytes of length and label overhead, so pick the smallest size
+    // that holds your data rather than defaulting to 256 points.
+
+    /// Small data series with 64 points (about 0.5 KiB of RAM)
+    pub type SmallSeries = StaticDataSeries<Point2D, 64>;
+
+    /// Medium data series with 256 points (about 2 KiB of RAM)
+    pub type MediumSeries = StaticDataSeries<Point2D, 256>;
+
+    /// Large data series with 1024 points (about 8 KiB of RAM)
+    pub type LargeSeries = StaticDataSeries<Point2D, 1024>;
+
+    /// Small multi-series container, 4 series of 64 points (about 2 KiB of RAM)
+    pub type SmallMultiSeries = MultiSeries<Point2D, 4, 64>;
+
+    /// Medium multi-series container, 8 series of 256 points (about 16 KiB of RAM)
+    pub type MediumMultiSeries = MultiSeries<Point2D, 8, 256>;
+
+    /// Large multi-series container, 8 series of 1024 points (about 64 KiB of RAM)
+    pub type LargeMultiSeries = MultiSeries<Point2D, 8, 1024>;
+
     /// Standard color palette with 8 colors
     pub type StandardColorPalette = ColorPalette<Rgb565, 8>;
 
@@ -434,6 +526,44 @@ pub mod quick {
             .subdivisions(12)
     }
 
+    /// Create a simple bar chart with default styling
+    #[cfg(feature = "bar")]
+    pub fn bar_chart() -> BarChartBuilder<Rgb565> {
+        BarChart::builder()
+    }
+
+    /// Create a bar chart with professional styling
+    #[cfg(feature = "bar")]
+    pub fn professional_bar_chart() -> BarChartBuilder<Rgb565> {
+        BarChart::builder().colors(&[Rgb565::new(70 >> 3, 130 >> 2, 180 >> 3)]) // Steel Blue
+    }
+
+    /// Create a simple pie chart with default styling
+    #[cfg(feature = "pie")]
+    pub fn pie_chart() -> PieChartBuilder<Rgb565> {
+        PieChart::builder()
+    }
+
+    /// Create a balanced donut chart (50% inner radius)
+    #[cfg(feature = "pie")]
+    pub fn donut_chart() -> PieChartBuilder<Rgb565> {
+        PieChart::builder().balanced_donut()
+    }
+
+    /// Create a simple scatter chart with default styling
+    #[cfg(feature = "scatter")]
+    pub fn scatter_chart() -> ScatterChartBuilder<Rgb565> {
+        ScatterChart::builder()
+    }
+
+    /// Create a semicircle gauge for the given value range
+    #[cfg(feature = "gauge")]
+    pub fn gauge_chart(min: f32, max: f32) -> GaugeChartBuilder<Rgb565> {
+        GaugeChart::builder()
+            .gauge_type(GaugeType::Semicircle)
+            .value_range(min, max)
+    }
+
     /// Create a simple data series from tuples
     pub fn data_series_from_tuples(data: &[(f32, f32)]) -> ChartResult<types::StandardDataSeries> {
         StaticDataSeries::from_tuples(data).map_err(ChartError::from)
@@ -565,6 +695,34 @@ macro_rules! data_points {
     };
 }
 
+/// Macro for creating a data series from plain values.
+///
+/// Each value becomes a point with its index as the X coordinate, which is the
+/// layout expected by value-centric charts such as pie, bar and gauge charts.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+///
+/// let shares = data_values![45.0, 30.0, 25.0];
+/// assert_eq!(shares.len(), 3);
+/// assert_eq!(shares.get(2), Some(Point2D::new(2.0, 25.0)));
+/// ```
+#[macro_export]
+macro_rules! data_values {
+    [$($value:expr),* $(,)?] => {
+        {
+            let mut series = $crate::data::StaticDataSeries::<$crate::data::Point2D, 256>::new();
+            $(
+                let x = series.as_slice().len() as f32;
+                series.push($crate::data::Point2D::new(x, $value)).unwrap();
+            )*
+            series
+        }
+    };
+}
+
 /// Macro for creating chart configurations with a fluent syntax.
 ///
 /// # Examples
@@ -575,6 +733,7 @@ macro_rules! data_points {
 ///
 /// let config = chart_config! {
 ///     title: "My Chart",
+///     subtitle: "Last 24 hours",
 ///     background: Rgb565::CSS_WHITE,
 ///     margins: Margins::symmetric(10, 10),
 ///     grid: true,
@@ -584,6 +743,7 @@ macro_rules! data_points {
 macro_rules! chart_config {
     (
         $(title: $title:expr,)?
+        $(subtitle: $subtitle:expr,)?
         $(background: $bg:expr,)?
         $(margins: $margins:expr,)?
         $(grid: $grid:expr,)?
@@ -593,6 +753,9 @@ macro_rules! chart_config {
             $(
                 config.title = Some($crate::heapless::String::try_from($title).unwrap());
             )?
+            $(
+                config.subtitle = Some($crate::heapless::String::try_from($subtitle).unwrap());
+            )?
             $(
                 config.background_color = Some($bg);
             )?
@@ -607,6 +770,196 @@ macro_rules! chart_config {
     };
 }
 
+/// Macro for declaring a module of concrete chart type aliases.
+///
+/// Most applications use a single color type and data capacity everywhere.
+/// This macro fixes both once and generates aliases such as `LineChart`,
+/// `Series` and `Config` inside a module, so the generic parameters don't
+/// have to be repeated throughout the application.
+///
+/// The capacity defaults to 256 points. Line charts are sized to the context
+/// capacity; the other built-in charts draw 256-point series whatever the
+/// capacity. Aliases for chart types are only generated when the
+/// corresponding feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_charts::prelude::*;
+///
+/// chart_context!(mod ui: embedded_graphics::pixelcolor::Rgb565);
+///
+/// let mut data = ui::Series::new();
+/// data.push(Point2D::new(0.0, 10.0))?;
+///
+/// let config = ui::Config::default();
+/// # #[cfg(feature = "line")]
+/// let chart: ui::LineChart = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+/// # Ok::<(), embedded_charts::error::ChartError>(())
+/// ```
+#[macro_export]
+macro_rules! chart_context {
+    ($vis:vis mod $name:ident : $color:ty) => {
+        $crate::chart_context!($vis mod $name: $color, 256);
+    };
+    ($vis:vis mod $name:ident : $color:ty, $capacity:expr) => {
+        $vis mod $name {
+            #![allow(dead_code)]
+
+            /// Color type used by all charts in this context
+            pub type Color = $color;
+
+            /// Data series with the context capacity
+            pub type Series = $crate::data::StaticDataSeries<$crate::data::Point2D, { $capacity }>;
+
+            /// Multi-series container (8 series) with the context capacity
+            pub type MultiSeries =
+                $crate::data::MultiSeries<$crate::data::Point2D, 8, { $capacity }>;
+
+            /// Chart configuration
+            pub type Config = $crate::chart::traits::ChartConfig<$color>;
+
+            /// Visual theme
+            pub type Theme = $crate::style::Theme<$color>;
+
+            /// Linear axis over `f32` values
+            pub type LinearAxis = $crate::axes::LinearAxis<f32, $color>;
+
+            $crate::__chart_context_line!($color, $capacity);
+            $crate::__chart_context_bar!($color);
+            $crate::__chart_context_pie!($color);
+            $crate::__chart_context_scatter!($color);
+            $crate::__chart_context_gauge!($color);
+            $crate::__chart_context_animations!($color, $capacity);
+        }
+    };
+}
+
+#[cfg(feature = "line")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_line {
+    ($color:ty, $capacity:expr) => {
+        /// Line chart drawing series with the context capacity
+        pub type LineChart = $crate::chart::LineChart<$color, { $capacity }>;
+        /// Line chart builder
+        pub type LineChartBuilder = $crate::chart::LineChartBuilder<$color, { $capacity }>;
+        /// Line chart style
+        pub type LineChartStyle = $crate::chart::LineChartStyle<$color>;
+        /// Line chart marker style
+        pub type MarkerStyle = $crate::chart::MarkerStyle<$color>;
+        /// Curve chart
+        pub type CurveChart = $crate::chart::CurveChart<$color>;
+        /// Curve chart builder
+        pub type CurveChartBuilder = $crate::chart::CurveChartBuilder<$color>;
+    };
+}
+
+#[cfg(not(feature = "line"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_line {
+    ($color:ty, $capacity:expr) => {};
+}
+
+#[cfg(feature = "bar")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_bar {
+    ($color:ty) => {
+        /// Bar chart
+        pub type BarChart = $crate::chart::BarChart<$color>;
+        /// Bar chart builder
+        pub type BarChartBuilder = $crate::chart::BarChartBuilder<$color>;
+        /// Bar chart style
+        pub type BarChartStyle = $crate::chart::BarChartStyle<$color>;
+    };
+}
+
+#[cfg(not(feature = "bar"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_bar {
+    ($color:ty) => {};
+}
+
+#[cfg(feature = "pie")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_pie {
+    ($color:ty) => {
+        /// Pie chart
+        pub type PieChart = $crate::chart::PieChart<$color>;
+        /// Pie chart builder
+        pub type PieChartBuilder = $crate::chart::PieChartBuilder<$color>;
+    };
+}
+
+#[cfg(not(feature = "pie"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_pie {
+    ($color:ty) => {};
+}
+
+#[cfg(feature = "scatter")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_scatter {
+    ($color:ty) => {
+        /// Scatter chart
+        pub type ScatterChart = $crate::chart::ScatterChart<$color>;
+        /// Scatter chart builder
+        pub type ScatterChartBuilder = $crate::chart::ScatterChartBuilder<$color>;
+    };
+}
+
+#[cfg(not(feature = "scatter"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_scatter {
+    ($color:ty) => {};
+}
+
+#[cfg(feature = "gauge")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_gauge {
+    ($color:ty) => {
+        /// Gauge chart
+        pub type GaugeChart = $crate::chart::GaugeChart<$color>;
+        /// Gauge chart builder
+        pub type GaugeChartBuilder = $crate::chart::GaugeChartBuilder<$color>;
+    };
+}
+
+#[cfg(not(feature = "gauge"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_gauge {
+    ($color:ty) => {};
+}
+
+#[cfg(feature = "animations")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_animations {
+    ($color:ty, $capacity:expr) => {
+        /// Sliding window with the context capacity
+        pub type SlidingWindow =
+            $crate::data::SlidingWindowSeries<$crate::data::Point2D, { $capacity }>;
+    };
+}
+
+#[cfg(not(feature = "animations"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chart_context_animations {
+    ($color:ty, $capacity:expr) => {};
+}
+
 pub use chart_config;
+pub use chart_context;
 /// Re-export the macros
 pub use data_points;
+pub use data_values;
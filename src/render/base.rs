@@ -1,7 +1,10 @@
 //! Rendering utilities for chart components.
 
 use crate::error::{RenderError, RenderResult};
-use crate::style::{FillStyle, GradientDirection, LineStyle, StrokeStyle};
+use crate::render::dash::DashStroke;
+use crate::render::span::SpanRasterizer;
+use crate::render::target::{ChartDrawTarget, FallbackTarget};
+use crate::style::{FillStyle, GradientDirection, LinePattern, LineStyle, StrokeStyle};
 use embedded_graphics::{
     draw_target::DrawTarget,
     prelude::*,
@@ -16,6 +19,8 @@ pub struct ChartRenderer;
 
 impl ChartRenderer {
     /// Draw a line with the specified style
+    ///
+    /// Dashed, dotted and dash-dot patterns are drawn with a [`DashStroke`].
     pub fn draw_line<C, D>(
         start: Point,
         end: Point,
@@ -26,6 +31,10 @@ impl ChartRenderer {
         C: PixelColor,
         D: DrawTarget<Color = C>,
     {
+        if !matches!(style.pattern, LinePattern::Solid | LinePattern::Custom) {
+            return DashStroke::new(style).line(start, end, target);
+        }
+
         let primitive_style = PrimitiveStyleBuilder::new()
             .stroke_color(style.color)
             .stroke_width(style.width)
@@ -40,6 +49,9 @@ impl ChartRenderer {
     }
 
     /// Draw a series of connected lines (polyline)
+    ///
+    /// Line patterns run on through the vertices rather than restarting at
+    /// every segment.
     pub fn draw_polyline<C, D>(
         points: &[Point],
         style: &LineStyle<C>,
@@ -52,6 +64,9 @@ impl ChartRenderer {
         if points.len() < 2 {
             return Ok(());
         }
+        if !matches!(style.pattern, LinePattern::Solid | LinePattern::Custom) {
+            return DashStroke::new(style).polyline(points, target);
+        }
 
         for window in points.windows(2) {
             if let [p1, p2] = window {
@@ -71,14 +86,42 @@ impl ChartRenderer {
     where
         C: PixelColor,
         D: DrawTarget<Color = C>,
+    {
+        Self::draw_filled_rectangle_accelerated(rect, fill_style, &mut FallbackTarget::new(target))
+    }
+
+    /// Draw a filled rectangle using the target's fast paths
+    ///
+    /// Solid fills go through [`ChartDrawTarget::fill_rect`] and patterns
+    /// through [`ChartDrawTarget::fill_span`].
+    pub fn draw_filled_rectangle_accelerated<C, D>(
+        rect: Rectangle,
+        fill_style: &FillStyle<C>,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        C: PixelColor,
+        D: ChartDrawTarget<Color = C>,
     {
         use crate::style::FillPattern;
 
+        // Gradients fall back to their middle color when the profile disables them
+        let gradients = super::QualityProfile::global().gradients();
+        let flat_color = match &fill_style.pattern {
+            FillPattern::LinearGradient(gradient) if !gradients => gradient.color_at(0.5),
+            FillPattern::RadialGradient(gradient) if !gradients => gradient.color_at_distance(0.5),
+            _ => None,
+        };
+        if let Some(color) = flat_color {
+            return target
+                .fill_rect(&rect, color)
+                .map_err(|_| RenderError::DrawingFailed);
+        }
+
         match &fill_style.pattern {
             FillPattern::Solid(color) => {
-                let primitive_style = PrimitiveStyle::with_fill(*color);
-                rect.into_styled(primitive_style)
-                    .draw(target)
+                target
+                    .fill_rect(&rect, *color)
                     .map_err(|_| RenderError::DrawingFailed)?;
             }
             FillPattern::LinearGradient(gradient) => {
@@ -341,21 +384,9 @@ impl ChartRenderer {
     ) -> RenderResult<()>
     where
         C: PixelColor,
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
-        // Draw each pixel with pattern color
-        for y in 0..rect.size.height {
-            for x in 0..rect.size.width {
-                let color = pattern.color_at(x as i32, y as i32);
-                Pixel(
-                    Point::new(rect.top_left.x + x as i32, rect.top_left.y + y as i32),
-                    color,
-                )
-                .draw(target)
-                .map_err(|_| RenderError::DrawingFailed)?;
-            }
-        }
-        Ok(())
+        SpanRasterizer::for_target(target).fill_pattern(rect, pattern, target)
     }
 
     /// Draw a horizontal line (optimized for gradient rendering)
@@ -385,42 +416,84 @@ impl ChartRenderer {
     ) -> RenderResult<()>
     where
         D: DrawTarget<Color = embedded_graphics::pixelcolor::Rgb565>,
+    {
+        Self::draw_linear_gradient_rect_accelerated(
+            rect,
+            gradient,
+            &mut FallbackTarget::new(target),
+        )
+    }
+
+    /// Draw a rectangle filled with a linear gradient using the target's fast paths
+    ///
+    /// Horizontal gradients are computed once per row and written with
+    /// [`ChartDrawTarget::blit_row`]; vertical gradients use
+    /// [`ChartDrawTarget::fill_span`] and diagonal gradients are drawn as small
+    /// squares with [`ChartDrawTarget::fill_rect`].
+    ///
+    /// # Arguments
+    /// * `rect` - The area to fill
+    /// * `gradient` - The gradient to render
+    /// * `target` - The accelerated display target
+    #[cfg(feature = "color-support")]
+    pub fn draw_linear_gradient_rect_accelerated<D, const N: usize>(
+        rect: Rectangle,
+        gradient: &crate::style::LinearGradient<embedded_graphics::pixelcolor::Rgb565, N>,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget<Color = embedded_graphics::pixelcolor::Rgb565>,
     {
         use crate::style::GradientInterpolation;
 
+        /// Number of gradient colors buffered per `blit_row` call
+        const ROW_CHUNK: usize = 64;
+
         if !gradient.is_valid() {
             return Ok(());
         }
 
         match gradient.direction() {
             GradientDirection::Horizontal => {
-                // Draw vertical lines with interpolated colors
-                for x in 0..rect.size.width {
-                    let t = x as f32 / (rect.size.width - 1) as f32;
-                    if let Some(color) = gradient.interpolated_color_at(t) {
-                        let line_start = Point::new(rect.top_left.x + x as i32, rect.top_left.y);
-                        let line_end = Point::new(
-                            rect.top_left.x + x as i32,
-                            rect.top_left.y + rect.size.height as i32 - 1,
-                        );
-                        Line::new(line_start, line_end)
-                            .into_styled(PrimitiveStyle::with_stroke(color, 1))
-                            .draw(target)
+                let mut x = 0;
+                while x < rect.size.width {
+                    let start = x;
+                    let mut row: heapless::Vec<embedded_graphics::pixelcolor::Rgb565, ROW_CHUNK> =
+                        heapless::Vec::new();
+                    while x < rect.size.width {
+                        let t = x as f32 / (rect.size.width - 1) as f32;
+                        match gradient.interpolated_color_at(t) {
+                            Some(color) if row.push(color).is_ok() => x += 1,
+                            _ => break,
+                        }
+                    }
+
+                    if row.is_empty() {
+                        // No color for this column, leave it untouched
+                        x += 1;
+                        continue;
+                    }
+
+                    for y in 0..rect.size.height {
+                        let row_start =
+                            Point::new(rect.top_left.x + start as i32, rect.top_left.y + y as i32);
+                        target
+                            .blit_row(row_start, &row)
                             .map_err(|_| RenderError::DrawingFailed)?;
                     }
                 }
             }
             GradientDirection::Vertical => {
-                // Draw horizontal lines with interpolated colors
                 for y in 0..rect.size.height {
                     let t = y as f32 / (rect.size.height - 1) as f32;
                     if let Some(color) = gradient.interpolated_color_at(t) {
-                        Self::draw_horizontal_line(
-                            Point::new(rect.top_left.x, rect.top_left.y + y as i32),
-                            rect.size.width,
-                            color,
-                            target,
-                        )?;
+                        target
+                            .fill_span(
+                                Point::new(rect.top_left.x, rect.top_left.y + y as i32),
+                                rect.size.width,
+                                color,
+                            )
+                            .map_err(|_| RenderError::DrawingFailed)?;
                     }
                 }
             }
@@ -439,18 +512,19 @@ impl ChartRenderer {
                         };
 
                         if let Some(color) = gradient.interpolated_color_at(t) {
-                            Rectangle::new(
+                            let square = Rectangle::new(
                                 Point::new(rect.top_left.x + x as i32, rect.top_left.y + y as i32),
                                 Size::new(step as u32, step as u32),
-                            )
-                            .into_styled(PrimitiveStyle::with_fill(color))
-                            .draw(target)
-                            .map_err(|_| RenderError::DrawingFailed)?;
+                            );
+                            target
+                                .fill_rect(&square, color)
+                                .map_err(|_| RenderError::DrawingFailed)?;
                         }
                     }
                 }
             }
         }
+
         Ok(())
     }
 
@@ -665,6 +739,117 @@ pub mod text {
 
             Self::draw_text(text, Point::new(x, y), style, target)
         }
+
+        /// Calculate the size of text drawn vertically
+        pub fn vertical_text_size<C>(text: &str, font: &MonoFont) -> Size {
+            let size = Self::text_size::<C>(text, font);
+            Size::new(size.height, size.width)
+        }
+
+        /// Draw text rotated by 90 degrees
+        ///
+        /// `position` is the top-left corner of the rotated text box, which is
+        /// as wide as the font is tall. Glyphs are rendered upright and
+        /// transposed pixel by pixel, since mono fonts cannot be rotated.
+        pub fn draw_vertical_text<C, D>(
+            text: &str,
+            position: Point,
+            rotation: TextRotation,
+            style: &MonoTextStyle<C>,
+            target: &mut D,
+        ) -> RenderResult<()>
+        where
+            C: PixelColor,
+            D: DrawTarget<Color = C>,
+        {
+            let size = Self::text_size::<C>(text, style.font);
+            let mut rotated = RotatedTarget {
+                parent: target,
+                origin: position,
+                size,
+                rotation,
+            };
+
+            Text::with_baseline(text, Point::zero(), *style, Baseline::Top)
+                .draw(&mut rotated)
+                .map_err(|_| RenderError::TextRenderingFailed)?;
+
+            Ok(())
+        }
+
+        /// Draw vertical text centered within a rectangle
+        pub fn draw_vertical_centered_text<C, D>(
+            text: &str,
+            container: Rectangle,
+            rotation: TextRotation,
+            style: &MonoTextStyle<C>,
+            target: &mut D,
+        ) -> RenderResult<()>
+        where
+            C: PixelColor,
+            D: DrawTarget<Color = C>,
+        {
+            let text_size = Self::vertical_text_size::<C>(text, style.font);
+            let x =
+                container.top_left.x + (container.size.width as i32 - text_size.width as i32) / 2;
+            let y =
+                container.top_left.y + (container.size.height as i32 - text_size.height as i32) / 2;
+
+            Self::draw_vertical_text(text, Point::new(x, y), rotation, style, target)
+        }
+    }
+
+    /// Direction of text rotated by 90 degrees
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum TextRotation {
+        /// Rotated counter-clockwise, reading from bottom to top (Y axis titles)
+        #[default]
+        BottomToTop,
+        /// Rotated clockwise, reading from top to bottom
+        TopToBottom,
+    }
+
+    /// Draw target that transposes upright text into a rotated column
+    struct RotatedTarget<'a, D> {
+        parent: &'a mut D,
+        /// Top-left corner of the rotated text box on the parent
+        origin: Point,
+        /// Size of the upright text
+        size: Size,
+        rotation: TextRotation,
+    }
+
+    impl TextRotation {
+        /// Map a point of upright text of the given size into the rotated box
+        fn transpose(self, point: Point, size: Size) -> Point {
+            match self {
+                TextRotation::BottomToTop => Point::new(point.y, size.width as i32 - 1 - point.x),
+                TextRotation::TopToBottom => Point::new(size.height as i32 - 1 - point.y, point.x),
+            }
+        }
+    }
+
+    impl<D: DrawTarget> Dimensions for RotatedTarget<'_, D> {
+        fn bounding_box(&self) -> Rectangle {
+            Rectangle::new(Point::zero(), self.size)
+        }
+    }
+
+    impl<D: DrawTarget> DrawTarget for RotatedTarget<'_, D> {
+        type Color = D::Color;
+        type Error = D::Error;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            let (origin, size, rotation) = (self.origin, self.size, self.rotation);
+            self.parent.draw_iter(
+                pixels.into_iter().map(|Pixel(point, color)| {
+                    Pixel(origin + rotation.transpose(point, size), color)
+                }),
+            )
+        }
     }
 }
 
@@ -684,6 +869,29 @@ impl PrimitiveRenderer {
     where
         C: PixelColor,
         D: DrawTarget<Color = C>,
+    {
+        Self::draw_triangle_accelerated(
+            p1,
+            p2,
+            p3,
+            stroke_style,
+            fill_style,
+            &mut FallbackTarget::new(target),
+        )
+    }
+
+    /// Draw a triangle, filling it through [`ChartDrawTarget::fill_span`]
+    pub fn draw_triangle_accelerated<C, D>(
+        p1: Point,
+        p2: Point,
+        p3: Point,
+        stroke_style: Option<&StrokeStyle<C>>,
+        fill_style: Option<&FillStyle<C>>,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        C: PixelColor,
+        D: ChartDrawTarget<Color = C>,
     {
         // For simplicity, draw triangle as three lines
         // A full implementation would use a proper triangle primitive
@@ -702,7 +910,7 @@ impl PrimitiveRenderer {
         Ok(())
     }
 
-    /// Fill a triangle using scanline algorithm
+    /// Fill a triangle using the shared span rasterizer
     fn fill_triangle<C, D>(
         p1: Point,
         p2: Point,
@@ -712,97 +920,10 @@ impl PrimitiveRenderer {
     ) -> RenderResult<()>
     where
         C: PixelColor,
-        D: DrawTarget<Color = C>,
-    {
-        // Sort points by Y coordinate (p1.y <= p2.y <= p3.y)
-        let mut points = [p1, p2, p3];
-        // Manual sorting for no_std compatibility
-        if points[0].y > points[1].y {
-            points.swap(0, 1);
-        }
-        if points[1].y > points[2].y {
-            points.swap(1, 2);
-        }
-        if points[0].y > points[1].y {
-            points.swap(0, 1);
-        }
-        let [top, mid, bottom] = points;
-
-        // Handle degenerate cases
-        if top.y == bottom.y {
-            // All points on same horizontal line
-            let min_x = top.x.min(mid.x).min(bottom.x);
-            let max_x = top.x.max(mid.x).max(bottom.x);
-            if let Some(color) = fill_style.solid_color() {
-                Self::draw_horizontal_line(min_x, max_x, top.y, color, target)?;
-            }
-            return Ok(());
-        }
-
-        // Calculate slopes for the three edges
-        let total_height = bottom.y - top.y;
-
-        // Draw upper part of triangle (from top to mid)
-        if mid.y > top.y {
-            let segment_height = mid.y - top.y;
-            for y in top.y..mid.y {
-                let alpha = (y - top.y) as f32 / total_height as f32;
-                let beta = (y - top.y) as f32 / segment_height as f32;
-
-                let x1 = top.x + ((bottom.x - top.x) as f32 * alpha) as i32;
-                let x2 = top.x + ((mid.x - top.x) as f32 * beta) as i32;
-
-                let (min_x, max_x) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
-                if let Some(color) = fill_style.solid_color() {
-                    Self::draw_horizontal_line(min_x, max_x, y, color, target)?;
-                }
-            }
-        }
-
-        // Draw lower part of triangle (from mid to bottom)
-        if bottom.y > mid.y {
-            let segment_height = bottom.y - mid.y;
-            for y in mid.y..=bottom.y {
-                let alpha = (y - top.y) as f32 / total_height as f32;
-                let beta = (y - mid.y) as f32 / segment_height as f32;
-
-                let x1 = top.x + ((bottom.x - top.x) as f32 * alpha) as i32;
-                let x2 = mid.x + ((bottom.x - mid.x) as f32 * beta) as i32;
-
-                let (min_x, max_x) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
-                if let Some(color) = fill_style.solid_color() {
-                    Self::draw_horizontal_line(min_x, max_x, y, color, target)?;
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Draw a horizontal line from x1 to x2 at y coordinate
-    fn draw_horizontal_line<C, D>(
-        x1: i32,
-        x2: i32,
-        y: i32,
-        color: C,
-        target: &mut D,
-    ) -> RenderResult<()>
-    where
-        C: PixelColor,
-        D: DrawTarget<Color = C>,
+        D: ChartDrawTarget<Color = C>,
     {
-        if x1 == x2 {
-            // Single pixel
-            target
-                .draw_iter(core::iter::once(Pixel(Point::new(x1, y), color)))
-                .map_err(|_| RenderError::DrawingFailed)?;
-        } else {
-            // Horizontal line
-            let line_style = PrimitiveStyle::with_stroke(color, 1);
-            Line::new(Point::new(x1, y), Point::new(x2, y))
-                .into_styled(line_style)
-                .draw(target)
-                .map_err(|_| RenderError::DrawingFailed)?;
+        if let Some(color) = fill_style.solid_color() {
+            SpanRasterizer::for_target(target).fill_triangle(p1, p2, p3, color, target)?;
         }
         Ok(())
     }
@@ -856,11 +977,11 @@ impl AnimationFrameRenderer {
 
     /// Update the frame renderer with elapsed time
     pub fn update(&mut self, current_time: crate::time::Milliseconds) -> bool {
-        let frame_duration = 1000 / self.frame_rate;
+        let frame_duration = 1000 / self.effective_frame_rate();
 
         if let Some(last_time) = self.last_frame_time {
-            let delta = current_time.saturating_sub(last_time);
-            self.time_accumulator += delta;
+            let delta = crate::time::wrapping_elapsed_ms(last_time, current_time);
+            self.time_accumulator = self.time_accumulator.saturating_add(delta);
         }
 
         self.last_frame_time = Some(current_time);
@@ -878,6 +999,13 @@ impl AnimationFrameRenderer {
         self.frame_rate
     }
 
+    /// Get the frame rate after applying the global quality profile
+    pub fn effective_frame_rate(&self) -> u32 {
+        super::QualityProfile::global()
+            .frame_rate(self.frame_rate)
+            .max(1)
+    }
+
     /// Set the target frame rate
     pub fn set_frame_rate(&mut self, fps: u32) {
         self.frame_rate = fps.clamp(1, 120);
@@ -1016,6 +1144,32 @@ mod tests {
     use embedded_graphics::mock_display::MockDisplay;
     use embedded_graphics::pixelcolor::Rgb565;
 
+    #[test]
+    fn test_vertical_text() {
+        use super::text::{TextRenderer, TextRotation};
+        use embedded_graphics::mono_font::{ascii::FONT_6X10, MonoTextStyle};
+
+        let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+        let size = TextRenderer::vertical_text_size::<Rgb565>("AB", &FONT_6X10);
+        assert_eq!(size, Size::new(10, 12));
+
+        for rotation in [TextRotation::BottomToTop, TextRotation::TopToBottom] {
+            let mut display = MockDisplay::<Rgb565>::new();
+            TextRenderer::draw_vertical_text(
+                "AB",
+                Point::new(4, 8),
+                rotation,
+                &style,
+                &mut display,
+            )
+            .unwrap();
+
+            let area = display.affected_area();
+            assert!(area.size.height > area.size.width);
+            assert!(Rectangle::new(Point::new(4, 8), size).contains(area.top_left));
+        }
+    }
+
     #[test]
     fn test_clipping_point_visibility() {
         let bounds = Rectangle::new(Point::new(10, 10), Size::new(100, 80));
@@ -1088,4 +1242,72 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    #[cfg(feature = "color-support")]
+    fn test_accelerated_gradient_uses_fast_paths() {
+        use crate::render::target::ChartDrawTarget;
+        use crate::style::LinearGradient;
+
+        struct SpanCounter {
+            spans: u32,
+        }
+
+        impl Dimensions for SpanCounter {
+            fn bounding_box(&self) -> Rectangle {
+                Rectangle::new(Point::zero(), Size::new(64, 64))
+            }
+        }
+
+        impl DrawTarget for SpanCounter {
+            type Color = Rgb565;
+            type Error = core::convert::Infallible;
+
+            fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+            where
+                I: IntoIterator<Item = Pixel<Self::Color>>,
+            {
+                Ok(())
+            }
+        }
+
+        impl ChartDrawTarget for SpanCounter {
+            fn fill_span(
+                &mut self,
+                _start: Point,
+                _width: u32,
+                _color: Rgb565,
+            ) -> Result<(), Self::Error> {
+                self.spans += 1;
+                Ok(())
+            }
+        }
+
+        let rect = Rectangle::new(Point::new(2, 2), Size::new(10, 8));
+        let gradient: LinearGradient<Rgb565, 8> =
+            LinearGradient::simple(Rgb565::RED, Rgb565::BLUE, GradientDirection::Vertical).unwrap();
+        let mut target = SpanCounter { spans: 0 };
+        ChartRenderer::draw_linear_gradient_rect_accelerated(rect, &gradient, &mut target).unwrap();
+        assert_eq!(target.spans, 8);
+
+        // The default path fills every pixel exactly once
+        let gradient: LinearGradient<Rgb565, 8> =
+            LinearGradient::simple(Rgb565::RED, Rgb565::BLUE, GradientDirection::Horizontal)
+                .unwrap();
+        let mut display = MockDisplay::<Rgb565>::new();
+        ChartRenderer::draw_linear_gradient_rect_rgb565(rect, &gradient, &mut display).unwrap();
+        assert_eq!(display.affected_area(), rect);
+        assert_eq!(display.get_pixel(Point::new(2, 9)), Some(Rgb565::RED));
+    }
+
+    #[test]
+    #[cfg(feature = "animations")]
+    fn test_frame_renderer_ignores_backward_clock_step() {
+        let mut renderer = AnimationFrameRenderer::new(10);
+        assert!(!renderer.update(1000));
+
+        // Stepping back must not count as a wrap of nearly 49 days
+        assert!(!renderer.update(500));
+        assert!(!renderer.update(510));
+    }
 }
@@ -0,0 +1,249 @@
+//! Dashed and dotted strokes.
+//!
+//! [`DashStroke`] walks lines with integer Bresenham steps and draws the "on"
+//! runs of a [`LinePattern`]. The position within the pattern carries over
+//! from one segment to the next, so a dashed polyline keeps its rhythm
+//! through its vertices instead of restarting a dash at every data point.
+//!
+//! ```rust
+//! use embedded_charts::render::DashStroke;
+//! use embedded_charts::style::LineStyle;
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*};
+//!
+//! let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+//! let mut stroke = DashStroke::new(&LineStyle::dashed(Rgb565::RED));
+//! stroke.polyline(
+//!     &[Point::new(0, 10), Point::new(30, 10), Point::new(30, 40)],
+//!     &mut display,
+//! )?;
+//! # Ok::<(), embedded_charts::error::RenderError>(())
+//! ```
+
+use crate::error::{RenderError, RenderResult};
+use crate::style::{LinePattern, LineStyle};
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle},
+};
+
+/// Stroke that draws a line pattern across consecutive segments
+///
+/// Pattern lengths are counted in pixels along the major axis of each
+/// segment and grow with the stroke width. Dots of strokes wider than one
+/// pixel are drawn as filled circles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashStroke<C: PixelColor> {
+    color: C,
+    width: u32,
+    /// Alternating on and off lengths, starting with "on"
+    dashes: [u32; 4],
+    /// Offset into the pattern of the next pixel
+    phase: u32,
+    /// End of the previous segment and whether its last pixel was on
+    last: Option<(Point, bool)>,
+}
+
+impl<C: PixelColor> DashStroke<C> {
+    /// Create a stroke with the color, width and pattern of `style`
+    ///
+    /// Solid and custom patterns draw continuous lines.
+    pub fn new(style: &LineStyle<C>) -> Self {
+        Self {
+            color: style.color,
+            width: style.width.max(1),
+            dashes: Self::dashes(style.pattern, style.width),
+            phase: 0,
+            last: None,
+        }
+    }
+
+    /// Get the alternating on and off lengths of `pattern` at `width`
+    pub const fn dashes(pattern: LinePattern, width: u32) -> [u32; 4] {
+        let width = if width == 0 { 1 } else { width };
+        match pattern {
+            LinePattern::Solid | LinePattern::Custom => [1, 0, 0, 0],
+            LinePattern::Dashed => [4 * width + 4, 4 * width, 0, 0],
+            LinePattern::Dotted => [1, 3 * width, 0, 0],
+            LinePattern::DashDot => [4 * width + 4, 3 * width, 1, 3 * width],
+        }
+    }
+
+    /// Restart the pattern, so the next segment begins with a full dash
+    pub fn reset(&mut self) {
+        self.phase = 0;
+        self.last = None;
+    }
+
+    /// Draw connected segments through `points`, continuing the pattern
+    pub fn polyline<D>(&mut self, points: &[Point], target: &mut D) -> RenderResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for window in points.windows(2) {
+            if let [start, end] = window {
+                self.line(*start, *end, target)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw a segment from `start` to `end`
+    ///
+    /// A segment starting where the previous one ended continues its pattern
+    /// without counting the shared pixel twice.
+    pub fn line<D>(&mut self, start: Point, end: Point, target: &mut D) -> RenderResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let continuing = self.last.filter(|(last, _)| *last == start);
+        // Wide dashes restart at the shared pixel so the join has no notch
+        let mut run_start = continuing.and_then(|(_, on)| (on && self.width > 1).then_some(start));
+        let mut skip = continuing.is_some();
+        let mut on = continuing.is_some_and(|(_, on)| on);
+
+        let (dx, dy) = ((end.x - start.x).abs(), -(end.y - start.y).abs());
+        let (sx, sy) = (
+            if start.x < end.x { 1 } else { -1 },
+            if start.y < end.y { 1 } else { -1 },
+        );
+        let mut error = dx + dy;
+        let (mut point, mut previous) = (start, start);
+
+        loop {
+            if !skip {
+                on = self.is_on();
+                match (on, run_start) {
+                    (true, None) => run_start = Some(point),
+                    (false, Some(first)) => {
+                        self.draw_run(first, previous, target)?;
+                        run_start = None;
+                    }
+                    _ => {}
+                }
+                self.advance();
+            }
+            skip = false;
+
+            if point == end {
+                break;
+            }
+            previous = point;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                point.x += sx;
+            }
+            if doubled <= dx {
+                error += dx;
+                point.y += sy;
+            }
+        }
+
+        if let Some(first) = run_start {
+            self.draw_run(first, end, target)?;
+        }
+        self.last = Some((end, on));
+        Ok(())
+    }
+
+    /// Check whether the pixel at the current phase is drawn
+    fn is_on(&self) -> bool {
+        let mut offset = self.phase;
+        for (index, &length) in self.dashes.iter().enumerate() {
+            if offset < length {
+                return index % 2 == 0;
+            }
+            offset -= length;
+        }
+        true
+    }
+
+    /// Move on to the next pixel of the pattern
+    fn advance(&mut self) {
+        let period: u32 = self.dashes.iter().sum();
+        self.phase = (self.phase + 1) % period.max(1);
+    }
+
+    /// Draw one run of "on" pixels
+    fn draw_run<D>(&self, first: Point, last: Point, target: &mut D) -> RenderResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if first == last && self.width > 1 {
+            Circle::with_center(first, self.width)
+                .into_styled(PrimitiveStyle::with_fill(self.color))
+                .draw(target)
+        } else {
+            Line::new(first, last)
+                .into_styled(PrimitiveStyle::with_stroke(self.color, self.width))
+                .draw(target)
+        }
+        .map_err(|_| RenderError::DrawingFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    fn row_pixels(display: &MockDisplay<Rgb565>, y: i32) -> heapless::Vec<bool, 64> {
+        (0..64)
+            .map(|x| display.get_pixel(Point::new(x, y)).is_some())
+            .collect()
+    }
+
+    #[test]
+    fn test_dashed_line_alternates() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        DashStroke::new(&LineStyle::dashed(Rgb565::RED))
+            .line(Point::new(0, 5), Point::new(40, 5), &mut display)
+            .unwrap();
+
+        // 8 pixels on, 4 off
+        let row = row_pixels(&display, 5);
+        assert!(row[0..8].iter().all(|&on| on));
+        assert!(row[8..12].iter().all(|&on| !on));
+        assert!(row[12..20].iter().all(|&on| on));
+    }
+
+    #[test]
+    fn test_pattern_continues_across_segments() {
+        let style = LineStyle::dashed(Rgb565::RED);
+        let mut joined: MockDisplay<Rgb565> = MockDisplay::new();
+        DashStroke::new(&style)
+            .polyline(
+                &[Point::new(0, 5), Point::new(10, 5), Point::new(40, 5)],
+                &mut joined,
+            )
+            .unwrap();
+
+        let mut single: MockDisplay<Rgb565> = MockDisplay::new();
+        DashStroke::new(&style)
+            .line(Point::new(0, 5), Point::new(40, 5), &mut single)
+            .unwrap();
+
+        assert_eq!(row_pixels(&joined, 5), row_pixels(&single, 5));
+    }
+
+    #[test]
+    fn test_dotted_and_solid() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        DashStroke::new(&LineStyle::dotted(Rgb565::RED).width(1))
+            .line(Point::new(0, 5), Point::new(12, 5), &mut display)
+            .unwrap();
+        let dots: heapless::Vec<usize, 64> = row_pixels(&display, 5)
+            .iter()
+            .enumerate()
+            .filter_map(|(x, &on)| on.then_some(x))
+            .collect();
+        assert_eq!(dots.as_slice(), &[0, 4, 8, 12]);
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        DashStroke::new(&LineStyle::solid(Rgb565::RED))
+            .line(Point::new(0, 5), Point::new(12, 5), &mut display)
+            .unwrap();
+        assert!(row_pixels(&display, 5)[0..=12].iter().all(|&on| on));
+    }
+}
@@ -0,0 +1,274 @@
+//! Decimation of dense series to the pixel columns they are drawn into.
+//!
+//! A series of thousands of samples drawn into a plot a hundred pixels wide
+//! puts dozens of points into every column. [`Decimation`] reduces them while
+//! the points are mapped to the screen, so stroking scales with the width of
+//! the plot rather than the number of points, and the full series is never
+//! copied.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::render::Decimation;
+//! use embedded_graphics::prelude::*;
+//!
+//! // 1000 samples across 10 pixel columns
+//! let points = (0..1000).map(|i| Point::new(i / 100, (i % 7) * 3));
+//!
+//! let mut decimated: heapless::Vec<Point, 32> = heapless::Vec::new();
+//! Decimation::MinMax.decimate(points, 10, &mut decimated)?;
+//! assert_eq!(decimated.len(), 20);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::prelude::*;
+use heapless::Vec;
+
+/// How a dense series is reduced before it is stroked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decimation {
+    /// Keep the lowest and highest point of every pixel column, in their
+    /// original order
+    ///
+    /// Spikes and the vertical extent of every column survive, so the drawn
+    /// line covers the same pixels as the full series. Needs room for two
+    /// points per column.
+    MinMax,
+    /// Largest-Triangle-Three-Buckets down to one point per column
+    ///
+    /// Keeps the overall shape with half the points of
+    /// [`MinMax`](Self::MinMax), but a narrow spike can be lost.
+    Lttb,
+}
+
+impl Decimation {
+    /// Reduce screen `points` drawn across `columns` pixels into `out`
+    ///
+    /// Points must be ordered by X. Series with no more points than columns
+    /// are copied unchanged. Fails with [`ChartError::MemoryFull`] when the
+    /// result does not fit `out`.
+    pub fn decimate<I, const M: usize>(
+        self,
+        points: I,
+        columns: u32,
+        out: &mut Vec<Point, M>,
+    ) -> ChartResult<()>
+    where
+        I: Iterator<Item = Point> + Clone,
+    {
+        // LTTB needs the first, the last and at least one bucket in between
+        let keep = match self {
+            Self::MinMax => columns as usize,
+            Self::Lttb => (columns as usize).max(3),
+        };
+        if points.clone().nth(keep).is_none() {
+            for point in points {
+                push(out, point)?;
+            }
+            return Ok(());
+        }
+
+        match self {
+            Self::MinMax => min_max(points, out),
+            Self::Lttb => lttb(points, keep, out),
+        }
+    }
+}
+
+fn push<const M: usize>(out: &mut Vec<Point, M>, point: Point) -> ChartResult<()> {
+    out.push(point).map_err(|_| ChartError::MemoryFull)
+}
+
+/// Lowest and highest point of one column, with their positions in the series
+#[derive(Clone, Copy)]
+struct Column {
+    x: i32,
+    low: (usize, Point),
+    high: (usize, Point),
+}
+
+impl Column {
+    fn new(index: usize, point: Point) -> Self {
+        Self {
+            x: point.x,
+            low: (index, point),
+            high: (index, point),
+        }
+    }
+
+    fn add(&mut self, index: usize, point: Point) {
+        if point.y < self.low.1.y {
+            self.low = (index, point);
+        }
+        if point.y > self.high.1.y {
+            self.high = (index, point);
+        }
+    }
+
+    fn emit<const M: usize>(&self, out: &mut Vec<Point, M>) -> ChartResult<()> {
+        let (first, second) = if self.low.0 <= self.high.0 {
+            (self.low, self.high)
+        } else {
+            (self.high, self.low)
+        };
+        push(out, first.1)?;
+        if second.0 != first.0 {
+            push(out, second.1)?;
+        }
+        Ok(())
+    }
+}
+
+fn min_max<I, const M: usize>(points: I, out: &mut Vec<Point, M>) -> ChartResult<()>
+where
+    I: Iterator<Item = Point>,
+{
+    let mut column: Option<Column> = None;
+    for (index, point) in points.enumerate() {
+        match column.as_mut() {
+            Some(current) if current.x == point.x => current.add(index, point),
+            _ => {
+                if let Some(done) = column {
+                    done.emit(out)?;
+                }
+                column = Some(Column::new(index, point));
+            }
+        }
+    }
+    match column {
+        Some(done) => done.emit(out),
+        None => Ok(()),
+    }
+}
+
+/// Twice the area of the triangle spanned by three points
+fn triangle_area(a: Point, b: Point, c: Point) -> i64 {
+    let (ax, ay) = (i64::from(a.x), i64::from(a.y));
+    let (bx, by) = (i64::from(b.x), i64::from(b.y));
+    let (cx, cy) = (i64::from(c.x), i64::from(c.y));
+    ((ax - cx) * (by - ay) - (ax - bx) * (cy - ay)).abs()
+}
+
+fn lttb<I, const M: usize>(points: I, threshold: usize, out: &mut Vec<Point, M>) -> ChartResult<()>
+where
+    I: Iterator<Item = Point> + Clone,
+{
+    let len = points.clone().count();
+    let buckets = threshold - 2;
+    // Bucket `b` covers indices `bound(b)..bound(b + 1)`; the first and last
+    // points are kept on their own
+    let bound = |bucket: usize| 1 + bucket * (len - 2) / buckets;
+
+    let mut rest = points;
+    let Some(mut previous) = rest.next() else {
+        return Ok(());
+    };
+    push(out, previous)?;
+
+    for bucket in 0..buckets {
+        let (start, end) = (bound(bucket), bound(bucket + 1));
+        let next_end = if bucket + 1 < buckets {
+            bound(bucket + 2)
+        } else {
+            len
+        };
+
+        // Average of the next bucket, or the last point after the final bucket
+        let (sum_x, sum_y, count) = rest
+            .clone()
+            .skip(end - start)
+            .take(next_end - end)
+            .fold((0i64, 0i64, 0i64), |(x, y, n), point| {
+                (x + i64::from(point.x), y + i64::from(point.y), n + 1)
+            });
+        let count = count.max(1);
+        let average = Point::new((sum_x / count) as i32, (sum_y / count) as i32);
+
+        let mut selected = previous;
+        let mut largest = -1;
+        for point in rest.by_ref().take(end - start) {
+            let area = triangle_area(previous, point, average);
+            if area > largest {
+                largest = area;
+                selected = point;
+            }
+        }
+        push(out, selected)?;
+        previous = selected;
+    }
+
+    match rest.last() {
+        Some(last) => push(out, last),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_max_keeps_column_extremes_in_order() {
+        let points = [
+            Point::new(0, 5),
+            Point::new(0, 9),
+            Point::new(0, 1),
+            Point::new(0, 4),
+            Point::new(1, 3),
+            Point::new(2, 7),
+            Point::new(2, 7),
+        ];
+        let mut out: Vec<Point, 16> = Vec::new();
+        Decimation::MinMax
+            .decimate(points.iter().copied(), 2, &mut out)
+            .unwrap();
+        assert_eq!(
+            out.as_slice(),
+            &[
+                Point::new(0, 9),
+                Point::new(0, 1),
+                Point::new(1, 3),
+                Point::new(2, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lttb_keeps_endpoints_and_spike() {
+        let points = (0..100).map(|x| Point::new(x, if x == 40 { 50 } else { 0 }));
+        let mut out: Vec<Point, 16> = Vec::new();
+        Decimation::Lttb.decimate(points, 10, &mut out).unwrap();
+
+        assert_eq!(out.len(), 10);
+        assert_eq!(out.first(), Some(&Point::new(0, 0)));
+        assert_eq!(out.last(), Some(&Point::new(99, 0)));
+        assert!(out.contains(&Point::new(40, 50)));
+    }
+
+    #[test]
+    fn test_sparse_series_is_copied() {
+        let points = [Point::new(0, 0), Point::new(10, 5), Point::new(20, 0)];
+        let mut out: Vec<Point, 4> = Vec::new();
+        Decimation::Lttb
+            .decimate(points.iter().copied(), 64, &mut out)
+            .unwrap();
+        assert_eq!(out.as_slice(), &points);
+
+        let mut small: Vec<Point, 2> = Vec::new();
+        assert_eq!(
+            Decimation::MinMax.decimate(points.iter().copied(), 64, &mut small),
+            Err(ChartError::MemoryFull)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Zoom-dependent level of detail.
+//!
+//! A [`DetailPolicy`] decides how much of a series is worth drawing from the
+//! number of visible points and the width they are drawn into. Zoomed out, the
+//! series is decimated with LTTB, markers are thinned and labels suppressed;
+//! zoomed in, every point, marker and label comes back. Charts and dashboards
+//! share one policy, so all chart types thin out at the same zoom levels.
+//!
+//! | Level       | Point spacing              | Data               | Markers  | Labels |
+//! |-------------|----------------------------|--------------------|----------|--------|
+//! | `Full`      | at least `full_spacing`    | all points         | all      | shown  |
+//! | `Thinned`   | at least `thin_spacing`    | all points         | thinned  | hidden |
+//! | `Decimated` | below `thin_spacing`       | LTTB to the width  | thinned  | hidden |
+
+use crate::data::aggregation::{DataAggregation, DownsamplingConfig};
+use crate::data::series::StaticDataSeries;
+use crate::data::DataPoint;
+use crate::error::DataResult;
+use crate::layout::Viewport;
+use crate::render::QualityProfile;
+
+/// How much detail a plan keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailLevel {
+    /// Every point, marker and label
+    Full,
+    /// Every point, but fewer markers and no labels
+    Thinned,
+    /// Data reduced with LTTB, fewer markers and no labels
+    Decimated,
+}
+
+/// What to draw for the current zoom level, produced by a [`DetailPolicy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetailPlan {
+    /// Chosen level of detail
+    pub level: DetailLevel,
+    /// Number of points to keep after decimation
+    pub max_points: usize,
+    /// Draw a marker for every n-th point
+    pub marker_stride: usize,
+    /// Whether data labels should be drawn
+    pub show_labels: bool,
+}
+
+impl DetailPlan {
+    /// Quality profile matching this plan, for charts with a quality override
+    pub fn quality(&self) -> QualityProfile {
+        match self.level {
+            DetailLevel::Full => QualityProfile::Full,
+            DetailLevel::Thinned => QualityProfile::Balanced,
+            DetailLevel::Decimated => QualityProfile::PowerSaver,
+        }
+    }
+
+    /// Downsampling settings that reduce a series to this plan
+    pub fn downsampling_config(&self) -> DownsamplingConfig {
+        DownsamplingConfig {
+            max_points: self.max_points,
+            preserve_endpoints: true,
+            min_reduction_ratio: 1.0,
+        }
+    }
+
+    /// Reduce `series` to the points this plan draws
+    ///
+    /// Series that already fit are copied unchanged; larger ones are decimated
+    /// with LTTB to at most `max_points` (and at most `N`) points.
+    pub fn thin<T, const M: usize, const N: usize>(
+        &self,
+        series: &StaticDataSeries<T, M>,
+    ) -> DataResult<StaticDataSeries<T, N>>
+    where
+        T: DataPoint,
+        StaticDataSeries<T, M>: DataAggregation<Item = T>,
+    {
+        let mut config = self.downsampling_config();
+        config.max_points = config.max_points.min(N);
+        series.downsample_lttb(&config)
+    }
+}
+
+/// Maps zoom level to a [`DetailPlan`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetailPolicy {
+    /// Pixels between points at which full detail is drawn
+    pub full_spacing: f32,
+    /// Pixels between points below which the data is decimated
+    pub thin_spacing: f32,
+    /// Points kept per pixel of width when decimating
+    pub points_per_pixel: f32,
+}
+
+impl Default for DetailPolicy {
+    fn default() -> Self {
+        Self {
+            full_spacing: 4.0,
+            thin_spacing: 1.0,
+            points_per_pixel: 0.5,
+        }
+    }
+}
+
+impl DetailPolicy {
+    /// Plan the detail for `visible_points` drawn across `width` pixels
+    pub fn plan(&self, visible_points: usize, width: u32) -> DetailPlan {
+        let full = DetailPlan {
+            level: DetailLevel::Full,
+            max_points: visible_points,
+            marker_stride: 1,
+            show_labels: true,
+        };
+        if visible_points < 2 || width == 0 {
+            return full;
+        }
+
+        let spacing = width as f32 / visible_points as f32;
+        if spacing >= self.full_spacing {
+            return full;
+        }
+
+        if spacing >= self.thin_spacing {
+            return DetailPlan {
+                level: DetailLevel::Thinned,
+                max_points: visible_points,
+                marker_stride: self.stride_for(spacing),
+                show_labels: false,
+            };
+        }
+
+        let max_points = ((width as f32 * self.points_per_pixel) as usize)
+            .max(2)
+            .min(visible_points);
+        DetailPlan {
+            level: DetailLevel::Decimated,
+            max_points,
+            marker_stride: self.stride_for(width as f32 / max_points as f32),
+            show_labels: false,
+        }
+    }
+
+    /// Plan the detail for a series of `total_points` shown in `viewport`
+    ///
+    /// Zooming in by a factor shows proportionally fewer points across the
+    /// same width, so detail returns as the user zooms in.
+    pub fn for_viewport(&self, viewport: &Viewport, total_points: usize) -> DetailPlan {
+        let zoom = if viewport.zoom > 0.0 {
+            viewport.zoom
+        } else {
+            1.0
+        };
+        let visible = (total_points as f32 / zoom) as usize;
+        let visible = visible.clamp(total_points.min(2), total_points);
+        self.plan(visible, viewport.area.size.width)
+    }
+
+    /// Marker stride that keeps markers at least `full_spacing` apart
+    fn stride_for(&self, spacing: f32) -> usize {
+        if spacing <= 0.0 {
+            return 1;
+        }
+        let ratio = self.full_spacing / spacing;
+        let stride = ratio as usize;
+        if (stride as f32) < ratio {
+            stride + 1
+        } else {
+            stride.max(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::point::Point2D;
+    use crate::data::DataSeries;
+    use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+    #[test]
+    fn test_detail_levels_follow_spacing() {
+        let policy = DetailPolicy::default();
+
+        let full = policy.plan(20, 100);
+        assert_eq!(full.level, DetailLevel::Full);
+        assert!(full.show_labels);
+        assert_eq!(full.marker_stride, 1);
+
+        let thinned = policy.plan(50, 100);
+        assert_eq!(thinned.level, DetailLevel::Thinned);
+        assert!(!thinned.show_labels);
+        assert_eq!(thinned.marker_stride, 2);
+        assert_eq!(thinned.max_points, 50);
+
+        let decimated = policy.plan(1000, 100);
+        assert_eq!(decimated.level, DetailLevel::Decimated);
+        assert_eq!(decimated.max_points, 50);
+        assert_eq!(decimated.quality(), QualityProfile::PowerSaver);
+    }
+
+    #[test]
+    fn test_zooming_in_restores_detail() {
+        let policy = DetailPolicy::default();
+        let viewport = Viewport::new(Rectangle::new(Point::zero(), Size::new(100, 50)));
+
+        let zoomed_out = policy.for_viewport(&viewport, 40);
+        let zoomed_in = policy.for_viewport(&viewport.with_zoom(10.0), 40);
+        assert_eq!(zoomed_out.level, DetailLevel::Thinned);
+        assert_eq!(zoomed_in.level, DetailLevel::Full);
+
+        let mut series: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        for i in 0..256 {
+            series.push(Point2D::new(i as f32, (i % 7) as f32)).unwrap();
+        }
+        let plan = policy.plan(series.len(), 64);
+        let thinned: StaticDataSeries<Point2D, 256> = plan.thin(&series).unwrap();
+        assert!(thinned.len() >= 2 && thinned.len() <= plan.max_points);
+        assert_eq!(thinned.get(0), series.get(0));
+    }
+}
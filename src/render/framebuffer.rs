@@ -0,0 +1,285 @@
+//! Off-screen frame buffer with differential flushing.
+//!
+//! Charts draw into a [`ChartFrameBuffer`] like into any other display. The
+//! buffer also keeps a copy of the frame the display currently shows, and
+//! [`ChartFrameBuffer::flush_diff`] writes only what changed since the last
+//! flush: on each row, the run from the first to the last changed pixel.
+//! Animated dashboards behind a serial bus then transfer a few rows per
+//! frame instead of the whole screen.
+//!
+//! The buffer holds two frames, so it needs `2 * W * H` colors of memory and
+//! is usually placed in a `static`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::ChartFrameBuffer;
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+//!
+//! let chart: LineChart<Rgb565> = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//! let mut frame: ChartFrameBuffer<Rgb565, 64, 64> = ChartFrameBuffer::new(Rgb565::BLACK);
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! display.set_allow_overdraw(true);
+//!
+//! let data = data_points![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+//! chart.draw(&data, chart.config(), frame.bounding_box(), &mut frame)?;
+//!
+//! // The first flush writes the whole frame, the next one nothing
+//! assert_eq!(frame.flush_diff(&mut display), Ok(Some(frame.bounding_box())));
+//! assert_eq!(frame.flush_diff(&mut display), Ok(None));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::render::target::ChartDrawTarget;
+use core::convert::Infallible;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+
+/// Frame buffer of `W` by `H` pixels that flushes only changed pixels
+#[derive(Debug, Clone)]
+pub struct ChartFrameBuffer<C: PixelColor, const W: usize, const H: usize> {
+    /// Frame being drawn
+    frame: [[C; W]; H],
+    /// Frame last written to the display
+    shown: [[C; W]; H],
+    /// Whether `shown` matches the display
+    synced: bool,
+}
+
+impl<C: PixelColor, const W: usize, const H: usize> ChartFrameBuffer<C, W, H> {
+    /// Create a buffer filled with `background`
+    ///
+    /// The first flush writes the whole frame.
+    pub const fn new(background: C) -> Self {
+        Self {
+            frame: [[background; W]; H],
+            shown: [[background; W]; H],
+            synced: false,
+        }
+    }
+
+    /// Get the color of the pixel at `point` in the frame being drawn
+    pub fn pixel(&self, point: Point) -> Option<C> {
+        let (x, y) = Self::index(point)?;
+        self.frame.get(y)?.get(x).copied()
+    }
+
+    /// Make the next flush write the whole frame, e.g. after the display was
+    /// cleared by other code
+    pub fn invalidate(&mut self) {
+        self.synced = false;
+    }
+
+    /// Check whether the frame differs from what the display shows
+    pub fn has_changes(&self) -> bool {
+        !self.synced || self.frame != self.shown
+    }
+
+    /// Write the pixels that changed since the last flush to `display`
+    ///
+    /// Each row with changes is written as one run from its first to its
+    /// last changed pixel. Returns the bounding box of everything written,
+    /// or `None` if the display is already up to date.
+    pub fn flush_diff<D>(&mut self, display: &mut D) -> Result<Option<Rectangle>, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut dirty: Option<(Point, Point)> = None;
+
+        for (y, (row, shown)) in self.frame.iter().zip(self.shown.iter_mut()).enumerate() {
+            let run = if self.synced {
+                let changed = |(a, b): (&C, &C)| a != b;
+                let first = row.iter().zip(shown.iter()).position(changed);
+                let last = row.iter().zip(shown.iter()).rposition(changed);
+                first.zip(last)
+            } else {
+                (W > 0).then(|| (0, W - 1))
+            };
+            let Some((first, last)) = run else {
+                continue;
+            };
+
+            let (start, end) = (
+                Point::new(first as i32, y as i32),
+                Point::new(last as i32, y as i32),
+            );
+            display.fill_contiguous(
+                &Rectangle::with_corners(start, end),
+                row.iter().skip(first).take(last - first + 1).copied(),
+            )?;
+            *shown = *row;
+
+            dirty = Some(match dirty {
+                Some((top_left, bottom_right)) => (
+                    top_left.component_min(start),
+                    bottom_right.component_max(end),
+                ),
+                None => (start, end),
+            });
+        }
+
+        self.synced = true;
+        Ok(dirty.map(|(top_left, bottom_right)| Rectangle::with_corners(top_left, bottom_right)))
+    }
+
+    /// Convert a point to column and row indices, if it is inside the buffer
+    fn index(point: Point) -> Option<(usize, usize)> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < W)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < H)?;
+        Some((x, y))
+    }
+
+    /// Get up to `width` pixels of the frame, starting at `start`
+    fn span_mut(&mut self, start: Point, width: u32) -> Option<&mut [C]> {
+        let (x, y) = Self::index(start)?;
+        let row = self.frame.get_mut(y)?;
+        let end = x.saturating_add(width as usize).min(W);
+        row.get_mut(x..end)
+    }
+}
+
+impl<C: PixelColor, const W: usize, const H: usize> OriginDimensions for ChartFrameBuffer<C, W, H> {
+    fn size(&self) -> Size {
+        Size::new(W as u32, H as u32)
+    }
+}
+
+impl<C: PixelColor, const W: usize, const H: usize> DrawTarget for ChartFrameBuffer<C, W, H> {
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(pixel) = self.span_mut(point, 1).and_then(|span| span.first_mut()) {
+                *pixel = color;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        for y in area.rows() {
+            self.fill_span(Point::new(area.top_left.x, y), area.size.width, color)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: PixelColor, const W: usize, const H: usize> ChartDrawTarget for ChartFrameBuffer<C, W, H> {
+    fn fill_span(
+        &mut self,
+        start: Point,
+        width: u32,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        // Spans starting left of the buffer are clipped to its first column
+        let clipped = start.x.max(0);
+        let width = width.saturating_sub(clipped.abs_diff(start.x));
+        if let Some(span) = self.span_mut(Point::new(clipped, start.y), width) {
+            span.fill(color);
+        }
+        Ok(())
+    }
+
+    fn blit_row(&mut self, start: Point, colors: &[Self::Color]) -> Result<(), Self::Error> {
+        let skip = start.x.min(0).unsigned_abs() as usize;
+        let colors = colors.get(skip..).unwrap_or(&[]);
+        let start = Point::new(start.x.max(0), start.y);
+        if let Some(span) = self.span_mut(start, colors.len() as u32) {
+            for (pixel, color) in span.iter_mut().zip(colors) {
+                *pixel = *color;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    #[test]
+    fn test_first_flush_writes_everything() {
+        let mut frame: ChartFrameBuffer<Rgb565, 8, 4> = ChartFrameBuffer::new(Rgb565::BLACK);
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+
+        assert!(frame.has_changes());
+        assert_eq!(
+            frame.flush_diff(&mut display),
+            Ok(Some(Rectangle::new(Point::zero(), Size::new(8, 4))))
+        );
+        assert_eq!(display.affected_area(), frame.bounding_box());
+        assert!(!frame.has_changes());
+        assert_eq!(frame.flush_diff(&mut display), Ok(None));
+    }
+
+    #[test]
+    fn test_flush_writes_only_changed_runs() {
+        let mut frame: ChartFrameBuffer<Rgb565, 16, 8> = ChartFrameBuffer::new(Rgb565::BLACK);
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        frame.flush_diff(&mut display).unwrap();
+
+        frame
+            .draw_iter([
+                Pixel(Point::new(3, 2), Rgb565::RED),
+                Pixel(Point::new(9, 2), Rgb565::RED),
+            ])
+            .unwrap();
+        frame
+            .draw_iter([Pixel(Point::new(5, 6), Rgb565::RED)])
+            .unwrap();
+        assert_eq!(frame.pixel(Point::new(3, 2)), Some(Rgb565::RED));
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let dirty = frame.flush_diff(&mut display).unwrap();
+        assert_eq!(
+            dirty,
+            Some(Rectangle::with_corners(Point::new(3, 2), Point::new(9, 6)))
+        );
+        // Rows are written from the first to the last changed pixel
+        assert_eq!(display.get_pixel(Point::new(3, 2)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(6, 2)), Some(Rgb565::BLACK));
+        assert_eq!(display.get_pixel(Point::new(2, 2)), None);
+        assert_eq!(display.get_pixel(Point::new(5, 6)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(5, 4)), None);
+    }
+
+    #[test]
+    fn test_drawing_is_clipped() {
+        let mut frame: ChartFrameBuffer<Rgb565, 8, 4> = ChartFrameBuffer::new(Rgb565::BLACK);
+        Rectangle::new(Point::new(-2, 2), Size::new(20, 10))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+            .draw(&mut frame)
+            .unwrap();
+        frame
+            .blit_row(Point::new(-1, 0), &[Rgb565::RED, Rgb565::BLUE])
+            .unwrap();
+
+        assert_eq!(frame.pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+        assert_eq!(frame.pixel(Point::new(1, 0)), Some(Rgb565::BLACK));
+        assert_eq!(frame.pixel(Point::new(0, 2)), Some(Rgb565::GREEN));
+        assert_eq!(frame.pixel(Point::new(7, 3)), Some(Rgb565::GREEN));
+        assert_eq!(frame.pixel(Point::new(8, 3)), None);
+
+        frame.invalidate();
+        assert!(frame.has_changes());
+    }
+}
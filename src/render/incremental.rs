@@ -0,0 +1,309 @@
+//! Incremental rendering for real-time charts.
+//!
+//! Redrawing a whole chart every frame is slow on displays behind a serial
+//! bus. [`IncrementalRender`] remembers a digest of every column of the last
+//! frame; [`IncrementalDraw::draw_changes`] runs the chart's drawing code once
+//! against the digest, compares it with the previous frame and only erases and
+//! redraws the columns that changed. The returned dirty rectangle tells the
+//! application which part of its frame buffer to flush.
+//!
+//! Columns are compared in buckets when the viewport is wider than the
+//! capacity of the render state, so a narrow state still works on a wide
+//! display at the cost of coarser dirty regions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::{IncrementalDraw, IncrementalRender};
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+//!
+//! let chart: LineChart<Rgb565> = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//! let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! display.set_allow_overdraw(true);
+//!
+//! let mut render: IncrementalRender<Rgb565, 64> = IncrementalRender::new(Rgb565::BLACK);
+//! let data = data_points![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+//!
+//! // The first frame is drawn completely
+//! let dirty = chart.draw_changes(&data, chart.config(), viewport, &mut display, &mut render)?;
+//! assert_eq!(dirty, Some(viewport));
+//!
+//! // Unchanged data leaves the display untouched
+//! let dirty = chart.draw_changes(&data, chart.config(), viewport, &mut display, &mut render)?;
+//! assert_eq!(dirty, None);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::Chart;
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::IntoStorage, prelude::*, primitives::Rectangle,
+};
+use heapless::Vec;
+
+/// Column digests of the last frame drawn incrementally
+///
+/// `COLUMNS` is the number of digests kept; viewports wider than that are
+/// compared in buckets of several columns.
+#[derive(Debug, Clone)]
+pub struct IncrementalRender<C: PixelColor, const COLUMNS: usize = 320> {
+    background: C,
+    viewport: Option<Rectangle>,
+    columns: Vec<u32, COLUMNS>,
+}
+
+impl<C: PixelColor, const COLUMNS: usize> IncrementalRender<C, COLUMNS> {
+    /// Create the render state; changed columns are erased with `background`
+    pub const fn new(background: C) -> Self {
+        Self {
+            background,
+            viewport: None,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Get the color used to erase changed columns
+    pub fn background(&self) -> C {
+        self.background
+    }
+
+    /// Set the color used to erase changed columns and redraw everything next frame
+    pub fn set_background(&mut self, background: C) {
+        self.background = background;
+        self.invalidate();
+    }
+
+    /// Forget the last frame, so the next one is drawn completely
+    pub fn invalidate(&mut self) {
+        self.viewport = None;
+        self.columns.clear();
+    }
+
+    /// Number of viewport columns covered by one digest
+    fn bucket_width(viewport: Rectangle) -> u32 {
+        let capacity = COLUMNS.max(1) as u32;
+        viewport.size.width.div_ceil(capacity).max(1)
+    }
+
+    /// Store the digests of a new frame and return the area that changed
+    fn update(&mut self, viewport: Rectangle, columns: Vec<u32, COLUMNS>) -> Option<Rectangle> {
+        let previous = core::mem::replace(&mut self.columns, columns);
+        if self.viewport != Some(viewport) || previous.len() != self.columns.len() {
+            self.viewport = Some(viewport);
+            return Some(viewport);
+        }
+
+        let changed = |(old, new): (&u32, &u32)| old != new;
+        let pairs = || previous.iter().zip(self.columns.iter());
+        let first = pairs().position(changed)?;
+        let last = pairs().rposition(changed).unwrap_or(first);
+
+        let width = Self::bucket_width(viewport);
+        let left = first as u32 * width;
+        let right = ((last as u32 + 1) * width).min(viewport.size.width);
+        Some(Rectangle::new(
+            viewport.top_left + Point::new(left as i32, 0),
+            Size::new(right - left, viewport.size.height),
+        ))
+    }
+}
+
+/// Draw target that only records a digest of the pixels in each column
+struct ColumnDigest<C, const COLUMNS: usize> {
+    bounds: Rectangle,
+    viewport: Rectangle,
+    bucket_width: u32,
+    columns: Vec<u32, COLUMNS>,
+    _color: core::marker::PhantomData<C>,
+}
+
+impl<C: PixelColor, const COLUMNS: usize> ColumnDigest<C, COLUMNS> {
+    fn new(bounds: Rectangle, viewport: Rectangle) -> Self {
+        let bucket_width = IncrementalRender::<C, COLUMNS>::bucket_width(viewport);
+        let buckets = viewport.size.width.div_ceil(bucket_width) as usize;
+        let mut columns = Vec::new();
+        let _ = columns.resize(buckets.min(COLUMNS), 0);
+        Self {
+            bounds,
+            viewport,
+            bucket_width,
+            columns,
+            _color: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<C: PixelColor, const COLUMNS: usize> Dimensions for ColumnDigest<C, COLUMNS> {
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<C, const COLUMNS: usize> DrawTarget for ColumnDigest<C, COLUMNS>
+where
+    C: PixelColor + IntoStorage,
+    <C as IntoStorage>::Storage: Into<u32>,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if !self.viewport.contains(point) {
+                continue;
+            }
+            let offset = point - self.viewport.top_left;
+            let bucket = offset.x as u32 / self.bucket_width;
+            if let Some(digest) = self.columns.get_mut(bucket as usize) {
+                // Summing keeps the digest independent of the drawing order
+                *digest = digest.wrapping_add(mix(offset, color.into_storage().into()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hash one pixel into a column digest
+fn mix(offset: Point, color: u32) -> u32 {
+    let mut hash = 0x811c_9dc5u32;
+    for word in [offset.x as u32, offset.y as u32, color] {
+        for byte in word.to_le_bytes() {
+            hash = (hash ^ byte as u32).wrapping_mul(0x0100_0193);
+        }
+    }
+    // Final avalanche so sums of similar pixels don't cancel out
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x7feb_352d);
+    hash ^ (hash >> 15)
+}
+
+/// Draw any chart incrementally, see the [module documentation](self)
+pub trait IncrementalDraw<C>: Chart<C>
+where
+    C: PixelColor + IntoStorage,
+    <C as IntoStorage>::Storage: Into<u32>,
+{
+    /// Redraw the columns that changed since the last frame
+    ///
+    /// Returns the dirty rectangle that was redrawn, or `None` if the frame is
+    /// identical to the last one. The first frame, and every frame after the
+    /// viewport changed, is drawn completely.
+    fn draw_changes<D, const COLUMNS: usize>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+        render: &mut IncrementalRender<C, COLUMNS>,
+    ) -> ChartResult<Option<Rectangle>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut digest = ColumnDigest::<C, COLUMNS>::new(target.bounding_box(), viewport);
+        self.draw(data, config, viewport, &mut digest)?;
+
+        let Some(dirty) = render.update(viewport, digest.columns) else {
+            return Ok(None);
+        };
+
+        let mut clipped = target.clipped(&dirty);
+        clipped
+            .fill_solid(&dirty, render.background())
+            .map_err(|_| ChartError::RenderingError)?;
+        if let Err(error) = self.draw(data, config, viewport, &mut clipped) {
+            // The display now shows a partial frame, so start over next time
+            render.invalidate();
+            return Err(error);
+        }
+        Ok(Some(dirty))
+    }
+}
+
+impl<C, T: Chart<C>> IncrementalDraw<C> for T
+where
+    C: PixelColor + IntoStorage,
+    <C as IntoStorage>::Storage: Into<u32>,
+{
+}
+
+#[cfg(all(test, feature = "line"))]
+mod tests {
+    use super::*;
+    use crate::chart::traits::ChartBuilder;
+    use crate::chart::{LineChart, LineChartBuilder};
+    use crate::data::{Point2D, StaticDataSeries};
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    fn series(values: &[f32]) -> StaticDataSeries<Point2D, 256> {
+        let mut data = StaticDataSeries::new();
+        for (i, &y) in values.iter().enumerate() {
+            data.push(Point2D::new(i as f32, y)).unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn test_only_changed_columns_are_redrawn() {
+        let chart: LineChart<Rgb565> = LineChartBuilder::new()
+            .line_color(Rgb565::GREEN)
+            .build()
+            .unwrap();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let mut render: IncrementalRender<Rgb565, 64> = IncrementalRender::new(Rgb565::BLACK);
+        let mut display = MockDisplay::<Rgb565>::new();
+        display.set_allow_overdraw(true);
+
+        let before = series(&[10.0, 20.0, 15.0, 30.0, 25.0, 40.0, 35.0, 50.0]);
+        let dirty = chart
+            .draw_changes(&before, chart.config(), viewport, &mut display, &mut render)
+            .unwrap();
+        assert_eq!(dirty, Some(viewport));
+
+        // Changing a late point only touches the segments next to it
+        let after = series(&[10.0, 20.0, 15.0, 30.0, 25.0, 40.0, 30.0, 50.0]);
+        let dirty = chart
+            .draw_changes(&after, chart.config(), viewport, &mut display, &mut render)
+            .unwrap()
+            .unwrap();
+        assert!(dirty.top_left.x > 32);
+        assert_eq!(dirty.size.height, 64);
+
+        // The display matches a full redraw of the new data
+        let mut expected = MockDisplay::<Rgb565>::new();
+        expected.set_allow_overdraw(true);
+        viewport
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
+            .draw(&mut expected)
+            .unwrap();
+        chart
+            .draw(&after, chart.config(), viewport, &mut expected)
+            .unwrap();
+        display.assert_eq(&expected);
+
+        let dirty = chart
+            .draw_changes(&after, chart.config(), viewport, &mut display, &mut render)
+            .unwrap();
+        assert_eq!(dirty, None);
+    }
+
+    #[test]
+    fn test_wide_viewport_uses_buckets() {
+        let viewport = Rectangle::new(Point::new(4, 0), Size::new(40, 10));
+        let mut render: IncrementalRender<Rgb565, 8> = IncrementalRender::new(Rgb565::BLACK);
+
+        let mut columns = Vec::new();
+        columns.resize(8, 0).unwrap();
+        assert_eq!(render.update(viewport, columns.clone()), Some(viewport));
+
+        columns[2] = 1;
+        let dirty = render.update(viewport, columns).unwrap();
+        assert_eq!(dirty, Rectangle::new(Point::new(14, 0), Size::new(5, 10)));
+    }
+}
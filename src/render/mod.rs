@@ -4,9 +4,37 @@
 //! - Base rendering operations
 //! - Display-specific optimizations
 //! - Performance optimizations for embedded systems
+//! - Accelerated draw target fast paths
+//! - Span rasterization shared by filled shapes
+//! - Dashed and dotted strokes that keep their pattern across segments
+//! - Polyline simplification before stroking
+//! - Decimation of dense series to the plot width
+//! - Quality profiles for reduced-power rendering
+//! - Zoom-dependent level of detail
+//! - Time-sliced rendering for cooperative schedulers
+//! - Incremental rendering of changed columns for real-time charts
+//! - Off-screen frame buffers that flush only changed pixels
+//! - Tracking of the regions a draw call touched
+//! - Terminal output for headless debugging (std only)
+//! - RGBA screenshots for documentation (std only)
 
 mod base;
+pub mod dash;
+pub mod decimate;
+pub mod detail;
+pub mod framebuffer;
+pub mod incremental;
 pub mod optimized;
+pub mod partial;
+pub mod quality;
+pub mod regions;
+#[cfg(feature = "std")]
+pub mod screenshot;
+pub mod simplify;
+pub mod span;
+pub mod target;
+#[cfg(feature = "std")]
+pub mod terminal;
 
 // Re-export the text module from base
 pub use base::text;
@@ -17,5 +45,22 @@ pub use base::{ChartRenderer, ClippingRenderer, EnhancedChartRenderer, Primitive
 #[cfg(feature = "animations")]
 pub use base::AnimationFrameRenderer;
 
+// Re-export span rasterization, simplification and draw target extensions
+pub use dash::DashStroke;
+pub use decimate::Decimation;
+pub use detail::{DetailLevel, DetailPlan, DetailPolicy};
+pub use framebuffer::ChartFrameBuffer;
+pub use incremental::{IncrementalDraw, IncrementalRender};
+pub use partial::{PartialDraw, PartialRender, RenderBudget, RenderProgress, SliceTarget};
+pub use quality::QualityProfile;
+pub use regions::{DirtyRegions, RegionDraw, RegionTracker};
+#[cfg(feature = "std")]
+pub use screenshot::{render_to_rgba_buffer, ChartView, RgbaBuffer};
+pub use simplify::PolylineSimplifier;
+pub use span::SpanRasterizer;
+pub use target::{ChartDrawTarget, FallbackTarget};
+#[cfg(feature = "std")]
+pub use terminal::{TerminalDisplay, TerminalMode};
+
 // Re-export optimized rendering
 pub use optimized::{DisplayType, EPaperRenderer, OLEDRenderer, OptimizedRenderer, TFTRenderer};
@@ -0,0 +1,354 @@
+//! Time-sliced chart rendering for cooperative schedulers.
+//!
+//! A full-screen chart can take longer to draw than one pass of a superloop.
+//! [`PartialRender`] spreads a render over several passes: each call to
+//! [`PartialDraw::draw_partial`] runs the chart's normal drawing code but only
+//! lets a limited number of pixels or primitives through to the display, and
+//! reports whether the frame is [`RenderProgress::Complete`] or still
+//! [`RenderProgress::Pending`]. The next call picks up where the last one
+//! stopped, so no RTOS or extra frame buffer is needed.
+//!
+//! Every slice re-runs the drawing code and skips what earlier slices already
+//! wrote, so the chart, its data and the viewport must not change until the
+//! frame is complete. Call [`PartialRender::restart`] to start over after a
+//! change.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::{PartialDraw, PartialRender, RenderBudget, RenderProgress};
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+//!
+//! let chart: LineChart<Rgb565> = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//! let data = data_points![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+//! let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+//!
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! display.set_allow_overdraw(true);
+//! let mut render = PartialRender::new(RenderBudget::Pixels(100));
+//!
+//! // One slice per loop iteration
+//! while chart.draw_partial(&data, chart.config(), viewport, &mut display, &mut render)?
+//!     == RenderProgress::Pending
+//! {
+//!     // ... service other tasks ...
+//! }
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::Chart;
+use crate::error::ChartResult;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+
+/// Amount of drawing allowed per slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderBudget {
+    /// Number of pixels written to the display
+    Pixels(u32),
+    /// Number of draw calls (pixel batches, rectangle fills) passed to the display
+    Primitives(u32),
+}
+
+impl RenderBudget {
+    /// Number of units allowed per slice, at least one
+    fn units(self) -> u32 {
+        match self {
+            Self::Pixels(units) | Self::Primitives(units) => units.max(1),
+        }
+    }
+}
+
+/// Result of drawing one slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderProgress {
+    /// More slices are needed to finish the frame
+    Pending,
+    /// The whole frame has been drawn
+    Complete,
+}
+
+/// State of a render spread over several slices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialRender {
+    budget: RenderBudget,
+    /// Units written by earlier slices of the current frame
+    done: u32,
+    complete: bool,
+}
+
+impl PartialRender {
+    /// Start a frame with the given budget per slice
+    pub const fn new(budget: RenderBudget) -> Self {
+        Self {
+            budget,
+            done: 0,
+            complete: false,
+        }
+    }
+
+    /// Get the budget per slice
+    pub fn budget(&self) -> RenderBudget {
+        self.budget
+    }
+
+    /// Change the budget per slice; takes effect with the next slice
+    pub fn set_budget(&mut self, budget: RenderBudget) {
+        if core::mem::discriminant(&budget) != core::mem::discriminant(&self.budget) {
+            // Progress is counted in the old unit, so the frame starts over
+            self.restart();
+        }
+        self.budget = budget;
+    }
+
+    /// Start the frame over, e.g. after the data changed
+    pub fn restart(&mut self) {
+        self.done = 0;
+        self.complete = false;
+    }
+
+    /// Check whether the frame has been drawn completely
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Units written so far in the current frame
+    pub fn progress(&self) -> u32 {
+        self.done
+    }
+
+    /// Draw the next slice of a frame produced by `draw`
+    ///
+    /// `draw` must produce the same drawing operations on every call until the
+    /// frame is complete. Once complete, further calls draw nothing until
+    /// [`PartialRender::restart`] is called.
+    pub fn draw<D, F>(&mut self, target: &mut D, draw: F) -> ChartResult<RenderProgress>
+    where
+        D: DrawTarget,
+        F: FnOnce(&mut SliceTarget<'_, D>) -> ChartResult<()>,
+    {
+        if self.complete {
+            return Ok(RenderProgress::Complete);
+        }
+
+        let end = self.done.saturating_add(self.budget.units());
+        let mut slice = SliceTarget {
+            target,
+            pixels: matches!(self.budget, RenderBudget::Pixels(_)),
+            start: self.done,
+            end,
+            count: 0,
+        };
+        draw(&mut slice)?;
+
+        if slice.count <= end {
+            self.done = slice.count;
+            self.complete = true;
+            Ok(RenderProgress::Complete)
+        } else {
+            self.done = end;
+            Ok(RenderProgress::Pending)
+        }
+    }
+}
+
+/// Draw target passing only the current slice of a frame to the display
+///
+/// Created by [`PartialRender::draw`].
+#[derive(Debug)]
+pub struct SliceTarget<'a, D> {
+    target: &'a mut D,
+    /// Count pixels rather than draw calls
+    pixels: bool,
+    /// First unit of the slice
+    start: u32,
+    /// First unit after the slice
+    end: u32,
+    /// Units produced so far by the drawing code
+    count: u32,
+}
+
+impl<D> SliceTarget<'_, D> {
+    /// Count `units` and return how they overlap the slice: (skip, take)
+    fn claim(&mut self, units: u32) -> (u32, u32) {
+        let first = self.count;
+        self.count = self.count.saturating_add(units);
+        let from = first.max(self.start);
+        let to = self.count.min(self.end);
+        if to <= from {
+            (0, 0)
+        } else {
+            (from - first, to - from)
+        }
+    }
+
+    /// Claim one draw call and check whether it falls into the slice
+    fn claim_primitive(&mut self) -> bool {
+        self.claim(1).1 == 1
+    }
+}
+
+impl<D: DrawTarget> Dimensions for SliceTarget<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for SliceTarget<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if !self.pixels {
+            return if self.claim_primitive() {
+                self.target.draw_iter(pixels)
+            } else {
+                Ok(())
+            };
+        }
+
+        let (start, end) = (self.start, self.end);
+        let count = &mut self.count;
+        self.target.draw_iter(pixels.into_iter().filter(|_| {
+            let index = *count;
+            *count = count.saturating_add(1);
+            index >= start && index < end
+        }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if !self.pixels {
+            return if self.claim_primitive() {
+                self.target.fill_contiguous(area, colors)
+            } else {
+                Ok(())
+            };
+        }
+
+        let (skip, take) = self.claim(area.size.width.saturating_mul(area.size.height));
+        if take == 0 {
+            return Ok(());
+        }
+        let pixels = area
+            .points()
+            .zip(colors)
+            .skip(skip as usize)
+            .take(take as usize)
+            .map(|(point, color)| Pixel(point, color));
+        self.target.draw_iter(pixels)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        if !self.pixels {
+            return if self.claim_primitive() {
+                self.target.fill_solid(area, color)
+            } else {
+                Ok(())
+            };
+        }
+
+        let total = area.size.width.saturating_mul(area.size.height);
+        let (skip, take) = self.claim(total);
+        if take == total {
+            // The whole rectangle fits, keep the display's fast path
+            return self.target.fill_solid(area, color);
+        }
+        if take == 0 {
+            return Ok(());
+        }
+        let pixels = area
+            .points()
+            .skip(skip as usize)
+            .take(take as usize)
+            .map(|point| Pixel(point, color));
+        self.target.draw_iter(pixels)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.target.bounding_box();
+        self.fill_solid(&area, color)
+    }
+}
+
+/// Draw any chart in slices, see the [module documentation](self)
+pub trait PartialDraw<C: PixelColor>: Chart<C> {
+    /// Draw the next slice of the chart into `target`
+    fn draw_partial<D>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+        render: &mut PartialRender,
+    ) -> ChartResult<RenderProgress>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        render.draw(target, |slice| self.draw(data, config, viewport, slice))
+    }
+}
+
+impl<C: PixelColor, T: Chart<C>> PartialDraw<C> for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+    use embedded_graphics::primitives::{PrimitiveStyle, StyledDrawable};
+
+    fn scene<D: DrawTarget<Color = Rgb565>>(target: &mut D) -> ChartResult<()> {
+        let style = PrimitiveStyle::with_fill(Rgb565::RED);
+        for y in [0, 4, 8] {
+            Rectangle::new(Point::new(0, y), Size::new(4, 2))
+                .draw_styled(&style, target)
+                .map_err(|_| crate::error::ChartError::RenderingError)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pixel_slices_match_full_render() {
+        let mut expected = MockDisplay::<Rgb565>::new();
+        scene(&mut expected).unwrap();
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        let mut render = PartialRender::new(RenderBudget::Pixels(5));
+        let mut slices = 0;
+        while render.draw(&mut display, |target| scene(target)).unwrap() == RenderProgress::Pending
+        {
+            slices += 1;
+        }
+
+        // 24 pixels in slices of 5
+        assert_eq!(slices, 4);
+        assert_eq!(render.progress(), 24);
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn test_primitive_slices() {
+        let mut display = MockDisplay::<Rgb565>::new();
+        let mut render = PartialRender::new(RenderBudget::Primitives(2));
+
+        assert_eq!(
+            render.draw(&mut display, |target| scene(target)).unwrap(),
+            RenderProgress::Pending
+        );
+        assert_eq!(display.get_pixel(Point::new(0, 4)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(0, 8)), None);
+
+        assert_eq!(
+            render.draw(&mut display, |target| scene(target)).unwrap(),
+            RenderProgress::Complete
+        );
+        assert!(render.is_complete());
+        assert_eq!(display.get_pixel(Point::new(0, 8)), Some(Rgb565::RED));
+    }
+}
@@ -0,0 +1,136 @@
+//! Rendering quality profiles.
+//!
+//! A [`QualityProfile`] trades visual fidelity for CPU time and therefore
+//! power. It can be set globally with [`QualityProfile::set_global`], e.g. when
+//! the battery runs low, and overridden per chart. Charts read the profile on
+//! every draw, so switching takes effect on the next frame.
+//!
+//! | Profile      | Smoothing     | Gradients | Anti-aliasing | Markers      | Frame rate |
+//! |--------------|---------------|-----------|---------------|--------------|------------|
+//! | `Full`       | as configured | yes       | yes           | every point  | as set     |
+//! | `Balanced`   | halved        | no        | yes           | every 2nd    | max 30 fps |
+//! | `PowerSaver` | none          | no        | no            | every 4th    | max 10 fps |
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Global profile used by charts without their own override
+static GLOBAL_PROFILE: AtomicU8 = AtomicU8::new(QualityProfile::Full as u8);
+
+/// Rendering quality profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum QualityProfile {
+    /// Render everything as configured
+    #[default]
+    Full = 0,
+    /// Cheaper smoothing and fills, fewer markers
+    Balanced = 1,
+    /// Minimal work per frame for low battery
+    PowerSaver = 2,
+}
+
+impl QualityProfile {
+    /// Get the global profile
+    pub fn global() -> Self {
+        match GLOBAL_PROFILE.load(Ordering::Relaxed) {
+            1 => Self::Balanced,
+            2 => Self::PowerSaver,
+            _ => Self::Full,
+        }
+    }
+
+    /// Set the global profile used by all charts without an override
+    pub fn set_global(profile: Self) {
+        GLOBAL_PROFILE.store(profile as u8, Ordering::Relaxed);
+    }
+
+    /// Resolve a per-chart override against the global profile
+    pub fn resolve(override_profile: Option<Self>) -> Self {
+        override_profile.unwrap_or_else(Self::global)
+    }
+
+    /// Number of curve subdivisions to use instead of `requested`.
+    ///
+    /// A result of 1 means data points are connected by straight lines.
+    pub fn subdivisions(self, requested: u32) -> u32 {
+        match self {
+            Self::Full => requested,
+            Self::Balanced => (requested / 2).max(2).min(requested),
+            Self::PowerSaver => 1,
+        }
+    }
+
+    /// Whether smoothing should be applied at all
+    pub fn smoothing(self) -> bool {
+        self != Self::PowerSaver
+    }
+
+    /// Whether gradient fills are drawn; otherwise a solid color is used
+    pub fn gradients(self) -> bool {
+        self == Self::Full
+    }
+
+    /// Whether anti-aliased drawing should be used by renderers that support it
+    pub fn anti_aliasing(self) -> bool {
+        self != Self::PowerSaver
+    }
+
+    /// Draw a marker for every n-th data point
+    pub fn marker_stride(self) -> usize {
+        match self {
+            Self::Full => 1,
+            Self::Balanced => 2,
+            Self::PowerSaver => 4,
+        }
+    }
+
+    /// Animation frame rate to use instead of `requested`
+    pub fn frame_rate(self, requested: u32) -> u32 {
+        match self {
+            Self::Full => requested,
+            Self::Balanced => requested.min(30),
+            Self::PowerSaver => requested.min(10),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_parameters() {
+        assert_eq!(QualityProfile::Full.subdivisions(8), 8);
+        assert_eq!(QualityProfile::Balanced.subdivisions(8), 4);
+        assert_eq!(QualityProfile::Balanced.subdivisions(1), 1);
+        assert_eq!(QualityProfile::PowerSaver.subdivisions(8), 1);
+
+        assert!(QualityProfile::Full.gradients());
+        assert!(!QualityProfile::Balanced.gradients());
+        assert!(!QualityProfile::PowerSaver.anti_aliasing());
+
+        assert_eq!(QualityProfile::Balanced.marker_stride(), 2);
+        assert_eq!(QualityProfile::Balanced.frame_rate(60), 30);
+        assert_eq!(QualityProfile::PowerSaver.frame_rate(5), 5);
+    }
+
+    #[test]
+    fn test_resolve_prefers_override() {
+        // Only the override path is checked; the global may be changed by
+        // other tests running in parallel
+        assert_eq!(
+            QualityProfile::resolve(Some(QualityProfile::PowerSaver)),
+            QualityProfile::PowerSaver
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Regions touched by a draw call.
+//!
+//! Displays that refresh slowly or per window, such as e-paper panels or
+//! DMA2D frame buffers, only need to update what a chart actually drew.
+//! [`RegionTracker`] wraps a draw target and records the areas that were
+//! written to as a small set of [`DirtyRegions`], and [`RegionDraw`] draws any
+//! chart through it.
+//!
+//! Charts painting a background touch their whole viewport. Combine the
+//! tracker with a transparent background, or use
+//! [`Chart::invalidate`](crate::chart::traits::Chart::invalidate) to find the
+//! area a data change affects before drawing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::{DirtyRegions, RegionDraw};
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+//!
+//! let chart: LineChart<Rgb565> = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//! let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! display.set_allow_overdraw(true);
+//!
+//! let data = data_points![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+//! let regions: DirtyRegions = chart.draw_regions(&data, chart.config(), viewport, &mut display)?;
+//! assert_eq!(regions.bounding_box(), Some(display.affected_area()));
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::Chart;
+use crate::error::ChartResult;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*, primitives::Rectangle};
+use heapless::Vec;
+
+/// Small set of rectangles covering the pixels that were drawn
+///
+/// Touching or overlapping rectangles are merged. When all `N` slots are in
+/// use, a new rectangle is merged into the one it enlarges least, so the set
+/// always covers everything that was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirtyRegions<const N: usize = 4> {
+    regions: Vec<Rectangle, N>,
+}
+
+impl<const N: usize> DirtyRegions<N> {
+    /// Create an empty set
+    pub const fn new() -> Self {
+        Self {
+            regions: Vec::new(),
+        }
+    }
+
+    /// Add `area` to the set
+    pub fn add(&mut self, area: Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+
+        // Absorb every region the area touches, growing it as it goes
+        let mut area = area;
+        while let Some(index) = self
+            .regions
+            .iter()
+            .position(|region| touches(region, &area))
+        {
+            area = union(&area, &self.regions.swap_remove(index));
+        }
+
+        if let Err(area) = self.regions.push(area) {
+            let closest = self
+                .regions
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, region)| pixel_count(&union(region, &area)))
+                .map(|(index, _)| index);
+            // With N == 0 there is nothing to merge into and nothing is recorded
+            if let Some(index) = closest {
+                let merged = union(&area, &self.regions.swap_remove(index));
+                self.add(merged);
+            }
+        }
+    }
+
+    /// Get the rectangles of the set
+    pub fn regions(&self) -> &[Rectangle] {
+        &self.regions
+    }
+
+    /// Get the rectangle enclosing the whole set, if it isn't empty
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        let (first, rest) = self.regions.split_first()?;
+        Some(rest.iter().fold(*first, |acc, region| union(&acc, region)))
+    }
+
+    /// Check whether nothing was added
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Remove all rectangles
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+}
+
+impl<const N: usize> Default for DirtyRegions<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draw target that forwards to another one and records what was drawn
+///
+/// Pixels outside the wrapped target's bounds aren't recorded.
+#[derive(Debug)]
+pub struct RegionTracker<'a, D, const N: usize = 4> {
+    target: &'a mut D,
+    regions: DirtyRegions<N>,
+}
+
+impl<'a, D: DrawTarget, const N: usize> RegionTracker<'a, D, N> {
+    /// Wrap a draw target
+    pub fn new(target: &'a mut D) -> Self {
+        Self {
+            target,
+            regions: DirtyRegions::new(),
+        }
+    }
+
+    /// Get the regions drawn so far
+    pub fn regions(&self) -> &DirtyRegions<N> {
+        &self.regions
+    }
+
+    /// Stop tracking and return the regions that were drawn
+    pub fn into_regions(self) -> DirtyRegions<N> {
+        self.regions
+    }
+}
+
+impl<D: DrawTarget, const N: usize> Dimensions for RegionTracker<'_, D, N> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget, const N: usize> DrawTarget for RegionTracker<'_, D, N> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounds = self.target.bounding_box();
+        let mut corners: Option<(Point, Point)> = None;
+        let result = self
+            .target
+            .draw_iter(pixels.into_iter().inspect(|Pixel(point, _)| {
+                if bounds.contains(*point) {
+                    corners = Some(match corners {
+                        Some((min, max)) => (min.component_min(*point), max.component_max(*point)),
+                        None => (*point, *point),
+                    });
+                }
+            }));
+        if let Some((min, max)) = corners {
+            self.regions.add(Rectangle::with_corners(min, max));
+        }
+        result
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.regions
+            .add(area.intersection(&self.target.bounding_box()));
+        self.target.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.regions
+            .add(area.intersection(&self.target.bounding_box()));
+        self.target.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.regions.add(self.target.bounding_box());
+        self.target.clear(color)
+    }
+}
+
+/// Draw any chart and report where it drew, see the [module documentation](self)
+pub trait RegionDraw<C: PixelColor>: Chart<C> {
+    /// Draw the chart and return the regions it touched
+    fn draw_regions<D, const N: usize>(
+        &self,
+        data: &Self::Data,
+        config: &Self::Config,
+        viewport: Rectangle,
+        target: &mut D,
+    ) -> ChartResult<DirtyRegions<N>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut tracker = RegionTracker::new(target);
+        self.draw(data, config, viewport, &mut tracker)?;
+        Ok(tracker.into_regions())
+    }
+}
+
+impl<C: PixelColor, T: Chart<C>> RegionDraw<C> for T {}
+
+/// Check whether two non-empty rectangles overlap or share an edge
+fn touches(a: &Rectangle, b: &Rectangle) -> bool {
+    let grown = Rectangle::new(a.top_left - Point::new(1, 1), a.size + Size::new(2, 2));
+    !grown.intersection(b).is_zero_sized()
+}
+
+/// Get the smallest rectangle containing two non-empty rectangles
+fn union(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    let bottom_right = |r: &Rectangle| r.bottom_right().unwrap_or(r.top_left);
+    Rectangle::with_corners(
+        a.top_left.component_min(b.top_left),
+        bottom_right(a).component_max(bottom_right(b)),
+    )
+}
+
+/// Number of pixels in a rectangle
+fn pixel_count(area: &Rectangle) -> u64 {
+    u64::from(area.size.width) * u64::from(area.size.height)
+}
+
+#[cfg(all(test, feature = "line"))]
+mod tests {
+    use super::*;
+    use crate::chart::traits::{ChartBuilder, DataChange};
+    use crate::chart::LineChart;
+    use crate::data::series::StaticDataSeries;
+    use crate::data::Point2D;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics::primitives::{Line, PrimitiveStyle};
+
+    #[test]
+    fn test_regions_merge_when_touching() {
+        let mut regions: DirtyRegions<2> = DirtyRegions::new();
+        regions.add(Rectangle::new(Point::new(0, 0), Size::new(4, 4)));
+        regions.add(Rectangle::new(Point::new(4, 0), Size::new(2, 2)));
+        assert_eq!(
+            regions.regions(),
+            &[Rectangle::new(Point::new(0, 0), Size::new(6, 4))]
+        );
+
+        regions.add(Rectangle::new(Point::new(20, 20), Size::new(2, 2)));
+        assert_eq!(regions.regions().len(), 2);
+
+        // A third separate area is merged into the nearest one
+        regions.add(Rectangle::new(Point::new(24, 20), Size::new(2, 2)));
+        assert_eq!(regions.regions().len(), 2);
+        assert!(regions
+            .regions()
+            .contains(&Rectangle::new(Point::new(20, 20), Size::new(6, 2))));
+        assert_eq!(
+            regions.bounding_box(),
+            Some(Rectangle::new(Point::zero(), Size::new(26, 22)))
+        );
+    }
+
+    #[test]
+    fn test_tracker_records_drawn_pixels() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let mut tracker: RegionTracker<_, 4> = RegionTracker::new(&mut display);
+        Line::new(Point::new(2, 3), Point::new(10, 3))
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+            .draw(&mut tracker)
+            .unwrap();
+        Line::new(Point::new(40, 40), Point::new(40, 60))
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+            .draw(&mut tracker)
+            .unwrap();
+
+        let regions = tracker.into_regions();
+        assert_eq!(
+            regions.regions(),
+            &[
+                Rectangle::with_corners(Point::new(2, 3), Point::new(10, 3)),
+                Rectangle::with_corners(Point::new(40, 40), Point::new(40, 60)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chart_regions_match_drawn_area() {
+        let chart: LineChart<Rgb565> = LineChart::builder().build().unwrap();
+        let mut data: StaticDataSeries<Point2D, 256> = StaticDataSeries::new();
+        data.push(Point2D::new(0.0, 1.0)).unwrap();
+        data.push(Point2D::new(1.0, 2.0)).unwrap();
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let viewport = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let regions: DirtyRegions = chart
+            .draw_regions(&data, chart.config(), viewport, &mut display)
+            .unwrap();
+        assert_eq!(regions.bounding_box(), Some(display.affected_area()));
+        assert_eq!(
+            chart.invalidate(&data, DataChange::Replaced, viewport),
+            viewport
+        );
+    }
+}
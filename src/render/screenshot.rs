@@ -0,0 +1,221 @@
+//! Screenshot rendering for documentation.
+//!
+//! [`render_to_rgba_buffer`] draws a chart into an in-memory RGBA8 image, so
+//! doc tests and documentation or website generators can produce pixel-exact
+//! screenshots without the simulator or the example capture scripts. The
+//! returned buffer is row-major with four bytes per pixel and can be handed
+//! directly to an image encoder such as `image::RgbaImage::from_raw`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "line")]
+//! # {
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::render_to_rgba_buffer;
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let data = data_points![(0.0, 0.0), (1.0, 3.0), (2.0, 1.0), (3.0, 4.0)];
+//! let chart = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//!
+//! let rgba = render_to_rgba_buffer(&(&chart, &data, chart.config()), Size::new(160, 120))?;
+//! assert_eq!(rgba.len(), 160 * 120 * 4);
+//! # }
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::chart::traits::Chart;
+use crate::error::{ChartError, ChartResult};
+use core::convert::Infallible;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::{Rgb888, RgbColor},
+    prelude::*,
+    primitives::Rectangle,
+};
+use std::vec::Vec;
+
+/// Something that can be drawn into a screenshot.
+///
+/// Implemented for `(&chart, &data, &config)` tuples of any [`Chart`];
+/// implement it for composite views such as dashboards.
+pub trait ChartView<C: PixelColor> {
+    /// Draw the view into `viewport`
+    fn draw_view<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>;
+}
+
+impl<C, CH> ChartView<C> for (&CH, &CH::Data, &CH::Config)
+where
+    C: PixelColor,
+    CH: Chart<C>,
+{
+    fn draw_view<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let (chart, data, config) = *self;
+        chart.draw(data, config, viewport, target)
+    }
+}
+
+/// Draw target backed by an RGBA8 pixel buffer.
+#[derive(Debug, Clone)]
+pub struct RgbaBuffer<C: PixelColor> {
+    size: Size,
+    pixels: Vec<u8>,
+    _color: core::marker::PhantomData<C>,
+}
+
+impl<C> RgbaBuffer<C>
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    /// Create a buffer filled with opaque white
+    pub fn new(size: Size) -> Self {
+        Self::with_background(size, Rgb888::WHITE)
+    }
+
+    /// Create a buffer filled with an opaque background color
+    pub fn with_background(size: Size, background: Rgb888) -> Self {
+        let count = size.width as usize * size.height as usize;
+        let mut pixels = Vec::with_capacity(count * 4);
+        for _ in 0..count {
+            pixels.extend_from_slice(&[background.r(), background.g(), background.b(), 255]);
+        }
+        Self {
+            size,
+            pixels,
+            _color: core::marker::PhantomData,
+        }
+    }
+
+    /// Get the RGBA bytes of the pixel at `point`
+    pub fn pixel(&self, point: Point) -> Option<[u8; 4]> {
+        self.index(point).map(|index| {
+            let mut rgba = [0; 4];
+            rgba.copy_from_slice(&self.pixels[index..index + 4]);
+            rgba
+        })
+    }
+
+    /// Get the raw buffer, row-major with four bytes per pixel
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Consume the buffer and return its bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        if point.x < 0 || point.y < 0 {
+            return None;
+        }
+        let (x, y) = (point.x as u32, point.y as u32);
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        Some((y as usize * self.size.width as usize + x as usize) * 4)
+    }
+}
+
+impl<C: PixelColor> OriginDimensions for RgbaBuffer<C> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<C> DrawTarget for RgbaBuffer<C>
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(index) = self.index(point) {
+                let color: Rgb888 = color.into();
+                self.pixels[index..index + 4].copy_from_slice(&[
+                    color.r(),
+                    color.g(),
+                    color.b(),
+                    255,
+                ]);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Render a chart view into an RGBA8 buffer of `size` pixels.
+///
+/// The view is drawn over an opaque white background covering the whole
+/// buffer. The result has `size.width * size.height * 4` bytes in row-major
+/// order.
+pub fn render_to_rgba_buffer<C, V>(chart_view: &V, size: Size) -> ChartResult<Vec<u8>>
+where
+    C: PixelColor + Into<Rgb888>,
+    V: ChartView<C>,
+{
+    if size.width == 0 || size.height == 0 {
+        return Err(ChartError::InvalidConfiguration);
+    }
+    let mut buffer = RgbaBuffer::new(size);
+    chart_view.draw_view(buffer.bounding_box(), &mut buffer)?;
+    Ok(buffer.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::Rgb565;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    struct Block;
+
+    impl ChartView<Rgb565> for Block {
+        fn draw_view<D>(&self, viewport: Rectangle, target: &mut D) -> ChartResult<()>
+        where
+            D: DrawTarget<Color = Rgb565>,
+        {
+            Rectangle::new(viewport.top_left, Size::new(2, 1))
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+                .draw(target)
+                .map_err(|_| ChartError::RenderingError)
+        }
+    }
+
+    #[test]
+    fn test_render_to_rgba_buffer() {
+        let rgba = render_to_rgba_buffer(&Block, Size::new(3, 2)).unwrap();
+        assert_eq!(rgba.len(), 3 * 2 * 4);
+        assert_eq!(&rgba[0..8], &[255, 0, 0, 255, 255, 0, 0, 255]);
+        assert_eq!(&rgba[8..12], &[255, 255, 255, 255]);
+        assert_eq!(&rgba[20..24], &[255, 255, 255, 255]);
+
+        assert_eq!(
+            render_to_rgba_buffer(&Block, Size::new(0, 2)),
+            Err(ChartError::InvalidConfiguration)
+        );
+    }
+
+    #[test]
+    fn test_buffer_ignores_out_of_bounds_pixels() {
+        let mut buffer: RgbaBuffer<Rgb565> = RgbaBuffer::new(Size::new(2, 2));
+        Pixel(Point::new(5, 0), Rgb565::BLUE)
+            .draw(&mut buffer)
+            .unwrap();
+        Pixel(Point::new(1, 1), Rgb565::BLUE)
+            .draw(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer.pixel(Point::new(1, 1)), Some([0, 0, 255, 255]));
+        assert_eq!(buffer.pixel(Point::new(-1, 0)), None);
+    }
+}
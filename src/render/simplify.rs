@@ -0,0 +1,211 @@
+//! Polyline simplification for stroking long lines.
+//!
+//! High-resolution data often maps several consecutive samples onto nearly the
+//! same screen line. [`PolylineSimplifier`] applies the Ramer–Douglas–Peucker
+//! algorithm in screen space, dropping vertices that lie within `epsilon` pixels
+//! of the simplified line, which reduces the number of segments drawn with
+//! negligible visual change.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::render::PolylineSimplifier;
+//! use embedded_graphics::prelude::*;
+//!
+//! let points = [
+//!     Point::new(0, 0),
+//!     Point::new(5, 0),
+//!     Point::new(10, 1),
+//!     Point::new(20, 0),
+//! ];
+//! let simplified: heapless::Vec<Point, 8> = PolylineSimplifier::new(1.5).simplify(&points)?;
+//! assert_eq!(simplified.as_slice(), &[Point::new(0, 0), Point::new(20, 0)]);
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::error::{ChartError, ChartResult};
+use embedded_graphics::prelude::*;
+
+/// Maximum number of pending segments tracked while simplifying.
+///
+/// Segments that don't fit are kept unsimplified rather than dropped.
+const MAX_PENDING_SEGMENTS: usize = 32;
+
+/// Ramer–Douglas–Peucker polyline simplifier working in screen space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolylineSimplifier {
+    /// Maximum distance in pixels a removed vertex may lie from the result
+    epsilon: f32,
+}
+
+impl PolylineSimplifier {
+    /// Create a simplifier with the given tolerance
+    ///
+    /// # Arguments
+    /// * `epsilon` - Maximum distance in pixels between a removed vertex and the simplified line
+    pub fn new(epsilon: f32) -> Self {
+        Self {
+            epsilon: epsilon.max(0.0),
+        }
+    }
+
+    /// Get the tolerance in pixels
+    pub fn epsilon(&self) -> f32 {
+        self.epsilon
+    }
+
+    /// Simplify a polyline, returning at most `N` vertices
+    ///
+    /// The whole input is simplified, and the first and last vertices are
+    /// always kept. Fails with [`ChartError::MemoryFull`] when the simplified
+    /// polyline has more than `N` vertices.
+    pub fn simplify<const N: usize>(
+        &self,
+        points: &[Point],
+    ) -> ChartResult<heapless::Vec<Point, N>> {
+        let mut result = heapless::Vec::new();
+        let Some((&first_point, _)) = points.split_first() else {
+            return Ok(result);
+        };
+        if self.epsilon <= 0.0 {
+            for point in points {
+                push(&mut result, *point)?;
+            }
+            return Ok(result);
+        }
+        push(&mut result, first_point)?;
+
+        // Segments are taken from the left first so vertices come out in order
+        let mut pending: heapless::Vec<(usize, usize), MAX_PENDING_SEGMENTS> = heapless::Vec::new();
+        if points.len() > 1 {
+            let _ = pending.push((0, points.len() - 1));
+        }
+
+        let epsilon_sq = self.epsilon * self.epsilon;
+        while let Some((first, last)) = pending.pop() {
+            let (Some(&start), Some(&end)) = (points.get(first), points.get(last)) else {
+                continue;
+            };
+            let mut max_index = first;
+            let mut max_distance = 0.0;
+            for (index, point) in points.iter().enumerate().take(last).skip(first + 1) {
+                let distance = distance_sq(*point, start, end);
+                if distance > max_distance {
+                    max_index = index;
+                    max_distance = distance;
+                }
+            }
+
+            if max_distance > epsilon_sq {
+                if pending.len() + 2 <= MAX_PENDING_SEGMENTS {
+                    let _ = pending.push((max_index, last));
+                    let _ = pending.push((first, max_index));
+                    continue;
+                }
+                // Out of bookkeeping space: keep the whole range unsimplified
+                for point in points.iter().take(last).skip(first + 1) {
+                    push(&mut result, *point)?;
+                }
+            }
+            push(&mut result, end)?;
+        }
+        Ok(result)
+    }
+
+    /// Simplify a polyline in place
+    pub fn simplify_in_place<const N: usize>(&self, points: &mut heapless::Vec<Point, N>) {
+        // The result never has more vertices than the input, so this cannot fail
+        if let Ok(simplified) = self.simplify(points) {
+            *points = simplified;
+        }
+    }
+}
+
+fn push<const N: usize>(out: &mut heapless::Vec<Point, N>, point: Point) -> ChartResult<()> {
+    out.push(point).map_err(|_| ChartError::MemoryFull)
+}
+
+/// Squared distance from `point` to the segment `start`-`end`
+fn distance_sq(point: Point, start: Point, end: Point) -> f32 {
+    let dx = (end.x - start.x) as f32;
+    let dy = (end.y - start.y) as f32;
+    let px = (point.x - start.x) as f32;
+    let py = (point.y - start.y) as f32;
+
+    let length_sq = dx * dx + dy * dy;
+    if length_sq == 0.0 {
+        return px * px + py * py;
+    }
+
+    // Clamp the projection so vertices beyond the end points count by their real distance
+    let t = ((px * dx + py * dy) / length_sq).clamp(0.0, 1.0);
+    let ex = px - t * dx;
+    let ey = py - t * dy;
+    ex * ex + ey * ey
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify_removes_collinear_points() {
+        let points = [
+            Point::new(0, 0),
+            Point::new(1, 1),
+            Point::new(2, 2),
+            Point::new(3, 3),
+            Point::new(4, 0),
+        ];
+        let simplified: heapless::Vec<Point, 8> =
+            PolylineSimplifier::new(0.5).simplify(&points).unwrap();
+        assert_eq!(
+            simplified.as_slice(),
+            &[Point::new(0, 0), Point::new(3, 3), Point::new(4, 0)]
+        );
+    }
+
+    #[test]
+    fn test_zero_epsilon_keeps_all_points() {
+        let mut points: heapless::Vec<Point, 8> = heapless::Vec::new();
+        for x in 0..5 {
+            points.push(Point::new(x, 0)).unwrap();
+        }
+        PolylineSimplifier::new(0.0).simplify_in_place(&mut points);
+        assert_eq!(points.len(), 5);
+
+        PolylineSimplifier::new(1.0).simplify_in_place(&mut points);
+        assert_eq!(points.as_slice(), &[Point::new(0, 0), Point::new(4, 0)]);
+    }
+
+    #[test]
+    fn test_simplify_keeps_last_vertex_of_long_input() {
+        let mut points = [Point::zero(); 20];
+        for (x, point) in points.iter_mut().enumerate() {
+            *point = Point::new(x as i32, 0);
+        }
+        points[19] = Point::new(19, 10);
+
+        let simplified: heapless::Vec<Point, 4> =
+            PolylineSimplifier::new(0.5).simplify(&points).unwrap();
+        assert_eq!(
+            simplified.as_slice(),
+            &[Point::new(0, 0), Point::new(18, 0), Point::new(19, 10)]
+        );
+
+        // A result that does not fit is reported instead of truncated
+        let zigzag: [Point; 8] = core::array::from_fn(|x| Point::new(x as i32, (x % 2) as i32 * 5));
+        let overflow = PolylineSimplifier::new(0.5).simplify::<4>(&zigzag);
+        assert_eq!(overflow.err(), Some(ChartError::MemoryFull));
+    }
+}
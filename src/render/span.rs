@@ -0,0 +1,472 @@
+//! Span-based rasterization shared by chart fills.
+//!
+//! Filled chart shapes (triangles and stacked bands, areas under a line, pattern
+//! fills and pie slices) are all decomposed into horizontal spans. Each span is
+//! clipped against a single rectangle and written with
+//! [`ChartDrawTarget::fill_span`] or [`ChartDrawTarget::blit_row`], so every fill
+//! uses the same clipping rules and benefits from accelerated targets.
+//!
+//! Targets without their own [`ChartDrawTarget`] implementation can be wrapped in
+//! a [`FallbackTarget`](crate::render::FallbackTarget).
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use crate::error::{RenderError, RenderResult};
+use crate::render::target::ChartDrawTarget;
+use crate::style::PatternFill;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// Number of pattern colors buffered per `blit_row` call
+const ROW_CHUNK: usize = 64;
+
+/// Clipped horizontal span rasterizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanRasterizer {
+    /// Spans are clipped to this rectangle
+    clip: Rectangle,
+}
+
+impl SpanRasterizer {
+    /// Create a rasterizer clipping to the given area
+    ///
+    /// # Arguments
+    /// * `clip` - The area spans are clipped to
+    pub const fn new(clip: Rectangle) -> Self {
+        Self { clip }
+    }
+
+    /// Create a rasterizer clipping to the bounds of a draw target
+    pub fn for_target<D: Dimensions>(target: &D) -> Self {
+        Self::new(target.bounding_box())
+    }
+
+    /// Get the clipping area
+    pub fn clip(&self) -> Rectangle {
+        self.clip
+    }
+
+    /// Create a rasterizer that additionally clips to `area`
+    pub fn clipped_to(&self, area: Rectangle) -> Self {
+        Self::new(self.clip.intersection(&area))
+    }
+
+    /// Fill the pixels from `x_start` to `x_end` (inclusive) on row `y`
+    ///
+    /// The end points may be given in either order.
+    pub fn fill_span<D>(
+        &self,
+        y: i32,
+        x_start: i32,
+        x_end: i32,
+        color: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        if let Some((x, width)) = self.clip_span(y, x_start, x_end) {
+            target
+                .fill_span(Point::new(x, y), width, color)
+                .map_err(|_| RenderError::DrawingFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Fill a triangle
+    pub fn fill_triangle<D>(
+        &self,
+        p1: Point,
+        p2: Point,
+        p3: Point,
+        color: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        // Sort points by Y coordinate (top.y <= mid.y <= bottom.y)
+        let (mut top, mut mid, mut bottom) = (p1, p2, p3);
+        if top.y > mid.y {
+            core::mem::swap(&mut top, &mut mid);
+        }
+        if mid.y > bottom.y {
+            core::mem::swap(&mut mid, &mut bottom);
+        }
+        if top.y > mid.y {
+            core::mem::swap(&mut top, &mut mid);
+        }
+
+        if top.y == bottom.y {
+            // All points on one row
+            let min_x = top.x.min(mid.x).min(bottom.x);
+            let max_x = top.x.max(mid.x).max(bottom.x);
+            return self.fill_span(top.y, min_x, max_x, color, target);
+        }
+
+        let (y_min, y_max) = self.clip_rows(top.y, bottom.y);
+        for y in y_min..=y_max {
+            let long_x = edge_x(top, bottom, y);
+            let short_x = if y < mid.y {
+                edge_x(top, mid, y)
+            } else {
+                edge_x(mid, bottom, y)
+            };
+            self.fill_span(y, long_x, short_x, color, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fill a quadrilateral given in drawing order
+    ///
+    /// The quadrilateral is split along the `p1`-`p3` diagonal.
+    pub fn fill_quad<D>(
+        &self,
+        p1: Point,
+        p2: Point,
+        p3: Point,
+        p4: Point,
+        color: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        self.fill_triangle(p1, p2, p3, color, target)?;
+        self.fill_triangle(p1, p3, p4, color, target)
+    }
+
+    /// Fill the area between a polyline and a horizontal baseline
+    ///
+    /// # Arguments
+    /// * `points` - Polyline vertices in drawing order
+    /// * `baseline_y` - Row the area extends to
+    /// * `color` - Fill color
+    /// * `target` - The display target to draw to
+    pub fn fill_under_polyline<D>(
+        &self,
+        points: &[Point],
+        baseline_y: i32,
+        color: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        for (&a, &b) in points.iter().zip(points.iter().skip(1)) {
+            self.fill_quad(
+                a,
+                b,
+                Point::new(b.x, baseline_y),
+                Point::new(a.x, baseline_y),
+                color,
+                target,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Fill the area between a polyline and a horizontal baseline, using a
+    /// separate color where the line runs below the baseline
+    ///
+    /// Segments crossing the baseline are split at the crossing point, so the
+    /// two colors meet exactly where the line passes through the baseline.
+    ///
+    /// # Arguments
+    /// * `points` - Polyline vertices in drawing order
+    /// * `baseline_y` - Row the area extends to
+    /// * `above` - Fill color where the line is above the baseline (smaller y)
+    /// * `below` - Fill color where the line is below the baseline
+    /// * `target` - The display target to draw to
+    pub fn fill_to_baseline<D>(
+        &self,
+        points: &[Point],
+        baseline_y: i32,
+        above: D::Color,
+        below: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        let color_for = |offset: i32| if offset > 0 { below } else { above };
+
+        for (&a, &b) in points.iter().zip(points.iter().skip(1)) {
+            let (offset_a, offset_b) = (a.y - baseline_y, b.y - baseline_y);
+
+            if (offset_a < 0 && offset_b > 0) || (offset_a > 0 && offset_b < 0) {
+                // Split at the crossing into one triangle on each side
+                let t_num = i64::from(-offset_a);
+                let t_den = i64::from(b.y - a.y);
+                let cross_x = a.x + (i64::from(b.x - a.x) * t_num / t_den) as i32;
+                let cross = Point::new(cross_x, baseline_y);
+                self.fill_triangle(
+                    a,
+                    cross,
+                    Point::new(a.x, baseline_y),
+                    color_for(offset_a),
+                    target,
+                )?;
+                self.fill_triangle(
+                    cross,
+                    b,
+                    Point::new(b.x, baseline_y),
+                    color_for(offset_b),
+                    target,
+                )?;
+            } else {
+                // A vertex on the baseline takes the side of the other vertex
+                let offset = if offset_a != 0 { offset_a } else { offset_b };
+                self.fill_quad(
+                    a,
+                    b,
+                    Point::new(b.x, baseline_y),
+                    Point::new(a.x, baseline_y),
+                    color_for(offset),
+                    target,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill every pixel of `area` for which `inside` returns true
+    ///
+    /// Consecutive pixels on a row are merged into a single span, so shapes
+    /// without a closed-form edge (such as pie slices) still render as spans.
+    pub fn fill_mask<D, F>(
+        &self,
+        area: Rectangle,
+        mut inside: F,
+        color: D::Color,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+        F: FnMut(i32, i32) -> bool,
+    {
+        let area = area.intersection(&self.clip);
+        let Some(bottom_right) = area.bottom_right() else {
+            return Ok(());
+        };
+
+        for y in area.top_left.y..=bottom_right.y {
+            let mut run_start: Option<i32> = None;
+            for x in area.top_left.x..=bottom_right.x {
+                match (inside(x, y), run_start) {
+                    (true, None) => run_start = Some(x),
+                    (false, Some(start)) => {
+                        self.fill_span(y, start, x - 1, color, target)?;
+                        run_start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(start) = run_start {
+                self.fill_span(y, start, bottom_right.x, color, target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fill a rectangle with a pattern, anchored at the rectangle's top-left corner
+    pub fn fill_pattern<D>(
+        &self,
+        area: Rectangle,
+        pattern: &PatternFill<D::Color>,
+        target: &mut D,
+    ) -> RenderResult<()>
+    where
+        D: ChartDrawTarget,
+    {
+        let visible = area.intersection(&self.clip);
+        let Some(bottom_right) = visible.bottom_right() else {
+            return Ok(());
+        };
+
+        for y in visible.top_left.y..=bottom_right.y {
+            let mut x = visible.top_left.x;
+            while x <= bottom_right.x {
+                let start = x;
+                let mut row: heapless::Vec<D::Color, ROW_CHUNK> = heapless::Vec::new();
+                while x <= bottom_right.x {
+                    let color = pattern.color_at(x - area.top_left.x, y - area.top_left.y);
+                    if row.push(color).is_err() {
+                        break;
+                    }
+                    x += 1;
+                }
+                target
+                    .blit_row(Point::new(start, y), &row)
+                    .map_err(|_| RenderError::DrawingFailed)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clip a span to the clipping area, returning its start and width
+    fn clip_span(&self, y: i32, x_start: i32, x_end: i32) -> Option<(i32, u32)> {
+        let bottom_right = self.clip.bottom_right()?;
+        if y < self.clip.top_left.y || y > bottom_right.y {
+            return None;
+        }
+
+        let (x0, x1) = if x_start <= x_end {
+            (x_start, x_end)
+        } else {
+            (x_end, x_start)
+        };
+        let x0 = x0.max(self.clip.top_left.x);
+        let x1 = x1.min(bottom_right.x);
+        if x0 > x1 {
+            return None;
+        }
+
+        Some((x0, (x1 - x0 + 1) as u32))
+    }
+
+    /// Clamp a row range to the clipping area
+    fn clip_rows(&self, y_start: i32, y_end: i32) -> (i32, i32) {
+        match self.clip.bottom_right() {
+            Some(bottom_right) => (y_start.max(self.clip.top_left.y), y_end.min(bottom_right.y)),
+            None => (0, -1),
+        }
+    }
+}
+
+/// X coordinate of the edge from `a` to `b` on row `y`, rounded to the nearest pixel
+///
+/// Requires `a.y <= b.y`; horizontal edges return `a.x`.
+fn edge_x(a: Point, b: Point, y: i32) -> i32 {
+    let dy = (b.y - a.y) as i64;
+    if dy == 0 {
+        return a.x;
+    }
+    let num = (b.x - a.x) as i64 * (y - a.y) as i64;
+    a.x + (2 * num + dy).div_euclid(2 * dy) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::target::FallbackTarget;
+    use crate::style::PatternType;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+    #[test]
+    fn test_span_clipping() {
+        let raster = SpanRasterizer::new(Rectangle::new(Point::new(2, 2), Size::new(4, 4)));
+
+        assert_eq!(raster.clip_span(3, 7, 0), Some((2, 4)));
+        assert_eq!(raster.clip_span(3, 3, 3), Some((3, 1)));
+        assert_eq!(raster.clip_span(1, 0, 7), None);
+        assert_eq!(raster.clip_span(3, 6, 9), None);
+    }
+
+    #[test]
+    fn test_fill_triangle() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let raster = SpanRasterizer::for_target(&display);
+        let mut target = FallbackTarget::new(&mut display);
+
+        raster
+            .fill_triangle(
+                Point::new(0, 0),
+                Point::new(8, 8),
+                Point::new(0, 8),
+                Rgb565::RED,
+                &mut target,
+            )
+            .unwrap();
+
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::zero(), Size::new(9, 9))
+        );
+        assert_eq!(display.get_pixel(Point::new(4, 4)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(5, 4)), None);
+    }
+
+    #[test]
+    fn test_fill_under_polyline_is_clipped() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let clip = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+        let raster = SpanRasterizer::new(clip);
+        let mut target = FallbackTarget::new(&mut display);
+
+        let points = [Point::new(-5, 2), Point::new(4, 2), Point::new(20, 6)];
+        raster
+            .fill_under_polyline(&points, 9, Rgb565::BLUE, &mut target)
+            .unwrap();
+
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(0, 2), Size::new(10, 8))
+        );
+        assert_eq!(display.get_pixel(Point::new(0, 9)), Some(Rgb565::BLUE));
+    }
+
+    #[test]
+    fn test_fill_to_baseline_splits_at_crossing() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let raster = SpanRasterizer::for_target(&display);
+        let mut target = FallbackTarget::new(&mut display);
+
+        // Rises from 4 rows below the baseline to 4 rows above it
+        let points = [Point::new(0, 14), Point::new(8, 6)];
+        raster
+            .fill_to_baseline(&points, 10, Rgb565::GREEN, Rgb565::RED, &mut target)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(1, 12)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(7, 8)), Some(Rgb565::GREEN));
+        // Nothing is filled on the far side of the line
+        assert_eq!(display.get_pixel(Point::new(1, 8)), None);
+        assert_eq!(display.get_pixel(Point::new(7, 12)), None);
+    }
+
+    #[test]
+    fn test_fill_mask_and_pattern() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let raster = SpanRasterizer::for_target(&display);
+        let mut target = FallbackTarget::new(&mut display);
+
+        let area = Rectangle::new(Point::zero(), Size::new(6, 1));
+        raster
+            .fill_mask(area, |x, _| x % 3 != 0, Rgb565::GREEN, &mut target)
+            .unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), None);
+        assert_eq!(display.get_pixel(Point::new(2, 0)), Some(Rgb565::GREEN));
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let mut target = FallbackTarget::new(&mut display);
+        let pattern = PatternFill::new(
+            Rgb565::BLACK,
+            Rgb565::WHITE,
+            PatternType::Checkerboard { size: 1 },
+        );
+        let area = Rectangle::new(Point::new(1, 1), Size::new(2, 2));
+        raster.fill_pattern(area, &pattern, &mut target).unwrap();
+        assert_eq!(
+            display.get_pixel(Point::new(1, 1)),
+            Some(pattern.color_at(0, 0))
+        );
+        assert_eq!(
+            display.get_pixel(Point::new(2, 1)),
+            Some(pattern.color_at(1, 0))
+        );
+    }
+}
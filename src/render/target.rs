@@ -0,0 +1,185 @@
+//! Draw target extension for accelerated chart rendering.
+//!
+//! Charts render through the plain [`DrawTarget`] trait, which many display drivers
+//! implement pixel by pixel. [`ChartDrawTarget`] adds a small set of fast-path
+//! operations that chart renderers use for row-oriented work such as gradient and
+//! area fills. Every method has a default built on [`DrawTarget`], so a driver only
+//! overrides what its hardware can accelerate (e.g. a DMA row transfer for
+//! [`ChartDrawTarget::blit_row`]).
+//!
+//! Draw charts with [`Chart::draw_accelerated`](crate::chart::Chart::draw_accelerated)
+//! to reach these overrides; [`Chart::draw`](crate::chart::Chart::draw) accepts
+//! any [`DrawTarget`] and uses the defaults.
+//!
+//! Targets that don't implement the trait can be wrapped in a [`FallbackTarget`],
+//! which uses the default implementations.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::render::ChartDrawTarget;
+//! use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+//!
+//! struct MyDisplay;
+//!
+//! impl Dimensions for MyDisplay {
+//!     fn bounding_box(&self) -> Rectangle {
+//!         Rectangle::new(Point::zero(), Size::new(320, 240))
+//!     }
+//! }
+//!
+//! impl DrawTarget for MyDisplay {
+//!     type Color = Rgb565;
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+//!     where
+//!         I: IntoIterator<Item = Pixel<Self::Color>>,
+//!     {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ChartDrawTarget for MyDisplay {
+//!     fn blit_row(&mut self, start: Point, colors: &[Rgb565]) -> Result<(), Self::Error> {
+//!         // Set the address window to the row and stream `colors` via DMA
+//!         Ok(())
+//!     }
+//! }
+//! ```
+#![cfg_attr(
+    all(feature = "no-panic-strict", not(test)),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable
+    )
+)]
+
+use embedded_graphics::{
+    draw_target::{Clipped, DrawTarget},
+    prelude::*,
+    primitives::Rectangle,
+};
+
+/// Fast-path drawing operations used by chart renderers.
+///
+/// All methods have defaults in terms of [`DrawTarget`]; override the ones the
+/// display can perform faster. Implementations must produce the same pixels as
+/// the defaults, clipped to the display bounds.
+pub trait ChartDrawTarget: DrawTarget {
+    /// Fill a horizontal span of `width` pixels starting at `start`
+    fn fill_span(
+        &mut self,
+        start: Point,
+        width: u32,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        self.fill_solid(&Rectangle::new(start, Size::new(width, 1)), color)
+    }
+
+    /// Write a row of individually colored pixels starting at `start`
+    fn blit_row(&mut self, start: Point, colors: &[Self::Color]) -> Result<(), Self::Error> {
+        let area = Rectangle::new(start, Size::new(colors.len() as u32, 1));
+        self.fill_contiguous(&area, colors.iter().copied())
+    }
+
+    /// Fill a rectangle with a solid color
+    fn fill_rect(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid(area, color)
+    }
+}
+
+/// Adapter giving any [`DrawTarget`] the default [`ChartDrawTarget`] behaviour.
+#[derive(Debug)]
+pub struct FallbackTarget<'a, D> {
+    target: &'a mut D,
+}
+
+impl<'a, D: DrawTarget> FallbackTarget<'a, D> {
+    /// Wrap a draw target
+    pub fn new(target: &'a mut D) -> Self {
+        Self { target }
+    }
+
+    /// Get the wrapped draw target
+    pub fn inner(&mut self) -> &mut D {
+        self.target
+    }
+}
+
+impl<D: DrawTarget> Dimensions for FallbackTarget<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for FallbackTarget<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.target.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.target.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
+
+impl<D: DrawTarget> ChartDrawTarget for FallbackTarget<'_, D> {}
+
+/// Clipped areas of a chart target, e.g. the plot area of a scrolling window.
+///
+/// The defaults go through [`Clipped`]'s fills, which clip to the area before
+/// reaching the parent target.
+impl<T: ChartDrawTarget> ChartDrawTarget for Clipped<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+    #[test]
+    fn test_fallback_fast_paths() {
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+        let mut target = FallbackTarget::new(&mut display);
+
+        target.fill_span(Point::new(2, 1), 3, Rgb565::RED).unwrap();
+        target
+            .blit_row(Point::new(0, 3), &[Rgb565::GREEN, Rgb565::BLUE])
+            .unwrap();
+        target
+            .fill_rect(
+                &Rectangle::new(Point::new(5, 5), Size::new(2, 2)),
+                Rgb565::WHITE,
+            )
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(4, 1)), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel(Point::new(5, 1)), None);
+        assert_eq!(display.get_pixel(Point::new(1, 3)), Some(Rgb565::BLUE));
+        assert_eq!(display.get_pixel(Point::new(6, 6)), Some(Rgb565::WHITE));
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(0, 1), Size::new(7, 6))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Terminal rendering for headless debugging.
+//!
+//! [`TerminalDisplay`] is a [`DrawTarget`] that records which pixels were drawn and
+//! prints them as text, so charts can be inspected in CI logs or a terminal
+//! without a simulator window. Two output modes are available:
+//!
+//! - [`TerminalMode::Braille`] packs 2×4 pixels into each Unicode braille character
+//! - [`TerminalMode::Ascii`] uses `#`, `'`, `.` or a space for each 1×2 pixel cell
+//!
+//! A pixel is shown when it was drawn with a color other than the background.
+//! Clearing the display sets the background, so a chart with a background color
+//! prints only its lines, bars and text.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "line")]
+//! # {
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::render::{TerminalDisplay, TerminalMode};
+//! use embedded_graphics::pixelcolor::Rgb565;
+//!
+//! let data = data_points![(0.0, 0.0), (1.0, 3.0), (2.0, 1.0), (3.0, 4.0)];
+//! let chart = LineChart::builder().line_color(Rgb565::BLUE).build()?;
+//!
+//! // 40 columns by 12 rows of braille characters
+//! let mut display = TerminalDisplay::new(40, 12, TerminalMode::Braille);
+//! display.clear(Rgb565::WHITE).ok();
+//! chart.draw(&data, chart.config(), display.bounding_box(), &mut display)?;
+//! println!("{}", display);
+//! # }
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use core::convert::Infallible;
+use core::fmt;
+use embedded_graphics::{draw_target::DrawTarget, prelude::*};
+use std::string::String;
+use std::vec::Vec;
+
+/// Character encoding used by [`TerminalDisplay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalMode {
+    /// Unicode braille, 2×4 pixels per character
+    Braille,
+    /// Plain ASCII, 1×2 pixels per character
+    Ascii,
+}
+
+impl TerminalMode {
+    /// Pixels covered by a single character
+    pub const fn cell_size(self) -> Size {
+        match self {
+            TerminalMode::Braille => Size::new(2, 4),
+            TerminalMode::Ascii => Size::new(1, 2),
+        }
+    }
+}
+
+/// Draw target that renders to text for terminal output.
+#[derive(Debug, Clone)]
+pub struct TerminalDisplay<C: PixelColor> {
+    /// Output size in characters
+    columns: u32,
+    rows: u32,
+    /// Character encoding
+    mode: TerminalMode,
+    /// Colors of drawn pixels, row-major
+    pixels: Vec<Option<C>>,
+    /// Pixels drawn with this color are not shown
+    background: Option<C>,
+}
+
+impl<C: PixelColor> TerminalDisplay<C> {
+    /// Create a terminal display with the given character resolution
+    ///
+    /// # Arguments
+    /// * `columns` - Output width in characters
+    /// * `rows` - Output height in characters
+    /// * `mode` - Character encoding
+    pub fn new(columns: u32, rows: u32, mode: TerminalMode) -> Self {
+        let cell = mode.cell_size();
+        let pixel_count = (columns * cell.width * rows * cell.height) as usize;
+        Self {
+            columns,
+            rows,
+            mode,
+            pixels: std::vec![None; pixel_count],
+            background: None,
+        }
+    }
+
+    /// Set the background color, which is never shown
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Get the character encoding
+    pub fn mode(&self) -> TerminalMode {
+        self.mode
+    }
+
+    /// Get the output size in characters as (columns, rows)
+    pub fn character_size(&self) -> (u32, u32) {
+        (self.columns, self.rows)
+    }
+
+    /// Check whether the pixel at `point` is shown
+    pub fn is_set(&self, point: Point) -> bool {
+        match self.index(point).and_then(|i| self.pixels[i]) {
+            Some(color) => Some(color) != self.background,
+            None => false,
+        }
+    }
+
+    /// Render the display as text, one line per character row
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        for row in 0..self.rows {
+            if row > 0 {
+                output.push('\n');
+            }
+            for column in 0..self.columns {
+                output.push(self.cell_char(column, row));
+            }
+        }
+        output
+    }
+
+    /// Print the display to stdout
+    pub fn print(&self) {
+        std::println!("{}", self.render());
+    }
+
+    /// Character for the cell at (`column`, `row`)
+    fn cell_char(&self, column: u32, row: u32) -> char {
+        let cell = self.mode.cell_size();
+        let origin = Point::new((column * cell.width) as i32, (row * cell.height) as i32);
+
+        match self.mode {
+            TerminalMode::Braille => {
+                // Braille dot bits, indexed by [y][x] within the 2×4 cell
+                const DOTS: [[u32; 2]; 4] =
+                    [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+                let mut bits = 0;
+                for (dy, row_bits) in DOTS.iter().enumerate() {
+                    for (dx, bit) in row_bits.iter().enumerate() {
+                        if self.is_set(origin + Point::new(dx as i32, dy as i32)) {
+                            bits |= bit;
+                        }
+                    }
+                }
+                char::from_u32(0x2800 + bits).unwrap_or(' ')
+            }
+            TerminalMode::Ascii => {
+                let top = self.is_set(origin);
+                let bottom = self.is_set(origin + Point::new(0, 1));
+                match (top, bottom) {
+                    (true, true) => '#',
+                    (true, false) => '\'',
+                    (false, true) => '.',
+                    (false, false) => ' ',
+                }
+            }
+        }
+    }
+
+    /// Buffer index of `point`, if it lies on the display
+    fn index(&self, point: Point) -> Option<usize> {
+        let size = self.size();
+        if point.x < 0
+            || point.y < 0
+            || point.x >= size.width as i32
+            || point.y >= size.height as i32
+        {
+            return None;
+        }
+        Some(point.y as usize * size.width as usize + point.x as usize)
+    }
+}
+
+impl<C: PixelColor> OriginDimensions for TerminalDisplay<C> {
+    fn size(&self) -> Size {
+        let cell = self.mode.cell_size();
+        Size::new(self.columns * cell.width, self.rows * cell.height)
+    }
+}
+
+impl<C: PixelColor> DrawTarget for TerminalDisplay<C> {
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(index) = self.index(point) {
+                self.pixels[index] = Some(color);
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.background = Some(color);
+        self.pixels
+            .iter_mut()
+            .for_each(|pixel| *pixel = Some(color));
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> fmt::Display for TerminalDisplay<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::{BinaryColor, Rgb565, RgbColor};
+    use embedded_graphics::primitives::{Line, PrimitiveStyle};
+
+    #[test]
+    fn test_braille_rendering() {
+        let mut display = TerminalDisplay::new(2, 1, TerminalMode::Braille);
+        Line::new(Point::new(0, 0), Point::new(0, 3))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.size(), Size::new(4, 4));
+        assert_eq!(display.render(), "\u{2847}\u{2800}");
+    }
+
+    #[test]
+    fn test_background_is_hidden() {
+        let mut display = TerminalDisplay::new(3, 1, TerminalMode::Ascii);
+        display.clear(Rgb565::WHITE).unwrap();
+        Pixel(Point::new(1, 0), Rgb565::RED)
+            .draw(&mut display)
+            .unwrap();
+        Pixel(Point::new(2, 1), Rgb565::RED)
+            .draw(&mut display)
+            .unwrap();
+
+        assert!(!display.is_set(Point::new(0, 0)));
+        assert_eq!(display.render(), " '.");
+    }
+}
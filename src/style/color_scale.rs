@@ -0,0 +1,215 @@
+//! Value-to-color scaling for color ramps.
+//!
+//! A value-based color ramp normalizes each value into `0.0..=1.0` before
+//! picking a color. Stretching the full data range over the ramp lets a few
+//! outliers squeeze everything else into one or two colors, so
+//! [`ColorScaling`] offers alternatives:
+//!
+//! - [`ColorScaling::MinMax`] stretches the data range (the default)
+//! - [`ColorScaling::Fixed`] uses a user-supplied range
+//! - [`ColorScaling::Percentile`] stretches between two percentiles and clamps the tails
+//! - [`ColorScaling::Equalized`] spreads values evenly over the ramp by rank
+//!
+//! A fitted [`ColorScale`] maps values to ramp positions and back, so a scale
+//! legend can label its color stops with the values they actually represent.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::style::{ColorScale, ColorScaling};
+//!
+//! let values = [1.0, 2.0, 3.0, 4.0, 100.0];
+//! let scale = ColorScale::fit(
+//!     ColorScaling::Percentile { low: 0.0, high: 75.0 },
+//!     values.iter().copied(),
+//! )?;
+//!
+//! assert_eq!(scale.normalize(1.0), 0.0);
+//! assert_eq!(scale.normalize(100.0), 1.0); // outlier is clamped
+//! assert!(scale.normalize(2.0) > 0.3); // the rest keeps its contrast
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use crate::data::stats::percentile;
+use crate::error::{ChartError, ChartResult};
+use heapless::Vec;
+
+/// Number of values a [`ColorScale`] can be fitted to.
+pub const MAX_SCALE_SAMPLES: usize = 256;
+
+/// Number of quantile breakpoints used by [`ColorScaling::Equalized`].
+const EQUALIZED_BREAKPOINTS: usize = 17;
+
+/// How values are normalized before picking a color.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorScaling {
+    /// Stretch the range between the smallest and largest value
+    #[default]
+    MinMax,
+    /// Stretch a fixed range; values outside it are clamped
+    Fixed {
+        /// Value mapped to the start of the ramp
+        min: f32,
+        /// Value mapped to the end of the ramp
+        max: f32,
+    },
+    /// Stretch the range between two percentiles (0 to 100); values outside it are clamped
+    Percentile {
+        /// Percentile mapped to the start of the ramp
+        low: f32,
+        /// Percentile mapped to the end of the ramp
+        high: f32,
+    },
+    /// Histogram equalization: each part of the ramp covers the same number of values
+    Equalized,
+}
+
+/// Value scale fitted to a set of values.
+///
+/// Values map piecewise-linearly between ascending breakpoints spread evenly
+/// over `0.0..=1.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorScale {
+    breakpoints: Vec<f32, EQUALIZED_BREAKPOINTS>,
+}
+
+impl ColorScale {
+    /// Create a linear scale over a fixed range
+    pub fn linear(min: f32, max: f32) -> Self {
+        let mut breakpoints = Vec::new();
+        let _ = breakpoints.push(min.min(max));
+        let _ = breakpoints.push(min.max(max));
+        Self { breakpoints }
+    }
+
+    /// Fit a scale to `values` using the given scaling
+    ///
+    /// At most [`MAX_SCALE_SAMPLES`] values are considered.
+    pub fn fit<I>(scaling: ColorScaling, values: I) -> ChartResult<Self>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        if let ColorScaling::Fixed { min, max } = scaling {
+            return Ok(Self::linear(min, max));
+        }
+
+        let mut sorted: Vec<f32, MAX_SCALE_SAMPLES> = Vec::new();
+        for value in values.into_iter().filter(|value| value.is_finite()) {
+            if sorted.push(value).is_err() {
+                break;
+            }
+        }
+        if sorted.is_empty() {
+            return Err(ChartError::InsufficientData);
+        }
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        let at = |p| percentile(&sorted, p).unwrap_or(sorted[0]);
+
+        let scale = match scaling {
+            ColorScaling::Fixed { min, max } => Self::linear(min, max),
+            ColorScaling::MinMax => Self::linear(sorted[0], sorted[sorted.len() - 1]),
+            ColorScaling::Percentile { low, high } => {
+                Self::linear(at(low.min(high)), at(low.max(high)))
+            }
+            ColorScaling::Equalized => {
+                let mut breakpoints = Vec::new();
+                let last = (EQUALIZED_BREAKPOINTS - 1) as f32;
+                for index in 0..EQUALIZED_BREAKPOINTS {
+                    let _ = breakpoints.push(at(index as f32 / last * 100.0));
+                }
+                Self { breakpoints }
+            }
+        };
+        Ok(scale)
+    }
+
+    /// Get the smallest and largest value the scale distinguishes
+    pub fn domain(&self) -> (f32, f32) {
+        (
+            self.breakpoints[0],
+            self.breakpoints[self.breakpoints.len() - 1],
+        )
+    }
+
+    /// Map a value to its position on the ramp, `0.0..=1.0`
+    pub fn normalize(&self, value: f32) -> f32 {
+        let (min, max) = self.domain();
+        if max <= min {
+            return 0.5;
+        }
+        if value <= min {
+            return 0.0;
+        }
+        if value >= max {
+            return 1.0;
+        }
+
+        let segments = (self.breakpoints.len() - 1) as f32;
+        for (index, pair) in self.breakpoints.windows(2).enumerate() {
+            if value <= pair[1] {
+                let within = if pair[1] > pair[0] {
+                    (value - pair[0]) / (pair[1] - pair[0])
+                } else {
+                    1.0
+                };
+                return (index as f32 + within) / segments;
+            }
+        }
+        1.0
+    }
+
+    /// Map a ramp position in `0.0..=1.0` back to the value it represents
+    ///
+    /// Use this to label the stops of a scale legend.
+    pub fn value_at(&self, position: f32) -> f32 {
+        let segments = self.breakpoints.len() - 1;
+        let scaled = position.clamp(0.0, 1.0) * segments as f32;
+        let index = (scaled as usize).min(segments - 1);
+        let within = scaled - index as f32;
+        let (start, end) = (self.breakpoints[index], self.breakpoints[index + 1]);
+        start + (end - start) * within
+    }
+
+    /// Pick an index into a palette of `count` colors for `value`
+    pub fn color_index(&self, value: f32, count: usize) -> usize {
+        if count == 0 {
+            return 0;
+        }
+        ((self.normalize(value) * (count - 1) as f32) as usize).min(count - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_and_min_max() {
+        let scale = ColorScale::fit(
+            ColorScaling::Fixed {
+                min: 0.0,
+                max: 10.0,
+            },
+            [50.0],
+        )
+        .unwrap();
+        assert_eq!(scale.normalize(5.0), 0.5);
+        assert_eq!(scale.normalize(50.0), 1.0);
+
+        let scale = ColorScale::fit(ColorScaling::MinMax, [2.0, 6.0, 4.0]).unwrap();
+        assert_eq!(scale.domain(), (2.0, 6.0));
+        assert_eq!(scale.value_at(0.5), 4.0);
+    }
+
+    #[test]
+    fn test_equalized_spreads_by_rank() {
+        let values = [1.0, 2.0, 3.0, 4.0, 1000.0];
+        let scale = ColorScale::fit(ColorScaling::Equalized, values).unwrap();
+
+        // Each value sits at its rank position despite the outlier
+        assert!((scale.normalize(2.0) - 0.25).abs() < 0.01);
+        assert!((scale.normalize(3.0) - 0.5).abs() < 0.01);
+        assert_eq!(scale.color_index(1000.0, 5), 4);
+        assert!((scale.value_at(0.5) - 3.0).abs() < 0.01);
+    }
+}
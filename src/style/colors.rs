@@ -1,20 +1,47 @@
 //! Color utilities and palettes for charts.
 
+use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use heapless::Vec;
 
 /// Color palette for charts
+///
+/// Palettes either own their colors or borrow a `'static` array. Borrowed
+/// palettes are built in `const` context with [`ColorPalette::from_static`],
+/// so the colors stay in flash and no setup code runs.
 #[derive(Debug, Clone)]
-pub struct ColorPalette<C: PixelColor, const N: usize> {
-    colors: Vec<C, N>,
+pub struct ColorPalette<C: PixelColor + 'static, const N: usize> {
+    colors: PaletteColors<C, N>,
     current_index: usize,
 }
 
-impl<C: PixelColor, const N: usize> ColorPalette<C, N> {
+/// Storage behind a [`ColorPalette`]
+#[derive(Debug, Clone)]
+enum PaletteColors<C: PixelColor + 'static, const N: usize> {
+    /// Colors copied into the palette
+    Owned(Vec<C, N>),
+    /// Colors borrowed from a constant array
+    Static(&'static [C]),
+}
+
+impl<C: PixelColor + 'static, const N: usize> ColorPalette<C, N> {
     /// Create a new empty color palette
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
-            colors: Vec::new(),
+            colors: PaletteColors::Owned(Vec::new()),
+            current_index: 0,
+        }
+    }
+
+    /// Create a palette that borrows a constant color array
+    ///
+    /// Nothing is copied, so a `const` or `static` palette built this way
+    /// keeps its colors in flash. Panics, at compile time in `const` items,
+    /// if `colors` holds more than `N` colors.
+    pub const fn from_static(colors: &'static [C]) -> Self {
+        assert!(colors.len() <= N, "palette holds more than N colors");
+        Self {
+            colors: PaletteColors::Static(colors),
             current_index: 0,
         }
     }
@@ -29,36 +56,46 @@ impl<C: PixelColor, const N: usize> ColorPalette<C, N> {
     }
 
     /// Add a color to the palette
+    ///
+    /// A palette borrowing a constant array copies its colors first.
     pub fn add_color(&mut self, color: C) -> Result<(), crate::error::DataError> {
-        self.colors
-            .push(color)
-            .map_err(|_| crate::error::DataError::buffer_full("add color to palette", N))
+        if let PaletteColors::Static(colors) = self.colors {
+            // from_static guarantees the borrowed colors fit
+            self.colors = PaletteColors::Owned(colors.iter().copied().collect());
+        }
+        match self.colors {
+            PaletteColors::Owned(ref mut colors) => colors
+                .push(color)
+                .map_err(|_| crate::error::DataError::buffer_full("add color to palette", N)),
+            PaletteColors::Static(_) => unreachable!("static palette was copied above"),
+        }
     }
 
     /// Get the next color in the palette (cycles through)
     pub fn next_color(&mut self) -> Option<C> {
-        if self.colors.is_empty() {
+        let colors = self.as_slice();
+        if colors.is_empty() {
             return None;
         }
 
-        let color = self.colors[self.current_index];
-        self.current_index = (self.current_index + 1) % self.colors.len();
+        let color = colors[self.current_index];
+        self.current_index = (self.current_index + 1) % colors.len();
         Some(color)
     }
 
     /// Get a color by index
     pub fn get_color(&self, index: usize) -> Option<C> {
-        self.colors.get(index).copied()
+        self.as_slice().get(index).copied()
     }
 
     /// Get the number of colors in the palette
     pub fn len(&self) -> usize {
-        self.colors.len()
+        self.as_slice().len()
     }
 
     /// Check if the palette is empty
     pub fn is_empty(&self) -> bool {
-        self.colors.is_empty()
+        self.as_slice().is_empty()
     }
 
     /// Reset the color index to the beginning
@@ -68,196 +105,321 @@ impl<C: PixelColor, const N: usize> ColorPalette<C, N> {
 
     /// Get all colors as a slice
     pub fn as_slice(&self) -> &[C] {
-        &self.colors
+        match self.colors {
+            PaletteColors::Owned(ref colors) => colors,
+            PaletteColors::Static(colors) => colors,
+        }
+    }
+
+    /// Check whether the palette borrows a constant array instead of owning its colors
+    pub fn is_static(&self) -> bool {
+        matches!(self.colors, PaletteColors::Static(_))
     }
 }
 
-impl<C: PixelColor, const N: usize> Default for ColorPalette<C, N> {
+impl<C: PixelColor + 'static, const N: usize> Default for ColorPalette<C, N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Default series colors with modern, vibrant tones
+const DEFAULT_SERIES_COLORS: [Rgb565; 8] = [
+    Rgb565::new(59 >> 3, 130 >> 2, 246 >> 3),  // Modern blue
+    Rgb565::new(239 >> 3, 68 >> 2, 68 >> 3),   // Modern red
+    Rgb565::new(34 >> 3, 197 >> 2, 94 >> 3),   // Emerald green
+    Rgb565::new(245 >> 3, 158 >> 2, 11 >> 3),  // Amber
+    Rgb565::new(147 >> 3, 51 >> 2, 234 >> 3),  // Purple
+    Rgb565::new(6 >> 3, 182 >> 2, 212 >> 3),   // Cyan
+    Rgb565::new(251 >> 3, 113 >> 2, 133 >> 3), // Rose
+    Rgb565::new(168 >> 3, 85 >> 2, 247 >> 3),  // Violet
+];
+
+/// Deterministic color assignment for data series
+///
+/// Series `i` always gets the same color, wrapping around when there are more
+/// series than colors. Charts, legends and labels that look up colors through
+/// the same palette therefore always agree.
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_charts::style::SeriesPalette;
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+///
+/// let palette: SeriesPalette<Rgb565, 2> = SeriesPalette::from_colors(&[Rgb565::RED, Rgb565::BLUE])?;
+/// assert_eq!(palette.color(1), Rgb565::BLUE);
+/// assert_eq!(palette.color(2), Rgb565::RED);
+/// # Ok::<(), embedded_charts::error::DataError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeriesPalette<C: PixelColor, const N: usize> {
+    colors: Vec<C, N>,
+}
+
+impl<C: PixelColor, const N: usize> SeriesPalette<C, N> {
+    /// Create a series palette from a slice of colors
+    ///
+    /// Returns an error if `colors` is empty or holds more than `N` colors.
+    pub fn from_colors(colors: &[C]) -> Result<Self, crate::error::DataError> {
+        if colors.is_empty() {
+            return Err(crate::error::DataError::INSUFFICIENT_DATA);
+        }
+        let colors = Vec::from_slice(colors)
+            .map_err(|_| crate::error::DataError::buffer_full("create series palette", N))?;
+        Ok(Self { colors })
+    }
+
+    /// Create a series palette from the colors of a color palette
+    pub fn from_palette(palette: &ColorPalette<C, N>) -> Result<Self, crate::error::DataError>
+    where
+        C: 'static,
+    {
+        Self::from_colors(palette.as_slice())
+    }
+
+    /// Get the color of the series at `index`
+    pub fn color(&self, index: usize) -> C {
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Iterate over the colors of series 0, 1, 2, ... without end
+    pub fn colors(&self) -> impl Iterator<Item = C> + '_ {
+        self.colors.iter().copied().cycle()
+    }
+
+    /// Get the number of distinct colors
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if the palette is empty (never true for a constructed palette)
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
+impl<C: PixelColor + From<Rgb565>> Default for SeriesPalette<C, 8> {
+    fn default() -> Self {
+        Self {
+            colors: DEFAULT_SERIES_COLORS
+                .iter()
+                .map(|&color| color.into())
+                .collect(),
+        }
+    }
+}
+
 /// Predefined color palettes for RGB565
+///
+/// Each palette is available as a constant borrowing a color array in flash,
+/// and through a function returning the same palette.
 #[cfg(feature = "color-support")]
 pub mod rgb565_palettes {
     use super::*;
-    use embedded_graphics::pixelcolor::Rgb565;
+
+    /// Default color palette with modern, vibrant colors
+    pub const DEFAULT: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&DEFAULT_SERIES_COLORS);
+
+    /// Professional color palette with sophisticated colors
+    pub const PROFESSIONAL: ColorPalette<Rgb565, 8> =
+        ColorPalette::from_static(&PROFESSIONAL_COLORS);
+    const PROFESSIONAL_COLORS: [Rgb565; 8] = [
+        Rgb565::new(30 >> 3, 58 >> 2, 138 >> 3),  // Navy blue
+        Rgb565::new(185 >> 3, 28 >> 2, 28 >> 3),  // Dark red
+        Rgb565::new(21 >> 3, 128 >> 2, 61 >> 3),  // Forest green
+        Rgb565::new(217 >> 3, 119 >> 2, 6 >> 3),  // Orange
+        Rgb565::new(88 >> 3, 28 >> 2, 135 >> 3),  // Indigo
+        Rgb565::new(14 >> 3, 116 >> 2, 144 >> 3), // Teal
+        Rgb565::new(120 >> 3, 53 >> 2, 15 >> 3),  // Brown
+        Rgb565::new(75 >> 3, 85 >> 2, 99 >> 3),   // Slate gray
+    ];
+
+    /// Pastel color palette for gentle, soothing appearance
+    pub const PASTEL: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&PASTEL_COLORS);
+    const PASTEL_COLORS: [Rgb565; 8] = [
+        Rgb565::new(147 >> 3, 197 >> 2, 253 >> 3), // Sky blue
+        Rgb565::new(252 >> 3, 165 >> 2, 165 >> 3), // Light pink
+        Rgb565::new(167 >> 3, 243 >> 2, 208 >> 3), // Mint green
+        Rgb565::new(254 >> 3, 215 >> 2, 170 >> 3), // Peach
+        Rgb565::new(196 >> 3, 181 >> 2, 253 >> 3), // Lavender
+        Rgb565::new(165 >> 3, 243 >> 2, 252 >> 3), // Light cyan
+        Rgb565::new(254 >> 3, 202 >> 2, 202 >> 3), // Light coral
+        Rgb565::new(253 >> 3, 230 >> 2, 138 >> 3), // Light yellow
+    ];
+
+    /// Vibrant color palette for energetic designs
+    pub const VIBRANT: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&VIBRANT_COLORS);
+    const VIBRANT_COLORS: [Rgb565; 8] = [
+        Rgb565::new(236 >> 3, 72 >> 2, 153 >> 3),  // Hot pink
+        Rgb565::new(14 >> 3, 165 >> 2, 233 >> 3),  // Sky blue
+        Rgb565::new(16 >> 3, 185 >> 2, 129 >> 3),  // Teal green
+        Rgb565::new(245 >> 3, 101 >> 2, 101 >> 3), // Coral
+        Rgb565::new(168 >> 3, 85 >> 2, 247 >> 3),  // Electric purple
+        Rgb565::new(251 >> 3, 191 >> 2, 36 >> 3),  // Bright yellow
+        Rgb565::new(220 >> 3, 38 >> 2, 127 >> 3),  // Deep pink
+        Rgb565::new(6 >> 3, 182 >> 2, 212 >> 3),   // Bright cyan
+    ];
+
+    /// Nature-inspired color palette with earth tones
+    pub const NATURE: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&NATURE_COLORS);
+    const NATURE_COLORS: [Rgb565; 8] = [
+        Rgb565::new(34 >> 3, 139 >> 2, 34 >> 3),  // Forest green
+        Rgb565::new(139 >> 3, 69 >> 2, 19 >> 3),  // Saddle brown
+        Rgb565::new(107 >> 3, 142 >> 2, 35 >> 3), // Olive green
+        Rgb565::new(218 >> 3, 165 >> 2, 32 >> 3), // Goldenrod
+        Rgb565::new(72 >> 3, 187 >> 2, 120 >> 3), // Medium sea green
+        Rgb565::new(160 >> 3, 82 >> 2, 45 >> 3),  // Sienna
+        Rgb565::new(85 >> 3, 107 >> 2, 47 >> 3),  // Dark olive green
+        Rgb565::new(205 >> 3, 133 >> 2, 63 >> 3), // Peru
+    ];
+
+    /// Ocean-inspired color palette with blue tones
+    pub const OCEAN: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&OCEAN_COLORS);
+    const OCEAN_COLORS: [Rgb565; 8] = [
+        Rgb565::new(30 >> 3, 144 >> 2, 255 >> 3),  // Dodger blue
+        Rgb565::new(0 >> 3, 191 >> 2, 255 >> 3),   // Deep sky blue
+        Rgb565::new(72 >> 3, 209 >> 2, 204 >> 3),  // Medium turquoise
+        Rgb565::new(32 >> 3, 178 >> 2, 170 >> 3),  // Light sea green
+        Rgb565::new(95 >> 3, 158 >> 2, 160 >> 3),  // Cadet blue
+        Rgb565::new(70 >> 3, 130 >> 2, 180 >> 3),  // Steel blue
+        Rgb565::new(123 >> 3, 104 >> 2, 238 >> 3), // Medium slate blue
+        Rgb565::new(25 >> 3, 25 >> 2, 112 >> 3),   // Midnight blue
+    ];
+
+    /// Sunset-inspired color palette with warm tones
+    pub const SUNSET: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&SUNSET_COLORS);
+    const SUNSET_COLORS: [Rgb565; 8] = [
+        Rgb565::new(255 >> 3, 99 >> 2, 71 >> 3),  // Tomato
+        Rgb565::new(255 >> 3, 165 >> 2, 0 >> 3),  // Orange
+        Rgb565::new(255 >> 3, 215 >> 2, 0 >> 3),  // Gold
+        Rgb565::new(255 >> 3, 20 >> 2, 147 >> 3), // Deep pink
+        Rgb565::new(255 >> 3, 140 >> 2, 0 >> 3),  // Dark orange
+        Rgb565::new(220 >> 3, 20 >> 2, 60 >> 3),  // Crimson
+        Rgb565::new(255 >> 3, 69 >> 2, 0 >> 3),   // Red orange
+        Rgb565::new(178 >> 3, 34 >> 2, 34 >> 3),  // Fire brick
+    ];
+
+    /// Cyberpunk-inspired color palette with neon colors
+    pub const CYBERPUNK: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&CYBERPUNK_COLORS);
+    const CYBERPUNK_COLORS: [Rgb565; 8] = [
+        Rgb565::new(0 >> 3, 255 >> 2, 255 >> 3),  // Cyan
+        Rgb565::new(255 >> 3, 0 >> 2, 255 >> 3),  // Magenta
+        Rgb565::new(0 >> 3, 255 >> 2, 127 >> 3),  // Spring green
+        Rgb565::new(255 >> 3, 255 >> 2, 0 >> 3),  // Yellow
+        Rgb565::new(50 >> 3, 205 >> 2, 50 >> 3),  // Lime green
+        Rgb565::new(255 >> 3, 165 >> 2, 0 >> 3),  // Orange
+        Rgb565::new(255 >> 3, 69 >> 2, 0 >> 3),   // Red orange
+        Rgb565::new(138 >> 3, 43 >> 2, 226 >> 3), // Blue violet
+    ];
+
+    /// High contrast palette for accessibility
+    pub const HIGH_CONTRAST: ColorPalette<Rgb565, 6> =
+        ColorPalette::from_static(&HIGH_CONTRAST_COLORS);
+    const HIGH_CONTRAST_COLORS: [Rgb565; 6] = [
+        Rgb565::BLACK,
+        Rgb565::WHITE,
+        Rgb565::new(255 >> 3, 0 >> 2, 0 >> 3),   // Pure red
+        Rgb565::new(0 >> 3, 0 >> 2, 255 >> 3),   // Pure blue
+        Rgb565::new(0 >> 3, 255 >> 2, 0 >> 3),   // Pure green
+        Rgb565::new(255 >> 3, 255 >> 2, 0 >> 3), // Pure yellow
+    ];
+
+    /// Monochrome palette using different shades of gray
+    pub const MONOCHROME: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&MONOCHROME_COLORS);
+    const MONOCHROME_COLORS: [Rgb565; 8] = [
+        Rgb565::BLACK,
+        Rgb565::new(32 >> 3, 32 >> 2, 32 >> 3), // Very dark gray
+        Rgb565::new(64 >> 3, 64 >> 2, 64 >> 3), // Dark gray
+        Rgb565::new(96 >> 3, 96 >> 2, 96 >> 3), // Medium dark gray
+        Rgb565::new(128 >> 3, 128 >> 2, 128 >> 3), // Gray
+        Rgb565::new(160 >> 3, 160 >> 2, 160 >> 3), // Medium light gray
+        Rgb565::new(192 >> 3, 192 >> 2, 192 >> 3), // Light gray
+        Rgb565::WHITE,
+    ];
+
+    /// Minimal palette with subtle, sophisticated colors
+    pub const MINIMAL: ColorPalette<Rgb565, 6> = ColorPalette::from_static(&MINIMAL_COLORS);
+    const MINIMAL_COLORS: [Rgb565; 6] = [
+        Rgb565::new(55 >> 3, 65 >> 2, 81 >> 3),    // Slate gray
+        Rgb565::new(107 >> 3, 114 >> 2, 128 >> 3), // Slate gray
+        Rgb565::new(148 >> 3, 163 >> 2, 184 >> 3), // Light slate gray
+        Rgb565::new(99 >> 3, 102 >> 2, 241 >> 3),  // Indigo
+        Rgb565::new(16 >> 3, 185 >> 2, 129 >> 3),  // Emerald
+        Rgb565::new(239 >> 3, 68 >> 2, 68 >> 3),   // Red
+    ];
+
+    /// Retro palette with vintage-inspired colors
+    pub const RETRO: ColorPalette<Rgb565, 8> = ColorPalette::from_static(&RETRO_COLORS);
+    const RETRO_COLORS: [Rgb565; 8] = [
+        Rgb565::new(205 >> 3, 92 >> 2, 92 >> 3),   // Indian red
+        Rgb565::new(218 >> 3, 165 >> 2, 32 >> 3),  // Goldenrod
+        Rgb565::new(107 >> 3, 142 >> 2, 35 >> 3),  // Olive drab
+        Rgb565::new(160 >> 3, 82 >> 2, 45 >> 3),   // Sienna
+        Rgb565::new(188 >> 3, 143 >> 2, 143 >> 3), // Rosy brown
+        Rgb565::new(222 >> 3, 184 >> 2, 135 >> 3), // Burlywood
+        Rgb565::new(139 >> 3, 69 >> 2, 19 >> 3),   // Saddle brown
+        Rgb565::new(205 >> 3, 133 >> 2, 63 >> 3),  // Peru
+    ];
 
     /// Default color palette with modern, vibrant colors
     pub fn default_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(59 >> 3, 130 >> 2, 246 >> 3),  // Modern blue
-            Rgb565::new(239 >> 3, 68 >> 2, 68 >> 3),   // Modern red
-            Rgb565::new(34 >> 3, 197 >> 2, 94 >> 3),   // Emerald green
-            Rgb565::new(245 >> 3, 158 >> 2, 11 >> 3),  // Amber
-            Rgb565::new(147 >> 3, 51 >> 2, 234 >> 3),  // Purple
-            Rgb565::new(6 >> 3, 182 >> 2, 212 >> 3),   // Cyan
-            Rgb565::new(251 >> 3, 113 >> 2, 133 >> 3), // Rose
-            Rgb565::new(168 >> 3, 85 >> 2, 247 >> 3),  // Violet
-        ])
-        .unwrap()
+        DEFAULT
     }
 
     /// Professional color palette with sophisticated colors
     pub fn professional_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(30 >> 3, 58 >> 2, 138 >> 3),  // Navy blue
-            Rgb565::new(185 >> 3, 28 >> 2, 28 >> 3),  // Dark red
-            Rgb565::new(21 >> 3, 128 >> 2, 61 >> 3),  // Forest green
-            Rgb565::new(217 >> 3, 119 >> 2, 6 >> 3),  // Orange
-            Rgb565::new(88 >> 3, 28 >> 2, 135 >> 3),  // Indigo
-            Rgb565::new(14 >> 3, 116 >> 2, 144 >> 3), // Teal
-            Rgb565::new(120 >> 3, 53 >> 2, 15 >> 3),  // Brown
-            Rgb565::new(75 >> 3, 85 >> 2, 99 >> 3),   // Slate gray
-        ])
-        .unwrap()
+        PROFESSIONAL
     }
 
     /// Pastel color palette for gentle, soothing appearance
     pub fn pastel_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(147 >> 3, 197 >> 2, 253 >> 3), // Sky blue
-            Rgb565::new(252 >> 3, 165 >> 2, 165 >> 3), // Light pink
-            Rgb565::new(167 >> 3, 243 >> 2, 208 >> 3), // Mint green
-            Rgb565::new(254 >> 3, 215 >> 2, 170 >> 3), // Peach
-            Rgb565::new(196 >> 3, 181 >> 2, 253 >> 3), // Lavender
-            Rgb565::new(165 >> 3, 243 >> 2, 252 >> 3), // Light cyan
-            Rgb565::new(254 >> 3, 202 >> 2, 202 >> 3), // Light coral
-            Rgb565::new(253 >> 3, 230 >> 2, 138 >> 3), // Light yellow
-        ])
-        .unwrap()
+        PASTEL
     }
 
     /// Vibrant color palette for energetic designs
     pub fn vibrant_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(236 >> 3, 72 >> 2, 153 >> 3),  // Hot pink
-            Rgb565::new(14 >> 3, 165 >> 2, 233 >> 3),  // Sky blue
-            Rgb565::new(16 >> 3, 185 >> 2, 129 >> 3),  // Teal green
-            Rgb565::new(245 >> 3, 101 >> 2, 101 >> 3), // Coral
-            Rgb565::new(168 >> 3, 85 >> 2, 247 >> 3),  // Electric purple
-            Rgb565::new(251 >> 3, 191 >> 2, 36 >> 3),  // Bright yellow
-            Rgb565::new(220 >> 3, 38 >> 2, 127 >> 3),  // Deep pink
-            Rgb565::new(6 >> 3, 182 >> 2, 212 >> 3),   // Bright cyan
-        ])
-        .unwrap()
+        VIBRANT
     }
 
     /// Nature-inspired color palette with earth tones
     pub fn nature_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(34 >> 3, 139 >> 2, 34 >> 3),  // Forest green
-            Rgb565::new(139 >> 3, 69 >> 2, 19 >> 3),  // Saddle brown
-            Rgb565::new(107 >> 3, 142 >> 2, 35 >> 3), // Olive green
-            Rgb565::new(218 >> 3, 165 >> 2, 32 >> 3), // Goldenrod
-            Rgb565::new(72 >> 3, 187 >> 2, 120 >> 3), // Medium sea green
-            Rgb565::new(160 >> 3, 82 >> 2, 45 >> 3),  // Sienna
-            Rgb565::new(85 >> 3, 107 >> 2, 47 >> 3),  // Dark olive green
-            Rgb565::new(205 >> 3, 133 >> 2, 63 >> 3), // Peru
-        ])
-        .unwrap()
+        NATURE
     }
 
     /// Ocean-inspired color palette with blue tones
     pub fn ocean_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(30 >> 3, 144 >> 2, 255 >> 3),  // Dodger blue
-            Rgb565::new(0 >> 3, 191 >> 2, 255 >> 3),   // Deep sky blue
-            Rgb565::new(72 >> 3, 209 >> 2, 204 >> 3),  // Medium turquoise
-            Rgb565::new(32 >> 3, 178 >> 2, 170 >> 3),  // Light sea green
-            Rgb565::new(95 >> 3, 158 >> 2, 160 >> 3),  // Cadet blue
-            Rgb565::new(70 >> 3, 130 >> 2, 180 >> 3),  // Steel blue
-            Rgb565::new(123 >> 3, 104 >> 2, 238 >> 3), // Medium slate blue
-            Rgb565::new(25 >> 3, 25 >> 2, 112 >> 3),   // Midnight blue
-        ])
-        .unwrap()
+        OCEAN
     }
 
     /// Sunset-inspired color palette with warm tones
     pub fn sunset_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(255 >> 3, 99 >> 2, 71 >> 3),  // Tomato
-            Rgb565::new(255 >> 3, 165 >> 2, 0 >> 3),  // Orange
-            Rgb565::new(255 >> 3, 215 >> 2, 0 >> 3),  // Gold
-            Rgb565::new(255 >> 3, 20 >> 2, 147 >> 3), // Deep pink
-            Rgb565::new(255 >> 3, 140 >> 2, 0 >> 3),  // Dark orange
-            Rgb565::new(220 >> 3, 20 >> 2, 60 >> 3),  // Crimson
-            Rgb565::new(255 >> 3, 69 >> 2, 0 >> 3),   // Red orange
-            Rgb565::new(178 >> 3, 34 >> 2, 34 >> 3),  // Fire brick
-        ])
-        .unwrap()
+        SUNSET
     }
 
     /// Cyberpunk-inspired color palette with neon colors
     pub fn cyberpunk_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(0 >> 3, 255 >> 2, 255 >> 3),  // Cyan
-            Rgb565::new(255 >> 3, 0 >> 2, 255 >> 3),  // Magenta
-            Rgb565::new(0 >> 3, 255 >> 2, 127 >> 3),  // Spring green
-            Rgb565::new(255 >> 3, 255 >> 2, 0 >> 3),  // Yellow
-            Rgb565::new(50 >> 3, 205 >> 2, 50 >> 3),  // Lime green
-            Rgb565::new(255 >> 3, 165 >> 2, 0 >> 3),  // Orange
-            Rgb565::new(255 >> 3, 69 >> 2, 0 >> 3),   // Red orange
-            Rgb565::new(138 >> 3, 43 >> 2, 226 >> 3), // Blue violet
-        ])
-        .unwrap()
+        CYBERPUNK
     }
 
     /// High contrast palette for accessibility
     pub fn high_contrast_palette() -> ColorPalette<Rgb565, 6> {
-        ColorPalette::from_colors(&[
-            Rgb565::BLACK,
-            Rgb565::WHITE,
-            Rgb565::new(255 >> 3, 0 >> 2, 0 >> 3),   // Pure red
-            Rgb565::new(0 >> 3, 0 >> 2, 255 >> 3),   // Pure blue
-            Rgb565::new(0 >> 3, 255 >> 2, 0 >> 3),   // Pure green
-            Rgb565::new(255 >> 3, 255 >> 2, 0 >> 3), // Pure yellow
-        ])
-        .unwrap()
+        HIGH_CONTRAST
     }
 
     /// Monochrome palette using different shades of gray
     pub fn monochrome_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::BLACK,
-            Rgb565::new(32 >> 3, 32 >> 2, 32 >> 3), // Very dark gray
-            Rgb565::new(64 >> 3, 64 >> 2, 64 >> 3), // Dark gray
-            Rgb565::new(96 >> 3, 96 >> 2, 96 >> 3), // Medium dark gray
-            Rgb565::new(128 >> 3, 128 >> 2, 128 >> 3), // Gray
-            Rgb565::new(160 >> 3, 160 >> 2, 160 >> 3), // Medium light gray
-            Rgb565::new(192 >> 3, 192 >> 2, 192 >> 3), // Light gray
-            Rgb565::WHITE,
-        ])
-        .unwrap()
+        MONOCHROME
     }
 
     /// Minimal palette with subtle, sophisticated colors
     pub fn minimal_palette() -> ColorPalette<Rgb565, 6> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(55 >> 3, 65 >> 2, 81 >> 3),    // Slate gray
-            Rgb565::new(107 >> 3, 114 >> 2, 128 >> 3), // Slate gray
-            Rgb565::new(148 >> 3, 163 >> 2, 184 >> 3), // Light slate gray
-            Rgb565::new(99 >> 3, 102 >> 2, 241 >> 3),  // Indigo
-            Rgb565::new(16 >> 3, 185 >> 2, 129 >> 3),  // Emerald
-            Rgb565::new(239 >> 3, 68 >> 2, 68 >> 3),   // Red
-        ])
-        .unwrap()
+        MINIMAL
     }
 
     /// Retro palette with vintage-inspired colors
     pub fn retro_palette() -> ColorPalette<Rgb565, 8> {
-        ColorPalette::from_colors(&[
-            Rgb565::new(205 >> 3, 92 >> 2, 92 >> 3),   // Indian red
-            Rgb565::new(218 >> 3, 165 >> 2, 32 >> 3),  // Goldenrod
-            Rgb565::new(107 >> 3, 142 >> 2, 35 >> 3),  // Olive drab
-            Rgb565::new(160 >> 3, 82 >> 2, 45 >> 3),   // Sienna
-            Rgb565::new(188 >> 3, 143 >> 2, 143 >> 3), // Rosy brown
-            Rgb565::new(222 >> 3, 184 >> 2, 135 >> 3), // Burlywood
-            Rgb565::new(139 >> 3, 69 >> 2, 19 >> 3),   // Saddle brown
-            Rgb565::new(205 >> 3, 133 >> 2, 63 >> 3),  // Peru
-        ])
-        .unwrap()
+        RETRO
     }
 }
 
@@ -413,6 +575,45 @@ mod tests {
         assert!(!palette.is_empty());
     }
 
+    #[test]
+    fn test_static_palette() {
+        use embedded_graphics::pixelcolor::BinaryColor;
+        const COLORS: [BinaryColor; 2] = [BinaryColor::On, BinaryColor::Off];
+        static PALETTE: ColorPalette<BinaryColor, 3> = ColorPalette::from_static(&COLORS);
+
+        let mut palette = PALETTE.clone();
+        assert!(palette.is_static());
+        assert_eq!(palette.next_color(), Some(BinaryColor::On));
+        assert_eq!(palette.get_color(1), Some(BinaryColor::Off));
+
+        // Adding a color switches to owned storage and keeps the borrowed colors
+        palette.add_color(BinaryColor::On).unwrap();
+        assert!(!palette.is_static());
+        assert_eq!(
+            palette.as_slice(),
+            &[BinaryColor::On, BinaryColor::Off, BinaryColor::On]
+        );
+        assert!(palette.add_color(BinaryColor::Off).is_err());
+    }
+
+    #[test]
+    fn test_series_palette() {
+        use embedded_graphics::pixelcolor::BinaryColor;
+        let palette: SeriesPalette<BinaryColor, 4> =
+            SeriesPalette::from_colors(&[BinaryColor::On, BinaryColor::Off]).unwrap();
+
+        assert_eq!(palette.color(3), BinaryColor::Off);
+        assert!(palette
+            .colors()
+            .take(5)
+            .eq((0..5).map(|i| palette.color(i))));
+        assert!(SeriesPalette::<BinaryColor, 4>::from_colors(&[]).is_err());
+
+        let standard: SeriesPalette<embedded_graphics::pixelcolor::Rgb565, 8> =
+            SeriesPalette::default();
+        assert_eq!(standard.len(), 8);
+    }
+
     #[test]
     fn test_color_palette_cycling() {
         use embedded_graphics::pixelcolor::BinaryColor;
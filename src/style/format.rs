@@ -0,0 +1,335 @@
+//! Value formatting with units.
+//!
+//! A [`ValueFormatter`] turns a number into label text without going through
+//! `core::fmt`, which keeps formatting code out of small firmware images. The
+//! formatters are small `Copy` values, and [`LabelFormat`] wraps all of them so
+//! a format can be stored on an axis, data label or gauge and switched at
+//! runtime.
+
+use heapless::String;
+
+/// Text produced by a [`ValueFormatter`]
+pub type FormattedValue = String<24>;
+
+/// Number to text conversion used by axis labels, data labels and gauges
+pub trait ValueFormatter {
+    /// Append the formatted value to `out`.
+    ///
+    /// Returns `false` if the text did not fit; `out` then holds as much of it
+    /// as fit.
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool;
+
+    /// Format a value into a new string
+    fn format(&self, value: f32) -> FormattedValue {
+        let mut out = FormattedValue::new();
+        self.format_into(value, &mut out);
+        out
+    }
+}
+
+/// Fixed number of decimals followed by an optional unit, e.g. `12.5 V`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedDecimals {
+    /// Digits after the decimal point (at most 6)
+    pub decimals: u8,
+    /// Unit text appended verbatim, including any leading space
+    pub unit: &'static str,
+}
+
+impl FixedDecimals {
+    /// Create a formatter without a unit
+    pub const fn new(decimals: u8) -> Self {
+        Self { decimals, unit: "" }
+    }
+
+    /// Append `unit` to every value
+    pub const fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl ValueFormatter for FixedDecimals {
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool {
+        push_fixed(out, value, self.decimals) && out.push_str(self.unit).is_ok()
+    }
+}
+
+/// Engineering notation with SI prefixes, e.g. `4.7 kΩ` or `220 nF`
+///
+/// Prefixes run from pico to tera; micro is written as `u` so it renders with
+/// ASCII fonts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SiPrefix {
+    /// Digits after the decimal point (at most 6)
+    pub decimals: u8,
+    /// Unit text placed after the prefix, e.g. `"Hz"`
+    pub unit: &'static str,
+    /// Text placed between the number and the prefix, e.g. `" "`
+    pub separator: &'static str,
+}
+
+impl SiPrefix {
+    /// Create a formatter for `unit` with a single space before the prefix
+    pub const fn new(decimals: u8, unit: &'static str) -> Self {
+        Self {
+            decimals,
+            unit,
+            separator: " ",
+        }
+    }
+
+    /// Scale a value into `[1, 1000)` and pick its prefix
+    fn scale(value: f32) -> (f32, &'static str) {
+        const PREFIXES: [(f32, &str); 9] = [
+            (1e12, "T"),
+            (1e9, "G"),
+            (1e6, "M"),
+            (1e3, "k"),
+            (1.0, ""),
+            (1e-3, "m"),
+            (1e-6, "u"),
+            (1e-9, "n"),
+            (1e-12, "p"),
+        ];
+
+        let magnitude = if value < 0.0 { -value } else { value };
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return (value, "");
+        }
+        for (factor, prefix) in PREFIXES {
+            if magnitude >= factor {
+                return (value / factor, prefix);
+            }
+        }
+        (value / 1e-12, "p")
+    }
+}
+
+impl ValueFormatter for SiPrefix {
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool {
+        let (scaled, prefix) = Self::scale(value);
+        push_fixed(out, scaled, self.decimals)
+            && out.push_str(self.separator).is_ok()
+            && out.push_str(prefix).is_ok()
+            && out.push_str(self.unit).is_ok()
+    }
+}
+
+/// Percentage, e.g. `42%`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percent {
+    /// Digits after the decimal point (at most 6)
+    pub decimals: u8,
+    /// Whether values are ratios (`0.42`) rather than percentages (`42.0`)
+    pub ratio: bool,
+}
+
+impl Percent {
+    /// Format values that are already percentages
+    pub const fn new(decimals: u8) -> Self {
+        Self {
+            decimals,
+            ratio: false,
+        }
+    }
+
+    /// Format ratios in `0..=1` as percentages
+    pub const fn of_ratio(decimals: u8) -> Self {
+        Self {
+            decimals,
+            ratio: true,
+        }
+    }
+}
+
+impl ValueFormatter for Percent {
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool {
+        let percent = if self.ratio { value * 100.0 } else { value };
+        push_fixed(out, percent, self.decimals) && out.push('%').is_ok()
+    }
+}
+
+/// Time span in seconds, e.g. `12.5s`, `3m05s` or `1h02m`
+///
+/// Spans below a minute keep `decimals` fractional digits; longer spans are
+/// shown with their two largest whole units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DurationFormat {
+    /// Digits after the decimal point for spans below a minute
+    pub decimals: u8,
+}
+
+impl ValueFormatter for DurationFormat {
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool {
+        if !value.is_finite() {
+            return push_fixed(out, value, 0);
+        }
+        let seconds = if value < 0.0 {
+            if out.push('-').is_err() {
+                return false;
+            }
+            -value
+        } else {
+            value
+        };
+
+        if seconds < 60.0 {
+            return push_fixed(out, seconds, self.decimals) && out.push('s').is_ok();
+        }
+
+        let total = (seconds + 0.5) as u32;
+        let (major, minor, units) = if total < 3600 {
+            (total / 60, total % 60, ['m', 's'])
+        } else if total < 86_400 {
+            (total / 3600, total % 3600 / 60, ['h', 'm'])
+        } else {
+            (total / 86_400, total % 86_400 / 3600, ['d', 'h'])
+        };
+
+        push_digits(out, major as u64, 1)
+            && out.push(units[0]).is_ok()
+            && push_digits(out, minor as u64, 2)
+            && out.push(units[1]).is_ok()
+    }
+}
+
+/// Any of the built-in formatters, selectable per axis or series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelFormat {
+    /// Fixed decimals with an optional unit
+    Fixed(FixedDecimals),
+    /// Engineering notation with SI prefixes
+    SiPrefix(SiPrefix),
+    /// Percentage
+    Percent(Percent),
+    /// Time span in seconds
+    Duration(DurationFormat),
+}
+
+impl Default for LabelFormat {
+    fn default() -> Self {
+        Self::Fixed(FixedDecimals::new(0))
+    }
+}
+
+impl ValueFormatter for LabelFormat {
+    fn format_into<const N: usize>(&self, value: f32, out: &mut String<N>) -> bool {
+        match self {
+            Self::Fixed(format) => format.format_into(value, out),
+            Self::SiPrefix(format) => format.format_into(value, out),
+            Self::Percent(format) => format.format_into(value, out),
+            Self::Duration(format) => format.format_into(value, out),
+        }
+    }
+}
+
+impl From<FixedDecimals> for LabelFormat {
+    fn from(format: FixedDecimals) -> Self {
+        Self::Fixed(format)
+    }
+}
+
+impl From<SiPrefix> for LabelFormat {
+    fn from(format: SiPrefix) -> Self {
+        Self::SiPrefix(format)
+    }
+}
+
+impl From<Percent> for LabelFormat {
+    fn from(format: Percent) -> Self {
+        Self::Percent(format)
+    }
+}
+
+impl From<DurationFormat> for LabelFormat {
+    fn from(format: DurationFormat) -> Self {
+        Self::Duration(format)
+    }
+}
+
+/// Append `value` rounded to `decimals` digits, without `core::fmt`
+fn push_fixed<const N: usize>(out: &mut String<N>, value: f32, decimals: u8) -> bool {
+    if value.is_nan() {
+        return out.push_str("NaN").is_ok();
+    }
+    if value.is_infinite() {
+        let text = if value < 0.0 { "-inf" } else { "inf" };
+        return out.push_str(text).is_ok();
+    }
+
+    let decimals = decimals.min(6) as u32;
+    let scale = 10u64.pow(decimals);
+    let magnitude = if value < 0.0 { -value } else { value };
+    let scaled = (magnitude as f64 * scale as f64 + 0.5) as u64;
+
+    // Only print the sign if something non-zero survives rounding
+    if value < 0.0 && scaled != 0 && out.push('-').is_err() {
+        return false;
+    }
+    if !push_digits(out, scaled / scale, 1) {
+        return false;
+    }
+    if decimals > 0 {
+        return out.push('.').is_ok() && push_digits(out, scaled % scale, decimals as usize);
+    }
+    true
+}
+
+/// Append an unsigned integer, zero-padded to at least `width` digits
+fn push_digits<const N: usize>(out: &mut String<N>, mut value: u64, width: usize) -> bool {
+    let mut digits = [0u8; 20];
+    let mut count = 0;
+    while value > 0 || count < width.max(1) {
+        digits[count] = b'0' + (value % 10) as u8;
+        value /= 10;
+        count += 1;
+    }
+    digits[..count]
+        .iter()
+        .rev()
+        .all(|&digit| out.push(digit as char).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_and_percent_formatting() {
+        assert_eq!(
+            FixedDecimals::new(1).with_unit(" V").format(12.46),
+            "12.5 V"
+        );
+        assert_eq!(FixedDecimals::new(0).format(-0.4), "0");
+        assert_eq!(FixedDecimals::new(2).format(-3.004), "-3.00");
+        assert_eq!(Percent::new(0).format(42.4), "42%");
+        assert_eq!(Percent::of_ratio(1).format(0.256), "25.6%");
+    }
+
+    #[test]
+    fn test_si_prefix_formatting() {
+        let ohms = SiPrefix::new(1, "Ohm");
+        assert_eq!(ohms.format(4700.0), "4.7 kOhm");
+        assert_eq!(ohms.format(0.0), "0.0 Ohm");
+        assert_eq!(SiPrefix::new(0, "F").format(220e-9), "220 nF");
+        assert_eq!(SiPrefix::new(2, "Hz").format(-1.5e6), "-1.50 MHz");
+    }
+
+    #[test]
+    fn test_duration_formatting() {
+        let duration = DurationFormat { decimals: 1 };
+        assert_eq!(duration.format(12.54), "12.5s");
+        assert_eq!(duration.format(185.0), "3m05s");
+        assert_eq!(duration.format(3720.0), "1h02m");
+        assert_eq!(duration.format(90_000.0), "1d01h");
+    }
+
+    #[test]
+    fn test_label_format_reports_truncation() {
+        let mut out: String<4> = String::new();
+        let format = LabelFormat::from(FixedDecimals::new(2));
+        assert!(!format.format_into(1234.5, &mut out));
+        assert!(format.format(1234.5) == "1234.50");
+    }
+}
@@ -137,6 +137,66 @@ impl<C: PixelColor, const N: usize> LinearGradient<C, N> {
     }
 }
 
+/// Linear gradient mapped onto a range of data values
+///
+/// Unlike a screen-space gradient, the color depends on the value a pixel
+/// represents: `min` maps to the first stop and `max` to the last, so e.g. a
+/// temperature area can run from blue at low readings to red at high ones
+/// regardless of the chart size. The direction of the wrapped gradient is
+/// ignored.
+#[derive(Debug, Clone)]
+pub struct ValueGradient<C: PixelColor, const N: usize = MAX_GRADIENT_STOPS> {
+    /// Gradient sampled by normalized value
+    gradient: LinearGradient<C, N>,
+    /// Value mapped to the start of the gradient
+    min: f32,
+    /// Value mapped to the end of the gradient
+    max: f32,
+}
+
+impl<C: PixelColor, const N: usize> ValueGradient<C, N> {
+    /// Map `gradient` onto the values `min..=max`
+    ///
+    /// # Errors
+    ///
+    /// - [`ChartError::InvalidConfiguration`] if the gradient has fewer than two stops
+    /// - [`ChartError::InvalidRange`] if the range is empty or not finite
+    pub fn new(gradient: LinearGradient<C, N>, min: f32, max: f32) -> Result<Self, ChartError> {
+        if !gradient.is_valid() {
+            return Err(ChartError::InvalidConfiguration);
+        }
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(ChartError::InvalidRange);
+        }
+        Ok(Self { gradient, min, max })
+    }
+
+    /// Create a two-color gradient from `low` at `min` to `high` at `max`
+    pub fn simple(low: C, high: C, min: f32, max: f32) -> Result<Self, ChartError> {
+        Self::new(
+            LinearGradient::simple(low, high, GradientDirection::Vertical)?,
+            min,
+            max,
+        )
+    }
+
+    /// Get the color for a data value; values outside the range are clamped
+    pub fn color_for_value(&self, value: f32) -> Option<C> {
+        self.gradient
+            .color_at((value - self.min) / (self.max - self.min))
+    }
+
+    /// Get the value range the gradient covers
+    pub fn range(&self) -> (f32, f32) {
+        (self.min, self.max)
+    }
+
+    /// Get the underlying gradient
+    pub fn gradient(&self) -> &LinearGradient<C, N> {
+        &self.gradient
+    }
+}
+
 /// Extension trait for color interpolation with gradients
 #[cfg(feature = "color-support")]
 pub trait GradientInterpolation<C: PixelColor> {
@@ -376,8 +436,19 @@ pub enum PatternType {
         /// Width of each line in pixels
         width: u32,
     },
+    /// Ordered dither, the foreground covering `level` sixteenths of the area
+    ///
+    /// Pixels are spread out with a 4x4 Bayer matrix, so 1-bit displays show
+    /// the fill as an even shade rather than a solid block.
+    Dither {
+        /// Foreground coverage from 0 (none) to 16 (solid)
+        level: u8,
+    },
 }
 
+/// Thresholds of a 4x4 Bayer matrix, indexed by row and column
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
 /// Pattern fill definition
 #[derive(Debug, Clone, Copy)]
 pub struct PatternFill<C: PixelColor> {
@@ -419,6 +490,7 @@ impl<C: PixelColor> PatternFill<C> {
                 let v = (x as u32 % spacing) < width;
                 h || v
             }
+            PatternType::Dither { level } => BAYER_4X4[(y & 3) as usize][(x & 3) as usize] < level,
         }
     }
 
@@ -472,4 +544,46 @@ mod tests {
         assert_eq!(pattern.color_at(0, 10), Rgb565::WHITE);
         assert_eq!(pattern.color_at(10, 10), Rgb565::BLACK);
     }
+
+    #[test]
+    fn test_dither_coverage() {
+        let coverage = |level| {
+            let pattern =
+                PatternFill::new(Rgb565::BLACK, Rgb565::WHITE, PatternType::Dither { level });
+            (-4..4)
+                .flat_map(|y| (-4..4).map(move |x| (x, y)))
+                .filter(|&(x, y)| pattern.is_foreground(x, y))
+                .count()
+        };
+
+        assert_eq!(coverage(0), 0);
+        assert_eq!(coverage(4), 16);
+        assert_eq!(coverage(8), 32);
+        assert_eq!(coverage(16), 64);
+
+        // Half coverage is a checkerboard
+        let half = PatternFill::new(
+            Rgb565::BLACK,
+            Rgb565::WHITE,
+            PatternType::Dither { level: 8 },
+        );
+        assert!(half.is_foreground(0, 0) && half.is_foreground(1, 1));
+        assert!(!half.is_foreground(1, 0) && !half.is_foreground(0, 1));
+    }
+
+    #[test]
+    fn test_value_gradient() {
+        let gradient: ValueGradient<Rgb565> =
+            ValueGradient::simple(Rgb565::BLUE, Rgb565::RED, -10.0, 40.0).unwrap();
+
+        assert_eq!(gradient.color_for_value(-10.0), Some(Rgb565::BLUE));
+        assert_eq!(gradient.color_for_value(40.0), Some(Rgb565::RED));
+        // Values outside the range clamp to the end colors
+        assert_eq!(gradient.color_for_value(-50.0), Some(Rgb565::BLUE));
+        assert_eq!(gradient.color_for_value(100.0), Some(Rgb565::RED));
+
+        assert!(ValueGradient::<Rgb565>::simple(Rgb565::BLUE, Rgb565::RED, 5.0, 5.0).is_err());
+        let empty: LinearGradient<Rgb565> = LinearGradient::new(GradientDirection::Vertical);
+        assert!(ValueGradient::new(empty, 0.0, 1.0).is_err());
+    }
 }
@@ -0,0 +1,132 @@
+//! Locale-aware number formatting.
+//!
+//! A [`NumberLocale`] describes the decimal separator and digit grouping used
+//! when numbers are turned into label text. It is a small `Copy` value, so the
+//! locale can be switched at runtime by handing a different value to an axis or
+//! label style without any allocation.
+
+use core::fmt::Write;
+use heapless::String;
+
+/// Decimal separator and digit grouping for formatted numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// Character placed between the integer and fractional digits
+    pub decimal_separator: char,
+    /// Character inserted between groups of three integer digits, if any
+    pub group_separator: Option<char>,
+}
+
+impl NumberLocale {
+    /// Plain formatting: `1234.5`, the default for all labels
+    pub const PLAIN: Self = Self::new('.', None);
+    /// English formatting: `1,234.5`
+    pub const ENGLISH: Self = Self::new('.', Some(','));
+    /// German formatting: `1.234,5`
+    pub const GERMAN: Self = Self::new(',', Some('.'));
+    /// French formatting: `1 234,5`
+    pub const FRENCH: Self = Self::new(',', Some(' '));
+    /// Swiss formatting: `1'234.5`
+    pub const SWISS: Self = Self::new('.', Some('\''));
+
+    /// Create a locale from its separators
+    pub const fn new(decimal_separator: char, group_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            group_separator,
+        }
+    }
+
+    /// Format `value` with a fixed number of decimals
+    pub fn format<const N: usize>(&self, value: f32, decimals: usize) -> String<N> {
+        let mut plain: String<32> = String::new();
+        let _ = write!(plain, "{value:.decimals$}");
+        self.localize(&plain)
+    }
+
+    /// Rewrite text that contains plainly formatted numbers for this locale.
+    ///
+    /// Digits before a `.` are grouped and the `.` between two digits becomes the
+    /// decimal separator; everything else, such as units or `k` suffixes, is
+    /// copied unchanged. Text that does not fit into `N` bytes is truncated.
+    pub fn localize<const N: usize>(&self, text: &str) -> String<N> {
+        let mut result = String::new();
+        let mut chars = text.chars().peekable();
+        let mut in_fraction = false;
+        let mut previous_digit = false;
+
+        while let Some(c) = chars.next() {
+            if c.is_ascii_digit() {
+                // Collect the whole digit run so groups can be counted from the right
+                let mut run: String<16> = String::new();
+                let _ = run.push(c);
+                while let Some(&next) = chars.peek() {
+                    if !next.is_ascii_digit() {
+                        break;
+                    }
+                    let _ = run.push(next);
+                    chars.next();
+                }
+
+                let len = run.len();
+                for (index, digit) in run.chars().enumerate() {
+                    if let (Some(separator), false) = (self.group_separator, in_fraction) {
+                        if index > 0 && (len - index) % 3 == 0 {
+                            let _ = result.push(separator);
+                        }
+                    }
+                    let _ = result.push(digit);
+                }
+                previous_digit = true;
+                continue;
+            }
+
+            let next_digit = chars.peek().is_some_and(|next| next.is_ascii_digit());
+            if c == '.' && previous_digit && next_digit && !in_fraction {
+                let _ = result.push(self.decimal_separator);
+                in_fraction = true;
+            } else {
+                let _ = result.push(c);
+                in_fraction = false;
+            }
+            previous_digit = false;
+        }
+
+        result
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_formatting() {
+        let value = 1234.5;
+        assert_eq!(NumberLocale::PLAIN.format::<16>(value, 1), "1234.5");
+        assert_eq!(NumberLocale::ENGLISH.format::<16>(value, 1), "1,234.5");
+        assert_eq!(NumberLocale::GERMAN.format::<16>(value, 1), "1.234,5");
+        assert_eq!(
+            NumberLocale::FRENCH.format::<16>(-1234567.0, 0),
+            "-1 234 567"
+        );
+        assert_eq!(NumberLocale::SWISS.format::<16>(100.25, 2), "100.25");
+    }
+
+    #[test]
+    fn test_localize_keeps_suffixes_and_fractions() {
+        assert_eq!(NumberLocale::GERMAN.localize::<16>("12.5k"), "12,5k");
+        assert_eq!(NumberLocale::GERMAN.localize::<16>("0.12345"), "0,12345");
+        assert_eq!(
+            NumberLocale::ENGLISH.localize::<24>("42% of 10000"),
+            "42% of 10,000"
+        );
+        assert_eq!(NumberLocale::GERMAN.localize::<16>("v1."), "v1.");
+    }
+}
@@ -240,21 +240,31 @@
 //! ### E-Paper Displays
 //! - Use monochrome or limited color palettes
 //! - Optimize for slow refresh rates
-//! - Use high contrast patterns
+//! - Use high contrast patterns, see [`MonochromeStyle`]
 //!
 //! ### TFT Displays
 //! - Take advantage of full color range
 //! - Use vibrant themes for better visibility
 //! - Optimize for fast refresh rates
 
+pub mod color_scale;
 pub mod colors;
 pub mod fonts;
+pub mod format;
 pub mod gradient;
 pub mod line;
+pub mod locale;
+pub mod monochrome;
+pub mod schedule;
 pub mod themes;
 
+pub use color_scale::*;
 pub use colors::*;
 pub use fonts::*;
+pub use format::*;
 pub use gradient::*;
 pub use line::*;
+pub use locale::*;
+pub use monochrome::*;
+pub use schedule::*;
 pub use themes::*;
@@ -0,0 +1,192 @@
+//! Styling for monochrome and grayscale displays.
+//!
+//! On 1-bit e-paper and OLED panels every color collapses to black or white:
+//! filled areas turn into solid blocks, grid lines look like data and series
+//! can't be told apart by color. A [`MonochromeStyle`] describes the display
+//! with an ink and a paper color and derives everything else from them:
+//!
+//! - area, bar and slice fills use dither and hatch [patterns](PatternType)
+//! - series are told apart by [line patterns](LinePattern) and fill patterns
+//! - grid lines are dotted, so they stay lighter than any data line
+//!
+//! Chart builders take it with their `monochrome` method. 4-level grayscale
+//! panels can add a [shade](MonochromeStyle::with_shade) for fills and grid
+//! lines.
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::style::MonochromeStyle;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//!
+//! let chart: LineChart<BinaryColor> = LineChart::builder()
+//!     .fill_area(BinaryColor::On)
+//!     .monochrome(MonochromeStyle::BINARY)
+//!     .build()?;
+//! assert!(chart.fill_style().is_some());
+//! # Ok::<(), embedded_charts::error::ChartError>(())
+//! ```
+
+use super::gradient::{PatternFill, PatternType};
+use super::line::{FillStyle, LinePattern, LineStyle};
+use super::themes::Theme;
+use crate::axes::style::{AxisStyle, LabelStyle, TickStyle};
+use embedded_graphics::pixelcolor::{BinaryColor, Gray2, GrayColor};
+use embedded_graphics::prelude::*;
+
+/// Line patterns series are told apart by, in order
+pub const SERIES_LINE_PATTERNS: [LinePattern; 4] = [
+    LinePattern::Solid,
+    LinePattern::Dashed,
+    LinePattern::Dotted,
+    LinePattern::DashDot,
+];
+
+/// Fill patterns series are told apart by, in order
+///
+/// Lighter patterns come first, so the fills of few series stay in the
+/// background of the lines drawn over them.
+pub const SERIES_FILL_PATTERNS: [PatternType; 6] = [
+    PatternType::Dither { level: 4 },
+    PatternType::DiagonalLines {
+        spacing: 4,
+        width: 1,
+    },
+    PatternType::Dither { level: 8 },
+    PatternType::CrossHatch {
+        spacing: 4,
+        width: 1,
+    },
+    PatternType::Dots {
+        spacing: 4,
+        radius: 1,
+    },
+    PatternType::HorizontalLines {
+        spacing: 3,
+        width: 1,
+    },
+];
+
+/// Ink and paper colors of a monochrome or grayscale display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonochromeStyle<C: PixelColor> {
+    /// Color of lines, text and markers
+    pub ink: C,
+    /// Background color
+    pub paper: C,
+    /// Color of fill patterns and grid lines, the ink on 1-bit displays
+    pub shade: C,
+}
+
+impl MonochromeStyle<BinaryColor> {
+    /// Style for 1-bit displays drawing with `On` pixels on an `Off` background
+    pub const BINARY: Self = Self::new(BinaryColor::On, BinaryColor::Off);
+}
+
+impl MonochromeStyle<Gray2> {
+    /// Style for 4-level grayscale displays, with dark gray fills and grid lines
+    pub const GRAY2: Self = Self::new(Gray2::BLACK, Gray2::WHITE).with_shade(Gray2::new(1));
+}
+
+impl<C: PixelColor> MonochromeStyle<C> {
+    /// Create a style drawing `ink` on `paper`
+    pub const fn new(ink: C, paper: C) -> Self {
+        Self {
+            ink,
+            paper,
+            shade: ink,
+        }
+    }
+
+    /// Set the color of fill patterns and grid lines
+    pub const fn with_shade(mut self, shade: C) -> Self {
+        self.shade = shade;
+        self
+    }
+
+    /// Get a theme with a paper background and everything else in ink
+    pub const fn theme(&self) -> Theme<C> {
+        Theme {
+            background: self.paper,
+            primary: self.ink,
+            secondary: self.ink,
+            text: self.ink,
+            grid: self.shade,
+            accent: self.ink,
+            success: self.ink,
+            warning: self.ink,
+            error: self.ink,
+        }
+    }
+
+    /// Get the line pattern of series `index`, cycling through
+    /// [`SERIES_LINE_PATTERNS`]
+    pub const fn series_pattern(&self, index: usize) -> LinePattern {
+        SERIES_LINE_PATTERNS[index % SERIES_LINE_PATTERNS.len()]
+    }
+
+    /// Get the fill of series `index`, cycling through [`SERIES_FILL_PATTERNS`]
+    pub const fn series_fill(&self, index: usize) -> PatternFill<C> {
+        PatternFill::new(
+            self.shade,
+            self.paper,
+            SERIES_FILL_PATTERNS[index % SERIES_FILL_PATTERNS.len()],
+        )
+    }
+
+    /// Get the fill style of series `index`
+    pub const fn series_fill_style(&self, index: usize) -> FillStyle<C> {
+        FillStyle::pattern(self.series_fill(index))
+    }
+
+    /// Get the style of grid lines, dotted so they don't look like data
+    pub const fn grid_line(&self) -> LineStyle<C> {
+        LineStyle::dotted(self.shade).width(1)
+    }
+
+    /// Get an axis style in ink with dotted grid lines
+    pub fn axis_style(&self) -> AxisStyle<C> {
+        AxisStyle {
+            axis_line: LineStyle::solid(self.ink),
+            major_ticks: TickStyle::new(self.ink, 4),
+            minor_ticks: TickStyle::new(self.ink, 2).hidden(),
+            grid_lines: Some(self.grid_line()),
+            zero_line: LineStyle::solid(self.ink),
+            labels: LabelStyle::new(self.ink),
+            label_offset: 6,
+            plot_gap: 0,
+            label_edge_margin: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_patterns_cycle() {
+        let style = MonochromeStyle::BINARY;
+        assert_eq!(style.series_pattern(0), LinePattern::Solid);
+        assert_eq!(style.series_pattern(1), LinePattern::Dashed);
+        assert_eq!(style.series_pattern(4), LinePattern::Solid);
+
+        let fill = style.series_fill(1);
+        assert_eq!(fill.foreground, BinaryColor::On);
+        assert_eq!(fill.background, BinaryColor::Off);
+        assert_eq!(fill.pattern, SERIES_FILL_PATTERNS[1]);
+        assert_eq!(style.series_fill(7).pattern, SERIES_FILL_PATTERNS[1]);
+    }
+
+    #[test]
+    fn test_theme_and_shade() {
+        let theme = MonochromeStyle::BINARY.theme();
+        assert_eq!(theme.background, BinaryColor::Off);
+        assert_eq!(theme.primary, BinaryColor::On);
+
+        let gray = MonochromeStyle::GRAY2;
+        assert_eq!(gray.theme().grid, Gray2::new(1));
+        assert_eq!(gray.series_fill(0).foreground, Gray2::new(1));
+        assert_eq!(gray.grid_line().pattern, LinePattern::Dotted);
+        assert_eq!(gray.axis_style().labels.color, Gray2::BLACK);
+    }
+}
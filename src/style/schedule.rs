@@ -0,0 +1,192 @@
+//! Automatic day/night theme switching.
+//!
+//! A [`ThemeSchedule`] holds a day and a night [`Theme`] and picks one based on
+//! the time of day. The time is read from a monotonic [`Clock`] that has been
+//! synchronized once with a real-time clock, so the RTC does not have to be
+//! queried on every frame.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_charts::prelude::*;
+//! use embedded_charts::style::{DayPhase, ThemeSchedule};
+//! use embedded_charts::time::ManualTimeProvider;
+//!
+//! let mut clock = ManualTimeProvider::new();
+//! let mut schedule: ThemeSchedule<Rgb565> =
+//!     ThemeSchedule::new(Theme::light(), Theme::dark()).with_day_window(7 * 60, 19 * 60);
+//!
+//! // The RTC reported 18:59:00 when the clock read zero
+//! schedule.sync_time_of_day(&clock, 18 * 3600 + 59 * 60);
+//! assert!(schedule.update(&clock)); // first update always needs a render
+//! assert_eq!(schedule.phase(), Some(DayPhase::Day));
+//!
+//! clock.advance_ms(60_000);
+//! assert!(schedule.update(&clock)); // 19:00, switch to the dark theme
+//! assert_eq!(schedule.phase(), Some(DayPhase::Night));
+//! assert!(!schedule.update(&clock));
+//! ```
+
+use crate::style::themes::Theme;
+use crate::time::Clock;
+use embedded_graphics::prelude::*;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+const MS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// Part of the day selected by a [`ThemeSchedule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPhase {
+    /// Inside the day window
+    Day,
+    /// Outside the day window
+    Night,
+}
+
+/// Switches between a day and a night theme by time of day
+#[derive(Debug, Clone)]
+pub struct ThemeSchedule<C: PixelColor> {
+    day: Theme<C>,
+    night: Theme<C>,
+    /// Start of the day window in minutes after midnight
+    day_start: u32,
+    /// End of the day window in minutes after midnight
+    day_end: u32,
+    /// Time of day in milliseconds when the clock read zero
+    offset_ms: u64,
+    phase: Option<DayPhase>,
+}
+
+impl<C: PixelColor> ThemeSchedule<C> {
+    /// Create a schedule with a day window from 07:00 to 19:00
+    pub fn new(day: Theme<C>, night: Theme<C>) -> Self {
+        Self {
+            day,
+            night,
+            day_start: 7 * 60,
+            day_end: 19 * 60,
+            offset_ms: 0,
+            phase: None,
+        }
+    }
+
+    /// Set the day window in minutes after midnight.
+    ///
+    /// The window may wrap around midnight, e.g. `22 * 60..6 * 60` for a
+    /// night shift display.
+    pub fn with_day_window(mut self, start_minutes: u32, end_minutes: u32) -> Self {
+        self.day_start = start_minutes % MINUTES_PER_DAY;
+        self.day_end = end_minutes % MINUTES_PER_DAY;
+        self.phase = None;
+        self
+    }
+
+    /// Align the clock with a time of day read from a real-time clock
+    pub fn sync_time_of_day(&mut self, clock: &dyn Clock, seconds_since_midnight: u32) {
+        let rtc_ms = u64::from(seconds_since_midnight) * 1000 % MS_PER_DAY;
+        let now_ms = clock.now_us() / 1000 % MS_PER_DAY;
+        self.offset_ms = (rtc_ms + MS_PER_DAY - now_ms) % MS_PER_DAY;
+    }
+
+    /// Get the current time of day in minutes after midnight
+    pub fn minutes_of_day(&self, clock: &dyn Clock) -> u32 {
+        let ms = (clock.now_us() / 1000 + self.offset_ms) % MS_PER_DAY;
+        (ms / 60_000) as u32
+    }
+
+    /// Get the phase at `minutes` after midnight
+    pub fn phase_at(&self, minutes: u32) -> DayPhase {
+        let minutes = minutes % MINUTES_PER_DAY;
+        let in_day = if self.day_start <= self.day_end {
+            minutes >= self.day_start && minutes < self.day_end
+        } else {
+            minutes >= self.day_start || minutes < self.day_end
+        };
+        if in_day {
+            DayPhase::Day
+        } else {
+            DayPhase::Night
+        }
+    }
+
+    /// Re-evaluate the phase, returning `true` when the theme changed and the
+    /// display should be redrawn
+    pub fn update(&mut self, clock: &dyn Clock) -> bool {
+        let phase = self.phase_at(self.minutes_of_day(clock));
+        let changed = self.phase != Some(phase);
+        self.phase = Some(phase);
+        changed
+    }
+
+    /// Get the phase selected by the last [`update`](Self::update)
+    pub fn phase(&self) -> Option<DayPhase> {
+        self.phase
+    }
+
+    /// Get the theme for the current phase, the day theme before the first update
+    pub fn theme(&self) -> &Theme<C> {
+        match self.phase {
+            Some(DayPhase::Night) => &self.night,
+            _ => &self.day,
+        }
+    }
+
+    /// Get the day theme
+    pub fn day_theme(&self) -> &Theme<C> {
+        &self.day
+    }
+
+    /// Get the night theme
+    pub fn night_theme(&self) -> &Theme<C> {
+        &self.night
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::ManualTimeProvider;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn test_phase_windows() {
+        let schedule: ThemeSchedule<Rgb565> = ThemeSchedule::new(Theme::light(), Theme::dark());
+        assert_eq!(schedule.phase_at(6 * 60 + 59), DayPhase::Night);
+        assert_eq!(schedule.phase_at(7 * 60), DayPhase::Day);
+        assert_eq!(schedule.phase_at(19 * 60), DayPhase::Night);
+
+        // A window that wraps around midnight
+        let schedule = schedule.with_day_window(22 * 60, 6 * 60);
+        assert_eq!(schedule.phase_at(23 * 60), DayPhase::Day);
+        assert_eq!(schedule.phase_at(3 * 60), DayPhase::Day);
+        assert_eq!(schedule.phase_at(12 * 60), DayPhase::Night);
+    }
+
+    #[test]
+    fn test_update_reports_changes() {
+        let mut clock = ManualTimeProvider::with_start_time(123_000_000);
+        let mut schedule: ThemeSchedule<Rgb565> = ThemeSchedule::new(Theme::light(), Theme::dark());
+        schedule.sync_time_of_day(&clock, 23 * 3600 + 59 * 60 + 30);
+        assert_eq!(schedule.minutes_of_day(&clock), 23 * 60 + 59);
+
+        assert!(schedule.update(&clock));
+        assert_eq!(
+            schedule.theme().background,
+            Theme::<Rgb565>::dark().background
+        );
+
+        // Past midnight it is still night
+        clock.advance_ms(60_000);
+        assert_eq!(schedule.minutes_of_day(&clock), 0);
+        assert!(!schedule.update(&clock));
+
+        // Seven hours later the day theme takes over
+        clock.advance_ms(7 * 3_600_000);
+        assert!(schedule.update(&clock));
+        assert_eq!(schedule.phase(), Some(DayPhase::Day));
+        assert_eq!(
+            schedule.theme().background,
+            Theme::<Rgb565>::light().background
+        );
+    }
+}
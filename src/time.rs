@@ -30,12 +30,15 @@ pub trait TimeProvider {
     /// Calculate elapsed time in milliseconds since the last call.
     ///
     /// This is a convenience method that handles the delta calculation.
-    /// The implementation should track the last time internally.
+    /// The implementation should track the last time internally. The
+    /// millisecond counter wraps after about 49 days; the delta stays correct
+    /// across the wrap and is zero when the clock steps backwards, see
+    /// [`wrapping_elapsed_ms`].
     fn elapsed_ms(&mut self) -> Milliseconds {
         let current = self.current_time_ms();
         let last = self.last_time_ms();
         self.update_last_time_ms(current);
-        current.saturating_sub(last)
+        wrapping_elapsed_ms(last, current)
     }
 
     /// Calculate elapsed time in microseconds since the last call.
@@ -357,6 +360,376 @@ impl TimeProvider for StdTimeProvider {
     }
 }
 
+/// A shared monotonic clock.
+///
+/// Unlike [`TimeProvider`], a clock only reports the current time and keeps no
+/// per-consumer state, so a single hardware timer can be handed to several
+/// animations or dashboards as a `&dyn Clock`. Wrap it in a [`ClockTimeProvider`]
+/// wherever a [`TimeProvider`] is expected.
+pub trait Clock {
+    /// Get the current time in microseconds since some reference point.
+    fn now_us(&self) -> Microseconds;
+
+    /// Get the current time in milliseconds since the same reference point.
+    fn now_ms(&self) -> Milliseconds {
+        (self.now_us() / 1000) as Milliseconds
+    }
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now_us(&self) -> Microseconds {
+        (**self).now_us()
+    }
+}
+
+impl<F> Clock for MonotonicTimeProvider<F>
+where
+    F: Fn() -> Microseconds,
+{
+    fn now_us(&self) -> Microseconds {
+        self.current_time_us()
+    }
+}
+
+impl Clock for ManualTimeProvider {
+    fn now_us(&self) -> Microseconds {
+        self.current_us
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for StdTimeProvider {
+    fn now_us(&self) -> Microseconds {
+        self.current_time_us()
+    }
+}
+
+/// Time provider that reads the time from a shared [`Clock`].
+///
+/// Each consumer keeps its own provider for elapsed-time tracking while all of
+/// them read the same underlying clock.
+///
+/// # Example
+///
+/// ```rust
+/// use embedded_charts::time::{Clock, ClockTimeProvider, ManualTimeProvider, TimeProvider};
+///
+/// let clock = ManualTimeProvider::with_start_time(5_000);
+/// let shared: &dyn Clock = &clock;
+///
+/// let mut provider = ClockTimeProvider::new(shared);
+/// assert_eq!(provider.elapsed_ms(), 0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ClockTimeProvider<'a> {
+    /// Clock to read the current time from.
+    clock: &'a dyn Clock,
+    /// Last recorded time in milliseconds.
+    last_ms: Milliseconds,
+    /// Last recorded time in microseconds.
+    last_us: Microseconds,
+}
+
+impl<'a> ClockTimeProvider<'a> {
+    /// Create a new time provider reading from the given clock.
+    ///
+    /// Elapsed time is measured from the moment the provider is created.
+    pub fn new(clock: &'a dyn Clock) -> Self {
+        let current_us = clock.now_us();
+        Self {
+            clock,
+            last_ms: (current_us / 1000) as Milliseconds,
+            last_us: current_us,
+        }
+    }
+
+    /// Get the underlying clock.
+    pub fn clock(&self) -> &'a dyn Clock {
+        self.clock
+    }
+}
+
+impl core::fmt::Debug for ClockTimeProvider<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ClockTimeProvider")
+            .field("last_ms", &self.last_ms)
+            .field("last_us", &self.last_us)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TimeProvider for ClockTimeProvider<'_> {
+    fn current_time_ms(&self) -> Milliseconds {
+        self.clock.now_ms()
+    }
+
+    fn current_time_us(&self) -> Microseconds {
+        self.clock.now_us()
+    }
+
+    fn last_time_ms(&self) -> Milliseconds {
+        self.last_ms
+    }
+
+    fn last_time_us(&self) -> Microseconds {
+        self.last_us
+    }
+
+    fn update_last_time_ms(&mut self, time: Milliseconds) {
+        self.last_ms = time;
+    }
+
+    fn update_last_time_us(&mut self, time: Microseconds) {
+        self.last_us = time;
+    }
+}
+
+/// Clock backed by `std::time::Instant`.
+///
+/// Time is measured from the moment the clock is created.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct StdClock {
+    /// Reference point for time calculations.
+    start_time: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl StdClock {
+    /// Create a new clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            start_time: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    fn now_us(&self) -> Microseconds {
+        self.start_time.elapsed().as_micros() as Microseconds
+    }
+}
+
+/// Clock adapter for [`embedded_time::Clock`] implementations.
+///
+/// If the underlying clock fails to report the time, the last successful
+/// reading is returned so animations pause instead of jumping.
+#[cfg(feature = "embedded-time")]
+#[derive(Debug)]
+pub struct EmbeddedTimeClock<C> {
+    /// The wrapped clock.
+    clock: C,
+    /// Last successful reading in microseconds.
+    last_us: core::cell::Cell<Microseconds>,
+}
+
+#[cfg(feature = "embedded-time")]
+impl<C> EmbeddedTimeClock<C>
+where
+    C: embedded_time::Clock,
+    C::T: Into<u64>,
+{
+    /// Wrap an `embedded-time` clock.
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            last_us: core::cell::Cell::new(0),
+        }
+    }
+
+    /// Get a reference to the wrapped clock.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+
+    /// Release the wrapped clock.
+    pub fn into_inner(self) -> C {
+        self.clock
+    }
+}
+
+#[cfg(feature = "embedded-time")]
+impl<C> Clock for EmbeddedTimeClock<C>
+where
+    C: embedded_time::Clock,
+    C::T: Into<u64>,
+{
+    fn now_us(&self) -> Microseconds {
+        let Ok(now) = self.clock.try_now() else {
+            return self.last_us.get();
+        };
+
+        // Ticks scaled by the clock's period (in seconds) give the time since epoch
+        let ticks: u64 = now.duration_since_epoch().integer().into();
+        let numerator = *C::SCALING_FACTOR.numerator() as u128;
+        let denominator = *C::SCALING_FACTOR.denominator() as u128;
+        let current_us = (ticks as u128 * numerator * 1_000_000 / denominator) as Microseconds;
+
+        self.last_us.set(current_us);
+        current_us
+    }
+}
+
+/// Instant types that can be converted to microseconds since the timer epoch.
+///
+/// Implemented for the `fugit` instants used by RTIC monotonics.
+#[cfg(feature = "rtic")]
+pub trait MonotonicInstant {
+    /// Get the time since the timer epoch in microseconds.
+    fn to_micros(&self) -> Microseconds;
+}
+
+#[cfg(feature = "rtic")]
+impl<const NOM: u32, const DENOM: u32> MonotonicInstant for fugit::Instant<u32, NOM, DENOM> {
+    fn to_micros(&self) -> Microseconds {
+        (self.ticks() as u128 * NOM as u128 * 1_000_000 / DENOM as u128) as Microseconds
+    }
+}
+
+#[cfg(feature = "rtic")]
+impl<const NOM: u32, const DENOM: u32> MonotonicInstant for fugit::Instant<u64, NOM, DENOM> {
+    fn to_micros(&self) -> Microseconds {
+        (self.ticks() as u128 * NOM as u128 * 1_000_000 / DENOM as u128) as Microseconds
+    }
+}
+
+/// Clock adapter for RTIC monotonic timers.
+///
+/// RTIC monotonics are accessed through associated functions, so the adapter
+/// carries no state and can be created wherever a clock is needed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use embedded_charts::time::{ClockTimeProvider, RticClock};
+///
+/// static CLOCK: RticClock<Mono> = RticClock::new();
+/// let mut provider = ClockTimeProvider::new(&CLOCK);
+/// ```
+#[cfg(feature = "rtic")]
+#[derive(Debug)]
+pub struct RticClock<M> {
+    _monotonic: core::marker::PhantomData<fn() -> M>,
+}
+
+#[cfg(feature = "rtic")]
+impl<M> RticClock<M> {
+    /// Create a clock reading from the monotonic `M`.
+    pub const fn new() -> Self {
+        Self {
+            _monotonic: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "rtic")]
+impl<M> Default for RticClock<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "rtic")]
+impl<M> Clock for RticClock<M>
+where
+    M: rtic_time::Monotonic,
+    M::Instant: MonotonicInstant,
+{
+    fn now_us(&self) -> Microseconds {
+        M::now().to_micros()
+    }
+}
+
+/// Milliseconds from `earlier` to `now` on a wrapping 32-bit counter.
+///
+/// [`Milliseconds`] wraps at 2^32 ms, about 49.7 days. Subtracting two
+/// readings with wrapping arithmetic gives the right delta across the wrap,
+/// as long as less than half a period passed between them. A larger delta
+/// means `now` is behind `earlier`, e.g. after the clock was reset or set
+/// back, and gives zero rather than a jump of weeks.
+///
+/// ```rust
+/// use embedded_charts::time::wrapping_elapsed_ms;
+///
+/// assert_eq!(wrapping_elapsed_ms(u32::MAX - 9, 20), 30);
+/// // The clock stepped back by 10 ms
+/// assert_eq!(wrapping_elapsed_ms(100, 90), 0);
+/// ```
+pub const fn wrapping_elapsed_ms(earlier: Milliseconds, now: Milliseconds) -> Milliseconds {
+    let delta = now.wrapping_sub(earlier);
+    if delta > i32::MAX as Milliseconds {
+        0
+    } else {
+        delta
+    }
+}
+
+/// Extends a wrapping 32-bit millisecond counter into a 64-bit timeline.
+///
+/// Feed every counter reading through [`unwrap`](Self::unwrap) and use the
+/// result as a timestamp, e.g. for a [`TimeAxis`](crate::axes::TimeAxis) in
+/// milliseconds. Each reading is placed within half a counter period (about
+/// 24.8 days) of the newest one seen so far, so a wrap is detected as long as
+/// the counter is read at least that often. Readings that arrive slightly out
+/// of order map to times before the newest one instead of jumping ahead by a
+/// full period.
+///
+/// ```rust
+/// use embedded_charts::time::TickUnwrapper;
+///
+/// let mut ticks = TickUnwrapper::new();
+/// assert_eq!(ticks.unwrap(u32::MAX - 9), u32::MAX as u64 - 9);
+/// assert_eq!(ticks.unwrap(20), u32::MAX as u64 + 21);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TickUnwrapper {
+    /// Newest unwrapped timestamp, if any reading was seen
+    latest: Option<u64>,
+}
+
+impl TickUnwrapper {
+    /// Create an unwrapper; the first reading is taken as is
+    pub const fn new() -> Self {
+        Self { latest: None }
+    }
+
+    /// Unwrapped timestamp for a counter reading
+    pub fn unwrap(&mut self, ticks: Milliseconds) -> u64 {
+        let Some(latest) = self.latest else {
+            self.latest = Some(ticks as u64);
+            return ticks as u64;
+        };
+
+        // Signed distance from the newest reading, within half a period
+        let delta = ticks.wrapping_sub(latest as Milliseconds) as i32;
+        let timestamp = if delta >= 0 {
+            latest.saturating_add(delta as u64)
+        } else {
+            latest.saturating_sub(delta.unsigned_abs() as u64)
+        };
+        self.latest = Some(latest.max(timestamp));
+        timestamp
+    }
+
+    /// Newest unwrapped timestamp, or `None` before the first reading
+    pub fn latest(&self) -> Option<u64> {
+        self.latest
+    }
+
+    /// Forget all readings, e.g. after the counter was reset
+    pub fn reset(&mut self) {
+        self.latest = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -444,4 +817,86 @@ mod tests {
 
         assert_eq!(elapsed, 25); // Should only count from reset point
     }
+
+    #[test]
+    fn test_clock_time_provider_shares_clock() {
+        use core::cell::Cell;
+
+        struct TestClock(Cell<Microseconds>);
+
+        impl Clock for TestClock {
+            fn now_us(&self) -> Microseconds {
+                self.0.get()
+            }
+        }
+
+        let clock = TestClock(Cell::new(1_000));
+        let shared: &dyn Clock = &clock;
+        let mut first = ClockTimeProvider::new(shared);
+
+        clock.0.set(11_000);
+        let mut second = ClockTimeProvider::new(shared);
+
+        clock.0.set(31_000);
+        assert_eq!(first.elapsed_ms(), 30);
+        assert_eq!(second.elapsed_ms(), 20);
+        assert_eq!(second.current_time_us(), 31_000);
+    }
+
+    #[test]
+    fn test_elapsed_across_counter_wrap() {
+        // 10 ms before the 32-bit millisecond counter wraps
+        let start_us = (u32::MAX as Microseconds - 9) * 1000;
+        let mut provider = ManualTimeProvider::with_start_time(start_us);
+        provider.reset();
+
+        provider.advance_ms(30);
+        assert_eq!(provider.current_time_ms(), 20);
+        assert_eq!(provider.elapsed_ms(), 30);
+    }
+
+    #[test]
+    fn test_elapsed_after_backward_step() {
+        let mut provider = ManualTimeProvider::new();
+        provider.set_time_ms(1000);
+        provider.elapsed_ms();
+
+        // The clock was set back; no time passed instead of ~49 days
+        provider.set_time_ms(400);
+        assert_eq!(provider.elapsed_ms(), 0);
+
+        // Deltas continue from the new reading
+        provider.advance_ms(25);
+        assert_eq!(provider.elapsed_ms(), 25);
+    }
+
+    #[test]
+    fn test_tick_unwrapper() {
+        let mut ticks = TickUnwrapper::new();
+        assert_eq!(ticks.latest(), None);
+
+        let period = 1u64 << 32;
+        assert_eq!(ticks.unwrap(4_000_000_000), 4_000_000_000);
+        assert_eq!(ticks.unwrap(100), period + 100);
+        // A late reading from before the wrap stays before it
+        assert_eq!(ticks.unwrap(4_294_967_000), 4_294_967_000);
+        assert_eq!(ticks.latest(), Some(period + 100));
+
+        // Several wraps, read at least every half period
+        for step in 1..=4u64 {
+            let expected = period + 100 + step * (period / 3);
+            assert_eq!(ticks.unwrap(expected as u32), expected);
+        }
+    }
+
+    #[test]
+    fn test_time_providers_as_clocks() {
+        let mut manual = ManualTimeProvider::new();
+        manual.advance_ms(42);
+        assert_eq!(Clock::now_ms(&manual), 42);
+
+        let monotonic = MonotonicTimeProvider::new(|| 7_500);
+        assert_eq!(monotonic.now_us(), 7_500);
+        assert_eq!(monotonic.now_ms(), 7);
+    }
 }
@@ -10,7 +10,7 @@ use embedded_charts::data::series::StaticDataSeries;
 use embedded_charts::data::Point2D;
 use embedded_charts::error::ChartError;
 use embedded_charts::grid::GridSystem;
-use embedded_charts::math::interpolation::{InterpolationConfig, InterpolationType};
+use embedded_charts::math::interpolation::{EndpointMode, InterpolationConfig, InterpolationType};
 use embedded_charts::prelude::*;
 use embedded_graphics::{
     mock_display::MockDisplay,
@@ -40,6 +40,7 @@ fn test_curve_chart_interpolation_config_setters() {
         subdivisions: 16,
         tension: 0.7,
         closed: true,
+        ..Default::default()
     };
 
     chart.set_interpolation_config(config.clone());
@@ -70,6 +71,10 @@ fn test_curve_chart_style_setters() {
         fill_color: Some(Rgb565::BLUE),
         smooth: false,
         smooth_subdivisions: 8,
+        simplify_epsilon: None,
+        decimation: None,
+        smooth_endpoints: EndpointMode::Duplicate,
+        line_pattern: LinePattern::Solid,
     };
 
     chart.set_style(style.clone());
@@ -90,10 +95,15 @@ fn test_curve_chart_config_setters() {
 
     let config = ChartConfig {
         title: Some(title),
+        subtitle: None,
+        title_style: None,
         background_color: Some(Rgb565::BLACK),
         margins: Margins::new(30, 20, 40, 10),
         show_grid: false,
         grid_color: None,
+        background_mode: BackgroundMode::Fill,
+        placeholder: None,
+        locale: NumberLocale::PLAIN,
     };
 
     chart.set_config(config.clone());
@@ -138,6 +148,10 @@ fn test_curve_chart_base_chart_access() {
         fill_color: None,
         smooth: false,
         smooth_subdivisions: 8,
+        simplify_epsilon: None,
+        decimation: None,
+        smooth_endpoints: EndpointMode::Duplicate,
+        line_pattern: LinePattern::Solid,
     });
 
     assert_eq!(chart.style().line_color, Rgb565::RED);
@@ -5,9 +5,10 @@
 #![allow(dead_code)] // Allow unused testing utilities - they're part of testing infrastructure
 
 use embedded_charts::{
-    chart::traits::{Chart, ChartBuilder, ChartConfig},
+    chart::traits::{BackgroundMode, Chart, ChartBuilder, ChartConfig},
     data::{point::Point2D, series::StaticDataSeries, DataSeries},
     error::{ChartError, ChartResult},
+    style::NumberLocale,
 };
 use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
 
@@ -137,17 +138,27 @@ impl ChartTestSuite {
         let color_configs = [
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: Some(TestColors::BACKGROUND),
                 margins: super::TEST_MARGINS,
                 grid_color: Some(TestColors::GRID),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: true,
+                locale: NumberLocale::PLAIN,
             },
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: None, // No background
                 margins: super::TEST_MARGINS,
                 grid_color: Some(TestColors::PRIMARY),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: false,
+                locale: NumberLocale::PLAIN,
             },
         ];
 
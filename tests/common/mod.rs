@@ -8,6 +8,7 @@
 use embedded_charts::{
     data::{point::Point2D, series::StaticDataSeries},
     error::{ChartError, ChartResult},
+    style::NumberLocale,
 };
 use embedded_graphics::{
     mock_display::MockDisplay,
@@ -73,10 +74,15 @@ pub fn validate_chart_output(display: &MockDisplay<Rgb565>) -> ChartResult<()> {
 pub fn create_test_config() -> embedded_charts::chart::traits::ChartConfig<Rgb565> {
     embedded_charts::chart::traits::ChartConfig {
         title: None,
+        subtitle: None,
+        title_style: None,
         background_color: Some(TestColors::BACKGROUND),
         margins: TEST_MARGINS,
         grid_color: Some(TestColors::GRID),
+        background_mode: embedded_charts::chart::traits::BackgroundMode::Fill,
+        placeholder: None,
         show_grid: true,
+        locale: NumberLocale::PLAIN,
     }
 }
 
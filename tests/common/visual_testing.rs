@@ -5,9 +5,10 @@
 #![allow(dead_code)] // Allow unused testing utilities - they're part of testing infrastructure
 
 use embedded_charts::{
-    chart::traits::{Chart, ChartConfig},
+    chart::traits::{BackgroundMode, Chart, ChartConfig},
     data::{point::Point2D, series::StaticDataSeries, DataSeries},
     error::ChartResult,
+    style::NumberLocale,
 };
 use embedded_graphics::{
     mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*, primitives::Rectangle,
@@ -140,24 +141,39 @@ impl VisualTester {
         let themes = [
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: Some(Rgb565::WHITE),
                 margins: super::TEST_MARGINS,
                 grid_color: Some(Rgb565::CSS_LIGHT_GRAY),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: true,
+                locale: NumberLocale::PLAIN,
             },
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: Some(Rgb565::BLACK),
                 margins: super::TEST_MARGINS,
                 grid_color: Some(Rgb565::CSS_DARK_GRAY),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: true,
+                locale: NumberLocale::PLAIN,
             },
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: None,
                 margins: super::TEST_MARGINS,
                 grid_color: Some(Rgb565::BLUE),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: false,
+                locale: NumberLocale::PLAIN,
             },
         ];
 
@@ -16,11 +16,12 @@ mod curve_tests {
         chart::{
             curve::{CurveChart, CurveChartBuilder},
             line::{MarkerShape, MarkerStyle},
-            traits::{Chart, ChartConfig, Margins},
+            traits::{BackgroundMode, Chart, ChartConfig, Margins},
         },
         data::{point::Point2D, series::StaticDataSeries},
         error::{ChartError, ChartResult},
         math::interpolation::{InterpolationConfig, InterpolationType},
+        style::NumberLocale,
     };
     use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
 
@@ -401,6 +402,7 @@ mod curve_tests {
             subdivisions: 16,
             tension: 0.8,
             closed: true,
+            ..Default::default()
         };
 
         chart.set_interpolation_config(new_config.clone());
@@ -438,6 +440,10 @@ mod curve_tests {
             }),
             smooth: false,          // Not used in CurveChart
             smooth_subdivisions: 8, // Not used in CurveChart
+            simplify_epsilon: None,
+            decimation: None,
+            smooth_endpoints: embedded_charts::math::interpolation::EndpointMode::Duplicate,
+            line_pattern: embedded_charts::style::LinePattern::Solid,
         };
 
         chart.set_style(new_style.clone());
@@ -448,6 +454,8 @@ mod curve_tests {
         // Test config mutation
         let new_config = ChartConfig {
             title: Some(heapless::String::try_from("Test Title").unwrap()),
+            subtitle: None,
+            title_style: None,
             background_color: Some(TestColors::BACKGROUND),
             margins: Margins {
                 top: 25,
@@ -456,7 +464,10 @@ mod curve_tests {
                 right: 20,
             },
             grid_color: Some(TestColors::GRID),
+            background_mode: BackgroundMode::Fill,
+            placeholder: None,
             show_grid: true,
+            locale: NumberLocale::PLAIN,
         };
 
         chart.set_config(new_config.clone());
@@ -486,6 +497,10 @@ mod curve_tests {
             markers: None,
             smooth: false,
             smooth_subdivisions: 8,
+            simplify_epsilon: None,
+            decimation: None,
+            smooth_endpoints: embedded_charts::math::interpolation::EndpointMode::Duplicate,
+            line_pattern: embedded_charts::style::LinePattern::Solid,
         };
         base_chart_mut.set_style(new_style);
 
@@ -510,17 +525,27 @@ mod curve_tests {
         let configs = [
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: Some(TestColors::BACKGROUND),
                 margins: crate::common::TEST_MARGINS,
                 grid_color: Some(TestColors::GRID),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: true,
+                locale: NumberLocale::PLAIN,
             },
             ChartConfig {
                 title: None,
+                subtitle: None,
+                title_style: None,
                 background_color: Some(TestColors::BACKGROUND),
                 margins: crate::common::TEST_MARGINS,
                 grid_color: Some(TestColors::GRID),
+                background_mode: BackgroundMode::Fill,
+                placeholder: None,
                 show_grid: false,
+                locale: NumberLocale::PLAIN,
             },
         ];
 
@@ -66,6 +66,10 @@ fn test_chart_error_display() {
             ChartError::ConfigurationError,
             "Configuration error occurred",
         ),
+        (
+            ChartError::ConfigError(ErrorContext::new("build gauge", "Set a value range")),
+            "Invalid configuration during build gauge (hint: Set a value range)",
+        ),
     ];
 
     for (error, expected_msg) in errors {
@@ -73,6 +77,16 @@ fn test_chart_error_display() {
     }
 }
 
+#[test]
+fn test_chart_error_is_configuration_error() {
+    assert!(ChartError::InvalidConfiguration.is_configuration_error());
+    assert!(ChartError::ConfigurationError.is_configuration_error());
+    assert!(ChartError::ConfigError(ErrorContext::new("build", "hint")).is_configuration_error());
+    assert!(ChartError::LayoutError(LayoutError::InvalidConfiguration).is_configuration_error());
+    assert!(!ChartError::MemoryFull.is_configuration_error());
+    assert!(!ChartError::LayoutError(LayoutError::InsufficientSpace).is_configuration_error());
+}
+
 #[test]
 fn test_chart_error_from_sub_errors() {
     // From DataError
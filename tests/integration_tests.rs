@@ -446,6 +446,7 @@ fn test_scatter_chart_color_mapping() {
         let color_mapping = ColorMapping {
             colors,
             strategy: *strategy,
+            scaling: embedded_charts::style::ColorScaling::MinMax,
         };
 
         // Create scatter chart with color mapping
@@ -597,6 +598,7 @@ fn test_scatter_chart_comprehensive_features() {
     let color_mapping = ColorMapping {
         colors,
         strategy: ColorMappingStrategy::ValueBased,
+        scaling: embedded_charts::style::ColorScaling::MinMax,
     };
 
     // Create size mapping
@@ -758,18 +760,18 @@ fn test_gauge_chart_threshold_zones() {
         .unwrap();
 
     // Verify threshold zones
-    assert_eq!(chart.style().threshold_zones.len(), 6);
+    assert_eq!(chart.style().threshold_zones.len(), 3);
 
     let zones = &chart.style().threshold_zones;
     assert_eq!(zones[0].start, 0.0);
-    assert_eq!(zones[0].end, 30.0);
+    assert_eq!(zones[0].end, 60.0);
     assert_eq!(zones[0].color, Rgb565::GREEN);
 
-    assert_eq!(zones[1].start, 30.0);
-    assert_eq!(zones[1].end, 70.0);
+    assert_eq!(zones[1].start, 60.0);
+    assert_eq!(zones[1].end, 80.0);
     assert_eq!(zones[1].color, Rgb565::YELLOW);
 
-    assert_eq!(zones[2].start, 70.0);
+    assert_eq!(zones[2].start, 80.0);
     assert_eq!(zones[2].end, 100.0);
     assert_eq!(zones[2].color, Rgb565::RED);
 
@@ -971,6 +973,7 @@ fn test_rendering_performance_benchmarks() {
         let color_mapping = ColorMapping {
             colors,
             strategy: ColorMappingStrategy::ValueBased,
+            scaling: embedded_charts::style::ColorScaling::MinMax,
         };
 
         let size_mapping = SizeMapping {
@@ -1098,6 +1101,32 @@ fn test_different_color_types() {
     assert!(result.is_ok());
 }
 
+#[test]
+#[cfg(feature = "line")]
+fn test_chart_context_line_chart_uses_context_capacity() {
+    use embedded_charts::prelude::*;
+
+    chart_context!(mod ui: embedded_graphics::pixelcolor::Rgb565, 64);
+
+    let mut series = ui::Series::new();
+    for i in 0..64 {
+        series.push(Point2D::new(i as f32, (i % 7) as f32)).unwrap();
+    }
+
+    let chart: ui::LineChart = LineChart::builder()
+        .capacity::<64>()
+        .line_color(Rgb565::BLUE)
+        .build()
+        .unwrap();
+    let mut display = MockDisplay::<Rgb565>::new();
+    display.set_allow_overdraw(true);
+    let viewport = Rectangle::new(Point::new(0, 0), Size::new(64, 64));
+
+    assert!(chart
+        .draw(&series, chart.config(), viewport, &mut display)
+        .is_ok());
+}
+
 #[test]
 fn test_data_points_macro() {
     // Test if the data_points! macro works correctly
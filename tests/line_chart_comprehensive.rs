@@ -22,6 +22,7 @@ use embedded_charts::{
     },
     data::{point::Point2D, series::StaticDataSeries},
     grid::GridSystem,
+    math::interpolation::EndpointMode,
 };
 use embedded_graphics::{
     mock_display::MockDisplay,
@@ -570,6 +571,10 @@ fn test_line_chart_style_accessors() {
         markers: None,
         smooth: false,
         smooth_subdivisions: 8,
+        simplify_epsilon: None,
+        decimation: None,
+        smooth_endpoints: EndpointMode::Duplicate,
+        line_pattern: embedded_charts::style::LinePattern::Solid,
     };
 
     // Test with fill color
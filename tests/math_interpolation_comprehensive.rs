@@ -70,6 +70,7 @@ fn test_linear_interpolation_edge_cases() {
         subdivisions: 10,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     // Test with exactly two points
@@ -113,6 +114,7 @@ fn test_cubic_spline_interpolation_comprehensive() {
         subdivisions: 8,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     // Test with smooth curve data
@@ -162,6 +164,7 @@ fn test_catmull_rom_interpolation_comprehensive() {
             subdivisions: 6,
             tension,
             closed: false,
+            ..Default::default()
         };
 
         let points = create_points(&[(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0), (4.0, 0.0)]);
@@ -185,6 +188,7 @@ fn test_catmull_rom_interpolation_comprehensive() {
         subdivisions: 16,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     let points = create_points(&[(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)]);
@@ -202,6 +206,7 @@ fn test_bezier_interpolation_comprehensive() {
         subdivisions: 10,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     // Test with control points forming a simple curve
@@ -243,6 +248,7 @@ fn test_subdivision_limits() {
         subdivisions: 1,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
     let result = CurveInterpolator::interpolate(&points, &config).unwrap();
     assert!(result.len() >= points.len());
@@ -253,6 +259,7 @@ fn test_subdivision_limits() {
         subdivisions: 50,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
     let result = CurveInterpolator::interpolate(&points, &config).unwrap();
     assert!(result.len() <= MAX_INTERPOLATED_POINTS);
@@ -273,6 +280,7 @@ fn test_memory_boundary_conditions() {
         subdivisions: 4,
         tension: 0.5,
         closed: false,
+        ..Default::default()
     };
 
     let result = CurveInterpolator::interpolate(&points, &config);
@@ -377,6 +385,7 @@ fn test_interpolation_accuracy() {
             subdivisions: 8,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         let result = CurveInterpolator::interpolate(&points, &config).unwrap();
@@ -418,6 +427,7 @@ fn test_edge_preservation() {
             subdivisions: 4,
             tension: 0.5,
             closed: false,
+            ..Default::default()
         };
 
         let result = CurveInterpolator::interpolate(&points, &config).unwrap();
@@ -463,6 +473,7 @@ fn test_performance_characteristics() {
                 subdivisions: 4,
                 tension: 0.5,
                 closed: false,
+                ..Default::default()
             };
 
             let start = Instant::now();